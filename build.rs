@@ -1,5 +1,66 @@
 extern crate lalrpop;
 
+use std::env;
+use std::path::PathBuf;
+use std::process::Command;
+
 fn main() {
-  lalrpop::process_root().unwrap();
+  lalrpop::Configuration::new()
+    .emit_rerun_directives(true)
+    .process_current_dir()
+    .unwrap();
+  build_runtime();
+}
+
+// Compile the runtime once into a static library. The snake binary embeds it
+// and links every compiled program against it.
+fn build_runtime() {
+  println!("cargo:rerun-if-changed=build.rs");
+  println!("cargo:rerun-if-changed=runtime/stub.rs");
+
+  let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
+  let rustc = env::var("RUSTC").unwrap_or_else(|_| String::from("rustc"));
+  // compiled snake code is always x86-64
+  let target = if env::var("CARGO_CFG_TARGET_OS").unwrap() == "macos" {
+    String::from("x86_64-apple-darwin")
+  } else {
+    env::var("TARGET").unwrap()
+  };
+
+  let out = Command::new(rustc)
+    .arg("runtime/stub.rs")
+    .arg("--crate-type=staticlib")
+    .arg("--crate-name=snake_runtime")
+    .arg("--print=native-static-libs")
+    .arg("-O")
+    .arg("--target")
+    .arg(&target)
+    .arg("-o")
+    .arg(out_dir.join("libsnake_runtime.a"))
+    .output()
+    .expect("failed to run rustc on the runtime");
+  let stderr = String::from_utf8_lossy(&out.stderr);
+  if !out.status.success() {
+    panic!("Failure compiling the runtime: {}\n{}", out.status, stderr);
+  }
+
+  // rustc reports the system libraries a static library needs on stderr
+  let native_libs = stderr
+    .lines()
+    .find_map(|line| line.split_once("native-static-libs: "))
+    .map(|(_, libs)| libs.trim())
+    .unwrap_or("");
+  println!("cargo:rustc-env=SNAKE_RUNTIME_NATIVE_LIBS={}", native_libs);
+
+  // object files compiled separately are only reused with the runtime they
+  // were compiled for
+  let lib = std::fs::read(out_dir.join("libsnake_runtime.a")).unwrap();
+  println!("cargo:rustc-env=SNAKE_RUNTIME_DIGEST={:016x}", fnv1a(&lib));
+}
+
+// The 64-bit FNV-1a hash of `bytes`, which link::Fingerprint also uses
+fn fnv1a(bytes: &[u8]) -> u64 {
+  bytes.iter().fold(0xcbf29ce484222325, |hash, b| {
+    (hash ^ *b as u64).wrapping_mul(0x100000001b3)
+  })
 }
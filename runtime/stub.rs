@@ -10,6 +10,7 @@ enum SnakeVal {
   Array(Vec<SnakeVal>),
   ArrayLoop,
   Closure(u64),
+  Channel,
   Custom(u64, Vec<SnakeVal>),
   Invalid(RawSnakeVal),
 }
@@ -47,6 +48,9 @@ impl From<RawSnakeVal> for SnakeVal {
         0b101 => unsafe {
           let ptr_to_val: *const RawSnakeVal = std::mem::transmute(rv.0 - 0b101);
           let type_tag: u64 = (*ptr_to_val).0;
+          if type_tag == CHANNEL_TAG {
+            return SnakeVal::Channel;
+          }
           let farr = *ptr_to_val.add(1);
           match SnakeVal::from(farr) {
             SnakeVal::Array(elts) => SnakeVal::Custom(type_tag, elts),
@@ -70,7 +74,6 @@ fn print_vec_internal(v: &Vec<SnakeVal>) -> String {
 use std::fmt;
 impl fmt::Display for SnakeVal {
   fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-    let type_lt: Vec<&str> = include!("custom_types.txt");
     write!(
       f,
       "{}",
@@ -80,11 +83,12 @@ impl fmt::Display for SnakeVal {
         SnakeVal::Array(elts) => format!("[{}]", print_vec_internal(elts)),
         SnakeVal::ArrayLoop => String::from("<loop>"),
         SnakeVal::Closure(_) => String::from("<closure>"),
+        SnakeVal::Channel => String::from("<channel>"),
         SnakeVal::Custom(tt, elts) =>
           if elts.is_empty() {
-            format!("{}", type_lt[*tt as usize])
+            format!("{}", type_name(*tt))
           } else {
-            format!("{}({})", type_lt[*tt as usize], print_vec_internal(elts))
+            format!("{}({})", type_name(*tt), print_vec_internal(elts))
           },
         SnakeVal::Invalid(rv) => format!("invalid SnakeVal: {:x}", rv.0),
       }
@@ -124,13 +128,43 @@ const INDEX_NOT_NUMBER: ErrorCode = 7;
 const CALLED_NON_FUNCTION: ErrorCode = 8;
 const WRONG_ARITY: ErrorCode = 9;
 const LENGTH_NON_ARRAY: ErrorCode = 10;
+const WHILE_ERROR: ErrorCode = 11;
+const FOR_ERROR: ErrorCode = 12;
+const GENERATOR_DONE: ErrorCode = 13;
+const NOT_CHANNEL: ErrorCode = 14;
+const FIELD_ERROR: ErrorCode = 15;
+const UNCAUGHT: ErrorCode = 16;
+
+// The type tag of channels, which no type of a program has, as in syntax.rs
+const CHANNEL_TAG: u64 = u64::MAX;
 
-#[link(name = "compiled_code", kind = "static")]
+// The size of the stack of each generator and thread, which is the usual
+// limit of the main stack
+const STACK_BYTES: usize = 8 << 20;
+// The unmapped pages below each of those stacks. A frame bigger than this
+// could skip over them.
+const GUARD_BYTES: usize = 64 << 10;
+// The room below the main stack where running past it faults
+const MAIN_GUARD_BYTES: usize = 1 << 20;
+
+// Provided by the compiled snake program this runtime is linked with
 extern "sysv64" {
-  // The \x01 here is an undocumented feature of LLVM that ensures
-  // it does not add an underscore in front of the name.
-  #[link_name = "\x01start_here"]
   fn start_here() -> RawSnakeVal;
+  // The number of custom types followed by pointers to their names,
+  // emitted by the compiler into the data section
+  #[link_name = "snake_type_names"]
+  static SNAKE_TYPE_NAMES: u64;
+}
+
+fn type_name(tag: u64) -> String {
+  unsafe {
+    let table: *const u64 = &SNAKE_TYPE_NAMES;
+    if tag >= *table {
+      return format!("<unknown type {}>", tag);
+    }
+    let name = *table.add(1 + tag as usize) as *const std::os::raw::c_char;
+    std::ffi::CStr::from_ptr(name).to_string_lossy().into_owned()
+  }
 }
 
 // reinterprets the bytes of an unsigned number to a signed number
@@ -142,12 +176,13 @@ fn sprint_snake_val(rv: RawSnakeVal) -> String {
   format!("{}", SnakeVal::from(rv))
 }
 
-#[export_name = "\x01print_snake_val"]
+#[no_mangle]
 extern "sysv64" fn print_snake_val(rv: RawSnakeVal) -> RawSnakeVal {
+  println!("{}", sprint_snake_val(rv));
   return rv;
 }
 
-#[export_name = "\x01snake_error"]
+#[no_mangle]
 extern "sysv64" fn snake_error(err_code: ErrorCode, v1: RawSnakeVal, v2: RawSnakeVal) {
   match err_code {
         ARITH_ERROR => eprintln!(
@@ -171,6 +206,15 @@ extern "sysv64" fn snake_error(err_code: ErrorCode, v1: RawSnakeVal, v2: RawSnak
             v2.0
         ),
         LENGTH_NON_ARRAY => eprintln!("length called with non-array: {}", sprint_snake_val(v1)),
+        WHILE_ERROR => eprintln!("while expected a boolean, but got {}", sprint_snake_val(v1)),
+        FOR_ERROR => eprintln!("for expected a number, but got {}", sprint_snake_val(v1)),
+        GENERATOR_DONE => eprintln!("called a generator that is finished or running"),
+        NOT_CHANNEL => eprintln!("expected a channel, but got {}", sprint_snake_val(v1)),
+        FIELD_ERROR => eprintln!(
+            "field access expected a value of a type with the field, but got {}",
+            sprint_snake_val(v1)
+        ),
+        UNCAUGHT => eprintln!("uncaught exception {}", sprint_snake_val(v1)),
         _ => eprintln!(
           "I apologize to you, dear user. I made a bug. The error code is {}. Here's a snake value: {}.",
           err_code,
@@ -180,7 +224,480 @@ extern "sysv64" fn snake_error(err_code: ErrorCode, v1: RawSnakeVal, v2: RawSnak
   std::process::exit(1);
 }
 
-fn main() {
+/* Stacks. Running out of the main stack, or of the stack of a generator
+ * or thread, faults in the unmapped memory below it, which the handler of
+ * SIGSEGV reports as a stack overflow. */
+
+// The system calls and their types, which differ between Linux and macOS
+#[cfg(target_os = "linux")]
+mod sys {
+  pub const MAP_ANON: i32 = 0x20;
+  pub const MAP_NORESERVE: i32 = 0x4000;
+  pub const SA_SIGINFO: i32 = 0x4;
+  pub const SA_ONSTACK: i32 = 0x0800_0000;
+  pub const SIGBUS: i32 = 7;
+
+  #[repr(C)]
+  pub struct SigAction {
+    pub handler: usize,
+    pub mask: [u64; 16],
+    pub flags: i32,
+    pub restorer: usize,
+  }
+
+  #[repr(C)]
+  pub struct SigInfo {
+    signo: i32,
+    errno: i32,
+    code: i32,
+    pub addr: usize,
+  }
+
+  #[repr(C)]
+  pub struct SigStack {
+    pub sp: *mut u8,
+    pub flags: i32,
+    pub size: usize,
+  }
+}
+
+#[cfg(target_os = "macos")]
+mod sys {
+  pub const MAP_ANON: i32 = 0x1000;
+  pub const MAP_NORESERVE: i32 = 0x40;
+  pub const SA_SIGINFO: i32 = 0x40;
+  pub const SA_ONSTACK: i32 = 0x1;
+  pub const SIGBUS: i32 = 10;
+
+  #[repr(C)]
+  pub struct SigAction {
+    pub handler: usize,
+    pub mask: u32,
+    pub flags: i32,
+  }
+
+  #[repr(C)]
+  pub struct SigInfo {
+    signo: i32,
+    errno: i32,
+    code: i32,
+    pid: i32,
+    uid: u32,
+    status: i32,
+    pub addr: usize,
+  }
+
+  #[repr(C)]
+  pub struct SigStack {
+    pub sp: *mut u8,
+    pub size: usize,
+    pub flags: i32,
+  }
+}
+
+#[cfg(unix)]
+mod stacks {
+  use super::sys::*;
+  use std::sync::atomic::{AtomicPtr, Ordering};
+
+  const PROT_NONE: i32 = 0;
+  const PROT_READ_WRITE: i32 = 0x3;
+  const MAP_PRIVATE: i32 = 0x2;
+  const SIGSEGV: i32 = 11;
+  const RLIMIT_STACK: i32 = 3;
+  // room for the handler, which runs on a stack of its own as the one that
+  // overflowed is full
+  const SIGNAL_STACK_BYTES: usize = 64 << 10;
+
+  extern "C" {
+    fn mmap(addr: *mut u8, len: usize, prot: i32, flags: i32, fd: i32, offset: i64) -> *mut u8;
+    fn mprotect(addr: *mut u8, len: usize, prot: i32) -> i32;
+    fn sigaction(signal: i32, action: *const SigAction, old: *mut SigAction) -> i32;
+    fn sigaltstack(stack: *const SigStack, old: *mut SigStack) -> i32;
+    fn getrlimit(resource: i32, limit: *mut [u64; 2]) -> i32;
+    fn write(fd: i32, buf: *const u8, count: usize) -> isize;
+    fn _exit(status: i32) -> !;
+  }
+
+  // An address range running into which is a stack overflow. Like the
+  // stacks, they're never freed, so the fault handler can walk the list
+  // without locking or allocating.
+  struct Guard {
+    lo: usize,
+    hi: usize,
+    next: *mut Guard,
+  }
+
+  static GUARDS: AtomicPtr<Guard> = AtomicPtr::new(std::ptr::null_mut());
+
+  fn add_guard(lo: usize, hi: usize) {
+    let guard = Box::leak(Box::new(Guard {
+      lo,
+      hi,
+      next: GUARDS.load(Ordering::Acquire),
+    }));
+    while let Err(next) =
+      GUARDS.compare_exchange_weak(guard.next, guard, Ordering::AcqRel, Ordering::Acquire)
+    {
+      guard.next = next;
+    }
+  }
+
+  // A stack of `bytes` with `guard` unmapped bytes below it, returning its
+  // top. Stacks are never unmapped, but the runtime reuses the ones that
+  // are done with.
+  pub fn new_stack(bytes: usize, guard: usize) -> *mut u64 {
+    unsafe {
+      let flags = MAP_PRIVATE | MAP_ANON | MAP_NORESERVE;
+      let base = mmap(std::ptr::null_mut(), guard + bytes, PROT_READ_WRITE, flags, -1, 0);
+      if base as usize == usize::MAX || mprotect(base, guard, PROT_NONE) != 0 {
+        eprintln!("out of memory for a stack");
+        std::process::exit(1);
+      }
+      add_guard(base as usize, base as usize + guard);
+      base.add(guard + bytes) as *mut u64
+    }
+  }
+
+  // Only calls what's async-signal-safe, since the fault can happen in the
+  // middle of the runtime allocating or printing
+  extern "C" fn on_fault(signal: i32, info: *const SigInfo, _context: *mut u8) {
+    let addr = unsafe { (*info).addr };
+    let mut guard = GUARDS.load(Ordering::Acquire);
+    while let Some(g) = unsafe { guard.as_ref() } {
+      if (g.lo..g.hi).contains(&addr) {
+        const MSG: &[u8] = b"stack overflow\n";
+        unsafe {
+          write(2, MSG.as_ptr(), MSG.len());
+          _exit(1);
+        }
+      }
+      guard = g.next;
+    }
+    // any other fault crashes as it would without the handler, once the
+    // faulting instruction runs again
+    let default = SigAction { handler: 0, ..unsafe { std::mem::zeroed() } };
+    unsafe { sigaction(signal, &default, std::ptr::null_mut()) };
+  }
+
+  // Installs the handler that reports stack overflows. `top` is about the
+  // top of the main stack.
+  pub fn catch_overflows(top: usize) {
+    unsafe {
+      let mut limit = [0u64; 2];
+      // without a limit, the main stack can grow until the memory runs out
+      if getrlimit(RLIMIT_STACK, &mut limit) == 0 && limit[0] < 1 << 40 {
+        // the main stack grows on demand, so it only faults past the limit,
+        // which counts from a bit above `top`
+        let bottom = top.saturating_sub(limit[0] as usize);
+        add_guard(bottom.saturating_sub(super::MAIN_GUARD_BYTES), top);
+      }
+      let stack = Box::leak(vec![0u8; SIGNAL_STACK_BYTES].into_boxed_slice());
+      let alt = SigStack {
+        sp: stack.as_mut_ptr(),
+        size: SIGNAL_STACK_BYTES,
+        ..std::mem::zeroed()
+      };
+      sigaltstack(&alt, std::ptr::null_mut());
+      let mut action: SigAction = std::mem::zeroed();
+      action.handler = on_fault as extern "C" fn(i32, *const SigInfo, *mut u8) as usize;
+      action.flags = SA_SIGINFO | SA_ONSTACK;
+      sigaction(SIGSEGV, &action, std::ptr::null_mut());
+      sigaction(SIGBUS, &action, std::ptr::null_mut());
+    }
+  }
+}
+
+thread_local! {
+  // The tops of the stacks of the generators and threads that finished,
+  // which new ones run on
+  static FREE_STACKS: std::cell::RefCell<Vec<*mut u64>> = std::cell::RefCell::new(Vec::new());
+}
+
+#[cfg(unix)]
+fn new_stack() -> *mut u64 {
+  stacks::new_stack(STACK_BYTES, GUARD_BYTES)
+}
+
+// Without guard pages, which overflowing the stack may write past
+#[cfg(not(unix))]
+fn new_stack() -> *mut u64 {
+  let words = (STACK_BYTES + GUARD_BYTES) / 8;
+  let stack = Box::leak(vec![0u64; words].into_boxed_slice());
+  unsafe { stack.as_mut_ptr().add(words) }
+}
+
+// A stack for a generator or thread to run on, returning its top aligned to
+// 16 bytes
+#[no_mangle]
+extern "sysv64" fn snake_new_stack() -> *mut u64 {
+  FREE_STACKS
+    .with(|free| free.borrow_mut().pop())
+    .unwrap_or_else(new_stack)
+}
+
+// Called by a generator or thread that finished with the top of its stack,
+// and whether a continuation copied frames of the stack, which have to be
+// where they were when it's called
+#[no_mangle]
+extern "sysv64" fn snake_free_stack(top: *mut u64, pinned: u64) {
+  if pinned == 0 {
+    FREE_STACKS.with(|free| free.borrow_mut().push(top));
+  }
+}
+
+/* Green threads. The compiled code switches between their stacks, and the
+ * runtime decides which thread runs next, at a `send`, at a `recv` with
+ * nothing to receive and when a thread finishes. The choice is random but
+ * reproducible: it only depends on the seed in SNAKE_SEED, or 0. */
+
+// What the compiled code saves of a thread that isn't running
+#[repr(C)]
+struct Context {
+  rsp: u64,
+  handler: u64,
+  stack_base: u64,
+  generator: u64,
+  // the operands of what the thread stopped at, then the value it continues
+  // with
+  value: u64,
+  arg: u64,
+  // 1 once a continuation copied frames of its stack, which then isn't
+  // reused when it finishes
+  pinned: u64,
+}
+
+// What a thread stopped at, as the compiled code passes it to snake_switch
+const SEND: u64 = 0;
+const RECV: u64 = 1;
+const EXIT: u64 = 2;
+
+#[derive(PartialEq, Eq)]
+enum ThreadState {
+  Runnable,
+  // blocked in a `recv`, until a `send` gives it a value
+  Waiting,
+  Finished,
+}
+
+struct Thread {
+  // boxed so that its address stays the same as threads are added
+  context: Box<Context>,
+  state: ThreadState,
+  // the top of its stack, or null for the main thread
+  stack: *mut u64,
+}
+
+#[derive(Default)]
+struct Channel {
+  // the values sent while no thread was waiting for one
+  values: std::collections::VecDeque<u64>,
+  // the threads waiting to receive, in the order they started waiting
+  waiting: std::collections::VecDeque<usize>,
+}
+
+struct Scheduler {
+  threads: Vec<Thread>,
+  channels: Vec<Channel>,
+  current: usize,
+  // the threads that can run besides the one running, in the order of
+  // their numbers
+  ready: Vec<usize>,
+  seed: u64,
+}
+
+// The context of the thread running, which the compiled code saves to
+#[export_name = "snake_thread"]
+static mut SNAKE_THREAD: *mut Context = std::ptr::null_mut();
+
+thread_local! {
+  static SCHEDULER: std::cell::RefCell<Scheduler> = std::cell::RefCell::new(Scheduler::new());
+}
+
+fn new_context(rsp: u64, stack_base: u64, value: u64) -> Box<Context> {
+  Box::new(Context {
+    rsp,
+    handler: 0,
+    stack_base,
+    generator: 0,
+    value,
+    arg: 0,
+    pinned: 0,
+  })
+}
+
+impl Scheduler {
+  // Only the main thread, which `start_here` runs on
+  fn new() -> Self {
+    let seed = std::env::var("SNAKE_SEED")
+      .ok()
+      .and_then(|seed| seed.parse().ok())
+      .unwrap_or(0);
+    let mut main = new_context(0, 0, 0);
+    unsafe { SNAKE_THREAD = &mut *main };
+    Scheduler {
+      threads: vec![Thread {
+        context: main,
+        state: ThreadState::Runnable,
+        stack: std::ptr::null_mut(),
+      }],
+      channels: vec![],
+      current: 0,
+      ready: vec![],
+      seed,
+    }
+  }
+
+  // splitmix64, which the interpreter's scheduler uses too
+  fn next_random(&mut self) -> u64 {
+    self.seed = self.seed.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    let mut z = self.seed;
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+  }
+
+  // The channel a snake value is, if it is one
+  fn channel(&mut self, c: u64) -> Option<&mut Channel> {
+    if c % 8 != 0b101 {
+      return None;
+    }
+    let value = (c - 0b101) as *const u64;
+    unsafe {
+      if *value != CHANNEL_TAG {
+        return None;
+      }
+      let fields = (*value.add(1) - 0b001) as *const u64;
+      self.channels.get_mut((*fields.add(1) >> 1) as usize)
+    }
+  }
+
+  // The context of the thread to continue with, or null if the operand of
+  // a `send` or `recv` isn't a channel
+  fn switch(&mut self, op: u64) -> *mut Context {
+    let current = self.current;
+    let (value, arg) = {
+      let context = &self.threads[current].context;
+      (context.value, context.arg)
+    };
+    match op {
+      SEND => {
+        let channel = match self.channel(value) {
+          Some(channel) => channel,
+          None => return std::ptr::null_mut(),
+        };
+        // straight to the thread that has waited longest, if any
+        match channel.waiting.pop_front() {
+          Some(receiver) => {
+            self.threads[receiver].context.value = arg;
+            self.make_runnable(receiver);
+          }
+          None => channel.values.push_back(arg),
+        }
+        self.threads[current].context.value = arg;
+        self.make_runnable(current);
+      }
+      RECV => {
+        let channel = match self.channel(value) {
+          Some(channel) => channel,
+          None => return std::ptr::null_mut(),
+        };
+        match channel.values.pop_front() {
+          Some(v) => {
+            let context = &mut self.threads[current].context;
+            context.value = v;
+            return &mut **context;
+          }
+          None => {
+            channel.waiting.push_back(current);
+            self.threads[current].state = ThreadState::Waiting;
+          }
+        }
+      }
+      // the stack is still in use until the next thread runs, but no
+      // stack is taken before that
+      EXIT => {
+        let thread = &mut self.threads[current];
+        thread.state = ThreadState::Finished;
+        if !thread.stack.is_null() {
+          snake_free_stack(thread.stack, thread.context.pinned);
+        }
+      }
+      _ => unreachable!("unknown thread operation {}", op),
+    }
+    self.schedule()
+  }
+
+  // Lets the thread `t` continue once the scheduler picks it
+  fn make_runnable(&mut self, t: usize) {
+    self.threads[t].state = ThreadState::Runnable;
+    let at = self.ready.partition_point(|r| *r < t);
+    self.ready.insert(at, t);
+  }
+
+  // Picks one of the threads that can run, as the interpreter does
+  fn schedule(&mut self) -> *mut Context {
+    if self.ready.is_empty() {
+      eprintln!("deadlock: every thread is waiting to receive from a channel");
+      std::process::exit(1);
+    }
+    let pick = (self.next_random() % self.ready.len() as u64) as usize;
+    self.current = self.ready.remove(pick);
+    let context: *mut Context = &mut *self.threads[self.current].context;
+    unsafe { SNAKE_THREAD = context };
+    context
+  }
+}
+
+// Makes a thread that calls the closure `f`, untagged, starting at the code
+// at `start`, returning the number of the thread
+#[no_mangle]
+extern "sysv64" fn snake_spawn(f: u64, start: u64) -> RawSnakeVal {
+  let top = snake_new_stack();
+  // the thread starts by returning to `start`
+  unsafe { *top.sub(1) = start };
+  let context = new_context(top as u64 - 8, top as u64, f);
+  SCHEDULER.with(|scheduler| {
+    let mut scheduler = scheduler.borrow_mut();
+    let thread = scheduler.threads.len();
+    scheduler.threads.push(Thread {
+      context,
+      state: ThreadState::Runnable,
+      stack: top,
+    });
+    scheduler.make_runnable(thread);
+    RawSnakeVal((thread as u64) << 1)
+  })
+}
+
+// A new channel, returning its number, which the compiled code puts in a
+// value of a custom type of its own on the heap
+#[no_mangle]
+extern "sysv64" fn snake_chan() -> RawSnakeVal {
+  SCHEDULER.with(|scheduler| {
+    let mut scheduler = scheduler.borrow_mut();
+    scheduler.channels.push(Channel::default());
+    RawSnakeVal((scheduler.channels.len() as u64 - 1) << 1)
+  })
+}
+
+// Called by the compiled code once it has saved the context of the thread
+// running, returning the context of the thread to continue with
+#[no_mangle]
+extern "sysv64" fn snake_switch(op: u64) -> *mut Context {
+  SCHEDULER.with(|scheduler| scheduler.borrow_mut().switch(op))
+}
+
+#[no_mangle]
+pub extern "C" fn main() -> i32 {
+  #[cfg(unix)]
+  {
+    let top = 0u64;
+    stacks::catch_overflows(&top as *const u64 as usize);
+  }
+  // the main thread, before the program can switch to another
+  SCHEDULER.with(|_| ());
   let output = unsafe { start_here() };
   println!("{}", sprint_snake_val(output));
+  0
 }
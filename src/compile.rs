@@ -1,11 +1,23 @@
-use crate::asm::instrs_to_string;
-use crate::errors::CompileErr;
-use crate::pipeline_functions::print_prog::{print_prog, print_sprog};
+use crate::asm::{instrs_to_string, Instr};
+use crate::errors::{CompileErr, RunnerErr};
+use crate::lexer::{self, LexError, Lexer, Tok};
+use crate::parser::ProgParser;
+use crate::pipeline_functions::print_prog::{print_prog, print_prog_with_comments, print_sprog};
 use crate::pipeline_functions::{
-  check_prog::check_prog, compile_to_instrs::compile_to_instrs, lambda_lift::lambda_lift,
-  resolve_types::resolve_types, sequentialize::sequentialize, uniquify::uniquify,
+  check_prog::check_prog, compile_to_instrs::{
+    compile_to_instrs, Linkage, GENERATOR_SYMBOL, HANDLER_SYMBOL, STACK_BASE_SYMBOL,
+  },
+  convert_assignments::{convert_assignments, convert_assignments_with}, drop_unused::drop_unused,
+  lambda_lift::lambda_lift, peephole::peephole, resolve_types::resolve_types, sequentialize::sequentialize,
+  uniquify::{uniquify, uniquify_with},
 };
+use crate::link::{self, Unit};
+use crate::modules;
+use crate::span::{file_info, span1_to_span2, FileId, Files, Span1, Span2, MAIN_FILE};
 use crate::syntax::{Exp, FunDecl, SeqProg, SurfProg};
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
 
 fn tag_exp<Ann>(p: &SurfProg<Ann>) -> SurfProg<u32> {
   let mut i = 0;
@@ -58,57 +70,334 @@ fn tag_sprog<Ann>(p: &SeqProg<Ann>) -> SeqProg<u32> {
 }
 
 use std::collections::HashMap;
-fn generate_typenames_file(lt: &HashMap<String, u64>) -> () {
-  use std::fs::File;
-  use std::io::prelude::*;
-  use std::path::Path;
+// The names of the custom types as written in the source, indexed by the
+// type tags they were resolved to
+fn type_names(lt: &HashMap<String, u64>) -> Vec<String> {
   let mut v = Vec::new();
   v.resize(lt.len(), String::from(""));
   for (ctype, tag) in lt {
-    let ctype = ctype.clone().replacen("__custom_type_", "", 1);
-    let ctype = ctype.split_once("_").unwrap().1;
-    v[*tag as usize] = format!("\"{}\"", ctype);
+    // the builtin error types aren't renamed
+    let ctype = match ctype.strip_prefix("__custom_type_") {
+      Some(ctype) => ctype.split_once("_").unwrap().1,
+      None => ctype,
+    };
+    // types from imported files are printed without their namespace
+    let ctype = ctype.rsplit('.').next().unwrap();
+    v[*tag as usize] = ctype.to_string();
   }
-  let output = format!("vec![{}]", v.join(", "));
-  let path = Path::new("runtime").join("custom_types.txt");
-  println!("{}", path.display());
-  let mut file = match File::create(&path) {
-    Err(why) => panic!("Error opening custom types txt file: {}", why),
-    Ok(file) => file,
-  };
-  match file.write_all(output.as_bytes()) {
-    Err(why) => panic!("Error writing custom types txt file: {}", why),
-    Ok(_) => (),
+  v
+}
+
+// The runtime prints custom type values by looking their tag up in this
+// table: the number of types followed by pointers to their null-terminated
+// constructor names
+pub(crate) fn type_names_data(v: &[String]) -> String {
+  let mut buf = format!("snake_type_names:\n        dq {}\n", v.len());
+  for tag in 0..v.len() {
+    buf.push_str(&format!("        dq __snake_type_name_{}\n", tag));
   }
+  for (tag, name) in v.iter().enumerate() {
+    buf.push_str(&format!("__snake_type_name_{}:\n        db \"{}\", 0\n", tag, name));
+  }
+  buf
 }
 
-pub fn compile_to_string<Span>(prog: &SurfProg<Span>) -> Result<String, CompileErr<Span>>
-where
-  Span: Clone + std::fmt::Debug,
-{
-  let print_before_seq = true;
-  let print_after_seq = false;
-  // println!("{:#?}", prog);
-
-  // first check for errors
-  check_prog(prog)?;
-  // then give all the variables unique names
-  let uniq_prog = uniquify(&tag_exp(prog));
-  // and tag the program again so that we can name resolve types
-  let tagged_uniq_prog = tag_exp(&uniq_prog);
-  // now let's resolve those types and get a lookup table for type tags
-  let (resolved_types_exp, custom_types) = resolve_types(&tagged_uniq_prog);
-  generate_typenames_file(&custom_types);
-  // tag the program again to name lambdas in lambda_lift
-  let tagged_resolve_type_exp = tag_exp(&resolved_types_exp);
-  // lift definitions to the top level
-  let (defs, main) = lambda_lift(&tagged_resolve_type_exp);
-
-  if print_before_seq {
-    println!(
-      "{}",
+// The intermediate programs that can be dumped while compiling
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Stage {
+  Parsed,
+  Uniquified,
+  ResolvedTypes,
+  Lifted,
+  Sequentialized,
+}
+
+impl Stage {
+  pub const ALL: [Stage; 5] = [
+    Stage::Parsed,
+    Stage::Uniquified,
+    Stage::ResolvedTypes,
+    Stage::Lifted,
+    Stage::Sequentialized,
+  ];
+
+  pub fn name(&self) -> &'static str {
+    match self {
+      Stage::Parsed => "parsed",
+      Stage::Uniquified => "uniquified",
+      Stage::ResolvedTypes => "resolved",
+      Stage::Lifted => "lifted",
+      Stage::Sequentialized => "sequentialized",
+    }
+  }
+}
+
+impl FromStr for Stage {
+  type Err = String;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    Stage::ALL
+      .iter()
+      .find(|stage| stage.name() == s)
+      .copied()
+      .ok_or_else(|| {
+        format!(
+          "unknown stage {}, expected one of {}",
+          s,
+          Stage::ALL
+            .iter()
+            .map(Stage::name)
+            .collect::<Vec<&str>>()
+            .join(", ")
+        )
+      })
+  }
+}
+
+impl fmt::Display for Stage {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "{}", self.name())
+  }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum OptLevel {
+  // emit the instructions exactly as compile_to_instrs produced them
+  O0,
+  // run the peephole optimizer over the instructions
+  O1,
+}
+
+impl FromStr for OptLevel {
+  type Err = String;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    match s {
+      "0" => Ok(OptLevel::O0),
+      "1" => Ok(OptLevel::O1),
+      _ => Err(format!("unknown optimization level {}, expected 0 or 1", s)),
+    }
+  }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Target {
+  Linux,
+  MacOs,
+  Windows,
+}
+
+impl Target {
+  // the platform the compiler itself was built for
+  pub fn host() -> Target {
+    if cfg!(target_os = "macos") {
+      Target::MacOs
+    } else if cfg!(target_os = "windows") {
+      Target::Windows
+    } else {
+      Target::Linux
+    }
+  }
+
+  pub fn nasm_format(&self) -> &'static str {
+    match self {
+      Target::Linux => "elf64",
+      Target::MacOs => "macho64",
+      Target::Windows => "win64",
+    }
+  }
+
+  pub fn name(&self) -> &'static str {
+    match self {
+      Target::Linux => "linux",
+      Target::MacOs => "macos",
+      Target::Windows => "windows",
+    }
+  }
+}
+
+impl fmt::Display for Target {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "{}", self.name())
+  }
+}
+
+impl FromStr for Target {
+  type Err = String;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    match s {
+      "linux" => Ok(Target::Linux),
+      "macos" => Ok(Target::MacOs),
+      "windows" => Ok(Target::Windows),
+      _ => Err(format!(
+        "unknown target {}, expected one of linux, macos, windows",
+        s
+      )),
+    }
+  }
+}
+
+// The result of a successful compilation: the assembly code together with
+// the intermediate programs that were requested with `Compiler::dump`
+#[derive(Clone, Debug)]
+pub struct Compiled {
+  pub assembly: String,
+  pub dumps: Vec<(Stage, String)>,
+}
+
+impl Compiled {
+  pub fn dump(&self, stage: Stage) -> Option<&str> {
+    self
+      .dumps
+      .iter()
+      .find(|(s, _)| *s == stage)
+      .map(|(_, d)| d.as_str())
+  }
+}
+
+/*
+ *  Compiles Snake source text to assembly.
+ *
+ *  let asm = Compiler::new()
+ *    .heap_size(1 << 16)
+ *    .opt_level(OptLevel::O1)
+ *    .dump(Stage::Lifted)
+ *    .compile("def f(x): x + 1 in f(1)")?
+ *    .assembly;
+ */
+#[derive(Clone, Debug)]
+pub struct Compiler {
+  dumps: Vec<Stage>,
+  heap_size: usize,
+  opt_level: OptLevel,
+  target: Target,
+  search_dirs: Vec<PathBuf>,
+}
+
+impl Default for Compiler {
+  fn default() -> Self {
+    Compiler::new()
+  }
+}
+
+impl Compiler {
+  pub const DEFAULT_HEAP_SIZE: usize = 999999;
+
+  pub fn new() -> Self {
+    Compiler {
+      dumps: Vec::new(),
+      heap_size: Compiler::DEFAULT_HEAP_SIZE,
+      opt_level: OptLevel::O0,
+      target: Target::host(),
+      search_dirs: Vec::new(),
+    }
+  }
+
+  // record the program as it looks after `stage` in `Compiled::dumps`
+  pub fn dump(mut self, stage: Stage) -> Self {
+    if !self.dumps.contains(&stage) {
+      self.dumps.push(stage);
+    }
+    self
+  }
+
+  // size of the heap in 8-byte words
+  pub fn heap_size(mut self, words: usize) -> Self {
+    self.heap_size = words;
+    self
+  }
+
+  pub fn opt_level(mut self, level: OptLevel) -> Self {
+    self.opt_level = level;
+    self
+  }
+
+  // look for imported files in `dir` when they aren't beside the file that
+  // imports them; directories are searched in the order they're added
+  pub fn search_dir(mut self, dir: &Path) -> Self {
+    self.search_dirs.push(dir.to_path_buf());
+    self
+  }
+
+  pub fn target(mut self, target: Target) -> Self {
+    self.target = target;
+    self
+  }
+
+  pub fn get_target(&self) -> Target {
+    self.target
+  }
+
+  // Parses `source` on its own, without the files it imports
+  pub fn parse(&self, source: &str) -> Result<SurfProg<Span1>, RunnerErr<Span2>> {
+    parse_prog(source).map_err(|e| {
+      let info = file_info(source);
+      RunnerErr::Parse {
+        msg: e.to_string(),
+        location: Some(span1_to_span2(&info, parse_error_location(&e, MAIN_FILE))),
+      }
+    })
+  }
+
+  // Parses `source` together with the files it imports, which are looked
+  // for in `dir` and then in the search directories
+  pub fn load(&self, source: &str, dir: &Path) -> Result<(SurfProg<Span1>, Files), RunnerErr<Span2>> {
+    modules::load(source, dir, &self.search_dirs)
+  }
+
+  // Compiles `source`, importing files relative to the current directory
+  pub fn compile(&self, source: &str) -> Result<Compiled, RunnerErr<Span2>> {
+    self.compile_in(source, Path::new("."))
+  }
+
+  // Compiles `source`, importing files relative to `dir`
+  pub fn compile_in(&self, source: &str, dir: &Path) -> Result<Compiled, RunnerErr<Span2>> {
+    let (prog, files) = self.load(source, dir)?;
+    let mut compiled = self
+      .compile_prog(&prog)
+      .map_err(|e| RunnerErr::CodeGen(e.map_span(|s| files.span2(*s))))?;
+    // the parsed program keeps the comments of the main file
+    if let Some((_, parsed)) = compiled.dumps.iter_mut().find(|(s, _)| *s == Stage::Parsed) {
+      let (_, comments) = lexer::lex(source).expect("the source was already parsed");
+      *parsed = print_prog_with_comments(&prog, &comments);
+    }
+    Ok(compiled)
+  }
+
+  pub fn compile_prog<Span>(&self, prog: &SurfProg<Span>) -> Result<Compiled, CompileErr<Span>>
+  where
+    Span: Clone,
+  {
+    let mut dumps = Vec::new();
+    let mut record = |stage: Stage, print: &dyn Fn() -> String| {
+      if self.dumps.contains(&stage) {
+        dumps.push((stage, print()));
+      }
+    };
+    record(Stage::Parsed, &|| print_prog(prog, 0));
+
+    // first check for errors
+    check_prog(prog)?;
+    // then give all the variables unique names
+    let uniq_prog = uniquify(&tag_exp(prog));
+    record(Stage::Uniquified, &|| print_prog(&uniq_prog, 0));
+    // and tag the program again so that we can name resolve types
+    let tagged_uniq_prog = tag_exp(&uniq_prog);
+    // now let's resolve those types and get a lookup table for type tags
+    let (resolved_types_exp, custom_types) = resolve_types(&tagged_uniq_prog);
+    record(Stage::ResolvedTypes, &|| print_prog(&resolved_types_exp, 0));
+    // put the `var`s that functions capture and assign to in arrays
+    let converted_exp = convert_assignments(&resolved_types_exp);
+    // tag the program again to name lambdas in lambda_lift
+    let tagged_resolve_type_exp = tag_exp(&converted_exp);
+    // lift definitions to the top level
+    let (defs, main) = lambda_lift(&tagged_resolve_type_exp);
+    // and drop the functions nothing uses, e.g. most of the prelude
+    let (defs, main) = drop_unused(&defs, &main);
+    record(Stage::Lifted, &|| {
       format!(
-        "{}\nin",
+        "{}\nin\n{}",
         defs
           .iter()
           .map(|decl| format!(
@@ -119,20 +408,16 @@ where
           ))
           .collect::<Vec<String>>()
           .join("\nand\n"),
+        print_prog(&main, 0)
       )
-    );
-    println!("{}\n\n\n", print_prog(&main, 0));
-  }
+    });
 
-  let (t_defs, t_main) = tag_prog(&defs, &main);
-  // then sequentialize
-  let seq_p = tag_sprog(&sequentialize(&t_defs, &t_main));
-
-  if print_after_seq {
-    println!(
-      "{}",
+    let (t_defs, t_main) = tag_prog(&defs, &main);
+    // then sequentialize
+    let seq_p = tag_sprog(&sequentialize(&t_defs, &t_main));
+    record(Stage::Sequentialized, &|| {
       format!(
-        "{}\nin",
+        "{}\nin\n{}",
         seq_p
           .funs
           .iter()
@@ -140,36 +425,207 @@ where
             "def {}({}):\n{}",
             decl.name,
             decl.parameters.join(", "),
-            print_sprog(&decl.body, 3)
+            print_sprog(&decl.body, 2)
           ))
           .collect::<Vec<String>>()
           .join("\nand\n"),
+        print_sprog(&seq_p.main, 0)
       )
-    );
-    println!("{}", print_sprog(&seq_p.main, 0));
-  }
+    });
 
-  // then codegen
-  let code = format!(
-    "
+    // then codegen
+    let mut instrs = compile_to_instrs(&seq_p, None);
+    if self.opt_level >= OptLevel::O1 {
+      instrs = peephole(instrs);
+    }
+    let assembly = format!(
+      "
         section .data
-HEAP_START:   times 999999 dq 0
+        global snake_type_names
+{}        align 8
+{}:   dq 0
+{}:   dq 0
+{}:   dq 0
+HEAP_START:   times {} dq 0
         section .text
         extern snake_error
         extern print_snake_val
+        extern snake_new_stack
+        extern snake_free_stack
+        extern snake_spawn
+        extern snake_chan
+        extern snake_switch
+        extern snake_thread
         global start_here
 start_here:
         push rbp                     ; rbp is callee-saved
         mov rbp, HEAP_START          ; use rbp as heap pointer
         sub rsp, 8                   ; keep stack aligned
+        mov r11, {}
+        mov [r11], rsp               ; the bottom of the stack callcc copies
         call __snake__main
         add rsp, 8
         pop rbp                      ; restore rbp
         ret
 {}
 ",
-    instrs_to_string(&compile_to_instrs(&seq_p))
-  );
-  println!("{}", code);
-  Ok(code)
+      type_names_data(&type_names(&custom_types)),
+      HANDLER_SYMBOL,
+      STACK_BASE_SYMBOL,
+      GENERATOR_SYMBOL,
+      self.heap_size,
+      STACK_BASE_SYMBOL,
+      instrs_to_string(&instrs)
+    );
+    Ok(Compiled { assembly, dumps })
+  }
+
+  // The files of a loaded program, to compile each on its own with
+  // `compile_unit`. Their fingerprints change with the version of snake and
+  // its runtime, so that object files from another build aren't reused.
+  pub fn units(&self, prog: &SurfProg<Span1>) -> Vec<Unit> {
+    let options = format!(
+      "{} {} {:?} {:?}",
+      env!("CARGO_PKG_VERSION"),
+      env!("SNAKE_RUNTIME_DIGEST"),
+      self.opt_level,
+      self.target
+    );
+    link::units(prog, &options)
+  }
+
+  // Compiles the unit `unit` of a checked program on its own, to assembly
+  // code that runs once it's linked with the others by `link_units`
+  pub fn compile_unit(&self, prog: &SurfProg<Span1>, units: &[Unit], unit: usize) -> String {
+    let name = &units[unit].name;
+    let mut globals = link::imported_exports(prog, units, unit);
+    let export_count = link::export_count(prog, unit);
+    if export_count.is_some() {
+      globals.push(link::exports_symbol(name));
+    }
+    let uniq_prog = uniquify_with(&tag_exp(&link::unit_prog(prog, units, unit)), &globals);
+    let (resolved_types_exp, custom_types) = resolve_types(&tag_exp(&uniq_prog));
+    let converted_exp = convert_assignments_with(&resolved_types_exp, units[unit].callcc);
+    let (defs, main) = lambda_lift(&tag_exp(&converted_exp));
+    let (defs, main) = drop_unused(&defs, &main);
+    let (t_defs, t_main) = tag_prog(&defs, &main);
+    let seq_p = tag_sprog(&sequentialize(&t_defs, &t_main));
+
+    let (funs, types) = link::visible_decls(&uniq_prog);
+    let type_symbols = link::type_symbols(name, &custom_types, &types);
+    let entry = link::init_symbol(name);
+    let linkage = Linkage {
+      entry: &entry,
+      type_symbols: &type_symbols,
+    };
+    let mut instrs = compile_to_instrs(&seq_p, Some(&linkage));
+    if self.opt_level >= OptLevel::O1 {
+      instrs = peephole(instrs);
+    }
+    // the functions a module exports are also global under their own names
+    let mut aliases = Vec::new();
+    if export_count.is_some() {
+      for (source, uniq) in funs {
+        let alias = format!("__snake_{}.{}", name, source);
+        if let Some(i) = instrs.iter().position(|i| *i == Instr::Label(uniq.clone())) {
+          instrs.insert(i, Instr::Label(alias.clone()));
+          aliases.push(alias);
+        }
+      }
+      aliases.sort();
+    }
+
+    let mut assembly = link::header(&units[unit], &link::own_types(name, &type_symbols));
+    if let Some(n) = export_count {
+      let exports = link::exports_symbol(name);
+      assembly.push_str(&format!(
+        "        section .data\n        global {}\n        align 8\n{}:\n        dq {}\n",
+        exports, exports, n
+      ));
+      if n > 0 {
+        assembly.push_str(&format!("        times {} dq 0\n", n));
+      }
+    }
+    let mut externs = link::imported_exports(prog, units, unit);
+    externs.extend(type_symbols.iter().cloned());
+    externs.push(HANDLER_SYMBOL.to_string());
+    externs.push(STACK_BASE_SYMBOL.to_string());
+    externs.push(GENERATOR_SYMBOL.to_string());
+    externs.sort();
+    externs.dedup();
+    assembly.push_str(&format!(
+      "        section .text\n        extern snake_error\n        extern print_snake_val\n        extern snake_new_stack\n        extern snake_free_stack\n        extern snake_spawn\n        extern snake_chan\n        extern snake_switch\n        extern snake_thread\n{}        global {}\n{}{}",
+      externs
+        .iter()
+        .map(|symbol| format!("        extern {}\n", symbol))
+        .collect::<String>(),
+      entry,
+      aliases
+        .iter()
+        .map(|alias| format!("        global {}\n", alias))
+        .collect::<String>(),
+      instrs_to_string(&instrs)
+    ));
+    assembly
+  }
+
+  // The code that starts a program made of the compiled `units`, which
+  // defines the symbols of the custom `types` the units export
+  pub fn link_units(&self, units: &[Unit], types: &[String]) -> String {
+    link::link_unit(units, types, self.heap_size)
+  }
+}
+
+pub type ParseError = lalrpop_util::ParseError<usize, Tok, LexError>;
+
+pub fn parse_prog(source: &str) -> Result<SurfProg<Span1>, ParseError> {
+  parse_file(source, MAIN_FILE)
+}
+
+// Parses one of the files of a program, whose spans point into `file`
+pub fn parse_file(source: &str, file: FileId) -> Result<SurfProg<Span1>, ParseError> {
+  ProgParser::new().parse(file, Lexer::new(source, file))
+}
+
+// Where in the source of `file` a parse error happened
+pub(crate) fn parse_error_location(e: &ParseError, file: FileId) -> Span1 {
+  match e {
+    lalrpop_util::ParseError::InvalidToken { location }
+    | lalrpop_util::ParseError::UnrecognizedEOF { location, .. } => Span1 {
+      file,
+      start_ix: *location,
+      end_ix: *location + 1,
+    },
+    lalrpop_util::ParseError::UnrecognizedToken {
+      token: (l, _, r), ..
+    }
+    | lalrpop_util::ParseError::ExtraToken { token: (l, _, r) } => Span1 {
+      file,
+      start_ix: *l,
+      end_ix: *r,
+    },
+    lalrpop_util::ParseError::User { error } => error.location(),
+  }
+}
+
+// The program with every name made unique. Each binding's new name is
+// built from the tag of the expression or def that binds it.
+pub(crate) fn uniquify_prog<Ann>(prog: &SurfProg<Ann>) -> Exp<()> {
+  uniquify(&tag_exp(prog))
+}
+
+// Gives the variables of a checked program unique names and resolves its
+// custom types to type tags, as the compiler does before lifting lambdas.
+// Also returns the names of the types, indexed by tag.
+pub(crate) fn resolve_prog_types<Ann>(prog: &SurfProg<Ann>) -> (Exp<()>, Vec<String>) {
+  let uniq_prog = uniquify_prog(prog);
+  let (resolved, custom_types) = resolve_types(&tag_exp(&uniq_prog));
+  (resolved, type_names(&custom_types))
+}
+
+pub fn compile_to_string<Span>(prog: &SurfProg<Span>) -> Result<String, CompileErr<Span>>
+where
+  Span: Clone,
+{
+  Ok(Compiler::new().compile_prog(prog)?.assembly)
 }
@@ -0,0 +1,576 @@
+use crate::interp::{sprint_snake_val, Config, Event, Heap, InterpErr, Machine, SnakeVal, Stack};
+use crate::pipeline_functions::print_prog::print_prog;
+use crate::replay::Recorder;
+use crate::span::{file_info, FileInfo, Span1, MAIN_FILE};
+use crate::syntax::{Exp, SurfProg};
+
+use std::collections::BTreeSet;
+use std::io::{BufRead, Write};
+
+static HELP_MSG: &str = "\
+Commands:
+
+    break LINE, b LINE     stop whenever execution reaches LINE
+    delete LINE, d LINE    remove the breakpoint on LINE
+    step, s                run to the next line, entering function calls
+    next, n                run to the next line, stepping over function calls
+    continue, c            run to the next breakpoint
+    reverse-step, rs       go back to the previous line
+    reverse-continue, rc   go back to the previous breakpoint
+    last-change ARRAY INDEX, lc ARRAY INDEX
+                           go back to the line that last changed ARRAY[INDEX],
+                           where ARRAY is a variable in scope
+    env, e                 print the variables in scope
+    stack, k               print the continuation, innermost frame first
+    where, w               print the current line
+    help, h                print this message
+    quit, q                stop debugging
+";
+
+// When to stop running the program
+#[derive(Clone, Copy)]
+enum Until {
+  // at the next line reached
+  Step,
+  // at the next line reached with a continuation no deeper than this
+  Next(usize),
+  // at the next breakpoint
+  Breakpoint,
+}
+
+struct Debugger<'exp> {
+  recorder: Recorder<'exp, Span1>,
+  info: FileInfo,
+  lines: Vec<&'exp str>,
+  breakpoints: BTreeSet<usize>,
+  // the line of the expression the machine last descended into
+  line: usize,
+  // the step at which each line was reached, in the order they were reached
+  stops: Vec<(u64, usize)>,
+}
+
+fn write_err(e: std::io::Error) -> InterpErr {
+  InterpErr::Write { msg: e.to_string() }
+}
+
+// Runs `prog` under the debugger, reading commands from `input`. The
+// debugger's output and the program's own output both go to `out`.
+//
+// If `record` is set, the debugger records the program's execution and can
+// go back to any point it has already run, even after it has finished.
+pub fn debug<R, W>(
+  prog: &SurfProg<Span1>,
+  source: &str,
+  config: &Config,
+  record: bool,
+  input: R,
+  out: &mut W,
+) -> Result<(), InterpErr>
+where
+  R: BufRead,
+  W: Write,
+{
+  let info = file_info(source);
+  let line = info.line_of(prog.ann().start_ix);
+  let mut dbg = Debugger {
+    recorder: Recorder::new(prog, config, record),
+    line,
+    info,
+    lines: source.lines().collect(),
+    breakpoints: BTreeSet::new(),
+    stops: vec![(0, line)],
+  };
+  dbg.show_location(out).map_err(write_err)?;
+
+  let mut commands = input.lines();
+  loop {
+    write!(out, "(sdb) ").map_err(write_err)?;
+    out.flush().map_err(write_err)?;
+    let command = match commands.next() {
+      None => return Ok(()),
+      Some(command) => command.map_err(write_err)?,
+    };
+    let words: Vec<&str> = command.split_whitespace().collect();
+    let until = match words.as_slice() {
+      [] => continue,
+      ["break" | "b", line] | ["delete" | "d", line] => {
+        match line.parse::<usize>() {
+          Ok(line) if words[0].starts_with('b') => {
+            dbg.breakpoints.insert(line);
+            writeln!(out, "Breakpoint at line {}", line)
+          }
+          Ok(line) => {
+            dbg.breakpoints.remove(&line);
+            writeln!(out, "Deleted breakpoint at line {}", line)
+          }
+          Err(_) => writeln!(out, "Expected a line number, got {}", line),
+        }
+        .map_err(write_err)?;
+        continue;
+      }
+      ["step" | "s"] => Until::Step,
+      ["next" | "n"] => Until::Next(dbg.depth()),
+      ["continue" | "c"] => Until::Breakpoint,
+      ["reverse-step" | "rs"] | ["reverse-continue" | "rc"] | ["last-change" | "lc", _, _]
+        if !dbg.recorder.is_recording() =>
+      {
+        writeln!(out, "Start the debugger with --record to go back in time").map_err(write_err)?;
+        continue;
+      }
+      ["reverse-step" | "rs"] => {
+        dbg.reverse(false, out)?;
+        continue;
+      }
+      ["reverse-continue" | "rc"] => {
+        dbg.reverse(true, out)?;
+        continue;
+      }
+      ["last-change" | "lc", array, index] => {
+        dbg.last_change(array, index, out)?;
+        continue;
+      }
+      ["env" | "e"] => {
+        dbg.show_env(out).map_err(write_err)?;
+        continue;
+      }
+      ["stack" | "k"] => {
+        dbg.show_stack(out).map_err(write_err)?;
+        continue;
+      }
+      ["where" | "w"] => {
+        dbg.show_location(out).map_err(write_err)?;
+        continue;
+      }
+      ["help" | "h"] => {
+        write!(out, "{}", HELP_MSG).map_err(write_err)?;
+        continue;
+      }
+      ["quit" | "q"] => return Ok(()),
+      _ => {
+        writeln!(out, "Unknown command {}. Type help for a list of commands", command.trim())
+          .map_err(write_err)?;
+        continue;
+      }
+    };
+
+    if dbg.recorder.session().is_done() {
+      writeln!(out, "The program has finished").map_err(write_err)?;
+      continue;
+    }
+    // the program prints its own result when it finishes
+    if dbg.run(until, out)? {
+      writeln!(out, "Program finished").map_err(write_err)?;
+      // a recorded program can still be gone back over
+      if !dbg.recorder.is_recording() {
+        return Ok(());
+      }
+      continue;
+    }
+    dbg.show_location(out).map_err(write_err)?;
+  }
+}
+
+impl<'exp> Debugger<'exp> {
+  fn depth(&self) -> usize {
+    match &self.recorder.session().machine {
+      Some(Machine::Descending { stk, .. }) | Some(Machine::Returning { stk, .. }) => stk.depth(),
+      None => 0,
+    }
+  }
+
+  // Runs until `until` says to stop or a breakpoint is hit, returning
+  // whether the program finished
+  fn run<W>(&mut self, until: Until, out: &mut W) -> Result<bool, InterpErr>
+  where
+    W: Write,
+  {
+    let mut called = false;
+    loop {
+      match self.recorder.step(out)? {
+        Event::Done => return Ok(true),
+        Event::Call { .. } => called = true,
+        Event::Step | Event::ArraySet { .. } => {}
+      }
+      let session = self.recorder.session();
+      if let Some(Machine::Descending { e, stk, .. }) = &session.machine {
+        // lines are only those of the main file
+        if e.ann().file != MAIN_FILE {
+          continue;
+        }
+        let line = self.info.line_of(e.ann().start_ix);
+        // stop only when reaching a line anew, not on every expression in it
+        let new_line = called || line != self.line;
+        self.line = line;
+        called = false;
+        if new_line {
+          if self.stops.last().is_some_and(|(step, _)| *step < session.steps) {
+            self.stops.push((session.steps, line));
+          }
+          let stop = self.breakpoints.contains(&line)
+            || match until {
+              Until::Step => true,
+              Until::Next(depth) => stk.depth() <= depth,
+              Until::Breakpoint => false,
+            };
+          if stop {
+            return Ok(false);
+          }
+        }
+      }
+    }
+  }
+
+  // Goes back to the previous line reached, or the previous breakpoint
+  fn reverse<W>(&mut self, to_breakpoint: bool, out: &mut W) -> Result<(), InterpErr>
+  where
+    W: Write,
+  {
+    let now = self.recorder.session().steps;
+    let stop = self
+      .stops
+      .iter()
+      .rev()
+      .find(|(step, line)| *step < now && (!to_breakpoint || self.breakpoints.contains(line)));
+    match stop {
+      Some(&(step, _)) => self.rewind(step)?,
+      None if now > 0 => self.rewind(0)?,
+      None => writeln!(out, "Already at the start of the program").map_err(write_err)?,
+    }
+    self.show_location(out).map_err(write_err)
+  }
+
+  fn last_change<W>(&mut self, array: &str, index: &str, out: &mut W) -> Result<(), InterpErr>
+  where
+    W: Write,
+  {
+    let session = self.recorder.session();
+    let env = match &session.machine {
+      Some(Machine::Descending { env, .. }) => env,
+      _ => return writeln!(out, "No variables in scope").map_err(write_err),
+    };
+    let ptr = match env.bindings(session.store.heap()).into_iter().find(|(x, _)| *x == array) {
+      Some((_, SnakeVal::Array(ptr))) => ptr,
+      Some((_, v)) => {
+        let v = sprint_snake_val(v, session.store.heap());
+        return writeln!(out, "{} is {}, not an array", array, v).map_err(write_err);
+      }
+      None => return writeln!(out, "No variable {} in scope", array).map_err(write_err),
+    };
+    let i = match index.parse::<usize>() {
+      Ok(i) => i,
+      Err(_) => return writeln!(out, "Expected an index, got {}", index).map_err(write_err),
+    };
+    let now = session.steps;
+    let change = self
+      .recorder
+      .mutations_of(ptr, i)
+      .filter(|m| m.step <= now)
+      .last()
+      .copied();
+    match change {
+      None => writeln!(out, "{}[{}] hasn't changed", array, i).map_err(write_err),
+      Some(m) => {
+        // show the values as they were right after the change
+        self.recorder.rewind(m.step)?;
+        let heap = self.recorder.session().store.heap();
+        writeln!(
+          out,
+          "{}[{}] changes from {} to {}",
+          array,
+          i,
+          sprint_snake_val(m.old, heap),
+          sprint_snake_val(m.new, heap)
+        )
+        .map_err(write_err)?;
+        // then stop on the line that made it
+        let (step, _) = *self
+          .stops
+          .iter()
+          .rev()
+          .find(|(step, _)| *step < m.step)
+          .expect("the start of the program comes before every change");
+        self.rewind(step)?;
+        self.show_location(out).map_err(write_err)
+      }
+    }
+  }
+
+  // Goes back to `step`, on the line reached last before it
+  fn rewind(&mut self, step: u64) -> Result<(), InterpErr> {
+    self.recorder.rewind(step)?;
+    self.line = self
+      .stops
+      .iter()
+      .rev()
+      .find(|(s, _)| *s <= step)
+      .map_or(self.line, |(_, line)| *line);
+    Ok(())
+  }
+
+  fn show_location<W>(&self, out: &mut W) -> std::io::Result<()>
+  where
+    W: Write,
+  {
+    let text = self.lines.get(self.line - 1).map_or("", |l| l.trim());
+    let marker = if self.breakpoints.contains(&self.line) {
+      "*"
+    } else {
+      " "
+    };
+    writeln!(out, "{}{:>4} | {}", marker, self.line, text)
+  }
+
+  fn show_env<W>(&self, out: &mut W) -> std::io::Result<()>
+  where
+    W: Write,
+  {
+    let session = self.recorder.session();
+    let heap = session.store.heap();
+    match &session.machine {
+      Some(Machine::Descending { env, .. }) => {
+        for (x, v) in env.bindings(heap) {
+          // leave out the functions of the prelude and the imported files,
+          // and the types they declare
+          if session.store.is_export(x, v) {
+            continue;
+          }
+          if let SnakeVal::Closure(fun) = v {
+            if let Some(body) = session.store.fun_body(fun) {
+              if body.ann().file != MAIN_FILE {
+                continue;
+              }
+            }
+          }
+          writeln!(out, "{} = {}", x, sprint_snake_val(v, heap))?;
+        }
+      }
+      _ => writeln!(out, "No variables in scope")?,
+    }
+    Ok(())
+  }
+
+  fn show_stack<W>(&self, out: &mut W) -> std::io::Result<()>
+  where
+    W: Write,
+  {
+    let session = self.recorder.session();
+    let heap = session.store.heap();
+    let mut stk = match &session.machine {
+      Some(Machine::Descending { stk, .. }) | Some(Machine::Returning { stk, .. }) => stk,
+      None => return Ok(()),
+    };
+    if let Stack::Done = stk {
+      return writeln!(out, "The continuation is empty");
+    }
+    let mut i = 0;
+    while let Some(frame) = describe_frame(stk, heap) {
+      writeln!(out, "#{:<3} {}", i, frame)?;
+      i += 1;
+      stk = stk.parent().expect("only the last frame has no parent");
+    }
+    Ok(())
+  }
+}
+
+// Shows a frame of the continuation as the expression it is waiting to
+// finish, with `_` marking the hole
+fn describe_frame(stk: &Stack<Span1>, heap: &Heap) -> Option<String> {
+  let hole = || Box::new(Exp::Var(String::from("_"), ()));
+  let val = |v: &SnakeVal| Exp::Var(sprint_snake_val(*v, heap), ());
+  let exp = |e: &Exp<Span1>| e.map_ann(&mut |_| ());
+  let frame: Exp<()> = match stk {
+    // the backtrace of a generator or thread ends at its bottom
+    Stack::Done | Stack::Generator { .. } | Stack::Thread => return None,
+    Stack::Prim1(op, _) => Exp::Prim1(*op, hole(), ()),
+    Stack::Prim2L(op, r, _) => Exp::Prim2(*op, hole(), Box::new(exp(r.exp)), ()),
+    Stack::Prim2R(op, l, _) => Exp::Prim2(*op, Box::new(val(l)), hole(), ()),
+    Stack::If { thn, els, .. } => Exp::If {
+      cond: hole(),
+      thn: Box::new(exp(thn)),
+      els: Box::new(exp(els)),
+      ann: (),
+    },
+    Stack::Let {
+      var,
+      bindings,
+      body,
+      ..
+    } => Exp::Let {
+      bindings: std::iter::once((var.to_string(), *hole()))
+        .chain(bindings.iter().rev().map(|(x, e)| (x.clone(), exp(e))))
+        .collect(),
+      body: Box::new(exp(body)),
+      ann: (),
+    },
+    Stack::LetVar {
+      var,
+      bindings,
+      body,
+      ..
+    } => Exp::LetVar {
+      bindings: std::iter::once((var.to_string(), *hole()))
+        .chain(bindings.iter().rev().map(|(x, e)| (x.clone(), exp(e))))
+        .collect(),
+      body: Box::new(exp(body)),
+      ann: (),
+    },
+    Stack::Assign { var, .. } => Exp::Assign {
+      var: var.to_string(),
+      new_value: hole(),
+      ann: (),
+    },
+    Stack::CallFun { args, .. } => Exp::Call(hole(), args.iter().map(|e| exp(e)).collect(), ()),
+    Stack::CallArgs {
+      fun,
+      evaled_args,
+      remaining_args,
+      ..
+    } => Exp::Call(
+      Box::new(val(fun)),
+      evaled_args
+        .iter()
+        .map(val)
+        .chain(std::iter::once(*hole()))
+        .chain(remaining_args.iter().rev().map(|e| exp(e)))
+        .collect(),
+      (),
+    ),
+    Stack::Array {
+      evaled_parts,
+      remaining_parts,
+      ..
+    } => Exp::Array(
+      evaled_parts
+        .iter()
+        .map(val)
+        .chain(std::iter::once(*hole()))
+        .chain(remaining_parts.iter().rev().map(|e| exp(e)))
+        .collect(),
+      (),
+    ),
+    Stack::ArraySet1 {
+      index, new_value, ..
+    } => Exp::ArraySet {
+      array: hole(),
+      index: Box::new(exp(index)),
+      new_value: Box::new(exp(new_value)),
+      ann: (),
+    },
+    Stack::ArraySet2 {
+      array, new_value, ..
+    } => Exp::ArraySet {
+      array: Box::new(val(array)),
+      index: hole(),
+      new_value: Box::new(exp(new_value.exp)),
+      ann: (),
+    },
+    Stack::ArraySet3 { array, index, .. } => Exp::ArraySet {
+      array: Box::new(val(array)),
+      index: Box::new(val(index)),
+      new_value: hole(),
+      ann: (),
+    },
+    Stack::Semicolon { next, .. } => Exp::Semicolon {
+      e1: hole(),
+      e2: Box::new(exp(next.exp)),
+      ann: (),
+    },
+    Stack::WhileCond { body, .. } => Exp::While {
+      cond: hole(),
+      body: Box::new(exp(body)),
+      ann: (),
+    },
+    Stack::WhileBody { cond, .. } => Exp::While {
+      cond: Box::new(exp(cond)),
+      body: hole(),
+      ann: (),
+    },
+    Stack::ForStart { var, end, body, .. } => Exp::For {
+      var: var.to_string(),
+      start: hole(),
+      end: Box::new(exp(end)),
+      body: Box::new(exp(body)),
+      ann: (),
+    },
+    Stack::ForEnd {
+      var, start, body, ..
+    } => Exp::For {
+      var: var.to_string(),
+      start: Box::new(val(start)),
+      end: hole(),
+      body: Box::new(exp(body)),
+      ann: (),
+    },
+    Stack::ForBody { var, i, end, .. } => Exp::For {
+      var: var.to_string(),
+      start: Box::new(Exp::Num(*i, ())),
+      end: Box::new(Exp::Num(*end, ())),
+      body: hole(),
+      ann: (),
+    },
+    Stack::MakeTypeInstance { typetag, .. } => Exp::MakeTypeInstance {
+      typetag: *typetag,
+      fields: hole(),
+      ann: (),
+    },
+    Stack::MatchType { typetag, .. } => Exp::MatchType {
+      expr: hole(),
+      typetag: *typetag,
+      ann: (),
+    },
+    Stack::GetTypeFields(_) => Exp::GetTypeFields(hole(), ()),
+    Stack::Match { default, arms, .. } => Exp::Match {
+      expr: hole(),
+      default: Box::new(exp(default)),
+      arms: arms
+        .iter()
+        .map(|(snake_type, args, e)| (snake_type.clone(), args.clone(), exp(e)))
+        .collect(),
+      ann: (),
+    },
+    Stack::GetField { field, .. } => Exp::GetField {
+      exp: hole(),
+      field: field.to_string(),
+      ann: (),
+    },
+    Stack::UpdateField1 {
+      field, new_value, ..
+    } => Exp::UpdateField {
+      exp: hole(),
+      field: field.to_string(),
+      new_value: Box::new(exp(new_value.exp)),
+      ann: (),
+    },
+    Stack::UpdateField2 { field, v, .. } => Exp::UpdateField {
+      exp: Box::new(val(v)),
+      field: field.to_string(),
+      new_value: hole(),
+      ann: (),
+    },
+    Stack::Try { try_exp, .. } => match try_exp {
+      Exp::Try { arms, .. } => Exp::Try {
+        body: hole(),
+        arms: arms
+          .iter()
+          .map(|(snake_type, args, e)| (snake_type.clone(), args.clone(), exp(e)))
+          .collect(),
+        ann: (),
+      },
+      Exp::Catch { var, handler, .. } => Exp::Catch {
+        body: hole(),
+        var: var.clone(),
+        handler: Box::new(exp(handler)),
+        ann: (),
+      },
+      _ => unreachable!("only a `try` handles raised values"),
+    },
+    Stack::Raise(_) => Exp::Raise(hole(), ()),
+  };
+  // keep each frame on one line
+  Some(
+    print_prog(&frame, 0)
+      .split_whitespace()
+      .collect::<Vec<&str>>()
+      .join(" "),
+  )
+}
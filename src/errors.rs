@@ -1,3 +1,6 @@
+use crate::interp::InterpErr;
+use std::fmt::Display;
+
 #[derive(Debug, PartialEq, Eq)]
 pub enum CompileErr<Span> {
   UnboundVariable {
@@ -61,4 +64,378 @@ pub enum CompileErr<Span> {
     primitive_type: String,
     location: Span,
   },
+
+  // a type declared with two fields of the same name
+  DuplicateFieldName {
+    field: String,
+    location: Span,
+  },
+
+  // `e.field` or `e with field = v` where no type in scope has `field`
+  UndefinedField {
+    field: String,
+    location: Span,
+  },
+
+  // an arm binding a field by name that its type doesn't have
+  FieldNotInType {
+    field: String,
+    location: Span,
+  },
+  // an arm binding fields by name of a type declared without field names,
+  // like the primitive and builtin error types
+  NoFieldNames {
+    type_used: String,
+    location: Span,
+  },
+
+  // `x := e` where `x` isn't bound by `var`
+  AssignToImmutable {
+    name: String,
+    location: Span,
+  },
+
+  // `yield` outside of the body of a function or lambda
+  YieldOutsideFunction {
+    location: Span,
+  },
+
+  // no file was found at the imported path
+  UnknownModule {
+    path: String,
+    location: Span,
+  },
+
+  // the imported file imports, maybe indirectly, the file importing it
+  ImportCycle {
+    path: String,
+    location: Span,
+  },
+}
+
+impl<Span> CompileErr<Span> {
+  pub fn location(&self) -> &Span {
+    match self {
+      CompileErr::UnboundVariable { location, .. }
+      | CompileErr::DuplicateBinding { location, .. }
+      | CompileErr::Overflow { location, .. }
+      | CompileErr::DuplicateFunName { location, .. }
+      | CompileErr::DuplicateArgName { location, .. }
+      | CompileErr::UndefinedType { location, .. }
+      | CompileErr::WrongTypeArity { location, .. }
+      | CompileErr::DuplicateTypeDefs { location, .. }
+      | CompileErr::DuplicateMatchArms { location, .. }
+      | CompileErr::DuplicateMatchArmArguments { location, .. }
+      | CompileErr::WrongTypeCall { location, .. }
+      | CompileErr::ShadowPrimType { location, .. }
+      | CompileErr::DuplicateFieldName { location, .. }
+      | CompileErr::UndefinedField { location, .. }
+      | CompileErr::FieldNotInType { location, .. }
+      | CompileErr::NoFieldNames { location, .. }
+      | CompileErr::AssignToImmutable { location, .. }
+      | CompileErr::YieldOutsideFunction { location }
+      | CompileErr::UnknownModule { location, .. }
+      | CompileErr::ImportCycle { location, .. } => location,
+    }
+  }
+
+  pub fn map_span<F, SpanPrime>(self, f: F) -> CompileErr<SpanPrime>
+  where
+    F: FnOnce(&Span) -> SpanPrime,
+  {
+    match self {
+      CompileErr::UnboundVariable { unbound, location } => CompileErr::UnboundVariable {
+        unbound,
+        location: f(&location),
+      },
+      CompileErr::DuplicateBinding {
+        duplicated_name,
+        location,
+      } => CompileErr::DuplicateBinding {
+        duplicated_name,
+        location: f(&location),
+      },
+      CompileErr::Overflow { num, location } => CompileErr::Overflow {
+        num,
+        location: f(&location),
+      },
+      CompileErr::DuplicateArgName {
+        duplicated_name,
+        location,
+      } => CompileErr::DuplicateArgName {
+        location: f(&location),
+        duplicated_name,
+      },
+
+      CompileErr::DuplicateFunName {
+        duplicated_name,
+        location,
+      } => CompileErr::DuplicateFunName {
+        duplicated_name,
+        location: f(&location),
+      },
+
+      CompileErr::UndefinedType {
+        undefined_type,
+        location,
+      } => CompileErr::UndefinedType {
+        undefined_type,
+        location: f(&location),
+      },
+      CompileErr::WrongTypeArity {
+        type_used,
+        expected_arity,
+        given_arity,
+        location,
+      } => CompileErr::WrongTypeArity {
+        type_used,
+        expected_arity,
+        given_arity,
+        location: f(&location),
+      },
+      CompileErr::DuplicateTypeDefs {
+        duplicate_type,
+        location,
+      } => CompileErr::DuplicateTypeDefs {
+        duplicate_type,
+        location: f(&location),
+      },
+      CompileErr::DuplicateMatchArms {
+        type_used,
+        location,
+      } => CompileErr::DuplicateMatchArms {
+        type_used,
+        location: f(&location),
+      },
+      CompileErr::DuplicateMatchArmArguments {
+        type_used,
+        location,
+      } => CompileErr::DuplicateMatchArmArguments {
+        type_used,
+        location: f(&location),
+      },
+      CompileErr::WrongTypeCall {
+        type_used,
+        location,
+      } => CompileErr::WrongTypeCall {
+        type_used,
+        location: f(&location),
+      },
+      CompileErr::ShadowPrimType {
+        primitive_type,
+        location,
+      } => CompileErr::ShadowPrimType {
+        primitive_type,
+        location: f(&location),
+      },
+      CompileErr::DuplicateFieldName { field, location } => CompileErr::DuplicateFieldName {
+        field,
+        location: f(&location),
+      },
+      CompileErr::UndefinedField { field, location } => CompileErr::UndefinedField {
+        field,
+        location: f(&location),
+      },
+      CompileErr::FieldNotInType { field, location } => CompileErr::FieldNotInType {
+        field,
+        location: f(&location),
+      },
+      CompileErr::NoFieldNames {
+        type_used,
+        location,
+      } => CompileErr::NoFieldNames {
+        type_used,
+        location: f(&location),
+      },
+      CompileErr::AssignToImmutable { name, location } => CompileErr::AssignToImmutable {
+        name,
+        location: f(&location),
+      },
+      CompileErr::YieldOutsideFunction { location } => CompileErr::YieldOutsideFunction {
+        location: f(&location),
+      },
+      CompileErr::UnknownModule { path, location } => CompileErr::UnknownModule {
+        path,
+        location: f(&location),
+      },
+      CompileErr::ImportCycle { path, location } => CompileErr::ImportCycle {
+        path,
+        location: f(&location),
+      },
+    }
+  }
+}
+
+#[derive(Debug)]
+pub enum RunnerErr<Span> {
+  FileOpen(String),
+  Parse {
+    msg: String,
+    location: Option<Span>,
+  },
+  CodeGen(CompileErr<Span>),
+  Link(String),
+  Interp(InterpErr),
+  Run(String),
+  Timeout(std::time::Duration),
+}
+
+impl<Span> Display for CompileErr<Span>
+where
+  Span: Display,
+{
+  fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    match self {
+      CompileErr::UnboundVariable { unbound, location } => {
+        write!(f, "Unbound variable {} at {}", unbound, location)
+      }
+      CompileErr::DuplicateBinding {
+        duplicated_name,
+        location,
+      } => write!(
+        f,
+        "Variable {} defined twice in let-expression at {}",
+        duplicated_name, location
+      ),
+
+      CompileErr::Overflow { num, location } => write!(
+        f,
+        "Number literal {} doesn't fit into 63-bit integer at {}",
+        num, location
+      ),
+
+      CompileErr::DuplicateArgName {
+        duplicated_name,
+        location,
+      } => write!(
+        f,
+        "Multiple arguments named \"{}\" at {}",
+        duplicated_name, location,
+      ),
+
+      CompileErr::DuplicateFunName {
+        duplicated_name,
+        location,
+      } => write!(
+        f,
+        "Multiple defined functions named \"{}\" at {}",
+        duplicated_name, location
+      ),
+
+      CompileErr::UndefinedType {
+        undefined_type,
+        location,
+      } => write!(
+        f,
+        "Use of undefined type {} at {}",
+        undefined_type, location
+      ),
+      CompileErr::WrongTypeArity {
+        type_used,
+        expected_arity,
+        given_arity,
+        location,
+      } => write!(
+        f,
+        "Type {} expected arguments of size {} but received {} at {}",
+        type_used, expected_arity, given_arity, location
+      ),
+      CompileErr::DuplicateTypeDefs {
+        duplicate_type,
+        location,
+      } => write!(
+        f,
+        "Custom type {} defined repeatedly at {}",
+        duplicate_type, location
+      ),
+      CompileErr::DuplicateMatchArms {
+        type_used,
+        location,
+      } => write!(
+        f,
+        "Type {} used repeatedly in one match expression at {}",
+        type_used, location
+      ),
+      CompileErr::DuplicateMatchArmArguments {
+        type_used,
+        location,
+      } => write!(
+        f,
+        "Duplicate arguments in match arm {} at {}",
+        type_used, location
+      ),
+      CompileErr::WrongTypeCall {
+        type_used,
+        location,
+      } => write!(
+        f,
+        "Type constructor for type {} was incorrectly called at {}",
+        type_used, location
+      ),
+      CompileErr::ShadowPrimType {
+        primitive_type,
+        location,
+      } => write!(
+        f,
+        "Attempting to shadow primitive type {} at {}",
+        primitive_type, location
+      ),
+      CompileErr::DuplicateFieldName { field, location } => {
+        write!(f, "Field {} declared repeatedly in one type at {}", field, location)
+      }
+      CompileErr::UndefinedField { field, location } => {
+        write!(f, "No type in scope has a field named {} at {}", field, location)
+      }
+      CompileErr::FieldNotInType { field, location } => write!(
+        f,
+        "Match arm binds field {} that its type doesn't have at {}",
+        field, location
+      ),
+      CompileErr::NoFieldNames {
+        type_used,
+        location,
+      } => write!(f, "Type {} has no field names to match by at {}", type_used, location),
+      CompileErr::AssignToImmutable { name, location } => write!(
+        f,
+        "Cannot assign to {}, which isn't declared with var, at {}",
+        name, location
+      ),
+      CompileErr::YieldOutsideFunction { location } => {
+        write!(f, "yield outside of a function at {}", location)
+      }
+      CompileErr::UnknownModule { path, location } => {
+        write!(f, "Cannot find the imported file \"{}\" at {}", path, location)
+      }
+      CompileErr::ImportCycle { path, location } => write!(
+        f,
+        "Importing \"{}\" at {} leads back to the importing file",
+        path, location
+      ),
+    }
+  }
+}
+
+impl<Span> Display for RunnerErr<Span>
+where
+  Span: Display,
+{
+  fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    match self {
+      RunnerErr::FileOpen(s) => write!(f, "Error reading file: {}", s),
+      RunnerErr::Parse {
+        msg,
+        location: Some(location),
+      } => write!(f, "Error parsing input: {} at {}", msg, location),
+      RunnerErr::Parse { msg, location: None } => write!(f, "Error parsing input: {}", msg),
+      RunnerErr::CodeGen(ce) => write!(f, "Error generating assembly: {}", ce),
+      RunnerErr::Link(s) => write!(f, "Error linking generated assembly with runtime: {}", s),
+      RunnerErr::Interp(s) => write!(f, "Error in interpreter: {}", s),
+      RunnerErr::Run(s) => write!(f, "Error running your compiled output: {}", s),
+      RunnerErr::Timeout(t) => write!(
+        f,
+        "Error running your compiled output: timed out after {}s",
+        t.as_secs_f64()
+      ),
+    }
+  }
 }
@@ -0,0 +1,715 @@
+use crate::lexer::{self, Comment, Tok, Token};
+use crate::span::Span1;
+use crate::syntax::{Exp, FunDecl, Prim0, Prim1, Prim2, SnakeType, SurfProg};
+
+// The line width the formatter aims for
+pub const DEFAULT_WIDTH: usize = 80;
+
+// A layout that can be printed flat on one line or broken over several,
+// after Wadler's "A prettier printer"
+enum Doc {
+  Text(String),
+  // a space, or a newline if its group doesn't fit on the line
+  Line,
+  // nothing, or a newline if its group doesn't fit on the line
+  SoftLine,
+  // always a newline, which breaks every group around it
+  HardLine,
+  Concat(Vec<Doc>),
+  Nest(usize, Box<Doc>),
+  // printed flat if it fits in the rest of the line, and broken otherwise
+  Group(Box<Doc>),
+}
+
+fn text<S>(s: S) -> Doc
+where
+  S: Into<String>,
+{
+  Doc::Text(s.into())
+}
+
+fn concat(docs: Vec<Doc>) -> Doc {
+  Doc::Concat(docs)
+}
+
+fn nest(indent: usize, doc: Doc) -> Doc {
+  Doc::Nest(indent, Box::new(doc))
+}
+
+fn group(doc: Doc) -> Doc {
+  Doc::Group(Box::new(doc))
+}
+
+fn join<F>(docs: Vec<Doc>, sep: F) -> Doc
+where
+  F: Fn() -> Doc,
+{
+  let mut joined = vec![];
+  for (i, doc) in docs.into_iter().enumerate() {
+    if i > 0 {
+      joined.push(sep());
+    }
+    joined.push(doc);
+  }
+  concat(joined)
+}
+
+// Whether `doc` fits in `width` columns when printed flat, together with
+// whatever follows it up to the next line break
+fn fits(mut width: isize, doc: &Doc, rest: &[(usize, bool, &Doc)]) -> bool {
+  let mut stack = vec![(true, doc)];
+  let mut rest = rest.iter().rev();
+  loop {
+    let (flat, doc) = match stack.pop() {
+      Some(next) => next,
+      None => match rest.next() {
+        Some((_, flat, doc)) => (*flat, *doc),
+        None => return true,
+      },
+    };
+    match doc {
+      Doc::Text(s) => {
+        width -= s.len() as isize;
+        if width < 0 {
+          return false;
+        }
+      }
+      Doc::Line | Doc::SoftLine | Doc::HardLine if !flat => return true,
+      Doc::HardLine => return false,
+      Doc::Line => {
+        width -= 1;
+        if width < 0 {
+          return false;
+        }
+      }
+      Doc::SoftLine => {}
+      Doc::Concat(docs) => stack.extend(docs.iter().rev().map(|doc| (flat, doc))),
+      Doc::Nest(_, doc) | Doc::Group(doc) => stack.push((flat, doc)),
+    }
+  }
+}
+
+fn render(doc: &Doc, width: usize) -> String {
+  let mut out = String::new();
+  let mut column = 0;
+  // (indentation, whether flat, doc) for what's left to print, last first
+  let mut stack = vec![(0, false, doc)];
+  while let Some((indent, flat, doc)) = stack.pop() {
+    match doc {
+      Doc::Text(s) => {
+        out.push_str(s);
+        column += s.len();
+      }
+      Doc::Line if flat => {
+        out.push(' ');
+        column += 1;
+      }
+      Doc::SoftLine if flat => {}
+      Doc::Line | Doc::SoftLine | Doc::HardLine => {
+        out.push('\n');
+        out.push_str(&" ".repeat(indent));
+        column = indent;
+      }
+      Doc::Concat(docs) => stack.extend(docs.iter().rev().map(|doc| (indent, flat, doc))),
+      Doc::Nest(more, doc) => stack.push((indent + more, flat, doc)),
+      Doc::Group(doc) => {
+        let flat = flat || fits(width as isize - column as isize, doc, &stack);
+        stack.push((indent, flat, doc));
+      }
+    }
+  }
+  out
+}
+
+// How tightly an expression binds, following the grammar's nonterminals
+// from Exp down to BaseExp. A subexpression is parenthesized when it binds
+// less tightly than the grammar allows where it appears.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+enum Level {
+  Exp,
+  Assign,
+  Update,
+  Log,
+  Cmp,
+  Sum,
+  Product,
+  Not,
+  Arr,
+  Base,
+}
+
+fn level<Ann>(e: &Exp<Ann>) -> Level {
+  match e {
+    Exp::If { .. }
+    | Exp::Let { .. }
+    | Exp::LetVar { .. }
+    | Exp::FunDefs { .. }
+    | Exp::TypeDefs { .. }
+    | Exp::Import { .. }
+    | Exp::Match { .. }
+    | Exp::Try { .. }
+    | Exp::Semicolon { .. } => Level::Exp,
+    Exp::Prim2(op, _, _, _) => match op {
+      Prim2::And | Prim2::Or => Level::Log,
+      Prim2::Lt | Prim2::Le | Prim2::Gt | Prim2::Ge | Prim2::Eq | Prim2::Neq => Level::Cmp,
+      Prim2::Add | Prim2::Sub => Level::Sum,
+      Prim2::Mul => Level::Product,
+      Prim2::ArrayGet => Level::Arr,
+      Prim2::Send => Level::Base,
+    },
+    Exp::Prim1(Prim1::Not, _, _) | Exp::Raise(..) => Level::Not,
+    Exp::Assign { .. } => Level::Assign,
+    Exp::UpdateField { .. } => Level::Update,
+    Exp::ArraySet { .. } | Exp::GetField { .. } => Level::Arr,
+    _ => Level::Base,
+  }
+}
+
+fn op_name(op: Prim2) -> &'static str {
+  match op {
+    Prim2::Add => "+",
+    Prim2::Sub => "-",
+    Prim2::Mul => "*",
+    Prim2::And => "&&",
+    Prim2::Or => "||",
+    Prim2::Lt => "<",
+    Prim2::Gt => ">",
+    Prim2::Le => "<=",
+    Prim2::Ge => ">=",
+    Prim2::Eq => "==",
+    Prim2::Neq => "!=",
+    Prim2::ArrayGet => unreachable!("array accesses aren't infix operators"),
+    Prim2::Send => unreachable!("send isn't an infix operator"),
+  }
+}
+
+fn prim1_name(op: Prim1) -> &'static str {
+  match op {
+    Prim1::Add1 => "add1",
+    Prim1::Sub1 => "sub1",
+    Prim1::Not => "!",
+    Prim1::Print => "print",
+    Prim1::IsBool => "isbool",
+    Prim1::IsNum => "isnum",
+    Prim1::Length => "length",
+    Prim1::IsArray => "isarray",
+    Prim1::IsFun => "isfun",
+    Prim1::CallCC => "callcc",
+    Prim1::Yield => "yield",
+    Prim1::Generator => "generator",
+    Prim1::Spawn => "spawn",
+    Prim1::Recv => "recv",
+    Prim1::NoField => "nofield",
+  }
+}
+
+// A comma-separated list between `open` and `close`, one item per line if
+// it doesn't fit on one
+fn list(open: Doc, items: Vec<Doc>, close: Doc) -> Doc {
+  if items.is_empty() {
+    return concat(vec![open, close]);
+  }
+  group(concat(vec![
+    open,
+    nest(2, concat(vec![Doc::SoftLine, join(items, || concat(vec![text(","), Doc::Line]))])),
+    Doc::SoftLine,
+    close,
+  ]))
+}
+
+// A comment on lines of its own, or inline if it's a one-line block comment
+fn comment_doc(comment: Comment) -> Doc {
+  let inline = comment.is_block() && !comment.text.contains('\n');
+  concat(vec![
+    text(comment.text),
+    if inline { Doc::Line } else { Doc::HardLine },
+  ])
+}
+
+// Lays out a program, putting back the comments of the source it was
+// parsed from.
+//
+// Each comment goes right before the token that followed it in the source.
+// The printer walks the source tokens alongside the ones it prints, which
+// are the same apart from the parentheses and commas it adds and drops.
+struct Printer<'a> {
+  source: &'a str,
+  // the source tokens left to print, next one last
+  tokens: Vec<Token>,
+  // the comments left to print, next one last
+  comments: Vec<Comment>,
+}
+
+impl<'a> Printer<'a> {
+  fn new(source: Option<&'a str>) -> Self {
+    let (tokens, comments) = match source {
+      None => (vec![], vec![]),
+      Some(source) => lexer::lex(source).expect("the source was already parsed"),
+    };
+    Printer {
+      source: source.unwrap_or_default(),
+      tokens: tokens
+        .into_iter()
+        .rev()
+        .filter(|(_, tok, _)| !matches!(tok, Tok::LParen | Tok::RParen | Tok::Comma))
+        .collect(),
+      comments: comments.into_iter().rev().collect(),
+    }
+  }
+
+  // The comments before the next token
+  fn leading(&mut self) -> Doc {
+    let (start, tok, _) = match self.tokens.last() {
+      Some(next) => next,
+      None => return concat(vec![]),
+    };
+    let mut docs = vec![];
+    let mut last_doc_line = false;
+    while let Some(comment) = self.comments.pop_if(|comment| comment.span.start_ix < *start) {
+      last_doc_line = !comment.is_block() && comment.text.starts_with("##");
+      docs.push(comment_doc(comment));
+    }
+    // a `##` comment that wasn't a doc comment mustn't become one when the
+    // tokens between it and a declaration were parentheses
+    if last_doc_line && matches!(tok, Tok::Def | Tok::Type | Tok::DocComment(_)) {
+      docs.extend([text("#"), Doc::HardLine]);
+    }
+    concat(docs)
+  }
+
+  fn tok<S>(&mut self, s: S) -> Doc
+  where
+    S: Into<String>,
+  {
+    let leading = self.leading();
+    self.tokens.pop();
+    concat(vec![leading, text(s)])
+  }
+
+  // A number as it was written, which may have been in hex or binary or
+  // with underscores
+  fn num(&mut self, n: i64) -> Doc {
+    let written = match self.tokens.last() {
+      Some((start, Tok::Num(m), end)) if *m == n => self.source[*start..*end].to_string(),
+      _ => n.to_string(),
+    };
+    self.tok(written)
+  }
+
+  fn doc_comment(&mut self, doc: &Option<String>) -> Doc {
+    let doc = match doc {
+      Some(doc) => doc,
+      None => return concat(vec![]),
+    };
+    let leading = self.leading();
+    self.tokens.pop();
+    let mut docs = vec![leading];
+    for line in doc.split('\n') {
+      docs.push(text(match line {
+        "" => "##".to_string(),
+        _ => format!("## {}", line),
+      }));
+      docs.push(Doc::HardLine);
+    }
+    concat(docs)
+  }
+
+  // `e` where the grammar expects at least `min`
+  fn child<Ann>(&mut self, e: &Exp<Ann>, min: Level) -> Doc {
+    if level(e) < min {
+      let leading = self.leading();
+      concat(vec![leading, text("("), nest(1, self.doc(e)), text(")")])
+    } else {
+      self.doc(e)
+    }
+  }
+
+  fn names(&mut self, names: &[String]) -> Doc {
+    let names = names.iter().map(|name| self.tok(name.clone())).collect();
+    join(names, || text(", "))
+  }
+
+  fn fields(&mut self, names: &[String]) -> Doc {
+    if names.is_empty() {
+      concat(vec![])
+    } else {
+      concat(vec![text("("), self.names(names), text(")")])
+    }
+  }
+
+  // `{x, y = b}`, which binds the field `x` to `x` and `y` to `b`
+  fn field_patterns(&mut self, fields: &[String], vars: &[String]) -> Doc {
+    let open = self.tok("{");
+    let patterns = fields
+      .iter()
+      .zip(vars)
+      .map(|(field, var)| {
+        let field_doc = self.tok(field.clone());
+        if field == var {
+          field_doc
+        } else {
+          concat(vec![field_doc, text(" "), self.tok("="), text(" "), self.tok(var.clone())])
+        }
+      })
+      .collect();
+    concat(vec![open, join(patterns, || text(", ")), self.tok("}")])
+  }
+
+  fn fun_decl<Ann>(&mut self, decl: &FunDecl<Exp<Ann>, Ann>) -> Doc {
+    concat(vec![
+      self.doc_comment(&decl.doc),
+      self.leading(),
+      group(concat(vec![
+        self.tok("def"),
+        text(" "),
+        self.tok(decl.name.clone()),
+        text("("),
+        self.names(&decl.parameters),
+        text(")"),
+        self.tok(":"),
+        nest(2, concat(vec![Doc::Line, self.doc(&decl.body)])),
+      ])),
+    ])
+  }
+
+  // `let` or `var` bindings and the body they're bound in
+  fn bindings<Ann>(
+    &mut self,
+    keyword: &str,
+    bindings: &[(String, Exp<Ann>)],
+    body: &Exp<Ann>,
+  ) -> Doc {
+    let keyword = self.tok(keyword);
+    let bindings = bindings
+      .iter()
+      .map(|(x, e)| {
+        concat(vec![
+          self.tok(x.clone()),
+          text(" "),
+          self.tok("="),
+          text(" "),
+          self.doc(e),
+        ])
+      })
+      .collect();
+    group(concat(vec![
+      group(concat(vec![
+        keyword,
+        text(" "),
+        nest(4, join(bindings, || concat(vec![text(","), Doc::Line]))),
+      ])),
+      text(" "),
+      self.tok("in"),
+      Doc::Line,
+      self.doc(body),
+    ]))
+  }
+
+  fn doc<Ann>(&mut self, e: &Exp<Ann>) -> Doc {
+    let leading = self.leading();
+    concat(vec![leading, self.exp(e)])
+  }
+
+  fn exp<Ann>(&mut self, e: &Exp<Ann>) -> Doc {
+    match e {
+      Exp::Num(n, _) => self.num(*n),
+      Exp::Bool(b, _) => self.tok(b.to_string()),
+      Exp::Var(x, _) => self.tok(x.clone()),
+      Exp::Prim1(Prim1::Not, e, _) => concat(vec![self.tok("!"), self.child(e, Level::Base)]),
+      Exp::Raise(e, _) => concat(vec![self.tok("raise"), text(" "), self.child(e, Level::Base)]),
+      Exp::Prim0(Prim0::Chan, _) => concat(vec![self.tok("chan"), text("()")]),
+      Exp::Prim1(op, e, _) => list(
+        concat(vec![self.tok(prim1_name(*op)), text("(")]),
+        vec![self.doc(e)],
+        text(")"),
+      ),
+      Exp::Prim2(Prim2::ArrayGet, array, index, _) => concat(vec![
+        self.child(array, Level::Arr),
+        self.tok("["),
+        self.doc(index),
+        self.tok("]"),
+      ]),
+      Exp::Prim2(Prim2::Send, c, v, _) => list(
+        concat(vec![self.tok("send"), text("(")]),
+        vec![self.doc(c), self.doc(v)],
+        text(")"),
+      ),
+      Exp::Prim2(op, e1, e2, _) => {
+        // && and || associate to the right, and the rest to the left
+        let (min1, min2) = match level(e) {
+          Level::Log => (Level::Cmp, Level::Log),
+          Level::Cmp => (Level::Cmp, Level::Sum),
+          Level::Sum => (Level::Sum, Level::Product),
+          _ => (Level::Product, Level::Not),
+        };
+        group(concat(vec![
+          self.child(e1, min1),
+          text(" "),
+          self.tok(op_name(*op)),
+          nest(2, concat(vec![Doc::Line, self.child(e2, min2)])),
+        ]))
+      }
+      Exp::Let { bindings, body, .. } => self.bindings("let", bindings, body),
+      Exp::LetVar { bindings, body, .. } => self.bindings("var", bindings, body),
+      Exp::Assign { var, new_value, .. } => concat(vec![
+        self.tok(var.clone()),
+        text(" "),
+        self.tok(":="),
+        text(" "),
+        self.child(new_value, Level::Log),
+      ]),
+      Exp::GetField { exp, field, .. } => concat(vec![
+        self.child(exp, Level::Arr),
+        self.tok("."),
+        self.tok(field.clone()),
+      ]),
+      Exp::UpdateField {
+        exp,
+        field,
+        new_value,
+        ..
+      } => group(concat(vec![
+        self.child(exp, Level::Update),
+        nest(
+          2,
+          concat(vec![
+            Doc::Line,
+            self.tok("with"),
+            text(" "),
+            self.tok(field.clone()),
+            text(" "),
+            self.tok("="),
+            text(" "),
+            self.child(new_value, Level::Log),
+          ]),
+        ),
+      ])),
+      Exp::If { cond, thn, els, .. } => group(concat(vec![
+        self.tok("if"),
+        text(" "),
+        self.doc(cond),
+        self.tok(":"),
+        nest(2, concat(vec![Doc::Line, self.doc(thn)])),
+        Doc::Line,
+        self.tok("else"),
+        self.tok(":"),
+        nest(2, concat(vec![Doc::Line, self.doc(els)])),
+      ])),
+      Exp::Array(es, _) => list(
+        self.tok("["),
+        es.iter().map(|e| self.doc(e)).collect(),
+        self.tok("]"),
+      ),
+      Exp::ArraySet {
+        array,
+        index,
+        new_value,
+        ..
+      } => concat(vec![
+        self.child(array, Level::Arr),
+        self.tok("["),
+        self.doc(index),
+        self.tok("]"),
+        text(" "),
+        self.tok(":="),
+        text(" "),
+        self.child(new_value, Level::Base),
+      ]),
+      Exp::Semicolon { .. } => {
+        let mut e = e;
+        let mut docs = vec![];
+        while let Exp::Semicolon { e1, e2, .. } = e {
+          docs.push(concat(vec![self.child(e1, Level::Assign), self.tok(";"), Doc::Line]));
+          e = e2;
+        }
+        docs.push(self.doc(e));
+        group(concat(docs))
+      }
+      Exp::While { cond, body, .. } => group(concat(vec![
+        self.tok("while"),
+        text(" "),
+        self.doc(cond),
+        self.tok(":"),
+        nest(2, concat(vec![Doc::Line, self.doc(body)])),
+        Doc::Line,
+        self.tok("end"),
+      ])),
+      Exp::For {
+        var,
+        start,
+        end,
+        body,
+        ..
+      } => group(concat(vec![
+        self.tok("for"),
+        text(" "),
+        self.tok(var.clone()),
+        text(" "),
+        self.tok("="),
+        text(" "),
+        self.doc(start),
+        text(" "),
+        self.tok("to"),
+        text(" "),
+        self.doc(end),
+        self.tok(":"),
+        nest(2, concat(vec![Doc::Line, self.doc(body)])),
+        Doc::Line,
+        self.tok("end"),
+      ])),
+      Exp::FunDefs { decls, body, .. } => {
+        let mut docs = vec![];
+        for (i, decl) in decls.iter().enumerate() {
+          if i > 0 {
+            docs.extend([Doc::Line, self.tok("and"), Doc::Line]);
+          }
+          docs.push(self.fun_decl(decl));
+        }
+        docs.extend([Doc::Line, self.tok("in"), Doc::Line, self.doc(body)]);
+        group(concat(docs))
+      }
+      Exp::Call(fun, args, _) => concat(vec![
+        self.child(fun, Level::Base),
+        list(text("("), args.iter().map(|e| self.doc(e)).collect(), text(")")),
+      ]),
+      Exp::Lambda {
+        parameters, body, ..
+      } => group(concat(vec![
+        self.tok("lambda"),
+        match parameters.as_slice() {
+          [] => concat(vec![]),
+          _ => concat(vec![text(" "), self.names(parameters)]),
+        },
+        self.tok(":"),
+        nest(2, concat(vec![Doc::Line, self.doc(body)])),
+        Doc::Line,
+        self.tok("end"),
+      ])),
+      Exp::TypeDefs {
+        decls, doc, body, ..
+      } => {
+        let doc = self.doc_comment(doc);
+        let leading = self.leading();
+        let type_ = self.tok("type");
+        let decls = decls
+          .iter()
+          .map(|(name, args)| concat(vec![self.tok(name.clone()), self.fields(args)]))
+          .collect();
+        concat(vec![
+          doc,
+          leading,
+          group(concat(vec![
+            type_,
+            text(" "),
+            join(decls, || text(", ")),
+            text(" "),
+            self.tok("in"),
+            Doc::Line,
+            self.doc(body),
+          ])),
+        ])
+      }
+      Exp::Match {
+        expr,
+        default,
+        arms,
+        ..
+      } => {
+        let head = concat(vec![
+          self.tok("match"),
+          text(" "),
+          self.doc(expr),
+          text(" "),
+          self.tok("default"),
+          text(" "),
+          self.doc(default),
+          self.tok(":"),
+        ]);
+        let arms = self.arms(arms);
+        group(concat(vec![head, arms, Doc::Line, self.tok("end")]))
+      }
+      // laid out like a `match`, with the body where its head would be
+      Exp::Try { body, arms, .. } => {
+        let try_ = self.tok("try");
+        let body = nest(2, concat(vec![Doc::Line, self.doc(body)]));
+        let with = self.tok("with");
+        let arms = self.arms(arms);
+        group(concat(vec![try_, body, Doc::Line, with, arms, Doc::Line, self.tok("end")]))
+      }
+      // each import is on its own line
+      Exp::Import { path, body, .. } => concat(vec![
+        self.tok("import"),
+        text(" "),
+        self.tok(format!("\"{}\"", path)),
+        Doc::HardLine,
+        self.doc(body),
+      ]),
+      Exp::Modules { .. }
+      | Exp::MakeClosure { .. }
+      | Exp::MakeTypeInstance { .. }
+      | Exp::MatchType { .. }
+      | Exp::GetTypeFields(..)
+      | Exp::Catch { .. } => unreachable!("only surface programs can be formatted"),
+    }
+  }
+
+  // The `case`s of a `match` or a `try`, indented on their own lines if
+  // they don't fit on one
+  fn arms<Ann>(&mut self, arms: &[(SnakeType, Vec<String>, Exp<Ann>)]) -> Doc {
+    let arms = arms
+      .iter()
+      .map(|(snake_type, args, e)| {
+        let leading = self.leading();
+        let name = match snake_type {
+          SnakeType::Custom(name) | SnakeType::CustomFields(name, _) => name.as_str(),
+          SnakeType::Num => "Num",
+          SnakeType::Bool => "Bool",
+          SnakeType::Array => "Array",
+          SnakeType::Func => "Func",
+        };
+        let pattern = concat(vec![self.tok("case"), text(" "), self.tok(name)]);
+        let fields = match snake_type {
+          SnakeType::CustomFields(_, fields) => self.field_patterns(fields, args),
+          _ => self.fields(args),
+        };
+        concat(vec![
+          leading,
+          group(concat(vec![
+            pattern,
+            fields,
+            text(" "),
+            self.tok("=>"),
+            nest(2, concat(vec![Doc::Line, self.doc(e)])),
+          ])),
+        ])
+      })
+      .collect();
+    nest(2, concat(vec![Doc::Line, join(arms, || concat(vec![text(","), Doc::Line]))]))
+  }
+
+  // The layout of `prog`, followed by any comments after its last token
+  fn prog<Ann>(mut self, prog: &SurfProg<Ann>) -> Doc {
+    let mut docs = vec![self.doc(prog)];
+    while let Some(comment) = self.comments.pop() {
+      docs.extend([Doc::HardLine, text(comment.text)]);
+    }
+    concat(docs)
+  }
+}
+
+// Prints `prog` in the canonical layout, breaking lines to fit in `width`
+// columns where it can. Parsing the output gives back `prog`, apart from
+// the spans.
+pub fn format_prog<Ann>(prog: &SurfProg<Ann>, width: usize) -> String {
+  let mut out = render(&Printer::new(None).prog(prog), width);
+  out.push('\n');
+  out
+}
+
+// Like `format_prog`, but keeps the comments in `source`, which `prog` was
+// parsed from
+pub fn format_source(prog: &SurfProg<Span1>, source: &str, width: usize) -> String {
+  let mut out = render(&Printer::new(Some(source)).prog(prog), width);
+  out.push('\n');
+  out
+}
@@ -1,13 +1,18 @@
-use crate::syntax::{Exp, Prim1, Prim2, SurfFunDecl, SurfProg};
+use crate::pipeline_functions::drop_unused::used_names;
+use crate::syntax::{
+  error_tag, Exp, Module, Prim0, Prim1, Prim2, SnakeType, SurfFunDecl, SurfProg, CHANNEL_TAG,
+  ERROR_TYPES,
+};
 
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::convert::TryInto;
 use std::fmt;
 use std::fmt::Display;
 use std::rc::Rc;
 
 // The machine is always either evaluating an expression against the stack (and heap) or returning a value
-enum Machine<'exp, Ann> {
+#[derive(Clone)]
+pub(crate) enum Machine<'exp, Ann> {
   Descending {
     e: &'exp Exp<Ann>,
     stk: Stack<'exp, Ann>,
@@ -19,6 +24,14 @@ enum Machine<'exp, Ann> {
   },
 }
 
+impl<'exp, Ann> Machine<'exp, Ann> {
+  fn stack(&self) -> &Stack<'exp, Ann> {
+    match self {
+      Machine::Descending { stk, .. } | Machine::Returning { stk, .. } => stk,
+    }
+  }
+}
+
 /* Semantic Values */
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum SnakeVal {
@@ -26,6 +39,8 @@ pub enum SnakeVal {
   Bool(bool),
   Array(usize),   // index into the array arena
   Closure(usize), // index into the closure arena
+  // a value of a custom type, with its fields in the array arena
+  Custom { tag: u64, fields: usize },
 }
 
 impl Display for SnakeVal {
@@ -35,103 +50,690 @@ impl Display for SnakeVal {
       SnakeVal::Bool(b) => write!(f, "{}", b),
       SnakeVal::Closure { .. } => write!(f, "closure"),
       SnakeVal::Array { .. } => write!(f, "array"),
+      SnakeVal::Custom { tag: CHANNEL_TAG, .. } => write!(f, "channel"),
+      SnakeVal::Custom { .. } => write!(f, "custom type"),
     }
   }
 }
 
 /* Semantic Stacks */
-enum Stack<'exp, Ann> {
+#[derive(Clone)]
+pub(crate) enum Stack<'exp, Ann> {
   Done,
-  Prim1(Prim1, Box<Stack<'exp, Ann>>),
-  Prim2L(Prim2, Closure<'exp, Ann>, Box<Stack<'exp, Ann>>),
-  Prim2R(Prim2, SnakeVal, Box<Stack<'exp, Ann>>),
+  Prim1(Prim1, Parent<'exp, Ann>),
+  Prim2L(Prim2, Closure<'exp, Ann>, Parent<'exp, Ann>),
+  Prim2R(Prim2, SnakeVal, Parent<'exp, Ann>),
   If {
     thn: &'exp Exp<Ann>,
     els: &'exp Exp<Ann>,
     env: Env,
-    stk: Box<Stack<'exp, Ann>>,
+    stk: Parent<'exp, Ann>,
   },
   Let {
     var: &'exp str,
     env: Env,
     bindings: Vec<&'exp (String, Exp<Ann>)>,
     body: &'exp Exp<Ann>,
-    stk: Box<Stack<'exp, Ann>>,
+    stk: Parent<'exp, Ann>,
+  },
+  // like `Let`, with each variable bound to a new cell
+  LetVar {
+    var: &'exp str,
+    env: Env,
+    bindings: Vec<&'exp (String, Exp<Ann>)>,
+    body: &'exp Exp<Ann>,
+    stk: Parent<'exp, Ann>,
+  },
+  // evaluating the new value of the variable in `cell`
+  Assign {
+    var: &'exp str,
+    cell: usize,
+    stk: Parent<'exp, Ann>,
   },
   CallFun {
     env: Env,
     args: Vec<&'exp Exp<Ann>>,
-    stk: Box<Stack<'exp, Ann>>,
+    stk: Parent<'exp, Ann>,
   },
   CallArgs {
     fun: SnakeVal,
     evaled_args: Vec<SnakeVal>,
     env: Env,
     remaining_args: Vec<&'exp Exp<Ann>>,
-    stk: Box<Stack<'exp, Ann>>,
+    stk: Parent<'exp, Ann>,
   },
   Array {
     evaled_parts: Vec<SnakeVal>,
     remaining_parts: Vec<&'exp Exp<Ann>>,
     env: Env,
-    stk: Box<Stack<'exp, Ann>>,
+    stk: Parent<'exp, Ann>,
   },
   ArraySet1 {
     index: &'exp Exp<Ann>,
     new_value: &'exp Exp<Ann>,
     env: Env,
-    stk: Box<Stack<'exp, Ann>>,
+    stk: Parent<'exp, Ann>,
   },
   ArraySet2 {
     array: SnakeVal,
     new_value: Closure<'exp, Ann>,
-    stk: Box<Stack<'exp, Ann>>,
+    stk: Parent<'exp, Ann>,
   },
   ArraySet3 {
     array: SnakeVal,
     index: SnakeVal,
-    stk: Box<Stack<'exp, Ann>>,
+    stk: Parent<'exp, Ann>,
   },
   Semicolon {
     next: Closure<'exp, Ann>,
-    stk: Box<Stack<'exp, Ann>>,
+    stk: Parent<'exp, Ann>,
+  },
+  // evaluating the condition of a `while` loop, then running its body
+  WhileCond {
+    cond: &'exp Exp<Ann>,
+    body: &'exp Exp<Ann>,
+    env: Env,
+    stk: Parent<'exp, Ann>,
+  },
+  WhileBody {
+    cond: &'exp Exp<Ann>,
+    body: &'exp Exp<Ann>,
+    env: Env,
+    stk: Parent<'exp, Ann>,
+  },
+  // evaluating the bounds of a `for` loop, then running its body with `var`
+  // bound to `i`
+  ForStart {
+    var: &'exp str,
+    end: &'exp Exp<Ann>,
+    body: &'exp Exp<Ann>,
+    env: Env,
+    stk: Parent<'exp, Ann>,
+  },
+  ForEnd {
+    var: &'exp str,
+    start: SnakeVal,
+    body: &'exp Exp<Ann>,
+    env: Env,
+    stk: Parent<'exp, Ann>,
+  },
+  ForBody {
+    var: &'exp str,
+    i: i64,
+    end: i64,
+    body: &'exp Exp<Ann>,
+    env: Env,
+    stk: Parent<'exp, Ann>,
+  },
+  MakeTypeInstance {
+    typetag: u64,
+    stk: Parent<'exp, Ann>,
+  },
+  MatchType {
+    typetag: u64,
+    stk: Parent<'exp, Ann>,
   },
+  GetTypeFields(Parent<'exp, Ann>),
+  // evaluating the value a `match` matches against its arms
+  Match {
+    default: &'exp Exp<Ann>,
+    arms: &'exp [(SnakeType, Vec<String>, Exp<Ann>)],
+    env: Env,
+    stk: Parent<'exp, Ann>,
+  },
+  GetField {
+    field: &'exp str,
+    stk: Parent<'exp, Ann>,
+  },
+  // evaluating the value whose field `with` replaces, then the new value
+  UpdateField1 {
+    field: &'exp str,
+    new_value: Closure<'exp, Ann>,
+    stk: Parent<'exp, Ann>,
+  },
+  UpdateField2 {
+    field: &'exp str,
+    v: SnakeVal,
+    stk: Parent<'exp, Ann>,
+  },
+  // running the body of a `try`, whose handler continues with this frame's
+  // parent if the body raises
+  Try {
+    try_exp: &'exp Exp<Ann>,
+    env: Env,
+    stk: Parent<'exp, Ann>,
+  },
+  Raise(Parent<'exp, Ann>),
+  // the bottom of the stack of the generator `gen`, whose body returns to
+  // the code that resumed it by raising GeneratorDone
+  Generator {
+    gen: usize,
+  },
+  // the bottom of the stack of a thread made by `spawn`, which finishes
+  // once its closure returns
+  Thread,
+}
+
+/* Limits on how long the interpreter runs and how much it allocates, and
+ * the seed its scheduler picks threads with */
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Config {
+  max_steps: Option<u64>,
+  max_heap_cells: Option<usize>,
+  max_stack_depth: Option<usize>,
+  seed: u64,
+}
+
+impl Config {
+  pub fn new() -> Self {
+    Config::default()
+  }
+
+  // stop with OutOfFuel after this many machine transitions
+  pub fn max_steps(mut self, steps: u64) -> Self {
+    self.max_steps = Some(steps);
+    self
+  }
+
+  // stop with OutOfMemory once this many heap cells are allocated. An array
+  // of n elements takes n + 1 cells, and a closure or a var takes one.
+  pub fn max_heap_cells(mut self, cells: usize) -> Self {
+    self.max_heap_cells = Some(cells);
+    self
+  }
+
+  // stop with OutOfStack once the stack of the running thread is more than
+  // this many frames deep
+  pub fn max_stack_depth(mut self, frames: usize) -> Self {
+    self.max_stack_depth = Some(frames);
+    self
+  }
+
+  // pick the next thread to run as the compiled code does with this seed in
+  // SNAKE_SEED, instead of 0
+  pub fn seed(mut self, seed: u64) -> Self {
+    self.seed = seed;
+    self
+  }
+}
+
+// The rest of the continuation after a frame, which knows how deep it is so
+// that tracing a deep recursion doesn't walk the whole continuation. Copies
+// of the continuation, like the debugger's snapshots, share it.
+#[derive(Clone)]
+pub(crate) struct Parent<'exp, Ann> {
+  depth: usize,
+  stk: Rc<Stack<'exp, Ann>>,
+}
+
+impl<'exp, Ann> Parent<'exp, Ann>
+where
+  Ann: Clone,
+{
+  fn new(stk: Stack<'exp, Ann>) -> Self {
+    Parent {
+      depth: stk.depth(),
+      stk: Rc::new(stk),
+    }
+  }
+
+  fn into_stack(mut self) -> Stack<'exp, Ann> {
+    match Rc::get_mut(&mut self.stk) {
+      Some(stk) => std::mem::replace(stk, Stack::Done),
+      None => (*self.stk).clone(),
+    }
+  }
+}
+
+// Dropping a continuation unlinks its frames one at a time, since dropping
+// them recursively would overflow the native stack on a deep recursion.
+// Frames another copy still shares are left to that copy.
+impl<'exp, Ann> Drop for Parent<'exp, Ann> {
+  fn drop(&mut self) {
+    let Some(stk) = Rc::get_mut(&mut self.stk) else {
+      return;
+    };
+    let mut stk = std::mem::replace(stk, Stack::Done);
+    while let Some(parent) = stk.parent_frame_mut() {
+      match Rc::get_mut(&mut parent.stk) {
+        Some(next) => stk = std::mem::replace(next, Stack::Done),
+        None => break,
+      }
+    }
+  }
+}
+
+impl<'exp, Ann> std::ops::Deref for Parent<'exp, Ann> {
+  type Target = Stack<'exp, Ann>;
+
+  fn deref(&self) -> &Stack<'exp, Ann> {
+    &self.stk
+  }
+}
+
+impl<'exp, Ann> Stack<'exp, Ann>
+where
+  Ann: Clone,
+{
+  // the rest of the continuation after this frame
+  pub(crate) fn parent(&self) -> Option<&Stack<'exp, Ann>> {
+    self.parent_frame().map(|stk| &*stk.stk)
+  }
+
+  fn parent_frame(&self) -> Option<&Parent<'exp, Ann>> {
+    match self {
+      Stack::Done | Stack::Generator { .. } | Stack::Thread => None,
+      Stack::Prim1(_, stk)
+      | Stack::Prim2L(_, _, stk)
+      | Stack::Prim2R(_, _, stk)
+      | Stack::If { stk, .. }
+      | Stack::Let { stk, .. }
+      | Stack::LetVar { stk, .. }
+      | Stack::Assign { stk, .. }
+      | Stack::CallFun { stk, .. }
+      | Stack::CallArgs { stk, .. }
+      | Stack::Array { stk, .. }
+      | Stack::ArraySet1 { stk, .. }
+      | Stack::ArraySet2 { stk, .. }
+      | Stack::ArraySet3 { stk, .. }
+      | Stack::MakeTypeInstance { stk, .. }
+      | Stack::MatchType { stk, .. }
+      | Stack::GetTypeFields(stk)
+      | Stack::Match { stk, .. }
+      | Stack::GetField { stk, .. }
+      | Stack::UpdateField1 { stk, .. }
+      | Stack::UpdateField2 { stk, .. }
+      | Stack::Semicolon { stk, .. }
+      | Stack::WhileCond { stk, .. }
+      | Stack::WhileBody { stk, .. }
+      | Stack::ForStart { stk, .. }
+      | Stack::ForEnd { stk, .. }
+      | Stack::ForBody { stk, .. }
+      | Stack::Try { stk, .. }
+      | Stack::Raise(stk) => Some(stk),
+    }
+  }
+
+  // like `parent`, dropping this frame
+  fn into_parent(self) -> Option<Stack<'exp, Ann>> {
+    match self {
+      Stack::Done | Stack::Generator { .. } | Stack::Thread => None,
+      Stack::Prim1(_, stk)
+      | Stack::Prim2L(_, _, stk)
+      | Stack::Prim2R(_, _, stk)
+      | Stack::If { stk, .. }
+      | Stack::Let { stk, .. }
+      | Stack::LetVar { stk, .. }
+      | Stack::Assign { stk, .. }
+      | Stack::CallFun { stk, .. }
+      | Stack::CallArgs { stk, .. }
+      | Stack::Array { stk, .. }
+      | Stack::ArraySet1 { stk, .. }
+      | Stack::ArraySet2 { stk, .. }
+      | Stack::ArraySet3 { stk, .. }
+      | Stack::MakeTypeInstance { stk, .. }
+      | Stack::MatchType { stk, .. }
+      | Stack::GetTypeFields(stk)
+      | Stack::Match { stk, .. }
+      | Stack::GetField { stk, .. }
+      | Stack::UpdateField1 { stk, .. }
+      | Stack::UpdateField2 { stk, .. }
+      | Stack::Semicolon { stk, .. }
+      | Stack::WhileCond { stk, .. }
+      | Stack::WhileBody { stk, .. }
+      | Stack::ForStart { stk, .. }
+      | Stack::ForEnd { stk, .. }
+      | Stack::ForBody { stk, .. }
+      | Stack::Try { stk, .. }
+      | Stack::Raise(stk) => Some(stk.into_stack()),
+    }
+  }
+
+  // the number of frames after this one
+  pub(crate) fn depth(&self) -> usize {
+    self.parent_frame().map_or(0, |stk| stk.depth + 1)
+  }
+}
+
+impl<'exp, Ann> Stack<'exp, Ann> {
+  fn parent_frame_mut(&mut self) -> Option<&mut Parent<'exp, Ann>> {
+    match self {
+      Stack::Done | Stack::Generator { .. } | Stack::Thread => None,
+      Stack::Prim1(_, stk)
+      | Stack::Prim2L(_, _, stk)
+      | Stack::Prim2R(_, _, stk)
+      | Stack::If { stk, .. }
+      | Stack::Let { stk, .. }
+      | Stack::LetVar { stk, .. }
+      | Stack::Assign { stk, .. }
+      | Stack::CallFun { stk, .. }
+      | Stack::CallArgs { stk, .. }
+      | Stack::Array { stk, .. }
+      | Stack::ArraySet1 { stk, .. }
+      | Stack::ArraySet2 { stk, .. }
+      | Stack::ArraySet3 { stk, .. }
+      | Stack::MakeTypeInstance { stk, .. }
+      | Stack::MatchType { stk, .. }
+      | Stack::GetTypeFields(stk)
+      | Stack::Match { stk, .. }
+      | Stack::GetField { stk, .. }
+      | Stack::UpdateField1 { stk, .. }
+      | Stack::UpdateField2 { stk, .. }
+      | Stack::Semicolon { stk, .. }
+      | Stack::WhileCond { stk, .. }
+      | Stack::WhileBody { stk, .. }
+      | Stack::ForStart { stk, .. }
+      | Stack::ForEnd { stk, .. }
+      | Stack::ForBody { stk, .. }
+      | Stack::Try { stk, .. }
+      | Stack::Raise(stk) => Some(stk),
+    }
+  }
 }
 
 /* The semantic Store consists of arenas for allocating arrays and closures */
-struct State<'e, Ann> {
+#[derive(Clone)]
+pub(crate) struct State<'e, Ann> {
   funs: Funs<'e, Ann>,
+  // the values of the names each imported file exports
+  exports: Vec<Vec<(&'e str, SnakeVal)>>,
   heap: Heap,
+  cells: usize,
+  max_cells: Option<usize>,
+  // whether each def or lambda, by its body, makes a generator when called
+  generators: HashMap<*const Exp<Ann>, bool>,
+  // the threads, indexed by the numbers `spawn` returns, and the one the
+  // machine is running
+  threads: Vec<Thread<'e, Ann>>,
+  current: usize,
+  // the threads that can run, in the order of their numbers
+  ready: Vec<usize>,
+  channels: Vec<Channel>,
+  // the state of the random numbers the scheduler picks threads with
+  seed: u64,
+}
+
+#[derive(Clone)]
+enum Thread<'e, Ann> {
+  // the thread the machine is running
+  Running,
+  Runnable(Machine<'e, Ann>),
+  // blocked in a `recv`, to continue with the stack once a `send` gives it
+  // a value
+  Waiting(Stack<'e, Ann>),
+  Finished,
+}
+
+#[derive(Clone, Default)]
+struct Channel {
+  // the values sent while no thread was waiting for one
+  values: VecDeque<SnakeVal>,
+  // the threads waiting to receive, in the order they started waiting
+  waiting: VecDeque<usize>,
+}
+
+// A vector whose copies share the chunks of elements that neither changes,
+// so that the debugger's snapshots of a growing heap stay cheap
+#[derive(Clone)]
+struct Shared<T> {
+  chunks: Vec<Rc<Vec<T>>>,
+  len: usize,
+}
+
+const CHUNK_LEN: usize = 256;
+
+impl<T> Default for Shared<T> {
+  fn default() -> Self {
+    Shared {
+      chunks: vec![],
+      len: 0,
+    }
+  }
+}
+
+impl<T> Shared<T> {
+  fn len(&self) -> usize {
+    self.len
+  }
+
+  fn get(&self, i: usize) -> Option<&T> {
+    self.chunks.get(i / CHUNK_LEN)?.get(i % CHUNK_LEN)
+  }
+}
+
+impl<T: Clone> Shared<T> {
+  fn push(&mut self, v: T) {
+    if self.len.is_multiple_of(CHUNK_LEN) {
+      self.chunks.push(Rc::new(Vec::with_capacity(CHUNK_LEN)));
+    }
+    Rc::make_mut(self.chunks.last_mut().unwrap()).push(v);
+    self.len += 1;
+  }
+
+  // copies the chunk of the element first if another copy shares it
+  fn get_mut(&mut self, i: usize) -> Option<&mut T> {
+    Rc::make_mut(self.chunks.get_mut(i / CHUNK_LEN)?).get_mut(i % CHUNK_LEN)
+  }
+}
+
+impl<T> std::ops::Index<usize> for Shared<T> {
+  type Output = T;
+
+  fn index(&self, i: usize) -> &T {
+    self.get(i).expect("index out of bounds")
+  }
+}
+
+impl<T: Clone> std::ops::IndexMut<usize> for Shared<T> {
+  fn index_mut(&mut self, i: usize) -> &mut T {
+    self.get_mut(i).expect("index out of bounds")
+  }
+}
+
+#[derive(Clone, Default)]
+pub(crate) struct Heap {
+  arrays: Shared<Vec<SnakeVal>>,
+  // the values of the variables bound by `var`
+  vars: Shared<SnakeVal>,
+  // the names of the custom types, indexed by type tag
+  type_names: Vec<String>,
+  // the names of their fields, for the types the interpreter declared
+  type_fields: Vec<Vec<String>>,
 }
-type Heap = Vec<Vec<SnakeVal>>;
 
-struct SemFun<'e, Ann> {
-  parameters: &'e [String],
-  closure: Closure<'e, Ann>,
+impl Heap {
+  // The number of arrays allocated
+  pub(crate) fn len(&self) -> usize {
+    self.arrays.len()
+  }
+
+  fn get(&self, ptr: usize) -> Option<&Vec<SnakeVal>> {
+    self.arrays.get(ptr)
+  }
+
+  fn get_mut(&mut self, ptr: usize) -> Option<&mut Vec<SnakeVal>> {
+    self.arrays.get_mut(ptr)
+  }
+
+  pub(crate) fn type_name(&self, tag: u64) -> &str {
+    self
+      .type_names
+      .get(tag as usize)
+      .map_or("<unknown type>", String::as_str)
+  }
+
+  // The index of the field named `field` of the values of the type `tag`
+  fn field_index(&self, tag: u64, field: &str) -> Option<usize> {
+    self.type_fields.get(tag as usize)?.iter().position(|f| f == field)
+  }
+}
+
+#[derive(Clone)]
+enum SemFun<'e, Ann> {
+  Fun {
+    // the name of the def, or "lambda"
+    name: &'e str,
+    parameters: &'e [String],
+    closure: Closure<'e, Ann>,
+    // whether the body has a `yield`, so that calling it makes a generator
+    generator: bool,
+  },
+  // a continuation made by `callcc`, which returns its one argument to `stk`
+  Cont(Stack<'e, Ann>),
+  // the constructor of a custom type with fields, which makes a value of
+  // the type `tag` with its arguments as the fields
+  Ctor {
+    name: &'e str,
+    tag: u64,
+    arity: usize,
+  },
+  // a generator, which is called to resume it
+  Gen(GenState<'e, Ann>),
+}
+
+#[derive(Clone)]
+enum GenState<'e, Ann> {
+  // not started, to run the closure on resuming
+  Start(Closure<'e, Ann>),
+  // suspended at a `yield`, to return to the stack on resuming
+  Suspended(Stack<'e, Ann>),
+  // resumed from the stack, which its next `yield` returns to
+  Running(Stack<'e, Ann>),
+  Done,
 }
-type Funs<'e, Ann> = Vec<SemFun<'e, Ann>>;
+type Funs<'e, Ann> = Shared<SemFun<'e, Ann>>;
 
-impl<'e, Ann> State<'e, Ann> {
-  fn new() -> Self {
+impl<'e, Ann> State<'e, Ann>
+where
+  Ann: Clone,
+{
+  fn new(config: &Config) -> Self {
     State {
-      funs: vec![],
-      heap: vec![],
+      funs: Shared::default(),
+      exports: vec![],
+      heap: Heap {
+        // the builtin error types have the first tags, as in a resolved
+        // program
+        type_names: ERROR_TYPES.iter().map(|(name, _)| name.to_string()).collect(),
+        type_fields: vec![vec![]; ERROR_TYPES.len()],
+        ..Heap::default()
+      },
+      cells: 0,
+      max_cells: config.max_heap_cells,
+      generators: HashMap::new(),
+      threads: vec![Thread::Running],
+      current: 0,
+      ready: vec![],
+      channels: vec![],
+      seed: config.seed,
+    }
+  }
+
+  pub(crate) fn heap(&self) -> &Heap {
+    &self.heap
+  }
+
+  pub(crate) fn fun_name(&self, fun: usize) -> &'e str {
+    match &self.funs[fun] {
+      SemFun::Fun { name, .. } => name,
+      SemFun::Cont(_) => "continuation",
+      SemFun::Ctor { name, .. } => name,
+      SemFun::Gen(_) => "generator",
+    }
+  }
+
+  // Whether `x` bound to `v` is one of the names an imported file exports
+  pub(crate) fn is_export(&self, x: &str, v: SnakeVal) -> bool {
+    self.exports.iter().flatten().any(|(name, w)| *name == x && *w == v)
+  }
+
+  // The body of a function, or None for a continuation, generator or
+  // constructor
+  pub(crate) fn fun_body(&self, fun: usize) -> Option<&'e Exp<Ann>> {
+    match &self.funs[fun] {
+      SemFun::Fun { closure, .. } => Some(closure.exp),
+      SemFun::Cont(_) | SemFun::Gen(_) | SemFun::Ctor { .. } => None,
+    }
+  }
+
+  // The number of arguments `fun` is called with
+  fn arity(&self, fun: usize) -> usize {
+    match &self.funs[fun] {
+      SemFun::Fun { parameters, .. } => parameters.len(),
+      SemFun::Cont(_) => 1,
+      SemFun::Gen(_) => 0,
+      SemFun::Ctor { arity, .. } => *arity,
+    }
+  }
+
+  // The event of calling `fun` with `args`. Calling a continuation isn't a
+  // call, since it returns instead, and neither is making or resuming a
+  // generator or making a value of a custom type.
+  fn call_event(&self, fun: usize, args: &[SnakeVal]) -> Event {
+    match &self.funs[fun] {
+      SemFun::Fun {
+        generator: false, ..
+      } => Event::Call {
+        fun,
+        args: args.to_vec(),
+      },
+      SemFun::Fun { .. } | SemFun::Cont(_) | SemFun::Gen(_) | SemFun::Ctor { .. } => Event::Step,
+    }
+  }
+
+  // Names the custom types of a program whose types have been resolved to
+  // type tags, so that values of those types can be printed
+  pub(crate) fn set_type_names(&mut self, names: Vec<String>) {
+    self.heap.type_names = names;
+  }
+
+  fn alloc_cells(&mut self, n: usize) -> Interp<()> {
+    self.cells += n;
+    match self.max_cells {
+      Some(max) if self.cells > max => Err(InterpErr::OutOfMemory { max_cells: max }),
+      _ => Ok(()),
     }
   }
 
-  fn alloc_fun(&mut self, parameters: &'e [String], body: &'e Exp<Ann>, env: Env) -> usize {
+  // whether the body has a `yield`, which is searched for once per body
+  // rather than each time a closure is made
+  fn is_generator(&mut self, body: &'e Exp<Ann>) -> bool {
+    *self
+      .generators
+      .entry(body as *const _)
+      .or_insert_with(|| body.first_yield().is_some())
+  }
+
+  fn alloc_fun(&mut self, parameters: &'e [String], body: &'e Exp<Ann>, env: Env) -> Interp<usize> {
+    self.alloc_cells(1)?;
+    let generator = self.is_generator(body);
     let i = self.funs.len();
-    self.funs.push(SemFun {
+    self.funs.push(SemFun::Fun {
+      name: "lambda",
       parameters: &parameters,
       closure: Closure {
         exp: body,
         env: env.clone(),
       },
+      generator,
     });
-    i
+    Ok(i)
+  }
+
+  fn alloc_cont(&mut self, stk: Stack<'e, Ann>) -> Interp<usize> {
+    self.alloc_cells(1)?;
+    self.funs.push(SemFun::Cont(stk));
+    Ok(self.funs.len() - 1)
+  }
+
+  fn alloc_gen(&mut self, closure: Closure<'e, Ann>) -> Interp<usize> {
+    self.alloc_cells(1)?;
+    self.funs.push(SemFun::Gen(GenState::Start(closure)));
+    Ok(self.funs.len() - 1)
   }
 
-  fn alloc_funs(&mut self, decls: &'e [SurfFunDecl<Ann>], mut env: Env) -> Env {
+  fn alloc_funs(&mut self, decls: &'e [SurfFunDecl<Ann>], mut env: Env) -> Interp<Env> {
+    self.alloc_cells(decls.len())?;
     // Each of the closures captures the same environment: the
     // current environment extended with all of their names
     // i.e., the env we return.
@@ -140,22 +742,171 @@ impl<'e, Ann> State<'e, Ann> {
       env = env.push_local(d.name.clone(), SnakeVal::Closure(i + j));
     }
     for d in decls.iter() {
-      self.funs.push(SemFun {
+      let generator = self.is_generator(&d.body);
+      self.funs.push(SemFun::Fun {
+        name: &d.name,
         parameters: &d.parameters,
         closure: Closure {
           exp: &d.body,
           env: env.clone(),
         },
+        generator,
       });
     }
+    Ok(env)
+  }
+
+  // The tag of the custom type `name`. Running a declaration again, as in
+  // a function called twice, gives its types the same tags.
+  fn declare_type(&mut self, name: &str, fields: &[String]) -> u64 {
+    let heap = &mut self.heap;
+    let declared = heap
+      .type_names
+      .iter()
+      .zip(&heap.type_fields)
+      .position(|(n, fs)| n == name && fs == fields);
+    let tag = declared.unwrap_or_else(|| {
+      heap.type_names.push(name.to_string());
+      heap.type_fields.push(fields.to_vec());
+      heap.type_names.len() - 1
+    });
+    tag as u64
+  }
+
+  // `env` extended with the constructors of the custom types `decls`, or
+  // the one value of a type without fields, unless `used` leaves it out
+  fn alloc_types(
+    &mut self,
+    decls: &'e [(String, Vec<String>)],
+    used: Option<&HashSet<String>>,
+    mut env: Env,
+  ) -> Interp<Env> {
+    for (name, fields) in decls {
+      let tag = self.declare_type(name, fields);
+      let v = if fields.is_empty() {
+        if used.is_some_and(|used| !used.contains(name)) {
+          continue;
+        }
+        let fields = self.alloc_array(vec![])?;
+        self.alloc_cells(1)?;
+        SnakeVal::Custom { tag, fields }
+      } else {
+        self.alloc_cells(1)?;
+        self.funs.push(SemFun::Ctor {
+          name,
+          tag,
+          arity: fields.len(),
+        });
+        SnakeVal::Closure(self.funs.len() - 1)
+      };
+      env = env.push_local(name.clone(), v);
+    }
+    Ok(env)
+  }
+
+  // `env` extended with the names exported by `module`
+  fn import(&self, module: Option<usize>, mut env: Env) -> Env {
+    let module = module.expect("imports are resolved when the program is loaded");
+    for (name, v) in self.exports[module].iter() {
+      env = env.push_local(name.to_string(), *v);
+    }
     env
   }
 
-  fn alloc_array(&mut self, vs: Vec<SnakeVal>) -> usize {
-    // can raise an Out of memory error?
-    let ptr = self.heap.len();
-    self.heap.push(vs);
-    ptr
+  // Defines the functions and types declared at the start of each imported
+  // file, in the order they're imported in. The rest of each file isn't
+  // run, and the values of types without fields that aren't in `used`, like
+  // the prelude's `None` in most programs, aren't allocated.
+  fn load_modules(&mut self, modules: &'e [Module<Ann>], used: &HashSet<String>) -> Interp<()> {
+    for module in modules {
+      let mut e = &module.prog;
+      let mut env = Env::new();
+      let mut exports = vec![];
+      loop {
+        match e {
+          Exp::Import { module, body, .. } => {
+            env = self.import(*module, env);
+            e = body;
+          }
+          Exp::FunDefs { decls, body, .. } => {
+            env = self.alloc_funs(decls, env)?;
+            for d in decls {
+              exports.push((d.name.as_str(), env.lookup(&d.name, &self.heap).unwrap()));
+            }
+            e = body;
+          }
+          Exp::TypeDefs { decls, body, .. } => {
+            env = self.alloc_types(decls, Some(used), env)?;
+            for (name, fields) in decls {
+              if !fields.is_empty() || used.contains(name) {
+                exports.push((name.as_str(), env.lookup(name, &self.heap).unwrap()));
+              }
+            }
+            e = body;
+          }
+          _ => break,
+        }
+      }
+      self.exports.push(exports);
+    }
+    Ok(())
+  }
+
+  // splitmix64, as the runtime's scheduler
+  fn next_random(&mut self) -> u64 {
+    self.seed = self.seed.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    let mut z = self.seed;
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+  }
+
+  fn channel(&mut self, c: SnakeVal) -> Interp<&mut Channel> {
+    let channel = match c {
+      SnakeVal::Custom {
+        tag: CHANNEL_TAG,
+        fields,
+      } => match self.heap.arrays[fields][0] {
+        SnakeVal::Num(n) => self.channels.get_mut(n as usize),
+        _ => None,
+      },
+      _ => None,
+    };
+    channel.ok_or(InterpErr::NotChannel { got: c })
+  }
+
+  // Lets the thread `t` continue with `machine` once the scheduler picks it
+  fn make_runnable(&mut self, t: usize, machine: Machine<'e, Ann>) {
+    self.threads[t] = Thread::Runnable(machine);
+    let at = self.ready.partition_point(|r| *r < t);
+    self.ready.insert(at, t);
+  }
+
+  // Continues with one of the threads that can run, picked like the
+  // runtime does
+  fn schedule(&mut self) -> Interp<Machine<'e, Ann>> {
+    if self.ready.is_empty() {
+      return Err(InterpErr::Deadlock);
+    }
+    let pick = (self.next_random() % self.ready.len() as u64) as usize;
+    self.current = self.ready.remove(pick);
+    match std::mem::replace(&mut self.threads[self.current], Thread::Running) {
+      Thread::Runnable(machine) => Ok(machine),
+      _ => unreachable!("only picked a runnable thread"),
+    }
+  }
+
+  fn alloc_var(&mut self, v: SnakeVal) -> Interp<usize> {
+    self.alloc_cells(1)?;
+    self.heap.vars.push(v);
+    Ok(self.heap.vars.len() - 1)
+  }
+
+  fn alloc_array(&mut self, vs: Vec<SnakeVal>) -> Interp<usize> {
+    self.alloc_cells(vs.len() + 1)?;
+    let ptr = self.heap.arrays.len();
+    self.heap.arrays.push(vs);
+    Ok(ptr)
   }
 }
 
@@ -192,10 +943,26 @@ fn get<'l, T>(stk: &'l List<(String, T)>, x: &str) -> Option<&'l T> {
   }
 }
 
+#[derive(Debug, Clone, Copy)]
+enum Binding {
+  Val(SnakeVal),
+  // a variable bound by `var`, by its index in the heap's variables
+  Var(usize),
+}
+
+impl Binding {
+  fn get(&self, heap: &Heap) -> SnakeVal {
+    match self {
+      Binding::Val(v) => *v,
+      Binding::Var(cell) => heap.vars[*cell],
+    }
+  }
+}
+
 // An environment is implemented as a ref-counted linked list to
 // enable sharing/avoid copying
 #[derive(Debug, Clone)]
-struct Env(Rc<List<(String, SnakeVal)>>);
+pub(crate) struct Env(Rc<List<(String, Binding)>>);
 
 impl Env {
   fn new() -> Env {
@@ -203,40 +970,68 @@ impl Env {
   }
 
   fn push_local(&self, name: String, v: SnakeVal) -> Env {
-    Env(Rc::new(List::Cons((name, v), self.0.clone())))
+    Env(Rc::new(List::Cons((name, Binding::Val(v)), self.0.clone())))
+  }
+
+  fn push_var(&self, name: String, cell: usize) -> Env {
+    Env(Rc::new(List::Cons((name, Binding::Var(cell)), self.0.clone())))
+  }
+
+  fn lookup(&self, x: &str, heap: &Heap) -> Option<SnakeVal> {
+    get(&self.0, x).map(|b| b.get(heap))
+  }
+
+  // The cell of the variable `x` bound by `var`
+  fn cell(&self, x: &str) -> Option<usize> {
+    match get(&self.0, x) {
+      Some(Binding::Var(cell)) => Some(*cell),
+      _ => None,
+    }
   }
 
-  fn lookup<'l>(&'l self, x: &str) -> Option<&'l SnakeVal> {
-    get(&self.0, x)
+  // The visible bindings, innermost first
+  pub(crate) fn bindings(&self, heap: &Heap) -> Vec<(&str, SnakeVal)> {
+    let mut seen = HashSet::new();
+    let mut bindings = Vec::new();
+    let mut l = &*self.0;
+    while let List::Cons((x, b), rest) = l {
+      if seen.insert(x.as_str()) {
+        bindings.push((x.as_str(), b.get(heap)));
+      }
+      l = rest;
+    }
+    bindings
   }
 }
 
 #[derive(Debug, Clone)]
-struct Closure<'exp, Ann> {
-  exp: &'exp Exp<Ann>,
-  env: Env,
+pub(crate) struct Closure<'exp, Ann> {
+  pub(crate) exp: &'exp Exp<Ann>,
+  pub(crate) env: Env,
 }
 
 #[derive(Debug, Clone)]
 pub enum InterpErr {
   ExpectedNum {
     who: String,
-    got: String,
+    got: SnakeVal,
     msg: String,
   },
   ExpectedBool {
     who: String,
-    got: String,
+    got: SnakeVal,
     msg: String,
   },
   ExpectedFun {
-    got: String,
+    got: SnakeVal,
   },
   ExpectedArray {
     msg: String,
     got: SnakeVal,
   },
-  ArrayOutOfBounds {},
+  ArrayOutOfBounds {
+    index: i64,
+  },
   Overflow {
     msg: String,
   },
@@ -247,6 +1042,34 @@ pub enum InterpErr {
     expected_arity: usize,
     num_provided: usize,
   },
+  OutOfFuel {
+    steps: u64,
+  },
+  OutOfMemory {
+    max_cells: usize,
+  },
+  OutOfStack {
+    max_depth: usize,
+  },
+  // a value raised by `raise` that no `try` handled
+  Uncaught {
+    v: SnakeVal,
+    printed: String,
+  },
+  // resuming a generator that has finished or is running
+  GeneratorDone,
+  // `send` or `recv` with something that isn't a channel
+  NotChannel {
+    got: SnakeVal,
+  },
+  // every thread is waiting in a `recv`
+  Deadlock,
+  // a field access or update of a value without the field, printed as the
+  // runtime prints it
+  NoField {
+    got: SnakeVal,
+    printed: String,
+  },
 }
 
 type Interp<T> = Result<T, InterpErr>;
@@ -272,7 +1095,7 @@ impl Display for InterpErr {
         write!(f, "Expected an array but got {} in {}", got, msg)
       }
       InterpErr::Overflow { msg } => write!(f, "Operation {} overflowed", msg),
-      InterpErr::ArrayOutOfBounds {} => write!(f, "Array index out of bounds"),
+      InterpErr::ArrayOutOfBounds { .. } => write!(f, "Array index out of bounds"),
       InterpErr::ArityErr {
         expected_arity,
         num_provided,
@@ -283,16 +1106,90 @@ impl Display for InterpErr {
           expected_arity, num_provided
         )
       }
+      InterpErr::OutOfFuel { steps } => write!(f, "Ran out of fuel after {} steps", steps),
+      InterpErr::OutOfMemory { max_cells } => {
+        write!(f, "Ran out of memory: the heap is limited to {} cells", max_cells)
+      }
+      InterpErr::OutOfStack { max_depth } => {
+        write!(f, "Ran out of stack: the stack is limited to {} frames", max_depth)
+      }
+      InterpErr::Uncaught { printed, .. } => write!(f, "Uncaught exception {}", printed),
+      InterpErr::GeneratorDone => write!(f, "Called a generator that is finished or running"),
+      InterpErr::NotChannel { got } => write!(f, "Expected a channel, but got {}", got),
+      InterpErr::Deadlock => write!(f, "Deadlock: every thread is waiting to receive from a channel"),
+      InterpErr::NoField { printed, .. } => write!(
+        f,
+        "Field access expected a value of a type with the field, but got {}",
+        printed
+      ),
     }
   }
 }
 
+impl InterpErr {
+  // The builtin error type a `try` catches this error as, with its fields,
+  // or None for the errors that always stop the program
+  fn builtin_error(&self) -> Option<(&'static str, Vec<SnakeVal>)> {
+    let error = match self {
+      InterpErr::ExpectedNum { who, got, .. } => {
+        let name = match who.as_str() {
+          "arithmetic" => "ArithError",
+          "comparison" => "ComparisonError",
+          "for" => "ForError",
+          _ => "IndexNotNumber",
+        };
+        (name, vec![*got])
+      }
+      InterpErr::ExpectedBool { who, got, .. } => {
+        let name = match who.as_str() {
+          "if" => "IfError",
+          "while" => "WhileError",
+          _ => "LogicError",
+        };
+        (name, vec![*got])
+      }
+      InterpErr::ExpectedFun { got } => ("CalledNonFunction", vec![*got]),
+      InterpErr::ExpectedArray { msg, got } if msg == "length" => ("LengthNonArray", vec![*got]),
+      InterpErr::ExpectedArray { got, .. } => ("NotArray", vec![*got]),
+      InterpErr::ArrayOutOfBounds { index } => ("IndexOutOfBounds", vec![SnakeVal::Num(*index)]),
+      InterpErr::Overflow { .. } => ("Overflow", vec![]),
+      InterpErr::GeneratorDone => ("GeneratorDone", vec![]),
+      InterpErr::NotChannel { got } => ("NotChannel", vec![*got]),
+      InterpErr::NoField { got, .. } => ("FieldError", vec![*got]),
+      InterpErr::ArityErr {
+        expected_arity,
+        num_provided,
+      } => (
+        "WrongArity",
+        vec![
+          SnakeVal::Num(*expected_arity as i64),
+          SnakeVal::Num(*num_provided as i64),
+        ],
+      ),
+      InterpErr::Write { .. }
+      | InterpErr::OutOfFuel { .. }
+      | InterpErr::OutOfMemory { .. }
+      | InterpErr::OutOfStack { .. }
+      | InterpErr::Uncaught { .. }
+      | InterpErr::Deadlock => return None,
+    };
+    Some(error)
+  }
+}
+
+fn no_field(v: SnakeVal, h: &Heap) -> InterpErr {
+  InterpErr::NoField {
+    got: v,
+    printed: sprint_snake_val(v, h),
+  }
+}
+
 fn prj_bool(v: SnakeVal, who: &str, msg: &str) -> Interp<bool> {
   match v {
     SnakeVal::Bool(b) => Ok(b),
     _ => Err(InterpErr::ExpectedBool {
       who: String::from(who),
-      got: v.to_string(),
+      got: v,
       msg: String::from(msg),
     }),
   }
@@ -303,7 +1200,7 @@ fn prj_num(v: SnakeVal, who: &str, msg: &str) -> Interp<i64> {
     SnakeVal::Num(n) => Ok(n),
     _ => Err(InterpErr::ExpectedNum {
       who: String::from(who),
-      got: v.to_string(),
+      got: v,
       msg: String::from(msg),
     }),
   }
@@ -312,7 +1209,7 @@ fn prj_num(v: SnakeVal, who: &str, msg: &str) -> Interp<i64> {
 fn prj_fun(v: SnakeVal) -> Interp<usize> {
   match v {
     SnakeVal::Closure(b) => Ok(b),
-    _ => Err(InterpErr::ExpectedFun { got: v.to_string() }),
+    _ => Err(InterpErr::ExpectedFun { got: v }),
   }
 }
 
@@ -351,7 +1248,7 @@ fn prj_array_mut<'heap>(
 
 fn valid_index(n: i64) -> Interp<usize> {
   match TryInto::<usize>::try_into(n) {
-    Err(_) => Err(InterpErr::ArrayOutOfBounds {}),
+    Err(_) => Err(InterpErr::ArrayOutOfBounds { index: n }),
     Ok(ix) => Ok(ix),
   }
 }
@@ -380,7 +1277,7 @@ where
           write!(w, "<loop>")?
         } else {
           parents.insert(*ptr);
-          let vs = &h[*ptr];
+          let vs = &h.arrays[*ptr];
           write!(w, "[")?;
           if !vs.is_empty() {
             print_loop(w, &vs[0], h, parents.clone())?;
@@ -395,6 +1292,24 @@ where
       SnakeVal::Closure { .. } => {
         write!(w, "<closure>")?;
       }
+      SnakeVal::Custom {
+        tag: CHANNEL_TAG, ..
+      } => {
+        write!(w, "<channel>")?;
+      }
+      SnakeVal::Custom { tag, fields } => {
+        write!(w, "{}", h.type_name(*tag))?;
+        let vs = &h.arrays[*fields];
+        if !vs.is_empty() {
+          write!(w, "(")?;
+          print_loop(w, &vs[0], h, parents.clone())?;
+          for v in &vs[1..] {
+            write!(w, ", ")?;
+            print_loop(w, v, h, parents.clone())?;
+          }
+          write!(w, ")")?;
+        }
+      }
     }
     Ok(())
   }
@@ -404,6 +1319,14 @@ where
   Ok(v)
 }
 
+// The way `print` shows `v`, without the trailing newline
+pub(crate) fn sprint_snake_val(v: SnakeVal, h: &Heap) -> String {
+  let mut buf = Vec::new();
+  print_snake_val(&mut buf, v, h).expect("internal interp error: printing to a Vec failed");
+  buf.pop();
+  String::from_utf8(buf).expect("internal interp error: printed invalid UTF-8")
+}
+
 fn interpret_prim1<W>(p: &Prim1, w: &mut W, v: SnakeVal, h: &Heap) -> Interp<SnakeVal>
 where
   W: std::io::Write,
@@ -433,6 +1356,10 @@ where
       SnakeVal::Closure(_) => Ok(SnakeVal::Bool(true)),
       _ => Ok(SnakeVal::Bool(false)),
     },
+    Prim1::CallCC => unreachable!("the machine calls the argument of callcc"),
+    Prim1::Yield | Prim1::Generator => unreachable!("the machine switches generators"),
+    Prim1::NoField => Err(no_field(v, h)),
+    Prim1::Spawn | Prim1::Recv => unreachable!("the machine switches threads"),
   }
 }
 
@@ -494,429 +1421,1249 @@ where
     Prim2::Gt => snake_cmp(v1, v2, |n1, n2| n1 > n2, ">"),
     Prim2::Ge => snake_cmp(v1, v2, |n1, n2| n1 >= n2, ">="),
 
+    Prim2::Send => unreachable!("the machine switches threads"),
     Prim2::Eq => Ok(SnakeVal::Bool(v1 == v2)),
     Prim2::Neq => Ok(SnakeVal::Bool(v1 != v2)),
     Prim2::ArrayGet => {
       let vs = prj_array(v1, "array index", heap)?;
-      let n = valid_index(prj_num(v2, "index", "")?)?;
-      match vs.get(n) {
-        None => Err(InterpErr::ArrayOutOfBounds {}),
+      let index = prj_num(v2, "index", "")?;
+      match vs.get(valid_index(index)?) {
+        None => Err(InterpErr::ArrayOutOfBounds { index }),
         Some(v) => Ok(*v),
       }
     }
   }
 }
 
-/*
- *  Abstract machine-style interpreter.
- *
- *  Defunctionalizes the kontinuation of the direct-style interpreter
- *  so that we don't blow the Rust stack/rely on Rust TCE.
- *
-*/
-fn machine<'exp, Ann, W>(e: &'exp Exp<Ann>, buf: &mut W, store: &mut State<'exp, Ann>) -> Interp<()>
+// What happened during one transition of the machine
+pub(crate) enum Event {
+  Step,
+  Call { fun: usize, args: Vec<SnakeVal> },
+  // element `index` of the array at `array` was overwritten
+  ArraySet {
+    array: usize,
+    index: usize,
+    old: SnakeVal,
+    new: SnakeVal,
+  },
+  Done,
+}
+
+fn call<'exp, Ann>(
+  fun_ptr: usize,
+  args: Vec<SnakeVal>,
+  stk: Stack<'exp, Ann>,
+  store: &mut State<'exp, Ann>,
+) -> Interp<Machine<'exp, Ann>>
 where
-  W: std::io::Write,
   Ann: Clone,
 {
-  fn call<'exp, Ann>(
-    fun_ptr: usize,
-    args: Vec<SnakeVal>,
-    stk: Stack<'exp, Ann>,
-    store: &State<'exp, Ann>,
-  ) -> Interp<Machine<'exp, Ann>>
-  where
-    Ann: Clone,
-  {
-    let fun = &store.funs[fun_ptr];
-    let mut env = fun.closure.env.clone();
-
-    if args.len() != fun.parameters.len() {
-      return Err(InterpErr::ArityErr {
-        expected_arity: fun.parameters.len(),
-        num_provided: args.len(),
+  let arity = store.arity(fun_ptr);
+  if args.len() != arity {
+    let err = InterpErr::ArityErr {
+      expected_arity: arity,
+      num_provided: args.len(),
+    };
+    return throw(err, stk, store);
+  }
+  let (parameters, closure, generator) = match &mut store.funs[fun_ptr] {
+    SemFun::Fun {
+      parameters,
+      closure,
+      generator,
+      ..
+    } => (*parameters, closure, *generator),
+    // the rest of the program is dropped for the one `callcc` was called in
+    SemFun::Cont(k) => {
+      return Ok(Machine::Returning {
+        v: args[0],
+        stk: k.clone(),
+      })
+    }
+    SemFun::Gen(state) => {
+      let resumed = match state {
+        GenState::Start(_) | GenState::Suspended(_) => std::mem::replace(state, GenState::Running(stk)),
+        GenState::Running(_) | GenState::Done => return throw(InterpErr::GeneratorDone, stk, store),
+      };
+      return Ok(match resumed {
+        GenState::Start(closure) => Machine::Descending {
+          e: closure.exp,
+          env: closure.env,
+          stk: Stack::Generator { gen: fun_ptr },
+        },
+        // the `yield` it is suspended at evaluates to false
+        GenState::Suspended(k) => Machine::Returning {
+          v: SnakeVal::Bool(false),
+          stk: k,
+        },
+        GenState::Running(_) | GenState::Done => unreachable!(),
       });
     }
-    // environment for the body should consist of the captured env
-    // extended with the new parameters
-    for (v, x) in args.iter().zip(fun.parameters.iter()) {
-      env = env.push_local(x.to_string(), *v)
+    SemFun::Ctor { tag, .. } => {
+      let tag = *tag;
+      let fields = store.alloc_array(args)?;
+      store.alloc_cells(1)?;
+      return Ok(Machine::Returning {
+        v: SnakeVal::Custom { tag, fields },
+        stk,
+      });
     }
-    Ok(Machine::Descending {
-      e: fun.closure.exp,
-      env,
+  };
+  // environment for the body should consist of the captured env
+  // extended with the new parameters
+  let mut env = closure.env.clone();
+  for (v, x) in args.iter().zip(parameters.iter()) {
+    env = env.push_local(x.to_string(), *v)
+  }
+  let exp = closure.exp;
+  if generator {
+    let gen = store.alloc_gen(Closure { exp, env })?;
+    return Ok(Machine::Returning {
+      v: SnakeVal::Closure(gen),
       stk,
-    })
+    });
   }
+  Ok(Machine::Descending { e: exp, env, stk })
+}
+
+// Sets element `index` of `array` to `v`, returning which array it is and
+// the element's old value
+fn array_set(array: SnakeVal, index: SnakeVal, v: SnakeVal, heap: &mut Heap) -> Interp<(usize, usize, SnakeVal)> {
+  let arr = prj_array_mut(array, "array set", heap)?;
+  let index = prj_num(index, "array set", "")?;
+  let loc = valid_index(index)
+    .ok()
+    .and_then(|i| arr.get_mut(i))
+    .ok_or(InterpErr::ArrayOutOfBounds { index })?;
+  let old = std::mem::replace(loc, v);
+  let ptr = match array {
+    SnakeVal::Array(ptr) => ptr,
+    _ => unreachable!("array set already checked for an array"),
+  };
+  Ok((ptr, index as usize, old))
+}
 
-  let mut machine = Machine::Descending {
-    e,
-    stk: Stack::Done,
-    env: Env::new(),
+// The environment an arm of a `try` runs in, if it matches the raised `v`
+fn match_arm(snake_type: &SnakeType, args: &[String], v: SnakeVal, heap: &Heap, env: &Env) -> Option<Env> {
+  let fields = match (snake_type, v) {
+    (SnakeType::Num, SnakeVal::Num(_))
+    | (SnakeType::Bool, SnakeVal::Bool(_))
+    | (SnakeType::Array, SnakeVal::Array(_))
+    | (SnakeType::Func, SnakeVal::Closure(_)) => vec![v],
+    (SnakeType::Custom(name), SnakeVal::Custom { tag, fields }) if heap.type_name(tag) == name => {
+      heap.arrays[fields].clone()
+    }
+    // the arguments are bound to the fields named in the arm
+    (SnakeType::CustomFields(name, names), SnakeVal::Custom { tag, fields }) if heap.type_name(tag) == name => {
+      let ixs = names.iter().map(|field| heap.field_index(tag, field));
+      ixs.map(|i| i.map(|i| heap.arrays[fields][i])).collect::<Option<_>>()?
+    }
+    _ => return None,
   };
+  Some(
+    args
+      .iter()
+      .zip(fields)
+      .fold(env.clone(), |env, (x, v)| env.push_local(x.clone(), v)),
+  )
+}
+
+// The array of the fields of `v` and the index of its field named `field`,
+// if it is a value of a custom type with that field
+fn custom_field(v: SnakeVal, field: &str, heap: &Heap) -> Option<(usize, usize)> {
+  match v {
+    SnakeVal::Custom { tag, fields } => Some((fields, heap.field_index(tag, field)?)),
+    _ => None,
+  }
+}
+
+// The generator whose stack `stk` is
+fn generator_of<Ann: Clone>(mut stk: &Stack<Ann>) -> usize {
   loop {
-    match machine {
-      Machine::Descending { e, stk, env } => match e {
-        Exp::Num(n, _) => {
-          machine = Machine::Returning {
-            v: SnakeVal::Num(*n),
-            stk,
-          }
-        }
-        Exp::Bool(b, _) => {
-          machine = Machine::Returning {
-            v: SnakeVal::Bool(*b),
-            stk,
+    match stk {
+      Stack::Generator { gen } => return *gen,
+      _ => stk = stk.parent().expect("only a generator yields"),
+    }
+  }
+}
+
+// Marks the generator `gen` as finished, returning the stack of the code
+// that resumed it
+fn finish_generator<'exp, Ann: Clone>(gen: usize, store: &mut State<'exp, Ann>) -> Interp<Stack<'exp, Ann>> {
+  match std::mem::replace(&mut store.funs[gen], SemFun::Gen(GenState::Done)) {
+    SemFun::Gen(GenState::Running(caller)) => Ok(caller),
+    // a continuation returned to a generator that isn't running
+    _ => Err(InterpErr::GeneratorDone),
+  }
+}
+
+// Continues with the handler of the innermost `try` in `stk` that handles
+// `err`, or stops with `err` if there is none. Runtime errors are raised as
+// values of the builtin error types.
+fn throw<'exp, Ann>(
+  mut err: InterpErr,
+  mut stk: Stack<'exp, Ann>,
+  store: &mut State<'exp, Ann>,
+) -> Interp<Machine<'exp, Ann>>
+where
+  Ann: Clone,
+{
+  let mut raised = match &err {
+    InterpErr::Uncaught { v, .. } => Some(*v),
+    _ if err.builtin_error().is_none() => return Err(err),
+    _ => None,
+  };
+  loop {
+    let (try_exp, env, parent) = match stk {
+      Stack::Done | Stack::Thread => return Err(err),
+      // raised again in the code that resumed the generator
+      Stack::Generator { gen } => {
+        stk = finish_generator(gen, store)?;
+        continue;
+      }
+      Stack::Try { try_exp, env, stk } => (try_exp, env, stk),
+      stk_ => {
+        stk = stk_.into_parent().unwrap();
+        continue;
+      }
+    };
+    // the value of a runtime error is only made once something handles it
+    let v = match raised {
+      Some(v) => v,
+      None => {
+        let (name, fields) = err.builtin_error().unwrap();
+        let fields = store.alloc_array(fields)?;
+        store.alloc_cells(1)?;
+        let v = SnakeVal::Custom {
+          tag: error_tag(name).unwrap(),
+          fields,
+        };
+        raised = Some(v);
+        v
+      }
+    };
+    match try_exp {
+      // a resolved `try` decides what it handles in its handler
+      Exp::Catch { var, handler, .. } => {
+        return Ok(Machine::Descending {
+          e: handler,
+          env: env.push_local(var.clone(), v),
+          stk: parent.into_stack(),
+        })
+      }
+      Exp::Try { arms, .. } => {
+        for (snake_type, args, arm) in arms {
+          if let Some(env) = match_arm(snake_type, args, v, &store.heap, &env) {
+            return Ok(Machine::Descending {
+              e: arm,
+              env,
+              stk: parent.into_stack(),
+            });
           }
         }
-        Exp::Var(x, _) => {
-          let v = env.lookup(x).expect(
-            "Unbound variable in interpreter! You should catch this in the check function!",
-          );
-          machine = Machine::Returning { v: *v, stk }
-        }
-        Exp::Prim1(op, e, _) => {
-          machine = Machine::Descending {
-            e,
-            stk: Stack::Prim1(*op, Box::new(stk)),
-            env,
-          };
+        // raised again, as the handler of a resolved `try` does
+        err = InterpErr::Uncaught {
+          v,
+          printed: sprint_snake_val(v, &store.heap),
+        };
+        stk = parent.into_stack();
+      }
+      _ => unreachable!("only a `try` handles raised values"),
+    }
+  }
+}
+
+// Runs the body of a `for` loop with its variable bound to `i`, or finishes
+// the loop once `i` is past `end`
+fn for_iteration<'exp, Ann>(
+  var: &'exp str,
+  i: i64,
+  end: i64,
+  body: &'exp Exp<Ann>,
+  env: Env,
+  stk: Stack<'exp, Ann>,
+) -> Machine<'exp, Ann>
+where
+  Ann: Clone,
+{
+  if i > end {
+    return Machine::Returning {
+      v: SnakeVal::Bool(false),
+      stk,
+    };
+  }
+  Machine::Descending {
+    e: body,
+    env: env.push_local(var.to_string(), SnakeVal::Num(i)),
+    stk: Stack::ForBody {
+      var,
+      i,
+      end,
+      body,
+      env,
+      stk: Parent::new(stk),
+    },
+  }
+}
+
+/*
+ *  Abstract machine-style interpreter.
+ *
+ *  Defunctionalizes the kontinuation of the direct-style interpreter
+ *  so that we don't blow the Rust stack/rely on Rust TCE.
+ *
+ *  `step` performs a single transition, returning None once the
+ *  program has finished.
+*/
+fn step<'exp, Ann, W>(
+  machine: Machine<'exp, Ann>,
+  buf: &mut W,
+  store: &mut State<'exp, Ann>,
+) -> Interp<(Option<Machine<'exp, Ann>>, Event)>
+where
+  W: std::io::Write,
+  Ann: Clone,
+{
+  let next_machine;
+  let mut event = Event::Step;
+  match machine {
+    Machine::Descending { e, stk, env } => match e {
+      Exp::Num(n, _) => {
+        next_machine = Machine::Returning {
+          v: SnakeVal::Num(*n),
+          stk,
         }
-        Exp::Prim2(op, e1, e2, _) => {
-          machine = Machine::Descending {
-            e: e1,
-            stk: Stack::Prim2L(
-              *op,
-              Closure {
-                exp: e2,
-                env: env.clone(),
-              },
-              Box::new(stk),
-            ),
-            env,
-          };
+      }
+      Exp::Bool(b, _) => {
+        next_machine = Machine::Returning {
+          v: SnakeVal::Bool(*b),
+          stk,
         }
-        Exp::Let { bindings, body, .. } => {
-          let mut rbindings: Vec<&(String, Exp<Ann>)> = bindings.iter().rev().collect();
-          match rbindings.pop() {
-            None => {
-              machine = Machine::Descending { e: body, stk, env };
-            }
-            Some((var, e)) => {
-              machine = Machine::Descending {
-                e,
-                stk: Stack::Let {
-                  var,
-                  env: env.clone(),
-                  bindings: rbindings,
-                  body,
-                  stk: Box::new(stk),
-                },
-                env,
-              };
-            }
-          }
+      }
+      Exp::Var(x, _) => {
+        let v = env.lookup(x, &store.heap).expect(
+          "Unbound variable in interpreter! You should catch this in the check function!",
+        );
+        next_machine = Machine::Returning { v, stk }
+      }
+      Exp::Prim0(Prim0::Chan, _) => {
+        let fields = store.alloc_array(vec![SnakeVal::Num(store.channels.len() as i64)])?;
+        store.channels.push(Channel::default());
+        next_machine = Machine::Returning {
+          v: SnakeVal::Custom {
+            tag: CHANNEL_TAG,
+            fields,
+          },
+          stk,
         }
-        Exp::If { cond, thn, els, .. } => {
-          machine = Machine::Descending {
-            e: cond,
-            stk: Stack::If {
-              thn,
-              els,
+      }
+      Exp::Prim1(op, e, _) => {
+        next_machine = Machine::Descending {
+          e,
+          stk: Stack::Prim1(*op, Parent::new(stk)),
+          env,
+        };
+      }
+      Exp::Prim2(op, e1, e2, _) => {
+        next_machine = Machine::Descending {
+          e: e1,
+          stk: Stack::Prim2L(
+            *op,
+            Closure {
+              exp: e2,
               env: env.clone(),
-              stk: Box::new(stk),
             },
-            env,
+            Parent::new(stk),
+          ),
+          env,
+        };
+      }
+      Exp::Let { bindings, body, .. } => {
+        let mut rbindings: Vec<&(String, Exp<Ann>)> = bindings.iter().rev().collect();
+        match rbindings.pop() {
+          None => {
+            next_machine = Machine::Descending { e: body, stk, env };
           }
-        }
-
-        Exp::Array(es, _) => {
-          let mut r_es: Vec<&Exp<_>> = es.iter().rev().collect();
-          machine = match r_es.pop() {
-            None => {
-              let ptr = store.alloc_array(vec![]);
-              Machine::Returning {
-                v: SnakeVal::Array(ptr),
-                stk,
-              }
-            }
-            Some(e) => Machine::Descending {
+          Some((var, e)) => {
+            next_machine = Machine::Descending {
               e,
-              stk: Stack::Array {
-                evaled_parts: Vec::new(),
+              stk: Stack::Let {
+                var,
                 env: env.clone(),
-                remaining_parts: r_es,
-                stk: Box::new(stk),
+                bindings: rbindings,
+                body,
+                stk: Parent::new(stk),
               },
               env,
-            },
+            };
           }
         }
-        Exp::ArraySet {
-          array,
-          index,
-          new_value,
-          ..
-        } => {
-          machine = Machine::Descending {
-            e: array,
-            env: env.clone(),
-            stk: Stack::ArraySet1 {
-              index,
-              new_value,
+      }
+      Exp::LetVar { bindings, body, .. } => {
+        let mut rbindings: Vec<&(String, Exp<Ann>)> = bindings.iter().rev().collect();
+        match rbindings.pop() {
+          None => {
+            next_machine = Machine::Descending { e: body, stk, env };
+          }
+          Some((var, e)) => {
+            next_machine = Machine::Descending {
+              e,
+              stk: Stack::LetVar {
+                var,
+                env: env.clone(),
+                bindings: rbindings,
+                body,
+                stk: Parent::new(stk),
+              },
               env,
-              stk: Box::new(stk),
-            },
+            };
           }
         }
-        Exp::Semicolon { e1, e2, .. } => {
-          machine = Machine::Descending {
-            e: e1,
+      }
+      Exp::Assign { var, new_value, .. } => {
+        let cell = env.cell(var).expect(
+          "Assignment to a non-var in interpreter! You should catch this in the check function!",
+        );
+        next_machine = Machine::Descending {
+          e: new_value,
+          stk: Stack::Assign {
+            var,
+            cell,
+            stk: Parent::new(stk),
+          },
+          env,
+        };
+      }
+      Exp::If { cond, thn, els, .. } => {
+        next_machine = Machine::Descending {
+          e: cond,
+          stk: Stack::If {
+            thn,
+            els,
             env: env.clone(),
-            stk: Stack::Semicolon {
-              next: Closure { exp: e2, env },
-              stk: Box::new(stk),
-            },
-          }
+            stk: Parent::new(stk),
+          },
+          env,
         }
+      }
 
-        Exp::Call(fun, args, _) => {
-          machine = Machine::Descending {
-            e: fun,
-            stk: Stack::CallFun {
-              args: args.iter().collect(),
+      Exp::Array(es, _) => {
+        let mut r_es: Vec<&Exp<_>> = es.iter().rev().collect();
+        next_machine = match r_es.pop() {
+          None => {
+            let ptr = store.alloc_array(vec![])?;
+            Machine::Returning {
+              v: SnakeVal::Array(ptr),
+              stk,
+            }
+          }
+          Some(e) => Machine::Descending {
+            e,
+            stk: Stack::Array {
+              evaled_parts: Vec::new(),
               env: env.clone(),
-              stk: Box::new(stk),
+              remaining_parts: r_es,
+              stk: Parent::new(stk),
             },
             env,
-          }
+          },
         }
-        Exp::FunDefs { decls, body, .. } => {
-          let env = store.alloc_funs(decls, env);
-          machine = Machine::Descending {
-            e: body,
+      }
+      Exp::ArraySet {
+        array,
+        index,
+        new_value,
+        ..
+      } => {
+        next_machine = Machine::Descending {
+          e: array,
+          env: env.clone(),
+          stk: Stack::ArraySet1 {
+            index,
+            new_value,
+            env,
+            stk: Parent::new(stk),
+          },
+        }
+      }
+      Exp::Semicolon { e1, e2, .. } => {
+        next_machine = Machine::Descending {
+          e: e1,
+          env: env.clone(),
+          stk: Stack::Semicolon {
+            next: Closure { exp: e2, env },
+            stk: Parent::new(stk),
+          },
+        }
+      }
+
+      Exp::While { cond, body, .. } => {
+        next_machine = Machine::Descending {
+          e: cond,
+          env: env.clone(),
+          stk: Stack::WhileCond {
+            cond,
+            body,
+            env,
+            stk: Parent::new(stk),
+          },
+        }
+      }
+      Exp::For {
+        var,
+        start,
+        end,
+        body,
+        ..
+      } => {
+        next_machine = Machine::Descending {
+          e: start,
+          env: env.clone(),
+          stk: Stack::ForStart {
+            var,
+            end,
+            body,
+            env,
+            stk: Parent::new(stk),
+          },
+        }
+      }
+
+      Exp::Call(fun, args, _) => {
+        next_machine = Machine::Descending {
+          e: fun,
+          stk: Stack::CallFun {
+            args: args.iter().collect(),
             env: env.clone(),
-            stk,
-          }
+            stk: Parent::new(stk),
+          },
+          env,
         }
-        Exp::Lambda {
-          parameters, body, ..
-        } => {
-          let fun_ptr = store.alloc_fun(parameters, body, env);
-          machine = Machine::Returning {
-            v: SnakeVal::Closure(fun_ptr),
-            stk,
-          }
+      }
+      Exp::FunDefs { decls, body, .. } => {
+        let env = store.alloc_funs(decls, env)?;
+        next_machine = Machine::Descending {
+          e: body,
+          env: env.clone(),
+          stk,
+        }
+      }
+      Exp::Lambda {
+        parameters, body, ..
+      } => {
+        let fun_ptr = store.alloc_fun(parameters, body, env)?;
+        next_machine = Machine::Returning {
+          v: SnakeVal::Closure(fun_ptr),
+          stk,
+        }
+      }
+      Exp::MakeClosure { .. } => {
+        panic!("Shouldn't happen: Interpreter encountered internal form MakeClosure or CallClosure or AssertSize")
+      }
+      // the forms custom types are resolved to
+      Exp::MakeTypeInstance {
+        typetag, fields, ..
+      } => {
+        next_machine = Machine::Descending {
+          e: fields,
+          env,
+          stk: Stack::MakeTypeInstance {
+            typetag: *typetag,
+            stk: Parent::new(stk),
+          },
         }
-        Exp::MakeClosure { .. } => {
-          panic!("Shouldn't happen: Interpreter encountered internal form MakeClosure or CallClosure or AssertSize")
+      }
+      Exp::MatchType { expr, typetag, .. } => {
+        next_machine = Machine::Descending {
+          e: expr,
+          env,
+          stk: Stack::MatchType {
+            typetag: *typetag,
+            stk: Parent::new(stk),
+          },
         }
-        _ => panic!("NYI"),
-      },
-      Machine::Returning { v, stk } => match stk {
-        Stack::Done => {
-          print_snake_val(buf, v, &store.heap)?;
-          return Ok(());
-        }
-        Stack::Prim1(op, stk) => {
-          let v = interpret_prim1(&op, buf, v, &store.heap)?;
-          machine = Machine::Returning { v, stk: *stk }
-        }
-        Stack::Prim2L(op, r, stk) => {
-          machine = Machine::Descending {
-            e: r.exp,
-            env: r.env,
-            stk: Stack::Prim2R(op, v, stk),
-          };
+      }
+      Exp::GetTypeFields(e, _) => {
+        next_machine = Machine::Descending {
+          e,
+          env,
+          stk: Stack::GetTypeFields(Parent::new(stk)),
         }
-        Stack::Prim2R(op, vl, stk) => {
-          let v = interpret_prim2(&op, vl, v, &store.heap)?;
-          machine = Machine::Returning { v, stk: *stk };
+      }
+      Exp::Modules { modules, body, .. } => {
+        store.load_modules(modules, &used_names(e))?;
+        next_machine = Machine::Descending { e: body, env, stk }
+      }
+      Exp::Import { module, body, .. } => {
+        next_machine = Machine::Descending {
+          e: body,
+          env: store.import(*module, env),
+          stk,
+        }
+      }
+      Exp::Raise(e, _) => {
+        next_machine = Machine::Descending {
+          e,
+          env,
+          stk: Stack::Raise(Parent::new(stk)),
         }
-        Stack::Let {
-          var,
-          mut env,
-          mut bindings,
-          body,
+      }
+      Exp::Try { body, .. } | Exp::Catch { body, .. } => {
+        next_machine = Machine::Descending {
+          e: body,
+          env: env.clone(),
+          stk: Stack::Try {
+            try_exp: e,
+            env,
+            stk: Parent::new(stk),
+          },
+        }
+      }
+      Exp::TypeDefs { decls, body, .. } => {
+        next_machine = Machine::Descending {
+          e: body,
+          env: store.alloc_types(decls, None, env)?,
           stk,
-        } => {
-          env = env.push_local(var.to_string(), v);
-          machine = match bindings.pop() {
-            None => Machine::Descending {
-              e: body,
-              env,
-              stk: *stk,
-            },
-            Some((var, e)) => Machine::Descending {
-              e,
-              stk: Stack::Let {
-                var,
-                env: env.clone(),
-                bindings,
-                body,
-                stk,
-              },
+        }
+      }
+      Exp::Match {
+        expr, default, arms, ..
+      } => {
+        next_machine = Machine::Descending {
+          e: expr,
+          env: env.clone(),
+          stk: Stack::Match {
+            default,
+            arms,
+            env,
+            stk: Parent::new(stk),
+          },
+        }
+      }
+      Exp::GetField { exp, field, .. } => {
+        next_machine = Machine::Descending {
+          e: exp,
+          env,
+          stk: Stack::GetField {
+            field,
+            stk: Parent::new(stk),
+          },
+        }
+      }
+      Exp::UpdateField {
+        exp,
+        field,
+        new_value,
+        ..
+      } => {
+        next_machine = Machine::Descending {
+          e: exp,
+          env: env.clone(),
+          stk: Stack::UpdateField1 {
+            field,
+            new_value: Closure {
+              exp: new_value,
               env,
             },
-          }
+            stk: Parent::new(stk),
+          },
         }
-
-        Stack::If { thn, els, env, stk } => {
-          let e = if prj_bool(v, "if", "if")? { thn } else { els };
-          machine = Machine::Descending { e, env, stk: *stk }
+      }
+    },
+    Machine::Returning { v, stk } => match stk {
+      Stack::Done => {
+        print_snake_val(buf, v, &store.heap)?;
+        return Ok((None, Event::Done));
+      }
+      // `f` is called with a closure that continues with `stk`
+      Stack::Prim1(Prim1::CallCC, stk) => match prj_fun(v) {
+        Ok(fun) => {
+          let k = SnakeVal::Closure(store.alloc_cont((*stk).clone())?);
+          event = store.call_event(fun, &[k]);
+          next_machine = call(fun, vec![k], stk.into_stack(), store)?;
         }
-        Stack::CallArgs {
-          fun: fun_v,
-          mut evaled_args,
-          env,
-          mut remaining_args,
-          stk,
-        } => {
-          evaled_args.push(v);
-          match remaining_args.pop() {
-            None => {
-              machine = call(prj_fun(fun_v)?, evaled_args, *stk, store)?;
-            }
-            Some(e) => {
-              machine = Machine::Descending {
-                e,
-                env: env.clone(),
-                stk: Stack::CallArgs {
-                  fun: fun_v,
-                  evaled_args,
-                  env,
-                  remaining_args,
-                  stk,
+        Err(err) => next_machine = throw(err, stk.into_stack(), store)?,
+      },
+      // back to the code that resumed the generator, which resumes the
+      // `yield` with `stk`
+      Stack::Prim1(Prim1::Yield, stk) => {
+        let gen = generator_of(&stk);
+        match std::mem::replace(&mut store.funs[gen], SemFun::Gen(GenState::Suspended(stk.into_stack()))) {
+          SemFun::Gen(GenState::Running(caller)) => next_machine = Machine::Returning { v, stk: caller },
+          _ => return Err(InterpErr::GeneratorDone),
+        }
+      }
+      Stack::Prim1(Prim1::Generator, stk) => {
+        let closure = match &store.funs[prj_fun(v).expect("a generator is made from a lambda")] {
+          SemFun::Fun { closure, .. } => closure.clone(),
+          _ => unreachable!("a generator is made from a lambda"),
+        };
+        let gen = store.alloc_gen(closure)?;
+        next_machine = Machine::Returning {
+          v: SnakeVal::Closure(gen),
+          stk: stk.into_stack(),
+        };
+      }
+      // the thread calls `f` with no arguments, once the scheduler picks it
+      Stack::Prim1(Prim1::Spawn, stk) => {
+        let spawned = prj_fun(v).and_then(|fun| match store.arity(fun) {
+          0 => Ok(fun),
+          arity => Err(InterpErr::ArityErr {
+            expected_arity: arity,
+            num_provided: 0,
+          }),
+        });
+        next_machine = match spawned {
+          Ok(_) => {
+            let thread = store.threads.len();
+            store.threads.push(Thread::Finished);
+            store.make_runnable(
+              thread,
+              Machine::Returning {
+                v,
+                stk: Stack::CallFun {
+                  env: Env::new(),
+                  args: vec![],
+                  stk: Parent::new(Stack::Thread),
                 },
-              }
+              },
+            );
+            Machine::Returning {
+              v: SnakeVal::Num(thread as i64),
+              stk: stk.into_stack(),
             }
           }
+          Err(err) => throw(err, stk.into_stack(), store)?,
         }
-        Stack::CallFun { env, args, stk } => {
-          let mut remaining_args = args;
-          remaining_args.reverse();
-          match remaining_args.pop() {
+      }
+      // waits for a `send` if nothing was sent yet, letting another thread
+      // run
+      Stack::Prim1(Prim1::Recv, stk) => {
+        let current = store.current;
+        next_machine = match store.channel(v) {
+          Ok(channel) => match channel.values.pop_front() {
+            Some(v) => Machine::Returning { v, stk: stk.into_stack() },
             None => {
-              machine = call(prj_fun(v)?, Vec::new(), *stk, store)?;
+              channel.waiting.push_back(current);
+              store.threads[current] = Thread::Waiting(stk.into_stack());
+              store.schedule()?
             }
-            Some(e) => {
-              machine = Machine::Descending {
-                e,
-                env: env.clone(),
-                stk: Stack::CallArgs {
-                  fun: v,
-                  evaled_args: Vec::new(),
-                  env,
-                  remaining_args,
-                  stk,
-                },
-              }
+          },
+          Err(err) => throw(err, stk.into_stack(), store)?,
+        }
+      }
+      Stack::Prim1(op, stk) => {
+        next_machine = match interpret_prim1(&op, buf, v, &store.heap) {
+          Ok(v) => Machine::Returning { v, stk: stk.into_stack() },
+          Err(err) => throw(err, stk.into_stack(), store)?,
+        }
+      }
+      Stack::Prim2L(op, r, stk) => {
+        next_machine = Machine::Descending {
+          e: r.exp,
+          env: r.env,
+          stk: Stack::Prim2R(op, v, stk),
+        };
+      }
+      // straight to the thread that has waited longest for the channel, if
+      // any, letting another thread run
+      Stack::Prim2R(Prim2::Send, c, stk) => {
+        let current = store.current;
+        next_machine = match store.channel(c) {
+          Ok(channel) => {
+            match channel.waiting.pop_front() {
+              Some(receiver) => match std::mem::replace(&mut store.threads[receiver], Thread::Finished) {
+                Thread::Waiting(stk) => store.make_runnable(receiver, Machine::Returning { v, stk }),
+                _ => unreachable!("only a waiting thread waits for a channel"),
+              },
+              None => channel.values.push_back(v),
             }
+            store.make_runnable(current, Machine::Returning { v, stk: stk.into_stack() });
+            store.schedule()?
           }
+          Err(err) => throw(err, stk.into_stack(), store)?,
         }
-        Stack::Array {
-          mut evaled_parts,
-          mut remaining_parts,
-          env,
-          stk,
-        } => {
-          evaled_parts.push(v);
-          machine = match remaining_parts.pop() {
-            None => {
-              let ptr = store.alloc_array(evaled_parts);
-              Machine::Returning {
-                v: SnakeVal::Array(ptr),
-                stk: *stk,
-              }
+      }
+      Stack::Prim2R(op, vl, stk) => {
+        next_machine = match interpret_prim2(&op, vl, v, &store.heap) {
+          Ok(v) => Machine::Returning { v, stk: stk.into_stack() },
+          Err(err) => throw(err, stk.into_stack(), store)?,
+        };
+      }
+      Stack::Let {
+        var,
+        mut env,
+        mut bindings,
+        body,
+        stk,
+      } => {
+        env = env.push_local(var.to_string(), v);
+        next_machine = match bindings.pop() {
+          None => Machine::Descending {
+            e: body,
+            env,
+            stk: stk.into_stack(),
+          },
+          Some((var, e)) => Machine::Descending {
+            e,
+            stk: Stack::Let {
+              var,
+              env: env.clone(),
+              bindings,
+              body,
+              stk,
+            },
+            env,
+          },
+        }
+      }
+      Stack::LetVar {
+        var,
+        mut env,
+        mut bindings,
+        body,
+        stk,
+      } => {
+        env = env.push_var(var.to_string(), store.alloc_var(v)?);
+        next_machine = match bindings.pop() {
+          None => Machine::Descending {
+            e: body,
+            env,
+            stk: stk.into_stack(),
+          },
+          Some((var, e)) => Machine::Descending {
+            e,
+            stk: Stack::LetVar {
+              var,
+              env: env.clone(),
+              bindings,
+              body,
+              stk,
+            },
+            env,
+          },
+        }
+      }
+      Stack::Assign { cell, stk, .. } => {
+        store.heap.vars[cell] = v;
+        next_machine = Machine::Returning { v, stk: stk.into_stack() };
+      }
+
+      Stack::If { thn, els, env, stk } => {
+        next_machine = match prj_bool(v, "if", "if") {
+          Ok(b) => Machine::Descending {
+            e: if b { thn } else { els },
+            env,
+            stk: stk.into_stack(),
+          },
+          Err(err) => throw(err, stk.into_stack(), store)?,
+        }
+      }
+      Stack::CallArgs {
+        fun: fun_v,
+        mut evaled_args,
+        env,
+        mut remaining_args,
+        stk,
+      } => {
+        evaled_args.push(v);
+        match remaining_args.pop() {
+          None => match prj_fun(fun_v) {
+            Ok(fun) => {
+              event = store.call_event(fun, &evaled_args);
+              next_machine = call(fun, evaled_args, stk.into_stack(), store)?;
             }
-            Some(e) => Machine::Descending {
+            Err(err) => next_machine = throw(err, stk.into_stack(), store)?,
+          },
+          Some(e) => {
+            next_machine = Machine::Descending {
               e,
               env: env.clone(),
-              stk: Stack::Array {
-                evaled_parts,
-                remaining_parts,
+              stk: Stack::CallArgs {
+                fun: fun_v,
+                evaled_args,
                 env,
+                remaining_args,
                 stk,
               },
-            },
+            }
           }
         }
-        Stack::ArraySet1 {
-          index,
-          new_value,
-          env,
-          stk,
-        } => {
-          machine = Machine::Descending {
-            e: index,
-            env: env.clone(),
-            stk: Stack::ArraySet2 {
-              array: v,
-              new_value: Closure {
-                exp: new_value,
+      }
+      Stack::CallFun { env, args, stk } => {
+        let mut remaining_args = args;
+        remaining_args.reverse();
+        match remaining_args.pop() {
+          None => match prj_fun(v) {
+            Ok(fun) => {
+              event = store.call_event(fun, &[]);
+              next_machine = call(fun, Vec::new(), stk.into_stack(), store)?;
+            }
+            Err(err) => next_machine = throw(err, stk.into_stack(), store)?,
+          },
+          Some(e) => {
+            next_machine = Machine::Descending {
+              e,
+              env: env.clone(),
+              stk: Stack::CallArgs {
+                fun: v,
+                evaled_args: Vec::new(),
                 env,
+                remaining_args,
+                stk,
               },
-              stk,
-            },
+            }
           }
         }
-        Stack::ArraySet2 {
-          array,
-          new_value,
-          stk,
-        } => {
-          machine = Machine::Descending {
-            e: new_value.exp,
-            env: new_value.env,
-            stk: Stack::ArraySet3 {
-              array,
-              index: v,
+      }
+      Stack::Array {
+        mut evaled_parts,
+        mut remaining_parts,
+        env,
+        stk,
+      } => {
+        evaled_parts.push(v);
+        next_machine = match remaining_parts.pop() {
+          None => {
+            let ptr = store.alloc_array(evaled_parts)?;
+            Machine::Returning {
+              v: SnakeVal::Array(ptr),
+              stk: stk.into_stack(),
+            }
+          }
+          Some(e) => Machine::Descending {
+            e,
+            env: env.clone(),
+            stk: Stack::Array {
+              evaled_parts,
+              remaining_parts,
+              env,
               stk,
             },
+          },
+        }
+      }
+      Stack::ArraySet1 {
+        index,
+        new_value,
+        env,
+        stk,
+      } => {
+        next_machine = Machine::Descending {
+          e: index,
+          env: env.clone(),
+          stk: Stack::ArraySet2 {
+            array: v,
+            new_value: Closure {
+              exp: new_value,
+              env,
+            },
+            stk,
+          },
+        }
+      }
+      Stack::ArraySet2 {
+        array,
+        new_value,
+        stk,
+      } => {
+        next_machine = Machine::Descending {
+          e: new_value.exp,
+          env: new_value.env,
+          stk: Stack::ArraySet3 {
+            array,
+            index: v,
+            stk,
+          },
+        }
+      }
+      Stack::ArraySet3 { array, index, stk } => match array_set(array, index, v, &mut store.heap) {
+        Ok((ptr, i, old)) => {
+          event = Event::ArraySet {
+            array: ptr,
+            index: i,
+            old,
+            new: v,
+          };
+          next_machine = Machine::Returning {
+            v: array,
+            stk: stk.into_stack(),
           }
         }
-        Stack::ArraySet3 { array, index, stk } => {
-          let arr = prj_array_mut(array, "array set", &mut store.heap)?;
-          let ix = prj_num(index, "array set", "")?;
-          match TryInto::<usize>::try_into(ix) {
-            Err(_) => return Err(InterpErr::ArrayOutOfBounds {}),
-            Ok(ptr) => match arr.get_mut(ptr) {
-              None => return Err(InterpErr::ArrayOutOfBounds {}),
-              Some(loc) => {
-                *loc = v;
-                machine = Machine::Returning {
-                  v: array,
-                  stk: *stk,
-                }
-              }
+        Err(err) => next_machine = throw(err, stk.into_stack(), store)?,
+      },
+      Stack::Semicolon { next, stk } => {
+        next_machine = Machine::Descending {
+          e: next.exp,
+          env: next.env,
+          stk: stk.into_stack(),
+        }
+      }
+      Stack::WhileCond {
+        cond,
+        body,
+        env,
+        stk,
+      } => {
+        next_machine = match prj_bool(v, "while", "while") {
+          Ok(true) => Machine::Descending {
+            e: body,
+            env: env.clone(),
+            stk: Stack::WhileBody {
+              cond,
+              body,
+              env,
+              stk,
             },
+          },
+          Ok(false) => Machine::Returning {
+            v: SnakeVal::Bool(false),
+            stk: stk.into_stack(),
+          },
+          Err(err) => throw(err, stk.into_stack(), store)?,
+        }
+      }
+      Stack::WhileBody {
+        cond,
+        body,
+        env,
+        stk,
+      } => {
+        next_machine = Machine::Descending {
+          e: cond,
+          env: env.clone(),
+          stk: Stack::WhileCond {
+            cond,
+            body,
+            env,
+            stk,
+          },
+        }
+      }
+      Stack::ForStart {
+        var,
+        end,
+        body,
+        env,
+        stk,
+      } => {
+        next_machine = Machine::Descending {
+          e: end,
+          env: env.clone(),
+          stk: Stack::ForEnd {
+            var,
+            start: v,
+            body,
+            env,
+            stk,
+          },
+        }
+      }
+      Stack::ForEnd {
+        var,
+        start,
+        body,
+        env,
+        stk,
+      } => {
+        let bounds = prj_num(start, "for", "for").and_then(|start| Ok((start, prj_num(v, "for", "for")?)));
+        next_machine = match bounds {
+          Ok((start, end)) => for_iteration(var, start, end, body, env, stk.into_stack()),
+          Err(err) => throw(err, stk.into_stack(), store)?,
+        };
+      }
+      Stack::ForBody {
+        var,
+        i,
+        end,
+        body,
+        env,
+        stk,
+      } => {
+        // `i` is at most `end`, so this doesn't overflow
+        next_machine = for_iteration(var, i + 1, end, body, env, stk.into_stack());
+      }
+      Stack::MakeTypeInstance { typetag, stk } => {
+        let fields = match v {
+          SnakeVal::Array(ptr) => ptr,
+          _ => unreachable!("the fields of a custom type are always an array"),
+        };
+        store.alloc_cells(1)?;
+        next_machine = Machine::Returning {
+          v: SnakeVal::Custom {
+            tag: typetag,
+            fields,
+          },
+          stk: stk.into_stack(),
+        }
+      }
+      Stack::MatchType { typetag, stk } => {
+        let matches = matches!(v, SnakeVal::Custom { tag, .. } if tag == typetag);
+        next_machine = Machine::Returning {
+          v: SnakeVal::Bool(matches),
+          stk: stk.into_stack(),
+        }
+      }
+      Stack::GetTypeFields(stk) => {
+        // like the compiled code, values of other types have no fields
+        let v = match v {
+          SnakeVal::Custom { fields, .. } => SnakeVal::Array(fields),
+          _ => SnakeVal::Num(0),
+        };
+        next_machine = Machine::Returning { v, stk: stk.into_stack() }
+      }
+      Stack::Match {
+        default,
+        arms,
+        env,
+        stk,
+      } => {
+        let arm = arms
+          .iter()
+          .find_map(|(snake_type, args, arm)| Some((arm, match_arm(snake_type, args, v, &store.heap, &env)?)));
+        let (e, env) = arm.unwrap_or((default, env));
+        next_machine = Machine::Descending { e, env, stk: stk.into_stack() }
+      }
+      Stack::GetField { field, stk } => match custom_field(v, field, &store.heap) {
+        Some((fields, i)) => {
+          next_machine = Machine::Returning {
+            v: store.heap.arrays[fields][i],
+            stk: stk.into_stack(),
           }
         }
-        Stack::Semicolon { next, stk } => {
-          machine = Machine::Descending {
-            e: next.exp,
-            env: next.env,
-            stk: *stk,
+        None => next_machine = throw(no_field(v, &store.heap), stk.into_stack(), store)?,
+      },
+      Stack::UpdateField1 {
+        field,
+        new_value,
+        stk,
+      } => {
+        next_machine = Machine::Descending {
+          e: new_value.exp,
+          env: new_value.env,
+          stk: Stack::UpdateField2 { field, v, stk },
+        }
+      }
+      Stack::UpdateField2 {
+        field,
+        v: updated,
+        stk,
+      } => match (updated, custom_field(updated, field, &store.heap)) {
+        (SnakeVal::Custom { tag, .. }, Some((fields, i))) => {
+          let mut fields = store.heap.arrays[fields].clone();
+          fields[i] = v;
+          let fields = store.alloc_array(fields)?;
+          store.alloc_cells(1)?;
+          next_machine = Machine::Returning {
+            v: SnakeVal::Custom { tag, fields },
+            stk: stk.into_stack(),
           }
         }
+        _ => next_machine = throw(no_field(updated, &store.heap), stk.into_stack(), store)?,
       },
+      Stack::Try { stk, .. } => next_machine = Machine::Returning { v, stk: stk.into_stack() },
+      Stack::Raise(stk) => {
+        let err = InterpErr::Uncaught {
+          v,
+          printed: sprint_snake_val(v, &store.heap),
+        };
+        next_machine = throw(err, stk.into_stack(), store)?;
+      }
+      // the body of the generator finished
+      Stack::Generator { gen } => {
+        let caller = finish_generator(gen, store)?;
+        next_machine = throw(InterpErr::GeneratorDone, caller, store)?;
+      }
+      // the thread finished, so another one runs
+      Stack::Thread => {
+        store.threads[store.current] = Thread::Finished;
+        next_machine = store.schedule()?;
+      }
+    },
+  }
+  Ok((Some(next_machine), event))
+}
+
+// A program being run one step at a time
+#[derive(Clone)]
+pub(crate) struct Session<'exp, Ann> {
+  pub(crate) machine: Option<Machine<'exp, Ann>>,
+  pub(crate) store: State<'exp, Ann>,
+  pub(crate) steps: u64,
+  max_steps: Option<u64>,
+  max_depth: Option<usize>,
+}
+
+impl<'exp, Ann> Session<'exp, Ann>
+where
+  Ann: Clone,
+{
+  pub(crate) fn new(e: &'exp Exp<Ann>, config: &Config) -> Self {
+    Session {
+      machine: Some(Machine::Descending {
+        e,
+        stk: Stack::Done,
+        env: Env::new(),
+      }),
+      store: State::new(config),
+      steps: 0,
+      max_steps: config.max_steps,
+      max_depth: config.max_stack_depth,
+    }
+  }
+
+  pub(crate) fn is_done(&self) -> bool {
+    self.machine.is_none()
+  }
+
+  pub(crate) fn step<W>(&mut self, buf: &mut W) -> Interp<Event>
+  where
+    W: std::io::Write,
+  {
+    let machine = self
+      .machine
+      .take()
+      .expect("internal interp error: stepped a finished program");
+    if self.max_steps.is_some_and(|max| self.steps >= max) {
+      return Err(InterpErr::OutOfFuel { steps: self.steps });
     }
+    self.steps += 1;
+    let (next, event) = step(machine, buf, &mut self.store)?;
+    if let (Some(next), Some(max)) = (&next, self.max_depth) {
+      if next.stack().depth() > max {
+        return Err(InterpErr::OutOfStack { max_depth: max });
+      }
+    }
+    self.machine = next;
+    Ok(event)
+  }
+}
+
+fn machine<Ann, W>(e: &Exp<Ann>, buf: &mut W, config: &Config) -> Interp<()>
+where
+  W: std::io::Write,
+  Ann: Clone,
+{
+  let mut session = Session::new(e, config);
+  while !session.is_done() {
+    session.step(buf)?;
   }
+  Ok(())
 }
 
 // Runs the reference interpreter.
-pub fn exp<Ann, W>(e: &Exp<Ann>, w: &mut W) -> Interp<()>
+pub fn exp<Ann, W>(e: &Exp<Ann>, config: &Config, w: &mut W) -> Interp<()>
 where
   Ann: Clone,
   W: std::io::Write,
 {
-  machine(e, w, &mut State::new())
+  machine(e, w, config)
 }
 
-pub fn prog<Ann, W>(p: &SurfProg<Ann>, w: &mut W) -> Interp<()>
+pub fn prog<Ann, W>(p: &SurfProg<Ann>, config: &Config, w: &mut W) -> Interp<()>
 where
   W: std::io::Write,
   Ann: Clone,
 {
-  machine(p, w, &mut State::new())
+  machine(p, w, config)
 }
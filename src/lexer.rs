@@ -0,0 +1,524 @@
+use crate::span::{FileId, Span1, MAIN_FILE};
+use std::convert::TryFrom;
+use std::fmt;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Tok {
+  Num(i64),
+  Id(String),
+  Str(String),
+  // `##` comments right before a def or type declaration, without the `##`
+  DocComment(String),
+
+  Import,
+  Def,
+  And,
+  In,
+  Let,
+  Var,
+  If,
+  Else,
+  Type,
+  Match,
+  Default,
+  End,
+  Case,
+  Lambda,
+  While,
+  For,
+  To,
+  Try,
+  With,
+  // `with` in `e with field = v`, rather than before the arms of a `try`
+  WithField,
+  Raise,
+  True,
+  False,
+  Add1,
+  Sub1,
+  Print,
+  IsNum,
+  IsBool,
+  IsArray,
+  IsFun,
+  Length,
+  CallCC,
+  Yield,
+  Spawn,
+  Chan,
+  Send,
+  Recv,
+
+  LParen,
+  RParen,
+  LBracket,
+  RBracket,
+  LBrace,
+  RBrace,
+  Comma,
+  Dot,
+  Colon,
+  Semicolon,
+  Equals,
+  Assign,
+  Arrow,
+  Plus,
+  Minus,
+  Times,
+  AndAnd,
+  OrOr,
+  Less,
+  LessEq,
+  Greater,
+  GreaterEq,
+  EqEq,
+  NotEq,
+  Bang,
+}
+
+// The range of snake numbers, which are 63-bit integers
+const MIN_NUM: i64 = i64::MIN >> 1;
+const MAX_NUM: i64 = i64::MAX >> 1;
+
+static KEYWORDS: &[(&str, Tok)] = &[
+  ("import", Tok::Import),
+  ("def", Tok::Def),
+  ("and", Tok::And),
+  ("in", Tok::In),
+  ("let", Tok::Let),
+  ("var", Tok::Var),
+  ("if", Tok::If),
+  ("else", Tok::Else),
+  ("type", Tok::Type),
+  ("match", Tok::Match),
+  ("default", Tok::Default),
+  ("end", Tok::End),
+  ("case", Tok::Case),
+  ("lambda", Tok::Lambda),
+  ("while", Tok::While),
+  ("for", Tok::For),
+  ("to", Tok::To),
+  ("try", Tok::Try),
+  ("with", Tok::With),
+  ("raise", Tok::Raise),
+  ("true", Tok::True),
+  ("false", Tok::False),
+  ("add1", Tok::Add1),
+  ("sub1", Tok::Sub1),
+  ("print", Tok::Print),
+  ("isnum", Tok::IsNum),
+  ("isbool", Tok::IsBool),
+  ("isarray", Tok::IsArray),
+  ("isfun", Tok::IsFun),
+  ("length", Tok::Length),
+  ("callcc", Tok::CallCC),
+  ("yield", Tok::Yield),
+  ("spawn", Tok::Spawn),
+  ("chan", Tok::Chan),
+  ("send", Tok::Send),
+  ("recv", Tok::Recv),
+];
+
+// Longest first, so that e.g. `<=` isn't read as `<` then `=`
+static PUNCTUATION: &[(&str, Tok)] = &[
+  (":=", Tok::Assign),
+  ("=>", Tok::Arrow),
+  ("&&", Tok::AndAnd),
+  ("||", Tok::OrOr),
+  ("<=", Tok::LessEq),
+  (">=", Tok::GreaterEq),
+  ("==", Tok::EqEq),
+  ("!=", Tok::NotEq),
+  ("(", Tok::LParen),
+  (")", Tok::RParen),
+  ("[", Tok::LBracket),
+  ("]", Tok::RBracket),
+  ("{", Tok::LBrace),
+  ("}", Tok::RBrace),
+  (",", Tok::Comma),
+  (".", Tok::Dot),
+  (":", Tok::Colon),
+  (";", Tok::Semicolon),
+  ("=", Tok::Equals),
+  ("+", Tok::Plus),
+  ("-", Tok::Minus),
+  ("*", Tok::Times),
+  ("<", Tok::Less),
+  (">", Tok::Greater),
+  ("!", Tok::Bang),
+];
+
+impl fmt::Display for Tok {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      Tok::Num(n) => write!(f, "{}", n),
+      Tok::Id(x) => write!(f, "{}", x),
+      Tok::Str(s) => write!(f, "\"{}\"", s),
+      Tok::DocComment(_) => write!(f, "##"),
+      Tok::WithField => write!(f, "with"),
+      tok => {
+        let (s, _) = KEYWORDS
+          .iter()
+          .chain(PUNCTUATION)
+          .find(|(_, t)| t == tok)
+          .expect("every other token is a keyword or punctuation");
+        write!(f, "{}", s)
+      }
+    }
+  }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum LexError {
+  UnexpectedChar { c: char, location: Span1 },
+  UnterminatedComment { location: Span1 },
+  UnterminatedString { location: Span1 },
+  // digits that don't belong to the literal's base, or misplaced underscores
+  InvalidNumber { literal: String, location: Span1 },
+  NumberOutOfRange { literal: String, location: Span1 },
+}
+
+impl LexError {
+  pub fn location(&self) -> Span1 {
+    match self {
+      LexError::UnexpectedChar { location, .. }
+      | LexError::UnterminatedComment { location }
+      | LexError::UnterminatedString { location }
+      | LexError::InvalidNumber { location, .. }
+      | LexError::NumberOutOfRange { location, .. } => *location,
+    }
+  }
+}
+
+impl fmt::Display for LexError {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      LexError::UnexpectedChar { c, .. } => write!(f, "Unexpected character {:?}", c),
+      LexError::UnterminatedComment { .. } => write!(f, "Unterminated block comment"),
+      LexError::UnterminatedString { .. } => write!(f, "Unterminated string"),
+      LexError::InvalidNumber { literal, .. } => write!(f, "Invalid number literal {}", literal),
+      LexError::NumberOutOfRange { literal, .. } => write!(
+        f,
+        "Number literal {} is out of the range {} to {}",
+        literal, MIN_NUM, MAX_NUM
+      ),
+    }
+  }
+}
+
+// A comment the parser doesn't see. Doc comments before a def or type are
+// tokens instead, and end up in the syntax tree.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Comment {
+  pub span: Span1,
+  // the comment as written, with its `#` or `#|` and `|#`
+  pub text: String,
+}
+
+impl Comment {
+  pub fn is_block(&self) -> bool {
+    self.text.starts_with("#|")
+  }
+}
+
+// a token with where it starts and ends
+pub type Token = (usize, Tok, usize);
+
+pub type Spanned = Result<Token, LexError>;
+
+// Splits source code into tokens for the parser.
+//
+// Comments are `#` to the end of the line, or between `#|` and `|#`, which
+// nest. Consecutive `##` lines directly before `def` or `type` are a doc
+// comment for that declaration.
+pub struct Lexer<'input> {
+  source: &'input str,
+  file: FileId,
+  pos: usize,
+  // whether the last token ends an operand, which makes a following `+` or
+  // `-` an operator rather than the sign of a number
+  after_operand: bool,
+  comments: Vec<Comment>,
+}
+
+fn is_ident_char(c: char) -> bool {
+  c.is_ascii_alphanumeric() || c == '_'
+}
+
+impl<'input> Lexer<'input> {
+  pub fn new(source: &'input str, file: FileId) -> Self {
+    Lexer {
+      source,
+      file,
+      pos: 0,
+      after_operand: false,
+      comments: vec![],
+    }
+  }
+
+  fn rest(&self) -> &'input str {
+    &self.source[self.pos..]
+  }
+
+  // The span from `start` to the current position
+  fn span_from(&self, start: usize) -> Span1 {
+    Span1 {
+      file: self.file,
+      start_ix: start,
+      end_ix: self.pos,
+    }
+  }
+
+  fn skip_whitespace(&mut self) {
+    let rest = self.rest();
+    self.pos += rest.len() - rest.trim_start().len();
+  }
+
+  // The length of the identifier at the start of `s`
+  fn ident_len(s: &str) -> usize {
+    match s.chars().next() {
+      Some(c) if c.is_ascii_alphabetic() => s.find(|c| !is_ident_char(c)).unwrap_or(s.len()),
+      _ => 0,
+    }
+  }
+
+  fn line_comment(&mut self) -> Comment {
+    let start = self.pos;
+    let len = self.rest().find('\n').unwrap_or(self.rest().len());
+    self.pos += len;
+    Comment {
+      span: self.span_from(start),
+      text: self.source[start..self.pos].trim_end().to_string(),
+    }
+  }
+
+  fn block_comment(&mut self) -> Result<Comment, LexError> {
+    let start = self.pos;
+    self.pos += 2;
+    let mut depth = 1;
+    while depth > 0 {
+      let rest = self.rest();
+      if rest.starts_with("#|") {
+        depth += 1;
+        self.pos += 2;
+      } else if rest.starts_with("|#") {
+        depth -= 1;
+        self.pos += 2;
+      } else if let Some(c) = rest.chars().next() {
+        self.pos += c.len_utf8();
+      } else {
+        self.pos = start + 2;
+        return Err(LexError::UnterminatedComment {
+          location: self.span_from(start),
+        });
+      }
+    }
+    Ok(Comment {
+      span: self.span_from(start),
+      text: self.source[start..self.pos].to_string(),
+    })
+  }
+
+  // Reads the comments at the current position, returning a doc comment
+  // token if they're a doc comment for the declaration after them
+  fn comment(&mut self) -> Result<Option<Token>, LexError> {
+    if self.rest().starts_with("#|") {
+      let comment = self.block_comment()?;
+      self.comments.push(comment);
+      return Ok(None);
+    }
+    if !self.rest().starts_with("##") {
+      let comment = self.line_comment();
+      self.comments.push(comment);
+      return Ok(None);
+    }
+    let mut lines = vec![];
+    loop {
+      lines.push(self.line_comment());
+      let after_line = self.pos;
+      self.skip_whitespace();
+      if !self.rest().starts_with("##") {
+        self.pos = after_line;
+        break;
+      }
+    }
+    let (start, end) = (lines[0].span.start_ix, self.pos);
+    let after_doc = self.pos;
+    self.skip_whitespace();
+    let next = &self.rest()[..Lexer::ident_len(self.rest())];
+    self.pos = after_doc;
+    if next == "def" || next == "type" {
+      let text = lines
+        .iter()
+        .map(|line| {
+          let text = &line.text[2..];
+          text.strip_prefix(' ').unwrap_or(text)
+        })
+        .collect::<Vec<&str>>()
+        .join("\n");
+      Ok(Some((start, Tok::DocComment(text), end)))
+    } else {
+      self.comments.extend(lines);
+      Ok(None)
+    }
+  }
+
+  // A decimal, `0x` hex or `0b` binary integer, maybe with a sign, and with
+  // underscores allowed between its digits
+  fn number(&mut self, start: usize) -> Spanned {
+    let rest = self.rest();
+    let sign_len = if rest.starts_with(['+', '-']) { 1 } else { 0 };
+    let negative = rest.starts_with('-');
+    let unsigned = &rest[sign_len..];
+    let (radix, prefix_len) = if unsigned.starts_with("0x") || unsigned.starts_with("0X") {
+      (16, 2)
+    } else if unsigned.starts_with("0b") || unsigned.starts_with("0B") {
+      (2, 2)
+    } else {
+      (10, 0)
+    };
+    let body = &unsigned[prefix_len..];
+    let len = sign_len + prefix_len + body.find(|c| !is_ident_char(c)).unwrap_or(body.len());
+    let literal = &rest[..len];
+    let digits = &rest[sign_len + prefix_len..len];
+    self.pos += len;
+    let location = self.span_from(start);
+    let well_formed = !digits.is_empty()
+      && !digits.starts_with('_')
+      && !digits.ends_with('_')
+      && digits.chars().all(|c| c == '_' || c.is_digit(radix));
+    if !well_formed {
+      return Err(LexError::InvalidNumber {
+        literal: literal.to_string(),
+        location,
+      });
+    }
+    let out_of_range = || LexError::NumberOutOfRange {
+      literal: literal.to_string(),
+      location,
+    };
+    let magnitude = u64::from_str_radix(&digits.replace('_', ""), radix).map_err(|_| out_of_range())?;
+    let n = if negative {
+      0i64.checked_sub_unsigned(magnitude)
+    } else {
+      i64::try_from(magnitude).ok()
+    };
+    match n {
+      Some(n) if (MIN_NUM..=MAX_NUM).contains(&n) => Ok((start, Tok::Num(n), self.pos)),
+      _ => Err(out_of_range()),
+    }
+  }
+
+  // A string between double quotes on one line, which has no escapes
+  fn string(&mut self, start: usize) -> Spanned {
+    let rest = &self.rest()[1..];
+    match rest.find(['"', '\n']) {
+      Some(len) if rest[len..].starts_with('"') => {
+        self.pos += len + 2;
+        Ok((start, Tok::Str(rest[..len].to_string()), self.pos))
+      }
+      _ => {
+        self.pos += 1;
+        Err(LexError::UnterminatedString {
+          location: self.span_from(start),
+        })
+      }
+    }
+  }
+
+  // Whether the next token is `case`, which tells the `with` of a `try`
+  // from the one of `e with field = v`
+  fn before_case(&self) -> bool {
+    let mut lexer = Lexer::new(self.source, self.file);
+    lexer.pos = self.pos;
+    matches!(lexer.next(), Some(Ok((_, Tok::Case, _))))
+  }
+
+  fn word(&mut self, start: usize) -> Spanned {
+    let len = Lexer::ident_len(self.rest());
+    let word = &self.rest()[..len];
+    self.pos += len;
+    let tok = match KEYWORDS.iter().find(|(k, _)| *k == word) {
+      Some((_, Tok::With)) if !self.before_case() => Tok::WithField,
+      Some((_, tok)) => tok.clone(),
+      None => Tok::Id(word.to_string()),
+    };
+    Ok((start, tok, self.pos))
+  }
+
+  fn token(&mut self, start: usize, c: char) -> Spanned {
+    let rest = self.rest();
+    let signed_number = !self.after_operand
+      && rest.starts_with(['+', '-'])
+      && rest[1..].starts_with(|c: char| c.is_ascii_digit());
+    if c.is_ascii_digit() || signed_number {
+      return self.number(start);
+    }
+    if c.is_ascii_alphabetic() {
+      return self.word(start);
+    }
+    if c == '"' {
+      return self.string(start);
+    }
+    if c == 'λ' {
+      self.pos += c.len_utf8();
+      return Ok((start, Tok::Lambda, self.pos));
+    }
+    match PUNCTUATION.iter().find(|(p, _)| rest.starts_with(p)) {
+      Some((p, tok)) => {
+        self.pos += p.len();
+        Ok((start, tok.clone(), self.pos))
+      }
+      None => {
+        self.pos += c.len_utf8();
+        Err(LexError::UnexpectedChar {
+          c,
+          location: self.span_from(start),
+        })
+      }
+    }
+  }
+}
+
+impl<'input> Iterator for Lexer<'input> {
+  type Item = Spanned;
+
+  fn next(&mut self) -> Option<Spanned> {
+    loop {
+      self.skip_whitespace();
+      let start = self.pos;
+      let c = self.rest().chars().next()?;
+      let result = if c == '#' {
+        match self.comment() {
+          Ok(None) => continue,
+          Ok(Some(doc)) => Ok(doc),
+          Err(e) => Err(e),
+        }
+      } else {
+        self.token(start, c)
+      };
+      match &result {
+        Ok((_, tok, _)) => {
+          self.after_operand = matches!(
+            tok,
+            Tok::Num(_) | Tok::Id(_) | Tok::True | Tok::False | Tok::RParen | Tok::RBracket | Tok::End
+          )
+        }
+        // there are no more tokens after an error
+        Err(_) => self.pos = self.source.len(),
+      }
+      return Some(result);
+    }
+  }
+}
+
+// All the tokens in `source`, and the comments that aren't doc comments
+// for a declaration
+pub fn lex(source: &str) -> Result<(Vec<Token>, Vec<Comment>), LexError> {
+  let mut lexer = Lexer::new(source, MAIN_FILE);
+  let mut tokens = vec![];
+  for token in lexer.by_ref() {
+    tokens.push(token?);
+  }
+  Ok((tokens, lexer.comments))
+}
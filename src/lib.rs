@@ -1,9 +1,21 @@
 pub mod asm;
 pub mod compile;
+pub mod debugger;
 pub mod errors;
+pub mod fmt;
 pub mod interp;
+pub mod lexer;
+pub mod link;
+pub mod lsp;
+pub mod modules;
 pub mod parser;
 pub mod pipeline_functions;
+pub mod profiler;
+pub mod repl;
+pub mod replay;
 pub mod runner;
 pub mod span;
 pub mod syntax;
+pub mod tracer;
+
+pub use compile::Compiler;
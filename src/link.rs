@@ -0,0 +1,482 @@
+/* Compiling each file of a program on its own and linking them.
+ *
+ * Every module, and then the main file, is a unit of the program. A unit's
+ * code refers to the others only through symbols:
+ *
+ *   __snake_exports_{unit}   a static array of the closures of the
+ *                            functions a module exports, in the order it
+ *                            declares them
+ *   __snake_init_{unit}      the code that declares the functions of a
+ *                            module and fills in its exports
+ *   __snake_type_{unit}.{T}  the tag of the custom type T of a unit
+ *   __snake_type_{E}         the tag of the builtin error type E
+ *
+ * The lifted functions a module exports are also global, as
+ * `__snake_{unit}.{f}`. Importing files read the functions from the exports
+ * and declare the imported types again, with the same symbols for their
+ * tags. The tags are only given values when the program is linked, in
+ * `link_unit`, so that they are unique across all the files.
+ */
+use crate::compile::type_names_data;
+use crate::pipeline_functions::compile_to_instrs::{
+  GENERATOR_SYMBOL, HANDLER_SYMBOL, STACK_BASE_SYMBOL,
+};
+use crate::pipeline_functions::convert_assignments::calls_callcc;
+use crate::pipeline_functions::drop_unused::used_names;
+use crate::syntax::{error_tag, Exp, Module, Prim2, SurfProg, ERROR_TYPES};
+use crate::span::Span1;
+use std::collections::{HashMap, HashSet};
+
+// One of the separately compiled files of a program
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Unit {
+  // what the symbols of the file are named after, its module name or
+  // `main`, made unique among the files of the program
+  pub name: String,
+  // changes whenever the code compiled for the file might
+  pub fingerprint: u64,
+  // whether any file of the program calls `callcc`
+  pub callcc: bool,
+}
+
+pub const MAIN_UNIT: &str = "main";
+
+pub fn exports_symbol(unit: &str) -> String {
+  format!("__snake_exports_{}", unit)
+}
+
+pub fn init_symbol(unit: &str) -> String {
+  if unit == MAIN_UNIT {
+    String::from("__snake__main")
+  } else {
+    format!("__snake_init_{}", unit)
+  }
+}
+
+// The modules of a loaded program and its main file
+fn parts(prog: &SurfProg<Span1>) -> (&[Module<Span1>], &SurfProg<Span1>) {
+  match prog {
+    Exp::Modules { modules, body, .. } => (modules, body),
+    _ => (&[], prog),
+  }
+}
+
+// The modules a file imports, and the rest of it
+fn imports(mut e: &SurfProg<Span1>) -> (Vec<usize>, &SurfProg<Span1>) {
+  let mut modules = vec![];
+  while let Exp::Import { module, body, .. } = e {
+    modules.push(module.expect("imports are resolved when the program is loaded"));
+    e = body;
+  }
+  (modules, e)
+}
+
+// What a file exports: the names of its functions, in the order they're
+// first declared, and its types, each as last declared
+#[derive(Debug, Default)]
+struct Interface {
+  funs: Vec<String>,
+  types: Vec<(String, Vec<String>)>,
+}
+
+fn interface(mut e: &SurfProg<Span1>) -> Interface {
+  let mut interface = Interface::default();
+  loop {
+    match e {
+      Exp::FunDefs { decls, body, .. } => {
+        for decl in decls {
+          if !interface.funs.contains(&decl.name) {
+            interface.funs.push(decl.name.clone());
+          }
+        }
+        e = body;
+      }
+      Exp::TypeDefs { decls, body, .. } => {
+        for decl in decls {
+          interface.types.retain(|(name, _)| *name != decl.0);
+          interface.types.push(decl.clone());
+        }
+        e = body;
+      }
+      _ => return interface,
+    }
+  }
+}
+
+// The declarations at the start of a file followed by `rest` instead of
+// the rest of the file
+fn replace_body(e: &SurfProg<Span1>, rest: SurfProg<Span1>) -> SurfProg<Span1> {
+  match e {
+    Exp::FunDefs { decls, body, ann } => Exp::FunDefs {
+      decls: decls.clone(),
+      body: Box::new(replace_body(body, rest)),
+      ann: *ann,
+    },
+    Exp::TypeDefs {
+      decls,
+      doc,
+      body,
+      ann,
+    } => Exp::TypeDefs {
+      decls: decls.clone(),
+      doc: doc.clone(),
+      body: Box::new(replace_body(body, rest)),
+      ann: *ann,
+    },
+    _ => rest,
+  }
+}
+
+// The names of the units of a loaded program, its modules in the order
+// they're imported and then its main file
+fn unit_names(modules: &[Module<Span1>]) -> Vec<String> {
+  let mut taken = HashSet::from([String::from(MAIN_UNIT)]);
+  let mut names = vec![];
+  for module in modules {
+    let mut name = module.name.clone();
+    let mut i = 1;
+    while taken.contains(&name) {
+      name = format!("{}_{}", module.name, i);
+      i += 1;
+    }
+    taken.insert(name.clone());
+    names.push(name);
+  }
+  names.push(String::from(MAIN_UNIT));
+  names
+}
+
+// The 64-bit FNV-1a hash of a sequence of strings. Unlike DefaultHasher,
+// its output is specified, so fingerprints written to an object directory
+// mean the same to every build of snake.
+struct Fingerprint(u64);
+
+impl Fingerprint {
+  fn new() -> Self {
+    Fingerprint(0xcbf29ce484222325)
+  }
+
+  // the length of `part` goes first, so that parts can't run together
+  fn add(&mut self, part: &str) {
+    let len = (part.len() as u64).to_le_bytes();
+    for b in len.iter().chain(part.as_bytes()) {
+      self.0 = (self.0 ^ *b as u64).wrapping_mul(0x100000001b3);
+    }
+  }
+
+  fn finish(&self) -> u64 {
+    self.0
+  }
+}
+
+// The units of a loaded program. A unit's code only depends on `options`,
+// its own syntax tree and the names and interfaces of the files it imports,
+// so its fingerprint covers just those, and whether the program calls
+// `callcc` anywhere. The tree is hashed without its spans, which the
+// compiled code doesn't depend on.
+pub fn units(prog: &SurfProg<Span1>, options: &str) -> Vec<Unit> {
+  let callcc = calls_callcc(prog);
+  let (modules, body) = parts(prog);
+  let files = modules.iter().map(|m| &m.prog).chain(std::iter::once(body));
+  let mut units: Vec<Unit> = vec![];
+  for (name, file) in unit_names(modules).into_iter().zip(files) {
+    let mut fingerprint = Fingerprint::new();
+    fingerprint.add(options);
+    fingerprint.add(&name);
+    fingerprint.add(&callcc.to_string());
+    let (imported, code) = imports(file);
+    for module in imported {
+      fingerprint.add(&units[module].name);
+      fingerprint.add(&format!("{:?}", interface(imports(&modules[module].prog).1)));
+    }
+    fingerprint.add(&format!("{:?}", code.map_ann(&mut |_| ())));
+    units.push(Unit {
+      name,
+      fingerprint: fingerprint.finish(),
+      callcc,
+    });
+  }
+  units
+}
+
+// The program compiled for the unit `unit` of `prog`. The files it imports
+// become modules with just their types, and the functions they export are
+// bound with a `let`. The rest of a module is replaced with filling in its
+// exports.
+pub fn unit_prog(prog: &SurfProg<Span1>, units: &[Unit], unit: usize) -> SurfProg<Span1> {
+  let (modules, body) = parts(prog);
+  let file = modules.get(unit).map_or(body, |m| &m.prog);
+  let (imported, code) = imports(file);
+  let ann = code.ann();
+  let mut stubs = vec![];
+  let mut bindings: Vec<(String, Exp<Span1>)> = vec![];
+  for module in imported.iter() {
+    let interface = interface(imports(&modules[*module].prog).1);
+    let exports = exports_symbol(&units[*module].name);
+    for (slot, name) in interface.funs.into_iter().enumerate() {
+      // later imports hide the functions of earlier ones
+      bindings.retain(|(bound, _)| *bound != name);
+      bindings.push((
+        name,
+        Exp::Prim2(
+          Prim2::ArrayGet,
+          Box::new(Exp::Var(exports.clone(), ann)),
+          Box::new(Exp::Num(slot as i64, ann)),
+          ann,
+        ),
+      ));
+    }
+    stubs.push(Module {
+      name: units[*module].name.clone(),
+      prog: if interface.types.is_empty() {
+        Exp::Num(0, ann)
+      } else {
+        Exp::TypeDefs {
+          decls: interface.types,
+          doc: None,
+          body: Box::new(Exp::Num(0, ann)),
+          ann,
+        }
+      },
+    });
+  }
+  let code = if unit < modules.len() {
+    let exports = exports_symbol(&units[unit].name);
+    let funs = interface(code).funs;
+    let fill = funs.into_iter().enumerate().rev().fold(Exp::Num(0, ann), |rest, (slot, name)| {
+      Exp::Semicolon {
+        e1: Box::new(Exp::ArraySet {
+          array: Box::new(Exp::Var(exports.clone(), ann)),
+          index: Box::new(Exp::Num(slot as i64, ann)),
+          new_value: Box::new(Exp::Var(name, ann)),
+          ann,
+        }),
+        e2: Box::new(rest),
+        ann,
+      }
+    });
+    replace_body(code, fill)
+  } else {
+    code.clone()
+  };
+  // only the imported functions the file uses are read from the exports,
+  // since lifting captures every variable in scope in each closure
+  let used = used_names(&code);
+  bindings.retain(|(name, _)| used.contains(name));
+  let code = if bindings.is_empty() {
+    code
+  } else {
+    Exp::Let {
+      bindings,
+      body: Box::new(code),
+      ann,
+    }
+  };
+  let body = (0..stubs.len()).rev().fold(code, |body, module| Exp::Import {
+    path: stubs[module].name.clone(),
+    module: Some(module),
+    body: Box::new(body),
+    ann,
+  });
+  Exp::Modules {
+    modules: stubs,
+    body: Box::new(body),
+    ann,
+  }
+}
+
+// The symbols the units a unit imports define, which its code refers to
+pub fn imported_exports(prog: &SurfProg<Span1>, units: &[Unit], unit: usize) -> Vec<String> {
+  let (modules, body) = parts(prog);
+  let file = modules.get(unit).map_or(body, |m| &m.prog);
+  imports(file)
+    .0
+    .into_iter()
+    .map(|module| exports_symbol(&units[module].name))
+    .collect()
+}
+
+// The number of functions the module of `unit` exports
+pub fn export_count(prog: &SurfProg<Span1>, unit: usize) -> Option<usize> {
+  let (modules, _) = parts(prog);
+  modules
+    .get(unit)
+    .map(|module| interface(imports(&module.prog).1).funs.len())
+}
+
+// The functions and types the rest of a uniquified unit can see, by their
+// names in the source. Imported types come first, then the `let` of the
+// imported functions, then the unit's own declarations.
+pub fn visible_decls(mut e: &Exp<()>) -> (HashMap<String, String>, HashMap<String, String>) {
+  fn source_name<'a>(uniq: &'a str, prefix: &str) -> &'a str {
+    let rest = uniq.strip_prefix(prefix).unwrap_or(uniq);
+    rest.split_once('_').map_or(rest, |(_, name)| name)
+  }
+  while let Exp::TypeDefs { decls, body, .. } = e {
+    if !decls.iter().all(|(name, _)| name.contains('.')) {
+      break;
+    }
+    e = body;
+  }
+  if let Exp::Let { body, .. } = e {
+    e = body;
+  }
+  let mut funs = HashMap::new();
+  let mut types = HashMap::new();
+  loop {
+    match e {
+      Exp::FunDefs { decls, body, .. } => {
+        for decl in decls {
+          funs.insert(
+            source_name(&decl.name, "__snake_function_").to_string(),
+            decl.name.clone(),
+          );
+        }
+        e = body;
+      }
+      Exp::TypeDefs { decls, body, .. } => {
+        for (name, _) in decls {
+          types.insert(source_name(name, "__custom_type_").to_string(), name.clone());
+        }
+        e = body;
+      }
+      _ => return (funs, types),
+    }
+  }
+}
+
+// The symbols of the tags of the custom types of the unit `unit`, by the
+// tags `resolve_types` gave them. Imported types keep the symbols of the
+// unit they come from, and the unit's own types the unit can't see at the
+// end of its declarations, which no other unit can import, get their own.
+pub fn type_symbols(
+  unit: &str,
+  custom_types: &HashMap<String, u64>,
+  visible: &HashMap<String, String>,
+) -> Vec<String> {
+  let mut symbols = vec![String::new(); custom_types.len()];
+  for (uniq, tag) in custom_types {
+    if error_tag(uniq).is_some() {
+      symbols[*tag as usize] = format!("__snake_type_{}", uniq);
+      continue;
+    }
+    let rest = uniq.strip_prefix("__custom_type_").unwrap_or(uniq);
+    let (uniq_tag, name) = rest.split_once('_').unwrap_or(("", rest));
+    symbols[*tag as usize] = if name.contains('.') {
+      format!("__snake_type_{}", name)
+    } else if visible.get(name) == Some(uniq) {
+      format!("__snake_type_{}.{}", unit, name)
+    } else {
+      format!("__snake_type_{}.{}.{}", unit, name, uniq_tag)
+    };
+  }
+  symbols
+}
+
+// The symbols a unit exports for its own types, out of all of its
+// `type_symbols`
+pub fn own_types(unit: &str, type_symbols: &[String]) -> Vec<String> {
+  let prefix = format!("__snake_type_{}.", unit);
+  type_symbols
+    .iter()
+    .filter(|symbol| symbol.starts_with(&prefix))
+    .cloned()
+    .collect()
+}
+
+// The first lines of the assembly code of a unit, which say what the
+// linker needs to know about it when it isn't compiled again
+pub fn header(unit: &Unit, types: &[String]) -> String {
+  format!(
+    "; snake unit {} {:016x}\n; types {}\n",
+    unit.name,
+    unit.fingerprint,
+    types.join(" ")
+  )
+}
+
+// The fingerprint in the header of a unit's assembly code
+pub fn header_fingerprint(assembly: &str) -> Option<u64> {
+  let line = assembly.lines().next()?;
+  let fingerprint = line.strip_prefix("; snake unit ")?.split(' ').nth(1)?;
+  u64::from_str_radix(fingerprint, 16).ok()
+}
+
+// The symbols of the custom types a unit defines, from the header of its
+// assembly code
+pub fn header_types(assembly: &str) -> Vec<String> {
+  assembly
+    .lines()
+    .nth(1)
+    .and_then(|line| line.strip_prefix("; types"))
+    .map(|types| types.split_whitespace().map(String::from).collect())
+    .unwrap_or_default()
+}
+
+// The name a type is printed with, from its symbol
+fn type_name(symbol: &str) -> &str {
+  match symbol.split('.').nth(1) {
+    Some(name) => name,
+    None => symbol.trim_start_matches("__snake_type_"),
+  }
+}
+
+// The code that starts a program made of `units`: it gives each type of
+// `types` its tag, after the builtin error types, whose tags are the same as
+// in a program compiled whole, and runs the units in order, the main file
+// last
+pub fn link_unit(units: &[Unit], types: &[String], heap_size: usize) -> String {
+  let symbols: Vec<String> = ERROR_TYPES
+    .iter()
+    .map(|(name, _)| format!("__snake_type_{}", name))
+    .chain(types.iter().cloned())
+    .collect();
+  let names: Vec<String> = symbols.iter().map(|t| type_name(t).to_string()).collect();
+  let mut tags = String::new();
+  for (tag, symbol) in symbols.iter().enumerate() {
+    tags.push_str(&format!("        global {}\n{} equ {}\n", symbol, symbol, tag));
+  }
+  let mut calls = String::new();
+  for unit in units {
+    calls.push_str(&format!("        extern {}\n", init_symbol(&unit.name)));
+  }
+  calls.push_str("start_here:\n");
+  calls.push_str("        push rbp                     ; rbp is callee-saved\n");
+  calls.push_str("        mov rbp, HEAP_START          ; use rbp as heap pointer\n");
+  calls.push_str("        sub rsp, 8                   ; keep stack aligned\n");
+  calls.push_str(&format!("        mov r11, {}\n", STACK_BASE_SYMBOL));
+  calls.push_str("        mov [r11], rsp               ; the bottom of the stack callcc copies\n");
+  for unit in units {
+    calls.push_str(&format!("        call {}\n", init_symbol(&unit.name)));
+  }
+  format!(
+    "
+        section .data
+        global snake_type_names
+        global {}
+        global {}
+        global {}
+{}        align 8
+{}:   dq 0
+{}:   dq 0
+{}:   dq 0
+HEAP_START:   times {} dq 0
+{}
+        section .text
+        global start_here
+{}        add rsp, 8
+        pop rbp                      ; restore rbp
+        ret
+",
+    HANDLER_SYMBOL,
+    STACK_BASE_SYMBOL,
+    GENERATOR_SYMBOL,
+    type_names_data(&names),
+    HANDLER_SYMBOL,
+    STACK_BASE_SYMBOL,
+    GENERATOR_SYMBOL,
+    heap_size,
+    tags,
+    calls
+  )
+}
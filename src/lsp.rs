@@ -0,0 +1,975 @@
+use crate::compile::uniquify_prog;
+use crate::errors::RunnerErr;
+use crate::lexer::{Lexer, Tok};
+use crate::modules::load_program;
+use crate::pipeline_functions::check_prog::check_prog;
+use crate::span::{file_info, Span1, MAIN_FILE};
+use crate::syntax::{Exp, Module, SnakeType, SurfProg};
+
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::io::{BufRead, Write};
+use std::path::{Path, PathBuf};
+
+// JSON-RPC error codes
+const PARSE_ERROR: i64 = -32700;
+const INVALID_PARAMS: i64 = -32602;
+const METHOD_NOT_FOUND: i64 = -32601;
+
+// LSP symbol kinds
+const SYMBOL_FUNCTION: u64 = 12;
+const SYMBOL_STRUCT: u64 = 23;
+
+// What introduced a name
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum BindingKind {
+  Let,
+  // bound by `var`
+  Var,
+  Fun,
+  // the function the parameter belongs to, or None for a lambda
+  Param(Option<String>),
+  Type,
+  // the type matched by the arm
+  ArmField(String),
+}
+
+struct Binding {
+  name: String,
+  kind: BindingKind,
+  // the name where it's bound
+  span: Span1,
+  // the doc comment of a def or type declaration
+  doc: Option<String>,
+}
+
+// A def or type declaration, with the declarations nested inside it
+struct Symbol {
+  name: String,
+  detail: String,
+  kind: u64,
+  span: Span1,
+  name_span: Span1,
+  children: Vec<Symbol>,
+}
+
+#[derive(Default)]
+struct Analysis {
+  diagnostics: Vec<(Span1, String)>,
+  bindings: Vec<Binding>,
+  // every appearance of a bound name, including where it's bound, with the
+  // index of its binding
+  occurrences: Vec<(Span1, usize)>,
+  symbols: Vec<Symbol>,
+}
+
+impl Analysis {
+  fn occurrence_at(&self, offset: usize) -> Option<(Span1, usize)> {
+    self
+      .occurrences
+      .iter()
+      .copied()
+      .find(|(span, _)| span.start_ix <= offset && offset <= span.end_ix)
+  }
+}
+
+// Parses and checks `source`, with the files it imports from `dir`. Names
+// are only resolved in programs that pass the checks, since uniquify
+// assumes every name is bound.
+fn analyze(source: &str, dir: &Path) -> Analysis {
+  let mut analysis = Analysis::default();
+  let (prog, files) = match load_program(source, dir, &[]) {
+    Ok(loaded) => loaded,
+    Err(e) => {
+      let msg = match e.err {
+        RunnerErr::Parse { msg, .. } => msg,
+        RunnerErr::CodeGen(e) => e.to_string(),
+        e => e.to_string(),
+      };
+      analysis.diagnostics.push((in_document(source, e.location), msg));
+      return analysis;
+    }
+  };
+  symbols(&prog, source, &mut analysis.symbols);
+  match check_prog(&prog) {
+    Ok(()) => {
+      let mut resolver = Resolver {
+        source,
+        analysis: &mut analysis,
+        by_unique_name: HashMap::new(),
+      };
+      let uniq = uniquify_prog(&prog);
+      match &prog {
+        Exp::Modules { modules, body, .. } => resolver.walk(body, skip_modules(modules, &uniq)),
+        _ => resolver.walk(&prog, &uniq),
+      }
+    }
+    Err(e) => {
+      let location = in_document(source, *e.location());
+      let msg = e.map_span(|s| files.span2(*s)).to_string();
+      analysis.diagnostics.push((location, msg));
+    }
+  }
+  analysis
+}
+
+// Where in the document to report something at `span`. Errors in the files
+// it imports are reported at its first import.
+fn in_document(source: &str, span: Span1) -> Span1 {
+  if span.file == MAIN_FILE {
+    return span;
+  }
+  let mut tokens = Lexer::new(source, MAIN_FILE).map_while(Result::ok);
+  match (tokens.next(), tokens.next()) {
+    (Some((start_ix, Tok::Import, _)), Some((_, _, end_ix))) => Span1 {
+      file: MAIN_FILE,
+      start_ix,
+      end_ix,
+    },
+    _ => Span1 {
+      file: MAIN_FILE,
+      start_ix: 0,
+      end_ix: 0,
+    },
+  }
+}
+
+// The main program in a uniquified program, after the declarations uniquify
+// puts before it from each of the imported files
+fn skip_modules<'u>(modules: &[Module<Span1>], mut u: &'u Exp<()>) -> &'u Exp<()> {
+  for module in modules {
+    let mut e = &module.prog;
+    loop {
+      match (e, u) {
+        (Exp::Import { body, .. }, _) => e = body,
+        (Exp::FunDefs { body, .. }, Exp::FunDefs { body: u_body, .. })
+        | (Exp::TypeDefs { body, .. }, Exp::TypeDefs { body: u_body, .. }) => {
+          e = body;
+          u = u_body;
+        }
+        _ => break,
+      }
+    }
+  }
+  u
+}
+
+// The directory of the document at `uri`, which the files it imports are
+// relative to
+fn document_dir(uri: &str) -> PathBuf {
+  uri
+    .strip_prefix("file://")
+    .and_then(|path| Path::new(path).parent())
+    .map_or_else(|| PathBuf::from("."), Path::to_path_buf)
+}
+
+// The identifiers in source[from..to], in order, leaving out the words in
+// comments
+fn idents(source: &str, from: usize, to: usize) -> Vec<Span1> {
+  Lexer::new(source, MAIN_FILE)
+    .map_while(Result::ok)
+    .skip_while(|(start, _, _)| *start < from)
+    .take_while(|(_, _, end)| *end <= to)
+    .filter(|(_, tok, _)| matches!(tok, Tok::Id(_)))
+    .map(|(start_ix, _, end_ix)| Span1 {
+      file: MAIN_FILE,
+      start_ix,
+      end_ix,
+    })
+    .collect()
+}
+
+// Finds each of `names` in source[from..to], in order. The syntax tree
+// doesn't keep the locations of the names it binds, but they're the only
+// identifiers between the keywords and the next subexpression.
+fn find_names(source: &str, from: usize, to: usize, names: &[&str]) -> Vec<Span1> {
+  let mut idents = idents(source, from, to).into_iter();
+  names
+    .iter()
+    .map(|name| {
+      idents
+        .find(|span| &source[span.start_ix..span.end_ix] == *name)
+        .unwrap_or(Span1 {
+          file: MAIN_FILE,
+          start_ix: from,
+          end_ix: from,
+        })
+    })
+    .collect()
+}
+
+// Links every name to its binding, using the unique names uniquify gives
+// each binding. The uniquified program has the same shape as the parsed
+// one, so the two are walked together.
+struct Resolver<'a> {
+  source: &'a str,
+  analysis: &'a mut Analysis,
+  by_unique_name: HashMap<String, usize>,
+}
+
+impl<'a> Resolver<'a> {
+  fn bind(&mut self, unique_name: &str, name: &str, kind: BindingKind, span: Span1, doc: Option<String>) {
+    let bindings = &mut self.analysis.bindings;
+    bindings.push(Binding {
+      name: name.to_string(),
+      kind,
+      span,
+      doc,
+    });
+    self.by_unique_name.insert(unique_name.to_string(), bindings.len() - 1);
+    self.analysis.occurrences.push((span, bindings.len() - 1));
+  }
+
+  fn refer(&mut self, unique_name: &str, span: Span1) {
+    // the primitive type names aren't bound anywhere
+    if let Some(binding) = self.by_unique_name.get(unique_name) {
+      self.analysis.occurrences.push((span, *binding));
+    }
+  }
+
+  // The `case`s of a `match` or a `try`, which start after `from`
+  fn walk_arms(
+    &mut self,
+    mut from: usize,
+    arms: &[(SnakeType, Vec<String>, SurfProg<Span1>)],
+    u_arms: &[(SnakeType, Vec<String>, Exp<()>)],
+  ) {
+    for ((snake_type, fields, arm), (u_snake_type, u_fields, u_arm)) in arms.iter().zip(u_arms) {
+      let type_name = match snake_type {
+        SnakeType::Custom(name) | SnakeType::CustomFields(name, _) => name.as_str(),
+        SnakeType::Num => "Num",
+        SnakeType::Bool => "Bool",
+        SnakeType::Array => "Array",
+        SnakeType::Func => "Func",
+      };
+      let mut names = vec![type_name];
+      // where each variable is in `names`, after the field's name in
+      // `field = x`
+      let mut var_ixs = vec![];
+      for (i, field) in fields.iter().enumerate() {
+        if let SnakeType::CustomFields(_, field_names) = snake_type {
+          if field_names[i] != *field {
+            names.push(&field_names[i]);
+          }
+        }
+        var_ixs.push(names.len());
+        names.push(field);
+      }
+      let spans = find_names(self.source, from, arm.ann().start_ix, &names);
+      if let SnakeType::Custom(unique_name) | SnakeType::CustomFields(unique_name, _) = u_snake_type {
+        self.refer(unique_name, spans[0]);
+      }
+      for ((field, unique_name), ix) in fields.iter().zip(u_fields).zip(var_ixs) {
+        self.bind(unique_name, field, BindingKind::ArmField(type_name.to_string()), spans[ix], None);
+      }
+      self.walk(arm, u_arm);
+      from = arm.ann().end_ix;
+    }
+  }
+
+  fn walk(&mut self, e: &SurfProg<Span1>, u: &Exp<()>) {
+    match (e, u) {
+      (Exp::Num(..), _) | (Exp::Bool(..), _) | (Exp::Prim0(..), _) => {}
+      // the imported names are bound in other files
+      (Exp::Import { body, .. }, u) => self.walk(body, u),
+      (Exp::Var(_, span), Exp::Var(unique_name, _)) => self.refer(unique_name, *span),
+      (Exp::Prim1(_, e, _), Exp::Prim1(_, u, _)) => self.walk(e, u),
+      (Exp::Prim2(_, e1, e2, _), Exp::Prim2(_, u1, u2, _)) => {
+        self.walk(e1, u1);
+        self.walk(e2, u2);
+      }
+      (
+        Exp::Let { bindings, body, ann },
+        Exp::Let {
+          bindings: u_bindings,
+          body: u_body,
+          ..
+        },
+      ) => {
+        let mut from = ann.start_ix;
+        for ((name, def), (unique_name, u_def)) in bindings.iter().zip(u_bindings) {
+          let span = find_names(self.source, from, def.ann().start_ix, &[name])[0];
+          // the binding isn't visible in its own definition
+          self.walk(def, u_def);
+          self.bind(unique_name, name, BindingKind::Let, span, None);
+          from = def.ann().end_ix;
+        }
+        self.walk(body, u_body);
+      }
+      (
+        Exp::LetVar { bindings, body, ann },
+        Exp::LetVar {
+          bindings: u_bindings,
+          body: u_body,
+          ..
+        },
+      ) => {
+        let mut from = ann.start_ix;
+        for ((name, def), (unique_name, u_def)) in bindings.iter().zip(u_bindings) {
+          let span = find_names(self.source, from, def.ann().start_ix, &[name])[0];
+          self.walk(def, u_def);
+          self.bind(unique_name, name, BindingKind::Var, span, None);
+          from = def.ann().end_ix;
+        }
+        self.walk(body, u_body);
+      }
+      (
+        Exp::Assign {
+          var,
+          new_value,
+          ann,
+        },
+        Exp::Assign {
+          var: unique_name,
+          new_value: u_new_value,
+          ..
+        },
+      ) => {
+        // the assignment starts with the variable's name
+        let span = Span1 {
+          end_ix: ann.start_ix + var.len(),
+          ..*ann
+        };
+        self.refer(unique_name, span);
+        self.walk(new_value, u_new_value);
+      }
+      (
+        Exp::If { cond, thn, els, .. },
+        Exp::If {
+          cond: u_cond,
+          thn: u_thn,
+          els: u_els,
+          ..
+        },
+      ) => {
+        self.walk(cond, u_cond);
+        self.walk(thn, u_thn);
+        self.walk(els, u_els);
+      }
+      (Exp::Array(es, _), Exp::Array(us, _)) => {
+        for (e, u) in es.iter().zip(us) {
+          self.walk(e, u);
+        }
+      }
+      (
+        Exp::ArraySet {
+          array,
+          index,
+          new_value,
+          ..
+        },
+        Exp::ArraySet {
+          array: u_array,
+          index: u_index,
+          new_value: u_new_value,
+          ..
+        },
+      ) => {
+        self.walk(array, u_array);
+        self.walk(index, u_index);
+        self.walk(new_value, u_new_value);
+      }
+      (Exp::Semicolon { e1, e2, .. }, Exp::Semicolon { e1: u1, e2: u2, .. }) => {
+        self.walk(e1, u1);
+        self.walk(e2, u2);
+      }
+      (
+        Exp::While { cond, body, .. },
+        Exp::While {
+          cond: u_cond,
+          body: u_body,
+          ..
+        },
+      ) => {
+        self.walk(cond, u_cond);
+        self.walk(body, u_body);
+      }
+      (
+        Exp::For {
+          var,
+          start,
+          end,
+          body,
+          ann,
+        },
+        Exp::For {
+          var: unique_name,
+          start: u_start,
+          end: u_end,
+          body: u_body,
+          ..
+        },
+      ) => {
+        let span = find_names(self.source, ann.start_ix, start.ann().start_ix, &[var])[0];
+        // the loop variable is only visible in the body
+        self.walk(start, u_start);
+        self.walk(end, u_end);
+        self.bind(unique_name, var, BindingKind::Let, span, None);
+        self.walk(body, u_body);
+      }
+      (
+        Exp::FunDefs { decls, body, .. },
+        Exp::FunDefs {
+          decls: u_decls,
+          body: u_body,
+          ..
+        },
+      ) => {
+        // the functions are visible in each other's bodies
+        let spans: Vec<Vec<Span1>> = decls
+          .iter()
+          .map(|decl| {
+            let mut names = vec![decl.name.as_str()];
+            names.extend(decl.parameters.iter().map(String::as_str));
+            find_names(self.source, decl.ann.start_ix, decl.body.ann().start_ix, &names)
+          })
+          .collect();
+        for ((decl, u_decl), spans) in decls.iter().zip(u_decls).zip(&spans) {
+          self.bind(&u_decl.name, &decl.name, BindingKind::Fun, spans[0], decl.doc.clone());
+        }
+        for ((decl, u_decl), spans) in decls.iter().zip(u_decls).zip(&spans) {
+          for ((param, unique_name), span) in decl.parameters.iter().zip(&u_decl.parameters).zip(&spans[1..]) {
+            self.bind(unique_name, param, BindingKind::Param(Some(decl.name.clone())), *span, None);
+          }
+          self.walk(&decl.body, &u_decl.body);
+        }
+        self.walk(body, u_body);
+      }
+      (Exp::Call(fun, args, _), Exp::Call(u_fun, u_args, _)) => {
+        self.walk(fun, u_fun);
+        for (e, u) in args.iter().zip(u_args) {
+          self.walk(e, u);
+        }
+      }
+      (
+        Exp::Lambda {
+          parameters,
+          body,
+          ann,
+        },
+        Exp::Lambda {
+          parameters: u_parameters,
+          body: u_body,
+          ..
+        },
+      ) => {
+        let names: Vec<&str> = parameters.iter().map(String::as_str).collect();
+        let spans = find_names(self.source, ann.start_ix, body.ann().start_ix, &names);
+        for ((param, unique_name), span) in parameters.iter().zip(u_parameters).zip(spans) {
+          self.bind(unique_name, param, BindingKind::Param(None), span, None);
+        }
+        self.walk(body, u_body);
+      }
+      (
+        Exp::TypeDefs {
+          decls, doc, body, ann, ..
+        },
+        Exp::TypeDefs {
+          decls: u_decls,
+          body: u_body,
+          ..
+        },
+      ) => {
+        // the fields are in the list too, so that a field isn't mistaken
+        // for a later type with the same name
+        let names: Vec<&str> = decls
+          .iter()
+          .flat_map(|(name, fields)| std::iter::once(name).chain(fields))
+          .map(String::as_str)
+          .collect();
+        let mut spans = find_names(self.source, ann.start_ix, body.ann().start_ix, &names).into_iter();
+        for ((name, fields), (unique_name, _)) in decls.iter().zip(u_decls) {
+          let span = spans.next().unwrap();
+          spans.by_ref().take(fields.len()).for_each(drop);
+          self.bind(unique_name, name, BindingKind::Type, span, doc.clone());
+        }
+        self.walk(body, u_body);
+      }
+      (
+        Exp::Match {
+          expr,
+          default,
+          arms,
+          ..
+        },
+        Exp::Match {
+          expr: u_expr,
+          default: u_default,
+          arms: u_arms,
+          ..
+        },
+      ) => {
+        self.walk(expr, u_expr);
+        self.walk(default, u_default);
+        self.walk_arms(default.ann().end_ix, arms, u_arms);
+      }
+      (Exp::Raise(e, _), Exp::Raise(u, _)) => self.walk(e, u),
+      (Exp::GetField { exp, .. }, Exp::GetField { exp: u, .. }) => self.walk(exp, u),
+      (
+        Exp::UpdateField { exp, new_value, .. },
+        Exp::UpdateField {
+          exp: u_exp,
+          new_value: u_new_value,
+          ..
+        },
+      ) => {
+        self.walk(exp, u_exp);
+        self.walk(new_value, u_new_value);
+      }
+      (
+        Exp::Try { body, arms, .. },
+        Exp::Try {
+          body: u_body,
+          arms: u_arms,
+          ..
+        },
+      ) => {
+        self.walk(body, u_body);
+        self.walk_arms(body.ann().end_ix, arms, u_arms);
+      }
+      _ => unreachable!("uniquify keeps the shape of the program"),
+    }
+  }
+}
+
+// The defs and type declarations in `e`, outermost first
+fn symbols(e: &SurfProg<Span1>, source: &str, out: &mut Vec<Symbol>) {
+  match e {
+    Exp::Num(..) | Exp::Bool(..) | Exp::Var(..) | Exp::Prim0(..) => {}
+    // only the declarations in the document itself
+    Exp::Import { body, .. } | Exp::Modules { body, .. } => symbols(body, source, out),
+    Exp::Prim1(_, e, _) | Exp::Assign { new_value: e, .. } => symbols(e, source, out),
+    Exp::Prim2(_, e1, e2, _)
+    | Exp::Semicolon { e1, e2, .. }
+    | Exp::While {
+      cond: e1, body: e2, ..
+    }
+    | Exp::UpdateField {
+      exp: e1,
+      new_value: e2,
+      ..
+    } => {
+      symbols(e1, source, out);
+      symbols(e2, source, out);
+    }
+    Exp::For { start, end, body, .. } => {
+      symbols(start, source, out);
+      symbols(end, source, out);
+      symbols(body, source, out);
+    }
+    Exp::Let { bindings, body, .. } | Exp::LetVar { bindings, body, .. } => {
+      for (_, def) in bindings {
+        symbols(def, source, out);
+      }
+      symbols(body, source, out);
+    }
+    Exp::If { cond, thn, els, .. } => {
+      symbols(cond, source, out);
+      symbols(thn, source, out);
+      symbols(els, source, out);
+    }
+    Exp::Array(es, _) => {
+      for e in es {
+        symbols(e, source, out);
+      }
+    }
+    Exp::ArraySet {
+      array,
+      index,
+      new_value,
+      ..
+    } => {
+      symbols(array, source, out);
+      symbols(index, source, out);
+      symbols(new_value, source, out);
+    }
+    Exp::FunDefs { decls, body, .. } => {
+      for decl in decls {
+        let mut children = vec![];
+        symbols(&decl.body, source, &mut children);
+        out.push(Symbol {
+          name: decl.name.clone(),
+          detail: format!("({})", decl.parameters.join(", ")),
+          kind: SYMBOL_FUNCTION,
+          span: decl.ann,
+          name_span: find_names(source, decl.ann.start_ix, decl.body.ann().start_ix, &[&decl.name])[0],
+          children,
+        });
+      }
+      symbols(body, source, out);
+    }
+    Exp::Call(fun, args, _) => {
+      symbols(fun, source, out);
+      for e in args {
+        symbols(e, source, out);
+      }
+    }
+    Exp::Lambda { body, .. } => symbols(body, source, out),
+    Exp::TypeDefs { decls, body, ann, .. } => {
+      let names: Vec<&str> = decls
+        .iter()
+        .flat_map(|(name, fields)| std::iter::once(name).chain(fields))
+        .map(String::as_str)
+        .collect();
+      let mut spans = find_names(source, ann.start_ix, body.ann().start_ix, &names).into_iter();
+      for (name, fields) in decls {
+        let name_span = spans.next().unwrap();
+        let field_spans: Vec<Span1> = spans.by_ref().take(fields.len()).collect();
+        // up to the closing parenthesis after the fields
+        let end_ix = match field_spans.last() {
+          Some(last) => source[last.end_ix..].find(')').map_or(last.end_ix, |i| last.end_ix + i + 1),
+          None => name_span.end_ix,
+        };
+        out.push(Symbol {
+          name: name.clone(),
+          detail: if fields.is_empty() {
+            String::new()
+          } else {
+            format!("({})", fields.join(", "))
+          },
+          kind: SYMBOL_STRUCT,
+          span: Span1 {
+            file: MAIN_FILE,
+            start_ix: name_span.start_ix,
+            end_ix,
+          },
+          name_span,
+          children: vec![],
+        });
+      }
+      symbols(body, source, out);
+    }
+    Exp::Match {
+      expr,
+      default,
+      arms,
+      ..
+    } => {
+      symbols(expr, source, out);
+      symbols(default, source, out);
+      for (_, _, arm) in arms {
+        symbols(arm, source, out);
+      }
+    }
+    Exp::Try { body, arms, .. } => {
+      symbols(body, source, out);
+      for (_, _, arm) in arms {
+        symbols(arm, source, out);
+      }
+    }
+    Exp::Catch { body, handler, .. } => {
+      symbols(body, source, out);
+      symbols(handler, source, out);
+    }
+    Exp::MakeClosure { env: e, .. }
+    | Exp::MakeTypeInstance { fields: e, .. }
+    | Exp::MatchType { expr: e, .. }
+    | Exp::GetTypeFields(e, _)
+    | Exp::GetField { exp: e, .. }
+    | Exp::Raise(e, _) => symbols(e, source, out),
+  }
+}
+
+// LSP positions count lines from 0, and characters in UTF-16 code units
+fn position(text: &str, offset: usize) -> Value {
+  let mut offset = offset.min(text.len());
+  while !text.is_char_boundary(offset) {
+    offset -= 1;
+  }
+  let line_start = text[..offset].rfind('\n').map_or(0, |i| i + 1);
+  let line = text[..line_start].matches('\n').count();
+  let character: usize = text[line_start..offset].chars().map(char::len_utf16).sum();
+  json!({ "line": line, "character": character })
+}
+
+fn offset(text: &str, position: &Value) -> Option<usize> {
+  let line = position["line"].as_u64()? as usize;
+  let character = position["character"].as_u64()? as usize;
+  let line_start = match line {
+    0 => 0,
+    _ => text.match_indices('\n').nth(line - 1)?.0 + 1,
+  };
+  let mut units = 0;
+  for (i, c) in text[line_start..].char_indices() {
+    if units >= character || c == '\n' {
+      return Some(line_start + i);
+    }
+    units += c.len_utf16();
+  }
+  Some(text.len())
+}
+
+fn range(text: &str, span: Span1) -> Value {
+  json!({ "start": position(text, span.start_ix), "end": position(text, span.end_ix) })
+}
+
+fn describe(binding: &Binding) -> String {
+  match &binding.kind {
+    BindingKind::Let => format!("variable `{}`", binding.name),
+    BindingKind::Var => format!("mutable variable `{}`", binding.name),
+    BindingKind::Fun => format!("function `{}`", binding.name),
+    BindingKind::Param(Some(fun)) => format!("parameter `{}` of `{}`", binding.name, fun),
+    BindingKind::Param(None) => format!("parameter `{}` of a lambda", binding.name),
+    BindingKind::Type => format!("type `{}`", binding.name),
+    BindingKind::ArmField(type_name) => {
+      format!("field `{}` of `{}` in a match arm", binding.name, type_name)
+    }
+  }
+}
+
+struct Document {
+  text: String,
+  analysis: Analysis,
+}
+
+type RequestResult = Result<Value, (i64, String)>;
+
+struct Server {
+  documents: HashMap<String, Document>,
+}
+
+impl Server {
+  // The document and the offset of the position a request is about
+  fn document_at<'a>(&'a self, params: &'a Value) -> Result<(&'a str, &'a Document, usize), (i64, String)> {
+    let uri = params["textDocument"]["uri"].as_str().unwrap_or_default();
+    let document = self
+      .documents
+      .get(uri)
+      .ok_or_else(|| (INVALID_PARAMS, format!("Unknown document {}", uri)))?;
+    let offset = offset(&document.text, &params["position"])
+      .ok_or_else(|| (INVALID_PARAMS, "Invalid position".to_string()))?;
+    Ok((uri, document, offset))
+  }
+
+  fn request(&self, method: &str, params: &Value) -> RequestResult {
+    match method {
+      "initialize" => Ok(json!({
+        "capabilities": {
+          "textDocumentSync": 1,
+          "definitionProvider": true,
+          "referencesProvider": true,
+          "hoverProvider": true,
+          "documentSymbolProvider": true,
+        },
+        "serverInfo": { "name": "snake", "version": env!("CARGO_PKG_VERSION") },
+      })),
+      "shutdown" => Ok(Value::Null),
+      "textDocument/definition" => {
+        let (uri, document, offset) = self.document_at(params)?;
+        Ok(match document.analysis.occurrence_at(offset) {
+          Some((_, binding)) => {
+            let span = document.analysis.bindings[binding].span;
+            json!({ "uri": uri, "range": range(&document.text, span) })
+          }
+          None => Value::Null,
+        })
+      }
+      "textDocument/references" => {
+        let (uri, document, offset) = self.document_at(params)?;
+        let with_declaration = params["context"]["includeDeclaration"].as_bool().unwrap_or(true);
+        let analysis = &document.analysis;
+        let locations: Vec<Value> = match analysis.occurrence_at(offset) {
+          Some((_, binding)) => analysis
+            .occurrences
+            .iter()
+            .filter(|(span, b)| *b == binding && (with_declaration || *span != analysis.bindings[binding].span))
+            .map(|(span, _)| json!({ "uri": uri, "range": range(&document.text, *span) }))
+            .collect(),
+          None => vec![],
+        };
+        Ok(Value::Array(locations))
+      }
+      "textDocument/hover" => {
+        let (_, document, offset) = self.document_at(params)?;
+        Ok(match document.analysis.occurrence_at(offset) {
+          Some((span, binding)) => {
+            let binding = &document.analysis.bindings[binding];
+            let line = file_info(&document.text).line_of(binding.span.start_ix);
+            let line_text = document.text.lines().nth(line - 1).unwrap_or_default();
+            let mut value = format!(
+              "{}, bound on line {}:\n\n```snake\n{}\n```",
+              describe(binding),
+              line,
+              line_text.trim()
+            );
+            if let Some(doc) = &binding.doc {
+              value.push_str(&format!("\n\n{}", doc));
+            }
+            json!({
+              "contents": { "kind": "markdown", "value": value },
+              "range": range(&document.text, span),
+            })
+          }
+          None => Value::Null,
+        })
+      }
+      "textDocument/documentSymbol" => {
+        let uri = params["textDocument"]["uri"].as_str().unwrap_or_default();
+        let document = self
+          .documents
+          .get(uri)
+          .ok_or_else(|| (INVALID_PARAMS, format!("Unknown document {}", uri)))?;
+        fn to_json(text: &str, symbols: &[Symbol]) -> Value {
+          symbols
+            .iter()
+            .map(|s| {
+              json!({
+                "name": s.name,
+                "detail": s.detail,
+                "kind": s.kind,
+                "range": range(text, s.span),
+                "selectionRange": range(text, s.name_span),
+                "children": to_json(text, &s.children),
+              })
+            })
+            .collect()
+        }
+        Ok(to_json(&document.text, &document.analysis.symbols))
+      }
+      _ => Err((METHOD_NOT_FOUND, format!("Unknown method {}", method))),
+    }
+  }
+
+  // Handles a notification, returning the notifications to send back
+  fn notify(&mut self, method: &str, params: &Value) -> Vec<Value> {
+    let uri = params["textDocument"]["uri"].as_str().unwrap_or_default().to_string();
+    let text = match method {
+      "textDocument/didOpen" => params["textDocument"]["text"].as_str(),
+      // the server asks for the whole text on every change
+      "textDocument/didChange" => params["contentChanges"]
+        .as_array()
+        .and_then(|changes| changes.last())
+        .and_then(|change| change["text"].as_str()),
+      "textDocument/didClose" => {
+        self.documents.remove(&uri);
+        return vec![diagnostics(&uri, &[])];
+      }
+      _ => None,
+    };
+    match text {
+      Some(text) => {
+        let document = Document {
+          text: text.to_string(),
+          analysis: analyze(text, &document_dir(&uri)),
+        };
+        let diagnostics_msg = diagnostics(
+          &uri,
+          &document
+            .analysis
+            .diagnostics
+            .iter()
+            .map(|(span, msg)| {
+              json!({
+                "range": range(&document.text, *span),
+                "severity": 1,
+                "source": "snake",
+                "message": msg,
+              })
+            })
+            .collect::<Vec<Value>>(),
+        );
+        self.documents.insert(uri, document);
+        vec![diagnostics_msg]
+      }
+      None => vec![],
+    }
+  }
+}
+
+fn diagnostics(uri: &str, diagnostics: &[Value]) -> Value {
+  json!({
+    "jsonrpc": "2.0",
+    "method": "textDocument/publishDiagnostics",
+    "params": { "uri": uri, "diagnostics": diagnostics },
+  })
+}
+
+// Reads the content of the next message, or None at the end of the input
+fn read_message<R>(input: &mut R) -> std::io::Result<Option<String>>
+where
+  R: BufRead,
+{
+  let invalid = |msg: String| std::io::Error::new(std::io::ErrorKind::InvalidData, msg);
+  let mut length = None;
+  loop {
+    let mut header = String::new();
+    if input.read_line(&mut header)? == 0 {
+      return Ok(None);
+    }
+    let header = header.trim_end();
+    if header.is_empty() {
+      break;
+    }
+    if let Some((name, value)) = header.split_once(':') {
+      if name.eq_ignore_ascii_case("Content-Length") {
+        let value = value.trim();
+        length = Some(
+          value
+            .parse::<usize>()
+            .map_err(|_| invalid(format!("Invalid Content-Length {}", value)))?,
+        );
+      }
+    }
+  }
+  let length = length.ok_or_else(|| invalid("Message without a Content-Length header".to_string()))?;
+  let mut content = vec![0; length];
+  input.read_exact(&mut content)?;
+  String::from_utf8(content)
+    .map(Some)
+    .map_err(|e| invalid(e.to_string()))
+}
+
+fn write_message<W>(out: &mut W, message: &Value) -> std::io::Result<()>
+where
+  W: Write,
+{
+  let content = message.to_string();
+  write!(out, "Content-Length: {}\r\n\r\n{}", content.len(), content)?;
+  out.flush()
+}
+
+// Serves the Language Server Protocol, reading messages from `input` and
+// writing replies and diagnostics to `out`, until the client says to exit
+pub fn serve<R, W>(mut input: R, out: &mut W) -> std::io::Result<()>
+where
+  R: BufRead,
+  W: Write,
+{
+  let mut server = Server {
+    documents: HashMap::new(),
+  };
+  while let Some(content) = read_message(&mut input)? {
+    let message: Value = match serde_json::from_str(&content) {
+      Ok(message) => message,
+      Err(e) => {
+        let error = json!({ "code": PARSE_ERROR, "message": e.to_string() });
+        write_message(out, &json!({ "jsonrpc": "2.0", "id": null, "error": error }))?;
+        continue;
+      }
+    };
+    let method = match message["method"].as_str() {
+      Some(method) => method,
+      // a reply to a request, but the server doesn't make any
+      None => continue,
+    };
+    let params = &message["params"];
+    match message.get("id") {
+      Some(id) => {
+        let reply = match server.request(method, params) {
+          Ok(result) => json!({ "jsonrpc": "2.0", "id": id, "result": result }),
+          Err((code, msg)) => {
+            json!({ "jsonrpc": "2.0", "id": id, "error": { "code": code, "message": msg } })
+          }
+        };
+        write_message(out, &reply)?;
+      }
+      None if method == "exit" => return Ok(()),
+      None => {
+        for notification in server.notify(method, params) {
+          write_message(out, &notification)?;
+        }
+      }
+    }
+  }
+  Ok(())
+}
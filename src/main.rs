@@ -1,52 +1,324 @@
+use snake::compile::{OptLevel, Target};
 use snake::runner::*;
-use std::path::Path;
+use snake::{interp, Compiler};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
 
 static USAGE_MSG: &str = "\
 To compile a program and emit assembly code to stdout use
 
-    snake INPUT_FILE
+    snake [OPTIONS] INPUT_FILE
 
 To compile a program, link it and run the produced binary use
 
-    snake --run INPUT_FILE
+    snake --run [OPTIONS] INPUT_FILE
+
+To compile a program into a standalone executable use
+
+    snake build [OPTIONS] [-o OUTPUT] INPUT_FILE
 
 To run the reference interpreter use
 
     snake --interp INPUT_FILE
 
+To step through a program in the reference interpreter use
+
+    snake --debug INPUT_FILE
+
+and type help at the (sdb) prompt for a list of commands
+
+To evaluate expressions and declarations interactively use
+
+    snake repl
+
+and type :help at the snake> prompt for a list of commands
+
+To rewrite programs in the canonical layout use
+
+    snake fmt [--check] FILES
+
+where --check lists the files that aren't formatted instead, and fails if
+there are any
+
+To start a language server for editors, speaking LSP on stdin and stdout, use
+
+    snake lsp
+
+Compiler options:
+
+    --dump STAGE        print the program after STAGE to stderr, where STAGE is
+                        one of parsed, uniquified, resolved, lifted, sequentialized
+    --heap-size WORDS   size of the heap in 8-byte words
+    -O0, -O1            optimization level (default -O0)
+    --target TARGET     one of linux, macos, windows (default: this machine)
+    -I DIR              look for imported files in DIR when they aren't beside
+                        the file importing them. Can be given more than once
+
+Run options:
+
+    --timeout SECONDS   kill the program after SECONDS of wall-clock time
+    --max-memory MB     limit the program's address space to MB megabytes
+    --max-cpu SECONDS   limit the program's CPU time to SECONDS
+
+Interpreter, debugger and REPL options:
+
+    --fuel STEPS        stop after STEPS steps of the interpreter
+    --trace             with --interp, log every function call and return to stderr
+    --trace-only NAME   like --trace, but only log calls to functions named NAME.
+                        Can be given more than once
+    --profile           with --interp, print the calls, steps and allocations of
+                        each function to stderr when the program finishes
+    --profile-folded FILE
+                        like --profile, and also write the steps of each call
+                        stack to FILE in the folded format of flamegraph tools
+    --max-heap CELLS    stop once the program has allocated CELLS heap cells
+    --max-stack FRAMES  stop once the program's stack is more than FRAMES
+                        frames deep
+    --record            with --debug, record the program's execution so the
+                        debugger can go back in time
+
+Thread options:
+
+    --seed N            with --run, --interp, --debug or repl, pick the next
+                        thread to run with the seed N (default 0). Compiled
+                        programs read it from the SNAKE_SEED environment variable
+
+Build options:
+
+    -o OUTPUT           where to write the output (default: the input file name
+                        without its extension, in the current directory)
+    --emit KIND         what to write to OUTPUT, one of exe, obj, asm (default: exe)
+    --save-temps        keep the intermediate assembly and object files beside OUTPUT
+    --obj-dir DIR       compile each imported file and the input file to its own
+                        object file in DIR, only compiling again the files that
+                        changed since the last build into DIR
+
 To see this usage message run
 
     snake --help
 ";
 
+enum Mode {
+    EmitAssembly,
+    Run,
+    Build,
+    Interp,
+    Debug,
+    Repl,
+    Lsp,
+    Fmt,
+}
+
 fn main() {
-    let args: Vec<String> = std::env::args().collect();
-    if args.len() == 2 {
-        match args[1].as_str() {
-            "--interp" | "--run" => usage(Some("interp and run comands require an input file")),
-            "--help" => usage(None),
-            path => emit_assembly(Path::new(&path)),
-        }
-    } else if args.len() == 3 {
-        match args[1].as_str() {
-            "--interp" => interp(Path::new(&args[2]), &mut std::io::stdout()),
-            "--run" => run(Path::new(&args[2])),
-            _ => usage(Some("Failed to parse input")),
-        }
-    } else if args.len() <= 1 {
-        usage(Some("Too few arguments"))
-    } else {
-        usage(Some("Too many arguments"))
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let mut mode = Mode::EmitAssembly;
+    let mut compiler = Compiler::new();
+    let mut inputs: Vec<&str> = vec![];
+    let mut output: Option<PathBuf> = None;
+    let mut artifact = Artifact::Executable;
+    let mut save_temps = false;
+    let mut obj_dir: Option<PathBuf> = None;
+    let mut limits = Limits::new();
+    let mut interp_config = interp::Config::new();
+    let mut trace_only: Option<Vec<String>> = None;
+    let mut profile_to: Option<Option<PathBuf>> = None;
+    let mut record = false;
+    let mut check = false;
+    let mut seed: Option<u64> = None;
+
+    let mut args_iter = args.iter().peekable();
+    match args_iter.peek().map(|a| a.as_str()) {
+        Some("build") => {
+            args_iter.next();
+            mode = Mode::Build;
+        }
+        Some("repl") => {
+            args_iter.next();
+            mode = Mode::Repl;
+        }
+        Some("lsp") => {
+            args_iter.next();
+            mode = Mode::Lsp;
+        }
+        Some("fmt") => {
+            args_iter.next();
+            mode = Mode::Fmt;
+        }
+        _ => {}
+    }
+    while let Some(arg) = args_iter.next() {
+        match arg.as_str() {
+            "--help" => return usage(None),
+            "--run" => mode = Mode::Run,
+            "--interp" => mode = Mode::Interp,
+            "--debug" => mode = Mode::Debug,
+            "-o" => output = Some(parse_flag_value(arg, args_iter.next().map(String::as_str))),
+            "--emit" => artifact = parse_flag_value(arg, args_iter.next().map(String::as_str)),
+            "--save-temps" => save_temps = true,
+            "--obj-dir" => obj_dir = Some(parse_flag_value(arg, args_iter.next().map(String::as_str))),
+            "--timeout" => {
+                limits = limits.timeout(Duration::from_secs(parse_flag_value(arg, args_iter.next().map(String::as_str))))
+            }
+            "--max-memory" => {
+                let megabytes: u64 = parse_flag_value(arg, args_iter.next().map(String::as_str));
+                match megabytes.checked_mul(1024 * 1024) {
+                    Some(bytes) => limits = limits.memory(bytes),
+                    None => usage_error(&format!("Invalid value for {}: {} megabytes is too large", arg, megabytes)),
+                }
+            }
+            "--max-cpu" => limits = limits.cpu_time(parse_flag_value(arg, args_iter.next().map(String::as_str))),
+            "--fuel" => interp_config = interp_config.max_steps(parse_flag_value(arg, args_iter.next().map(String::as_str))),
+            "--trace" => {
+                trace_only.get_or_insert_with(Vec::new);
+            }
+            "--trace-only" => trace_only
+                .get_or_insert_with(Vec::new)
+                .push(parse_flag_value(arg, args_iter.next().map(String::as_str))),
+            "--profile" => {
+                profile_to.get_or_insert(None);
+            }
+            "--profile-folded" => profile_to = Some(Some(parse_flag_value(arg, args_iter.next().map(String::as_str)))),
+            "--record" => record = true,
+            "--seed" => seed = Some(parse_flag_value(arg, args_iter.next().map(String::as_str))),
+            "--check" => check = true,
+            "--max-heap" => {
+                interp_config = interp_config.max_heap_cells(parse_flag_value(arg, args_iter.next().map(String::as_str)))
+            }
+            "--max-stack" => {
+                interp_config = interp_config.max_stack_depth(parse_flag_value(arg, args_iter.next().map(String::as_str)))
+            }
+            "--dump" => compiler = compiler.dump(parse_flag_value(arg, args_iter.next().map(String::as_str))),
+            "--heap-size" => compiler = compiler.heap_size(parse_flag_value(arg, args_iter.next().map(String::as_str))),
+            "--target" => compiler = compiler.target(parse_flag_value::<Target>(arg, args_iter.next().map(String::as_str))),
+            "-I" => compiler = compiler.search_dir(&parse_flag_value::<PathBuf>(arg, args_iter.next().map(String::as_str))),
+            level if level.starts_with("-O") => {
+                compiler = compiler.opt_level(parse_flag_value::<OptLevel>(arg, Some(&level[2..])))
+            }
+            flag if flag.starts_with('-') => usage_error(&format!("Unknown option {}", flag)),
+            path => {
+                if !inputs.is_empty() && !matches!(mode, Mode::Fmt) {
+                    usage_error("Too many arguments")
+                }
+                inputs.push(path)
+            }
+        }
+    }
+
+    if !matches!(mode, Mode::Build)
+        && (output.is_some() || save_temps || artifact != Artifact::Executable || obj_dir.is_some())
+    {
+        usage_error("-o, --emit, --save-temps and --obj-dir can only be used with build")
+    }
+    if obj_dir.is_some() && (save_temps || artifact != Artifact::Executable) {
+        usage_error("--obj-dir can't be used with --emit or --save-temps")
+    }
+    if !matches!(mode, Mode::Run) && limits != Limits::new() {
+        usage_error("--timeout, --max-memory and --max-cpu can only be used with --run")
+    }
+    if !matches!(mode, Mode::Interp) && trace_only.is_some() {
+        usage_error("--trace and --trace-only can only be used with --interp")
+    }
+    if !matches!(mode, Mode::Interp) && profile_to.is_some() {
+        usage_error("--profile and --profile-folded can only be used with --interp")
+    }
+    if trace_only.is_some() && profile_to.is_some() {
+        usage_error("--trace and --profile can't be used together")
+    }
+    if !matches!(mode, Mode::Debug) && record {
+        usage_error("--record can only be used with --debug")
+    }
+    if !matches!(mode, Mode::Interp | Mode::Debug | Mode::Repl) && interp_config != interp::Config::new() {
+        usage_error("--fuel, --max-heap and --max-stack can only be used with --interp, --debug or repl")
+    }
+    if !matches!(mode, Mode::Fmt) && check {
+        usage_error("--check can only be used with fmt")
+    }
+    if let Some(seed) = seed {
+        match mode {
+            // the compiled program reads it when it starts
+            Mode::Run => std::env::set_var("SNAKE_SEED", seed.to_string()),
+            Mode::Interp | Mode::Debug | Mode::Repl => interp_config = interp_config.seed(seed),
+            _ => usage_error("--seed can only be used with --run, --interp, --debug or repl"),
+        }
+    }
+    if matches!(mode, Mode::Fmt) {
+        if inputs.is_empty() {
+            usage_error("fmt requires at least one input file")
+        }
+        let paths: Vec<&Path> = inputs.iter().map(Path::new).collect();
+        return fmt(&paths, check);
+    }
+    let path = match inputs.first() {
+        Some(_) if matches!(mode, Mode::Repl) => usage_error("repl doesn't take an input file"),
+        Some(_) if matches!(mode, Mode::Lsp) => usage_error("lsp doesn't take an input file"),
+        Some(path) => Path::new(path),
+        None => match mode {
+            Mode::EmitAssembly => usage_error("Too few arguments"),
+            Mode::Run | Mode::Build | Mode::Interp | Mode::Debug => {
+                usage_error("build, interp, debug and run comands require an input file")
+            }
+            Mode::Repl => return repl(&interp_config),
+            Mode::Lsp => return lsp(),
+            Mode::Fmt => unreachable!("fmt takes every input file"),
+        },
+    };
+    match mode {
+        Mode::EmitAssembly => emit_assembly(path, &compiler),
+        Mode::Run => run(path, &compiler, &limits),
+        Mode::Build => {
+            let output = output.unwrap_or_else(|| default_output(path, artifact));
+            match obj_dir {
+                Some(obj_dir) => build_separately(path, &compiler, &output, &obj_dir),
+                None => build(path, &compiler, artifact, &output, save_temps),
+            }
+        }
+        Mode::Interp => match (trace_only, profile_to) {
+            (Some(only), _) => trace(path, &compiler, &interp_config, &only, &mut std::io::stdout()),
+            (None, Some(folded)) => profile(
+                path,
+                &compiler,
+                &interp_config,
+                folded.as_deref(),
+                &mut std::io::stdout(),
+            ),
+            (None, None) => interp(path, &compiler, &interp_config, &mut std::io::stdout()),
+        },
+        Mode::Debug => debug(path, &compiler, &interp_config, record),
+        Mode::Repl | Mode::Lsp | Mode::Fmt => unreachable!("handled before reading the input file"),
+    }
+}
+
+fn default_output(input: &Path, artifact: Artifact) -> PathBuf {
+    let stem = PathBuf::from(input.file_stem().unwrap_or(input.as_os_str()));
+    match artifact.extension() {
+        Some(ext) => stem.with_extension(ext),
+        None => stem,
+    }
+}
+
+fn parse_flag_value<T>(flag: &str, value: Option<&str>) -> T
+where
+    T: std::str::FromStr,
+    T::Err: std::fmt::Display,
+{
+    match value {
+        None => usage_error(&format!("{} requires a value", flag)),
+        Some(v) => match v.parse() {
+            Ok(t) => t,
+            Err(e) => usage_error(&format!("Invalid value for {}: {}", flag, e)),
+        },
     }
 }
 
 fn usage(err_msg: Option<&str>) {
     match err_msg {
         None => println!("{}", USAGE_MSG),
-        Some(e) => {
-            eprintln!("{}", e);
-            eprintln!("{}", USAGE_MSG);
-            std::process::exit(1);
-        }
+        Some(e) => usage_error(e),
     }
 }
+
+fn usage_error(e: &str) -> ! {
+    eprintln!("{}", e);
+    eprintln!("{}", USAGE_MSG);
+    std::process::exit(1);
+}
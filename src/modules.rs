@@ -0,0 +1,194 @@
+/* Loading a program together with the files it imports.
+ *
+ * `import "path"` looks for the file at `path` relative to the directory
+ * of the importing file, and then in each of the search directories. Each
+ * file is loaded once, however many files import it, and becomes one of
+ * the `Module`s of the program, after the modules it imports.
+ *
+ * The prelude, `src/prelude.garter`, is always the first module, and every
+ * file imports it before anything else.
+ */
+use crate::compile::{parse_error_location, parse_file};
+use crate::errors::{CompileErr, RunnerErr};
+use crate::span::{FileId, Files, Span1, Span2, MAIN_FILE};
+use crate::syntax::{Exp, Module, SurfProg};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+const PRELUDE: &str = include_str!("prelude.garter");
+
+// Where the prelude is in the `Modules` of every loaded program
+pub const PRELUDE_MODULE: usize = 0;
+// and its file, which is loaded right after the main one
+pub const PRELUDE_FILE: FileId = MAIN_FILE + 1;
+
+// Why a program couldn't be loaded, and where, which may be in one of the
+// files it imports
+pub(crate) struct LoadErr {
+  pub(crate) location: Span1,
+  pub(crate) err: RunnerErr<Span2>,
+}
+
+struct Loader<'a> {
+  search_dirs: &'a [PathBuf],
+  files: Files,
+  modules: Vec<Module<Span1>>,
+  // the module loaded from each file, by its canonical path
+  loaded: HashMap<PathBuf, usize>,
+  // the files being loaded, each imported by the one before it
+  loading: Vec<PathBuf>,
+}
+
+impl<'a> Loader<'a> {
+  fn parse(&mut self, name: &str, source: &str) -> Result<SurfProg<Span1>, LoadErr> {
+    let file = self.files.add(name, source);
+    parse_file(source, file).map_err(|e| {
+      let location = parse_error_location(&e, file);
+      LoadErr {
+        location,
+        err: RunnerErr::Parse {
+          msg: if file == MAIN_FILE {
+            e.to_string()
+          } else {
+            format!("{} in {}", e, name)
+          },
+          location: Some(self.files.span2(location)),
+        },
+      }
+    })
+  }
+
+  fn load_prelude(&mut self) -> Result<(), LoadErr> {
+    let prog = self.parse("<prelude>", PRELUDE)?;
+    self.modules.push(Module {
+      name: String::from("prelude"),
+      prog,
+    });
+    Ok(())
+  }
+
+  // `prog` importing the prelude before its own imports
+  fn with_prelude(prog: SurfProg<Span1>) -> SurfProg<Span1> {
+    Exp::Import {
+      path: String::from("<prelude>"),
+      module: Some(PRELUDE_MODULE),
+      ann: prog.ann(),
+      body: Box::new(prog),
+    }
+  }
+
+  fn error(&self, e: CompileErr<Span1>) -> LoadErr {
+    LoadErr {
+      location: *e.location(),
+      err: RunnerErr::CodeGen(e.map_span(|s| self.files.span2(*s))),
+    }
+  }
+
+  // The file `path` names when imported from `dir`, as found and canonicalized
+  fn find(&self, path: &str, dir: &Path) -> Option<(PathBuf, PathBuf)> {
+    std::iter::once(dir)
+      .chain(self.search_dirs.iter().map(PathBuf::as_path))
+      .map(|dir| dir.join(path))
+      .find(|found| found.is_file())
+      .and_then(|found| Some((found.canonicalize().ok()?, found)))
+  }
+
+  // Loads the files imported at the start of `prog`, a file in `dir`, and
+  // points its imports at their modules
+  fn resolve(&mut self, prog: &mut SurfProg<Span1>, dir: &Path) -> Result<(), LoadErr> {
+    let mut e = prog;
+    while let Exp::Import {
+      path,
+      module,
+      body,
+      ann,
+    } = e
+    {
+      let (canonical, found) = self.find(path, dir).ok_or_else(|| {
+        self.error(CompileErr::UnknownModule {
+          path: path.clone(),
+          location: *ann,
+        })
+      })?;
+      if self.loading.contains(&canonical) {
+        return Err(self.error(CompileErr::ImportCycle {
+          path: path.clone(),
+          location: *ann,
+        }));
+      }
+      *module = Some(match self.loaded.get(&canonical) {
+        Some(i) => *i,
+        None => self.load_file(canonical, &found, *ann)?,
+      });
+      e = body;
+    }
+    Ok(())
+  }
+
+  fn load_file(&mut self, canonical: PathBuf, found: &Path, import: Span1) -> Result<usize, LoadErr> {
+    let source = std::fs::read_to_string(found).map_err(|e| LoadErr {
+      location: import,
+      err: RunnerErr::FileOpen(format!("{}: {}", found.display(), e)),
+    })?;
+    let mut prog = self.parse(&found.display().to_string(), &source)?;
+    self.loading.push(canonical.clone());
+    self.resolve(&mut prog, found.parent().unwrap_or(Path::new("")))?;
+    self.loading.pop();
+    self.modules.push(Module {
+      name: module_name(found),
+      prog: Loader::with_prelude(prog),
+    });
+    self.loaded.insert(canonical, self.modules.len() - 1);
+    Ok(self.modules.len() - 1)
+  }
+}
+
+// The namespace of the names a file exports: its file name without the
+// extension, with anything that can't be in a label replaced
+fn module_name(path: &Path) -> String {
+  path
+    .file_stem()
+    .map(|stem| stem.to_string_lossy())
+    .unwrap_or_default()
+    .chars()
+    .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+    .collect()
+}
+
+// The declarations of the prelude, with the spans of the file `file`
+pub(crate) fn prelude(file: FileId) -> SurfProg<Span1> {
+  parse_file(PRELUDE, file).expect("the prelude parses")
+}
+
+// Parses the main file of a program, in `dir`, and the files it imports,
+// wrapped in `Exp::Modules` with the prelude
+pub fn load(
+  source: &str,
+  dir: &Path,
+  search_dirs: &[PathBuf],
+) -> Result<(SurfProg<Span1>, Files), RunnerErr<Span2>> {
+  load_program(source, dir, search_dirs).map_err(|e| e.err)
+}
+
+pub(crate) fn load_program(
+  source: &str,
+  dir: &Path,
+  search_dirs: &[PathBuf],
+) -> Result<(SurfProg<Span1>, Files), LoadErr> {
+  let mut loader = Loader {
+    search_dirs,
+    files: Files::new(),
+    modules: Vec::new(),
+    loaded: HashMap::new(),
+    loading: Vec::new(),
+  };
+  let mut prog = loader.parse("", source)?;
+  loader.load_prelude()?;
+  loader.resolve(&mut prog, dir)?;
+  let prog = Exp::Modules {
+    modules: loader.modules,
+    ann: prog.ann(),
+    body: Box::new(Loader::with_prelude(prog)),
+  };
+  Ok((prog, loader.files))
+}
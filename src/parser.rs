@@ -1,8 +1,8 @@
 // auto-generated: "lalrpop 0.19.8"
-// sha3: ffe6d993cfe132ea33ffba17c9ccdc240885d36ec63a6a617f44c7d8a005b519
-use std::str::FromStr;
-use crate::syntax::{FunDecl, SurfProg, SurfFunDecl, Exp, Prim1, Prim2, SnakeType};
-use crate::span::Span1;
+// sha3: 9cca52d0a6cc5c2ce6bb6a53ed944ff7bdbaf599620a79c659b6a360910aefb9
+use crate::syntax::{FunDecl, SurfProg, SurfFunDecl, Exp, Prim0, Prim1, Prim2, SnakeType};
+use crate::span::{FileId, Span1};
+use crate::lexer::{LexError, Tok};
 #[allow(unused_extern_crates)]
 extern crate lalrpop_util as __lalrpop_util;
 #[allow(unused_imports)]
@@ -14,431 +14,545 @@ extern crate alloc;
 mod __parse__Prog {
     #![allow(non_snake_case, non_camel_case_types, unused_mut, unused_variables, unused_imports, unused_parens, clippy::all)]
 
-    use std::str::FromStr;
-    use crate::syntax::{FunDecl, SurfProg, SurfFunDecl, Exp, Prim1, Prim2, SnakeType};
-    use crate::span::Span1;
+    use crate::syntax::{FunDecl, SurfProg, SurfFunDecl, Exp, Prim0, Prim1, Prim2, SnakeType};
+    use crate::span::{FileId, Span1};
+    use crate::lexer::{LexError, Tok};
     #[allow(unused_extern_crates)]
     extern crate lalrpop_util as __lalrpop_util;
     #[allow(unused_imports)]
     use self::__lalrpop_util::state_machine as __state_machine;
     extern crate core;
     extern crate alloc;
-    use self::__lalrpop_util::lexer::Token;
+    use super::__ToTriple;
     #[allow(dead_code)]
-    pub(crate) enum __Symbol<'input>
+    pub(crate) enum __Symbol<>
      {
-        Variant0(&'input str),
-        Variant1(Exp<Span1>),
-        Variant2(alloc::vec::Vec<Exp<Span1>>),
-        Variant3(String),
-        Variant4(alloc::vec::Vec<String>),
-        Variant5((SurfFunDecl<Span1>, &'input str)),
-        Variant6(alloc::vec::Vec<(SurfFunDecl<Span1>, &'input str)>),
-        Variant7(usize),
-        Variant8(Vec<(String, Exp<Span1>)>),
-        Variant9(bool),
-        Variant10(Box<Exp<Span1>>),
-        Variant11(Vec<(SnakeType, Vec<String>, Exp<Span1>)>),
-        Variant12((SnakeType, Vec<String>, Exp<Span1>)),
-        Variant13(Prim2),
-        Variant14(Vec<Exp<Span1>>),
-        Variant15(Vec<String>),
-        Variant16(core::option::Option<Exp<Span1>>),
-        Variant17(SurfFunDecl<Span1>),
-        Variant18(core::option::Option<String>),
-        Variant19(()),
-        Variant20(i64),
-        Variant21(Prim1),
-        Variant22(SurfProg<Span1>),
-        Variant23(Vec<(String, Vec<String>)>),
-        Variant24((String, Vec<String>)),
+        Variant0(Tok),
+        Variant1(String),
+        Variant2(i64),
+        Variant3(Exp<Span1>),
+        Variant4(alloc::vec::Vec<Exp<Span1>>),
+        Variant5((String, String)),
+        Variant6(alloc::vec::Vec<(String, String)>),
+        Variant7(alloc::vec::Vec<String>),
+        Variant8((SurfFunDecl<Span1>, Tok)),
+        Variant9(alloc::vec::Vec<(SurfFunDecl<Span1>, Tok)>),
+        Variant10(usize),
+        Variant11(Vec<(String, Exp<Span1>)>),
+        Variant12(bool),
+        Variant13(Box<Exp<Span1>>),
+        Variant14(Box<SurfProg<Span1>>),
+        Variant15(Vec<(SnakeType, Vec<String>, Exp<Span1>)>),
+        Variant16((SnakeType, Vec<String>, Exp<Span1>)),
+        Variant17(Prim2),
+        Variant18(Vec<Exp<Span1>>),
+        Variant19(Vec<(String, String)>),
+        Variant20(Vec<String>),
+        Variant21(core::option::Option<String>),
+        Variant22(core::option::Option<Exp<Span1>>),
+        Variant23(core::option::Option<(String, String)>),
+        Variant24(SurfFunDecl<Span1>),
+        Variant25(Prim1),
+        Variant26(SurfProg<Span1>),
+        Variant27(Vec<(String, Vec<String>)>),
+        Variant28((String, Vec<String>)),
     }
     const __ACTION: &[i16] = &[
         // State 0
-        8, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 81, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 82, 12, 0, 83, 84, 85, 86, 87, 88, 13, 14, 89, 90, 91, 15, 0, 92, 93, 94,
+        7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 9, 0, 95, 0, 96, 0, 97, 98, 0, 0, 0, 99, 100, 10, 101, 0, 102, 103, 104, 105, 11, 106, 12, 13, 107, 14, 108, 109, 110, 111, 0, 112, 15, 16, 17, 18, 0, 113, 0, 0, 0, 114, 115, 116, 0, 0,
         // State 1
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 98, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 118, 0, 0, 0, 0,
         // State 2
-        0, 0, 96, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 97, 0, 0, 0,
+        0, 0, 120, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 121, 0, 0, 0, 0, 0, 0,
         // State 3
-        0, 98, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 99, 100, 0, 101, 0, 102, 103, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 122, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 123, 124, 0, 125, 0, 126, 127, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 4
-        0, 0, 0, 0, 0, 0, 104, 0, 105, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 128, 0, 129, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 5
-        0, 0, 0, 0, 0, 106, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 130, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 6
-        0, 0, 0, 0, 0, 0, 0, 0, 0, -65, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 94,
+        0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 9, 0, 95, 0, 96, 0, 97, 0, 0, 0, 0, 99, 100, 0, 0, 0, 102, 103, 104, 105, 11, 106, 0, 0, 107, 0, 108, 109, 110, 111, 0, 112, 0, 0, 0, 18, 0, 113, 0, 0, 0, 0, 135, 116, 0, 0,
         // State 7
-        0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 81, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 82, 0, 0, 83, 84, 85, 86, 87, 88, 0, 0, 89, 90, 91, 0, 0, 92, 93, 94,
+        7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 9, 0, 95, 0, 96, 0, 97, 98, 0, 0, 0, 99, 100, 10, 0, 0, 102, 103, 104, 105, 11, 106, 12, 13, 107, 14, 108, 109, 110, 111, 0, 112, 15, 16, 17, 18, 0, 113, 0, 0, 0, 114, 115, 116, 0, 0,
         // State 8
-        8, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 81, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 82, 12, 0, 83, 84, 85, 86, 87, 88, 13, 14, 89, 90, 91, 15, 0, 92, 93, 94,
+        7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 9, -72, 95, 0, 96, 0, 97, 98, 0, 0, 0, 99, 100, 10, 0, 0, 102, 103, 104, 105, 11, 106, 12, 13, 107, 14, 108, 109, 110, 111, 0, 112, 15, 16, 17, 18, 0, 113, 0, 0, 0, 114, 115, 116, 0, 0,
         // State 9
-        8, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, -61, 81, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 82, 12, 0, 83, 84, 85, 86, 87, 88, 13, 14, 89, 90, 91, 15, 0, 92, 93, 94,
+        7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 9, 0, 95, 0, 96, 0, 97, 98, 0, 0, 0, 99, 100, 10, 0, 0, 102, 103, 104, 105, 11, 106, 12, 13, 107, 14, 108, 109, 110, 111, 0, 112, 15, 16, 17, 18, 0, 113, 0, 0, 0, 114, 115, 116, 0, 0,
         // State 10
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 94,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -80, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 147, 0, 0, 0,
         // State 11
-        8, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 81, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 82, 12, 0, 83, 84, 85, 86, 87, 88, 13, 14, 89, 90, 91, 15, 0, 92, 93, 94,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 150, 0, 0, 0,
         // State 12
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 94,
+        7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 9, 0, 95, 0, 96, 0, 97, 98, 0, 0, 0, 99, 100, 10, 0, 0, 102, 103, 104, 105, 11, 106, 12, 13, 107, 14, 108, 109, 110, 111, 0, 112, 15, 16, 17, 18, 0, 113, 0, 0, 0, 114, 115, 116, 0, 0,
         // State 13
-        8, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 81, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 82, 12, 0, 83, 84, 85, 86, 87, 88, 13, 14, 89, 90, 91, 15, 0, 92, 93, 94,
+        0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 9, 0, 95, 0, 96, 0, 97, 0, 0, 0, 0, 99, 100, 0, 0, 0, 102, 103, 104, 105, 11, 106, 0, 0, 107, 0, 108, 109, 110, 111, 0, 112, 0, 0, 0, 18, 0, 113, 0, 0, 0, 0, 135, 116, 0, 0,
         // State 14
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 94,
+        7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 9, 0, 95, 0, 96, 0, 97, 98, 0, 0, 0, 99, 100, 10, 0, 0, 102, 103, 104, 105, 11, 106, 12, 13, 107, 14, 108, 109, 110, 111, 0, 112, 15, 16, 17, 18, 0, 113, 0, 0, 0, 114, 115, 116, 0, 0,
         // State 15
-        8, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 81, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 82, 12, 0, 83, 84, 85, 86, 87, 88, 13, 14, 89, 90, 91, 15, 0, 92, 93, 94,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 156, 0, 0, 0,
         // State 16
-        8, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 81, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 82, 12, 0, 83, 84, 85, 86, 87, 88, 13, 14, 89, 90, 91, 15, 0, 92, 93, 94,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 150, 0, 0, 0,
         // State 17
-        8, 0, 0, 9, -61, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 81, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 82, 12, 0, 83, 84, 85, 86, 87, 88, 13, 14, 89, 90, 91, 15, 0, 92, 93, 94,
+        7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 9, 0, 95, 0, 96, 0, 97, 98, 0, 0, 0, 99, 100, 10, 0, 0, 102, 103, 104, 105, 11, 106, 12, 13, 107, 14, 108, 109, 110, 111, 0, 112, 15, 16, 17, 18, 0, 113, 0, 0, 0, 114, 115, 116, 0, 0,
         // State 18
-        8, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 81, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 82, 0, 0, 83, 84, 85, 86, 87, 88, 0, 0, 89, 90, 91, 0, 0, 92, 93, 94,
+        7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 9, 0, 95, 0, 96, 0, 97, 98, 0, 0, 0, 99, 100, 10, 0, 0, 102, 103, 104, 105, 11, 106, 12, 13, 107, 14, 108, 109, 110, 111, 0, 112, 15, 16, 17, 18, 0, 113, 0, 0, 0, 114, 115, 116, 0, 0,
         // State 19
-        8, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 81, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 82, 0, 0, 83, 84, 85, 86, 87, 88, 0, 0, 89, 90, 91, 0, 0, 92, 93, 94,
+        7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 9, 0, 95, 0, 96, 0, 97, 98, 0, 0, 0, 99, 100, 10, 0, 0, 102, 103, 104, 105, 11, 106, 12, 13, 107, 14, 108, 109, 110, 111, 0, 112, 15, 16, 17, 18, 0, 113, 0, 0, 0, 114, 115, 116, 0, 0,
         // State 20
-        8, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 81, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 82, 0, 0, 83, 84, 85, 86, 87, 88, 0, 0, 89, 90, 91, 0, 0, 92, 93, 94,
+        7, 0, 0, 8, -72, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 9, 0, 95, 0, 96, 0, 97, 98, 0, 0, 0, 99, 100, 10, 0, 0, 102, 103, 104, 105, 11, 106, 12, 13, 107, 14, 108, 109, 110, 111, 0, 112, 15, 16, 17, 18, 0, 113, 0, 0, 0, 114, 115, 116, 0, 0,
         // State 21
-        8, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 81, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 82, 0, 0, 83, 84, 85, 86, 87, 88, 0, 0, 89, 90, 91, 0, 0, 92, 93, 94,
+        7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 9, 0, 95, 0, 96, 0, 97, 0, 0, 0, 0, 99, 100, 0, 0, 0, 102, 103, 104, 105, 11, 106, 0, 0, 107, 14, 108, 109, 110, 111, 0, 112, 0, 0, 0, 18, 0, 113, 0, 0, 0, 0, 135, 116, 0, 0,
         // State 22
-        8, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 81, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 82, 12, 0, 83, 84, 85, 86, 87, 88, 13, 14, 89, 90, 91, 15, 0, 92, 93, 94,
+        7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 9, 0, 95, 0, 96, 0, 97, 0, 0, 0, 0, 99, 100, 0, 0, 0, 102, 103, 104, 105, 11, 106, 0, 0, 107, 14, 108, 109, 110, 111, 0, 112, 0, 0, 0, 18, 0, 113, 0, 0, 0, 0, 135, 116, 0, 0,
         // State 23
-        0, 0, 0, 0, -67, 0, 0, 0, 0, -67, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 94,
+        7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 9, 0, 95, 0, 96, 0, 97, 0, 0, 0, 0, 99, 100, 0, 0, 0, 102, 103, 104, 105, 11, 106, 0, 0, 107, 14, 108, 109, 110, 111, 0, 112, 0, 0, 0, 18, 0, 113, 0, 0, 0, 0, 135, 116, 0, 0,
         // State 24
-        8, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 81, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 82, 12, 0, 83, 84, 85, 86, 87, 88, 13, 14, 89, 90, 91, 15, 0, 92, 93, 94,
+        7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 9, 0, 95, 0, 96, 0, 97, 0, 0, 0, 0, 99, 100, 0, 0, 0, 102, 103, 104, 105, 11, 106, 0, 0, 107, 14, 108, 109, 110, 111, 0, 112, 0, 0, 0, 18, 0, 113, 0, 0, 0, 0, 135, 116, 0, 0,
         // State 25
-        8, 0, 0, 9, -63, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, -63, 81, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 82, 12, 0, 83, 84, 85, 86, 87, 88, 13, 14, 89, 90, 91, 15, 0, 92, 93, 94,
+        7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 9, 0, 95, 0, 96, 0, 97, 98, 0, 0, 0, 99, 100, 10, 0, 0, 102, 103, 104, 105, 11, 106, 12, 13, 107, 14, 108, 109, 110, 111, 0, 112, 15, 16, 17, 18, 0, 113, 0, 0, 0, 114, 115, 116, 0, 0,
         // State 26
-        8, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 81, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 82, 12, 0, 83, 84, 85, 86, 87, 88, 13, 14, 89, 90, 91, 15, 0, 92, 93, 94,
+        7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 9, 0, 95, 0, 96, 0, 97, 98, 0, 0, 0, 99, 100, 10, 0, 0, 102, 103, 104, 105, 11, 106, 12, 13, 107, 14, 108, 109, 110, 111, 0, 112, 15, 16, 17, 18, 0, 113, 0, 0, 0, 114, 115, 116, 0, 0,
         // State 27
-        8, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 81, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 82, 12, 0, 83, 84, 85, 86, 87, 88, 13, 14, 89, 90, 91, 15, 0, 92, 93, 94,
+        7, 0, 0, 8, -74, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 9, -74, 95, 0, 96, 0, 97, 98, 0, 0, 0, 99, 100, 10, 0, 0, 102, 103, 104, 105, 11, 106, 12, 13, 107, 14, 108, 109, 110, 111, 0, 112, 15, 16, 17, 18, 0, 113, 0, 0, 0, 114, 115, 116, 0, 0,
         // State 28
-        0, 0, 0, 0, -65, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 94,
+        7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 9, 0, 95, 0, 96, 0, 97, 98, 0, 0, 0, 99, 100, 10, 101, 0, 102, 103, 104, 105, 11, 106, 12, 13, 107, 14, 108, 109, 110, 111, 0, 112, 15, 16, 17, 18, 0, 113, 0, 0, 0, 114, 115, 116, 0, 0,
         // State 29
-        8, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 81, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 82, 12, 0, 83, 84, 85, 86, 87, 88, 13, 14, 89, 90, 91, 15, 0, 92, 93, 94,
+        7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 9, 0, 95, 0, 96, 0, 97, 98, 0, 0, 0, 99, 100, 10, 0, 0, 102, 103, 104, 105, 11, 106, 12, 13, 107, 14, 108, 109, 110, 111, 0, 112, 15, 16, 17, 18, 0, 113, 0, 0, 0, 114, 115, 116, 0, 0,
         // State 30
-        8, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 81, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 82, 12, 0, 83, 84, 85, 86, 87, 88, 13, 14, 89, 90, 91, 15, 0, 92, 93, 94,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 156, 0, 0, 0,
         // State 31
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 94,
+        7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 9, 0, 95, 0, 96, 0, 97, 0, 0, 0, 0, 99, 100, 0, 0, 0, 102, 103, 104, 105, 11, 106, 0, 0, 107, 14, 108, 109, 110, 111, 0, 112, 0, 0, 0, 18, 0, 113, 0, 0, 0, 0, 135, 116, 0, 0,
         // State 32
-        8, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 81, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 82, 12, 0, 83, 84, 85, 86, 87, 88, 13, 14, 89, 90, 91, 15, 0, 92, 93, 94,
+        7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 9, 0, 95, 0, 96, 0, 97, 98, 0, 0, 0, 99, 100, 10, 0, 0, 102, 103, 104, 105, 11, 106, 12, 13, 107, 14, 108, 109, 110, 111, 0, 112, 15, 16, 17, 18, 0, 113, 0, 0, 0, 114, 115, 116, 0, 0,
         // State 33
-        8, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 81, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 82, 12, 0, 83, 84, 85, 86, 87, 88, 13, 14, 89, 90, 91, 15, 0, 92, 93, 94,
+        0, 0, 0, 0, -80, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 147, 0, 0, 0,
         // State 34
-        0, 0, 0, 0, -65, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 94,
+        7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 9, 0, 95, 0, 96, 0, 97, 98, 0, 0, 0, 99, 100, 10, 0, 0, 102, 103, 104, 105, 11, 106, 12, 13, 107, 14, 108, 109, 110, 111, 0, 112, 15, 16, 17, 18, 0, 113, 0, 0, 0, 114, 115, 116, 0, 0,
         // State 35
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 94,
+        7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 9, 0, 95, 0, 96, 0, 97, 98, 0, 0, 0, 99, 100, 10, 0, 0, 102, 103, 104, 105, 11, 106, 12, 13, 107, 14, 108, 109, 110, 111, 0, 112, 15, 16, 17, 18, 0, 113, 0, 0, 0, 114, 115, 116, 0, 0,
         // State 36
-        8, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 81, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 82, 12, 0, 83, 84, 85, 86, 87, 88, 13, 14, 89, 90, 91, 15, 0, 92, 93, 94,
+        7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 9, 0, 95, 0, 96, 0, 97, 98, 0, 0, 0, 99, 100, 10, 0, 0, 102, 103, 104, 105, 11, 106, 12, 13, 107, 14, 108, 109, 110, 111, 0, 112, 15, 16, 17, 18, 0, 113, 0, 0, 0, 114, 115, 116, 0, 0,
         // State 37
-        0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 81, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 82, 0, 0, 83, 84, 85, 86, 87, 88, 0, 0, 89, 90, 91, 0, 0, 92, 93, 94,
+        7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 9, 0, 95, 0, 96, 0, 97, 98, 0, 0, 0, 99, 100, 10, 0, 0, 102, 103, 104, 105, 11, 106, 12, 13, 107, 14, 108, 109, 110, 111, 0, 112, 15, 16, 17, 18, 0, 113, 0, 0, 0, 114, 115, 116, 0, 0,
         // State 38
-        8, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 81, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 82, 12, 0, 83, 84, 85, 86, 87, 88, 13, 14, 89, 90, 91, 15, 0, 92, 93, 94,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 150, 0, 0, 0,
         // State 39
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 43, 170, 171, 172, 173, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 9, 0, 95, 0, 96, 0, 97, 98, 0, 0, 0, 99, 100, 10, 0, 0, 102, 103, 104, 105, 11, 106, 12, 13, 107, 14, 108, 109, 110, 111, 0, 112, 15, 16, 17, 18, 0, 113, 0, 0, 0, 114, 115, 116, 0, 0,
         // State 40
-        8, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 81, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 82, 12, 0, 83, 84, 85, 86, 87, 88, 13, 14, 89, 90, 91, 15, 0, 92, 93, 94,
+        7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 9, 0, 95, 0, 96, 0, 97, 98, 0, 0, 0, 99, 100, 10, 0, 0, 102, 103, 104, 105, 11, 106, 12, 13, 107, 14, 108, 109, 110, 111, 0, 112, 15, 16, 17, 18, 0, 113, 0, 0, 0, 114, 115, 116, 0, 0,
         // State 41
-        8, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 81, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 82, 12, 0, 83, 84, 85, 86, 87, 88, 13, 14, 89, 90, 91, 15, 0, 92, 93, 94,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 50, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 42
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 94,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 156, 0, 0, 0,
         // State 43
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 43, 170, 171, 172, 173, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 9, 0, 95, 0, 96, 0, 97, 98, 0, 0, 0, 99, 100, 10, 0, 0, 102, 103, 104, 105, 11, 106, 12, 13, 107, 14, 108, 109, 110, 111, 0, 112, 15, 16, 17, 18, 0, 113, 0, 0, 0, 114, 115, 116, 0, 0,
         // State 44
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 94,
+        0, 0, 0, 0, -80, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 147, 0, 0, 0,
         // State 45
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 94,
+        7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 9, 0, 95, 0, 96, 0, 97, 98, 0, 0, 0, 99, 100, 10, 0, 0, 102, 103, 104, 105, 11, 106, 12, 13, 107, 14, 108, 109, 110, 111, 0, 112, 15, 16, 17, 18, 0, 113, 0, 0, 0, 114, 115, 116, 0, 0,
         // State 46
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 94,
+        7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 9, 0, 95, 0, 96, 0, 97, 98, 0, 0, 0, 99, 100, 10, 0, 0, 102, 103, 104, 105, 11, 106, 12, 13, 107, 14, 108, 109, 110, 111, 0, 112, 15, 16, 17, 18, 0, 113, 0, 0, 0, 114, 115, 116, 0, 0,
         // State 47
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 94,
+        7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 9, 0, 95, 0, 96, 0, 97, 0, 0, 0, 0, 99, 100, 0, 0, 0, 102, 103, 104, 105, 11, 106, 0, 0, 107, 14, 108, 109, 110, 111, 0, 112, 0, 0, 0, 18, 0, 113, 0, 0, 0, 0, 135, 116, 0, 0,
         // State 48
-        8, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 81, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 82, 12, 0, 83, 84, 85, 86, 87, 88, 13, 14, 89, 90, 91, 15, 0, 92, 93, 94,
+        7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 9, 0, 95, 0, 96, 0, 97, 98, 0, 0, 0, 99, 100, 10, 0, 0, 102, 103, 104, 105, 11, 106, 12, 13, 107, 14, 108, 109, 110, 111, 0, 112, 15, 16, 17, 18, 0, 113, 0, 0, 0, 114, 115, 116, 0, 0,
         // State 49
-        8, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 81, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 82, 12, 0, 83, 84, 85, 86, 87, 88, 13, 14, 89, 90, 91, 15, 0, 92, 93, 94,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 218, 0, 0, 0,
         // State 50
-        8, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 81, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 82, 12, 0, 83, 84, 85, 86, 87, 88, 13, 14, 89, 90, 91, 15, 0, 92, 93, 94,
+        0, 0, 0, 0, -80, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 147, 0, 0, 0,
         // State 51
-        8, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 81, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 82, 12, 0, 83, 84, 85, 86, 87, 88, 13, 14, 89, 90, 91, 15, 0, 92, 93, 94,
+        7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 9, 0, 95, 0, 96, 0, 97, 98, 0, 0, 0, 99, 100, 10, 0, 0, 102, 103, 104, 105, 11, 106, 12, 13, 107, 14, 108, 109, 110, 111, 0, 112, 15, 16, 17, 18, 0, 113, 0, 0, 0, 114, 115, 116, 0, 0,
         // State 52
-        8, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 81, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 82, 12, 0, 83, 84, 85, 86, 87, 88, 13, 14, 89, 90, 91, 15, 0, 92, 93, 94,
+        0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 9, 0, 95, 0, 96, 0, 97, 0, 0, 0, 0, 99, 100, 0, 0, 0, 102, 103, 104, 105, 11, 106, 0, 0, 107, 0, 108, 109, 110, 111, 0, 112, 0, 0, 0, 18, 0, 113, 0, 0, 0, 0, 135, 116, 0, 0,
         // State 53
-        0, -97, -97, 0, -97, -97, -97, -97, -97, -97, 0, -97, -97, -97, 0, -97, 0, -97, -97, -35, -97, 0, -97, 0, 0, 0, 0, 0, 0, -97, -97, -97, 0, 0, -97, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -97, 0, 0, 0,
+        7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 9, 0, 95, 0, 96, 0, 97, 98, 0, 0, 0, 99, 100, 10, 0, 0, 102, 103, 104, 105, 11, 106, 12, 13, 107, 14, 108, 109, 110, 111, 0, 112, 15, 16, 17, 18, 0, 113, 0, 0, 0, 114, 115, 116, 0, 0,
         // State 54
-        0, -18, -18, -36, -18, -18, -18, -18, -18, -18, 0, -18, -18, -18, 0, -18, 0, -18, -18, -18, -18, 0, -18, 0, 0, 0, 0, 0, 0, -18, -18, -18, 0, 0, -18, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -18, 0, 0, 0,
+        7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 9, 0, 95, 0, 96, 0, 97, 98, 0, 0, 0, 99, 100, 10, 0, 0, 102, 103, 104, 105, 11, 106, 12, 13, 107, 14, 108, 109, 110, 111, 0, 112, 15, 16, 17, 18, 0, 113, 0, 0, 0, 114, 115, 116, 0, 0,
         // State 55
-        0, 0, 0, 0, -115, 0, 0, -115, 0, -115, 0, 16, 0, 0, 0, 0, 0, 0, 0, 0, -115, 0, -115, 0, 0, 0, 0, 0, 0, -115, -115, -115, 0, 0, -115, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 50, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 56
-        0, -22, -22, -22, -22, -22, -22, -22, -22, -22, 0, -22, -22, -22, 0, -22, 0, -22, -22, -22, -22, 0, -22, 0, 0, 0, 0, 0, 0, -22, -22, -22, 0, 0, -22, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -22, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 50, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 57
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 17, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 9, 0, 95, 0, 96, 0, 97, 98, 0, 0, 0, 99, 100, 10, 0, 0, 102, 103, 104, 105, 11, 106, 12, 13, 107, 14, 108, 109, 110, 111, 0, 112, 15, 16, 17, 18, 0, 113, 0, 0, 0, 114, 115, 116, 0, 0,
         // State 58
-        0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 9, 0, 95, 0, 96, 0, 97, 98, 0, 0, 0, 99, 100, 10, 0, 0, 102, 103, 104, 105, 11, 106, 12, 13, 107, 14, 108, 109, 110, 111, 0, 112, 15, 16, 17, 18, 0, 113, 0, 0, 0, 114, 115, 116, 0, 0,
         // State 59
-        0, 0, -37, 0, -113, 0, 0, -113, 0, -113, 0, -113, 0, 0, 0, 0, 0, 0, 0, 0, -113, 0, -113, 0, 0, 0, 0, 0, 0, -113, -113, -113, 0, 0, -113, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -37, 0, 0, 0,
+        7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 9, 0, 95, 0, 96, 0, 97, 98, 0, 0, 0, 99, 100, 10, 0, 0, 102, 103, 104, 105, 11, 106, 12, 13, 107, 14, 108, 109, 110, 111, 0, 112, 15, 16, 17, 18, 0, 113, 0, 0, 0, 114, 115, 116, 0, 0,
         // State 60
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -76, 0, 236, 0, 0, 0,
         // State 61
-        0, 0, 0, 0, -70, 0, 0, -70, 0, -70, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -70, 0, -70, 0, 0, 0, 0, 0, 0, -70, -70, -70, 0, 0, -70, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 9, 0, 95, 0, 96, 0, 97, 98, 0, 0, 0, 99, 100, 10, 0, 0, 102, 103, 104, 105, 11, 106, 12, 13, 107, 14, 108, 109, 110, 111, 0, 112, 15, 16, 17, 18, 0, 113, 0, 0, 0, 114, 115, 116, 0, 0,
         // State 62
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 107, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 23, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -78, 0, 236, 0, 0, 0,
         // State 63
-        0, 0, 0, 0, -68, 0, 0, -68, 0, -68, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -68, 0, -68, 0, 0, 0, 0, 0, 0, -68, -68, -68, 0, 0, -68, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 9, 0, 95, 0, 96, 0, 97, 98, 0, 0, 0, 99, 100, 10, 0, 0, 102, 103, 104, 105, 11, 106, 12, 13, 107, 14, 108, 109, 110, 111, 0, 112, 15, 16, 17, 18, 0, 113, 0, 0, 0, 114, 115, 116, 0, 0,
         // State 64
-        0, -21, -21, -21, -21, -21, -21, -21, -21, -21, 0, -21, -21, -21, 0, -21, 0, -21, -21, -21, -21, 0, -21, 0, 0, 0, 0, 0, 0, -21, -21, -21, 0, 0, -21, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -21, 0, 0, 0,
+        7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 9, 0, 95, 0, 96, 0, 97, 98, 0, 0, 0, 99, 100, 10, 0, 0, 102, 103, 104, 105, 11, 106, 12, 13, 107, 14, 108, 109, 110, 111, 0, 112, 15, 16, 17, 18, 0, 113, 0, 0, 0, 114, 115, 116, 0, 0,
         // State 65
-        0, -39, -59, 0, -59, 0, 0, -59, 0, -59, 0, -59, -39, -39, 0, -39, 0, -39, -39, 0, -59, 0, -59, 0, 0, 0, 0, 0, 0, -59, -59, -59, 0, 0, -59, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -59, 0, 0, 0,
+        0, -119, -119, 0, -119, -119, -119, -119, -119, -46, -119, 0, -119, -119, -119, 0, -119, 0, -119, -119, -46, -119, 0, -119, 0, 0, 0, 0, -119, -119, -119, 0, 0, 0, 0, -119, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -119, 0, 0, 0, 0, 0, -119, 0, 0, -119, 0, 0, 0, 0, 0, -119,
         // State 66
-        0, -116, -116, 0, -116, 0, -40, -116, -40, -116, 0, -116, -116, -116, 0, -116, 0, -116, -116, 0, -116, 0, -116, 0, 0, 0, 0, 0, 0, -116, -116, -116, 0, 0, -116, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -116, 0, 0, 0,
+        0, -23, -23, -47, -23, -23, -23, -23, -23, -23, -23, 0, -23, -23, -23, 0, -23, 0, -23, -23, -23, -23, 0, -23, 0, 0, 0, 0, -23, -23, -23, 0, 0, 0, 0, -23, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -23, 0, 0, 0, 0, 0, -23, 0, 0, -23, 0, 0, 0, 0, 0, -23,
         // State 67
-        0, -110, -110, 0, -110, -41, -110, -110, -110, -110, 0, -110, -110, -110, 0, -110, 0, -110, -110, 0, -110, 0, -110, 0, 0, 0, 0, 0, 0, -110, -110, -110, 0, 0, -110, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -110, 0, 0, 0,
+        0, 0, 0, 0, -141, 0, 0, -141, 0, 0, -141, 0, 19, 0, 0, 0, 0, 0, 0, 0, 0, -141, 0, -141, 0, 0, 0, 0, -141, -141, -141, 0, 0, 0, 0, -141, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -141, 0, 0, 0, 0, 0, -141, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 68
-        0, 0, 0, 0, -69, 0, 0, -69, 0, -69, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -69, 0, -69, 0, 0, 0, 0, 0, 0, -69, -69, -69, 0, 0, -69, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, 0, -28, -28, -28, 0, -28, 0, -28, -28, -28, -28, 0, -28, 0, 0, 0, 0, -28, -28, -28, 0, 0, 0, 0, -28, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -28, 0, 0, 0, 0, 0, -28, 0, 0, -28, 0, 0, 0, 0, 0, -28,
         // State 69
-        0, 0, 0, 0, -29, 0, 0, -29, 0, -29, 0, -29, 0, 0, 0, 0, 0, 0, 0, 0, -29, 0, -29, 0, 0, 0, 0, 0, 0, -29, -29, -29, 0, 0, -29, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 119, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 70
-        0, 0, 0, 0, -73, 0, 0, -73, 0, -73, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -73, 0, -73, 0, 0, 0, 0, 0, 0, -73, -73, -73, 0, 0, -73, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 21, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 71
-        0, -90, -90, 0, -90, -90, -90, -90, -90, -90, 0, -90, -90, -90, 0, -90, 0, -90, -90, 0, -90, 0, -90, 0, 0, 0, 0, 0, 0, -90, -90, -90, 0, 0, -90, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -90, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 131,
         // State 72
-        0, -23, -23, -23, -23, -23, -23, -23, -23, -23, 0, -23, -23, -23, 0, -23, 0, -23, -23, -23, -23, 0, -23, 0, 0, 0, 0, 0, 0, -23, -23, -23, 0, 0, -23, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -23, 0, 0, 0,
+        0, 0, -48, 0, -139, 0, 0, -139, 0, 0, -139, 0, -139, 0, 0, 0, 0, 0, 0, 0, 0, -139, 0, -139, 0, 0, 0, 0, -139, -139, -139, 0, 0, 0, 0, -139, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -139, 0, 0, 0, 0, 0, -139, 0, 0, -48, 0, 0, 0, 0, 0, -139,
         // State 73
-        0, 0, 0, 25, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 74
-        0, -88, -88, 0, -88, 0, -88, -88, -88, -88, 0, -88, -88, -88, 0, -88, 0, -88, -88, 0, -88, 0, -88, 0, 0, 0, 0, 0, 0, -88, -88, -88, 0, 0, -88, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -88, 0, 0, 0,
+        0, 0, 0, 0, -88, 0, 0, -88, 0, 0, -88, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -88, 0, -88, 0, 0, 0, 0, -88, -88, -88, 0, 0, 0, 0, -88, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -88, 0, 0, 0, 0, 0, -88, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 75
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 132, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 26, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 76
-        0, 0, 0, 0, -94, 0, 0, -94, 0, -94, 0, -94, 0, 0, 0, 0, 0, 0, 0, 0, -94, 0, -94, 0, 0, 0, 0, 0, 0, -94, -94, -94, 0, 0, -94, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, -85, 0, 0, -85, 0, 0, -85, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -85, 0, -85, 0, 0, 0, 0, -85, -85, -85, 0, 0, 0, 0, -85, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -85, 0, 0, 0, 0, 0, -85, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 77
-        0, 0, 0, 0, -71, 0, 0, -71, 0, -71, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -71, 0, -71, 0, 0, 0, 0, 0, 0, -71, -71, -71, 0, 0, -71, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -50, -70, 0, -70, 0, 0, -70, 0, 0, -70, 0, -70, -50, -50, 0, -50, 0, -50, -50, 0, -70, 0, -70, 0, 0, 0, 0, -70, -70, -70, 0, 0, 0, 0, -70, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -70, 0, 0, 0, 0, 0, -70, 0, 0, -70, 0, 0, 0, 0, 0, -70,
         // State 78
-        0, -86, -86, 0, -86, 0, 0, -86, 0, -86, 0, -86, -86, -86, 0, -86, 0, -86, -86, 0, -86, 0, -86, 0, 0, 0, 0, 0, 0, -86, -86, -86, 0, 0, -86, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -86, 0, 0, 0,
+        0, -142, -142, 0, -142, 0, -51, -142, -51, 0, -142, 0, -142, -142, -142, 0, -142, 0, -142, -142, 0, -142, 0, -142, 0, 0, 0, 0, -142, -142, -142, 0, 0, 0, 0, -142, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -142, 0, 0, 0, 0, 0, -142, 0, 0, -142, 0, 0, 0, 0, 0, -142,
         // State 79
-        0, 0, 0, 0, -72, 0, 0, -72, 0, -72, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -72, 0, -72, 0, 0, 0, 0, 0, 0, -72, -72, -72, 0, 0, -72, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -135, -135, 0, -135, -52, -135, -135, -135, 0, -135, 0, -135, -135, -135, 0, -135, 0, -135, -135, 0, -135, 0, -135, 0, 0, 0, 0, -135, -135, -135, 0, 0, 0, 0, -135, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -135, 0, 0, 0, 0, 0, -135, 0, 0, -135, 0, 0, 0, 0, 0, -135,
         // State 80
-        0, 0, 0, -102, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, -86, 0, 0, -86, 0, 0, -86, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -86, 0, -86, 0, 0, 0, 0, -86, -86, -86, 0, 0, 0, 0, -86, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -86, 0, 0, 0, 0, 0, -86, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 81
-        0, -34, -34, -34, -34, -34, -34, -34, -34, -34, 0, -34, -34, -34, 0, -34, 0, -34, -34, -34, -34, 0, -34, 0, 0, 0, 0, 0, 0, -34, -34, -34, 0, 0, -34, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -34, 0, 0, 0,
+        0, 0, 0, 0, -152, 0, 0, -152, 0, 0, -152, 0, -152, 0, 0, 0, 0, 0, 0, 0, 0, -152, 0, -152, 0, 0, 0, 0, -152, -152, -152, 0, 0, 0, 0, -152, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -152, 0, 0, 0, 0, 0, -152, 0, 0, 0, 0, 0, 0, 0, 0, -152,
         // State 82
-        0, 0, 0, -107, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, -91, 0, 0, -91, 0, 0, -91, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -91, 0, -91, 0, 0, 0, 0, -91, -91, -91, 0, 0, 0, 0, -91, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -91, 0, 0, 0, 0, 0, -91, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 83
-        0, 0, 0, -106, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -111, -111, 0, -111, -111, -111, -111, -111, 0, -111, 0, -111, -111, -111, 0, -111, 0, -111, -111, 0, -111, 0, -111, 0, 0, 0, 0, -111, -111, -111, 0, 0, 0, 0, -111, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -111, 0, 0, 0, 0, 0, -111, 0, 0, -111, 0, 0, 0, 0, 0, -111,
         // State 84
-        0, 0, 0, -109, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 27, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 85
-        0, 0, 0, -105, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -109, -109, 0, -109, 0, -109, -109, -109, 0, -109, 0, -109, -109, -109, 0, -109, 0, -109, -109, 0, -109, 0, -109, 0, 0, 0, 0, -109, -109, -109, 0, 0, 0, 0, -109, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -109, 0, 0, 0, 0, 0, -109, 0, 0, -109, 0, 0, 0, 0, 0, -109,
         // State 86
-        0, 0, 0, 0, 0, 0, 0, 0, 0, -83, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -83,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 87
-        0, 0, 0, -108, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, -115, 0, 0, -115, 0, 0, -115, 0, -115, 0, 0, 0, 0, 0, 0, 0, 0, -115, 0, -115, 0, 0, 0, 0, -115, -115, -115, 0, 0, 0, 0, -115, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -115, 0, 0, 0, 0, 0, -115, 0, 0, 0, 0, 0, 0, 0, 0, -115,
         // State 88
-        0, 0, 0, -104, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, -89, 0, 0, -89, 0, 0, -89, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -89, 0, -89, 0, 0, 0, 0, -89, -89, -89, 0, 0, 0, 0, -89, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -89, 0, 0, 0, 0, 0, -89, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 89
-        0, 0, 0, -103, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -107, -107, 0, -107, 0, 0, -107, 0, 0, -107, 0, -107, -107, -107, 0, -107, 0, -107, -107, 0, -107, 0, -107, 0, 0, 0, 0, -107, -107, -107, 0, 0, 0, 0, -107, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -107, 0, 0, 0, 0, 0, -107, 0, 0, -107, 0, 0, 0, 0, 0, -107,
         // State 90
-        0, -33, -33, -33, -33, -33, -33, -33, -33, -33, 0, -33, -33, -33, 0, -33, 0, -33, -33, -33, -33, 0, -33, 0, 0, 0, 0, 0, 0, -33, -33, -33, 0, 0, -33, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -33, 0, 0, 0,
+        0, 0, 0, 0, -92, 0, 0, -92, 0, 0, -92, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -92, 0, -92, 0, 0, 0, 0, -92, -92, -92, 0, 0, 0, 0, -92, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -92, 0, 0, 0, 0, 0, -92, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 91
-        0, 0, 0, 0, 0, 0, 0, 0, 0, -84, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -84,
+        0, 0, 0, 0, -90, 0, 0, -90, 0, 0, -90, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -90, 0, -90, 0, 0, 0, 0, -90, -90, -90, 0, 0, 0, 0, -90, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -90, 0, 0, 0, 0, 0, -90, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 92
-        0, -98, -98, -98, -98, -98, -98, -98, -98, -98, 0, -98, -98, -98, 0, -98, 0, -98, -98, -98, -98, 0, -98, 0, 0, 0, 0, 0, 0, -98, -98, -98, 0, 0, -98, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -98, 0, 0, 0,
+        0, 0, 0, 0, -40, 0, 0, -40, 0, 0, -40, 0, -40, 0, 0, 0, 0, 0, 0, 0, 0, -40, 0, -40, 0, 0, 0, 0, -40, -40, -40, 0, 0, 0, 0, -40, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -40, 0, 0, 0, 0, 0, -40, 0, 0, 0, 0, 0, 0, 0, 0, -59,
         // State 93
-        0, -80, -80, -80, -80, -80, -80, -80, -80, -80, 0, -80, -80, -80, -80, -80, -80, -80, -80, -80, -80, 0, -80, 0, 0, 0, 0, 0, 0, -80, -80, -80, 0, 0, -80, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -80, 0, 0, 0,
+        0, 0, 0, 0, -87, 0, 0, -87, 0, 0, -87, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -87, 0, -87, 0, 0, 0, 0, -87, -87, -87, 0, 0, 0, 0, -87, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -87, 0, 0, 0, 0, 0, -87, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 94
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 126, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 27, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, -123, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 95
-        -92, 0, 0, -92, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -92, 0, -92, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -92, 0, 0, -92, -92, -92, -92, -92, -92, 0, 0, -92, -92, -92, 0, 0, -92, -92, -92,
+        0, 0, 0, -131, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 96
-        -93, 0, 0, -93, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -93, 0, -93, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -93, 0, 0, -93, -93, -93, -93, -93, -93, 0, 0, -93, -93, -93, 0, 0, -93, -93, -93,
+        0, 0, 0, 139, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 97
-        -58, 0, 0, -58, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -58, 0, -58, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -58, 0, 0, -58, -58, -58, -58, -58, -58, 0, 0, -58, -58, -58, 0, 0, -58, -58, -58,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 140, 0, 0, 0,
         // State 98
-        -53, 0, 0, -53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -53, 0, -53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -53, 0, 0, -53, -53, -53, -53, -53, -53, 0, 0, -53, -53, -53, 0, 0, -53, -53, -53,
+        0, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, 0, -45, -45, -45, 0, -45, 0, -45, -45, -45, -45, 0, -45, 0, 0, 0, 0, -45, -45, -45, 0, 0, 0, 0, -45, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -45, 0, 0, 0, 0, 0, -45, 0, 0, -45, 0, 0, 0, 0, 0, -45,
         // State 99
-        -54, 0, 0, -54, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -54, 0, -54, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -54, 0, 0, -54, -54, -54, -54, -54, -54, 0, 0, -54, -54, -54, 0, 0, -54, -54, -54,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 141, 0, 0, 0,
         // State 100
-        -57, 0, 0, -57, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -57, 0, -57, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -57, 0, 0, -57, -57, -57, -57, -57, -57, 0, 0, -57, -57, -57, 0, 0, -57, -57, -57,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 29, 0,
         // State 101
-        -55, 0, 0, -55, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -55, 0, -55, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -55, 0, 0, -55, -55, -55, -55, -55, -55, 0, 0, -55, -55, -55, 0, 0, -55, -55, -55,
+        0, 0, 0, -128, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 102
-        -56, 0, 0, -56, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -56, 0, -56, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -56, 0, 0, -56, -56, -56, -56, -56, -56, 0, 0, -56, -56, -56, 0, 0, -56, -56, -56,
+        0, 0, 0, -127, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 103
-        -100, 0, 0, -100, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -100, 0, -100, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -100, 0, 0, -100, -100, -100, -100, -100, -100, 0, 0, -100, -100, -100, 0, 0, -100, -100, -100,
+        0, 0, 0, -130, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 104
-        -101, 0, 0, -101, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -101, 0, -101, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -101, 0, 0, -101, -101, -101, -101, -101, -101, 0, 0, -101, -101, -101, 0, 0, -101, -101, -101,
+        0, 0, 0, -126, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 105
-        -118, 0, 0, -118, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -118, 0, -118, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -118, 0, 0, -118, -118, -118, -118, -118, -118, 0, 0, -118, -118, -118, 0, 0, -118, -118, -118,
+        0, 0, 0, -129, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 106
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -14, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, -125, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 107
-        0, 0, 0, 0, -99, 0, 0, 0, 0, -99, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, -134, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 108
-        0, 0, 0, 0, -64, 0, 0, 140, 0, -64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 30, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 109
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 28, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, -133, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 110
-        0, -36, -36, -36, -36, -36, -36, -36, -36, -36, 0, -36, -36, -36, 0, -36, 0, -36, -36, -36, -36, 0, -36, 0, 0, 0, 0, 0, 0, -36, -36, -36, 0, 0, -36, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -36, 0, 0, 0,
+        0, 0, 0, -124, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 111
-        0, -96, -96, 18, -96, -96, -96, -96, -96, -96, 0, -96, -96, -96, 0, -96, 0, -96, -96, 0, -96, 0, -96, 0, 0, 0, 0, 0, 0, -96, -96, -96, 0, 0, -96, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -96, 0, 0, 0,
+        0, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, 0, -44, -44, -44, 0, -44, 0, -44, -44, -44, -44, 0, -44, 0, 0, 0, 0, -44, -44, -44, 0, 0, 0, 0, -44, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -44, 0, 0, 0, 0, 0, -44, 0, 0, -44, 0, 0, 0, 0, 0, -44,
         // State 112
-        0, 0, 0, 0, 142, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, -132, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 113
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 144, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 159, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 31, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 114
-        0, 0, 0, 0, -60, 0, 0, 145, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -60, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, 32, -27, -27, -27, 0, -27, 0, -27, -27, -27, -27, 0, -27, 0, 0, 0, 0, -27, -27, -27, 0, 0, 0, 0, -27, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -27, 0, 0, 0, 0, 0, -27, 0, 0, -27, 0, 0, 0, 0, 0, -27,
         // State 115
-        0, 0, 0, 29, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, 0, -29, -29, -29, 0, -29, 0, -29, -29, -29, -29, 0, -29, 0, 0, 0, 0, -29, -29, -29, 0, 0, 0, 0, -29, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -29, 0, 0, 0, 0, 0, -29, 0, 0, -29, 0, 0, 0, 0, 0, -29,
         // State 116
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 30, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 160, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 33, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 117
-        0, 0, 0, 0, -38, 0, 0, -38, 0, -38, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -38, 0, -38, 0, 0, 0, 0, 0, 0, -38, -38, -38, 0, 0, -38, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 159, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 118
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 31, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 162, 0, 0, 0,
         // State 119
-        0, 0, 0, 0, 0, 0, 0, 32, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 33, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -113, 0, 0, -113, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -113, 0, -113, 0, -113, 0, -113, 0, 0, 0, 0, -113, -113, 0, 0, 0, -113, -113, -113, -113, -113, -113, 0, 0, -113, -113, -113, -113, -113, -113, 0, -113, 0, 0, 0, -113, 0, -113, 0, 0, 0, 0, -113, -113, 0, 0,
         // State 120
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -30, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -114, 0, 0, -114, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -114, 0, -114, 0, -114, 0, -114, 0, 0, 0, 0, -114, -114, 0, 0, 0, -114, -114, -114, -114, -114, -114, 0, 0, -114, -114, -114, -114, -114, -114, 0, -114, 0, 0, 0, -114, 0, -114, 0, 0, 0, 0, -114, -114, 0, 0,
         // State 121
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 34, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -69, 0, 0, -69, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -69, 0, -69, 0, -69, 0, -69, 0, 0, 0, 0, -69, -69, 0, 0, 0, -69, -69, -69, -69, -69, -69, 0, 0, -69, -69, -69, -69, -69, -69, 0, -69, 0, 0, 0, -69, 0, -69, 0, 0, 0, 0, -69, -69, 0, 0,
         // State 122
-        0, 0, 0, 35, 0, 0, 0, -122, 0, 0, 0, 0, 0, 0, 0, 0, -122, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -122, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -64, 0, 0, -64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -64, 0, -64, 0, -64, 0, -64, 0, 0, 0, 0, -64, -64, 0, 0, 0, -64, -64, -64, -64, -64, -64, 0, 0, -64, -64, -64, -64, -64, -64, 0, -64, 0, 0, 0, -64, 0, -64, 0, 0, 0, 0, -64, -64, 0, 0,
         // State 123
-        0, 0, 0, 0, 0, 0, 0, 36, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 37, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -65, 0, 0, -65, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -65, 0, -65, 0, -65, 0, -65, 0, 0, 0, 0, -65, -65, 0, 0, 0, -65, -65, -65, -65, -65, -65, 0, 0, -65, -65, -65, -65, -65, -65, 0, -65, 0, 0, 0, -65, 0, -65, 0, 0, 0, 0, -65, -65, 0, 0,
         // State 124
-        0, 0, 0, 0, 0, 0, 0, -119, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -119, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -68, 0, 0, -68, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -68, 0, -68, 0, -68, 0, -68, 0, 0, 0, 0, -68, -68, 0, 0, 0, -68, -68, -68, -68, -68, -68, 0, 0, -68, -68, -68, -68, -68, -68, 0, -68, 0, 0, 0, -68, 0, -68, 0, 0, 0, 0, -68, -68, 0, 0,
         // State 125
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -15, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -66, 0, 0, -66, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -66, 0, -66, 0, -66, 0, -66, 0, 0, 0, 0, -66, -66, 0, 0, 0, -66, -66, -66, -66, -66, -66, 0, 0, -66, -66, -66, -66, -66, -66, 0, -66, 0, 0, 0, -66, 0, -66, 0, 0, 0, 0, -66, -66, 0, 0,
         // State 126
-        0, 0, 0, 0, -114, 0, 0, -114, 0, -114, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -114, 0, -114, 0, 0, 0, 0, 0, 0, -114, -114, -114, 0, 0, -114, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -67, 0, 0, -67, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -67, 0, -67, 0, -67, 0, -67, 0, 0, 0, 0, -67, -67, 0, 0, 0, -67, -67, -67, -67, -67, -67, 0, 0, -67, -67, -67, -67, -67, -67, 0, -67, 0, 0, 0, -67, 0, -67, 0, 0, 0, 0, -67, -67, 0, 0,
         // State 127
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 147, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -121, 0, 0, -121, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -121, 0, -121, 0, -121, 0, -121, 0, 0, 0, 0, -121, -121, 0, 0, 0, -121, -121, -121, -121, -121, -121, 0, 0, -121, -121, -121, -121, -121, -121, 0, -121, 0, 0, 0, -121, 0, -121, 0, 0, 0, 0, -121, -121, 0, 0,
         // State 128
-        0, 0, 0, 0, 148, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -122, 0, 0, -122, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -122, 0, -122, 0, -122, 0, -122, 0, 0, 0, 0, -122, -122, 0, 0, 0, -122, -122, -122, -122, -122, -122, 0, 0, -122, -122, -122, -122, -122, -122, 0, -122, 0, 0, 0, -122, 0, -122, 0, 0, 0, 0, -122, -122, 0, 0,
         // State 129
-        0, 0, 0, 0, -112, 0, 0, -112, 0, -112, 0, -112, 0, 0, 0, 0, 0, 0, 0, 0, -112, 0, -112, 0, 0, 0, 0, 0, 0, -112, -112, -112, 0, 0, -112, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -146, 0, 0, -146, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -146, 0, -146, 0, -146, 0, -146, 0, 0, 0, 0, -146, -146, 0, 0, 0, -146, -146, -146, -146, -146, -146, 0, 0, -146, -146, -146, -146, -146, -146, 0, -146, 0, 0, 0, -146, 0, -146, 0, 0, 0, 0, -146, -146, 0, 0,
         // State 130
-        0, 0, 0, 0, -44, 0, 0, -44, 0, -44, 0, -44, 0, 0, 0, 0, 0, 0, 0, 0, -44, 0, -44, 0, 0, 0, 0, 0, 0, -44, -44, -44, 0, 0, -44, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 173, 0, 0, 0,
         // State 131
-        0, -85, -85, 0, -85, 0, 0, -85, 0, -85, 0, -85, -85, -85, 0, -85, 0, -85, -85, 0, -85, 0, -85, 0, 0, 0, 0, 0, 0, -85, -85, -85, 0, 0, -85, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -85, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -19, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -19, 0, 0, 0, 0,
         // State 132
-        0, -45, -45, 0, -45, 0, 0, -45, 0, -45, 0, -45, -45, -45, 0, -45, 0, -45, -45, 0, -45, 0, -45, 0, 0, 0, 0, 0, 0, -45, -45, -45, 0, 0, -45, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -45, 0, 0, 0,
+        0, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, 0, -47, -47, -47, 0, -47, 0, -47, -47, -47, -47, 0, -47, 0, 0, 0, 0, -47, -47, -47, 0, 0, 0, 0, -47, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -47, 0, 0, 0, 0, 0, -47, 0, 0, -47, 0, 0, 0, 0, 0, -47,
         // State 133
-        0, -87, -87, 0, -87, 0, -87, -87, -87, -87, 0, -87, -87, -87, 0, -87, 0, -87, -87, 0, -87, 0, -87, 0, 0, 0, 0, 0, 0, -87, -87, -87, 0, 0, -87, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -87, 0, 0, 0,
+        0, -117, -117, 21, -117, -117, -117, -117, -117, 0, -117, 0, -117, -117, -117, 0, -117, 0, -117, -117, 0, -117, 0, -117, 0, 0, 0, 0, -117, -117, -117, 0, 0, 0, 0, -117, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -117, 0, 0, 0, 0, 0, -117, 0, 0, -117, 0, 0, 0, 0, 0, -117,
         // State 134
-        0, -43, -43, 0, -43, 0, -43, -43, -43, -43, 0, -43, -43, -43, 0, -43, 0, -43, -43, 0, -43, 0, -43, 0, 0, 0, 0, 0, 0, -43, -43, -43, 0, 0, -43, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -43, 0, 0, 0,
+        0, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, 0, -27, -27, -27, 0, -27, 0, -27, -27, -27, -27, 0, -27, 0, 0, 0, 0, -27, -27, -27, 0, 0, 0, 0, -27, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -27, 0, 0, 0, 0, 0, -27, 0, 0, -27, 0, 0, 0, 0, 0, -27,
         // State 135
-        0, -89, -89, 0, -89, -89, -89, -89, -89, -89, 0, -89, -89, -89, 0, -89, 0, -89, -89, 0, -89, 0, -89, 0, 0, 0, 0, 0, 0, -89, -89, -89, 0, 0, -89, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -89, 0, 0, 0,
+        0, 0, 0, 0, 176, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 136
-        0, -42, -42, 0, -42, -42, -42, -42, -42, -42, 0, -42, -42, -42, 0, -42, 0, -42, -42, 0, -42, 0, -42, 0, 0, 0, 0, 0, 0, -42, -42, -42, 0, 0, -42, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -42, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 178, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 137
-        0, 0, 0, 0, -76, 0, 0, -76, 0, -76, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -76, 0, -76, 0, 0, 0, 0, 0, 0, -76, -76, -76, 0, 0, -76, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, -71, 0, 0, 179, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -71, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 138
-        0, 0, 0, 0, -66, 0, 0, 149, 0, -66, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 180, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 139
-        0, 0, 0, 0, -9, 0, 0, 0, 0, -9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -9,
+        0, 0, 0, 34, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 140
-        0, 0, 0, 0, 151, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 35, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 141
-        0, -25, -25, -25, -25, -25, -25, -25, -25, -25, 0, -25, -25, -25, 0, -25, 0, -25, -25, -25, -25, 0, -25, 0, 0, 0, 0, 0, 0, -25, -25, -25, 0, 0, -25, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -25, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 36, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 142
-        0, 0, 0, 0, -62, 0, 0, 152, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -62, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, -49, 0, 0, -49, 0, 0, -49, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -49, 0, -49, 0, 0, 0, 0, -49, -49, -49, 0, 0, 0, 0, -49, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -49, 0, 0, 0, 0, 0, -49, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 143
-        0, -28, -28, -28, -28, -28, -28, -28, -28, -28, 0, -28, -28, -28, 0, -28, 0, -28, -28, -28, -28, 0, -28, 0, 0, 0, 0, 0, 0, -28, -28, -28, 0, 0, -28, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -28, 0, 0, 0,
+        0, 0, 0, 0, -82, 0, 0, 0, 0, 0, -82, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 0, 0, 0,
         // State 144
-        -4, 0, 0, -4, -4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -4, -4, -4, 0, 0, 0, 0, 0, 0, -4, 0, 0, 0, -4, -4, 0, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, 0, -4, -4, -4,
+        0, 0, 0, 0, -120, 0, 0, 0, 0, 0, -120, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 145
-        0, 0, 0, 0, -77, 0, 0, -77, 0, -77, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -77, 0, -77, 0, 0, 0, 0, 0, 0, -77, -77, -77, 0, 0, -77, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 37, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 146
-        0, -19, -19, 0, -19, -19, -19, -19, -19, -19, 38, -19, -19, -19, 0, -19, 0, -19, -19, -19, -19, 0, -19, 0, 0, 0, 0, 0, 0, -19, -19, -19, 0, 0, -19, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -19, 0, 0, 0,
+        0, 0, 0, 0, -79, 0, 0, 184, 0, 0, -79, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 147
-        0, -26, -26, -26, -26, -26, -26, -26, -26, -26, 0, -26, -26, -26, 0, -26, 0, -26, -26, -26, -26, 0, -26, 0, 0, 0, 0, 0, 0, -26, -26, -26, 0, 0, -26, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -26, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 38, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 148
-        0, 0, 0, 0, -10, 0, 0, 0, 0, -10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -10,
+        0, 0, 0, 0, 0, 0, 0, 39, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 40, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 149
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 162, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -41, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 150
-        0, -24, -24, -24, -24, -24, -24, -24, -24, -24, 0, -24, -24, -24, 0, -24, 0, -24, -24, -24, -24, 0, -24, 0, 0, 0, 0, 0, 0, -24, -24, -24, 0, 0, -24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -24, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 41, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 151
-        -5, 0, 0, -5, -5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -5, -5, -5, 0, 0, 0, 0, 0, 0, -5, 0, 0, 0, -5, -5, 0, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, 0, -5, -5, -5,
+        0, -118, -118, 21, -118, -118, -118, -118, -118, 0, -118, 0, -118, -118, -118, 0, -118, 0, -118, -118, 0, -118, 0, -118, 0, 0, 0, 0, -118, -118, -118, 0, 0, 0, 0, -118, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -118, 0, 0, 0, 0, 0, -118, 0, 0, -118, 0, 0, 0, 0, 0, -118,
         // State 152
-        0, 0, 0, 0, 163, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 42, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 153
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 164, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 43, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 44, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 154
-        0, 0, 0, 0, 0, 0, 0, -31, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -31, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, -147, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -147, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 155
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 39, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 45, 0, 0, 0, -150, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -150, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 156
-        0, 0, 0, 0, -91, 0, 0, -91, 0, -91, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -91, 0, -91, 0, 0, 0, 0, 0, 0, -91, -91, -91, 0, 0, -91, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 39, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 157
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 40, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 47, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 158
-        0, 0, 0, 0, 165, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 186, 0, 0, 0,
         // State 159
-        0, 0, 0, 0, 0, 0, 0, -120, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -120, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -20, 0, 0, 0, 0,
         // State 160
-        0, 0, 0, 0, -117, 0, 0, -117, 0, -117, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -117, 0, -117, 0, 0, 0, 0, 0, 0, -117, -117, -117, 0, 0, -117, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, -140, 0, 0, -140, 0, 0, -140, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -140, 0, -140, 0, 0, 0, 0, -140, -140, -140, 0, 0, 0, 0, -140, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -140, 0, 0, 0, 0, 0, -140, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 161
-        0, -27, -27, -27, -27, -27, -27, -27, -27, -27, 0, -27, -27, -27, 0, -27, 0, -27, -27, -27, -27, 0, -27, 0, 0, 0, 0, 0, 0, -27, -27, -27, 0, 0, -27, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -27, 0, 0, 0,
+        0, -25, -25, 0, -25, -25, -25, -25, -25, -25, -25, 0, -25, -25, -25, 0, -25, 0, -25, -25, -25, -25, 0, -25, 0, 0, 0, 0, -25, -25, -25, 0, 0, 0, 0, -25, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -25, 0, 0, 0, 0, 0, -25, 0, 0, -25, 0, 0, 0, 0, 0, -25,
         // State 162
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 41, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 191, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 163
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 42, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 192, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 164
-        0, 0, 0, 0, 0, 0, 0, -121, 0, 0, 0, 0, 0, 0, 0, 0, -121, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -121, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, -138, 0, 0, -138, 0, 0, -138, 0, -138, 0, 0, 0, 0, 0, 0, 0, 0, -138, 0, -138, 0, 0, 0, 0, -138, -138, -138, 0, 0, 0, 0, -138, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -138, 0, 0, 0, 0, 0, -138, 0, 0, 0, 0, 0, 0, 0, 0, -138,
         // State 165
-        0, -20, -20, 18, -20, -20, -20, -20, -20, -20, 0, -20, -20, -20, 0, -20, 0, -20, -20, -20, -20, 0, -20, 0, 0, 0, 0, 0, 0, -20, -20, -20, 0, 0, -20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -20, 0, 0, 0,
+        0, 0, 0, 0, -57, 0, 0, -57, 0, 0, -57, 0, -57, 0, 0, 0, 0, 0, 0, 0, 0, -57, 0, -57, 0, 0, 0, 0, -57, -57, -57, 0, 0, 0, 0, -57, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -57, 0, 0, 0, 0, 0, -57, 0, 0, 0, 0, 0, 0, 0, 0, -57,
         // State 166
-        0, 0, 0, 0, 0, 0, 0, -32, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -32, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -106, -106, 0, -106, 0, 0, -106, 0, 0, -106, 0, -106, -106, -106, 0, -106, 0, -106, -106, 0, -106, 0, -106, 0, 0, 0, 0, -106, -106, -106, 0, 0, 0, 0, -106, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -106, 0, 0, 0, 0, 0, -106, 0, 0, -106, 0, 0, 0, 0, 0, -106,
         // State 167
-        0, 0, 0, 0, 0, 0, 0, 44, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 176, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -58, -58, 0, -58, 0, 0, -58, 0, 0, -58, 0, -58, -58, -58, 0, -58, 0, -58, -58, 0, -58, 0, -58, 0, 0, 0, 0, -58, -58, -58, 0, 0, 0, 0, -58, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -58, 0, 0, 0, 0, 0, -58, 0, 0, -58, 0, 0, 0, 0, 0, -58,
         // State 168
-        0, 0, 0, 0, 0, 0, 0, -46, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -46, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -108, -108, 0, -108, 0, -108, -108, -108, 0, -108, 0, -108, -108, -108, 0, -108, 0, -108, -108, 0, -108, 0, -108, 0, 0, 0, 0, -108, -108, -108, 0, 0, 0, 0, -108, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -108, 0, 0, 0, 0, 0, -108, 0, 0, -108, 0, 0, 0, 0, 0, -108,
         // State 169
-        0, 0, 0, 45, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -55, -55, 0, -55, 0, -55, -55, -55, 0, -55, 0, -55, -55, -55, 0, -55, 0, -55, -55, 0, -55, 0, -55, 0, 0, 0, 0, -55, -55, -55, 0, 0, 0, 0, -55, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -55, 0, 0, 0, 0, 0, -55, 0, 0, -55, 0, 0, 0, 0, 0, -55,
         // State 170
-        0, 0, 0, 46, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -110, -110, 0, -110, -110, -110, -110, -110, 0, -110, 0, -110, -110, -110, 0, -110, 0, -110, -110, 0, -110, 0, -110, 0, 0, 0, 0, -110, -110, -110, 0, 0, 0, 0, -110, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -110, 0, 0, 0, 0, 0, -110, 0, 0, -110, 0, 0, 0, 0, 0, -110,
         // State 171
-        0, 0, 0, 47, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -54, -54, 0, -54, -54, -54, -54, -54, 0, -54, 0, -54, -54, -54, 0, -54, 0, -54, -54, 0, -54, 0, -54, 0, 0, 0, 0, -54, -54, -54, 0, 0, 0, 0, -54, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -54, 0, 0, 0, 0, 0, -54, 0, 0, -54, 0, 0, 0, 0, 0, -54,
         // State 172
-        0, 0, 0, 48, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 48, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 173
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -78, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -78, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, -95, 0, 0, -95, 0, 0, -95, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -95, 0, -95, 0, 0, 0, 0, -95, -95, -95, 0, 0, 0, 0, -95, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -95, 0, 0, 0, 0, 0, -95, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 174
-        0, 0, 0, 0, -79, 0, 0, -79, 0, -79, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -79, 0, -79, 0, 0, 0, 0, 0, 0, -79, -79, -79, 0, 0, -79, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 193, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 175
-        0, 0, 0, 0, -95, 0, 0, -95, 0, -95, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -95, 0, -95, 0, 0, 0, 0, 0, 0, -95, -95, -95, 0, 0, -95, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, 0, -33, -33, -33, 0, -33, 0, -33, -33, -33, -33, 0, -33, 0, 0, 0, 0, -33, -33, -33, 0, 0, 0, 0, -33, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -33, 0, 0, 0, 0, 0, -33, 0, 0, -33, 0, 0, 0, 0, 0, -33,
         // State 176
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 49, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, -73, 0, 0, 194, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -73, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 177
-        0, 0, 0, 0, 0, 0, 0, -47, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -47, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, 0, -38, -38, -38, 0, -38, 0, -38, -38, -38, -38, 0, -38, 0, 0, 0, 0, -38, -38, -38, 0, 0, 0, 0, -38, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -38, 0, 0, 0, 0, 0, -38, 0, 0, -38, 0, 0, 0, 0, 0, -38,
         // State 178
-        0, 0, 0, 0, 184, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -4, 0, 0, -4, -4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -4, -4, -4, 0, -4, 0, -4, -4, 0, 0, 0, -4, -4, -4, 0, 0, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, 0, -4, -4, -4, -4, -4, 0, -4, 0, 0, 0, -4, -4, -4, 0, 0,
         // State 179
-        0, 0, 0, 0, 185, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, 0, -31, -31, -31, 0, -31, 0, -31, -31, -31, -31, 0, -31, 0, 0, 0, 0, -31, -31, -31, 0, 0, 0, 0, -31, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -31, 0, 0, 0, 0, 0, -31, 0, 0, -31, 0, 0, 0, 0, 0, -31,
         // State 180
-        0, 0, 0, 0, 186, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 181
-        0, 0, 0, 0, 187, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 182
-        0, 0, 0, 0, 0, 0, 0, -48, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -48, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, -81, 0, 0, 198, 0, 0, -81, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 183
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 50, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, -14, 0, 0, 0, 0, 0, -14, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -14, 0, 0, 0,
         // State 184
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 51, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 49, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 185
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 52, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 51, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 186
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 43, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 52, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 187
-        0, 0, 0, 0, 0, 0, 0, -52, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -52, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, -39, 0, 0, -39, 0, 0, -39, 0, -39, 0, 0, 0, 0, 0, 0, 0, 0, -39, 0, -39, 0, 0, 0, 0, -39, -39, -39, 0, 0, 0, 0, -39, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -39, 0, 0, 0, 0, 0, -39, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 188
-        0, 0, 0, 0, 0, 0, 0, -51, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -51, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, -53, 0, 0, -53, 0, 0, -53, 0, -53, 0, 0, 0, 0, 0, 0, 0, 0, -53, 0, -53, 0, 0, 0, 0, -53, -53, -53, 0, 0, 0, 0, -53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -53, 0, 0, 0, 0, 0, -53, 0, 0, 0, 0, 0, 0, 0, 0, -53,
         // State 189
-        0, 0, 0, 0, 0, 0, 0, -50, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -50, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, -96, 0, 0, -96, 0, 0, -96, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -96, 0, -96, 0, 0, 0, 0, -96, -96, -96, 0, 0, 0, 0, -96, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -96, 0, 0, 0, 0, 0, -96, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 190
-        0, 0, 0, 0, 0, 0, 0, -49, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -49, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -24, -24, 0, -24, -24, -24, -24, -24, -24, -24, 53, -24, -24, -24, 0, -24, 0, -24, -24, -24, -24, 0, -24, 0, 0, 0, 0, -24, -24, -24, 0, 0, 0, 0, -24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -24, 0, 0, 0, 0, 0, -24, 0, 0, -24, 0, 0, 0, 0, 0, -24,
+        // State 191
+        0, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, 0, -34, -34, -34, 0, -34, 0, -34, -34, -34, -34, 0, -34, 0, 0, 0, 0, -34, -34, -34, 0, 0, 0, 0, -34, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -34, 0, 0, 0, 0, 0, -34, 0, 0, -34, 0, 0, 0, 0, 0, -34,
+        // State 192
+        0, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, 0, -30, -30, -30, 0, -30, 0, -30, -30, -30, -30, 0, -30, 0, 0, 0, 0, -30, -30, -30, 0, 0, 0, 0, -30, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -30, 0, 0, 0, 0, 0, -30, 0, 0, -30, 0, 0, 0, 0, 0, -30,
+        // State 193
+        -5, 0, 0, -5, -5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -5, -5, -5, 0, -5, 0, -5, -5, 0, 0, 0, -5, -5, -5, 0, 0, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, 0, -5, -5, -5, -5, -5, 0, -5, 0, 0, 0, -5, -5, -5, 0, 0,
+        // State 194
+        0, 0, 0, 0, 212, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 195
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 54, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 196
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 213, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 197
+        0, 0, 0, 0, -15, 0, 0, 0, 0, 0, -15, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -15, 0, 0, 0,
+        // State 198
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 214, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 199
+        0, 0, 0, 0, 0, 0, 0, -42, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -42, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 200
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 55, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 201
+        0, 0, 0, 0, -112, 0, 0, -112, 0, 0, -112, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -112, 0, -112, 0, 0, 0, 0, -112, -112, -112, 0, 0, 0, 0, -112, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -112, 0, 0, 0, 0, 0, -112, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 202
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 56, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 203
+        0, 0, 0, 0, 0, 0, 0, 57, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 216, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 204
+        0, 0, 0, 0, 0, 0, 0, -60, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -60, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 205
+        0, 0, 0, 0, 0, 0, 0, -148, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -148, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 206
+        0, 0, 0, 0, -145, 0, 0, -145, 0, 0, -145, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -145, 0, -145, 0, 0, 0, 0, -145, -145, -145, 0, 0, 0, 0, -145, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -145, 0, 0, 0, 0, 0, -145, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 207
+        0, 0, 0, 0, 219, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 208
+        0, 0, 0, 0, -153, 0, 0, -153, 0, 0, -153, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -153, 0, -153, 0, 0, 0, 0, -153, -153, -153, 0, 0, 0, 0, -153, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -153, 0, 0, 0, 0, 0, -153, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 209
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 220, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 210
+        0, 0, 0, 0, -151, 0, 0, -151, 0, 0, -151, 0, -151, 0, 0, 0, 0, 0, 0, 0, 0, -151, 0, -151, 0, 0, 0, 0, -151, -151, -151, 0, 0, 0, 0, -151, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -151, 0, 0, 0, 0, 0, -151, 0, 0, 0, 0, 0, 0, 0, 0, -151,
+        // State 211
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 58, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 212
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 59, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 213
+        0, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, 0, -35, -35, -35, 0, -35, 0, -35, -35, -35, -35, 0, -35, 0, 0, 0, 0, -35, -35, -35, 0, 0, 0, 0, -35, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -35, 0, 0, 0, 0, 0, -35, 0, 0, -35, 0, 0, 0, 0, 0, -35,
+        // State 214
+        0, 0, 0, 0, 227, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 215
+        0, 0, 0, 0, -143, 0, 0, -143, 0, 0, -143, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -143, 0, -143, 0, 0, 0, 0, -143, -143, -143, 0, 0, 0, 0, -143, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -143, 0, 0, 0, 0, 0, -143, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 216
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 60, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 217
+        0, 0, 0, 45, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -150, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 61, 0, 0, 0, 0, 0, 0, 0,
+        // State 218
+        0, 0, 0, 0, 0, 0, 0, -149, 0, 0, 0, 0, 0, 0, 0, 0, 0, -149, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -149, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 219
+        0, -36, -36, -36, -36, -36, -36, -36, -36, -36, -36, 0, -36, -36, -36, 0, -36, 0, -36, -36, -36, -36, 0, -36, 0, 0, 0, 0, -36, -36, -36, 0, 0, 0, 0, -36, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -36, 0, 0, 0, 0, 0, -36, 0, 0, -36, 0, 0, 0, 0, 0, -36,
+        // State 220
+        0, 0, 0, 0, 229, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 221
+        0, 0, 0, 0, -144, 0, 0, -144, 0, 0, -144, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -144, 0, -144, 0, 0, 0, 0, -144, -144, -144, 0, 0, 0, 0, -144, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -144, 0, 0, 0, 0, 0, -144, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 222
+        0, -26, -26, 21, -26, -26, -26, -26, -26, -26, -26, 0, -26, -26, -26, 0, -26, 0, -26, -26, -26, -26, 0, -26, 0, 0, 0, 0, -26, -26, -26, 0, 0, 0, 0, -26, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -26, 0, 0, 0, 0, 0, -26, 0, 0, -26, 0, 0, 0, 0, 0, -26,
+        // State 223
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 62, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 224
+        0, 0, 0, 0, 0, 0, 0, -43, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -43, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 225
+        0, 0, 0, 0, 0, 0, 0, 57, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 232, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 226
+        0, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, 0, -32, -32, -32, 0, -32, 0, -32, -32, -32, -32, 0, -32, 0, 0, 0, 0, -32, -32, -32, 0, 0, 0, 0, -32, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -32, 0, 0, 0, 0, 0, -32, 0, 0, -32, 0, 0, 0, 0, 0, -32,
+        // State 227
+        0, 0, 0, 0, 0, 0, 0, -61, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -61, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 228
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 229
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -102, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -102, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 230
+        0, 0, 0, 0, -103, 0, 0, -103, 0, 0, -103, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -103, 0, -103, 0, 0, 0, 0, -103, -103, -103, 0, 0, 0, 0, -103, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -103, 0, 0, 0, 0, 0, -103, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 231
+        0, 0, 0, 0, -116, 0, 0, -116, 0, 0, -116, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -116, 0, -116, 0, 0, 0, 0, -116, -116, -116, 0, 0, 0, 0, -116, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -116, 0, 0, 0, 0, 0, -116, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 232
+        0, 0, 0, 0, 0, 0, 0, -62, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -62, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 233
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 239, 0, 0, 0, 0, 0,
+        // State 234
+        0, 0, 0, 0, 0, 0, 0, 240, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -75, 0, 0, 0, 0, 0,
+        // State 235
+        0, 0, 0, 0, 0, 0, 0, -97, 0, 0, 0, 0, 0, 0, 0, 241, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -97, 0, 0, 0, 0, 0,
+        // State 236
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 243, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 237
+        0, 0, 0, 0, 0, 0, 0, 244, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -77, 0, 0, 0, 0, 0,
+        // State 238
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 65, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 239
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -9, 0, -9, 0, 0, 0,
+        // State 240
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 245, 0, 0, 0,
+        // State 241
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -101, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -101, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 242
+        0, -37, -37, -37, -37, -37, -37, -37, -37, -37, -37, 0, -37, -37, -37, 0, -37, 0, -37, -37, -37, -37, 0, -37, 0, 0, 0, 0, -37, -37, -37, 0, 0, 0, 0, -37, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -37, 0, 0, 0, 0, 0, -37, 0, 0, -37, 0, 0, 0, 0, 0, -37,
+        // State 243
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -10, 0, -10, 0, 0, 0,
+        // State 244
+        0, 0, 0, 0, 0, 0, 0, -98, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -98, 0, 0, 0, 0, 0,
+        // State 245
+        0, 0, 0, 0, 0, 0, 0, -63, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -63, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
     ];
     fn __action(state: i16, integer: usize) -> i16 {
-        __ACTION[(state as usize) * 51 + integer]
+        __ACTION[(state as usize) * 66 + integer]
     }
     const __EOF_ACTION: &[i16] = &[
         // State 0
@@ -548,87 +662,87 @@ mod __parse__Prog {
         // State 52
         0,
         // State 53
-        -97,
+        0,
         // State 54
-        -18,
+        0,
         // State 55
-        -115,
+        0,
         // State 56
-        -22,
+        0,
         // State 57
         0,
         // State 58
         0,
         // State 59
-        -113,
+        0,
         // State 60
-        -111,
+        0,
         // State 61
-        -70,
+        0,
         // State 62
         0,
         // State 63
-        -68,
+        0,
         // State 64
-        -21,
+        0,
         // State 65
-        -59,
+        -119,
         // State 66
-        -116,
+        -23,
         // State 67
-        -110,
+        -141,
         // State 68
-        -69,
+        -28,
         // State 69
-        -29,
+        0,
         // State 70
-        -73,
+        0,
         // State 71
-        -90,
+        0,
         // State 72
-        -23,
+        -139,
         // State 73
-        0,
+        -137,
         // State 74
         -88,
         // State 75
-        -123,
+        0,
         // State 76
-        -94,
+        -85,
         // State 77
-        -71,
+        -70,
         // State 78
-        -86,
+        -142,
         // State 79
-        -72,
+        -135,
         // State 80
-        0,
+        -86,
         // State 81
-        -34,
+        -152,
         // State 82
-        0,
+        -91,
         // State 83
-        0,
+        -111,
         // State 84
         0,
         // State 85
-        0,
+        -109,
         // State 86
-        0,
+        -154,
         // State 87
-        0,
+        -115,
         // State 88
-        0,
+        -89,
         // State 89
-        0,
+        -107,
         // State 90
-        -33,
+        -92,
         // State 91
-        0,
+        -90,
         // State 92
-        -98,
+        -40,
         // State 93
-        -80,
+        -87,
         // State 94
         0,
         // State 95
@@ -638,7 +752,7 @@ mod __parse__Prog {
         // State 97
         0,
         // State 98
-        0,
+        -45,
         // State 99
         0,
         // State 100
@@ -662,21 +776,21 @@ mod __parse__Prog {
         // State 109
         0,
         // State 110
-        -36,
+        0,
         // State 111
-        -96,
+        -44,
         // State 112
         0,
         // State 113
         0,
         // State 114
-        0,
+        -27,
         // State 115
-        0,
+        -29,
         // State 116
         0,
         // State 117
-        -38,
+        0,
         // State 118
         0,
         // State 119
@@ -694,29 +808,29 @@ mod __parse__Prog {
         // State 125
         0,
         // State 126
-        -114,
+        0,
         // State 127
         0,
         // State 128
         0,
         // State 129
-        -112,
+        0,
         // State 130
-        -44,
+        0,
         // State 131
-        -85,
+        0,
         // State 132
-        -45,
+        -47,
         // State 133
-        -87,
+        -117,
         // State 134
-        -43,
+        -27,
         // State 135
-        -89,
+        0,
         // State 136
-        -42,
+        0,
         // State 137
-        -76,
+        0,
         // State 138
         0,
         // State 139
@@ -724,27 +838,27 @@ mod __parse__Prog {
         // State 140
         0,
         // State 141
-        -25,
-        // State 142
         0,
+        // State 142
+        -49,
         // State 143
-        -28,
+        0,
         // State 144
         0,
         // State 145
-        -77,
+        0,
         // State 146
-        -19,
+        0,
         // State 147
-        -26,
+        0,
         // State 148
         0,
         // State 149
         0,
         // State 150
-        -24,
-        // State 151
         0,
+        // State 151
+        -118,
         // State 152
         0,
         // State 153
@@ -754,7 +868,7 @@ mod __parse__Prog {
         // State 155
         0,
         // State 156
-        -91,
+        0,
         // State 157
         0,
         // State 158
@@ -762,49 +876,49 @@ mod __parse__Prog {
         // State 159
         0,
         // State 160
-        -117,
+        -140,
         // State 161
-        -27,
+        -25,
         // State 162
         0,
         // State 163
         0,
         // State 164
-        0,
+        -138,
         // State 165
-        -20,
+        -57,
         // State 166
-        0,
+        -106,
         // State 167
-        0,
+        -58,
         // State 168
-        0,
+        -108,
         // State 169
-        0,
+        -55,
         // State 170
-        0,
+        -110,
         // State 171
-        0,
+        -54,
         // State 172
         0,
         // State 173
-        0,
+        -95,
         // State 174
-        -79,
+        0,
         // State 175
-        -95,
+        -33,
         // State 176
         0,
         // State 177
-        0,
+        -38,
         // State 178
         0,
         // State 179
-        0,
+        -31,
         // State 180
-        0,
+        -136,
         // State 181
-        0,
+        -56,
         // State 182
         0,
         // State 183
@@ -816,149 +930,285 @@ mod __parse__Prog {
         // State 186
         0,
         // State 187
-        0,
+        -39,
         // State 188
-        0,
+        -53,
         // State 189
-        0,
+        -96,
         // State 190
+        -24,
+        // State 191
+        -34,
+        // State 192
+        -30,
+        // State 193
+        0,
+        // State 194
+        0,
+        // State 195
+        0,
+        // State 196
+        0,
+        // State 197
+        0,
+        // State 198
+        0,
+        // State 199
+        0,
+        // State 200
+        0,
+        // State 201
+        -112,
+        // State 202
+        0,
+        // State 203
+        0,
+        // State 204
+        0,
+        // State 205
+        0,
+        // State 206
+        -145,
+        // State 207
+        0,
+        // State 208
+        -153,
+        // State 209
+        0,
+        // State 210
+        -151,
+        // State 211
+        0,
+        // State 212
+        0,
+        // State 213
+        -35,
+        // State 214
+        0,
+        // State 215
+        -143,
+        // State 216
+        0,
+        // State 217
+        0,
+        // State 218
+        0,
+        // State 219
+        -36,
+        // State 220
+        0,
+        // State 221
+        -144,
+        // State 222
+        -26,
+        // State 223
+        0,
+        // State 224
+        0,
+        // State 225
+        0,
+        // State 226
+        -32,
+        // State 227
+        0,
+        // State 228
+        0,
+        // State 229
+        0,
+        // State 230
+        -103,
+        // State 231
+        -116,
+        // State 232
+        0,
+        // State 233
+        0,
+        // State 234
+        0,
+        // State 235
+        0,
+        // State 236
+        0,
+        // State 237
+        0,
+        // State 238
+        0,
+        // State 239
+        0,
+        // State 240
+        0,
+        // State 241
+        0,
+        // State 242
+        -37,
+        // State 243
+        0,
+        // State 244
+        0,
+        // State 245
         0,
     ];
     fn __goto(state: i16, nt: usize) -> i16 {
         match nt {
-            2 => 25,
-            5 => 23,
-            8 => 1,
-            11 => 53,
-            12 => match state {
-                7 | 37 => 110,
-                _ => 54,
+            2 => 27,
+            5 => 62,
+            8 => 143,
+            11 => 1,
+            14 => 65,
+            15 => match state {
+                6 | 13 | 52 => 132,
+                _ => 66,
             },
-            13 => 55,
-            14 => match state {
-                31 => 155,
-                _ => 118,
+            16 => 67,
+            17 => match state {
+                38 => 200,
+                _ => 147,
             },
-            15 => 119,
-            16 => 56,
-            17 => 57,
             18 => match state {
-                7 => 111,
-                37 => 165,
-                _ => 58,
+                16 => 156,
+                _ => 148,
             },
-            19 => 2,
-            20 => match state {
-                13 => 121,
-                16 => 127,
-                22 => 137,
-                24 => 140,
-                26 => 145,
-                27 => 149,
-                29 => 153,
-                32 => 156,
-                33 => 157,
-                36 => 160,
-                41 => 174,
-                _ => 116,
+            19 => 68,
+            20 => 69,
+            21 => match state {
+                6 => 133,
+                13 => 151,
+                52 => 222,
+                _ => 70,
             },
-            21 => 3,
-            22 => 4,
-            23 => 5,
-            24 => 135,
-            25 => 133,
-            26 => 129,
-            27 => 131,
-            28 => 167,
-            29 => match state {
-                43 => 177,
-                _ => 168,
+            22 => 2,
+            23 => match state {
+                12 => 150,
+                14 => 152,
+                17 => 157,
+                19 => 162,
+                25 => 173,
+                26 => 174,
+                29 => 184,
+                32 => 189,
+                34 => 195,
+                35 => 196,
+                36 => 198,
+                39 => 201,
+                40 => 202,
+                43 => 206,
+                45 => 208,
+                46 => 209,
+                48 => 214,
+                51 => 221,
+                53 => 223,
+                58 => 230,
+                61 => 236,
+                _ => 141,
             },
-            30 => 19,
-            31 => 59,
-            32 => match state {
-                17 => 128,
-                _ => 113,
-            },
-            33 => match state {
-                34 => 158,
-                _ => 107,
+            24 => 3,
+            25 => 4,
+            26 => 5,
+            27 => match state {
+                47 => 210,
+                _ => 187,
             },
+            28 => 170,
+            29 => 168,
+            30 => 180,
+            31 => 164,
+            32 => 166,
+            33 => 71,
             34 => match state {
-                0 => 60,
-                8 => 112,
-                9 | 17 => 114,
-                15 => 126,
-                25 => 142,
-                30 => 154,
-                38 => 166,
-                40 => 173,
-                48 => 182,
-                49 => 187,
-                50 => 188,
-                51 => 189,
-                52 => 190,
-                _ => 117,
+                55 => 225,
+                _ => 203,
+            },
+            35 => match state {
+                56 => 227,
+                _ => 204,
             },
-            36 => 61,
-            37 => match state {
-                1 => 94,
-                _ => 62,
+            36 => 22,
+            37 => 72,
+            38 => match state {
+                20 => 163,
+                _ => 136,
             },
-            38 => 63,
-            39 => match state {
-                6 | 28 | 34 => 108,
-                10 => 115,
-                12 | 31 => 120,
-                14 | 35 | 42 => 122,
-                23 => 138,
-                44 => 178,
-                45 => 179,
-                46 => 180,
-                47 => 181,
-                _ => 64,
+            39 => 233,
+            40 => match state {
+                44 => 207,
+                _ => 144,
             },
-            41 => 6,
-            42 => 65,
-            43 => 66,
-            44 => 67,
-            45 => 68,
-            46 => 18,
-            47 => 69,
-            48 => 70,
-            49 => match state {
-                21 => 136,
-                _ => 71,
+            42 => match state {
+                0 | 28 => 73,
+                7 => 135,
+                8 | 20 => 137,
+                18 => 160,
+                27 => 176,
+                37 => 199,
+                54 => 224,
+                57 => 229,
+                59 => 232,
+                63 => 241,
+                64 => 245,
+                _ => 142,
             },
-            50 => 72,
-            51 => match state {
-                28 => 152,
-                _ => 109,
+            44 => 74,
+            45 => match state {
+                62 => 237,
+                _ => 234,
             },
-            52 => 20,
-            53 => 73,
-            54 => match state {
-                20 => 134,
-                _ => 74,
+            47 => match state {
+                1 => 116,
+                _ => 75,
             },
-            55 => 75,
-            56 => match state {
-                18 => 130,
-                _ => 76,
+            48 => 76,
+            50 => 77,
+            51 => 78,
+            52 => 79,
+            53 => 80,
+            54 => 21,
+            55 => match state {
+                31 | 47 => 188,
+                _ => 81,
+            },
+            56 => 82,
+            57 => match state {
+                24 => 171,
+                _ => 83,
             },
-            57 => 77,
             58 => match state {
-                19 => 132,
-                _ => 78,
+                33 => 194,
+                50 => 220,
+                _ => 145,
+            },
+            59 => 23,
+            60 => 84,
+            61 => match state {
+                23 => 169,
+                _ => 85,
             },
-            59 => 79,
-            60 => 21,
-            61 => 123,
             62 => match state {
-                35 => 159,
-                42 => 176,
-                _ => 124,
+                28 => 181,
+                _ => 86,
             },
+            63 => match state {
+                21 => 165,
+                _ => 87,
+            },
+            64 => 88,
+            65 => match state {
+                22 => 167,
+                _ => 89,
+            },
+            66 => 90,
+            67 => 91,
+            68 => 24,
+            69 => match state {
+                30 => 186,
+                _ => 153,
+            },
+            70 => match state {
+                42 => 205,
+                49 => 216,
+                _ => 154,
+            },
+            71 => 92,
+            72 => 93,
             _ => 0,
         }
     }
@@ -973,6 +1223,7 @@ mod __parse__Prog {
             r###""+""###,
             r###"",""###,
             r###""-""###,
+            r###"".""###,
             r###"":""###,
             r###"":=""###,
             r###"";""###,
@@ -987,17 +1238,17 @@ mod __parse__Prog {
             r###""]""###,
             r###""add1""###,
             r###""and""###,
+            r###""callcc""###,
             r###""case""###,
-            r###""case Array""###,
-            r###""case Bool""###,
-            r###""case Func""###,
-            r###""case Num""###,
+            r###""chan""###,
             r###""def""###,
             r###""default""###,
             r###""else""###,
             r###""end""###,
             r###""false""###,
+            r###""for""###,
             r###""if""###,
+            r###""import""###,
             r###""in""###,
             r###""isarray""###,
             r###""isbool""###,
@@ -1008,13 +1259,27 @@ mod __parse__Prog {
             r###""let""###,
             r###""match""###,
             r###""print""###,
+            r###""raise""###,
+            r###""recv""###,
+            r###""send""###,
+            r###""spawn""###,
             r###""sub1""###,
+            r###""to""###,
             r###""true""###,
+            r###""try""###,
             r###""type""###,
+            r###""var""###,
+            r###""while""###,
+            r###""with""###,
+            r###""yield""###,
+            r###""{""###,
             r###""||""###,
-            r###""λ""###,
-            r###"r#"[+-]?[0-9]+"#"###,
-            r###"r#"[a-zA-Z][a-zA-Z0-9_]*"#"###,
+            r###""}""###,
+            r###"DocComment"###,
+            r###"Id"###,
+            r###"Num"###,
+            r###"Str"###,
+            r###"WithField"###,
         ];
         __TERMINAL.iter().enumerate().filter_map(|(index, terminal)| {
             let next_state = __action(__state, index);
@@ -1025,20 +1290,20 @@ mod __parse__Prog {
             }
         }).collect()
     }
-    pub(crate) struct __StateMachine<'input>
+    pub(crate) struct __StateMachine<>
     where 
     {
-        input: &'input str,
-        __phantom: core::marker::PhantomData<(&'input ())>,
+        file: FileId,
+        __phantom: core::marker::PhantomData<()>,
     }
-    impl<'input> __state_machine::ParserDefinition for __StateMachine<'input>
+    impl<> __state_machine::ParserDefinition for __StateMachine<>
     where 
     {
         type Location = usize;
-        type Error = &'static str;
-        type Token = Token<'input>;
+        type Error = LexError;
+        type Token = Tok;
         type TokenIndex = usize;
-        type Symbol = __Symbol<'input>;
+        type Symbol = __Symbol<>;
         type Success = SurfProg<Span1>;
         type StateIndex = i16;
         type Action = i16;
@@ -1057,7 +1322,7 @@ mod __parse__Prog {
 
         #[inline]
         fn token_to_index(&self, token: &Self::Token) -> Option<usize> {
-            __token_to_integer(token, core::marker::PhantomData::<(&())>)
+            __token_to_integer(token, core::marker::PhantomData::<()>)
         }
 
         #[inline]
@@ -1067,7 +1332,7 @@ mod __parse__Prog {
 
         #[inline]
         fn error_action(&self, state: i16) -> i16 {
-            __action(state, 51 - 1)
+            __action(state, 66 - 1)
         }
 
         #[inline]
@@ -1081,7 +1346,7 @@ mod __parse__Prog {
         }
 
         fn token_to_symbol(&self, token_index: usize, token: Self::Token) -> Self::Symbol {
-            __token_to_symbol(token_index, token, core::marker::PhantomData::<(&())>)
+            __token_to_symbol(token_index, token, core::marker::PhantomData::<()>)
         }
 
         fn expected_tokens(&self, state: i16) -> alloc::vec::Vec<alloc::string::String> {
@@ -1109,12 +1374,12 @@ mod __parse__Prog {
             symbols: &mut alloc::vec::Vec<__state_machine::SymbolTriple<Self>>,
         ) -> Option<__state_machine::ParseResult<Self>> {
             __reduce(
-                self.input,
+                self.file,
                 action,
                 start_location,
                 states,
                 symbols,
-                core::marker::PhantomData::<(&())>,
+                core::marker::PhantomData::<()>,
             )
         }
 
@@ -1123,499 +1388,609 @@ mod __parse__Prog {
         }
     }
     fn __token_to_integer<
-        'input,
     >(
-        __token: &Token<'input>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __token: &Tok,
+        _: core::marker::PhantomData<()>,
     ) -> Option<usize>
     {
         match *__token {
-            Token(2, _) if true => Some(0),
-            Token(3, _) if true => Some(1),
-            Token(4, _) if true => Some(2),
-            Token(5, _) if true => Some(3),
-            Token(6, _) if true => Some(4),
-            Token(7, _) if true => Some(5),
-            Token(8, _) if true => Some(6),
-            Token(9, _) if true => Some(7),
-            Token(10, _) if true => Some(8),
-            Token(11, _) if true => Some(9),
-            Token(12, _) if true => Some(10),
-            Token(13, _) if true => Some(11),
-            Token(14, _) if true => Some(12),
-            Token(15, _) if true => Some(13),
-            Token(16, _) if true => Some(14),
-            Token(17, _) if true => Some(15),
-            Token(18, _) if true => Some(16),
-            Token(19, _) if true => Some(17),
-            Token(20, _) if true => Some(18),
-            Token(21, _) if true => Some(19),
-            Token(22, _) if true => Some(20),
-            Token(23, _) if true => Some(21),
-            Token(24, _) if true => Some(22),
-            Token(25, _) if true => Some(23),
-            Token(26, _) if true => Some(24),
-            Token(27, _) if true => Some(25),
-            Token(28, _) if true => Some(26),
-            Token(29, _) if true => Some(27),
-            Token(30, _) if true => Some(28),
-            Token(31, _) if true => Some(29),
-            Token(32, _) if true => Some(30),
-            Token(33, _) if true => Some(31),
-            Token(34, _) if true => Some(32),
-            Token(35, _) if true => Some(33),
-            Token(36, _) if true => Some(34),
-            Token(37, _) if true => Some(35),
-            Token(38, _) if true => Some(36),
-            Token(39, _) if true => Some(37),
-            Token(40, _) if true => Some(38),
-            Token(41, _) if true => Some(39),
-            Token(42, _) if true => Some(40),
-            Token(43, _) if true => Some(41),
-            Token(44, _) if true => Some(42),
-            Token(45, _) if true => Some(43),
-            Token(46, _) if true => Some(44),
-            Token(47, _) if true => Some(45),
-            Token(48, _) if true => Some(46),
-            Token(49, _) if true => Some(47),
-            Token(50, _) if true => Some(48),
-            Token(0, _) if true => Some(49),
-            Token(1, _) if true => Some(50),
+            Tok::Bang if true => Some(0),
+            Tok::NotEq if true => Some(1),
+            Tok::AndAnd if true => Some(2),
+            Tok::LParen if true => Some(3),
+            Tok::RParen if true => Some(4),
+            Tok::Times if true => Some(5),
+            Tok::Plus if true => Some(6),
+            Tok::Comma if true => Some(7),
+            Tok::Minus if true => Some(8),
+            Tok::Dot if true => Some(9),
+            Tok::Colon if true => Some(10),
+            Tok::Assign if true => Some(11),
+            Tok::Semicolon if true => Some(12),
+            Tok::Less if true => Some(13),
+            Tok::LessEq if true => Some(14),
+            Tok::Equals if true => Some(15),
+            Tok::EqEq if true => Some(16),
+            Tok::Arrow if true => Some(17),
+            Tok::Greater if true => Some(18),
+            Tok::GreaterEq if true => Some(19),
+            Tok::LBracket if true => Some(20),
+            Tok::RBracket if true => Some(21),
+            Tok::Add1 if true => Some(22),
+            Tok::And if true => Some(23),
+            Tok::CallCC if true => Some(24),
+            Tok::Case if true => Some(25),
+            Tok::Chan if true => Some(26),
+            Tok::Def if true => Some(27),
+            Tok::Default if true => Some(28),
+            Tok::Else if true => Some(29),
+            Tok::End if true => Some(30),
+            Tok::False if true => Some(31),
+            Tok::For if true => Some(32),
+            Tok::If if true => Some(33),
+            Tok::Import if true => Some(34),
+            Tok::In if true => Some(35),
+            Tok::IsArray if true => Some(36),
+            Tok::IsBool if true => Some(37),
+            Tok::IsFun if true => Some(38),
+            Tok::IsNum if true => Some(39),
+            Tok::Lambda if true => Some(40),
+            Tok::Length if true => Some(41),
+            Tok::Let if true => Some(42),
+            Tok::Match if true => Some(43),
+            Tok::Print if true => Some(44),
+            Tok::Raise if true => Some(45),
+            Tok::Recv if true => Some(46),
+            Tok::Send if true => Some(47),
+            Tok::Spawn if true => Some(48),
+            Tok::Sub1 if true => Some(49),
+            Tok::To if true => Some(50),
+            Tok::True if true => Some(51),
+            Tok::Try if true => Some(52),
+            Tok::Type if true => Some(53),
+            Tok::Var if true => Some(54),
+            Tok::While if true => Some(55),
+            Tok::With if true => Some(56),
+            Tok::Yield if true => Some(57),
+            Tok::LBrace if true => Some(58),
+            Tok::OrOr if true => Some(59),
+            Tok::RBrace if true => Some(60),
+            Tok::DocComment(_) if true => Some(61),
+            Tok::Id(_) if true => Some(62),
+            Tok::Num(_) if true => Some(63),
+            Tok::Str(_) if true => Some(64),
+            Tok::WithField if true => Some(65),
             _ => None,
         }
     }
     fn __token_to_symbol<
-        'input,
     >(
         __token_index: usize,
-        __token: Token<'input>,
-        _: core::marker::PhantomData<(&'input ())>,
-    ) -> __Symbol<'input>
+        __token: Tok,
+        _: core::marker::PhantomData<()>,
+    ) -> __Symbol<>
     {
         match __token_index {
-            0 | 1 | 2 | 3 | 4 | 5 | 6 | 7 | 8 | 9 | 10 | 11 | 12 | 13 | 14 | 15 | 16 | 17 | 18 | 19 | 20 | 21 | 22 | 23 | 24 | 25 | 26 | 27 | 28 | 29 | 30 | 31 | 32 | 33 | 34 | 35 | 36 | 37 | 38 | 39 | 40 | 41 | 42 | 43 | 44 | 45 | 46 | 47 | 48 | 49 | 50 => match __token {
-                Token(2, __tok0) | Token(3, __tok0) | Token(4, __tok0) | Token(5, __tok0) | Token(6, __tok0) | Token(7, __tok0) | Token(8, __tok0) | Token(9, __tok0) | Token(10, __tok0) | Token(11, __tok0) | Token(12, __tok0) | Token(13, __tok0) | Token(14, __tok0) | Token(15, __tok0) | Token(16, __tok0) | Token(17, __tok0) | Token(18, __tok0) | Token(19, __tok0) | Token(20, __tok0) | Token(21, __tok0) | Token(22, __tok0) | Token(23, __tok0) | Token(24, __tok0) | Token(25, __tok0) | Token(26, __tok0) | Token(27, __tok0) | Token(28, __tok0) | Token(29, __tok0) | Token(30, __tok0) | Token(31, __tok0) | Token(32, __tok0) | Token(33, __tok0) | Token(34, __tok0) | Token(35, __tok0) | Token(36, __tok0) | Token(37, __tok0) | Token(38, __tok0) | Token(39, __tok0) | Token(40, __tok0) | Token(41, __tok0) | Token(42, __tok0) | Token(43, __tok0) | Token(44, __tok0) | Token(45, __tok0) | Token(46, __tok0) | Token(47, __tok0) | Token(48, __tok0) | Token(49, __tok0) | Token(50, __tok0) | Token(0, __tok0) | Token(1, __tok0) if true => __Symbol::Variant0(__tok0),
+            0 | 1 | 2 | 3 | 4 | 5 | 6 | 7 | 8 | 9 | 10 | 11 | 12 | 13 | 14 | 15 | 16 | 17 | 18 | 19 | 20 | 21 | 22 | 23 | 24 | 25 | 26 | 27 | 28 | 29 | 30 | 31 | 32 | 33 | 34 | 35 | 36 | 37 | 38 | 39 | 40 | 41 | 42 | 43 | 44 | 45 | 46 | 47 | 48 | 49 | 50 | 51 | 52 | 53 | 54 | 55 | 56 | 57 | 58 | 59 | 60 | 65 => __Symbol::Variant0(__token),
+            61 | 62 | 64 => match __token {
+                Tok::DocComment(__tok0) | Tok::Id(__tok0) | Tok::Str(__tok0) if true => __Symbol::Variant1(__tok0),
+                _ => unreachable!(),
+            },
+            63 => match __token {
+                Tok::Num(__tok0) if true => __Symbol::Variant2(__tok0),
                 _ => unreachable!(),
             },
             _ => unreachable!(),
         }
     }
     pub struct ProgParser {
-        builder: __lalrpop_util::lexer::MatcherBuilder,
         _priv: (),
     }
 
     impl ProgParser {
         pub fn new() -> ProgParser {
-            let __builder = super::__intern_token::new_builder();
             ProgParser {
-                builder: __builder,
                 _priv: (),
             }
         }
 
         #[allow(dead_code)]
         pub fn parse<
-            'input,
+            __TOKEN: __ToTriple<>,
+            __TOKENS: IntoIterator<Item=__TOKEN>,
         >(
             &self,
-            input: &'input str,
-        ) -> Result<SurfProg<Span1>, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            file: FileId,
+            __tokens0: __TOKENS,
+        ) -> Result<SurfProg<Span1>, __lalrpop_util::ParseError<usize, Tok, LexError>>
         {
-            let mut __tokens = self.builder.matcher(input);
+            let __tokens = __tokens0.into_iter();
+            let mut __tokens = __tokens.map(|t| __ToTriple::to_triple(t));
             __state_machine::Parser::drive(
                 __StateMachine {
-                    input,
-                    __phantom: core::marker::PhantomData::<(&())>,
+                    file,
+                    __phantom: core::marker::PhantomData::<()>,
                 },
                 __tokens,
             )
         }
     }
     pub(crate) fn __reduce<
-        'input,
     >(
-        input: &'input str,
+        file: FileId,
         __action: i16,
         __lookahead_start: Option<&usize>,
         __states: &mut alloc::vec::Vec<i16>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
-    ) -> Option<Result<SurfProg<Span1>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> Option<Result<SurfProg<Span1>,__lalrpop_util::ParseError<usize, Tok, LexError>>>
     {
         let (__pop_states, __nonterminal) = match __action {
             0 => {
-                __reduce0(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce0(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             1 => {
-                __reduce1(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce1(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             2 => {
-                __reduce2(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce2(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             3 => {
-                __reduce3(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce3(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             4 => {
-                __reduce4(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce4(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             5 => {
-                __reduce5(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce5(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             6 => {
-                __reduce6(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce6(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             7 => {
-                __reduce7(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce7(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             8 => {
-                __reduce8(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce8(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             9 => {
-                __reduce9(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce9(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             10 => {
-                __reduce10(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce10(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             11 => {
-                __reduce11(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce11(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             12 => {
-                __reduce12(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce12(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             13 => {
-                __reduce13(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce13(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             14 => {
-                __reduce14(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce14(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             15 => {
-                __reduce15(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce15(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             16 => {
-                __reduce16(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce16(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             17 => {
-                __reduce17(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce17(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             18 => {
-                __reduce18(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce18(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             19 => {
-                __reduce19(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce19(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             20 => {
-                __reduce20(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce20(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             21 => {
-                __reduce21(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce21(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             22 => {
-                __reduce22(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce22(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             23 => {
-                __reduce23(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce23(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             24 => {
-                __reduce24(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce24(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             25 => {
-                __reduce25(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce25(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             26 => {
-                __reduce26(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce26(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             27 => {
-                __reduce27(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce27(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             28 => {
-                __reduce28(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce28(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             29 => {
-                __reduce29(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce29(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             30 => {
-                __reduce30(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce30(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             31 => {
-                __reduce31(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce31(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             32 => {
-                __reduce32(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce32(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             33 => {
-                __reduce33(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce33(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             34 => {
-                __reduce34(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce34(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             35 => {
-                __reduce35(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce35(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             36 => {
-                __reduce36(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce36(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             37 => {
-                __reduce37(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce37(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             38 => {
-                __reduce38(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce38(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             39 => {
-                __reduce39(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce39(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             40 => {
-                __reduce40(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce40(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             41 => {
-                __reduce41(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce41(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             42 => {
-                __reduce42(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce42(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             43 => {
-                __reduce43(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce43(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             44 => {
-                __reduce44(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce44(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             45 => {
-                __reduce45(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce45(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             46 => {
-                __reduce46(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce46(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             47 => {
-                __reduce47(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce47(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             48 => {
-                __reduce48(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce48(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             49 => {
-                __reduce49(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce49(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             50 => {
-                __reduce50(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce50(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             51 => {
-                __reduce51(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce51(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             52 => {
-                __reduce52(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce52(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             53 => {
-                __reduce53(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce53(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             54 => {
-                __reduce54(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce54(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             55 => {
-                __reduce55(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce55(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             56 => {
-                __reduce56(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce56(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             57 => {
-                __reduce57(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce57(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             58 => {
-                __reduce58(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce58(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             59 => {
-                __reduce59(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce59(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             60 => {
-                __reduce60(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce60(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             61 => {
-                __reduce61(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce61(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             62 => {
-                __reduce62(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce62(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             63 => {
-                __reduce63(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce63(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             64 => {
-                __reduce64(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce64(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             65 => {
-                __reduce65(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce65(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             66 => {
-                __reduce66(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce66(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             67 => {
-                __reduce67(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce67(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             68 => {
-                __reduce68(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce68(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             69 => {
-                __reduce69(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce69(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             70 => {
-                __reduce70(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce70(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             71 => {
-                __reduce71(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce71(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             72 => {
-                __reduce72(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce72(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             73 => {
-                __reduce73(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce73(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             74 => {
-                __reduce74(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce74(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             75 => {
-                __reduce75(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce75(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             76 => {
-                __reduce76(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce76(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             77 => {
-                __reduce77(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce77(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             78 => {
-                __reduce78(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce78(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             79 => {
-                __reduce79(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce79(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             80 => {
-                __reduce80(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce80(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             81 => {
-                __reduce81(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce81(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             82 => {
-                __reduce82(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce82(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             83 => {
-                __reduce83(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce83(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             84 => {
-                __reduce84(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce84(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             85 => {
-                __reduce85(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce85(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             86 => {
-                __reduce86(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce86(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             87 => {
-                __reduce87(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce87(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             88 => {
-                __reduce88(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce88(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             89 => {
-                __reduce89(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce89(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             90 => {
-                __reduce90(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce90(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             91 => {
-                __reduce91(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce91(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             92 => {
-                __reduce92(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce92(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             93 => {
-                __reduce93(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce93(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             94 => {
-                __reduce94(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce94(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             95 => {
-                __reduce95(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce95(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             96 => {
-                __reduce96(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce96(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             97 => {
-                __reduce97(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce97(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             98 => {
-                __reduce98(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce98(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             99 => {
-                __reduce99(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce99(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             100 => {
-                __reduce100(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce100(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             101 => {
-                __reduce101(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce101(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             102 => {
-                __reduce102(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce102(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             103 => {
-                __reduce103(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce103(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             104 => {
-                __reduce104(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce104(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             105 => {
-                __reduce105(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce105(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             106 => {
-                __reduce106(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce106(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             107 => {
-                __reduce107(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce107(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             108 => {
-                __reduce108(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce108(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             109 => {
-                __reduce109(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce109(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             110 => {
-                __reduce110(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce110(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             111 => {
-                __reduce111(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce111(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             112 => {
-                __reduce112(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce112(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             113 => {
-                __reduce113(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce113(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             114 => {
-                __reduce114(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce114(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             115 => {
-                __reduce115(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce115(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             116 => {
-                __reduce116(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce116(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             117 => {
-                __reduce117(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce117(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             118 => {
-                __reduce118(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce118(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             119 => {
-                __reduce119(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce119(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             120 => {
-                __reduce120(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce120(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             121 => {
-                __reduce121(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce121(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             122 => {
+                __reduce122(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            123 => {
+                __reduce123(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            124 => {
+                __reduce124(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            125 => {
+                __reduce125(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            126 => {
+                __reduce126(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            127 => {
+                __reduce127(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            128 => {
+                __reduce128(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            129 => {
+                __reduce129(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            130 => {
+                __reduce130(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            131 => {
+                __reduce131(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            132 => {
+                __reduce132(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            133 => {
+                __reduce133(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            134 => {
+                __reduce134(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            135 => {
+                __reduce135(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            136 => {
+                __reduce136(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            137 => {
+                __reduce137(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            138 => {
+                __reduce138(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            139 => {
+                __reduce139(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            140 => {
+                __reduce140(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            141 => {
+                __reduce141(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            142 => {
+                __reduce142(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            143 => {
+                __reduce143(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            144 => {
+                __reduce144(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            145 => {
+                __reduce145(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            146 => {
+                __reduce146(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            147 => {
+                __reduce147(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            148 => {
+                __reduce148(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            149 => {
+                __reduce149(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            150 => {
+                __reduce150(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            151 => {
+                __reduce151(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            152 => {
+                __reduce152(file, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            153 => {
                 // __Prog = Prog => ActionFn(0);
-                let __sym0 = __pop_Variant22(__symbols);
+                let __sym0 = __pop_Variant26(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action0::<>(input, __sym0);
+                let __nt = super::__action0::<>(file, __sym0);
                 return Some(Ok(__nt));
             }
             _ => panic!("invalid action code {}", __action)
@@ -1631,3002 +2006,4082 @@ mod __parse__Prog {
     fn __symbol_type_mismatch() -> ! {
         panic!("symbol type mismatch")
     }
-    fn __pop_Variant19<
-      'input,
+    fn __pop_Variant16<
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, (), usize)
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
+    ) -> (usize, (SnakeType, Vec<String>, Exp<Span1>), usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant19(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant16(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant12<
-      'input,
+    fn __pop_Variant5<
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, (SnakeType, Vec<String>, Exp<Span1>), usize)
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
+    ) -> (usize, (String, String), usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant12(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant5(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant24<
-      'input,
+    fn __pop_Variant28<
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
     ) -> (usize, (String, Vec<String>), usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant24(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant28(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant5<
-      'input,
+    fn __pop_Variant8<
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, (SurfFunDecl<Span1>, &'input str), usize)
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
+    ) -> (usize, (SurfFunDecl<Span1>, Tok), usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant5(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant8(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant10<
-      'input,
+    fn __pop_Variant13<
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
     ) -> (usize, Box<Exp<Span1>>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant10(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant13(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant1<
-      'input,
+    fn __pop_Variant14<
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
+    ) -> (usize, Box<SurfProg<Span1>>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant14(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant3<
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
     ) -> (usize, Exp<Span1>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant1(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant3(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant21<
-      'input,
+    fn __pop_Variant25<
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
     ) -> (usize, Prim1, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant21(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant25(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant13<
-      'input,
+    fn __pop_Variant17<
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
     ) -> (usize, Prim2, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant13(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant17(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant3<
-      'input,
+    fn __pop_Variant1<
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
     ) -> (usize, String, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant3(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant1(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant17<
-      'input,
+    fn __pop_Variant24<
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
     ) -> (usize, SurfFunDecl<Span1>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant17(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant24(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant22<
-      'input,
+    fn __pop_Variant26<
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
     ) -> (usize, SurfProg<Span1>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant22(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant26(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant11<
-      'input,
+    fn __pop_Variant0<
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Vec<(SnakeType, Vec<String>, Exp<Span1>)>, usize)
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
+    ) -> (usize, Tok, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant11(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant0(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant8<
-      'input,
+    fn __pop_Variant15<
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Vec<(String, Exp<Span1>)>, usize)
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
+    ) -> (usize, Vec<(SnakeType, Vec<String>, Exp<Span1>)>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant8(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant15(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant23<
-      'input,
+    fn __pop_Variant11<
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
+    ) -> (usize, Vec<(String, Exp<Span1>)>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant11(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant19<
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
+    ) -> (usize, Vec<(String, String)>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant19(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant27<
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
     ) -> (usize, Vec<(String, Vec<String>)>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant23(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant27(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant14<
-      'input,
+    fn __pop_Variant18<
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
     ) -> (usize, Vec<Exp<Span1>>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant14(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant18(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant15<
-      'input,
+    fn __pop_Variant20<
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
     ) -> (usize, Vec<String>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant15(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant20(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
     fn __pop_Variant6<
-      'input,
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, alloc::vec::Vec<(SurfFunDecl<Span1>, &'input str)>, usize)
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
+    ) -> (usize, alloc::vec::Vec<(String, String)>, usize)
      {
         match __symbols.pop() {
             Some((__l, __Symbol::Variant6(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant2<
-      'input,
+    fn __pop_Variant9<
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, alloc::vec::Vec<Exp<Span1>>, usize)
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
+    ) -> (usize, alloc::vec::Vec<(SurfFunDecl<Span1>, Tok)>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant2(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant9(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
     fn __pop_Variant4<
-      'input,
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, alloc::vec::Vec<String>, usize)
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
+    ) -> (usize, alloc::vec::Vec<Exp<Span1>>, usize)
      {
         match __symbols.pop() {
             Some((__l, __Symbol::Variant4(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant9<
-      'input,
+    fn __pop_Variant7<
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
+    ) -> (usize, alloc::vec::Vec<String>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant7(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant12<
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
     ) -> (usize, bool, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant9(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant12(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant16<
-      'input,
+    fn __pop_Variant23<
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, core::option::Option<Exp<Span1>>, usize)
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
+    ) -> (usize, core::option::Option<(String, String)>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant16(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant23(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant18<
-      'input,
+    fn __pop_Variant22<
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, core::option::Option<String>, usize)
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
+    ) -> (usize, core::option::Option<Exp<Span1>>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant18(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant22(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant20<
-      'input,
+    fn __pop_Variant21<
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, i64, usize)
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
+    ) -> (usize, core::option::Option<String>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant20(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant21(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant7<
-      'input,
+    fn __pop_Variant2<
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, usize, usize)
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
+    ) -> (usize, i64, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant7(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant2(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant0<
-      'input,
+    fn __pop_Variant10<
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, &'input str, usize)
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
+    ) -> (usize, usize, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant0(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant10(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
     pub(crate) fn __reduce0<
-        'input,
     >(
-        input: &'input str,
+        file: FileId,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // (<Exp> ",") = Exp, "," => ActionFn(111);
+        // (<Exp> ",") = Exp, "," => ActionFn(130);
         assert!(__symbols.len() >= 2);
         let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant1(__symbols);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym1.2.clone();
-        let __nt = super::__action111::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
+        let __nt = super::__action130::<>(file, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
         (2, 0)
     }
     pub(crate) fn __reduce1<
-        'input,
     >(
-        input: &'input str,
+        file: FileId,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // (<Exp> ",")* =  => ActionFn(109);
+        // (<Exp> ",")* =  => ActionFn(128);
         let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
         let __end = __start.clone();
-        let __nt = super::__action109::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
+        let __nt = super::__action128::<>(file, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
         (0, 1)
     }
     pub(crate) fn __reduce2<
-        'input,
     >(
-        input: &'input str,
+        file: FileId,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // (<Exp> ",")* = (<Exp> ",")+ => ActionFn(110);
-        let __sym0 = __pop_Variant2(__symbols);
+        // (<Exp> ",")* = (<Exp> ",")+ => ActionFn(129);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action110::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
+        let __nt = super::__action129::<>(file, __sym0);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
         (1, 1)
     }
     pub(crate) fn __reduce3<
-        'input,
     >(
-        input: &'input str,
+        file: FileId,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // (<Exp> ",")+ = Exp, "," => ActionFn(116);
+        // (<Exp> ",")+ = Exp, "," => ActionFn(142);
         assert!(__symbols.len() >= 2);
         let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant1(__symbols);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym1.2.clone();
-        let __nt = super::__action116::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
+        let __nt = super::__action142::<>(file, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
         (2, 2)
     }
     pub(crate) fn __reduce4<
-        'input,
     >(
-        input: &'input str,
+        file: FileId,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // (<Exp> ",")+ = (<Exp> ",")+, Exp, "," => ActionFn(117);
+        // (<Exp> ",")+ = (<Exp> ",")+, Exp, "," => ActionFn(143);
         assert!(__symbols.len() >= 3);
         let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant1(__symbols);
-        let __sym0 = __pop_Variant2(__symbols);
+        let __sym1 = __pop_Variant3(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym2.2.clone();
-        let __nt = super::__action117::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
+        let __nt = super::__action143::<>(file, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
         (3, 2)
     }
     pub(crate) fn __reduce5<
-        'input,
     >(
-        input: &'input str,
+        file: FileId,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // (<Id> ",") = Id, "," => ActionFn(98);
+        // (<FieldPattern> ",") = FieldPattern, "," => ActionFn(135);
         assert!(__symbols.len() >= 2);
         let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant3(__symbols);
+        let __sym0 = __pop_Variant5(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym1.2.clone();
-        let __nt = super::__action98::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        let __nt = super::__action135::<>(file, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
         (2, 3)
     }
     pub(crate) fn __reduce6<
-        'input,
     >(
-        input: &'input str,
+        file: FileId,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // (<Id> ",")* =  => ActionFn(96);
+        // (<FieldPattern> ",")* =  => ActionFn(133);
         let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
         let __end = __start.clone();
-        let __nt = super::__action96::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        let __nt = super::__action133::<>(file, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
         (0, 4)
     }
     pub(crate) fn __reduce7<
-        'input,
     >(
-        input: &'input str,
+        file: FileId,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // (<Id> ",")* = (<Id> ",")+ => ActionFn(97);
-        let __sym0 = __pop_Variant4(__symbols);
+        // (<FieldPattern> ",")* = (<FieldPattern> ",")+ => ActionFn(134);
+        let __sym0 = __pop_Variant6(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action97::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        let __nt = super::__action134::<>(file, __sym0);
+        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
         (1, 4)
     }
     pub(crate) fn __reduce8<
-        'input,
     >(
-        input: &'input str,
+        file: FileId,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // (<Id> ",")+ = Id, "," => ActionFn(120);
+        // (<FieldPattern> ",")+ = FieldPattern, "," => ActionFn(146);
         assert!(__symbols.len() >= 2);
         let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant3(__symbols);
+        let __sym0 = __pop_Variant5(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym1.2.clone();
-        let __nt = super::__action120::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        let __nt = super::__action146::<>(file, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
         (2, 5)
     }
     pub(crate) fn __reduce9<
-        'input,
     >(
-        input: &'input str,
+        file: FileId,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // (<Id> ",")+ = (<Id> ",")+, Id, "," => ActionFn(121);
+        // (<FieldPattern> ",")+ = (<FieldPattern> ",")+, FieldPattern, "," => ActionFn(147);
         assert!(__symbols.len() >= 3);
         let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant3(__symbols);
-        let __sym0 = __pop_Variant4(__symbols);
+        let __sym1 = __pop_Variant5(__symbols);
+        let __sym0 = __pop_Variant6(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym2.2.clone();
-        let __nt = super::__action121::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        let __nt = super::__action147::<>(file, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
         (3, 5)
     }
     pub(crate) fn __reduce10<
-        'input,
     >(
-        input: &'input str,
+        file: FileId,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // (FunDecl "and") = FunDecl, "and" => ActionFn(90);
+        // (<Id> ",") = Id, "," => ActionFn(117);
         assert!(__symbols.len() >= 2);
         let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant17(__symbols);
+        let __sym0 = __pop_Variant1(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym1.2.clone();
-        let __nt = super::__action90::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        let __nt = super::__action117::<>(file, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
         (2, 6)
     }
     pub(crate) fn __reduce11<
-        'input,
     >(
-        input: &'input str,
+        file: FileId,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // (FunDecl "and")* =  => ActionFn(88);
+        // (<Id> ",")* =  => ActionFn(115);
         let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
         let __end = __start.clone();
-        let __nt = super::__action88::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
+        let __nt = super::__action115::<>(file, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
         (0, 7)
     }
     pub(crate) fn __reduce12<
-        'input,
     >(
-        input: &'input str,
+        file: FileId,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // (FunDecl "and")* = (FunDecl "and")+ => ActionFn(89);
-        let __sym0 = __pop_Variant6(__symbols);
+        // (<Id> ",")* = (<Id> ",")+ => ActionFn(116);
+        let __sym0 = __pop_Variant7(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action89::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
+        let __nt = super::__action116::<>(file, __sym0);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
         (1, 7)
     }
     pub(crate) fn __reduce13<
-        'input,
     >(
-        input: &'input str,
+        file: FileId,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // (FunDecl "and")+ = FunDecl, "and" => ActionFn(124);
+        // (<Id> ",")+ = Id, "," => ActionFn(150);
         assert!(__symbols.len() >= 2);
         let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant17(__symbols);
+        let __sym0 = __pop_Variant1(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym1.2.clone();
-        let __nt = super::__action124::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
+        let __nt = super::__action150::<>(file, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
         (2, 8)
     }
     pub(crate) fn __reduce14<
-        'input,
     >(
-        input: &'input str,
+        file: FileId,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // (FunDecl "and")+ = (FunDecl "and")+, FunDecl, "and" => ActionFn(125);
+        // (<Id> ",")+ = (<Id> ",")+, Id, "," => ActionFn(151);
         assert!(__symbols.len() >= 3);
         let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant17(__symbols);
-        let __sym0 = __pop_Variant6(__symbols);
+        let __sym1 = __pop_Variant1(__symbols);
+        let __sym0 = __pop_Variant7(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym2.2.clone();
-        let __nt = super::__action125::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
+        let __nt = super::__action151::<>(file, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
         (3, 8)
     }
     pub(crate) fn __reduce15<
-        'input,
     >(
-        input: &'input str,
+        file: FileId,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // @L =  => ActionFn(91);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
-        let __end = __start.clone();
-        let __nt = super::__action91::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
-        (0, 9)
+        // (FunDecl "and") = FunDecl, "and" => ActionFn(107);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant24(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action107::<>(file, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
+        (2, 9)
     }
     pub(crate) fn __reduce16<
-        'input,
     >(
-        input: &'input str,
+        file: FileId,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // @R =  => ActionFn(86);
+        // (FunDecl "and")* =  => ActionFn(105);
         let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
         let __end = __start.clone();
-        let __nt = super::__action86::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        let __nt = super::__action105::<>(file, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
         (0, 10)
     }
     pub(crate) fn __reduce17<
-        'input,
     >(
-        input: &'input str,
+        file: FileId,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // ArrExp = BaseExp => ActionFn(35);
-        let __sym0 = __pop_Variant1(__symbols);
+        // (FunDecl "and")* = (FunDecl "and")+ => ActionFn(106);
+        let __sym0 = __pop_Variant9(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action35::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 11)
+        let __nt = super::__action106::<>(file, __sym0);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (1, 10)
     }
     pub(crate) fn __reduce18<
-        'input,
     >(
-        input: &'input str,
+        file: FileId,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // ArrExp = Boxed<ArrExp>, "[", Boxed<Exp>, "]" => ActionFn(150);
-        assert!(__symbols.len() >= 4);
-        let __sym3 = __pop_Variant0(__symbols);
-        let __sym2 = __pop_Variant10(__symbols);
+        // (FunDecl "and")+ = FunDecl, "and" => ActionFn(154);
+        assert!(__symbols.len() >= 2);
         let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant10(__symbols);
+        let __sym0 = __pop_Variant24(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym3.2.clone();
-        let __nt = super::__action150::<>(input, __sym0, __sym1, __sym2, __sym3);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (4, 11)
+        let __end = __sym1.2.clone();
+        let __nt = super::__action154::<>(file, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (2, 11)
     }
     pub(crate) fn __reduce19<
-        'input,
     >(
-        input: &'input str,
+        file: FileId,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // ArrExp = Boxed<ArrExp>, "[", Boxed<Exp>, "]", ":=", Boxed<BaseExp> => ActionFn(151);
-        assert!(__symbols.len() >= 6);
-        let __sym5 = __pop_Variant10(__symbols);
-        let __sym4 = __pop_Variant0(__symbols);
-        let __sym3 = __pop_Variant0(__symbols);
-        let __sym2 = __pop_Variant10(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant10(__symbols);
+        // (FunDecl "and")+ = (FunDecl "and")+, FunDecl, "and" => ActionFn(155);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant24(__symbols);
+        let __sym0 = __pop_Variant9(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym5.2.clone();
-        let __nt = super::__action151::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (6, 11)
+        let __end = __sym2.2.clone();
+        let __nt = super::__action155::<>(file, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (3, 11)
     }
     pub(crate) fn __reduce20<
-        'input,
     >(
-        input: &'input str,
+        file: FileId,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // BaseExp = Id => ActionFn(152);
-        let __sym0 = __pop_Variant3(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action152::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 12)
+        // @L =  => ActionFn(110);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action110::<>(file, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
+        (0, 12)
     }
     pub(crate) fn __reduce21<
-        'input,
     >(
-        input: &'input str,
+        file: FileId,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // BaseExp = Bool => ActionFn(153);
-        let __sym0 = __pop_Variant9(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action153::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 12)
+        // @R =  => ActionFn(109);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action109::<>(file, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
+        (0, 13)
     }
     pub(crate) fn __reduce22<
-        'input,
     >(
-        input: &'input str,
+        file: FileId,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // BaseExp = Num => ActionFn(154);
-        let __sym0 = __pop_Variant20(__symbols);
+        // ArrExp = BaseExp => ActionFn(44);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action154::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 12)
+        let __nt = super::__action44::<>(file, __sym0);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (1, 14)
     }
     pub(crate) fn __reduce23<
-        'input,
     >(
-        input: &'input str,
+        file: FileId,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // BaseExp = Prim1, "(", Boxed<Exp>, ")" => ActionFn(155);
+        // ArrExp = Boxed<ArrExp>, "[", Boxed<Exp>, "]" => ActionFn(191);
         assert!(__symbols.len() >= 4);
         let __sym3 = __pop_Variant0(__symbols);
-        let __sym2 = __pop_Variant10(__symbols);
+        let __sym2 = __pop_Variant13(__symbols);
         let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant21(__symbols);
+        let __sym0 = __pop_Variant13(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym3.2.clone();
-        let __nt = super::__action155::<>(input, __sym0, __sym1, __sym2, __sym3);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (4, 12)
+        let __nt = super::__action191::<>(file, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (4, 14)
     }
     pub(crate) fn __reduce24<
-        'input,
     >(
-        input: &'input str,
+        file: FileId,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // BaseExp = "(", Exp, ")" => ActionFn(42);
+        // ArrExp = Boxed<ArrExp>, ".", Id => ActionFn(192);
         assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant1(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant1(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant13(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym2.2.clone();
-        let __nt = super::__action42::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (3, 12)
+        let __nt = super::__action192::<>(file, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (3, 14)
     }
     pub(crate) fn __reduce25<
-        'input,
     >(
-        input: &'input str,
+        file: FileId,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // BaseExp = Boxed<BaseExp>, "(", Comma<Exp>, ")" => ActionFn(156);
-        assert!(__symbols.len() >= 4);
+        // ArrExp = Boxed<ArrExp>, "[", Boxed<Exp>, "]", ":=", Boxed<BaseExp> => ActionFn(193);
+        assert!(__symbols.len() >= 6);
+        let __sym5 = __pop_Variant13(__symbols);
+        let __sym4 = __pop_Variant0(__symbols);
         let __sym3 = __pop_Variant0(__symbols);
-        let __sym2 = __pop_Variant14(__symbols);
+        let __sym2 = __pop_Variant13(__symbols);
         let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant10(__symbols);
+        let __sym0 = __pop_Variant13(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym3.2.clone();
-        let __nt = super::__action156::<>(input, __sym0, __sym1, __sym2, __sym3);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (4, 12)
+        let __end = __sym5.2.clone();
+        let __nt = super::__action193::<>(file, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (6, 14)
     }
     pub(crate) fn __reduce26<
-        'input,
     >(
-        input: &'input str,
+        file: FileId,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // BaseExp = LAMBDA, Params, ":", Boxed<Exp>, "end" => ActionFn(157);
-        assert!(__symbols.len() >= 5);
-        let __sym4 = __pop_Variant0(__symbols);
-        let __sym3 = __pop_Variant10(__symbols);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant15(__symbols);
-        let __sym0 = __pop_Variant19(__symbols);
+        // BaseExp = Id => ActionFn(194);
+        let __sym0 = __pop_Variant1(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym4.2.clone();
-        let __nt = super::__action157::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (5, 12)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action194::<>(file, __sym0);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (1, 15)
     }
     pub(crate) fn __reduce27<
-        'input,
     >(
-        input: &'input str,
+        file: FileId,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // BaseExp = "[", Comma<Exp>, "]" => ActionFn(158);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant14(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // BaseExp = Bool => ActionFn(195);
+        let __sym0 = __pop_Variant12(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action158::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (3, 12)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action195::<>(file, __sym0);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (1, 15)
     }
     pub(crate) fn __reduce28<
-        'input,
     >(
-        input: &'input str,
+        file: FileId,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // BinOps = LogExp => ActionFn(17);
-        let __sym0 = __pop_Variant1(__symbols);
+        // BaseExp = Num => ActionFn(196);
+        let __sym0 = __pop_Variant2(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action17::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 13)
+        let __nt = super::__action196::<>(file, __sym0);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (1, 15)
     }
     pub(crate) fn __reduce29<
-        'input,
     >(
-        input: &'input str,
+        file: FileId,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // BindExp = Id => ActionFn(69);
-        let __sym0 = __pop_Variant3(__symbols);
+        // BaseExp = Prim1, "(", Boxed<Exp>, ")" => ActionFn(197);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant13(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant25(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action69::<>(input, __sym0);
+        let __end = __sym3.2.clone();
+        let __nt = super::__action197::<>(file, __sym0, __sym1, __sym2, __sym3);
         __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (1, 14)
+        (4, 15)
     }
     pub(crate) fn __reduce30<
-        'input,
     >(
-        input: &'input str,
+        file: FileId,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Bindings = BindExp, "=", Exp => ActionFn(60);
+        // BaseExp = "chan", "(", ")" => ActionFn(198);
         assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant1(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
         let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant3(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym2.2.clone();
-        let __nt = super::__action60::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
+        let __nt = super::__action198::<>(file, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
         (3, 15)
     }
     pub(crate) fn __reduce31<
-        'input,
     >(
-        input: &'input str,
+        file: FileId,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Bindings = Bindings, ",", BindExp, "=", Exp => ActionFn(61);
-        assert!(__symbols.len() >= 5);
-        let __sym4 = __pop_Variant1(__symbols);
+        // BaseExp = "send", "(", Boxed<Exp>, ",", Boxed<Exp>, ")" => ActionFn(199);
+        assert!(__symbols.len() >= 6);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant13(__symbols);
         let __sym3 = __pop_Variant0(__symbols);
-        let __sym2 = __pop_Variant3(__symbols);
+        let __sym2 = __pop_Variant13(__symbols);
         let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant8(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym4.2.clone();
-        let __nt = super::__action61::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4);
-        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
-        (5, 15)
+        let __end = __sym5.2.clone();
+        let __nt = super::__action199::<>(file, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (6, 15)
     }
     pub(crate) fn __reduce32<
-        'input,
     >(
-        input: &'input str,
+        file: FileId,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Bool = "true" => ActionFn(72);
+        // BaseExp = "(", Exp, ")" => ActionFn(54);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant3(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action72::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
-        (1, 16)
+        let __end = __sym2.2.clone();
+        let __nt = super::__action54::<>(file, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (3, 15)
     }
     pub(crate) fn __reduce33<
-        'input,
     >(
-        input: &'input str,
+        file: FileId,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Bool = "false" => ActionFn(73);
-        let __sym0 = __pop_Variant0(__symbols);
+        // BaseExp = Boxed<BaseExp>, "(", Comma<Exp>, ")" => ActionFn(200);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant18(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant13(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action73::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
-        (1, 16)
+        let __end = __sym3.2.clone();
+        let __nt = super::__action200::<>(file, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (4, 15)
     }
     pub(crate) fn __reduce34<
-        'input,
     >(
-        input: &'input str,
+        file: FileId,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Boxed<ArrExp> = ArrExp => ActionFn(75);
-        let __sym0 = __pop_Variant1(__symbols);
+        // BaseExp = "lambda", Params, ":", Boxed<Exp>, "end" => ActionFn(201);
+        assert!(__symbols.len() >= 5);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant13(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant20(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action75::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
-        (1, 17)
+        let __end = __sym4.2.clone();
+        let __nt = super::__action201::<>(file, __sym0, __sym1, __sym2, __sym3, __sym4);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (5, 15)
     }
     pub(crate) fn __reduce35<
-        'input,
     >(
-        input: &'input str,
+        file: FileId,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Boxed<BaseExp> = BaseExp => ActionFn(76);
-        let __sym0 = __pop_Variant1(__symbols);
+        // BaseExp = "while", Boxed<Exp>, ":", Boxed<Exp>, "end" => ActionFn(202);
+        assert!(__symbols.len() >= 5);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant13(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant13(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action76::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
-        (1, 18)
+        let __end = __sym4.2.clone();
+        let __nt = super::__action202::<>(file, __sym0, __sym1, __sym2, __sym3, __sym4);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (5, 15)
     }
     pub(crate) fn __reduce36<
-        'input,
     >(
-        input: &'input str,
+        file: FileId,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Boxed<CmpExp> = CmpExp => ActionFn(100);
-        let __sym0 = __pop_Variant1(__symbols);
+        // BaseExp = "for", Id, "=", Boxed<Exp>, "to", Boxed<Exp>, ":", Boxed<Exp>, "end" => ActionFn(203);
+        assert!(__symbols.len() >= 9);
+        let __sym8 = __pop_Variant0(__symbols);
+        let __sym7 = __pop_Variant13(__symbols);
+        let __sym6 = __pop_Variant0(__symbols);
+        let __sym5 = __pop_Variant13(__symbols);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant13(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant1(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action100::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
-        (1, 19)
+        let __end = __sym8.2.clone();
+        let __nt = super::__action203::<>(file, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6, __sym7, __sym8);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (9, 15)
     }
     pub(crate) fn __reduce37<
-        'input,
     >(
-        input: &'input str,
+        file: FileId,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Boxed<Exp> = Exp => ActionFn(87);
-        let __sym0 = __pop_Variant1(__symbols);
+        // BaseExp = "[", Comma<Exp>, "]" => ActionFn(204);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant18(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action87::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
-        (1, 20)
+        let __end = __sym2.2.clone();
+        let __nt = super::__action204::<>(file, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (3, 15)
     }
     pub(crate) fn __reduce38<
-        'input,
     >(
-        input: &'input str,
+        file: FileId,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Boxed<LAssoc<Cmp, Sum>> = LAssoc<Cmp, Sum> => ActionFn(102);
+        // BinOps = Id, ":=", Boxed<LogExp> => ActionFn(205);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant13(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
         let __sym0 = __pop_Variant1(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action102::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
-        (1, 21)
+        let __end = __sym2.2.clone();
+        let __nt = super::__action205::<>(file, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (3, 16)
     }
     pub(crate) fn __reduce39<
-        'input,
     >(
-        input: &'input str,
+        file: FileId,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Boxed<LAssoc<PlusMinus, Product>> = LAssoc<PlusMinus, Product> => ActionFn(104);
-        let __sym0 = __pop_Variant1(__symbols);
+        // BinOps = UpdExp => ActionFn(23);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action104::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
-        (1, 22)
+        let __nt = super::__action23::<>(file, __sym0);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (1, 16)
     }
     pub(crate) fn __reduce40<
-        'input,
     >(
-        input: &'input str,
+        file: FileId,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Boxed<LAssoc<Times, NotExp>> = LAssoc<Times, NotExp> => ActionFn(106);
+        // BindExp = Id => ActionFn(84);
         let __sym0 = __pop_Variant1(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action106::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
-        (1, 23)
+        let __nt = super::__action84::<>(file, __sym0);
+        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
+        (1, 17)
     }
     pub(crate) fn __reduce41<
-        'input,
     >(
-        input: &'input str,
+        file: FileId,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Boxed<NotExp> = NotExp => ActionFn(105);
+        // Bindings = BindExp, "=", Exp => ActionFn(76);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant3(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
         let __sym0 = __pop_Variant1(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action105::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
-        (1, 24)
+        let __end = __sym2.2.clone();
+        let __nt = super::__action76::<>(file, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
+        (3, 18)
     }
     pub(crate) fn __reduce42<
-        'input,
     >(
-        input: &'input str,
+        file: FileId,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Boxed<Product> = Product => ActionFn(103);
-        let __sym0 = __pop_Variant1(__symbols);
+        // Bindings = Bindings, ",", BindExp, "=", Exp => ActionFn(77);
+        assert!(__symbols.len() >= 5);
+        let __sym4 = __pop_Variant3(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant1(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant11(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action103::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
-        (1, 25)
+        let __end = __sym4.2.clone();
+        let __nt = super::__action77::<>(file, __sym0, __sym1, __sym2, __sym3, __sym4);
+        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
+        (5, 18)
     }
     pub(crate) fn __reduce43<
-        'input,
     >(
-        input: &'input str,
+        file: FileId,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Boxed<RAssoc<Log, CmpExp>> = RAssoc<Log, CmpExp> => ActionFn(99);
-        let __sym0 = __pop_Variant1(__symbols);
+        // Bool = "true" => ActionFn(85);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action99::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
-        (1, 26)
+        let __nt = super::__action85::<>(file, __sym0);
+        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
+        (1, 19)
     }
     pub(crate) fn __reduce44<
-        'input,
     >(
-        input: &'input str,
+        file: FileId,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Boxed<Sum> = Sum => ActionFn(101);
-        let __sym0 = __pop_Variant1(__symbols);
+        // Bool = "false" => ActionFn(86);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action101::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
-        (1, 27)
+        let __nt = super::__action86::<>(file, __sym0);
+        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
+        (1, 19)
     }
     pub(crate) fn __reduce45<
-        'input,
     >(
-        input: &'input str,
+        file: FileId,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // CaseDecls = CaseExp => ActionFn(58);
-        let __sym0 = __pop_Variant12(__symbols);
+        // Boxed<ArrExp> = ArrExp => ActionFn(89);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action58::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (1, 28)
+        let __nt = super::__action89::<>(file, __sym0);
+        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
+        (1, 20)
     }
     pub(crate) fn __reduce46<
-        'input,
     >(
-        input: &'input str,
+        file: FileId,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // CaseDecls = CaseDecls, ",", CaseExp => ActionFn(59);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant12(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant11(__symbols);
+        // Boxed<BaseExp> = BaseExp => ActionFn(90);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action59::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (3, 28)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action90::<>(file, __sym0);
+        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
+        (1, 21)
     }
     pub(crate) fn __reduce47<
-        'input,
     >(
-        input: &'input str,
+        file: FileId,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // CaseExp = "case", TypeExp, "=>", Exp => ActionFn(64);
-        assert!(__symbols.len() >= 4);
-        let __sym3 = __pop_Variant1(__symbols);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant24(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Boxed<CmpExp> = CmpExp => ActionFn(119);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym3.2.clone();
-        let __nt = super::__action64::<>(input, __sym0, __sym1, __sym2, __sym3);
-        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
-        (4, 29)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action119::<>(file, __sym0);
+        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
+        (1, 22)
     }
     pub(crate) fn __reduce48<
-        'input,
     >(
-        input: &'input str,
+        file: FileId,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // CaseExp = "case Num", "(", Id, ")", "=>", Exp => ActionFn(65);
-        assert!(__symbols.len() >= 6);
-        let __sym5 = __pop_Variant1(__symbols);
-        let __sym4 = __pop_Variant0(__symbols);
-        let __sym3 = __pop_Variant0(__symbols);
-        let __sym2 = __pop_Variant3(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Boxed<Exp> = Exp => ActionFn(104);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym5.2.clone();
-        let __nt = super::__action65::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
-        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
-        (6, 29)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action104::<>(file, __sym0);
+        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
+        (1, 23)
     }
     pub(crate) fn __reduce49<
-        'input,
     >(
-        input: &'input str,
+        file: FileId,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // CaseExp = "case Func", "(", Id, ")", "=>", Exp => ActionFn(66);
-        assert!(__symbols.len() >= 6);
-        let __sym5 = __pop_Variant1(__symbols);
-        let __sym4 = __pop_Variant0(__symbols);
-        let __sym3 = __pop_Variant0(__symbols);
-        let __sym2 = __pop_Variant3(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Boxed<LAssoc<Cmp, Sum>> = LAssoc<Cmp, Sum> => ActionFn(121);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym5.2.clone();
-        let __nt = super::__action66::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
-        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
-        (6, 29)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action121::<>(file, __sym0);
+        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
+        (1, 24)
     }
     pub(crate) fn __reduce50<
-        'input,
     >(
-        input: &'input str,
+        file: FileId,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // CaseExp = "case Bool", "(", Id, ")", "=>", Exp => ActionFn(67);
-        assert!(__symbols.len() >= 6);
-        let __sym5 = __pop_Variant1(__symbols);
-        let __sym4 = __pop_Variant0(__symbols);
-        let __sym3 = __pop_Variant0(__symbols);
-        let __sym2 = __pop_Variant3(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Boxed<LAssoc<PlusMinus, Product>> = LAssoc<PlusMinus, Product> => ActionFn(123);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym5.2.clone();
-        let __nt = super::__action67::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
-        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
-        (6, 29)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action123::<>(file, __sym0);
+        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
+        (1, 25)
     }
     pub(crate) fn __reduce51<
-        'input,
     >(
-        input: &'input str,
+        file: FileId,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // CaseExp = "case Array", "(", Id, ")", "=>", Exp => ActionFn(68);
-        assert!(__symbols.len() >= 6);
-        let __sym5 = __pop_Variant1(__symbols);
-        let __sym4 = __pop_Variant0(__symbols);
-        let __sym3 = __pop_Variant0(__symbols);
-        let __sym2 = __pop_Variant3(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Boxed<LAssoc<Times, NotExp>> = LAssoc<Times, NotExp> => ActionFn(125);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym5.2.clone();
-        let __nt = super::__action68::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
-        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
-        (6, 29)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action125::<>(file, __sym0);
+        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
+        (1, 26)
     }
     pub(crate) fn __reduce52<
-        'input,
     >(
-        input: &'input str,
+        file: FileId,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Cmp = "<" => ActionFn(27);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Boxed<LogExp> = LogExp => ActionFn(100);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action27::<>(input, __sym0);
+        let __nt = super::__action100::<>(file, __sym0);
         __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (1, 30)
+        (1, 27)
     }
     pub(crate) fn __reduce53<
-        'input,
     >(
-        input: &'input str,
+        file: FileId,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Cmp = "<=" => ActionFn(28);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Boxed<NotExp> = NotExp => ActionFn(124);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action28::<>(input, __sym0);
+        let __nt = super::__action124::<>(file, __sym0);
         __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (1, 30)
+        (1, 28)
     }
     pub(crate) fn __reduce54<
-        'input,
     >(
-        input: &'input str,
+        file: FileId,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Cmp = ">" => ActionFn(29);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Boxed<Product> = Product => ActionFn(122);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action29::<>(input, __sym0);
+        let __nt = super::__action122::<>(file, __sym0);
         __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (1, 30)
+        (1, 29)
     }
     pub(crate) fn __reduce55<
-        'input,
     >(
-        input: &'input str,
+        file: FileId,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Cmp = ">=" => ActionFn(30);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Boxed<Prog> = Prog => ActionFn(108);
+        let __sym0 = __pop_Variant26(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action30::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
+        let __nt = super::__action108::<>(file, __sym0);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
         (1, 30)
     }
     pub(crate) fn __reduce56<
-        'input,
     >(
-        input: &'input str,
+        file: FileId,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Cmp = "==" => ActionFn(31);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Boxed<RAssoc<Log, CmpExp>> = RAssoc<Log, CmpExp> => ActionFn(118);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action31::<>(input, __sym0);
+        let __nt = super::__action118::<>(file, __sym0);
         __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (1, 30)
+        (1, 31)
     }
     pub(crate) fn __reduce57<
-        'input,
     >(
-        input: &'input str,
+        file: FileId,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Cmp = "!=" => ActionFn(32);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Boxed<Sum> = Sum => ActionFn(120);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action32::<>(input, __sym0);
+        let __nt = super::__action120::<>(file, __sym0);
         __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (1, 30)
+        (1, 32)
     }
     pub(crate) fn __reduce58<
-        'input,
     >(
-        input: &'input str,
+        file: FileId,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // CmpExp = LAssoc<Cmp, Sum> => ActionFn(19);
-        let __sym0 = __pop_Variant1(__symbols);
+        // Boxed<UpdExp> = UpdExp => ActionFn(99);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action19::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 31)
+        let __nt = super::__action99::<>(file, __sym0);
+        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
+        (1, 33)
     }
     pub(crate) fn __reduce59<
-        'input,
     >(
-        input: &'input str,
+        file: FileId,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Comma<Exp> = Exp => ActionFn(172);
-        let __sym0 = __pop_Variant1(__symbols);
+        // CaseDecls = CaseExp => ActionFn(74);
+        let __sym0 = __pop_Variant16(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action172::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
-        (1, 32)
+        let __nt = super::__action74::<>(file, __sym0);
+        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
+        (1, 34)
     }
     pub(crate) fn __reduce60<
-        'input,
     >(
-        input: &'input str,
+        file: FileId,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Comma<Exp> =  => ActionFn(173);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
-        let __end = __start.clone();
-        let __nt = super::__action173::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
-        (0, 32)
+        // CaseDecls = CaseDecls, ",", CaseExp => ActionFn(75);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant16(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant15(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action75::<>(file, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
+        (3, 34)
     }
     pub(crate) fn __reduce61<
-        'input,
     >(
-        input: &'input str,
+        file: FileId,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Comma<Exp> = (<Exp> ",")+, Exp => ActionFn(174);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant1(__symbols);
-        let __sym0 = __pop_Variant2(__symbols);
+        // CaseExp = "case", TypeExp, "=>", Exp => ActionFn(80);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant3(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant28(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action174::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
-        (2, 32)
+        let __end = __sym3.2.clone();
+        let __nt = super::__action80::<>(file, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
+        (4, 35)
     }
     pub(crate) fn __reduce62<
-        'input,
     >(
-        input: &'input str,
+        file: FileId,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Comma<Exp> = (<Exp> ",")+ => ActionFn(175);
-        let __sym0 = __pop_Variant2(__symbols);
+        // CaseExp = "case", Id, "{", Comma<FieldPattern>, "}", "=>", Exp => ActionFn(81);
+        assert!(__symbols.len() >= 7);
+        let __sym6 = __pop_Variant3(__symbols);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant19(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant1(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action175::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
-        (1, 32)
+        let __end = __sym6.2.clone();
+        let __nt = super::__action81::<>(file, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
+        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
+        (7, 35)
     }
     pub(crate) fn __reduce63<
-        'input,
     >(
-        input: &'input str,
+        file: FileId,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Comma<Id> = Id => ActionFn(176);
-        let __sym0 = __pop_Variant3(__symbols);
+        // Cmp = "<" => ActionFn(35);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action176::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
-        (1, 33)
+        let __nt = super::__action35::<>(file, __sym0);
+        __symbols.push((__start, __Symbol::Variant17(__nt), __end));
+        (1, 36)
     }
     pub(crate) fn __reduce64<
-        'input,
     >(
-        input: &'input str,
+        file: FileId,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Comma<Id> =  => ActionFn(177);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
-        let __end = __start.clone();
-        let __nt = super::__action177::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
-        (0, 33)
+        // Cmp = "<=" => ActionFn(36);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action36::<>(file, __sym0);
+        __symbols.push((__start, __Symbol::Variant17(__nt), __end));
+        (1, 36)
     }
     pub(crate) fn __reduce65<
-        'input,
     >(
-        input: &'input str,
+        file: FileId,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Comma<Id> = (<Id> ",")+, Id => ActionFn(178);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant3(__symbols);
-        let __sym0 = __pop_Variant4(__symbols);
+        // Cmp = ">" => ActionFn(37);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action178::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
-        (2, 33)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action37::<>(file, __sym0);
+        __symbols.push((__start, __Symbol::Variant17(__nt), __end));
+        (1, 36)
     }
     pub(crate) fn __reduce66<
-        'input,
     >(
-        input: &'input str,
+        file: FileId,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Comma<Id> = (<Id> ",")+ => ActionFn(179);
-        let __sym0 = __pop_Variant4(__symbols);
+        // Cmp = ">=" => ActionFn(38);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action179::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
-        (1, 33)
+        let __nt = super::__action38::<>(file, __sym0);
+        __symbols.push((__start, __Symbol::Variant17(__nt), __end));
+        (1, 36)
     }
     pub(crate) fn __reduce67<
-        'input,
     >(
-        input: &'input str,
+        file: FileId,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Exp = IF => ActionFn(5);
-        let __sym0 = __pop_Variant1(__symbols);
+        // Cmp = "==" => ActionFn(39);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action5::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 34)
+        let __nt = super::__action39::<>(file, __sym0);
+        __symbols.push((__start, __Symbol::Variant17(__nt), __end));
+        (1, 36)
     }
     pub(crate) fn __reduce68<
-        'input,
     >(
-        input: &'input str,
+        file: FileId,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Exp = LET => ActionFn(6);
-        let __sym0 = __pop_Variant1(__symbols);
+        // Cmp = "!=" => ActionFn(40);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action6::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 34)
+        let __nt = super::__action40::<>(file, __sym0);
+        __symbols.push((__start, __Symbol::Variant17(__nt), __end));
+        (1, 36)
     }
     pub(crate) fn __reduce69<
-        'input,
     >(
-        input: &'input str,
+        file: FileId,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Exp = FUNDEFS => ActionFn(7);
-        let __sym0 = __pop_Variant1(__symbols);
+        // CmpExp = LAssoc<Cmp, Sum> => ActionFn(27);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action7::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 34)
+        let __nt = super::__action27::<>(file, __sym0);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (1, 37)
     }
     pub(crate) fn __reduce70<
-        'input,
     >(
-        input: &'input str,
+        file: FileId,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Exp = SEQ => ActionFn(8);
-        let __sym0 = __pop_Variant1(__symbols);
+        // Comma<Exp> = Exp => ActionFn(228);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action8::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 34)
+        let __nt = super::__action228::<>(file, __sym0);
+        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
+        (1, 38)
     }
     pub(crate) fn __reduce71<
-        'input,
     >(
-        input: &'input str,
+        file: FileId,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Exp = TYPEDECL => ActionFn(9);
-        let __sym0 = __pop_Variant1(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action9::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 34)
+        // Comma<Exp> =  => ActionFn(229);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action229::<>(file, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
+        (0, 38)
     }
     pub(crate) fn __reduce72<
-        'input,
     >(
-        input: &'input str,
+        file: FileId,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Exp = MATCHDECL => ActionFn(10);
-        let __sym0 = __pop_Variant1(__symbols);
+        // Comma<Exp> = (<Exp> ",")+, Exp => ActionFn(230);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant3(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action10::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 34)
+        let __end = __sym1.2.clone();
+        let __nt = super::__action230::<>(file, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
+        (2, 38)
     }
     pub(crate) fn __reduce73<
-        'input,
     >(
-        input: &'input str,
+        file: FileId,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Exp? = Exp => ActionFn(107);
-        let __sym0 = __pop_Variant1(__symbols);
+        // Comma<Exp> = (<Exp> ",")+ => ActionFn(231);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action107::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
-        (1, 35)
+        let __nt = super::__action231::<>(file, __sym0);
+        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
+        (1, 38)
     }
     pub(crate) fn __reduce74<
-        'input,
     >(
-        input: &'input str,
+        file: FileId,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Exp? =  => ActionFn(108);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
-        let __end = __start.clone();
-        let __nt = super::__action108::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
-        (0, 35)
+        // Comma<FieldPattern> = FieldPattern => ActionFn(232);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action232::<>(file, __sym0);
+        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
+        (1, 39)
     }
     pub(crate) fn __reduce75<
-        'input,
     >(
-        input: &'input str,
+        file: FileId,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // FUNDEFS = FunDecl, "in", Boxed<Exp> => ActionFn(159);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant10(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant17(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action159::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (3, 36)
+        // Comma<FieldPattern> =  => ActionFn(233);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action233::<>(file, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
+        (0, 39)
     }
     pub(crate) fn __reduce76<
-        'input,
     >(
-        input: &'input str,
+        file: FileId,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // FUNDEFS = (FunDecl "and")+, FunDecl, "in", Boxed<Exp> => ActionFn(160);
-        assert!(__symbols.len() >= 4);
-        let __sym3 = __pop_Variant10(__symbols);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant17(__symbols);
+        // Comma<FieldPattern> = (<FieldPattern> ",")+, FieldPattern => ActionFn(234);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant5(__symbols);
         let __sym0 = __pop_Variant6(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym3.2.clone();
-        let __nt = super::__action160::<>(input, __sym0, __sym1, __sym2, __sym3);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (4, 36)
+        let __end = __sym1.2.clone();
+        let __nt = super::__action234::<>(file, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
+        (2, 39)
     }
     pub(crate) fn __reduce77<
-        'input,
     >(
-        input: &'input str,
+        file: FileId,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // FunDecl = "def", Id, "(", Params, ")", ":", Exp => ActionFn(161);
-        assert!(__symbols.len() >= 7);
-        let __sym6 = __pop_Variant1(__symbols);
-        let __sym5 = __pop_Variant0(__symbols);
-        let __sym4 = __pop_Variant0(__symbols);
-        let __sym3 = __pop_Variant15(__symbols);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant3(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Comma<FieldPattern> = (<FieldPattern> ",")+ => ActionFn(235);
+        let __sym0 = __pop_Variant6(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym6.2.clone();
-        let __nt = super::__action161::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
-        __symbols.push((__start, __Symbol::Variant17(__nt), __end));
-        (7, 37)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action235::<>(file, __sym0);
+        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
+        (1, 39)
     }
     pub(crate) fn __reduce78<
-        'input,
     >(
-        input: &'input str,
+        file: FileId,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // IF = "if", Boxed<Exp>, ":", Boxed<Exp>, "else", ":", Boxed<Exp> => ActionFn(162);
-        assert!(__symbols.len() >= 7);
-        let __sym6 = __pop_Variant10(__symbols);
-        let __sym5 = __pop_Variant0(__symbols);
-        let __sym4 = __pop_Variant0(__symbols);
-        let __sym3 = __pop_Variant10(__symbols);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant10(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Comma<Id> = Id => ActionFn(236);
+        let __sym0 = __pop_Variant1(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym6.2.clone();
-        let __nt = super::__action162::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (7, 38)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action236::<>(file, __sym0);
+        __symbols.push((__start, __Symbol::Variant20(__nt), __end));
+        (1, 40)
     }
     pub(crate) fn __reduce79<
-        'input,
     >(
-        input: &'input str,
+        file: FileId,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Id = r#"[a-zA-Z][a-zA-Z0-9_]*"# => ActionFn(71);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action71::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (1, 39)
+        // Comma<Id> =  => ActionFn(237);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action237::<>(file, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant20(__nt), __end));
+        (0, 40)
     }
     pub(crate) fn __reduce80<
-        'input,
     >(
-        input: &'input str,
+        file: FileId,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Id? = Id => ActionFn(94);
-        let __sym0 = __pop_Variant3(__symbols);
+        // Comma<Id> = (<Id> ",")+, Id => ActionFn(238);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant1(__symbols);
+        let __sym0 = __pop_Variant7(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action94::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
-        (1, 40)
+        let __end = __sym1.2.clone();
+        let __nt = super::__action238::<>(file, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant20(__nt), __end));
+        (2, 40)
     }
     pub(crate) fn __reduce81<
-        'input,
     >(
-        input: &'input str,
+        file: FileId,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Id? =  => ActionFn(95);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
-        let __end = __start.clone();
-        let __nt = super::__action95::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
-        (0, 40)
+        // Comma<Id> = (<Id> ",")+ => ActionFn(239);
+        let __sym0 = __pop_Variant7(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action239::<>(file, __sym0);
+        __symbols.push((__start, __Symbol::Variant20(__nt), __end));
+        (1, 40)
     }
     pub(crate) fn __reduce82<
-        'input,
     >(
-        input: &'input str,
+        file: FileId,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // LAMBDA = "lambda" => ActionFn(46);
-        let __sym0 = __pop_Variant0(__symbols);
+        // DocComment? = DocComment => ActionFn(101);
+        let __sym0 = __pop_Variant1(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action46::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
+        let __nt = super::__action101::<>(file, __sym0);
+        __symbols.push((__start, __Symbol::Variant21(__nt), __end));
         (1, 41)
     }
     pub(crate) fn __reduce83<
-        'input,
     >(
-        input: &'input str,
+        file: FileId,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // LAMBDA = "λ" => ActionFn(47);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action47::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
-        (1, 41)
+        // DocComment? =  => ActionFn(102);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action102::<>(file, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant21(__nt), __end));
+        (0, 41)
     }
     pub(crate) fn __reduce84<
-        'input,
     >(
-        input: &'input str,
+        file: FileId,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // LAssoc<Cmp, Sum> = Boxed<LAssoc<Cmp, Sum>>, Cmp, Boxed<Sum> => ActionFn(163);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant10(__symbols);
-        let __sym1 = __pop_Variant13(__symbols);
-        let __sym0 = __pop_Variant10(__symbols);
+        // Exp = IF => ActionFn(6);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action163::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (3, 42)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action6::<>(file, __sym0);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (1, 42)
     }
     pub(crate) fn __reduce85<
-        'input,
     >(
-        input: &'input str,
+        file: FileId,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // LAssoc<Cmp, Sum> = Sum => ActionFn(82);
-        let __sym0 = __pop_Variant1(__symbols);
+        // Exp = LET => ActionFn(7);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action82::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
+        let __nt = super::__action7::<>(file, __sym0);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
         (1, 42)
     }
     pub(crate) fn __reduce86<
-        'input,
     >(
-        input: &'input str,
+        file: FileId,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // LAssoc<PlusMinus, Product> = Boxed<LAssoc<PlusMinus, Product>>, PlusMinus, Boxed<Product> => ActionFn(164);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant10(__symbols);
-        let __sym1 = __pop_Variant13(__symbols);
-        let __sym0 = __pop_Variant10(__symbols);
+        // Exp = VAR => ActionFn(8);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action164::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (3, 43)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action8::<>(file, __sym0);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (1, 42)
     }
     pub(crate) fn __reduce87<
-        'input,
     >(
-        input: &'input str,
+        file: FileId,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // LAssoc<PlusMinus, Product> = Product => ActionFn(80);
-        let __sym0 = __pop_Variant1(__symbols);
+        // Exp = FUNDEFS => ActionFn(9);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action80::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 43)
+        let __nt = super::__action9::<>(file, __sym0);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (1, 42)
     }
     pub(crate) fn __reduce88<
-        'input,
     >(
-        input: &'input str,
+        file: FileId,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // LAssoc<Times, NotExp> = Boxed<LAssoc<Times, NotExp>>, Times, Boxed<NotExp> => ActionFn(165);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant10(__symbols);
-        let __sym1 = __pop_Variant13(__symbols);
-        let __sym0 = __pop_Variant10(__symbols);
+        // Exp = SEQ => ActionFn(10);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action165::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (3, 44)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action10::<>(file, __sym0);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (1, 42)
     }
     pub(crate) fn __reduce89<
-        'input,
     >(
-        input: &'input str,
+        file: FileId,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // LAssoc<Times, NotExp> = NotExp => ActionFn(78);
-        let __sym0 = __pop_Variant1(__symbols);
+        // Exp = TYPEDECL => ActionFn(11);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action78::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 44)
+        let __nt = super::__action11::<>(file, __sym0);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (1, 42)
     }
     pub(crate) fn __reduce90<
-        'input,
     >(
-        input: &'input str,
+        file: FileId,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // LET = "let", Bindings, "in", Boxed<Exp> => ActionFn(166);
-        assert!(__symbols.len() >= 4);
-        let __sym3 = __pop_Variant10(__symbols);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant8(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Exp = MATCHDECL => ActionFn(12);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym3.2.clone();
-        let __nt = super::__action166::<>(input, __sym0, __sym1, __sym2, __sym3);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (4, 45)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action12::<>(file, __sym0);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (1, 42)
     }
     pub(crate) fn __reduce91<
-        'input,
     >(
-        input: &'input str,
+        file: FileId,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Log = "&&" => ActionFn(25);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Exp = TRY => ActionFn(13);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action25::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (1, 46)
+        let __nt = super::__action13::<>(file, __sym0);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (1, 42)
     }
     pub(crate) fn __reduce92<
-        'input,
     >(
-        input: &'input str,
+        file: FileId,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Log = "||" => ActionFn(26);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Exp? = Exp => ActionFn(126);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action26::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (1, 46)
+        let __nt = super::__action126::<>(file, __sym0);
+        __symbols.push((__start, __Symbol::Variant22(__nt), __end));
+        (1, 43)
     }
     pub(crate) fn __reduce93<
-        'input,
     >(
-        input: &'input str,
+        file: FileId,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // LogExp = RAssoc<Log, CmpExp> => ActionFn(18);
-        let __sym0 = __pop_Variant1(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action18::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 47)
+        // Exp? =  => ActionFn(127);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action127::<>(file, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant22(__nt), __end));
+        (0, 43)
     }
     pub(crate) fn __reduce94<
-        'input,
     >(
-        input: &'input str,
+        file: FileId,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // MATCHDECL = "match", Boxed<Exp>, "default", Boxed<Exp>, ":", CaseDecls, "end" => ActionFn(167);
-        assert!(__symbols.len() >= 7);
-        let __sym6 = __pop_Variant0(__symbols);
-        let __sym5 = __pop_Variant11(__symbols);
-        let __sym4 = __pop_Variant0(__symbols);
-        let __sym3 = __pop_Variant10(__symbols);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant10(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // FUNDEFS = FunDecl, "in", Boxed<Exp> => ActionFn(206);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant13(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant24(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym6.2.clone();
-        let __nt = super::__action167::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (7, 48)
+        let __end = __sym2.2.clone();
+        let __nt = super::__action206::<>(file, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (3, 44)
     }
     pub(crate) fn __reduce95<
-        'input,
     >(
-        input: &'input str,
+        file: FileId,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // NotExp = "!", Boxed<BaseExp> => ActionFn(168);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant10(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // FUNDEFS = (FunDecl "and")+, FunDecl, "in", Boxed<Exp> => ActionFn(207);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant13(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant24(__symbols);
+        let __sym0 = __pop_Variant9(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action168::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (2, 49)
+        let __end = __sym3.2.clone();
+        let __nt = super::__action207::<>(file, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (4, 44)
     }
     pub(crate) fn __reduce96<
-        'input,
     >(
-        input: &'input str,
+        file: FileId,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // NotExp = ArrExp => ActionFn(34);
+        // FieldPattern = Id => ActionFn(82);
         let __sym0 = __pop_Variant1(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action34::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 49)
+        let __nt = super::__action82::<>(file, __sym0);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (1, 45)
     }
     pub(crate) fn __reduce97<
-        'input,
     >(
-        input: &'input str,
+        file: FileId,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Num = r#"[+-]?[0-9]+"# => ActionFn(70);
-        let __sym0 = __pop_Variant0(__symbols);
+        // FieldPattern = Id, "=", Id => ActionFn(83);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant1(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant1(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action70::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant20(__nt), __end));
-        (1, 50)
+        let __end = __sym2.2.clone();
+        let __nt = super::__action83::<>(file, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (3, 45)
     }
     pub(crate) fn __reduce98<
-        'input,
     >(
-        input: &'input str,
+        file: FileId,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Params = Comma<Id> => ActionFn(3);
-        let __sym0 = __pop_Variant15(__symbols);
+        // FieldPattern? = FieldPattern => ActionFn(131);
+        let __sym0 = __pop_Variant5(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action3::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
-        (1, 51)
+        let __nt = super::__action131::<>(file, __sym0);
+        __symbols.push((__start, __Symbol::Variant23(__nt), __end));
+        (1, 46)
     }
     pub(crate) fn __reduce99<
-        'input,
     >(
-        input: &'input str,
+        file: FileId,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // PlusMinus = "+" => ActionFn(22);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action22::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (1, 52)
+        // FieldPattern? =  => ActionFn(132);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action132::<>(file, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant23(__nt), __end));
+        (0, 46)
     }
     pub(crate) fn __reduce100<
-        'input,
     >(
-        input: &'input str,
+        file: FileId,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // PlusMinus = "-" => ActionFn(23);
-        let __sym0 = __pop_Variant0(__symbols);
+        // FunDecl = DocComment, "def", Id, "(", Params, ")", ":", Exp => ActionFn(224);
+        assert!(__symbols.len() >= 8);
+        let __sym7 = __pop_Variant3(__symbols);
+        let __sym6 = __pop_Variant0(__symbols);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant20(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant1(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant1(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action23::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (1, 52)
+        let __end = __sym7.2.clone();
+        let __nt = super::__action224::<>(file, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6, __sym7);
+        __symbols.push((__start, __Symbol::Variant24(__nt), __end));
+        (8, 47)
     }
     pub(crate) fn __reduce101<
-        'input,
     >(
-        input: &'input str,
+        file: FileId,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Prim1 = "add1" => ActionFn(48);
+        // FunDecl = "def", Id, "(", Params, ")", ":", Exp => ActionFn(225);
+        assert!(__symbols.len() >= 7);
+        let __sym6 = __pop_Variant3(__symbols);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant20(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant1(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action48::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant21(__nt), __end));
-        (1, 53)
+        let __end = __sym6.2.clone();
+        let __nt = super::__action225::<>(file, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
+        __symbols.push((__start, __Symbol::Variant24(__nt), __end));
+        (7, 47)
     }
     pub(crate) fn __reduce102<
-        'input,
     >(
-        input: &'input str,
+        file: FileId,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Prim1 = "sub1" => ActionFn(49);
+        // IF = "if", Boxed<Exp>, ":", Boxed<Exp>, "else", ":", Boxed<Exp> => ActionFn(209);
+        assert!(__symbols.len() >= 7);
+        let __sym6 = __pop_Variant13(__symbols);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant13(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant13(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action49::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant21(__nt), __end));
-        (1, 53)
+        let __end = __sym6.2.clone();
+        let __nt = super::__action209::<>(file, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (7, 48)
     }
     pub(crate) fn __reduce103<
-        'input,
     >(
-        input: &'input str,
+        file: FileId,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Prim1 = "print" => ActionFn(50);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Id? = Id => ActionFn(113);
+        let __sym0 = __pop_Variant1(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action50::<>(input, __sym0);
+        let __nt = super::__action113::<>(file, __sym0);
         __symbols.push((__start, __Symbol::Variant21(__nt), __end));
-        (1, 53)
+        (1, 49)
     }
     pub(crate) fn __reduce104<
-        'input,
     >(
-        input: &'input str,
+        file: FileId,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Prim1 = "isnum" => ActionFn(51);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action51::<>(input, __sym0);
+        // Id? =  => ActionFn(114);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action114::<>(file, &__start, &__end);
         __symbols.push((__start, __Symbol::Variant21(__nt), __end));
-        (1, 53)
+        (0, 49)
     }
     pub(crate) fn __reduce105<
-        'input,
     >(
-        input: &'input str,
+        file: FileId,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Prim1 = "isbool" => ActionFn(52);
-        let __sym0 = __pop_Variant0(__symbols);
+        // LAssoc<Cmp, Sum> = Boxed<LAssoc<Cmp, Sum>>, Cmp, Boxed<Sum> => ActionFn(210);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant13(__symbols);
+        let __sym1 = __pop_Variant17(__symbols);
+        let __sym0 = __pop_Variant13(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action52::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant21(__nt), __end));
-        (1, 53)
+        let __end = __sym2.2.clone();
+        let __nt = super::__action210::<>(file, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (3, 50)
     }
     pub(crate) fn __reduce106<
-        'input,
     >(
-        input: &'input str,
+        file: FileId,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Prim1 = "isarray" => ActionFn(53);
-        let __sym0 = __pop_Variant0(__symbols);
+        // LAssoc<Cmp, Sum> = Sum => ActionFn(96);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action53::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant21(__nt), __end));
-        (1, 53)
+        let __nt = super::__action96::<>(file, __sym0);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (1, 50)
     }
     pub(crate) fn __reduce107<
-        'input,
     >(
-        input: &'input str,
+        file: FileId,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Prim1 = "length" => ActionFn(54);
-        let __sym0 = __pop_Variant0(__symbols);
+        // LAssoc<PlusMinus, Product> = Boxed<LAssoc<PlusMinus, Product>>, PlusMinus, Boxed<Product> => ActionFn(211);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant13(__symbols);
+        let __sym1 = __pop_Variant17(__symbols);
+        let __sym0 = __pop_Variant13(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action54::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant21(__nt), __end));
-        (1, 53)
+        let __end = __sym2.2.clone();
+        let __nt = super::__action211::<>(file, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (3, 51)
     }
     pub(crate) fn __reduce108<
-        'input,
     >(
-        input: &'input str,
+        file: FileId,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Prim1 = "isfun" => ActionFn(55);
-        let __sym0 = __pop_Variant0(__symbols);
+        // LAssoc<PlusMinus, Product> = Product => ActionFn(94);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action55::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant21(__nt), __end));
-        (1, 53)
+        let __nt = super::__action94::<>(file, __sym0);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (1, 51)
     }
     pub(crate) fn __reduce109<
-        'input,
     >(
-        input: &'input str,
+        file: FileId,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Product = LAssoc<Times, NotExp> => ActionFn(21);
-        let __sym0 = __pop_Variant1(__symbols);
+        // LAssoc<Times, NotExp> = Boxed<LAssoc<Times, NotExp>>, Times, Boxed<NotExp> => ActionFn(212);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant13(__symbols);
+        let __sym1 = __pop_Variant17(__symbols);
+        let __sym0 = __pop_Variant13(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action21::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 54)
+        let __end = __sym2.2.clone();
+        let __nt = super::__action212::<>(file, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (3, 52)
     }
     pub(crate) fn __reduce110<
-        'input,
     >(
-        input: &'input str,
+        file: FileId,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Prog = Exp => ActionFn(1);
-        let __sym0 = __pop_Variant1(__symbols);
+        // LAssoc<Times, NotExp> = NotExp => ActionFn(92);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action1::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant22(__nt), __end));
-        (1, 55)
+        let __nt = super::__action92::<>(file, __sym0);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (1, 52)
     }
     pub(crate) fn __reduce111<
-        'input,
     >(
-        input: &'input str,
+        file: FileId,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // RAssoc<Log, CmpExp> = Boxed<CmpExp>, Log, Boxed<RAssoc<Log, CmpExp>> => ActionFn(169);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant10(__symbols);
-        let __sym1 = __pop_Variant13(__symbols);
-        let __sym0 = __pop_Variant10(__symbols);
+        // LET = "let", Bindings, "in", Boxed<Exp> => ActionFn(213);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant13(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant11(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action169::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (3, 56)
+        let __end = __sym3.2.clone();
+        let __nt = super::__action213::<>(file, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (4, 53)
     }
     pub(crate) fn __reduce112<
-        'input,
     >(
-        input: &'input str,
+        file: FileId,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // RAssoc<Log, CmpExp> = CmpExp => ActionFn(84);
-        let __sym0 = __pop_Variant1(__symbols);
+        // Log = "&&" => ActionFn(33);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action84::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 56)
+        let __nt = super::__action33::<>(file, __sym0);
+        __symbols.push((__start, __Symbol::Variant17(__nt), __end));
+        (1, 54)
     }
     pub(crate) fn __reduce113<
-        'input,
     >(
-        input: &'input str,
+        file: FileId,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // SEQ = BinOps, ";", Exp => ActionFn(170);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant1(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant1(__symbols);
+        // Log = "||" => ActionFn(34);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action170::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (3, 57)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action34::<>(file, __sym0);
+        __symbols.push((__start, __Symbol::Variant17(__nt), __end));
+        (1, 54)
     }
     pub(crate) fn __reduce114<
-        'input,
     >(
-        input: &'input str,
+        file: FileId,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // SEQ = BinOps => ActionFn(16);
-        let __sym0 = __pop_Variant1(__symbols);
+        // LogExp = RAssoc<Log, CmpExp> => ActionFn(26);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action16::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 57)
+        let __nt = super::__action26::<>(file, __sym0);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (1, 55)
     }
     pub(crate) fn __reduce115<
-        'input,
     >(
-        input: &'input str,
+        file: FileId,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Sum = LAssoc<PlusMinus, Product> => ActionFn(20);
-        let __sym0 = __pop_Variant1(__symbols);
+        // MATCHDECL = "match", Boxed<Exp>, "default", Boxed<Exp>, ":", CaseDecls, "end" => ActionFn(214);
+        assert!(__symbols.len() >= 7);
+        let __sym6 = __pop_Variant0(__symbols);
+        let __sym5 = __pop_Variant15(__symbols);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant13(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant13(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action20::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 58)
+        let __end = __sym6.2.clone();
+        let __nt = super::__action214::<>(file, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (7, 56)
     }
     pub(crate) fn __reduce116<
-        'input,
     >(
-        input: &'input str,
+        file: FileId,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // TYPEDECL = "type", TypeDefs, "in", Boxed<Exp> => ActionFn(171);
-        assert!(__symbols.len() >= 4);
-        let __sym3 = __pop_Variant10(__symbols);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant23(__symbols);
+        // NotExp = "!", Boxed<BaseExp> => ActionFn(215);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant13(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym3.2.clone();
-        let __nt = super::__action171::<>(input, __sym0, __sym1, __sym2, __sym3);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (4, 59)
+        let __end = __sym1.2.clone();
+        let __nt = super::__action215::<>(file, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (2, 57)
     }
     pub(crate) fn __reduce117<
-        'input,
     >(
-        input: &'input str,
+        file: FileId,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Times = "*" => ActionFn(24);
+        // NotExp = "raise", Boxed<BaseExp> => ActionFn(216);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant13(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action24::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (1, 60)
+        let __end = __sym1.2.clone();
+        let __nt = super::__action216::<>(file, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (2, 57)
     }
     pub(crate) fn __reduce118<
-        'input,
     >(
-        input: &'input str,
+        file: FileId,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // TypeDefs = TypeExp => ActionFn(56);
-        let __sym0 = __pop_Variant24(__symbols);
+        // NotExp = ArrExp => ActionFn(43);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action56::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant23(__nt), __end));
-        (1, 61)
+        let __nt = super::__action43::<>(file, __sym0);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (1, 57)
     }
     pub(crate) fn __reduce119<
-        'input,
     >(
-        input: &'input str,
+        file: FileId,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // TypeDefs = TypeDefs, ",", TypeExp => ActionFn(57);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant24(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant23(__symbols);
+        // Params = Comma<Id> => ActionFn(4);
+        let __sym0 = __pop_Variant20(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action57::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant23(__nt), __end));
-        (3, 61)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action4::<>(file, __sym0);
+        __symbols.push((__start, __Symbol::Variant20(__nt), __end));
+        (1, 58)
     }
     pub(crate) fn __reduce120<
-        'input,
     >(
-        input: &'input str,
+        file: FileId,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // TypeExp = Id, "(", Comma<Id>, ")" => ActionFn(62);
-        assert!(__symbols.len() >= 4);
-        let __sym3 = __pop_Variant0(__symbols);
-        let __sym2 = __pop_Variant15(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant3(__symbols);
+        // PlusMinus = "+" => ActionFn(30);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym3.2.clone();
-        let __nt = super::__action62::<>(input, __sym0, __sym1, __sym2, __sym3);
-        __symbols.push((__start, __Symbol::Variant24(__nt), __end));
-        (4, 62)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action30::<>(file, __sym0);
+        __symbols.push((__start, __Symbol::Variant17(__nt), __end));
+        (1, 59)
     }
     pub(crate) fn __reduce121<
-        'input,
     >(
-        input: &'input str,
+        file: FileId,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // TypeExp = Id => ActionFn(63);
-        let __sym0 = __pop_Variant3(__symbols);
+        // PlusMinus = "-" => ActionFn(31);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action63::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant24(__nt), __end));
-        (1, 62)
+        let __nt = super::__action31::<>(file, __sym0);
+        __symbols.push((__start, __Symbol::Variant17(__nt), __end));
+        (1, 59)
     }
-}
-pub use self::__parse__Prog::ProgParser;
-#[cfg_attr(rustfmt, rustfmt_skip)]
-mod __intern_token {
-    #![allow(unused_imports)]
-    use std::str::FromStr;
-    use crate::syntax::{FunDecl, SurfProg, SurfFunDecl, Exp, Prim1, Prim2, SnakeType};
-    use crate::span::Span1;
-    #[allow(unused_extern_crates)]
-    extern crate lalrpop_util as __lalrpop_util;
-    #[allow(unused_imports)]
-    use self::__lalrpop_util::state_machine as __state_machine;
-    extern crate core;
-    extern crate alloc;
-    pub fn new_builder() -> __lalrpop_util::lexer::MatcherBuilder {
-        let __strs: &[(&str, bool)] = &[
-            ("^([\\+\\-]?[0-9]+)", false),
-            ("^([A-Za-z][0-9A-Z_a-z]*)", false),
-            ("^(!)", false),
-            ("^(!=)", false),
-            ("^(\\&\\&)", false),
-            ("^(\\()", false),
-            ("^(\\))", false),
-            ("^(\\*)", false),
-            ("^(\\+)", false),
-            ("^(,)", false),
-            ("^(\\-)", false),
-            ("^(:)", false),
-            ("^(:=)", false),
-            ("^(;)", false),
-            ("^(<)", false),
-            ("^(<=)", false),
-            ("^(=)", false),
-            ("^(==)", false),
-            ("^(=>)", false),
-            ("^(>)", false),
-            ("^(>=)", false),
-            ("^(\\[)", false),
-            ("^(\\])", false),
-            ("^(add1)", false),
-            ("^(and)", false),
-            ("^(case)", false),
-            ("^(case Array)", false),
-            ("^(case Bool)", false),
-            ("^(case Func)", false),
-            ("^(case Num)", false),
-            ("^(def)", false),
-            ("^(default)", false),
-            ("^(else)", false),
-            ("^(end)", false),
-            ("^(false)", false),
-            ("^(if)", false),
-            ("^(in)", false),
-            ("^(isarray)", false),
-            ("^(isbool)", false),
-            ("^(isfun)", false),
-            ("^(isnum)", false),
-            ("^(lambda)", false),
-            ("^(length)", false),
-            ("^(let)", false),
-            ("^(match)", false),
-            ("^(print)", false),
-            ("^(sub1)", false),
-            ("^(true)", false),
-            ("^(type)", false),
-            ("^(\\|\\|)", false),
-            ("^(λ)", false),
-            (r"^(\s*)", true),
-        ];
-        __lalrpop_util::lexer::MatcherBuilder::new(__strs.iter().copied()).unwrap()
+    pub(crate) fn __reduce122<
+    >(
+        file: FileId,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // Prim1 = "add1" => ActionFn(60);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action60::<>(file, __sym0);
+        __symbols.push((__start, __Symbol::Variant25(__nt), __end));
+        (1, 60)
     }
-}
-pub(crate) use self::__lalrpop_util::lexer::Token;
-
-#[allow(unused_variables)]
-fn __action0<
-    'input,
->(
-    input: &'input str,
-    (_, __0, _): (usize, SurfProg<Span1>, usize),
-) -> SurfProg<Span1>
-{
-    __0
-}
-
-#[allow(unused_variables)]
-fn __action1<
-    'input,
->(
-    input: &'input str,
-    (_, __0, _): (usize, Exp<Span1>, usize),
-) -> SurfProg<Span1>
-{
-    __0
-}
-
-#[allow(unused_variables)]
-fn __action2<
-    'input,
->(
-    input: &'input str,
-    (_, l, _): (usize, usize, usize),
-    (_, funs, _): (usize, alloc::vec::Vec<(SurfFunDecl<Span1>, &'input str)>, usize),
-    (_, last, _): (usize, SurfFunDecl<Span1>, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, body, _): (usize, Box<Exp<Span1>>, usize),
-    (_, r, _): (usize, usize, usize),
-) -> Exp<Span1>
-{
+    pub(crate) fn __reduce123<
+    >(
+        file: FileId,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
     {
-    let mut decls: Vec<SurfFunDecl<Span1>> = funs.into_iter().map(|(decl, _)| decl).collect();
-    decls.push(last);
-    Exp::FunDefs { decls, body, ann: Span1 { start_ix: l, end_ix: r} }
-  }
-}
-
-#[allow(unused_variables)]
-fn __action3<
-    'input,
->(
-    input: &'input str,
-    (_, __0, _): (usize, Vec<String>, usize),
-) -> Vec<String>
-{
-    __0
-}
-
-#[allow(unused_variables)]
-fn __action4<
-    'input,
->(
-    input: &'input str,
-    (_, l, _): (usize, usize, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, name, _): (usize, String, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, parameters, _): (usize, Vec<String>, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, body, _): (usize, Exp<Span1>, usize),
-    (_, r, _): (usize, usize, usize),
-) -> SurfFunDecl<Span1>
-{
-    FunDecl { name, parameters, body, ann: Span1 { start_ix: l, end_ix: r}  }
-}
-
-#[allow(unused_variables)]
-fn __action5<
-    'input,
->(
-    input: &'input str,
-    (_, __0, _): (usize, Exp<Span1>, usize),
-) -> Exp<Span1>
-{
-    __0
-}
-
-#[allow(unused_variables)]
-fn __action6<
-    'input,
->(
-    input: &'input str,
-    (_, __0, _): (usize, Exp<Span1>, usize),
-) -> Exp<Span1>
-{
-    __0
-}
-
-#[allow(unused_variables)]
-fn __action7<
-    'input,
->(
-    input: &'input str,
-    (_, __0, _): (usize, Exp<Span1>, usize),
-) -> Exp<Span1>
-{
-    __0
-}
-
-#[allow(unused_variables)]
-fn __action8<
-    'input,
->(
-    input: &'input str,
-    (_, __0, _): (usize, Exp<Span1>, usize),
-) -> Exp<Span1>
-{
-    __0
-}
-
+        // Prim1 = "sub1" => ActionFn(61);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action61::<>(file, __sym0);
+        __symbols.push((__start, __Symbol::Variant25(__nt), __end));
+        (1, 60)
+    }
+    pub(crate) fn __reduce124<
+    >(
+        file: FileId,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // Prim1 = "print" => ActionFn(62);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action62::<>(file, __sym0);
+        __symbols.push((__start, __Symbol::Variant25(__nt), __end));
+        (1, 60)
+    }
+    pub(crate) fn __reduce125<
+    >(
+        file: FileId,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // Prim1 = "isnum" => ActionFn(63);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action63::<>(file, __sym0);
+        __symbols.push((__start, __Symbol::Variant25(__nt), __end));
+        (1, 60)
+    }
+    pub(crate) fn __reduce126<
+    >(
+        file: FileId,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // Prim1 = "isbool" => ActionFn(64);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action64::<>(file, __sym0);
+        __symbols.push((__start, __Symbol::Variant25(__nt), __end));
+        (1, 60)
+    }
+    pub(crate) fn __reduce127<
+    >(
+        file: FileId,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // Prim1 = "isarray" => ActionFn(65);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action65::<>(file, __sym0);
+        __symbols.push((__start, __Symbol::Variant25(__nt), __end));
+        (1, 60)
+    }
+    pub(crate) fn __reduce128<
+    >(
+        file: FileId,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // Prim1 = "length" => ActionFn(66);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action66::<>(file, __sym0);
+        __symbols.push((__start, __Symbol::Variant25(__nt), __end));
+        (1, 60)
+    }
+    pub(crate) fn __reduce129<
+    >(
+        file: FileId,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // Prim1 = "isfun" => ActionFn(67);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action67::<>(file, __sym0);
+        __symbols.push((__start, __Symbol::Variant25(__nt), __end));
+        (1, 60)
+    }
+    pub(crate) fn __reduce130<
+    >(
+        file: FileId,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // Prim1 = "callcc" => ActionFn(68);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action68::<>(file, __sym0);
+        __symbols.push((__start, __Symbol::Variant25(__nt), __end));
+        (1, 60)
+    }
+    pub(crate) fn __reduce131<
+    >(
+        file: FileId,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // Prim1 = "yield" => ActionFn(69);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action69::<>(file, __sym0);
+        __symbols.push((__start, __Symbol::Variant25(__nt), __end));
+        (1, 60)
+    }
+    pub(crate) fn __reduce132<
+    >(
+        file: FileId,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // Prim1 = "spawn" => ActionFn(70);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action70::<>(file, __sym0);
+        __symbols.push((__start, __Symbol::Variant25(__nt), __end));
+        (1, 60)
+    }
+    pub(crate) fn __reduce133<
+    >(
+        file: FileId,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // Prim1 = "recv" => ActionFn(71);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action71::<>(file, __sym0);
+        __symbols.push((__start, __Symbol::Variant25(__nt), __end));
+        (1, 60)
+    }
+    pub(crate) fn __reduce134<
+    >(
+        file: FileId,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // Product = LAssoc<Times, NotExp> => ActionFn(29);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action29::<>(file, __sym0);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (1, 61)
+    }
+    pub(crate) fn __reduce135<
+    >(
+        file: FileId,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // Prog = "import", Str, Boxed<Prog> => ActionFn(217);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant14(__symbols);
+        let __sym1 = __pop_Variant1(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action217::<>(file, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant26(__nt), __end));
+        (3, 62)
+    }
+    pub(crate) fn __reduce136<
+    >(
+        file: FileId,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // Prog = Exp => ActionFn(2);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action2::<>(file, __sym0);
+        __symbols.push((__start, __Symbol::Variant26(__nt), __end));
+        (1, 62)
+    }
+    pub(crate) fn __reduce137<
+    >(
+        file: FileId,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // RAssoc<Log, CmpExp> = Boxed<CmpExp>, Log, Boxed<RAssoc<Log, CmpExp>> => ActionFn(218);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant13(__symbols);
+        let __sym1 = __pop_Variant17(__symbols);
+        let __sym0 = __pop_Variant13(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action218::<>(file, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (3, 63)
+    }
+    pub(crate) fn __reduce138<
+    >(
+        file: FileId,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // RAssoc<Log, CmpExp> = CmpExp => ActionFn(98);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action98::<>(file, __sym0);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (1, 63)
+    }
+    pub(crate) fn __reduce139<
+    >(
+        file: FileId,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // SEQ = BinOps, ";", Exp => ActionFn(219);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant3(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action219::<>(file, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (3, 64)
+    }
+    pub(crate) fn __reduce140<
+    >(
+        file: FileId,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // SEQ = BinOps => ActionFn(21);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action21::<>(file, __sym0);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (1, 64)
+    }
+    pub(crate) fn __reduce141<
+    >(
+        file: FileId,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // Sum = LAssoc<PlusMinus, Product> => ActionFn(28);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action28::<>(file, __sym0);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (1, 65)
+    }
+    pub(crate) fn __reduce142<
+    >(
+        file: FileId,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // TRY = "try", Boxed<Exp>, "with", CaseDecls, "end" => ActionFn(220);
+        assert!(__symbols.len() >= 5);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant15(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant13(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym4.2.clone();
+        let __nt = super::__action220::<>(file, __sym0, __sym1, __sym2, __sym3, __sym4);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (5, 66)
+    }
+    pub(crate) fn __reduce143<
+    >(
+        file: FileId,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // TYPEDECL = DocComment, "type", TypeDefs, "in", Boxed<Exp> => ActionFn(226);
+        assert!(__symbols.len() >= 5);
+        let __sym4 = __pop_Variant13(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant27(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant1(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym4.2.clone();
+        let __nt = super::__action226::<>(file, __sym0, __sym1, __sym2, __sym3, __sym4);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (5, 67)
+    }
+    pub(crate) fn __reduce144<
+    >(
+        file: FileId,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // TYPEDECL = "type", TypeDefs, "in", Boxed<Exp> => ActionFn(227);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant13(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant27(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym3.2.clone();
+        let __nt = super::__action227::<>(file, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (4, 67)
+    }
+    pub(crate) fn __reduce145<
+    >(
+        file: FileId,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // Times = "*" => ActionFn(32);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action32::<>(file, __sym0);
+        __symbols.push((__start, __Symbol::Variant17(__nt), __end));
+        (1, 68)
+    }
+    pub(crate) fn __reduce146<
+    >(
+        file: FileId,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // TypeDefs = TypeExp => ActionFn(72);
+        let __sym0 = __pop_Variant28(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action72::<>(file, __sym0);
+        __symbols.push((__start, __Symbol::Variant27(__nt), __end));
+        (1, 69)
+    }
+    pub(crate) fn __reduce147<
+    >(
+        file: FileId,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // TypeDefs = TypeDefs, ",", TypeExp => ActionFn(73);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant28(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant27(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action73::<>(file, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant27(__nt), __end));
+        (3, 69)
+    }
+    pub(crate) fn __reduce148<
+    >(
+        file: FileId,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // TypeExp = Id, "(", Comma<Id>, ")" => ActionFn(78);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant20(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant1(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym3.2.clone();
+        let __nt = super::__action78::<>(file, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant28(__nt), __end));
+        (4, 70)
+    }
+    pub(crate) fn __reduce149<
+    >(
+        file: FileId,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // TypeExp = Id => ActionFn(79);
+        let __sym0 = __pop_Variant1(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action79::<>(file, __sym0);
+        __symbols.push((__start, __Symbol::Variant28(__nt), __end));
+        (1, 70)
+    }
+    pub(crate) fn __reduce150<
+    >(
+        file: FileId,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // UpdExp = Boxed<UpdExp>, WithField, Id, "=", Boxed<LogExp> => ActionFn(222);
+        assert!(__symbols.len() >= 5);
+        let __sym4 = __pop_Variant13(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant1(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant13(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym4.2.clone();
+        let __nt = super::__action222::<>(file, __sym0, __sym1, __sym2, __sym3, __sym4);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (5, 71)
+    }
+    pub(crate) fn __reduce151<
+    >(
+        file: FileId,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // UpdExp = LogExp => ActionFn(25);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action25::<>(file, __sym0);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (1, 71)
+    }
+    pub(crate) fn __reduce152<
+    >(
+        file: FileId,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // VAR = "var", Bindings, "in", Boxed<Exp> => ActionFn(223);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant13(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant11(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym3.2.clone();
+        let __nt = super::__action223::<>(file, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (4, 72)
+    }
+}
+pub use self::__parse__Prog::ProgParser;
+
+#[allow(unused_variables)]
+fn __action0<
+>(
+    file: FileId,
+    (_, __0, _): (usize, SurfProg<Span1>, usize),
+) -> SurfProg<Span1>
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action1<
+>(
+    file: FileId,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, Tok, usize),
+    (_, path, _): (usize, String, usize),
+    (_, r, _): (usize, usize, usize),
+    (_, body, _): (usize, Box<SurfProg<Span1>>, usize),
+) -> SurfProg<Span1>
+{
+    Exp::Import { path, module: None, body, ann: Span1 { file, start_ix: l, end_ix: r } }
+}
+
+#[allow(unused_variables)]
+fn __action2<
+>(
+    file: FileId,
+    (_, __0, _): (usize, Exp<Span1>, usize),
+) -> SurfProg<Span1>
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action3<
+>(
+    file: FileId,
+    (_, l, _): (usize, usize, usize),
+    (_, funs, _): (usize, alloc::vec::Vec<(SurfFunDecl<Span1>, Tok)>, usize),
+    (_, last, _): (usize, SurfFunDecl<Span1>, usize),
+    (_, _, _): (usize, Tok, usize),
+    (_, body, _): (usize, Box<Exp<Span1>>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Exp<Span1>
+{
+    {
+    let mut decls: Vec<SurfFunDecl<Span1>> = funs.into_iter().map(|(decl, _)| decl).collect();
+    decls.push(last);
+    Exp::FunDefs { decls, body, ann: Span1 { file, start_ix: l, end_ix: r} }
+  }
+}
+
+#[allow(unused_variables)]
+fn __action4<
+>(
+    file: FileId,
+    (_, __0, _): (usize, Vec<String>, usize),
+) -> Vec<String>
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action5<
+>(
+    file: FileId,
+    (_, doc, _): (usize, core::option::Option<String>, usize),
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, Tok, usize),
+    (_, name, _): (usize, String, usize),
+    (_, _, _): (usize, Tok, usize),
+    (_, parameters, _): (usize, Vec<String>, usize),
+    (_, _, _): (usize, Tok, usize),
+    (_, _, _): (usize, Tok, usize),
+    (_, body, _): (usize, Exp<Span1>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> SurfFunDecl<Span1>
+{
+    FunDecl { name, parameters, body, doc, ann: Span1 { file, start_ix: l, end_ix: r}  }
+}
+
+#[allow(unused_variables)]
+fn __action6<
+>(
+    file: FileId,
+    (_, __0, _): (usize, Exp<Span1>, usize),
+) -> Exp<Span1>
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action7<
+>(
+    file: FileId,
+    (_, __0, _): (usize, Exp<Span1>, usize),
+) -> Exp<Span1>
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action8<
+>(
+    file: FileId,
+    (_, __0, _): (usize, Exp<Span1>, usize),
+) -> Exp<Span1>
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action9<
+>(
+    file: FileId,
+    (_, __0, _): (usize, Exp<Span1>, usize),
+) -> Exp<Span1>
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action10<
+>(
+    file: FileId,
+    (_, __0, _): (usize, Exp<Span1>, usize),
+) -> Exp<Span1>
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action11<
+>(
+    file: FileId,
+    (_, __0, _): (usize, Exp<Span1>, usize),
+) -> Exp<Span1>
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action12<
+>(
+    file: FileId,
+    (_, __0, _): (usize, Exp<Span1>, usize),
+) -> Exp<Span1>
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action13<
+>(
+    file: FileId,
+    (_, __0, _): (usize, Exp<Span1>, usize),
+) -> Exp<Span1>
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action14<
+>(
+    file: FileId,
+    (_, doc, _): (usize, core::option::Option<String>, usize),
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, Tok, usize),
+    (_, decls, _): (usize, Vec<(String, Vec<String>)>, usize),
+    (_, _, _): (usize, Tok, usize),
+    (_, body, _): (usize, Box<Exp<Span1>>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Exp<Span1>
+{
+    Exp::TypeDefs { decls, doc, body, ann: Span1 { file, start_ix: l, end_ix: r } }
+}
+
+#[allow(unused_variables)]
+fn __action15<
+>(
+    file: FileId,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, Tok, usize),
+    (_, expr, _): (usize, Box<Exp<Span1>>, usize),
+    (_, _, _): (usize, Tok, usize),
+    (_, default, _): (usize, Box<Exp<Span1>>, usize),
+    (_, _, _): (usize, Tok, usize),
+    (_, arms, _): (usize, Vec<(SnakeType, Vec<String>, Exp<Span1>)>, usize),
+    (_, _, _): (usize, Tok, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Exp<Span1>
+{
+    Exp::Match { expr, default, arms, ann: Span1 { file, start_ix: l, end_ix: r } }
+}
+
+#[allow(unused_variables)]
+fn __action16<
+>(
+    file: FileId,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, Tok, usize),
+    (_, body, _): (usize, Box<Exp<Span1>>, usize),
+    (_, _, _): (usize, Tok, usize),
+    (_, arms, _): (usize, Vec<(SnakeType, Vec<String>, Exp<Span1>)>, usize),
+    (_, _, _): (usize, Tok, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Exp<Span1>
+{
+    Exp::Try { body, arms, ann: Span1 { file, start_ix: l, end_ix: r } }
+}
+
+#[allow(unused_variables)]
+fn __action17<
+>(
+    file: FileId,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, Tok, usize),
+    (_, cond, _): (usize, Box<Exp<Span1>>, usize),
+    (_, _, _): (usize, Tok, usize),
+    (_, thn, _): (usize, Box<Exp<Span1>>, usize),
+    (_, _, _): (usize, Tok, usize),
+    (_, _, _): (usize, Tok, usize),
+    (_, els, _): (usize, Box<Exp<Span1>>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Exp<Span1>
+{
+    Exp::If { cond, thn, els, ann: Span1 { file, start_ix: l, end_ix: r }}
+}
+
+#[allow(unused_variables)]
+fn __action18<
+>(
+    file: FileId,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, Tok, usize),
+    (_, bindings, _): (usize, Vec<(String, Exp<Span1>)>, usize),
+    (_, _, _): (usize, Tok, usize),
+    (_, body, _): (usize, Box<Exp<Span1>>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Exp<Span1>
+{
+    Exp::Let { bindings, body, ann: Span1 { file, start_ix: l, end_ix: r }}
+}
+
 #[allow(unused_variables)]
-fn __action9<
-    'input,
+fn __action19<
+>(
+    file: FileId,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, Tok, usize),
+    (_, bindings, _): (usize, Vec<(String, Exp<Span1>)>, usize),
+    (_, _, _): (usize, Tok, usize),
+    (_, body, _): (usize, Box<Exp<Span1>>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Exp<Span1>
+{
+    Exp::LetVar { bindings, body, ann: Span1 { file, start_ix: l, end_ix: r }}
+}
+
+#[allow(unused_variables)]
+fn __action20<
+>(
+    file: FileId,
+    (_, l, _): (usize, usize, usize),
+    (_, e1, _): (usize, Exp<Span1>, usize),
+    (_, _, _): (usize, Tok, usize),
+    (_, e2, _): (usize, Exp<Span1>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Exp<Span1>
+{
+    Exp::Semicolon { e1: Box::new(e1), e2: Box::new(e2), ann: Span1 { file, start_ix: l, end_ix: r } }
+}
+
+#[allow(unused_variables)]
+fn __action21<
+>(
+    file: FileId,
+    (_, __0, _): (usize, Exp<Span1>, usize),
+) -> Exp<Span1>
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action22<
+>(
+    file: FileId,
+    (_, l, _): (usize, usize, usize),
+    (_, var, _): (usize, String, usize),
+    (_, _, _): (usize, Tok, usize),
+    (_, new_value, _): (usize, Box<Exp<Span1>>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Exp<Span1>
+{
+    Exp::Assign { var, new_value, ann: Span1 { file, start_ix: l, end_ix: r } }
+}
+
+#[allow(unused_variables)]
+fn __action23<
+>(
+    file: FileId,
+    (_, __0, _): (usize, Exp<Span1>, usize),
+) -> Exp<Span1>
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action24<
+>(
+    file: FileId,
+    (_, l, _): (usize, usize, usize),
+    (_, exp, _): (usize, Box<Exp<Span1>>, usize),
+    (_, _, _): (usize, Tok, usize),
+    (_, field, _): (usize, String, usize),
+    (_, _, _): (usize, Tok, usize),
+    (_, new_value, _): (usize, Box<Exp<Span1>>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Exp<Span1>
+{
+    Exp::UpdateField { exp, field, new_value, ann: Span1 { file, start_ix: l, end_ix: r } }
+}
+
+#[allow(unused_variables)]
+fn __action25<
+>(
+    file: FileId,
+    (_, __0, _): (usize, Exp<Span1>, usize),
+) -> Exp<Span1>
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action26<
+>(
+    file: FileId,
+    (_, __0, _): (usize, Exp<Span1>, usize),
+) -> Exp<Span1>
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action27<
+>(
+    file: FileId,
+    (_, __0, _): (usize, Exp<Span1>, usize),
+) -> Exp<Span1>
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action28<
+>(
+    file: FileId,
+    (_, __0, _): (usize, Exp<Span1>, usize),
+) -> Exp<Span1>
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action29<
+>(
+    file: FileId,
+    (_, __0, _): (usize, Exp<Span1>, usize),
+) -> Exp<Span1>
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action30<
+>(
+    file: FileId,
+    (_, __0, _): (usize, Tok, usize),
+) -> Prim2
+{
+    Prim2::Add
+}
+
+#[allow(unused_variables)]
+fn __action31<
+>(
+    file: FileId,
+    (_, __0, _): (usize, Tok, usize),
+) -> Prim2
+{
+    Prim2::Sub
+}
+
+#[allow(unused_variables)]
+fn __action32<
+>(
+    file: FileId,
+    (_, __0, _): (usize, Tok, usize),
+) -> Prim2
+{
+    Prim2::Mul
+}
+
+#[allow(unused_variables)]
+fn __action33<
+>(
+    file: FileId,
+    (_, __0, _): (usize, Tok, usize),
+) -> Prim2
+{
+    Prim2::And
+}
+
+#[allow(unused_variables)]
+fn __action34<
+>(
+    file: FileId,
+    (_, __0, _): (usize, Tok, usize),
+) -> Prim2
+{
+    Prim2::Or
+}
+
+#[allow(unused_variables)]
+fn __action35<
+>(
+    file: FileId,
+    (_, __0, _): (usize, Tok, usize),
+) -> Prim2
+{
+    Prim2::Lt
+}
+
+#[allow(unused_variables)]
+fn __action36<
+>(
+    file: FileId,
+    (_, __0, _): (usize, Tok, usize),
+) -> Prim2
+{
+    Prim2::Le
+}
+
+#[allow(unused_variables)]
+fn __action37<
+>(
+    file: FileId,
+    (_, __0, _): (usize, Tok, usize),
+) -> Prim2
+{
+    Prim2::Gt
+}
+
+#[allow(unused_variables)]
+fn __action38<
+>(
+    file: FileId,
+    (_, __0, _): (usize, Tok, usize),
+) -> Prim2
+{
+    Prim2::Ge
+}
+
+#[allow(unused_variables)]
+fn __action39<
+>(
+    file: FileId,
+    (_, __0, _): (usize, Tok, usize),
+) -> Prim2
+{
+    Prim2::Eq
+}
+
+#[allow(unused_variables)]
+fn __action40<
+>(
+    file: FileId,
+    (_, __0, _): (usize, Tok, usize),
+) -> Prim2
+{
+    Prim2::Neq
+}
+
+#[allow(unused_variables)]
+fn __action41<
+>(
+    file: FileId,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, Tok, usize),
+    (_, e, _): (usize, Box<Exp<Span1>>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Exp<Span1>
+{
+    Exp::Prim1(Prim1::Not, e, Span1 { file, start_ix: l, end_ix: r })
+}
+
+#[allow(unused_variables)]
+fn __action42<
+>(
+    file: FileId,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, Tok, usize),
+    (_, e, _): (usize, Box<Exp<Span1>>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Exp<Span1>
+{
+    Exp::Raise(e, Span1 { file, start_ix: l, end_ix: r })
+}
+
+#[allow(unused_variables)]
+fn __action43<
+>(
+    file: FileId,
+    (_, __0, _): (usize, Exp<Span1>, usize),
+) -> Exp<Span1>
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action44<
+>(
+    file: FileId,
+    (_, __0, _): (usize, Exp<Span1>, usize),
+) -> Exp<Span1>
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action45<
+>(
+    file: FileId,
+    (_, l, _): (usize, usize, usize),
+    (_, a, _): (usize, Box<Exp<Span1>>, usize),
+    (_, _, _): (usize, Tok, usize),
+    (_, e, _): (usize, Box<Exp<Span1>>, usize),
+    (_, _, _): (usize, Tok, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Exp<Span1>
+{
+    Exp::Prim2(Prim2::ArrayGet, a, e, Span1 { file, start_ix: l, end_ix: r })
+}
+
+#[allow(unused_variables)]
+fn __action46<
+>(
+    file: FileId,
+    (_, l, _): (usize, usize, usize),
+    (_, exp, _): (usize, Box<Exp<Span1>>, usize),
+    (_, _, _): (usize, Tok, usize),
+    (_, field, _): (usize, String, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Exp<Span1>
+{
+    Exp::GetField { exp, field, ann: Span1 { file, start_ix: l, end_ix: r } }
+}
+
+#[allow(unused_variables)]
+fn __action47<
 >(
-    input: &'input str,
-    (_, __0, _): (usize, Exp<Span1>, usize),
+    file: FileId,
+    (_, l, _): (usize, usize, usize),
+    (_, array, _): (usize, Box<Exp<Span1>>, usize),
+    (_, _, _): (usize, Tok, usize),
+    (_, index, _): (usize, Box<Exp<Span1>>, usize),
+    (_, _, _): (usize, Tok, usize),
+    (_, _, _): (usize, Tok, usize),
+    (_, new_value, _): (usize, Box<Exp<Span1>>, usize),
+    (_, r, _): (usize, usize, usize),
 ) -> Exp<Span1>
 {
-    __0
+    Exp::ArraySet{ ann: Span1 { file, start_ix: l, end_ix: r }, array, index, new_value }
 }
 
 #[allow(unused_variables)]
-fn __action10<
-    'input,
+fn __action48<
 >(
-    input: &'input str,
-    (_, __0, _): (usize, Exp<Span1>, usize),
+    file: FileId,
+    (_, l, _): (usize, usize, usize),
+    (_, x, _): (usize, String, usize),
+    (_, r, _): (usize, usize, usize),
 ) -> Exp<Span1>
 {
-    __0
+    Exp::Var(x, Span1 { file, start_ix: l, end_ix: r })
 }
 
 #[allow(unused_variables)]
-fn __action11<
-    'input,
+fn __action49<
 >(
-    input: &'input str,
+    file: FileId,
     (_, l, _): (usize, usize, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, decls, _): (usize, Vec<(String, Vec<String>)>, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, body, _): (usize, Box<Exp<Span1>>, usize),
+    (_, b, _): (usize, bool, usize),
     (_, r, _): (usize, usize, usize),
 ) -> Exp<Span1>
 {
-    Exp::TypeDefs { decls, body, ann: Span1 { start_ix: l, end_ix: r } }
+    Exp::Bool(b, Span1 { file, start_ix: l, end_ix: r })
 }
 
 #[allow(unused_variables)]
-fn __action12<
-    'input,
+fn __action50<
 >(
-    input: &'input str,
+    file: FileId,
     (_, l, _): (usize, usize, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, expr, _): (usize, Box<Exp<Span1>>, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, default, _): (usize, Box<Exp<Span1>>, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, arms, _): (usize, Vec<(SnakeType, Vec<String>, Exp<Span1>)>, usize),
-    (_, _, _): (usize, &'input str, usize),
+    (_, n, _): (usize, i64, usize),
     (_, r, _): (usize, usize, usize),
 ) -> Exp<Span1>
 {
-    Exp::Match { expr, default, arms, ann: Span1 { start_ix: l, end_ix: r } }
+    Exp::Num(n, Span1 { file, start_ix: l, end_ix: r })
 }
 
 #[allow(unused_variables)]
-fn __action13<
-    'input,
+fn __action51<
 >(
-    input: &'input str,
+    file: FileId,
     (_, l, _): (usize, usize, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, cond, _): (usize, Box<Exp<Span1>>, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, thn, _): (usize, Box<Exp<Span1>>, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, els, _): (usize, Box<Exp<Span1>>, usize),
+    (_, p, _): (usize, Prim1, usize),
+    (_, _, _): (usize, Tok, usize),
+    (_, e, _): (usize, Box<Exp<Span1>>, usize),
+    (_, _, _): (usize, Tok, usize),
     (_, r, _): (usize, usize, usize),
 ) -> Exp<Span1>
 {
-    Exp::If { cond, thn, els, ann: Span1 { start_ix: l, end_ix: r }}
+    Exp::Prim1(p, e, Span1 { file, start_ix: l, end_ix: r })
 }
 
 #[allow(unused_variables)]
-fn __action14<
-    'input,
+fn __action52<
 >(
-    input: &'input str,
+    file: FileId,
     (_, l, _): (usize, usize, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, bindings, _): (usize, Vec<(String, Exp<Span1>)>, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, body, _): (usize, Box<Exp<Span1>>, usize),
+    (_, _, _): (usize, Tok, usize),
+    (_, _, _): (usize, Tok, usize),
+    (_, _, _): (usize, Tok, usize),
     (_, r, _): (usize, usize, usize),
 ) -> Exp<Span1>
 {
-    Exp::Let { bindings, body, ann: Span1 { start_ix: l, end_ix: r }}
+    Exp::Prim0(Prim0::Chan, Span1 { file, start_ix: l, end_ix: r })
 }
 
 #[allow(unused_variables)]
-fn __action15<
-    'input,
+fn __action53<
 >(
-    input: &'input str,
+    file: FileId,
     (_, l, _): (usize, usize, usize),
-    (_, e1, _): (usize, Exp<Span1>, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, e2, _): (usize, Exp<Span1>, usize),
+    (_, _, _): (usize, Tok, usize),
+    (_, _, _): (usize, Tok, usize),
+    (_, c, _): (usize, Box<Exp<Span1>>, usize),
+    (_, _, _): (usize, Tok, usize),
+    (_, v, _): (usize, Box<Exp<Span1>>, usize),
+    (_, _, _): (usize, Tok, usize),
     (_, r, _): (usize, usize, usize),
 ) -> Exp<Span1>
 {
-    Exp::Semicolon { e1: Box::new(e1), e2: Box::new(e2), ann: Span1 { start_ix: l, end_ix: r } }
+    Exp::Prim2(Prim2::Send, c, v, Span1 { file, start_ix: l, end_ix: r })
 }
 
 #[allow(unused_variables)]
-fn __action16<
-    'input,
+fn __action54<
 >(
-    input: &'input str,
-    (_, __0, _): (usize, Exp<Span1>, usize),
+    file: FileId,
+    (_, _, _): (usize, Tok, usize),
+    (_, e, _): (usize, Exp<Span1>, usize),
+    (_, _, _): (usize, Tok, usize),
 ) -> Exp<Span1>
 {
-    __0
+    e
 }
 
 #[allow(unused_variables)]
-fn __action17<
-    'input,
+fn __action55<
 >(
-    input: &'input str,
-    (_, __0, _): (usize, Exp<Span1>, usize),
+    file: FileId,
+    (_, l, _): (usize, usize, usize),
+    (_, x, _): (usize, Box<Exp<Span1>>, usize),
+    (_, _, _): (usize, Tok, usize),
+    (_, es, _): (usize, Vec<Exp<Span1>>, usize),
+    (_, _, _): (usize, Tok, usize),
+    (_, r, _): (usize, usize, usize),
 ) -> Exp<Span1>
 {
-    __0
+    Exp::Call(x, es, Span1 { file, start_ix: l, end_ix: r })
 }
 
 #[allow(unused_variables)]
-fn __action18<
-    'input,
+fn __action56<
 >(
-    input: &'input str,
-    (_, __0, _): (usize, Exp<Span1>, usize),
+    file: FileId,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, Tok, usize),
+    (_, parameters, _): (usize, Vec<String>, usize),
+    (_, _, _): (usize, Tok, usize),
+    (_, body, _): (usize, Box<Exp<Span1>>, usize),
+    (_, _, _): (usize, Tok, usize),
+    (_, r, _): (usize, usize, usize),
 ) -> Exp<Span1>
 {
-    __0
+    Exp::Lambda { parameters, body, ann: Span1 { file, start_ix: l, end_ix: r } }
 }
 
 #[allow(unused_variables)]
-fn __action19<
-    'input,
+fn __action57<
 >(
-    input: &'input str,
-    (_, __0, _): (usize, Exp<Span1>, usize),
+    file: FileId,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, Tok, usize),
+    (_, cond, _): (usize, Box<Exp<Span1>>, usize),
+    (_, _, _): (usize, Tok, usize),
+    (_, body, _): (usize, Box<Exp<Span1>>, usize),
+    (_, _, _): (usize, Tok, usize),
+    (_, r, _): (usize, usize, usize),
 ) -> Exp<Span1>
 {
-    __0
+    Exp::While { cond, body, ann: Span1 { file, start_ix: l, end_ix: r } }
 }
 
 #[allow(unused_variables)]
-fn __action20<
-    'input,
+fn __action58<
 >(
-    input: &'input str,
-    (_, __0, _): (usize, Exp<Span1>, usize),
+    file: FileId,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, Tok, usize),
+    (_, var, _): (usize, String, usize),
+    (_, _, _): (usize, Tok, usize),
+    (_, start, _): (usize, Box<Exp<Span1>>, usize),
+    (_, _, _): (usize, Tok, usize),
+    (_, end, _): (usize, Box<Exp<Span1>>, usize),
+    (_, _, _): (usize, Tok, usize),
+    (_, body, _): (usize, Box<Exp<Span1>>, usize),
+    (_, _, _): (usize, Tok, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Exp<Span1>
+{
+    Exp::For { var, start, end, body, ann: Span1 { file, start_ix: l, end_ix: r } }
+}
+
+#[allow(unused_variables)]
+fn __action59<
+>(
+    file: FileId,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, Tok, usize),
+    (_, es, _): (usize, Vec<Exp<Span1>>, usize),
+    (_, _, _): (usize, Tok, usize),
+    (_, r, _): (usize, usize, usize),
 ) -> Exp<Span1>
 {
-    __0
+    Exp::Array(es, Span1 { file, start_ix: l, end_ix: r })
+}
+
+#[allow(unused_variables)]
+fn __action60<
+>(
+    file: FileId,
+    (_, __0, _): (usize, Tok, usize),
+) -> Prim1
+{
+    Prim1::Add1
+}
+
+#[allow(unused_variables)]
+fn __action61<
+>(
+    file: FileId,
+    (_, __0, _): (usize, Tok, usize),
+) -> Prim1
+{
+    Prim1::Sub1
+}
+
+#[allow(unused_variables)]
+fn __action62<
+>(
+    file: FileId,
+    (_, __0, _): (usize, Tok, usize),
+) -> Prim1
+{
+    Prim1::Print
+}
+
+#[allow(unused_variables)]
+fn __action63<
+>(
+    file: FileId,
+    (_, __0, _): (usize, Tok, usize),
+) -> Prim1
+{
+    Prim1::IsNum
+}
+
+#[allow(unused_variables)]
+fn __action64<
+>(
+    file: FileId,
+    (_, __0, _): (usize, Tok, usize),
+) -> Prim1
+{
+    Prim1::IsBool
+}
+
+#[allow(unused_variables)]
+fn __action65<
+>(
+    file: FileId,
+    (_, __0, _): (usize, Tok, usize),
+) -> Prim1
+{
+    Prim1::IsArray
+}
+
+#[allow(unused_variables)]
+fn __action66<
+>(
+    file: FileId,
+    (_, __0, _): (usize, Tok, usize),
+) -> Prim1
+{
+    Prim1::Length
+}
+
+#[allow(unused_variables)]
+fn __action67<
+>(
+    file: FileId,
+    (_, __0, _): (usize, Tok, usize),
+) -> Prim1
+{
+    Prim1::IsFun
+}
+
+#[allow(unused_variables)]
+fn __action68<
+>(
+    file: FileId,
+    (_, __0, _): (usize, Tok, usize),
+) -> Prim1
+{
+    Prim1::CallCC
+}
+
+#[allow(unused_variables)]
+fn __action69<
+>(
+    file: FileId,
+    (_, __0, _): (usize, Tok, usize),
+) -> Prim1
+{
+    Prim1::Yield
+}
+
+#[allow(unused_variables)]
+fn __action70<
+>(
+    file: FileId,
+    (_, __0, _): (usize, Tok, usize),
+) -> Prim1
+{
+    Prim1::Spawn
+}
+
+#[allow(unused_variables)]
+fn __action71<
+>(
+    file: FileId,
+    (_, __0, _): (usize, Tok, usize),
+) -> Prim1
+{
+    Prim1::Recv
+}
+
+#[allow(unused_variables)]
+fn __action72<
+>(
+    file: FileId,
+    (_, type_exp, _): (usize, (String, Vec<String>), usize),
+) -> Vec<(String, Vec<String>)>
+{
+    vec![type_exp]
+}
+
+#[allow(unused_variables)]
+fn __action73<
+>(
+    file: FileId,
+    (_, mut type_exps, _): (usize, Vec<(String, Vec<String>)>, usize),
+    (_, _, _): (usize, Tok, usize),
+    (_, type_exp, _): (usize, (String, Vec<String>), usize),
+) -> Vec<(String, Vec<String>)>
+{
+    {
+    type_exps.push(type_exp);
+    type_exps
+  }
+}
+
+#[allow(unused_variables)]
+fn __action74<
+>(
+    file: FileId,
+    (_, case, _): (usize, (SnakeType, Vec<String>, Exp<Span1>), usize),
+) -> Vec<(SnakeType, Vec<String>, Exp<Span1>)>
+{
+    vec![case]
+}
+
+#[allow(unused_variables)]
+fn __action75<
+>(
+    file: FileId,
+    (_, mut cases, _): (usize, Vec<(SnakeType, Vec<String>, Exp<Span1>)>, usize),
+    (_, _, _): (usize, Tok, usize),
+    (_, case, _): (usize, (SnakeType, Vec<String>, Exp<Span1>), usize),
+) -> Vec<(SnakeType, Vec<String>, Exp<Span1>)>
+{
+    {
+    cases.push(case);
+    cases
+  }
+}
+
+#[allow(unused_variables)]
+fn __action76<
+>(
+    file: FileId,
+    (_, binding, _): (usize, String, usize),
+    (_, _, _): (usize, Tok, usize),
+    (_, exp, _): (usize, Exp<Span1>, usize),
+) -> Vec<(String, Exp<Span1>)>
+{
+    vec![(binding, exp)]
+}
+
+#[allow(unused_variables)]
+fn __action77<
+>(
+    file: FileId,
+    (_, mut bindings, _): (usize, Vec<(String, Exp<Span1>)>, usize),
+    (_, _, _): (usize, Tok, usize),
+    (_, binding, _): (usize, String, usize),
+    (_, _, _): (usize, Tok, usize),
+    (_, exp, _): (usize, Exp<Span1>, usize),
+) -> Vec<(String, Exp<Span1>)>
+{
+    {
+    bindings.push((binding, exp));
+    bindings
+  }
+}
+
+#[allow(unused_variables)]
+fn __action78<
+>(
+    file: FileId,
+    (_, id, _): (usize, String, usize),
+    (_, _, _): (usize, Tok, usize),
+    (_, ids, _): (usize, Vec<String>, usize),
+    (_, _, _): (usize, Tok, usize),
+) -> (String, Vec<String>)
+{
+    (id, ids)
 }
 
 #[allow(unused_variables)]
-fn __action21<
-    'input,
+fn __action79<
 >(
-    input: &'input str,
-    (_, __0, _): (usize, Exp<Span1>, usize),
-) -> Exp<Span1>
+    file: FileId,
+    (_, id, _): (usize, String, usize),
+) -> (String, Vec<String>)
 {
-    __0
+    (id, vec![])
 }
 
 #[allow(unused_variables)]
-fn __action22<
-    'input,
+fn __action80<
 >(
-    input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> Prim2
+    file: FileId,
+    (_, _, _): (usize, Tok, usize),
+    (_, type_match, _): (usize, (String, Vec<String>), usize),
+    (_, _, _): (usize, Tok, usize),
+    (_, exp, _): (usize, Exp<Span1>, usize),
+) -> (SnakeType, Vec<String>, Exp<Span1>)
 {
-    Prim2::Add
+    {
+    let (name, fields) = type_match;
+    let snake_type = match (name.as_str(), fields.len()) {
+      ("Num", 1) => SnakeType::Num,
+      ("Func", 1) => SnakeType::Func,
+      ("Bool", 1) => SnakeType::Bool,
+      ("Array", 1) => SnakeType::Array,
+      _ => SnakeType::Custom(name),
+    };
+    (snake_type, fields, exp)
+  }
 }
 
 #[allow(unused_variables)]
-fn __action23<
-    'input,
+fn __action81<
 >(
-    input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> Prim2
+    file: FileId,
+    (_, _, _): (usize, Tok, usize),
+    (_, name, _): (usize, String, usize),
+    (_, _, _): (usize, Tok, usize),
+    (_, fields, _): (usize, Vec<(String, String)>, usize),
+    (_, _, _): (usize, Tok, usize),
+    (_, _, _): (usize, Tok, usize),
+    (_, exp, _): (usize, Exp<Span1>, usize),
+) -> (SnakeType, Vec<String>, Exp<Span1>)
 {
-    Prim2::Sub
+    {
+    let (fields, vars) = fields.into_iter().unzip();
+    (SnakeType::CustomFields(name, fields), vars, exp)
+  }
 }
 
 #[allow(unused_variables)]
-fn __action24<
-    'input,
+fn __action82<
 >(
-    input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> Prim2
+    file: FileId,
+    (_, field, _): (usize, String, usize),
+) -> (String, String)
 {
-    Prim2::Mul
+    (field.clone(), field)
 }
 
 #[allow(unused_variables)]
-fn __action25<
-    'input,
+fn __action83<
 >(
-    input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> Prim2
+    file: FileId,
+    (_, field, _): (usize, String, usize),
+    (_, _, _): (usize, Tok, usize),
+    (_, var, _): (usize, String, usize),
+) -> (String, String)
 {
-    Prim2::And
+    (field, var)
 }
 
 #[allow(unused_variables)]
-fn __action26<
-    'input,
+fn __action84<
 >(
-    input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> Prim2
+    file: FileId,
+    (_, __0, _): (usize, String, usize),
+) -> String
 {
-    Prim2::Or
+    __0
 }
 
 #[allow(unused_variables)]
-fn __action27<
-    'input,
+fn __action85<
 >(
-    input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> Prim2
+    file: FileId,
+    (_, __0, _): (usize, Tok, usize),
+) -> bool
 {
-    Prim2::Lt
+    true
 }
 
 #[allow(unused_variables)]
-fn __action28<
-    'input,
+fn __action86<
 >(
-    input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> Prim2
+    file: FileId,
+    (_, __0, _): (usize, Tok, usize),
+) -> bool
 {
-    Prim2::Le
+    false
 }
 
 #[allow(unused_variables)]
-fn __action29<
-    'input,
+fn __action87<
 >(
-    input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> Prim2
+    file: FileId,
+    (_, mut v, _): (usize, alloc::vec::Vec<(String, String)>, usize),
+    (_, last, _): (usize, core::option::Option<(String, String)>, usize),
+) -> Vec<(String, String)>
 {
-    Prim2::Gt
+    {
+    match last {
+      None => { },
+      Some(t) => { v.push(t); }
+    };
+    v
+  }
 }
 
 #[allow(unused_variables)]
-fn __action30<
-    'input,
+fn __action88<
 >(
-    input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> Prim2
+    file: FileId,
+    (_, mut v, _): (usize, alloc::vec::Vec<Exp<Span1>>, usize),
+    (_, last, _): (usize, core::option::Option<Exp<Span1>>, usize),
+) -> Vec<Exp<Span1>>
 {
-    Prim2::Ge
+    {
+    match last {
+      None => { },
+      Some(t) => { v.push(t); }
+    };
+    v
+  }
 }
 
 #[allow(unused_variables)]
-fn __action31<
-    'input,
+fn __action89<
 >(
-    input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> Prim2
+    file: FileId,
+    (_, t, _): (usize, Exp<Span1>, usize),
+) -> Box<Exp<Span1>>
 {
-    Prim2::Eq
+    Box::new(t)
 }
 
 #[allow(unused_variables)]
-fn __action32<
-    'input,
+fn __action90<
 >(
-    input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> Prim2
+    file: FileId,
+    (_, t, _): (usize, Exp<Span1>, usize),
+) -> Box<Exp<Span1>>
 {
-    Prim2::Neq
+    Box::new(t)
 }
 
 #[allow(unused_variables)]
-fn __action33<
-    'input,
+fn __action91<
 >(
-    input: &'input str,
+    file: FileId,
     (_, l, _): (usize, usize, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, e, _): (usize, Box<Exp<Span1>>, usize),
+    (_, e1, _): (usize, Box<Exp<Span1>>, usize),
+    (_, op, _): (usize, Prim2, usize),
+    (_, e2, _): (usize, Box<Exp<Span1>>, usize),
     (_, r, _): (usize, usize, usize),
 ) -> Exp<Span1>
 {
-    Exp::Prim1(Prim1::Not, e, Span1 { start_ix: l, end_ix: r })
+    Exp::Prim2(op, e1, e2, Span1 { file, start_ix: l, end_ix: r })
 }
 
 #[allow(unused_variables)]
-fn __action34<
-    'input,
+fn __action92<
 >(
-    input: &'input str,
+    file: FileId,
     (_, __0, _): (usize, Exp<Span1>, usize),
 ) -> Exp<Span1>
 {
@@ -4634,10 +6089,23 @@ fn __action34<
 }
 
 #[allow(unused_variables)]
-fn __action35<
-    'input,
+fn __action93<
+>(
+    file: FileId,
+    (_, l, _): (usize, usize, usize),
+    (_, e1, _): (usize, Box<Exp<Span1>>, usize),
+    (_, op, _): (usize, Prim2, usize),
+    (_, e2, _): (usize, Box<Exp<Span1>>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Exp<Span1>
+{
+    Exp::Prim2(op, e1, e2, Span1 { file, start_ix: l, end_ix: r })
+}
+
+#[allow(unused_variables)]
+fn __action94<
 >(
-    input: &'input str,
+    file: FileId,
     (_, __0, _): (usize, Exp<Span1>, usize),
 ) -> Exp<Span1>
 {
@@ -4645,1304 +6113,1773 @@ fn __action35<
 }
 
 #[allow(unused_variables)]
-fn __action36<
-    'input,
+fn __action95<
 >(
-    input: &'input str,
+    file: FileId,
     (_, l, _): (usize, usize, usize),
-    (_, a, _): (usize, Box<Exp<Span1>>, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, e, _): (usize, Box<Exp<Span1>>, usize),
-    (_, _, _): (usize, &'input str, usize),
+    (_, e1, _): (usize, Box<Exp<Span1>>, usize),
+    (_, op, _): (usize, Prim2, usize),
+    (_, e2, _): (usize, Box<Exp<Span1>>, usize),
     (_, r, _): (usize, usize, usize),
 ) -> Exp<Span1>
 {
-    Exp::Prim2(Prim2::ArrayGet, a, e, Span1 { start_ix: l, end_ix: r })
+    Exp::Prim2(op, e1, e2, Span1 { file, start_ix: l, end_ix: r })
 }
 
 #[allow(unused_variables)]
-fn __action37<
-    'input,
+fn __action96<
 >(
-    input: &'input str,
-    (_, l, _): (usize, usize, usize),
-    (_, array, _): (usize, Box<Exp<Span1>>, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, index, _): (usize, Box<Exp<Span1>>, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, new_value, _): (usize, Box<Exp<Span1>>, usize),
-    (_, r, _): (usize, usize, usize),
+    file: FileId,
+    (_, __0, _): (usize, Exp<Span1>, usize),
 ) -> Exp<Span1>
 {
-    Exp::ArraySet{ ann: Span1 { start_ix: l, end_ix: r }, array, index, new_value }
+    __0
 }
 
 #[allow(unused_variables)]
-fn __action38<
-    'input,
+fn __action97<
 >(
-    input: &'input str,
+    file: FileId,
     (_, l, _): (usize, usize, usize),
-    (_, x, _): (usize, String, usize),
+    (_, e1, _): (usize, Box<Exp<Span1>>, usize),
+    (_, op, _): (usize, Prim2, usize),
+    (_, e2, _): (usize, Box<Exp<Span1>>, usize),
     (_, r, _): (usize, usize, usize),
 ) -> Exp<Span1>
 {
-    Exp::Var(x, Span1 { start_ix: l, end_ix: r })
+    Exp::Prim2(op, e1, e2, Span1 { file, start_ix: l, end_ix: r })
 }
 
 #[allow(unused_variables)]
-fn __action39<
-    'input,
+fn __action98<
 >(
-    input: &'input str,
-    (_, l, _): (usize, usize, usize),
-    (_, b, _): (usize, bool, usize),
-    (_, r, _): (usize, usize, usize),
+    file: FileId,
+    (_, __0, _): (usize, Exp<Span1>, usize),
 ) -> Exp<Span1>
 {
-    Exp::Bool(b, Span1 { start_ix: l, end_ix: r })
+    __0
 }
 
 #[allow(unused_variables)]
-fn __action40<
-    'input,
+fn __action99<
 >(
-    input: &'input str,
-    (_, l, _): (usize, usize, usize),
-    (_, n, _): (usize, i64, usize),
-    (_, r, _): (usize, usize, usize),
-) -> Exp<Span1>
+    file: FileId,
+    (_, t, _): (usize, Exp<Span1>, usize),
+) -> Box<Exp<Span1>>
 {
-    Exp::Num(n, Span1 { start_ix: l, end_ix: r })
+    Box::new(t)
 }
 
 #[allow(unused_variables)]
-fn __action41<
-    'input,
+fn __action100<
 >(
-    input: &'input str,
-    (_, l, _): (usize, usize, usize),
-    (_, p, _): (usize, Prim1, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, e, _): (usize, Box<Exp<Span1>>, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, r, _): (usize, usize, usize),
-) -> Exp<Span1>
+    file: FileId,
+    (_, t, _): (usize, Exp<Span1>, usize),
+) -> Box<Exp<Span1>>
 {
-    Exp::Prim1(p, e, Span1 { start_ix: l, end_ix: r })
+    Box::new(t)
 }
 
 #[allow(unused_variables)]
-fn __action42<
-    'input,
+fn __action101<
 >(
-    input: &'input str,
-    (_, _, _): (usize, &'input str, usize),
-    (_, e, _): (usize, Exp<Span1>, usize),
-    (_, _, _): (usize, &'input str, usize),
-) -> Exp<Span1>
+    file: FileId,
+    (_, __0, _): (usize, String, usize),
+) -> core::option::Option<String>
 {
-    e
+    Some(__0)
 }
 
 #[allow(unused_variables)]
-fn __action43<
-    'input,
+fn __action102<
 >(
-    input: &'input str,
-    (_, l, _): (usize, usize, usize),
-    (_, x, _): (usize, Box<Exp<Span1>>, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, es, _): (usize, Vec<Exp<Span1>>, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, r, _): (usize, usize, usize),
-) -> Exp<Span1>
+    file: FileId,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> core::option::Option<String>
 {
-    Exp::Call(x, es, Span1 { start_ix: l, end_ix: r })
+    None
 }
 
 #[allow(unused_variables)]
-fn __action44<
-    'input,
+fn __action103<
 >(
-    input: &'input str,
-    (_, l, _): (usize, usize, usize),
-    (_, _, _): (usize, (), usize),
-    (_, parameters, _): (usize, Vec<String>, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, body, _): (usize, Box<Exp<Span1>>, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, r, _): (usize, usize, usize),
-) -> Exp<Span1>
+    file: FileId,
+    (_, mut v, _): (usize, alloc::vec::Vec<String>, usize),
+    (_, last, _): (usize, core::option::Option<String>, usize),
+) -> Vec<String>
 {
-    Exp::Lambda { parameters, body, ann: Span1 { start_ix: l, end_ix: r } }
+    {
+    match last {
+      None => { },
+      Some(t) => { v.push(t); }
+    };
+    v
+  }
 }
 
 #[allow(unused_variables)]
-fn __action45<
-    'input,
+fn __action104<
 >(
-    input: &'input str,
-    (_, l, _): (usize, usize, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, es, _): (usize, Vec<Exp<Span1>>, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, r, _): (usize, usize, usize),
-) -> Exp<Span1>
+    file: FileId,
+    (_, t, _): (usize, Exp<Span1>, usize),
+) -> Box<Exp<Span1>>
 {
-    Exp::Array(es, Span1 { start_ix: l, end_ix: r })
+    Box::new(t)
 }
 
 #[allow(unused_variables)]
-fn __action46<
-    'input,
+fn __action105<
 >(
-    input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> ()
+    file: FileId,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> alloc::vec::Vec<(SurfFunDecl<Span1>, Tok)>
 {
-    ()
+    alloc::vec![]
 }
 
 #[allow(unused_variables)]
-fn __action47<
-    'input,
+fn __action106<
 >(
-    input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> ()
+    file: FileId,
+    (_, v, _): (usize, alloc::vec::Vec<(SurfFunDecl<Span1>, Tok)>, usize),
+) -> alloc::vec::Vec<(SurfFunDecl<Span1>, Tok)>
 {
-    ()
+    v
 }
 
 #[allow(unused_variables)]
-fn __action48<
-    'input,
+fn __action107<
 >(
-    input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> Prim1
+    file: FileId,
+    (_, __0, _): (usize, SurfFunDecl<Span1>, usize),
+    (_, __1, _): (usize, Tok, usize),
+) -> (SurfFunDecl<Span1>, Tok)
 {
-    Prim1::Add1
+    (__0, __1)
 }
 
 #[allow(unused_variables)]
-fn __action49<
-    'input,
+fn __action108<
 >(
-    input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> Prim1
+    file: FileId,
+    (_, t, _): (usize, SurfProg<Span1>, usize),
+) -> Box<SurfProg<Span1>>
 {
-    Prim1::Sub1
+    Box::new(t)
 }
 
 #[allow(unused_variables)]
-fn __action50<
-    'input,
+fn __action109<
 >(
-    input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> Prim1
+    file: FileId,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> usize
 {
-    Prim1::Print
+    __lookbehind.clone()
 }
 
 #[allow(unused_variables)]
-fn __action51<
-    'input,
+fn __action110<
 >(
-    input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> Prim1
+    file: FileId,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> usize
 {
-    Prim1::IsNum
+    __lookahead.clone()
 }
 
 #[allow(unused_variables)]
-fn __action52<
-    'input,
+fn __action111<
 >(
-    input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> Prim1
+    file: FileId,
+    (_, __0, _): (usize, (SurfFunDecl<Span1>, Tok), usize),
+) -> alloc::vec::Vec<(SurfFunDecl<Span1>, Tok)>
 {
-    Prim1::IsBool
+    alloc::vec![__0]
+}
+
+#[allow(unused_variables)]
+fn __action112<
+>(
+    file: FileId,
+    (_, v, _): (usize, alloc::vec::Vec<(SurfFunDecl<Span1>, Tok)>, usize),
+    (_, e, _): (usize, (SurfFunDecl<Span1>, Tok), usize),
+) -> alloc::vec::Vec<(SurfFunDecl<Span1>, Tok)>
+{
+    { let mut v = v; v.push(e); v }
 }
 
 #[allow(unused_variables)]
-fn __action53<
-    'input,
+fn __action113<
 >(
-    input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> Prim1
+    file: FileId,
+    (_, __0, _): (usize, String, usize),
+) -> core::option::Option<String>
 {
-    Prim1::IsArray
+    Some(__0)
 }
 
 #[allow(unused_variables)]
-fn __action54<
-    'input,
+fn __action114<
 >(
-    input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> Prim1
+    file: FileId,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> core::option::Option<String>
 {
-    Prim1::Length
+    None
 }
 
 #[allow(unused_variables)]
-fn __action55<
-    'input,
+fn __action115<
 >(
-    input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> Prim1
+    file: FileId,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> alloc::vec::Vec<String>
 {
-    Prim1::IsFun
+    alloc::vec![]
 }
 
 #[allow(unused_variables)]
-fn __action56<
-    'input,
+fn __action116<
 >(
-    input: &'input str,
-    (_, type_exp, _): (usize, (String, Vec<String>), usize),
-) -> Vec<(String, Vec<String>)>
+    file: FileId,
+    (_, v, _): (usize, alloc::vec::Vec<String>, usize),
+) -> alloc::vec::Vec<String>
 {
-    vec![type_exp]
+    v
 }
 
 #[allow(unused_variables)]
-fn __action57<
-    'input,
+fn __action117<
 >(
-    input: &'input str,
-    (_, mut type_exps, _): (usize, Vec<(String, Vec<String>)>, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, type_exp, _): (usize, (String, Vec<String>), usize),
-) -> Vec<(String, Vec<String>)>
+    file: FileId,
+    (_, __0, _): (usize, String, usize),
+    (_, _, _): (usize, Tok, usize),
+) -> String
 {
-    {
-    type_exps.push(type_exp);
-    type_exps
-  }
+    __0
 }
 
 #[allow(unused_variables)]
-fn __action58<
-    'input,
+fn __action118<
 >(
-    input: &'input str,
-    (_, case, _): (usize, (SnakeType, Vec<String>, Exp<Span1>), usize),
-) -> Vec<(SnakeType, Vec<String>, Exp<Span1>)>
+    file: FileId,
+    (_, t, _): (usize, Exp<Span1>, usize),
+) -> Box<Exp<Span1>>
 {
-    vec![case]
+    Box::new(t)
 }
 
 #[allow(unused_variables)]
-fn __action59<
-    'input,
+fn __action119<
 >(
-    input: &'input str,
-    (_, mut cases, _): (usize, Vec<(SnakeType, Vec<String>, Exp<Span1>)>, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, case, _): (usize, (SnakeType, Vec<String>, Exp<Span1>), usize),
-) -> Vec<(SnakeType, Vec<String>, Exp<Span1>)>
+    file: FileId,
+    (_, t, _): (usize, Exp<Span1>, usize),
+) -> Box<Exp<Span1>>
 {
-    {
-    cases.push(case);
-    cases
-  }
+    Box::new(t)
 }
 
 #[allow(unused_variables)]
-fn __action60<
-    'input,
+fn __action120<
 >(
-    input: &'input str,
-    (_, binding, _): (usize, String, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, exp, _): (usize, Exp<Span1>, usize),
-) -> Vec<(String, Exp<Span1>)>
+    file: FileId,
+    (_, t, _): (usize, Exp<Span1>, usize),
+) -> Box<Exp<Span1>>
 {
-    vec![(binding, exp)]
+    Box::new(t)
 }
 
 #[allow(unused_variables)]
-fn __action61<
-    'input,
+fn __action121<
 >(
-    input: &'input str,
-    (_, mut bindings, _): (usize, Vec<(String, Exp<Span1>)>, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, binding, _): (usize, String, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, exp, _): (usize, Exp<Span1>, usize),
-) -> Vec<(String, Exp<Span1>)>
+    file: FileId,
+    (_, t, _): (usize, Exp<Span1>, usize),
+) -> Box<Exp<Span1>>
 {
-    {
-    bindings.push((binding, exp));
-    bindings
-  }
+    Box::new(t)
 }
 
 #[allow(unused_variables)]
-fn __action62<
-    'input,
+fn __action122<
 >(
-    input: &'input str,
-    (_, id, _): (usize, String, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, ids, _): (usize, Vec<String>, usize),
-    (_, _, _): (usize, &'input str, usize),
-) -> (String, Vec<String>)
+    file: FileId,
+    (_, t, _): (usize, Exp<Span1>, usize),
+) -> Box<Exp<Span1>>
 {
-    (id, ids)
+    Box::new(t)
 }
 
 #[allow(unused_variables)]
-fn __action63<
-    'input,
+fn __action123<
 >(
-    input: &'input str,
-    (_, id, _): (usize, String, usize),
-) -> (String, Vec<String>)
+    file: FileId,
+    (_, t, _): (usize, Exp<Span1>, usize),
+) -> Box<Exp<Span1>>
 {
-    (id, vec![])
+    Box::new(t)
 }
 
 #[allow(unused_variables)]
-fn __action64<
-    'input,
+fn __action124<
 >(
-    input: &'input str,
-    (_, _, _): (usize, &'input str, usize),
-    (_, type_match, _): (usize, (String, Vec<String>), usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, exp, _): (usize, Exp<Span1>, usize),
-) -> (SnakeType, Vec<String>, Exp<Span1>)
+    file: FileId,
+    (_, t, _): (usize, Exp<Span1>, usize),
+) -> Box<Exp<Span1>>
 {
-    (SnakeType::Custom(type_match.0), type_match.1, exp)
+    Box::new(t)
 }
 
 #[allow(unused_variables)]
-fn __action65<
-    'input,
+fn __action125<
 >(
-    input: &'input str,
-    (_, _, _): (usize, &'input str, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, id, _): (usize, String, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, exp, _): (usize, Exp<Span1>, usize),
-) -> (SnakeType, Vec<String>, Exp<Span1>)
+    file: FileId,
+    (_, t, _): (usize, Exp<Span1>, usize),
+) -> Box<Exp<Span1>>
 {
-    (SnakeType::Num, vec![id], exp)
+    Box::new(t)
 }
 
 #[allow(unused_variables)]
-fn __action66<
-    'input,
+fn __action126<
 >(
-    input: &'input str,
-    (_, _, _): (usize, &'input str, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, id, _): (usize, String, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, exp, _): (usize, Exp<Span1>, usize),
-) -> (SnakeType, Vec<String>, Exp<Span1>)
+    file: FileId,
+    (_, __0, _): (usize, Exp<Span1>, usize),
+) -> core::option::Option<Exp<Span1>>
 {
-    (SnakeType::Func, vec![id], exp)
+    Some(__0)
 }
 
 #[allow(unused_variables)]
-fn __action67<
-    'input,
+fn __action127<
 >(
-    input: &'input str,
-    (_, _, _): (usize, &'input str, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, id, _): (usize, String, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, exp, _): (usize, Exp<Span1>, usize),
-) -> (SnakeType, Vec<String>, Exp<Span1>)
+    file: FileId,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> core::option::Option<Exp<Span1>>
 {
-    (SnakeType::Bool, vec![id], exp)
+    None
 }
 
 #[allow(unused_variables)]
-fn __action68<
-    'input,
+fn __action128<
 >(
-    input: &'input str,
-    (_, _, _): (usize, &'input str, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, id, _): (usize, String, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, exp, _): (usize, Exp<Span1>, usize),
-) -> (SnakeType, Vec<String>, Exp<Span1>)
+    file: FileId,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> alloc::vec::Vec<Exp<Span1>>
 {
-    (SnakeType::Array, vec![id], exp)
+    alloc::vec![]
 }
 
 #[allow(unused_variables)]
-fn __action69<
-    'input,
+fn __action129<
 >(
-    input: &'input str,
-    (_, __0, _): (usize, String, usize),
-) -> String
+    file: FileId,
+    (_, v, _): (usize, alloc::vec::Vec<Exp<Span1>>, usize),
+) -> alloc::vec::Vec<Exp<Span1>>
 {
-    __0
+    v
 }
 
 #[allow(unused_variables)]
-fn __action70<
-    'input,
+fn __action130<
 >(
-    input: &'input str,
-    (_, s, _): (usize, &'input str, usize),
-) -> i64
+    file: FileId,
+    (_, __0, _): (usize, Exp<Span1>, usize),
+    (_, _, _): (usize, Tok, usize),
+) -> Exp<Span1>
 {
-    i64::from_str(s).unwrap()
+    __0
 }
 
 #[allow(unused_variables)]
-fn __action71<
-    'input,
+fn __action131<
 >(
-    input: &'input str,
-    (_, s, _): (usize, &'input str, usize),
-) -> String
+    file: FileId,
+    (_, __0, _): (usize, (String, String), usize),
+) -> core::option::Option<(String, String)>
 {
-    String::from(s)
+    Some(__0)
 }
 
 #[allow(unused_variables)]
-fn __action72<
-    'input,
+fn __action132<
 >(
-    input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> bool
+    file: FileId,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> core::option::Option<(String, String)>
 {
-    true
+    None
 }
 
 #[allow(unused_variables)]
-fn __action73<
-    'input,
+fn __action133<
 >(
-    input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> bool
+    file: FileId,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> alloc::vec::Vec<(String, String)>
 {
-    false
+    alloc::vec![]
 }
 
 #[allow(unused_variables)]
-fn __action74<
-    'input,
+fn __action134<
 >(
-    input: &'input str,
-    (_, mut v, _): (usize, alloc::vec::Vec<Exp<Span1>>, usize),
-    (_, last, _): (usize, core::option::Option<Exp<Span1>>, usize),
-) -> Vec<Exp<Span1>>
+    file: FileId,
+    (_, v, _): (usize, alloc::vec::Vec<(String, String)>, usize),
+) -> alloc::vec::Vec<(String, String)>
 {
-    {
-    match last {
-      None => { },
-      Some(t) => { v.push(t); }
-    };
     v
-  }
 }
 
 #[allow(unused_variables)]
-fn __action75<
-    'input,
+fn __action135<
 >(
-    input: &'input str,
-    (_, t, _): (usize, Exp<Span1>, usize),
-) -> Box<Exp<Span1>>
+    file: FileId,
+    (_, __0, _): (usize, (String, String), usize),
+    (_, _, _): (usize, Tok, usize),
+) -> (String, String)
 {
-    Box::new(t)
+    __0
 }
 
 #[allow(unused_variables)]
-fn __action76<
-    'input,
+fn __action136<
 >(
-    input: &'input str,
-    (_, t, _): (usize, Exp<Span1>, usize),
-) -> Box<Exp<Span1>>
+    file: FileId,
+    (_, __0, _): (usize, (String, String), usize),
+) -> alloc::vec::Vec<(String, String)>
 {
-    Box::new(t)
+    alloc::vec![__0]
 }
 
 #[allow(unused_variables)]
-fn __action77<
-    'input,
+fn __action137<
 >(
-    input: &'input str,
-    (_, l, _): (usize, usize, usize),
-    (_, e1, _): (usize, Box<Exp<Span1>>, usize),
-    (_, op, _): (usize, Prim2, usize),
-    (_, e2, _): (usize, Box<Exp<Span1>>, usize),
-    (_, r, _): (usize, usize, usize),
-) -> Exp<Span1>
+    file: FileId,
+    (_, v, _): (usize, alloc::vec::Vec<(String, String)>, usize),
+    (_, e, _): (usize, (String, String), usize),
+) -> alloc::vec::Vec<(String, String)>
 {
-    Exp::Prim2(op, e1, e2, Span1 { start_ix: l, end_ix: r })
+    { let mut v = v; v.push(e); v }
 }
 
 #[allow(unused_variables)]
-fn __action78<
-    'input,
+fn __action138<
 >(
-    input: &'input str,
+    file: FileId,
     (_, __0, _): (usize, Exp<Span1>, usize),
-) -> Exp<Span1>
+) -> alloc::vec::Vec<Exp<Span1>>
 {
-    __0
+    alloc::vec![__0]
 }
 
 #[allow(unused_variables)]
-fn __action79<
-    'input,
+fn __action139<
 >(
-    input: &'input str,
-    (_, l, _): (usize, usize, usize),
-    (_, e1, _): (usize, Box<Exp<Span1>>, usize),
-    (_, op, _): (usize, Prim2, usize),
-    (_, e2, _): (usize, Box<Exp<Span1>>, usize),
-    (_, r, _): (usize, usize, usize),
-) -> Exp<Span1>
+    file: FileId,
+    (_, v, _): (usize, alloc::vec::Vec<Exp<Span1>>, usize),
+    (_, e, _): (usize, Exp<Span1>, usize),
+) -> alloc::vec::Vec<Exp<Span1>>
 {
-    Exp::Prim2(op, e1, e2, Span1 { start_ix: l, end_ix: r })
+    { let mut v = v; v.push(e); v }
 }
 
 #[allow(unused_variables)]
-fn __action80<
-    'input,
+fn __action140<
 >(
-    input: &'input str,
-    (_, __0, _): (usize, Exp<Span1>, usize),
-) -> Exp<Span1>
+    file: FileId,
+    (_, __0, _): (usize, String, usize),
+) -> alloc::vec::Vec<String>
 {
-    __0
+    alloc::vec![__0]
 }
 
 #[allow(unused_variables)]
-fn __action81<
-    'input,
+fn __action141<
 >(
-    input: &'input str,
-    (_, l, _): (usize, usize, usize),
-    (_, e1, _): (usize, Box<Exp<Span1>>, usize),
-    (_, op, _): (usize, Prim2, usize),
-    (_, e2, _): (usize, Box<Exp<Span1>>, usize),
-    (_, r, _): (usize, usize, usize),
-) -> Exp<Span1>
+    file: FileId,
+    (_, v, _): (usize, alloc::vec::Vec<String>, usize),
+    (_, e, _): (usize, String, usize),
+) -> alloc::vec::Vec<String>
 {
-    Exp::Prim2(op, e1, e2, Span1 { start_ix: l, end_ix: r })
+    { let mut v = v; v.push(e); v }
 }
 
 #[allow(unused_variables)]
-fn __action82<
-    'input,
+fn __action142<
 >(
-    input: &'input str,
-    (_, __0, _): (usize, Exp<Span1>, usize),
-) -> Exp<Span1>
+    file: FileId,
+    __0: (usize, Exp<Span1>, usize),
+    __1: (usize, Tok, usize),
+) -> alloc::vec::Vec<Exp<Span1>>
 {
-    __0
+    let __start0 = __0.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action130(
+        file,
+        __0,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action138(
+        file,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action83<
-    'input,
+fn __action143<
 >(
-    input: &'input str,
-    (_, l, _): (usize, usize, usize),
-    (_, e1, _): (usize, Box<Exp<Span1>>, usize),
-    (_, op, _): (usize, Prim2, usize),
-    (_, e2, _): (usize, Box<Exp<Span1>>, usize),
-    (_, r, _): (usize, usize, usize),
-) -> Exp<Span1>
+    file: FileId,
+    __0: (usize, alloc::vec::Vec<Exp<Span1>>, usize),
+    __1: (usize, Exp<Span1>, usize),
+    __2: (usize, Tok, usize),
+) -> alloc::vec::Vec<Exp<Span1>>
 {
-    Exp::Prim2(op, e1, e2, Span1 { start_ix: l, end_ix: r })
+    let __start0 = __1.0.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action130(
+        file,
+        __1,
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action139(
+        file,
+        __0,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action84<
-    'input,
+fn __action144<
 >(
-    input: &'input str,
-    (_, __0, _): (usize, Exp<Span1>, usize),
-) -> Exp<Span1>
+    file: FileId,
+    __0: (usize, core::option::Option<Exp<Span1>>, usize),
+) -> Vec<Exp<Span1>>
 {
-    __0
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action128(
+        file,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action88(
+        file,
+        __temp0,
+        __0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action85<
-    'input,
+fn __action145<
 >(
-    input: &'input str,
-    (_, mut v, _): (usize, alloc::vec::Vec<String>, usize),
-    (_, last, _): (usize, core::option::Option<String>, usize),
-) -> Vec<String>
+    file: FileId,
+    __0: (usize, alloc::vec::Vec<Exp<Span1>>, usize),
+    __1: (usize, core::option::Option<Exp<Span1>>, usize),
+) -> Vec<Exp<Span1>>
 {
-    {
-    match last {
-      None => { },
-      Some(t) => { v.push(t); }
-    };
-    v
-  }
+    let __start0 = __0.0.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action129(
+        file,
+        __0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action88(
+        file,
+        __temp0,
+        __1,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action86<
-    'input,
+fn __action146<
 >(
-    input: &'input str,
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> usize
+    file: FileId,
+    __0: (usize, (String, String), usize),
+    __1: (usize, Tok, usize),
+) -> alloc::vec::Vec<(String, String)>
 {
-    __lookbehind.clone()
+    let __start0 = __0.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action135(
+        file,
+        __0,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action136(
+        file,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action87<
-    'input,
+fn __action147<
 >(
-    input: &'input str,
-    (_, t, _): (usize, Exp<Span1>, usize),
-) -> Box<Exp<Span1>>
+    file: FileId,
+    __0: (usize, alloc::vec::Vec<(String, String)>, usize),
+    __1: (usize, (String, String), usize),
+    __2: (usize, Tok, usize),
+) -> alloc::vec::Vec<(String, String)>
 {
-    Box::new(t)
+    let __start0 = __1.0.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action135(
+        file,
+        __1,
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action137(
+        file,
+        __0,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action88<
-    'input,
+fn __action148<
 >(
-    input: &'input str,
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> alloc::vec::Vec<(SurfFunDecl<Span1>, &'input str)>
+    file: FileId,
+    __0: (usize, core::option::Option<(String, String)>, usize),
+) -> Vec<(String, String)>
 {
-    alloc::vec![]
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action133(
+        file,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action87(
+        file,
+        __temp0,
+        __0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action89<
-    'input,
+fn __action149<
 >(
-    input: &'input str,
-    (_, v, _): (usize, alloc::vec::Vec<(SurfFunDecl<Span1>, &'input str)>, usize),
-) -> alloc::vec::Vec<(SurfFunDecl<Span1>, &'input str)>
+    file: FileId,
+    __0: (usize, alloc::vec::Vec<(String, String)>, usize),
+    __1: (usize, core::option::Option<(String, String)>, usize),
+) -> Vec<(String, String)>
 {
-    v
+    let __start0 = __0.0.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action134(
+        file,
+        __0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action87(
+        file,
+        __temp0,
+        __1,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action90<
-    'input,
+fn __action150<
 >(
-    input: &'input str,
-    (_, __0, _): (usize, SurfFunDecl<Span1>, usize),
-    (_, __1, _): (usize, &'input str, usize),
-) -> (SurfFunDecl<Span1>, &'input str)
+    file: FileId,
+    __0: (usize, String, usize),
+    __1: (usize, Tok, usize),
+) -> alloc::vec::Vec<String>
 {
-    (__0, __1)
+    let __start0 = __0.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action117(
+        file,
+        __0,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action140(
+        file,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action91<
-    'input,
+fn __action151<
 >(
-    input: &'input str,
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> usize
+    file: FileId,
+    __0: (usize, alloc::vec::Vec<String>, usize),
+    __1: (usize, String, usize),
+    __2: (usize, Tok, usize),
+) -> alloc::vec::Vec<String>
 {
-    __lookahead.clone()
+    let __start0 = __1.0.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action117(
+        file,
+        __1,
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action141(
+        file,
+        __0,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action92<
-    'input,
+fn __action152<
 >(
-    input: &'input str,
-    (_, __0, _): (usize, (SurfFunDecl<Span1>, &'input str), usize),
-) -> alloc::vec::Vec<(SurfFunDecl<Span1>, &'input str)>
+    file: FileId,
+    __0: (usize, core::option::Option<String>, usize),
+) -> Vec<String>
 {
-    alloc::vec![__0]
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action115(
+        file,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action103(
+        file,
+        __temp0,
+        __0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action93<
-    'input,
+fn __action153<
 >(
-    input: &'input str,
-    (_, v, _): (usize, alloc::vec::Vec<(SurfFunDecl<Span1>, &'input str)>, usize),
-    (_, e, _): (usize, (SurfFunDecl<Span1>, &'input str), usize),
-) -> alloc::vec::Vec<(SurfFunDecl<Span1>, &'input str)>
+    file: FileId,
+    __0: (usize, alloc::vec::Vec<String>, usize),
+    __1: (usize, core::option::Option<String>, usize),
+) -> Vec<String>
 {
-    { let mut v = v; v.push(e); v }
+    let __start0 = __0.0.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action116(
+        file,
+        __0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action103(
+        file,
+        __temp0,
+        __1,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action94<
-    'input,
+fn __action154<
 >(
-    input: &'input str,
-    (_, __0, _): (usize, String, usize),
-) -> core::option::Option<String>
+    file: FileId,
+    __0: (usize, SurfFunDecl<Span1>, usize),
+    __1: (usize, Tok, usize),
+) -> alloc::vec::Vec<(SurfFunDecl<Span1>, Tok)>
 {
-    Some(__0)
+    let __start0 = __0.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action107(
+        file,
+        __0,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action111(
+        file,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action95<
-    'input,
+fn __action155<
 >(
-    input: &'input str,
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> core::option::Option<String>
+    file: FileId,
+    __0: (usize, alloc::vec::Vec<(SurfFunDecl<Span1>, Tok)>, usize),
+    __1: (usize, SurfFunDecl<Span1>, usize),
+    __2: (usize, Tok, usize),
+) -> alloc::vec::Vec<(SurfFunDecl<Span1>, Tok)>
 {
-    None
+    let __start0 = __1.0.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action107(
+        file,
+        __1,
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action112(
+        file,
+        __0,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action96<
-    'input,
+fn __action156<
 >(
-    input: &'input str,
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> alloc::vec::Vec<String>
+    file: FileId,
+    __0: (usize, usize, usize),
+    __1: (usize, SurfFunDecl<Span1>, usize),
+    __2: (usize, Tok, usize),
+    __3: (usize, Box<Exp<Span1>>, usize),
+    __4: (usize, usize, usize),
+) -> Exp<Span1>
 {
-    alloc::vec![]
+    let __start0 = __0.2.clone();
+    let __end0 = __1.0.clone();
+    let __temp0 = __action105(
+        file,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action3(
+        file,
+        __0,
+        __temp0,
+        __1,
+        __2,
+        __3,
+        __4,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action97<
-    'input,
+fn __action157<
 >(
-    input: &'input str,
-    (_, v, _): (usize, alloc::vec::Vec<String>, usize),
-) -> alloc::vec::Vec<String>
+    file: FileId,
+    __0: (usize, usize, usize),
+    __1: (usize, alloc::vec::Vec<(SurfFunDecl<Span1>, Tok)>, usize),
+    __2: (usize, SurfFunDecl<Span1>, usize),
+    __3: (usize, Tok, usize),
+    __4: (usize, Box<Exp<Span1>>, usize),
+    __5: (usize, usize, usize),
+) -> Exp<Span1>
 {
-    v
+    let __start0 = __1.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action106(
+        file,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action3(
+        file,
+        __0,
+        __temp0,
+        __2,
+        __3,
+        __4,
+        __5,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action98<
-    'input,
+fn __action158<
 >(
-    input: &'input str,
-    (_, __0, _): (usize, String, usize),
-    (_, _, _): (usize, &'input str, usize),
-) -> String
+    file: FileId,
+    __0: (usize, Box<Exp<Span1>>, usize),
+    __1: (usize, Tok, usize),
+    __2: (usize, Box<Exp<Span1>>, usize),
+    __3: (usize, Tok, usize),
+    __4: (usize, usize, usize),
+) -> Exp<Span1>
 {
-    __0
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action110(
+        file,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action45(
+        file,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action99<
-    'input,
+fn __action159<
 >(
-    input: &'input str,
-    (_, t, _): (usize, Exp<Span1>, usize),
-) -> Box<Exp<Span1>>
+    file: FileId,
+    __0: (usize, Box<Exp<Span1>>, usize),
+    __1: (usize, Tok, usize),
+    __2: (usize, String, usize),
+    __3: (usize, usize, usize),
+) -> Exp<Span1>
 {
-    Box::new(t)
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action110(
+        file,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action46(
+        file,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action100<
-    'input,
+fn __action160<
 >(
-    input: &'input str,
-    (_, t, _): (usize, Exp<Span1>, usize),
-) -> Box<Exp<Span1>>
+    file: FileId,
+    __0: (usize, Box<Exp<Span1>>, usize),
+    __1: (usize, Tok, usize),
+    __2: (usize, Box<Exp<Span1>>, usize),
+    __3: (usize, Tok, usize),
+    __4: (usize, Tok, usize),
+    __5: (usize, Box<Exp<Span1>>, usize),
+    __6: (usize, usize, usize),
+) -> Exp<Span1>
 {
-    Box::new(t)
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action110(
+        file,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action47(
+        file,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action101<
-    'input,
+fn __action161<
 >(
-    input: &'input str,
-    (_, t, _): (usize, Exp<Span1>, usize),
-) -> Box<Exp<Span1>>
+    file: FileId,
+    __0: (usize, String, usize),
+    __1: (usize, usize, usize),
+) -> Exp<Span1>
 {
-    Box::new(t)
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action110(
+        file,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action48(
+        file,
+        __temp0,
+        __0,
+        __1,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action102<
-    'input,
+fn __action162<
 >(
-    input: &'input str,
-    (_, t, _): (usize, Exp<Span1>, usize),
-) -> Box<Exp<Span1>>
+    file: FileId,
+    __0: (usize, bool, usize),
+    __1: (usize, usize, usize),
+) -> Exp<Span1>
 {
-    Box::new(t)
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action110(
+        file,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action49(
+        file,
+        __temp0,
+        __0,
+        __1,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action103<
-    'input,
+fn __action163<
 >(
-    input: &'input str,
-    (_, t, _): (usize, Exp<Span1>, usize),
-) -> Box<Exp<Span1>>
+    file: FileId,
+    __0: (usize, i64, usize),
+    __1: (usize, usize, usize),
+) -> Exp<Span1>
 {
-    Box::new(t)
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action110(
+        file,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action50(
+        file,
+        __temp0,
+        __0,
+        __1,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action104<
-    'input,
+fn __action164<
 >(
-    input: &'input str,
-    (_, t, _): (usize, Exp<Span1>, usize),
-) -> Box<Exp<Span1>>
+    file: FileId,
+    __0: (usize, Prim1, usize),
+    __1: (usize, Tok, usize),
+    __2: (usize, Box<Exp<Span1>>, usize),
+    __3: (usize, Tok, usize),
+    __4: (usize, usize, usize),
+) -> Exp<Span1>
 {
-    Box::new(t)
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action110(
+        file,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action51(
+        file,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action105<
-    'input,
+fn __action165<
 >(
-    input: &'input str,
-    (_, t, _): (usize, Exp<Span1>, usize),
-) -> Box<Exp<Span1>>
+    file: FileId,
+    __0: (usize, Tok, usize),
+    __1: (usize, Tok, usize),
+    __2: (usize, Tok, usize),
+    __3: (usize, usize, usize),
+) -> Exp<Span1>
 {
-    Box::new(t)
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action110(
+        file,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action52(
+        file,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action106<
-    'input,
+fn __action166<
 >(
-    input: &'input str,
-    (_, t, _): (usize, Exp<Span1>, usize),
-) -> Box<Exp<Span1>>
+    file: FileId,
+    __0: (usize, Tok, usize),
+    __1: (usize, Tok, usize),
+    __2: (usize, Box<Exp<Span1>>, usize),
+    __3: (usize, Tok, usize),
+    __4: (usize, Box<Exp<Span1>>, usize),
+    __5: (usize, Tok, usize),
+    __6: (usize, usize, usize),
+) -> Exp<Span1>
 {
-    Box::new(t)
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action110(
+        file,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action53(
+        file,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action107<
-    'input,
+fn __action167<
 >(
-    input: &'input str,
-    (_, __0, _): (usize, Exp<Span1>, usize),
-) -> core::option::Option<Exp<Span1>>
+    file: FileId,
+    __0: (usize, Box<Exp<Span1>>, usize),
+    __1: (usize, Tok, usize),
+    __2: (usize, Vec<Exp<Span1>>, usize),
+    __3: (usize, Tok, usize),
+    __4: (usize, usize, usize),
+) -> Exp<Span1>
 {
-    Some(__0)
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action110(
+        file,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action55(
+        file,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action108<
-    'input,
+fn __action168<
 >(
-    input: &'input str,
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> core::option::Option<Exp<Span1>>
+    file: FileId,
+    __0: (usize, Tok, usize),
+    __1: (usize, Vec<String>, usize),
+    __2: (usize, Tok, usize),
+    __3: (usize, Box<Exp<Span1>>, usize),
+    __4: (usize, Tok, usize),
+    __5: (usize, usize, usize),
+) -> Exp<Span1>
 {
-    None
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action110(
+        file,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action56(
+        file,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action109<
-    'input,
->(
-    input: &'input str,
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> alloc::vec::Vec<Exp<Span1>>
+fn __action169<
+>(
+    file: FileId,
+    __0: (usize, Tok, usize),
+    __1: (usize, Box<Exp<Span1>>, usize),
+    __2: (usize, Tok, usize),
+    __3: (usize, Box<Exp<Span1>>, usize),
+    __4: (usize, Tok, usize),
+    __5: (usize, usize, usize),
+) -> Exp<Span1>
 {
-    alloc::vec![]
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action110(
+        file,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action57(
+        file,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action110<
-    'input,
+fn __action170<
 >(
-    input: &'input str,
-    (_, v, _): (usize, alloc::vec::Vec<Exp<Span1>>, usize),
-) -> alloc::vec::Vec<Exp<Span1>>
+    file: FileId,
+    __0: (usize, Tok, usize),
+    __1: (usize, String, usize),
+    __2: (usize, Tok, usize),
+    __3: (usize, Box<Exp<Span1>>, usize),
+    __4: (usize, Tok, usize),
+    __5: (usize, Box<Exp<Span1>>, usize),
+    __6: (usize, Tok, usize),
+    __7: (usize, Box<Exp<Span1>>, usize),
+    __8: (usize, Tok, usize),
+    __9: (usize, usize, usize),
+) -> Exp<Span1>
 {
-    v
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action110(
+        file,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action58(
+        file,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+        __7,
+        __8,
+        __9,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action111<
-    'input,
+fn __action171<
 >(
-    input: &'input str,
-    (_, __0, _): (usize, Exp<Span1>, usize),
-    (_, _, _): (usize, &'input str, usize),
+    file: FileId,
+    __0: (usize, Tok, usize),
+    __1: (usize, Vec<Exp<Span1>>, usize),
+    __2: (usize, Tok, usize),
+    __3: (usize, usize, usize),
 ) -> Exp<Span1>
 {
-    __0
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action110(
+        file,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action59(
+        file,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action112<
-    'input,
+fn __action172<
 >(
-    input: &'input str,
-    (_, __0, _): (usize, Exp<Span1>, usize),
-) -> alloc::vec::Vec<Exp<Span1>>
+    file: FileId,
+    __0: (usize, String, usize),
+    __1: (usize, Tok, usize),
+    __2: (usize, Box<Exp<Span1>>, usize),
+    __3: (usize, usize, usize),
+) -> Exp<Span1>
 {
-    alloc::vec![__0]
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action110(
+        file,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action22(
+        file,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action113<
-    'input,
+fn __action173<
 >(
-    input: &'input str,
-    (_, v, _): (usize, alloc::vec::Vec<Exp<Span1>>, usize),
-    (_, e, _): (usize, Exp<Span1>, usize),
-) -> alloc::vec::Vec<Exp<Span1>>
+    file: FileId,
+    __0: (usize, SurfFunDecl<Span1>, usize),
+    __1: (usize, Tok, usize),
+    __2: (usize, Box<Exp<Span1>>, usize),
+    __3: (usize, usize, usize),
+) -> Exp<Span1>
 {
-    { let mut v = v; v.push(e); v }
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action110(
+        file,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action156(
+        file,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action114<
-    'input,
+fn __action174<
 >(
-    input: &'input str,
-    (_, __0, _): (usize, String, usize),
-) -> alloc::vec::Vec<String>
+    file: FileId,
+    __0: (usize, alloc::vec::Vec<(SurfFunDecl<Span1>, Tok)>, usize),
+    __1: (usize, SurfFunDecl<Span1>, usize),
+    __2: (usize, Tok, usize),
+    __3: (usize, Box<Exp<Span1>>, usize),
+    __4: (usize, usize, usize),
+) -> Exp<Span1>
 {
-    alloc::vec![__0]
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action110(
+        file,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action157(
+        file,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action115<
-    'input,
+fn __action175<
 >(
-    input: &'input str,
-    (_, v, _): (usize, alloc::vec::Vec<String>, usize),
-    (_, e, _): (usize, String, usize),
-) -> alloc::vec::Vec<String>
+    file: FileId,
+    __0: (usize, core::option::Option<String>, usize),
+    __1: (usize, Tok, usize),
+    __2: (usize, String, usize),
+    __3: (usize, Tok, usize),
+    __4: (usize, Vec<String>, usize),
+    __5: (usize, Tok, usize),
+    __6: (usize, Tok, usize),
+    __7: (usize, Exp<Span1>, usize),
+    __8: (usize, usize, usize),
+) -> SurfFunDecl<Span1>
 {
-    { let mut v = v; v.push(e); v }
+    let __start0 = __0.2.clone();
+    let __end0 = __1.0.clone();
+    let __temp0 = __action110(
+        file,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action5(
+        file,
+        __0,
+        __temp0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+        __7,
+        __8,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action116<
-    'input,
+fn __action176<
 >(
-    input: &'input str,
-    __0: (usize, Exp<Span1>, usize),
-    __1: (usize, &'input str, usize),
-) -> alloc::vec::Vec<Exp<Span1>>
+    file: FileId,
+    __0: (usize, Tok, usize),
+    __1: (usize, Box<Exp<Span1>>, usize),
+    __2: (usize, Tok, usize),
+    __3: (usize, Box<Exp<Span1>>, usize),
+    __4: (usize, Tok, usize),
+    __5: (usize, Tok, usize),
+    __6: (usize, Box<Exp<Span1>>, usize),
+    __7: (usize, usize, usize),
+) -> Exp<Span1>
 {
     let __start0 = __0.0.clone();
-    let __end0 = __1.2.clone();
-    let __temp0 = __action111(
-        input,
-        __0,
-        __1,
+    let __end0 = __0.0.clone();
+    let __temp0 = __action110(
+        file,
+        &__start0,
+        &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action112(
-        input,
+    __action17(
+        file,
         __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+        __7,
     )
 }
 
 #[allow(unused_variables)]
-fn __action117<
-    'input,
+fn __action177<
 >(
-    input: &'input str,
-    __0: (usize, alloc::vec::Vec<Exp<Span1>>, usize),
-    __1: (usize, Exp<Span1>, usize),
-    __2: (usize, &'input str, usize),
-) -> alloc::vec::Vec<Exp<Span1>>
+    file: FileId,
+    __0: (usize, Box<Exp<Span1>>, usize),
+    __1: (usize, Prim2, usize),
+    __2: (usize, Box<Exp<Span1>>, usize),
+    __3: (usize, usize, usize),
+) -> Exp<Span1>
 {
-    let __start0 = __1.0.clone();
-    let __end0 = __2.2.clone();
-    let __temp0 = __action111(
-        input,
-        __1,
-        __2,
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action110(
+        file,
+        &__start0,
+        &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action113(
-        input,
-        __0,
+    __action95(
+        file,
         __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
     )
 }
 
 #[allow(unused_variables)]
-fn __action118<
-    'input,
+fn __action178<
 >(
-    input: &'input str,
-    __0: (usize, core::option::Option<Exp<Span1>>, usize),
-) -> Vec<Exp<Span1>>
+    file: FileId,
+    __0: (usize, Box<Exp<Span1>>, usize),
+    __1: (usize, Prim2, usize),
+    __2: (usize, Box<Exp<Span1>>, usize),
+    __3: (usize, usize, usize),
+) -> Exp<Span1>
 {
     let __start0 = __0.0.clone();
     let __end0 = __0.0.clone();
-    let __temp0 = __action109(
-        input,
+    let __temp0 = __action110(
+        file,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action74(
-        input,
+    __action93(
+        file,
         __temp0,
         __0,
+        __1,
+        __2,
+        __3,
     )
 }
 
 #[allow(unused_variables)]
-fn __action119<
-    'input,
+fn __action179<
 >(
-    input: &'input str,
-    __0: (usize, alloc::vec::Vec<Exp<Span1>>, usize),
-    __1: (usize, core::option::Option<Exp<Span1>>, usize),
-) -> Vec<Exp<Span1>>
+    file: FileId,
+    __0: (usize, Box<Exp<Span1>>, usize),
+    __1: (usize, Prim2, usize),
+    __2: (usize, Box<Exp<Span1>>, usize),
+    __3: (usize, usize, usize),
+) -> Exp<Span1>
 {
     let __start0 = __0.0.clone();
-    let __end0 = __0.2.clone();
+    let __end0 = __0.0.clone();
     let __temp0 = __action110(
-        input,
-        __0,
+        file,
+        &__start0,
+        &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action74(
-        input,
+    __action91(
+        file,
         __temp0,
+        __0,
         __1,
+        __2,
+        __3,
     )
 }
 
 #[allow(unused_variables)]
-fn __action120<
-    'input,
+fn __action180<
 >(
-    input: &'input str,
-    __0: (usize, String, usize),
-    __1: (usize, &'input str, usize),
-) -> alloc::vec::Vec<String>
+    file: FileId,
+    __0: (usize, Tok, usize),
+    __1: (usize, Vec<(String, Exp<Span1>)>, usize),
+    __2: (usize, Tok, usize),
+    __3: (usize, Box<Exp<Span1>>, usize),
+    __4: (usize, usize, usize),
+) -> Exp<Span1>
 {
     let __start0 = __0.0.clone();
-    let __end0 = __1.2.clone();
-    let __temp0 = __action98(
-        input,
-        __0,
-        __1,
+    let __end0 = __0.0.clone();
+    let __temp0 = __action110(
+        file,
+        &__start0,
+        &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action114(
-        input,
+    __action18(
+        file,
         __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
     )
 }
 
 #[allow(unused_variables)]
-fn __action121<
-    'input,
+fn __action181<
 >(
-    input: &'input str,
-    __0: (usize, alloc::vec::Vec<String>, usize),
-    __1: (usize, String, usize),
-    __2: (usize, &'input str, usize),
-) -> alloc::vec::Vec<String>
+    file: FileId,
+    __0: (usize, Tok, usize),
+    __1: (usize, Box<Exp<Span1>>, usize),
+    __2: (usize, Tok, usize),
+    __3: (usize, Box<Exp<Span1>>, usize),
+    __4: (usize, Tok, usize),
+    __5: (usize, Vec<(SnakeType, Vec<String>, Exp<Span1>)>, usize),
+    __6: (usize, Tok, usize),
+    __7: (usize, usize, usize),
+) -> Exp<Span1>
 {
-    let __start0 = __1.0.clone();
-    let __end0 = __2.2.clone();
-    let __temp0 = __action98(
-        input,
-        __1,
-        __2,
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action110(
+        file,
+        &__start0,
+        &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action115(
-        input,
-        __0,
+    __action15(
+        file,
         __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+        __7,
     )
 }
 
 #[allow(unused_variables)]
-fn __action122<
-    'input,
+fn __action182<
 >(
-    input: &'input str,
-    __0: (usize, core::option::Option<String>, usize),
-) -> Vec<String>
+    file: FileId,
+    __0: (usize, Tok, usize),
+    __1: (usize, Box<Exp<Span1>>, usize),
+    __2: (usize, usize, usize),
+) -> Exp<Span1>
 {
     let __start0 = __0.0.clone();
     let __end0 = __0.0.clone();
-    let __temp0 = __action96(
-        input,
+    let __temp0 = __action110(
+        file,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action85(
-        input,
+    __action41(
+        file,
         __temp0,
         __0,
+        __1,
+        __2,
     )
 }
 
 #[allow(unused_variables)]
-fn __action123<
-    'input,
+fn __action183<
 >(
-    input: &'input str,
-    __0: (usize, alloc::vec::Vec<String>, usize),
-    __1: (usize, core::option::Option<String>, usize),
-) -> Vec<String>
+    file: FileId,
+    __0: (usize, Tok, usize),
+    __1: (usize, Box<Exp<Span1>>, usize),
+    __2: (usize, usize, usize),
+) -> Exp<Span1>
 {
     let __start0 = __0.0.clone();
-    let __end0 = __0.2.clone();
-    let __temp0 = __action97(
-        input,
-        __0,
+    let __end0 = __0.0.clone();
+    let __temp0 = __action110(
+        file,
+        &__start0,
+        &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action85(
-        input,
+    __action42(
+        file,
         __temp0,
+        __0,
         __1,
+        __2,
     )
 }
 
 #[allow(unused_variables)]
-fn __action124<
-    'input,
+fn __action184<
 >(
-    input: &'input str,
-    __0: (usize, SurfFunDecl<Span1>, usize),
-    __1: (usize, &'input str, usize),
-) -> alloc::vec::Vec<(SurfFunDecl<Span1>, &'input str)>
+    file: FileId,
+    __0: (usize, Tok, usize),
+    __1: (usize, String, usize),
+    __2: (usize, usize, usize),
+    __3: (usize, Box<SurfProg<Span1>>, usize),
+) -> SurfProg<Span1>
 {
     let __start0 = __0.0.clone();
-    let __end0 = __1.2.clone();
-    let __temp0 = __action90(
-        input,
-        __0,
-        __1,
+    let __end0 = __0.0.clone();
+    let __temp0 = __action110(
+        file,
+        &__start0,
+        &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action92(
-        input,
+    __action1(
+        file,
         __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
     )
 }
 
 #[allow(unused_variables)]
-fn __action125<
-    'input,
+fn __action185<
 >(
-    input: &'input str,
-    __0: (usize, alloc::vec::Vec<(SurfFunDecl<Span1>, &'input str)>, usize),
-    __1: (usize, SurfFunDecl<Span1>, usize),
-    __2: (usize, &'input str, usize),
-) -> alloc::vec::Vec<(SurfFunDecl<Span1>, &'input str)>
+    file: FileId,
+    __0: (usize, Box<Exp<Span1>>, usize),
+    __1: (usize, Prim2, usize),
+    __2: (usize, Box<Exp<Span1>>, usize),
+    __3: (usize, usize, usize),
+) -> Exp<Span1>
 {
-    let __start0 = __1.0.clone();
-    let __end0 = __2.2.clone();
-    let __temp0 = __action90(
-        input,
-        __1,
-        __2,
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action110(
+        file,
+        &__start0,
+        &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action93(
-        input,
-        __0,
+    __action97(
+        file,
         __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
     )
 }
 
 #[allow(unused_variables)]
-fn __action126<
-    'input,
+fn __action186<
 >(
-    input: &'input str,
-    __0: (usize, usize, usize),
-    __1: (usize, SurfFunDecl<Span1>, usize),
-    __2: (usize, &'input str, usize),
-    __3: (usize, Box<Exp<Span1>>, usize),
-    __4: (usize, usize, usize),
+    file: FileId,
+    __0: (usize, Exp<Span1>, usize),
+    __1: (usize, Tok, usize),
+    __2: (usize, Exp<Span1>, usize),
+    __3: (usize, usize, usize),
 ) -> Exp<Span1>
 {
-    let __start0 = __0.2.clone();
-    let __end0 = __1.0.clone();
-    let __temp0 = __action88(
-        input,
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action110(
+        file,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action2(
-        input,
-        __0,
+    __action20(
+        file,
         __temp0,
+        __0,
         __1,
         __2,
         __3,
-        __4,
     )
 }
 
 #[allow(unused_variables)]
-fn __action127<
-    'input,
+fn __action187<
 >(
-    input: &'input str,
-    __0: (usize, usize, usize),
-    __1: (usize, alloc::vec::Vec<(SurfFunDecl<Span1>, &'input str)>, usize),
-    __2: (usize, SurfFunDecl<Span1>, usize),
-    __3: (usize, &'input str, usize),
-    __4: (usize, Box<Exp<Span1>>, usize),
+    file: FileId,
+    __0: (usize, Tok, usize),
+    __1: (usize, Box<Exp<Span1>>, usize),
+    __2: (usize, Tok, usize),
+    __3: (usize, Vec<(SnakeType, Vec<String>, Exp<Span1>)>, usize),
+    __4: (usize, Tok, usize),
     __5: (usize, usize, usize),
 ) -> Exp<Span1>
 {
-    let __start0 = __1.0.clone();
-    let __end0 = __1.2.clone();
-    let __temp0 = __action89(
-        input,
-        __1,
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action110(
+        file,
+        &__start0,
+        &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action2(
-        input,
-        __0,
+    __action16(
+        file,
         __temp0,
+        __0,
+        __1,
         __2,
         __3,
         __4,
@@ -5951,60 +7888,59 @@ fn __action127<
 }
 
 #[allow(unused_variables)]
-fn __action128<
-    'input,
+fn __action188<
 >(
-    input: &'input str,
-    __0: (usize, Box<Exp<Span1>>, usize),
-    __1: (usize, &'input str, usize),
-    __2: (usize, Box<Exp<Span1>>, usize),
-    __3: (usize, &'input str, usize),
-    __4: (usize, usize, usize),
+    file: FileId,
+    __0: (usize, core::option::Option<String>, usize),
+    __1: (usize, Tok, usize),
+    __2: (usize, Vec<(String, Vec<String>)>, usize),
+    __3: (usize, Tok, usize),
+    __4: (usize, Box<Exp<Span1>>, usize),
+    __5: (usize, usize, usize),
 ) -> Exp<Span1>
 {
-    let __start0 = __0.0.clone();
-    let __end0 = __0.0.clone();
-    let __temp0 = __action91(
-        input,
+    let __start0 = __0.2.clone();
+    let __end0 = __1.0.clone();
+    let __temp0 = __action110(
+        file,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action36(
-        input,
-        __temp0,
+    __action14(
+        file,
         __0,
+        __temp0,
         __1,
         __2,
         __3,
         __4,
+        __5,
     )
 }
 
 #[allow(unused_variables)]
-fn __action129<
-    'input,
+fn __action189<
 >(
-    input: &'input str,
+    file: FileId,
     __0: (usize, Box<Exp<Span1>>, usize),
-    __1: (usize, &'input str, usize),
-    __2: (usize, Box<Exp<Span1>>, usize),
-    __3: (usize, &'input str, usize),
-    __4: (usize, &'input str, usize),
-    __5: (usize, Box<Exp<Span1>>, usize),
-    __6: (usize, usize, usize),
+    __1: (usize, Tok, usize),
+    __2: (usize, String, usize),
+    __3: (usize, Tok, usize),
+    __4: (usize, Box<Exp<Span1>>, usize),
+    __5: (usize, usize, usize),
 ) -> Exp<Span1>
 {
     let __start0 = __0.0.clone();
     let __end0 = __0.0.clone();
-    let __temp0 = __action91(
-        input,
+    let __temp0 = __action110(
+        file,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action37(
-        input,
+    __action24(
+        file,
         __temp0,
         __0,
         __1,
@@ -6012,854 +7948,812 @@ fn __action129<
         __3,
         __4,
         __5,
-        __6,
     )
 }
 
 #[allow(unused_variables)]
-fn __action130<
-    'input,
+fn __action190<
 >(
-    input: &'input str,
-    __0: (usize, String, usize),
-    __1: (usize, usize, usize),
+    file: FileId,
+    __0: (usize, Tok, usize),
+    __1: (usize, Vec<(String, Exp<Span1>)>, usize),
+    __2: (usize, Tok, usize),
+    __3: (usize, Box<Exp<Span1>>, usize),
+    __4: (usize, usize, usize),
 ) -> Exp<Span1>
 {
     let __start0 = __0.0.clone();
     let __end0 = __0.0.clone();
-    let __temp0 = __action91(
-        input,
+    let __temp0 = __action110(
+        file,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action38(
-        input,
+    __action19(
+        file,
         __temp0,
         __0,
         __1,
+        __2,
+        __3,
+        __4,
     )
 }
 
 #[allow(unused_variables)]
-fn __action131<
-    'input,
+fn __action191<
 >(
-    input: &'input str,
-    __0: (usize, bool, usize),
-    __1: (usize, usize, usize),
+    file: FileId,
+    __0: (usize, Box<Exp<Span1>>, usize),
+    __1: (usize, Tok, usize),
+    __2: (usize, Box<Exp<Span1>>, usize),
+    __3: (usize, Tok, usize),
 ) -> Exp<Span1>
 {
-    let __start0 = __0.0.clone();
-    let __end0 = __0.0.clone();
-    let __temp0 = __action91(
-        input,
+    let __start0 = __3.2.clone();
+    let __end0 = __3.2.clone();
+    let __temp0 = __action109(
+        file,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action39(
-        input,
-        __temp0,
+    __action158(
+        file,
         __0,
         __1,
+        __2,
+        __3,
+        __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action132<
-    'input,
+fn __action192<
 >(
-    input: &'input str,
-    __0: (usize, i64, usize),
-    __1: (usize, usize, usize),
+    file: FileId,
+    __0: (usize, Box<Exp<Span1>>, usize),
+    __1: (usize, Tok, usize),
+    __2: (usize, String, usize),
 ) -> Exp<Span1>
 {
-    let __start0 = __0.0.clone();
-    let __end0 = __0.0.clone();
-    let __temp0 = __action91(
-        input,
+    let __start0 = __2.2.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action109(
+        file,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action40(
-        input,
-        __temp0,
+    __action159(
+        file,
         __0,
         __1,
+        __2,
+        __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action133<
-    'input,
+fn __action193<
 >(
-    input: &'input str,
-    __0: (usize, Prim1, usize),
-    __1: (usize, &'input str, usize),
+    file: FileId,
+    __0: (usize, Box<Exp<Span1>>, usize),
+    __1: (usize, Tok, usize),
     __2: (usize, Box<Exp<Span1>>, usize),
-    __3: (usize, &'input str, usize),
-    __4: (usize, usize, usize),
+    __3: (usize, Tok, usize),
+    __4: (usize, Tok, usize),
+    __5: (usize, Box<Exp<Span1>>, usize),
 ) -> Exp<Span1>
 {
-    let __start0 = __0.0.clone();
-    let __end0 = __0.0.clone();
-    let __temp0 = __action91(
-        input,
+    let __start0 = __5.2.clone();
+    let __end0 = __5.2.clone();
+    let __temp0 = __action109(
+        file,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action41(
-        input,
-        __temp0,
+    __action160(
+        file,
         __0,
         __1,
         __2,
         __3,
         __4,
+        __5,
+        __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action134<
-    'input,
+fn __action194<
 >(
-    input: &'input str,
-    __0: (usize, Box<Exp<Span1>>, usize),
-    __1: (usize, &'input str, usize),
-    __2: (usize, Vec<Exp<Span1>>, usize),
-    __3: (usize, &'input str, usize),
-    __4: (usize, usize, usize),
+    file: FileId,
+    __0: (usize, String, usize),
 ) -> Exp<Span1>
 {
-    let __start0 = __0.0.clone();
-    let __end0 = __0.0.clone();
-    let __temp0 = __action91(
-        input,
+    let __start0 = __0.2.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action109(
+        file,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action43(
-        input,
-        __temp0,
+    __action161(
+        file,
         __0,
-        __1,
-        __2,
-        __3,
-        __4,
+        __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action135<
-    'input,
+fn __action195<
 >(
-    input: &'input str,
-    __0: (usize, (), usize),
-    __1: (usize, Vec<String>, usize),
-    __2: (usize, &'input str, usize),
-    __3: (usize, Box<Exp<Span1>>, usize),
-    __4: (usize, &'input str, usize),
-    __5: (usize, usize, usize),
+    file: FileId,
+    __0: (usize, bool, usize),
 ) -> Exp<Span1>
 {
-    let __start0 = __0.0.clone();
-    let __end0 = __0.0.clone();
-    let __temp0 = __action91(
-        input,
+    let __start0 = __0.2.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action109(
+        file,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action44(
-        input,
-        __temp0,
+    __action162(
+        file,
         __0,
-        __1,
-        __2,
-        __3,
-        __4,
-        __5,
+        __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action136<
-    'input,
+fn __action196<
 >(
-    input: &'input str,
-    __0: (usize, &'input str, usize),
-    __1: (usize, Vec<Exp<Span1>>, usize),
-    __2: (usize, &'input str, usize),
-    __3: (usize, usize, usize),
+    file: FileId,
+    __0: (usize, i64, usize),
 ) -> Exp<Span1>
 {
-    let __start0 = __0.0.clone();
-    let __end0 = __0.0.clone();
-    let __temp0 = __action91(
-        input,
+    let __start0 = __0.2.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action109(
+        file,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action45(
-        input,
-        __temp0,
+    __action163(
+        file,
         __0,
-        __1,
-        __2,
-        __3,
+        __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action137<
-    'input,
+fn __action197<
 >(
-    input: &'input str,
-    __0: (usize, SurfFunDecl<Span1>, usize),
-    __1: (usize, &'input str, usize),
+    file: FileId,
+    __0: (usize, Prim1, usize),
+    __1: (usize, Tok, usize),
     __2: (usize, Box<Exp<Span1>>, usize),
-    __3: (usize, usize, usize),
+    __3: (usize, Tok, usize),
 ) -> Exp<Span1>
 {
-    let __start0 = __0.0.clone();
-    let __end0 = __0.0.clone();
-    let __temp0 = __action91(
-        input,
+    let __start0 = __3.2.clone();
+    let __end0 = __3.2.clone();
+    let __temp0 = __action109(
+        file,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action126(
-        input,
-        __temp0,
+    __action164(
+        file,
         __0,
         __1,
         __2,
         __3,
+        __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action138<
-    'input,
+fn __action198<
 >(
-    input: &'input str,
-    __0: (usize, alloc::vec::Vec<(SurfFunDecl<Span1>, &'input str)>, usize),
-    __1: (usize, SurfFunDecl<Span1>, usize),
-    __2: (usize, &'input str, usize),
-    __3: (usize, Box<Exp<Span1>>, usize),
-    __4: (usize, usize, usize),
+    file: FileId,
+    __0: (usize, Tok, usize),
+    __1: (usize, Tok, usize),
+    __2: (usize, Tok, usize),
 ) -> Exp<Span1>
 {
-    let __start0 = __0.0.clone();
-    let __end0 = __0.0.clone();
-    let __temp0 = __action91(
-        input,
+    let __start0 = __2.2.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action109(
+        file,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action127(
-        input,
-        __temp0,
+    __action165(
+        file,
         __0,
         __1,
         __2,
-        __3,
-        __4,
+        __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action139<
-    'input,
+fn __action199<
 >(
-    input: &'input str,
-    __0: (usize, &'input str, usize),
-    __1: (usize, String, usize),
-    __2: (usize, &'input str, usize),
-    __3: (usize, Vec<String>, usize),
-    __4: (usize, &'input str, usize),
-    __5: (usize, &'input str, usize),
-    __6: (usize, Exp<Span1>, usize),
-    __7: (usize, usize, usize),
-) -> SurfFunDecl<Span1>
+    file: FileId,
+    __0: (usize, Tok, usize),
+    __1: (usize, Tok, usize),
+    __2: (usize, Box<Exp<Span1>>, usize),
+    __3: (usize, Tok, usize),
+    __4: (usize, Box<Exp<Span1>>, usize),
+    __5: (usize, Tok, usize),
+) -> Exp<Span1>
 {
-    let __start0 = __0.0.clone();
-    let __end0 = __0.0.clone();
-    let __temp0 = __action91(
-        input,
+    let __start0 = __5.2.clone();
+    let __end0 = __5.2.clone();
+    let __temp0 = __action109(
+        file,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action4(
-        input,
-        __temp0,
+    __action166(
+        file,
         __0,
         __1,
         __2,
         __3,
         __4,
         __5,
-        __6,
-        __7,
+        __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action140<
-    'input,
+fn __action200<
 >(
-    input: &'input str,
-    __0: (usize, &'input str, usize),
-    __1: (usize, Box<Exp<Span1>>, usize),
-    __2: (usize, &'input str, usize),
-    __3: (usize, Box<Exp<Span1>>, usize),
-    __4: (usize, &'input str, usize),
-    __5: (usize, &'input str, usize),
-    __6: (usize, Box<Exp<Span1>>, usize),
-    __7: (usize, usize, usize),
+    file: FileId,
+    __0: (usize, Box<Exp<Span1>>, usize),
+    __1: (usize, Tok, usize),
+    __2: (usize, Vec<Exp<Span1>>, usize),
+    __3: (usize, Tok, usize),
 ) -> Exp<Span1>
 {
-    let __start0 = __0.0.clone();
-    let __end0 = __0.0.clone();
-    let __temp0 = __action91(
-        input,
+    let __start0 = __3.2.clone();
+    let __end0 = __3.2.clone();
+    let __temp0 = __action109(
+        file,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action13(
-        input,
-        __temp0,
+    __action167(
+        file,
         __0,
         __1,
         __2,
         __3,
-        __4,
-        __5,
-        __6,
-        __7,
+        __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action141<
-    'input,
+fn __action201<
 >(
-    input: &'input str,
-    __0: (usize, Box<Exp<Span1>>, usize),
-    __1: (usize, Prim2, usize),
-    __2: (usize, Box<Exp<Span1>>, usize),
-    __3: (usize, usize, usize),
+    file: FileId,
+    __0: (usize, Tok, usize),
+    __1: (usize, Vec<String>, usize),
+    __2: (usize, Tok, usize),
+    __3: (usize, Box<Exp<Span1>>, usize),
+    __4: (usize, Tok, usize),
 ) -> Exp<Span1>
 {
-    let __start0 = __0.0.clone();
-    let __end0 = __0.0.clone();
-    let __temp0 = __action91(
-        input,
+    let __start0 = __4.2.clone();
+    let __end0 = __4.2.clone();
+    let __temp0 = __action109(
+        file,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action81(
-        input,
-        __temp0,
+    __action168(
+        file,
         __0,
         __1,
         __2,
         __3,
+        __4,
+        __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action142<
-    'input,
+fn __action202<
 >(
-    input: &'input str,
-    __0: (usize, Box<Exp<Span1>>, usize),
-    __1: (usize, Prim2, usize),
-    __2: (usize, Box<Exp<Span1>>, usize),
-    __3: (usize, usize, usize),
+    file: FileId,
+    __0: (usize, Tok, usize),
+    __1: (usize, Box<Exp<Span1>>, usize),
+    __2: (usize, Tok, usize),
+    __3: (usize, Box<Exp<Span1>>, usize),
+    __4: (usize, Tok, usize),
 ) -> Exp<Span1>
 {
-    let __start0 = __0.0.clone();
-    let __end0 = __0.0.clone();
-    let __temp0 = __action91(
-        input,
+    let __start0 = __4.2.clone();
+    let __end0 = __4.2.clone();
+    let __temp0 = __action109(
+        file,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action79(
-        input,
-        __temp0,
+    __action169(
+        file,
         __0,
         __1,
         __2,
         __3,
+        __4,
+        __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action143<
-    'input,
+fn __action203<
 >(
-    input: &'input str,
-    __0: (usize, Box<Exp<Span1>>, usize),
-    __1: (usize, Prim2, usize),
-    __2: (usize, Box<Exp<Span1>>, usize),
-    __3: (usize, usize, usize),
+    file: FileId,
+    __0: (usize, Tok, usize),
+    __1: (usize, String, usize),
+    __2: (usize, Tok, usize),
+    __3: (usize, Box<Exp<Span1>>, usize),
+    __4: (usize, Tok, usize),
+    __5: (usize, Box<Exp<Span1>>, usize),
+    __6: (usize, Tok, usize),
+    __7: (usize, Box<Exp<Span1>>, usize),
+    __8: (usize, Tok, usize),
 ) -> Exp<Span1>
 {
-    let __start0 = __0.0.clone();
-    let __end0 = __0.0.clone();
-    let __temp0 = __action91(
-        input,
+    let __start0 = __8.2.clone();
+    let __end0 = __8.2.clone();
+    let __temp0 = __action109(
+        file,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action77(
-        input,
-        __temp0,
+    __action170(
+        file,
         __0,
         __1,
         __2,
         __3,
+        __4,
+        __5,
+        __6,
+        __7,
+        __8,
+        __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action144<
-    'input,
+fn __action204<
 >(
-    input: &'input str,
-    __0: (usize, &'input str, usize),
-    __1: (usize, Vec<(String, Exp<Span1>)>, usize),
-    __2: (usize, &'input str, usize),
-    __3: (usize, Box<Exp<Span1>>, usize),
-    __4: (usize, usize, usize),
+    file: FileId,
+    __0: (usize, Tok, usize),
+    __1: (usize, Vec<Exp<Span1>>, usize),
+    __2: (usize, Tok, usize),
 ) -> Exp<Span1>
 {
-    let __start0 = __0.0.clone();
-    let __end0 = __0.0.clone();
-    let __temp0 = __action91(
-        input,
+    let __start0 = __2.2.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action109(
+        file,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action14(
-        input,
-        __temp0,
+    __action171(
+        file,
         __0,
         __1,
         __2,
-        __3,
-        __4,
+        __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action145<
-    'input,
+fn __action205<
 >(
-    input: &'input str,
-    __0: (usize, &'input str, usize),
-    __1: (usize, Box<Exp<Span1>>, usize),
-    __2: (usize, &'input str, usize),
-    __3: (usize, Box<Exp<Span1>>, usize),
-    __4: (usize, &'input str, usize),
-    __5: (usize, Vec<(SnakeType, Vec<String>, Exp<Span1>)>, usize),
-    __6: (usize, &'input str, usize),
-    __7: (usize, usize, usize),
+    file: FileId,
+    __0: (usize, String, usize),
+    __1: (usize, Tok, usize),
+    __2: (usize, Box<Exp<Span1>>, usize),
 ) -> Exp<Span1>
 {
-    let __start0 = __0.0.clone();
-    let __end0 = __0.0.clone();
-    let __temp0 = __action91(
-        input,
+    let __start0 = __2.2.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action109(
+        file,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action12(
-        input,
-        __temp0,
+    __action172(
+        file,
         __0,
         __1,
         __2,
-        __3,
-        __4,
-        __5,
-        __6,
-        __7,
+        __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action146<
-    'input,
+fn __action206<
 >(
-    input: &'input str,
-    __0: (usize, &'input str, usize),
-    __1: (usize, Box<Exp<Span1>>, usize),
-    __2: (usize, usize, usize),
+    file: FileId,
+    __0: (usize, SurfFunDecl<Span1>, usize),
+    __1: (usize, Tok, usize),
+    __2: (usize, Box<Exp<Span1>>, usize),
 ) -> Exp<Span1>
 {
-    let __start0 = __0.0.clone();
-    let __end0 = __0.0.clone();
-    let __temp0 = __action91(
-        input,
+    let __start0 = __2.2.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action109(
+        file,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action33(
-        input,
-        __temp0,
+    __action173(
+        file,
         __0,
         __1,
         __2,
+        __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action147<
-    'input,
+fn __action207<
 >(
-    input: &'input str,
-    __0: (usize, Box<Exp<Span1>>, usize),
-    __1: (usize, Prim2, usize),
-    __2: (usize, Box<Exp<Span1>>, usize),
-    __3: (usize, usize, usize),
+    file: FileId,
+    __0: (usize, alloc::vec::Vec<(SurfFunDecl<Span1>, Tok)>, usize),
+    __1: (usize, SurfFunDecl<Span1>, usize),
+    __2: (usize, Tok, usize),
+    __3: (usize, Box<Exp<Span1>>, usize),
 ) -> Exp<Span1>
 {
-    let __start0 = __0.0.clone();
-    let __end0 = __0.0.clone();
-    let __temp0 = __action91(
-        input,
+    let __start0 = __3.2.clone();
+    let __end0 = __3.2.clone();
+    let __temp0 = __action109(
+        file,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action83(
-        input,
-        __temp0,
+    __action174(
+        file,
         __0,
         __1,
         __2,
         __3,
+        __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action148<
-    'input,
+fn __action208<
 >(
-    input: &'input str,
-    __0: (usize, Exp<Span1>, usize),
-    __1: (usize, &'input str, usize),
-    __2: (usize, Exp<Span1>, usize),
-    __3: (usize, usize, usize),
-) -> Exp<Span1>
+    file: FileId,
+    __0: (usize, core::option::Option<String>, usize),
+    __1: (usize, Tok, usize),
+    __2: (usize, String, usize),
+    __3: (usize, Tok, usize),
+    __4: (usize, Vec<String>, usize),
+    __5: (usize, Tok, usize),
+    __6: (usize, Tok, usize),
+    __7: (usize, Exp<Span1>, usize),
+) -> SurfFunDecl<Span1>
 {
-    let __start0 = __0.0.clone();
-    let __end0 = __0.0.clone();
-    let __temp0 = __action91(
-        input,
+    let __start0 = __7.2.clone();
+    let __end0 = __7.2.clone();
+    let __temp0 = __action109(
+        file,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action15(
-        input,
-        __temp0,
+    __action175(
+        file,
         __0,
         __1,
         __2,
         __3,
+        __4,
+        __5,
+        __6,
+        __7,
+        __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action149<
-    'input,
+fn __action209<
 >(
-    input: &'input str,
-    __0: (usize, &'input str, usize),
-    __1: (usize, Vec<(String, Vec<String>)>, usize),
-    __2: (usize, &'input str, usize),
+    file: FileId,
+    __0: (usize, Tok, usize),
+    __1: (usize, Box<Exp<Span1>>, usize),
+    __2: (usize, Tok, usize),
     __3: (usize, Box<Exp<Span1>>, usize),
-    __4: (usize, usize, usize),
+    __4: (usize, Tok, usize),
+    __5: (usize, Tok, usize),
+    __6: (usize, Box<Exp<Span1>>, usize),
 ) -> Exp<Span1>
 {
-    let __start0 = __0.0.clone();
-    let __end0 = __0.0.clone();
-    let __temp0 = __action91(
-        input,
+    let __start0 = __6.2.clone();
+    let __end0 = __6.2.clone();
+    let __temp0 = __action109(
+        file,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action11(
-        input,
-        __temp0,
+    __action176(
+        file,
         __0,
         __1,
         __2,
         __3,
         __4,
+        __5,
+        __6,
+        __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action150<
-    'input,
+fn __action210<
 >(
-    input: &'input str,
+    file: FileId,
     __0: (usize, Box<Exp<Span1>>, usize),
-    __1: (usize, &'input str, usize),
+    __1: (usize, Prim2, usize),
     __2: (usize, Box<Exp<Span1>>, usize),
-    __3: (usize, &'input str, usize),
 ) -> Exp<Span1>
 {
-    let __start0 = __3.2.clone();
-    let __end0 = __3.2.clone();
-    let __temp0 = __action86(
-        input,
+    let __start0 = __2.2.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action109(
+        file,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action128(
-        input,
+    __action177(
+        file,
         __0,
         __1,
         __2,
-        __3,
         __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action151<
-    'input,
+fn __action211<
 >(
-    input: &'input str,
+    file: FileId,
     __0: (usize, Box<Exp<Span1>>, usize),
-    __1: (usize, &'input str, usize),
+    __1: (usize, Prim2, usize),
     __2: (usize, Box<Exp<Span1>>, usize),
-    __3: (usize, &'input str, usize),
-    __4: (usize, &'input str, usize),
-    __5: (usize, Box<Exp<Span1>>, usize),
 ) -> Exp<Span1>
 {
-    let __start0 = __5.2.clone();
-    let __end0 = __5.2.clone();
-    let __temp0 = __action86(
-        input,
+    let __start0 = __2.2.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action109(
+        file,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action129(
-        input,
+    __action178(
+        file,
         __0,
         __1,
         __2,
-        __3,
-        __4,
-        __5,
         __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action152<
-    'input,
+fn __action212<
 >(
-    input: &'input str,
-    __0: (usize, String, usize),
+    file: FileId,
+    __0: (usize, Box<Exp<Span1>>, usize),
+    __1: (usize, Prim2, usize),
+    __2: (usize, Box<Exp<Span1>>, usize),
 ) -> Exp<Span1>
 {
-    let __start0 = __0.2.clone();
-    let __end0 = __0.2.clone();
-    let __temp0 = __action86(
-        input,
+    let __start0 = __2.2.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action109(
+        file,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action130(
-        input,
+    __action179(
+        file,
         __0,
+        __1,
+        __2,
         __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action153<
-    'input,
+fn __action213<
 >(
-    input: &'input str,
-    __0: (usize, bool, usize),
+    file: FileId,
+    __0: (usize, Tok, usize),
+    __1: (usize, Vec<(String, Exp<Span1>)>, usize),
+    __2: (usize, Tok, usize),
+    __3: (usize, Box<Exp<Span1>>, usize),
 ) -> Exp<Span1>
 {
-    let __start0 = __0.2.clone();
-    let __end0 = __0.2.clone();
-    let __temp0 = __action86(
-        input,
+    let __start0 = __3.2.clone();
+    let __end0 = __3.2.clone();
+    let __temp0 = __action109(
+        file,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action131(
-        input,
+    __action180(
+        file,
         __0,
+        __1,
+        __2,
+        __3,
         __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action154<
-    'input,
+fn __action214<
 >(
-    input: &'input str,
-    __0: (usize, i64, usize),
+    file: FileId,
+    __0: (usize, Tok, usize),
+    __1: (usize, Box<Exp<Span1>>, usize),
+    __2: (usize, Tok, usize),
+    __3: (usize, Box<Exp<Span1>>, usize),
+    __4: (usize, Tok, usize),
+    __5: (usize, Vec<(SnakeType, Vec<String>, Exp<Span1>)>, usize),
+    __6: (usize, Tok, usize),
 ) -> Exp<Span1>
 {
-    let __start0 = __0.2.clone();
-    let __end0 = __0.2.clone();
-    let __temp0 = __action86(
-        input,
+    let __start0 = __6.2.clone();
+    let __end0 = __6.2.clone();
+    let __temp0 = __action109(
+        file,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action132(
-        input,
+    __action181(
+        file,
         __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
         __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action155<
-    'input,
+fn __action215<
 >(
-    input: &'input str,
-    __0: (usize, Prim1, usize),
-    __1: (usize, &'input str, usize),
-    __2: (usize, Box<Exp<Span1>>, usize),
-    __3: (usize, &'input str, usize),
+    file: FileId,
+    __0: (usize, Tok, usize),
+    __1: (usize, Box<Exp<Span1>>, usize),
 ) -> Exp<Span1>
 {
-    let __start0 = __3.2.clone();
-    let __end0 = __3.2.clone();
-    let __temp0 = __action86(
-        input,
+    let __start0 = __1.2.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action109(
+        file,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action133(
-        input,
+    __action182(
+        file,
         __0,
         __1,
-        __2,
-        __3,
         __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action156<
-    'input,
+fn __action216<
 >(
-    input: &'input str,
-    __0: (usize, Box<Exp<Span1>>, usize),
-    __1: (usize, &'input str, usize),
-    __2: (usize, Vec<Exp<Span1>>, usize),
-    __3: (usize, &'input str, usize),
+    file: FileId,
+    __0: (usize, Tok, usize),
+    __1: (usize, Box<Exp<Span1>>, usize),
 ) -> Exp<Span1>
 {
-    let __start0 = __3.2.clone();
-    let __end0 = __3.2.clone();
-    let __temp0 = __action86(
-        input,
+    let __start0 = __1.2.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action109(
+        file,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action134(
-        input,
+    __action183(
+        file,
         __0,
         __1,
-        __2,
-        __3,
         __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action157<
-    'input,
+fn __action217<
 >(
-    input: &'input str,
-    __0: (usize, (), usize),
-    __1: (usize, Vec<String>, usize),
-    __2: (usize, &'input str, usize),
-    __3: (usize, Box<Exp<Span1>>, usize),
-    __4: (usize, &'input str, usize),
-) -> Exp<Span1>
+    file: FileId,
+    __0: (usize, Tok, usize),
+    __1: (usize, String, usize),
+    __2: (usize, Box<SurfProg<Span1>>, usize),
+) -> SurfProg<Span1>
 {
-    let __start0 = __4.2.clone();
-    let __end0 = __4.2.clone();
-    let __temp0 = __action86(
-        input,
+    let __start0 = __1.2.clone();
+    let __end0 = __2.0.clone();
+    let __temp0 = __action109(
+        file,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action135(
-        input,
+    __action184(
+        file,
         __0,
         __1,
-        __2,
-        __3,
-        __4,
         __temp0,
+        __2,
     )
 }
 
 #[allow(unused_variables)]
-fn __action158<
-    'input,
+fn __action218<
 >(
-    input: &'input str,
-    __0: (usize, &'input str, usize),
-    __1: (usize, Vec<Exp<Span1>>, usize),
-    __2: (usize, &'input str, usize),
+    file: FileId,
+    __0: (usize, Box<Exp<Span1>>, usize),
+    __1: (usize, Prim2, usize),
+    __2: (usize, Box<Exp<Span1>>, usize),
 ) -> Exp<Span1>
 {
     let __start0 = __2.2.clone();
     let __end0 = __2.2.clone();
-    let __temp0 = __action86(
-        input,
+    let __temp0 = __action109(
+        file,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action136(
-        input,
+    __action185(
+        file,
         __0,
         __1,
         __2,
@@ -6868,25 +8762,24 @@ fn __action158<
 }
 
 #[allow(unused_variables)]
-fn __action159<
-    'input,
+fn __action219<
 >(
-    input: &'input str,
-    __0: (usize, SurfFunDecl<Span1>, usize),
-    __1: (usize, &'input str, usize),
-    __2: (usize, Box<Exp<Span1>>, usize),
+    file: FileId,
+    __0: (usize, Exp<Span1>, usize),
+    __1: (usize, Tok, usize),
+    __2: (usize, Exp<Span1>, usize),
 ) -> Exp<Span1>
 {
     let __start0 = __2.2.clone();
     let __end0 = __2.2.clone();
-    let __temp0 = __action86(
-        input,
+    let __temp0 = __action109(
+        file,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action137(
-        input,
+    __action186(
+        file,
         __0,
         __1,
         __2,
@@ -6895,551 +8788,519 @@ fn __action159<
 }
 
 #[allow(unused_variables)]
-fn __action160<
-    'input,
+fn __action220<
 >(
-    input: &'input str,
-    __0: (usize, alloc::vec::Vec<(SurfFunDecl<Span1>, &'input str)>, usize),
-    __1: (usize, SurfFunDecl<Span1>, usize),
-    __2: (usize, &'input str, usize),
-    __3: (usize, Box<Exp<Span1>>, usize),
+    file: FileId,
+    __0: (usize, Tok, usize),
+    __1: (usize, Box<Exp<Span1>>, usize),
+    __2: (usize, Tok, usize),
+    __3: (usize, Vec<(SnakeType, Vec<String>, Exp<Span1>)>, usize),
+    __4: (usize, Tok, usize),
 ) -> Exp<Span1>
 {
-    let __start0 = __3.2.clone();
-    let __end0 = __3.2.clone();
-    let __temp0 = __action86(
-        input,
+    let __start0 = __4.2.clone();
+    let __end0 = __4.2.clone();
+    let __temp0 = __action109(
+        file,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action138(
-        input,
+    __action187(
+        file,
         __0,
         __1,
         __2,
         __3,
+        __4,
         __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action161<
-    'input,
+fn __action221<
 >(
-    input: &'input str,
-    __0: (usize, &'input str, usize),
-    __1: (usize, String, usize),
-    __2: (usize, &'input str, usize),
-    __3: (usize, Vec<String>, usize),
-    __4: (usize, &'input str, usize),
-    __5: (usize, &'input str, usize),
-    __6: (usize, Exp<Span1>, usize),
-) -> SurfFunDecl<Span1>
+    file: FileId,
+    __0: (usize, core::option::Option<String>, usize),
+    __1: (usize, Tok, usize),
+    __2: (usize, Vec<(String, Vec<String>)>, usize),
+    __3: (usize, Tok, usize),
+    __4: (usize, Box<Exp<Span1>>, usize),
+) -> Exp<Span1>
 {
-    let __start0 = __6.2.clone();
-    let __end0 = __6.2.clone();
-    let __temp0 = __action86(
-        input,
+    let __start0 = __4.2.clone();
+    let __end0 = __4.2.clone();
+    let __temp0 = __action109(
+        file,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action139(
-        input,
+    __action188(
+        file,
         __0,
         __1,
         __2,
         __3,
         __4,
-        __5,
-        __6,
         __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action162<
-    'input,
+fn __action222<
 >(
-    input: &'input str,
-    __0: (usize, &'input str, usize),
-    __1: (usize, Box<Exp<Span1>>, usize),
-    __2: (usize, &'input str, usize),
-    __3: (usize, Box<Exp<Span1>>, usize),
-    __4: (usize, &'input str, usize),
-    __5: (usize, &'input str, usize),
-    __6: (usize, Box<Exp<Span1>>, usize),
+    file: FileId,
+    __0: (usize, Box<Exp<Span1>>, usize),
+    __1: (usize, Tok, usize),
+    __2: (usize, String, usize),
+    __3: (usize, Tok, usize),
+    __4: (usize, Box<Exp<Span1>>, usize),
 ) -> Exp<Span1>
 {
-    let __start0 = __6.2.clone();
-    let __end0 = __6.2.clone();
-    let __temp0 = __action86(
-        input,
+    let __start0 = __4.2.clone();
+    let __end0 = __4.2.clone();
+    let __temp0 = __action109(
+        file,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action140(
-        input,
+    __action189(
+        file,
         __0,
         __1,
         __2,
         __3,
         __4,
-        __5,
-        __6,
         __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action163<
-    'input,
+fn __action223<
 >(
-    input: &'input str,
-    __0: (usize, Box<Exp<Span1>>, usize),
-    __1: (usize, Prim2, usize),
-    __2: (usize, Box<Exp<Span1>>, usize),
+    file: FileId,
+    __0: (usize, Tok, usize),
+    __1: (usize, Vec<(String, Exp<Span1>)>, usize),
+    __2: (usize, Tok, usize),
+    __3: (usize, Box<Exp<Span1>>, usize),
 ) -> Exp<Span1>
 {
-    let __start0 = __2.2.clone();
-    let __end0 = __2.2.clone();
-    let __temp0 = __action86(
-        input,
+    let __start0 = __3.2.clone();
+    let __end0 = __3.2.clone();
+    let __temp0 = __action109(
+        file,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action141(
-        input,
+    __action190(
+        file,
         __0,
         __1,
         __2,
+        __3,
         __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action164<
-    'input,
+fn __action224<
 >(
-    input: &'input str,
-    __0: (usize, Box<Exp<Span1>>, usize),
-    __1: (usize, Prim2, usize),
-    __2: (usize, Box<Exp<Span1>>, usize),
-) -> Exp<Span1>
+    file: FileId,
+    __0: (usize, String, usize),
+    __1: (usize, Tok, usize),
+    __2: (usize, String, usize),
+    __3: (usize, Tok, usize),
+    __4: (usize, Vec<String>, usize),
+    __5: (usize, Tok, usize),
+    __6: (usize, Tok, usize),
+    __7: (usize, Exp<Span1>, usize),
+) -> SurfFunDecl<Span1>
 {
-    let __start0 = __2.2.clone();
-    let __end0 = __2.2.clone();
-    let __temp0 = __action86(
-        input,
-        &__start0,
-        &__end0,
+    let __start0 = __0.0.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action101(
+        file,
+        __0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action142(
-        input,
-        __0,
+    __action208(
+        file,
+        __temp0,
         __1,
         __2,
-        __temp0,
+        __3,
+        __4,
+        __5,
+        __6,
+        __7,
     )
 }
 
 #[allow(unused_variables)]
-fn __action165<
-    'input,
+fn __action225<
 >(
-    input: &'input str,
-    __0: (usize, Box<Exp<Span1>>, usize),
-    __1: (usize, Prim2, usize),
-    __2: (usize, Box<Exp<Span1>>, usize),
-) -> Exp<Span1>
+    file: FileId,
+    __0: (usize, Tok, usize),
+    __1: (usize, String, usize),
+    __2: (usize, Tok, usize),
+    __3: (usize, Vec<String>, usize),
+    __4: (usize, Tok, usize),
+    __5: (usize, Tok, usize),
+    __6: (usize, Exp<Span1>, usize),
+) -> SurfFunDecl<Span1>
 {
-    let __start0 = __2.2.clone();
-    let __end0 = __2.2.clone();
-    let __temp0 = __action86(
-        input,
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action102(
+        file,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action143(
-        input,
+    __action208(
+        file,
+        __temp0,
         __0,
         __1,
         __2,
-        __temp0,
+        __3,
+        __4,
+        __5,
+        __6,
     )
 }
 
 #[allow(unused_variables)]
-fn __action166<
-    'input,
+fn __action226<
 >(
-    input: &'input str,
-    __0: (usize, &'input str, usize),
-    __1: (usize, Vec<(String, Exp<Span1>)>, usize),
-    __2: (usize, &'input str, usize),
-    __3: (usize, Box<Exp<Span1>>, usize),
+    file: FileId,
+    __0: (usize, String, usize),
+    __1: (usize, Tok, usize),
+    __2: (usize, Vec<(String, Vec<String>)>, usize),
+    __3: (usize, Tok, usize),
+    __4: (usize, Box<Exp<Span1>>, usize),
 ) -> Exp<Span1>
 {
-    let __start0 = __3.2.clone();
-    let __end0 = __3.2.clone();
-    let __temp0 = __action86(
-        input,
-        &__start0,
-        &__end0,
+    let __start0 = __0.0.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action101(
+        file,
+        __0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action144(
-        input,
-        __0,
+    __action221(
+        file,
+        __temp0,
         __1,
         __2,
         __3,
-        __temp0,
+        __4,
     )
 }
 
 #[allow(unused_variables)]
-fn __action167<
-    'input,
+fn __action227<
 >(
-    input: &'input str,
-    __0: (usize, &'input str, usize),
-    __1: (usize, Box<Exp<Span1>>, usize),
-    __2: (usize, &'input str, usize),
+    file: FileId,
+    __0: (usize, Tok, usize),
+    __1: (usize, Vec<(String, Vec<String>)>, usize),
+    __2: (usize, Tok, usize),
     __3: (usize, Box<Exp<Span1>>, usize),
-    __4: (usize, &'input str, usize),
-    __5: (usize, Vec<(SnakeType, Vec<String>, Exp<Span1>)>, usize),
-    __6: (usize, &'input str, usize),
 ) -> Exp<Span1>
 {
-    let __start0 = __6.2.clone();
-    let __end0 = __6.2.clone();
-    let __temp0 = __action86(
-        input,
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action102(
+        file,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action145(
-        input,
+    __action221(
+        file,
+        __temp0,
         __0,
         __1,
         __2,
         __3,
-        __4,
-        __5,
-        __6,
-        __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action168<
-    'input,
+fn __action228<
 >(
-    input: &'input str,
-    __0: (usize, &'input str, usize),
-    __1: (usize, Box<Exp<Span1>>, usize),
-) -> Exp<Span1>
+    file: FileId,
+    __0: (usize, Exp<Span1>, usize),
+) -> Vec<Exp<Span1>>
 {
-    let __start0 = __1.2.clone();
-    let __end0 = __1.2.clone();
-    let __temp0 = __action86(
-        input,
-        &__start0,
-        &__end0,
+    let __start0 = __0.0.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action126(
+        file,
+        __0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action146(
-        input,
-        __0,
-        __1,
+    __action144(
+        file,
         __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action169<
-    'input,
+fn __action229<
 >(
-    input: &'input str,
-    __0: (usize, Box<Exp<Span1>>, usize),
-    __1: (usize, Prim2, usize),
-    __2: (usize, Box<Exp<Span1>>, usize),
-) -> Exp<Span1>
+    file: FileId,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> Vec<Exp<Span1>>
 {
-    let __start0 = __2.2.clone();
-    let __end0 = __2.2.clone();
-    let __temp0 = __action86(
-        input,
+    let __start0 = __lookbehind.clone();
+    let __end0 = __lookahead.clone();
+    let __temp0 = __action127(
+        file,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action147(
-        input,
-        __0,
-        __1,
-        __2,
+    __action144(
+        file,
         __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action170<
-    'input,
+fn __action230<
 >(
-    input: &'input str,
-    __0: (usize, Exp<Span1>, usize),
-    __1: (usize, &'input str, usize),
-    __2: (usize, Exp<Span1>, usize),
-) -> Exp<Span1>
+    file: FileId,
+    __0: (usize, alloc::vec::Vec<Exp<Span1>>, usize),
+    __1: (usize, Exp<Span1>, usize),
+) -> Vec<Exp<Span1>>
 {
-    let __start0 = __2.2.clone();
-    let __end0 = __2.2.clone();
-    let __temp0 = __action86(
-        input,
-        &__start0,
-        &__end0,
+    let __start0 = __1.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action126(
+        file,
+        __1,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action148(
-        input,
+    __action145(
+        file,
         __0,
-        __1,
-        __2,
         __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action171<
-    'input,
+fn __action231<
 >(
-    input: &'input str,
-    __0: (usize, &'input str, usize),
-    __1: (usize, Vec<(String, Vec<String>)>, usize),
-    __2: (usize, &'input str, usize),
-    __3: (usize, Box<Exp<Span1>>, usize),
-) -> Exp<Span1>
+    file: FileId,
+    __0: (usize, alloc::vec::Vec<Exp<Span1>>, usize),
+) -> Vec<Exp<Span1>>
 {
-    let __start0 = __3.2.clone();
-    let __end0 = __3.2.clone();
-    let __temp0 = __action86(
-        input,
+    let __start0 = __0.2.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action127(
+        file,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action149(
-        input,
+    __action145(
+        file,
         __0,
-        __1,
-        __2,
-        __3,
         __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action172<
-    'input,
+fn __action232<
 >(
-    input: &'input str,
-    __0: (usize, Exp<Span1>, usize),
-) -> Vec<Exp<Span1>>
+    file: FileId,
+    __0: (usize, (String, String), usize),
+) -> Vec<(String, String)>
 {
     let __start0 = __0.0.clone();
     let __end0 = __0.2.clone();
-    let __temp0 = __action107(
-        input,
+    let __temp0 = __action131(
+        file,
         __0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action118(
-        input,
+    __action148(
+        file,
         __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action173<
-    'input,
+fn __action233<
 >(
-    input: &'input str,
+    file: FileId,
     __lookbehind: &usize,
     __lookahead: &usize,
-) -> Vec<Exp<Span1>>
+) -> Vec<(String, String)>
 {
     let __start0 = __lookbehind.clone();
     let __end0 = __lookahead.clone();
-    let __temp0 = __action108(
-        input,
+    let __temp0 = __action132(
+        file,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action118(
-        input,
+    __action148(
+        file,
         __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action174<
-    'input,
+fn __action234<
 >(
-    input: &'input str,
-    __0: (usize, alloc::vec::Vec<Exp<Span1>>, usize),
-    __1: (usize, Exp<Span1>, usize),
-) -> Vec<Exp<Span1>>
+    file: FileId,
+    __0: (usize, alloc::vec::Vec<(String, String)>, usize),
+    __1: (usize, (String, String), usize),
+) -> Vec<(String, String)>
 {
     let __start0 = __1.0.clone();
     let __end0 = __1.2.clone();
-    let __temp0 = __action107(
-        input,
+    let __temp0 = __action131(
+        file,
         __1,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action119(
-        input,
+    __action149(
+        file,
         __0,
         __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action175<
-    'input,
+fn __action235<
 >(
-    input: &'input str,
-    __0: (usize, alloc::vec::Vec<Exp<Span1>>, usize),
-) -> Vec<Exp<Span1>>
+    file: FileId,
+    __0: (usize, alloc::vec::Vec<(String, String)>, usize),
+) -> Vec<(String, String)>
 {
     let __start0 = __0.2.clone();
     let __end0 = __0.2.clone();
-    let __temp0 = __action108(
-        input,
+    let __temp0 = __action132(
+        file,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action119(
-        input,
+    __action149(
+        file,
         __0,
         __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action176<
-    'input,
+fn __action236<
 >(
-    input: &'input str,
+    file: FileId,
     __0: (usize, String, usize),
 ) -> Vec<String>
 {
     let __start0 = __0.0.clone();
     let __end0 = __0.2.clone();
-    let __temp0 = __action94(
-        input,
+    let __temp0 = __action113(
+        file,
         __0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action122(
-        input,
+    __action152(
+        file,
         __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action177<
-    'input,
+fn __action237<
 >(
-    input: &'input str,
+    file: FileId,
     __lookbehind: &usize,
     __lookahead: &usize,
 ) -> Vec<String>
 {
     let __start0 = __lookbehind.clone();
     let __end0 = __lookahead.clone();
-    let __temp0 = __action95(
-        input,
+    let __temp0 = __action114(
+        file,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action122(
-        input,
+    __action152(
+        file,
         __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action178<
-    'input,
+fn __action238<
 >(
-    input: &'input str,
+    file: FileId,
     __0: (usize, alloc::vec::Vec<String>, usize),
     __1: (usize, String, usize),
 ) -> Vec<String>
 {
     let __start0 = __1.0.clone();
     let __end0 = __1.2.clone();
-    let __temp0 = __action94(
-        input,
+    let __temp0 = __action113(
+        file,
         __1,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action123(
-        input,
+    __action153(
+        file,
         __0,
         __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action179<
-    'input,
+fn __action239<
 >(
-    input: &'input str,
+    file: FileId,
     __0: (usize, alloc::vec::Vec<String>, usize),
 ) -> Vec<String>
 {
     let __start0 = __0.2.clone();
     let __end0 = __0.2.clone();
-    let __temp0 = __action95(
-        input,
+    let __temp0 = __action114(
+        file,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action123(
-        input,
+    __action153(
+        file,
         __0,
         __temp0,
     )
 }
 
-pub trait __ToTriple<'input, >
+pub trait __ToTriple<>
 {
-    fn to_triple(value: Self) -> Result<(usize,Token<'input>,usize), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>;
+    fn to_triple(value: Self) -> Result<(usize,Tok,usize), __lalrpop_util::ParseError<usize, Tok, LexError>>;
 }
 
-impl<'input, > __ToTriple<'input, > for (usize, Token<'input>, usize)
+impl<> __ToTriple<> for (usize, Tok, usize)
 {
-    fn to_triple(value: Self) -> Result<(usize,Token<'input>,usize), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>> {
+    fn to_triple(value: Self) -> Result<(usize,Tok,usize), __lalrpop_util::ParseError<usize, Tok, LexError>> {
         Ok(value)
     }
 }
-impl<'input, > __ToTriple<'input, > for Result<(usize, Token<'input>, usize), &'static str>
+impl<> __ToTriple<> for Result<(usize, Tok, usize), LexError>
 {
-    fn to_triple(value: Self) -> Result<(usize,Token<'input>,usize), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>> {
+    fn to_triple(value: Self) -> Result<(usize,Tok,usize), __lalrpop_util::ParseError<usize, Tok, LexError>> {
         match value {
             Ok(v) => Ok(v),
             Err(error) => Err(__lalrpop_util::ParseError::User { error }),
@@ -1,11 +1,14 @@
 use crate::errors::CompileErr;
-use crate::syntax::{Exp, SnakeType, SurfProg};
+use crate::syntax::{Exp, SnakeType, SurfProg, ERROR_TYPES};
 use std::collections::{HashMap, HashSet};
 
 #[derive(Clone)]
-enum NameType {
-    Type(usize),
+enum NameType<'exp> {
+    // a custom type, with the names of its fields
+    Type(&'exp [String]),
     Var,
+    // a variable bound by `var`, which can be assigned to
+    Mutable,
 }
 
 #[derive(Clone)]
@@ -30,13 +33,127 @@ where
             SnakeType::Bool => "Bool",
             SnakeType::Func => "Func",
             SnakeType::Num => "Num",
-            SnakeType::Custom(custom_type) => custom_type.as_str(),
+            SnakeType::Custom(custom_type) | SnakeType::CustomFields(custom_type, _) => {
+                custom_type.as_str()
+            }
+        }
+    }
+
+    // Checks the arms of a `match` or `try`
+    fn check_arms<'exp, Span>(
+        arms: &'exp [(SnakeType, Vec<String>, Exp<Span>)],
+        env: &HashMap<&'exp str, NameType<'exp>>,
+        ann: &Span,
+    ) -> Result<(), CompileErr<Span>>
+    where
+        Span: Clone,
+    {
+        let mut seen_types: HashSet<&str> = HashSet::new();
+        for (type_used, type_args, arm_exp) in arms {
+            let type_name = get_type_name(type_used);
+
+            // the builtin error types and primitive types have no field names
+            let (arity, fields): (Option<usize>, &[String]) = match env.get(type_name) {
+                Some(NameType::Var | NameType::Mutable) => {
+                    return Err(CompileErr::UndefinedType {
+                        undefined_type: String::from(type_name),
+                        location: ann.clone(),
+                    });
+                }
+                Some(NameType::Type(fields)) => (Some(fields.len()), fields),
+                None if PRIM_TYPES.contains(&type_name) => (None, &[]),
+                // the builtin error types, unless the program declares a
+                // type with the same name
+                None => match ERROR_TYPES.iter().find(|(error, _)| *error == type_name) {
+                    Some((_, arity)) => (Some(*arity), &[]),
+                    None => {
+                        return Err(CompileErr::UndefinedType {
+                            undefined_type: String::from(type_name),
+                            location: ann.clone(),
+                        });
+                    }
+                },
+            };
+            if let Some(arity) = arity {
+                if seen_types.contains(type_name) {
+                    return Err(CompileErr::DuplicateMatchArms {
+                        type_used: String::from(type_name),
+                        location: ann.clone(),
+                    });
+                }
+                if arity != type_args.len() && !matches!(type_used, SnakeType::CustomFields(..)) {
+                    return Err(CompileErr::WrongTypeArity {
+                        type_used: String::from(type_name),
+                        expected_arity: arity,
+                        given_arity: type_args.len(),
+                        location: ann.clone(),
+                    });
+                }
+                seen_types.insert(type_name);
+            }
+            // an arm binding fields by name can bind any of them
+            if let SnakeType::CustomFields(_, names) = type_used {
+                if !matches!(env.get(type_name), Some(NameType::Type(_))) {
+                    return Err(CompileErr::NoFieldNames {
+                        type_used: String::from(type_name),
+                        location: ann.clone(),
+                    });
+                }
+                if let Some(name) = names.iter().find(|name| !fields.contains(name)) {
+                    return Err(CompileErr::FieldNotInType {
+                        field: name.clone(),
+                        location: ann.clone(),
+                    });
+                }
+            }
+
+            let mut seen_args: HashSet<String> = HashSet::new();
+            for arg in type_args {
+                if seen_args.contains(arg.as_str()) {
+                    return Err(CompileErr::DuplicateMatchArmArguments {
+                        type_used: arg.clone(),
+                        location: ann.clone(),
+                    });
+                }
+                seen_args.insert(arg.clone());
+            }
+
+            let mut local_env = env.clone();
+            for arg in type_args {
+                local_env.insert(arg, NameType::Var);
+            }
+            check_prog_help(arm_exp, local_env, CtorArg::NoField)?;
+        }
+
+        Ok(())
+    }
+
+    // Checks that a type in scope has a field named `field`
+    fn check_field<Span>(
+        field: &str,
+        env: &HashMap<&str, NameType>,
+        ann: &Span,
+    ) -> Result<(), CompileErr<Span>>
+    where
+        Span: Clone,
+    {
+        let declared = env.values().any(|name_type| match name_type {
+            NameType::Type(fields) => fields.iter().any(|f| f == field),
+            NameType::Var | NameType::Mutable => false,
+        });
+        if declared {
+            Ok(())
+        } else {
+            Err(CompileErr::UndefinedField {
+                field: field.to_string(),
+                location: ann.clone(),
+            })
         }
     }
 
     fn check_prog_help<'exp, Span>(
         expr: &'exp Exp<Span>,
-        mut env: HashMap<&'exp str, NameType>,
+        mut env: HashMap<&'exp str, NameType<'exp>>,
         type_args: CtorArg,
     ) -> Result<(), CompileErr<Span>>
     where
@@ -53,21 +170,21 @@ where
                     Ok(())
                 }
             }
-            Exp::Bool(_, _) => Ok(()),
+            Exp::Bool(_, _) | Exp::Prim0(_, _) => Ok(()),
             Exp::Var(name, ann) => match env.get(name.as_str()) {
                 None => Err(CompileErr::UnboundVariable {
                     unbound: name.clone(),
                     location: ann.clone(),
                 }),
                 Some(var) => match var {
-                    NameType::Var => Ok(()),
-                    NameType::Type(arity) => match type_args {
+                    NameType::Var | NameType::Mutable => Ok(()),
+                    NameType::Type(fields) => match type_args {
                         CtorArg::InvalidCall => Err(CompileErr::WrongTypeCall {
                             type_used: name.clone(),
                             location: ann.clone(),
                         }),
                         CtorArg::Fields(num_args) => {
-                            if num_args != *arity {
+                            if num_args != fields.len() {
                                 Err(CompileErr::WrongTypeCall {
                                     type_used: name.clone(),
                                     location: ann.clone(),
@@ -77,7 +194,7 @@ where
                             }
                         }
                         CtorArg::NoField => {
-                            if *arity != 0 {
+                            if !fields.is_empty() {
                                 Err(CompileErr::WrongTypeCall {
                                     type_used: name.clone(),
                                     location: ann.clone(),
@@ -120,6 +237,52 @@ where
                 }
                 check_prog_help(body, env, CtorArg::NoField)
             }
+            Exp::LetVar {
+                bindings,
+                body,
+                ann: span,
+            } => {
+                let mut local_env: HashSet<&str> = HashSet::new();
+                for (name, expr) in bindings {
+                    if PRIM_TYPES.contains(&name.as_str()) {
+                        return Err(CompileErr::ShadowPrimType {
+                            primitive_type: name.clone(),
+                            location: span.clone(),
+                        });
+                    }
+                    if !local_env.insert(name.as_str()) {
+                        return Err(CompileErr::DuplicateBinding {
+                            duplicated_name: name.clone(),
+                            location: span.clone(),
+                        });
+                    }
+                    check_prog_help(expr, env.clone(), CtorArg::NoField)?;
+                    env.insert(name.as_str(), NameType::Mutable);
+                }
+                check_prog_help(body, env, CtorArg::NoField)
+            }
+            Exp::Assign {
+                var,
+                new_value,
+                ann,
+            } => {
+                match env.get(var.as_str()) {
+                    Some(NameType::Mutable) => (),
+                    Some(_) => {
+                        return Err(CompileErr::AssignToImmutable {
+                            name: var.clone(),
+                            location: ann.clone(),
+                        })
+                    }
+                    None => {
+                        return Err(CompileErr::UnboundVariable {
+                            unbound: var.clone(),
+                            location: ann.clone(),
+                        })
+                    }
+                }
+                check_prog_help(new_value, env, CtorArg::NoField)
+            }
             Exp::If {
                 cond,
                 thn,
@@ -150,6 +313,28 @@ where
                 check_prog_help(e1, env.clone(), CtorArg::NoField)?;
                 check_prog_help(e2, env.clone(), CtorArg::NoField)
             }
+            Exp::While { cond, body, ann: _ } => {
+                check_prog_help(cond, env.clone(), CtorArg::NoField)?;
+                check_prog_help(body, env, CtorArg::NoField)
+            }
+            Exp::For {
+                var,
+                start,
+                end,
+                body,
+                ann,
+            } => {
+                if PRIM_TYPES.contains(&var.as_str()) {
+                    return Err(CompileErr::ShadowPrimType {
+                        primitive_type: var.clone(),
+                        location: ann.clone(),
+                    });
+                }
+                check_prog_help(start, env.clone(), CtorArg::NoField)?;
+                check_prog_help(end, env.clone(), CtorArg::NoField)?;
+                env.insert(var.as_str(), NameType::Var);
+                check_prog_help(body, env, CtorArg::NoField)
+            }
             Exp::FunDefs {
                 decls,
                 body,
@@ -255,7 +440,9 @@ where
                 env: _,
                 ann: _,
             } => unreachable!(),
-            Exp::TypeDefs { decls, body, ann } => {
+            Exp::TypeDefs {
+                decls, body, ann, ..
+            } => {
                 let mut seen_types: HashSet<&str> = HashSet::new();
 
                 for (name, args) in decls {
@@ -271,7 +458,16 @@ where
                         });
                     } else {
                         seen_types.insert(name);
-                        env.insert(name, NameType::Type(args.len()));
+                        env.insert(name, NameType::Type(args));
+                    }
+                    let mut seen_fields: HashSet<&str> = HashSet::new();
+                    for field in args {
+                        if !seen_fields.insert(field) {
+                            return Err(CompileErr::DuplicateFieldName {
+                                field: field.clone(),
+                                location: ann.clone(),
+                            });
+                        }
                     }
                 }
                 check_prog_help(body, env, CtorArg::NoField)
@@ -284,65 +480,26 @@ where
             } => {
                 check_prog_help(expr, env.clone(), CtorArg::NoField)?;
                 check_prog_help(default, env.clone(), CtorArg::NoField)?;
-
-                let mut seen_types: HashSet<&str> = HashSet::new();
-                for (type_used, type_args, arm_exp) in arms {
-                    let type_name = get_type_name(type_used);
-
-                    match env.get(type_name) {
-                        Some(var_type) => match var_type {
-                            NameType::Var => {
-                                return Err(CompileErr::UndefinedType {
-                                    undefined_type: String::from(type_name),
-                                    location: ann.clone(),
-                                });
-                            }
-                            NameType::Type(arity) => {
-                                if seen_types.contains(type_name) {
-                                    return Err(CompileErr::DuplicateMatchArms {
-                                        type_used: String::from(type_name),
-                                        location: ann.clone(),
-                                    });
-                                }
-                                if *arity != type_args.len() {
-                                    return Err(CompileErr::WrongTypeArity {
-                                        type_used: String::from(type_name),
-                                        expected_arity: *arity,
-                                        given_arity: type_args.len(),
-                                        location: ann.clone(),
-                                    });
-                                }
-                                seen_types.insert(type_name);
-                            }
-                        },
-                        None if PRIM_TYPES.contains(&type_name) => (),
-                        None => {
-                            return Err(CompileErr::UndefinedType {
-                                undefined_type: String::from(type_name),
-                                location: ann.clone(),
-                            });
-                        }
-                    };
-
-                    let mut seen_args: HashSet<String> = HashSet::new();
-                    for arg in type_args {
-                        if seen_args.contains(arg.as_str()) {
-                            return Err(CompileErr::DuplicateMatchArmArguments {
-                                type_used: arg.clone(),
-                                location: ann.clone(),
-                            });
-                        }
-                        seen_args.insert(arg.clone());
-                    }
-
-                    let mut local_env = env.clone();
-                    for arg in type_args {
-                        local_env.insert(arg, NameType::Var);
-                    }
-                    check_prog_help(arm_exp, local_env, CtorArg::NoField)?;
-                }
-
-                Ok(())
+                check_arms(arms, &env, ann)
+            }
+            Exp::GetField { exp, field, ann } => {
+                check_field(field, &env, ann)?;
+                check_prog_help(exp, env, CtorArg::NoField)
+            }
+            Exp::UpdateField {
+                exp,
+                field,
+                new_value,
+                ann,
+            } => {
+                check_field(field, &env, ann)?;
+                check_prog_help(exp, env.clone(), CtorArg::NoField)?;
+                check_prog_help(new_value, env, CtorArg::NoField)
+            }
+            Exp::Raise(e, _) => check_prog_help(e, env, CtorArg::NoField),
+            Exp::Try { body, arms, ann } => {
+                check_prog_help(body, env.clone(), CtorArg::NoField)?;
+                check_arms(arms, &env, ann)
             }
             Exp::MakeTypeInstance {
                 typetag: _,
@@ -354,10 +511,83 @@ where
                 typetag: _,
                 ann: _,
             } => unreachable!(),
-            Exp::GetTypeFields(_, _) => unreachable!(),
+            Exp::GetTypeFields(_, _) | Exp::Catch { .. } => unreachable!(),
+            // imports only come at the start of a file, and the modules
+            // around the whole program
+            Exp::Import { .. } | Exp::Modules { .. } => unreachable!(),
         }
     }
 
-    let env = HashMap::new();
-    check_prog_help(p, env, CtorArg::NoField)
+    // The names declared at the start of a file, which files importing it see
+    fn exported_names<Span>(mut expr: &Exp<Span>) -> HashMap<&str, NameType<'_>> {
+        let mut exports = HashMap::new();
+        loop {
+            match expr {
+                Exp::FunDefs { decls, body, .. } => {
+                    for decl in decls {
+                        exports.insert(decl.name.as_str(), NameType::Var);
+                    }
+                    expr = body;
+                }
+                Exp::TypeDefs { decls, body, .. } => {
+                    for (name, args) in decls {
+                        exports.insert(name.as_str(), NameType::Type(args));
+                    }
+                    expr = body;
+                }
+                _ => return exports,
+            }
+        }
+    }
+
+    // Checks one file with the names exported by the files it imports in
+    // scope, returning the names it exports in turn
+    fn check_file<'exp, Span>(
+        prog: &'exp Exp<Span>,
+        exports: &[HashMap<&'exp str, NameType<'exp>>],
+    ) -> Result<HashMap<&'exp str, NameType<'exp>>, CompileErr<Span>>
+    where
+        Span: Clone,
+    {
+        let mut env = HashMap::new();
+        let mut expr = prog;
+        while let Exp::Import {
+            path,
+            module,
+            body,
+            ann,
+        } = expr
+        {
+            match module {
+                Some(module) => env.extend(exports[*module].clone()),
+                // the program was parsed without loading the files it imports
+                None => {
+                    return Err(CompileErr::UnknownModule {
+                        path: path.clone(),
+                        location: ann.clone(),
+                    })
+                }
+            }
+            expr = body;
+        }
+        check_prog_help(expr, env, CtorArg::NoField)?;
+        // only the body of a function can be suspended
+        if let Some(location) = expr.first_yield() {
+            return Err(CompileErr::YieldOutsideFunction {
+                location: location.clone(),
+            });
+        }
+        Ok(exported_names(expr))
+    }
+
+    match p {
+        Exp::Modules { modules, body, .. } => {
+            let mut exports = Vec::new();
+            for module in modules {
+                exports.push(check_file(&module.prog, &exports)?);
+            }
+            check_file(body, &exports).map(|_| ())
+        }
+        _ => check_file(p, &[]).map(|_| ()),
+    }
 }
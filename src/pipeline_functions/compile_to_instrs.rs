@@ -1,6 +1,6 @@
 use crate::asm::{Arg32, Arg64, BinArgs, Instr, JmpArg, MemRef, MovArgs, Offset, Reg, Reg32};
 use crate::pipeline_functions::space_needed::space_needed;
-use crate::syntax::{ImmExp, Prim1, Prim2, SeqExp, SeqProg};
+use crate::syntax::{ImmExp, Prim0, Prim1, Prim2, SeqExp, SeqProg, CHANNEL_TAG};
 use std::collections::HashMap;
 
 type ErrorCode = u64;
@@ -15,6 +15,80 @@ static INDEX_NOT_NUMBER: ErrorCode = 7;
 static CALLED_NON_FUNCTION: ErrorCode = 8;
 static WRONG_ARITY: ErrorCode = 9;
 static LENGTH_NON_ARRAY: ErrorCode = 10;
+static WHILE_ERROR: ErrorCode = 11;
+static FOR_ERROR: ErrorCode = 12;
+static GENERATOR_DONE: ErrorCode = 13;
+static NOT_CHANNEL: ErrorCode = 14;
+static FIELD_ERROR: ErrorCode = 15;
+static UNCAUGHT: ErrorCode = 16;
+
+// The global that points to the handler record of the innermost `try`
+// running, or is 0 outside of them. A record is three words on the stack of
+// the function that runs the `try`: the address of the handler, the stack
+// pointer to continue with, and the record of the enclosing `try`.
+pub const HANDLER_SYMBOL: &str = "__snake_handler";
+// Runtime errors jump here with the error code and the values of the error
+// in rdi, rsi and rdx, to raise it as a value of the builtin error type of
+// the same tag, or to stop with an error message when no `try` handles it
+static RAISE_ERROR: &str = "__snake_raise_error";
+// Raises the value in rax
+static RAISE: &str = "__snake_raise";
+// The global that holds the stack pointer `start_here` calls the program
+// with. The stack between a stack pointer and this one is all the program
+// has left to do, which is what `callcc` copies to the heap.
+pub const STACK_BASE_SYMBOL: &str = "__snake_stack_base";
+// Calls the closure in rax, untagged, with the continuation of its call
+static CALLCC: &str = "__snake_callcc";
+// The code of the closures `callcc` makes, whose environment is the copy of
+// the stack they continue with
+static CONTINUE: &str = "__snake_continue";
+// The global that points to the generator running, or is 0 outside of them.
+// A generator is an object on the heap, with the words:
+//   0: 0 when suspended or not started, 1 when running, 2 when finished
+//   8: the stack pointer to resume it with
+//   16, 24, 32: the stack pointer, handler record and stack base of the code
+//     that resumed it
+//   40: the top of its own stack, which is its stack base
+//   48: the closure of its body
+//   56: the handler record to resume it with
+//   64: the generator running when it was resumed
+//   72: a handler record that raises in the code that resumed it what the
+//     body doesn't handle
+//   96: the closure that resumes it, which is what the program sees
+//   120: 1 once a continuation copied frames of its stack, which then isn't
+//     reused when it finishes
+pub const GENERATOR_SYMBOL: &str = "__snake_generator";
+// The code of the closures that resume a generator
+static RESUME: &str = "__snake_resume";
+// Suspends the generator running, returning the value in rax from its resume
+static YIELD: &str = "__snake_yield";
+// Where a generator starts, which calls its body
+static GENERATOR_START: &str = "__snake_generator_start";
+// The handler of the record at the bottom of a generator
+static GENERATOR_RAISE: &str = "__snake_generator_raise";
+// The runtime's symbol that points to the context of the thread running,
+// where a thread saves what it needs to continue when another one runs:
+//   0: its stack pointer
+//   8, 16, 24: its handler record, stack base and generator running
+//   32: the first operand of what it stopped at, then the value it
+//     continues with
+//   40: the second operand of what it stopped at
+//   48: 1 once a continuation copied frames of its stack, which then isn't
+//     reused when it finishes
+static THREAD_SYMBOL: &str = "snake_thread";
+// Sends the value in r11 to the channel in rax
+static SEND: &str = "__snake_send";
+// Receives a value from the channel in rax
+static RECV: &str = "__snake_recv";
+// Saves the context of the thread running and continues with the one the
+// runtime picks, given what the thread stopped at in rdi
+static SWITCH: &str = "__snake_switch";
+// Where a thread starts, which calls the closure in rax, untagged
+static THREAD_START: &str = "__snake_thread_start";
+// What a thread stopped at, as the runtime expects it
+static SWITCH_SEND: u64 = 0;
+static SWITCH_RECV: u64 = 1;
+static SWITCH_EXIT: u64 = 2;
 
 /*
   Numbers: 0 in the least significant bit
@@ -89,9 +163,20 @@ fn stack_align(sz: u32, cc: CallingConvention) -> u32 {
     }
 }
 
-pub fn compile_to_instrs(p: &SeqProg<u32>) -> Vec<Instr> {
-    fn alloc<Ann>(stack_lt: &HashMap<&str, Ann>) -> i32 {
-        usize_to_i32(stack_lt.len() + 1)
+// How the code of a file compiled on its own refers to what's only known
+// once it's linked with the other files of the program
+pub struct Linkage<'a> {
+    // the label of the code that runs the file
+    pub entry: &'a str,
+    // the symbols for the tags of the custom types, by the tags they were
+    // resolved to in this file
+    pub type_symbols: &'a [String],
+}
+
+pub fn compile_to_instrs(p: &SeqProg<u32>, linkage: Option<&Linkage>) -> Vec<Instr> {
+    fn alloc(stack_lt: &HashMap<&str, i32>) -> i32 {
+        // a `try` takes more slots than the variables it binds
+        stack_lt.values().max().map_or(1, |mem_addr| mem_addr + 1)
     }
     fn get_mem(mem_addr: i32) -> MemRef {
         MemRef {
@@ -106,10 +191,21 @@ pub fn compile_to_instrs(p: &SeqProg<u32>) -> Vec<Instr> {
                 reg,
                 Arg64::Unsigned(RawSnakeVal::from(*val).0),
             )),
-            ImmExp::Var(name) => {
-                let mem_addr = stack_lt.get(name.as_str()).unwrap();
-                Instr::Mov(MovArgs::ToReg(reg, Arg64::Mem(get_mem(*mem_addr))))
-            }
+            ImmExp::Var(name) => match stack_lt.get(name.as_str()) {
+                Some(mem_addr) => Instr::Mov(MovArgs::ToReg(reg, Arg64::Mem(get_mem(*mem_addr)))),
+                // the exports of another file, a static array
+                None => Instr::Mov(MovArgs::ToReg(
+                    reg,
+                    Arg64::Label(format!("{}+{}", name, ARRAY_TAG)),
+                )),
+            },
+        }
+    }
+    // the tag of a custom type, or the symbol it's linked to
+    fn type_tag(typetag: u64, linkage: Option<&Linkage>) -> Arg64 {
+        match linkage {
+            Some(linkage) => Arg64::Label(linkage.type_symbols[typetag as usize].clone()),
+            None => Arg64::Unsigned(typetag),
         }
     }
     fn compile_to_instrs_help(
@@ -117,6 +213,7 @@ pub fn compile_to_instrs(p: &SeqProg<u32>) -> Vec<Instr> {
         stack_lt: HashMap<&str, i32>,
         sf_size: u32,
         is_tail: bool,
+        linkage: Option<&Linkage>,
     ) -> Vec<Instr> {
         fn generate_type_check(
             reg: Reg,
@@ -168,7 +265,7 @@ pub fn compile_to_instrs(p: &SeqProg<u32>) -> Vec<Instr> {
                 [
                     Instr::Mov(MovArgs::ToReg(Reg::Rdi, Arg64::Unsigned(error_code))),
                     Instr::Mov(MovArgs::ToReg(Reg::Rsi, Arg64::Reg(reg))),
-                    Instr::Call(JmpArg::Label("snake_error".to_string())),
+                    Instr::Jmp(JmpArg::Label(RAISE_ERROR.to_string())),
                     Instr::Label(type_pass_label.clone()),
                 ]
                 .to_vec(),
@@ -184,7 +281,7 @@ pub fn compile_to_instrs(p: &SeqProg<u32>) -> Vec<Instr> {
                 Instr::Comment(String::from("overflow check")),
                 Instr::Jno(JmpArg::Label(format!("overflowcheck_pass_{}", tag))),
                 Instr::Mov(MovArgs::ToReg(Reg::Rdi, Arg64::Unsigned(OVERFLOW_ERROR))),
-                Instr::Call(JmpArg::Label("snake_error".to_string())),
+                Instr::Jmp(JmpArg::Label(RAISE_ERROR.to_string())),
                 Instr::Label(format!("overflowcheck_pass_{}", tag)),
             ])
             .to_vec()
@@ -218,7 +315,7 @@ pub fn compile_to_instrs(p: &SeqProg<u32>) -> Vec<Instr> {
                     Arg64::Unsigned(INDEX_OUT_OF_BOUNDS),
                 )),
                 Instr::Mov(MovArgs::ToReg(Reg::Rsi, Arg64::Reg(index_reg))),
-                Instr::Call(JmpArg::Label("snake_error".to_string())),
+                Instr::Jmp(JmpArg::Label(RAISE_ERROR.to_string())),
                 Instr::Label(index_in_bounds.clone()),
             ]
             .to_vec()
@@ -244,295 +341,470 @@ pub fn compile_to_instrs(p: &SeqProg<u32>) -> Vec<Instr> {
                 Instr::Mov(MovArgs::ToReg(Reg::Rdi, Arg64::Unsigned(WRONG_ARITY))),
                 Instr::Mov(MovArgs::ToReg(Reg::Rsi, Arg64::Reg(Reg::R11))),
                 Instr::Mov(MovArgs::ToReg(Reg::Rdx, Arg64::Unsigned(num_args as u64))),
-                Instr::Call(JmpArg::Label("snake_error".to_string())),
+                Instr::Jmp(JmpArg::Label(RAISE_ERROR.to_string())),
                 Instr::Label(done_label.clone()),
             ]
             .to_vec()
         }
 
-        let mut is = Vec::new();
-
-        match expr {
-            SeqExp::Imm(imm, _) => {
-                is.push(compile_immediate_help(imm, &stack_lt, Reg::Rax));
+        // The code of a primitive of no arguments
+        fn compile_prim0(op: &Prim0, sf_size: u32) -> Vec<Instr> {
+            let heap = |offset: i32| MemRef {
+                reg: Reg::Rbp,
+                offset: Offset::Constant(offset),
+            };
+            match op {
+                Prim0::Chan => {
+                    let stack_offset = stack_align(sf_size, CallingConvention::SystemV);
+                    vec![
+                        // the runtime makes the channel and returns its number
+                        Instr::Sub(BinArgs::ToReg(Reg::Rsp, Arg32::Unsigned(stack_offset))),
+                        Instr::Call(JmpArg::Label("snake_chan".to_string())),
+                        Instr::Add(BinArgs::ToReg(Reg::Rsp, Arg32::Unsigned(stack_offset))),
+                        // which is the one field of a value of the channel type,
+                        // with the fields array just before the value
+                        Instr::Mov(MovArgs::ToMem(heap(0), Reg32::Unsigned(1))),
+                        Instr::Mov(MovArgs::ToMem(heap(8), Reg32::Reg(Reg::Rax))),
+                        Instr::Mov(MovArgs::ToReg(Reg::R11, Arg64::Unsigned(CHANNEL_TAG))),
+                        Instr::Mov(MovArgs::ToMem(heap(16), Reg32::Reg(Reg::R11))),
+                        Instr::Mov(MovArgs::ToReg(Reg::R11, Arg64::Reg(Reg::Rbp))),
+                        Instr::Add(BinArgs::ToReg(Reg::R11, Arg32::Unsigned(0b001))),
+                        Instr::Mov(MovArgs::ToMem(heap(24), Reg32::Reg(Reg::R11))),
+                        Instr::Mov(MovArgs::ToReg(Reg::Rax, Arg64::Reg(Reg::Rbp))),
+                        Instr::Add(BinArgs::ToReg(Reg::Rax, Arg32::Unsigned(16 + 0b101))),
+                        Instr::Add(BinArgs::ToReg(Reg::Rbp, Arg32::Unsigned(32))),
+                    ]
+                }
             }
-            SeqExp::Prim1(op, e1, tag) => {
-                is.push(compile_immediate_help(e1, &stack_lt, Reg::Rax));
-                match op {
-                    Prim1::Add1 | Prim1::Sub1 => {
-                        is.extend(generate_type_check(
-                            Reg::Rax,
-                            SnakeType::Number,
-                            ARITH_ERROR,
-                            &format!("1_{}", *tag),
-                        ));
-                    }
-                    Prim1::Not => {
-                        is.extend(generate_type_check(
-                            Reg::Rax,
-                            SnakeType::Boolean,
-                            LOGIC_ERROR,
-                            &format!("2_{}", *tag),
-                        ));
-                    }
-                    Prim1::Length => is.extend(generate_type_check(
+        }
+
+        // The code of a primitive of one argument, which is in rax. It's
+        // apart from compile_to_instrs_help, which is recursive, to keep its
+        // stack frame small.
+        fn compile_prim1(op: &Prim1, tag: &u32, sf_size: u32) -> Vec<Instr> {
+            let mut is = Vec::new();
+            match op {
+                Prim1::Add1 | Prim1::Sub1 => {
+                    is.extend(generate_type_check(
                         Reg::Rax,
-                        SnakeType::Array,
-                        LENGTH_NON_ARRAY,
+                        SnakeType::Number,
+                        ARITH_ERROR,
                         &format!("1_{}", *tag),
-                    )),
-                    _ => (),
+                    ));
                 }
-                match op {
-                    Prim1::Add1 => {
-                        is.push(Instr::Add(BinArgs::ToReg(Reg::Rax, Arg32::Signed(2))));
-                    }
-                    Prim1::Sub1 => {
-                        is.push(Instr::Sub(BinArgs::ToReg(Reg::Rax, Arg32::Signed(2))));
-                    }
-                    Prim1::Not => is.extend([
-                        Instr::Mov(MovArgs::ToReg(Reg::R11, Arg64::Unsigned(SNAKE_TRU.0))),
-                        Instr::Cmp(BinArgs::ToReg(Reg::Rax, Arg32::Reg(Reg::R11))),
-                        Instr::Je(JmpArg::Label(format!("not_true_{}", tag))),
+                Prim1::Not => {
+                    is.extend(generate_type_check(
+                        Reg::Rax,
+                        SnakeType::Boolean,
+                        LOGIC_ERROR,
+                        &format!("2_{}", *tag),
+                    ));
+                }
+                Prim1::Length => is.extend(generate_type_check(
+                    Reg::Rax,
+                    SnakeType::Array,
+                    LENGTH_NON_ARRAY,
+                    &format!("1_{}", *tag),
+                )),
+                Prim1::CallCC => {
+                    is.extend(generate_type_check(
+                        Reg::Rax,
+                        SnakeType::Closure,
+                        CALLED_NON_FUNCTION,
+                        &format!("callcc_{}", *tag),
+                    ));
+                    is.push(Instr::Sub(BinArgs::ToReg(Reg::Rax, Arg32::Unsigned(3))));
+                    is.extend(generate_arity_check(Reg::Rax, 1, *tag));
+                }
+                Prim1::Spawn => {
+                    is.extend(generate_type_check(
+                        Reg::Rax,
+                        SnakeType::Closure,
+                        CALLED_NON_FUNCTION,
+                        &format!("spawn_{}", *tag),
+                    ));
+                    is.push(Instr::Sub(BinArgs::ToReg(Reg::Rax, Arg32::Unsigned(3))));
+                    is.extend(generate_arity_check(Reg::Rax, 0, *tag));
+                }
+                _ => (),
+            }
+            match op {
+                Prim1::Add1 => {
+                    is.push(Instr::Add(BinArgs::ToReg(Reg::Rax, Arg32::Signed(2))));
+                }
+                Prim1::Sub1 => {
+                    is.push(Instr::Sub(BinArgs::ToReg(Reg::Rax, Arg32::Signed(2))));
+                }
+                Prim1::Not => is.extend([
+                    Instr::Mov(MovArgs::ToReg(Reg::R11, Arg64::Unsigned(SNAKE_TRU.0))),
+                    Instr::Cmp(BinArgs::ToReg(Reg::Rax, Arg32::Reg(Reg::R11))),
+                    Instr::Je(JmpArg::Label(format!("not_true_{}", tag))),
+                    put_snakeval_in_rax(SNAKE_TRU),
+                    Instr::Jmp(JmpArg::Label(format!("not_end_{}", tag))),
+                    Instr::Label(format!("not_true_{}", tag)),
+                    put_snakeval_in_rax(SNAKE_FLS),
+                    Instr::Label(format!("not_end_{}", tag)),
+                ]),
+                Prim1::IsBool => is.extend([
+                    Instr::Mov(MovArgs::ToReg(Reg::R10, Arg64::Unsigned(TAG_CHECKER))),
+                    Instr::And(BinArgs::ToReg(Reg::R10, Arg32::Reg(Reg::Rax))),
+                    Instr::Cmp(BinArgs::ToReg(Reg::R10, Arg32::Unsigned(BOOL_TAG))),
+                    put_snakeval_in_rax(SNAKE_TRU),
+                    Instr::Je(JmpArg::Label(format!("isbool_true_{}", tag))),
+                    put_snakeval_in_rax(SNAKE_FLS),
+                    Instr::Label(format!("isbool_true_{}", tag)),
+                ]),
+                Prim1::IsNum => is.extend([
+                    Instr::Mov(MovArgs::ToReg(Reg::R11, Arg64::Unsigned(1))),
+                    Instr::Test(BinArgs::ToReg(Reg::Rax, Arg32::Reg(Reg::R11))),
+                    Instr::Jz(JmpArg::Label(format!("isnum_true_{}", tag))),
+                    put_snakeval_in_rax(SNAKE_FLS),
+                    Instr::Jmp(JmpArg::Label(format!("isnum_end_{}", tag))),
+                    Instr::Label(format!("isnum_true_{}", tag)),
+                    put_snakeval_in_rax(SNAKE_TRU),
+                    Instr::Label(format!("isnum_end_{}", tag)),
+                ]),
+                Prim1::Print => {
+                    let stack_offset = stack_align(sf_size, CallingConvention::SystemV);
+                    is.extend([
+                        Instr::Mov(MovArgs::ToReg(Reg::Rdi, Arg64::Reg(Reg::Rax))),
+                        Instr::Sub(BinArgs::ToReg(Reg::Rsp, Arg32::Unsigned(stack_offset))),
+                        Instr::Call(JmpArg::Label("print_snake_val".to_string())),
+                        Instr::Add(BinArgs::ToReg(Reg::Rsp, Arg32::Unsigned(stack_offset))),
+                    ])
+                }
+                Prim1::Length => {
+                    is.extend(
+                        [
+                            // untag array
+                            Instr::Sub(BinArgs::ToReg(Reg::Rax, Arg32::Unsigned(1))),
+                            // return length
+                            Instr::Mov(MovArgs::ToReg(
+                                Reg::Rax,
+                                Arg64::Mem(MemRef {
+                                    reg: Reg::Rax,
+                                    offset: Offset::Constant(0),
+                                }),
+                            )),
+                            // tag length value
+                            Instr::Shl(BinArgs::ToReg(Reg::Rax, Arg32::Signed(1))),
+                        ]
+                        .to_vec(),
+                    )
+                }
+                Prim1::IsArray => is.extend(
+                    [
+                        Instr::Mov(MovArgs::ToReg(Reg::R10, Arg64::Unsigned(TAG_CHECKER))),
+                        Instr::And(BinArgs::ToReg(Reg::R10, Arg32::Reg(Reg::Rax))),
+                        Instr::Cmp(BinArgs::ToReg(Reg::R10, Arg32::Unsigned(ARRAY_TAG))),
                         put_snakeval_in_rax(SNAKE_TRU),
-                        Instr::Jmp(JmpArg::Label(format!("not_end_{}", tag))),
-                        Instr::Label(format!("not_true_{}", tag)),
+                        Instr::Je(JmpArg::Label(format!("isarray_true_{}", tag))),
                         put_snakeval_in_rax(SNAKE_FLS),
-                        Instr::Label(format!("not_end_{}", tag)),
-                    ]),
-                    Prim1::IsBool => is.extend([
+                        Instr::Label(format!("isarray_true_{}", tag)),
+                    ]
+                    .to_vec(),
+                ),
+                Prim1::IsFun => is.extend(
+                    [
                         Instr::Mov(MovArgs::ToReg(Reg::R10, Arg64::Unsigned(TAG_CHECKER))),
                         Instr::And(BinArgs::ToReg(Reg::R10, Arg32::Reg(Reg::Rax))),
-                        Instr::Cmp(BinArgs::ToReg(Reg::R10, Arg32::Unsigned(BOOL_TAG))),
+                        Instr::Cmp(BinArgs::ToReg(Reg::R10, Arg32::Unsigned(CLOSURE_TAG))),
                         put_snakeval_in_rax(SNAKE_TRU),
-                        Instr::Je(JmpArg::Label(format!("isbool_true_{}", tag))),
-                        put_snakeval_in_rax(SNAKE_FLS),
-                        Instr::Label(format!("isbool_true_{}", tag)),
-                    ]),
-                    Prim1::IsNum => is.extend([
-                        Instr::Mov(MovArgs::ToReg(Reg::R11, Arg64::Unsigned(1))),
-                        Instr::Test(BinArgs::ToReg(Reg::Rax, Arg32::Reg(Reg::R11))),
-                        Instr::Jz(JmpArg::Label(format!("isnum_true_{}", tag))),
+                        Instr::Je(JmpArg::Label(format!("isfun_true_{}", tag))),
                         put_snakeval_in_rax(SNAKE_FLS),
-                        Instr::Jmp(JmpArg::Label(format!("isnum_end_{}", tag))),
-                        Instr::Label(format!("isnum_true_{}", tag)),
-                        put_snakeval_in_rax(SNAKE_TRU),
-                        Instr::Label(format!("isnum_end_{}", tag)),
-                    ]),
-                    Prim1::Print => {
-                        let stack_offset = stack_align(sf_size, CallingConvention::SystemV);
-                        is.extend([
-                            Instr::Mov(MovArgs::ToReg(Reg::Rdi, Arg64::Reg(Reg::Rax))),
-                            Instr::Sub(BinArgs::ToReg(Reg::Rsp, Arg32::Unsigned(stack_offset))),
-                            Instr::Call(JmpArg::Label("print_snake_val".to_string())),
-                            Instr::Add(BinArgs::ToReg(Reg::Rsp, Arg32::Unsigned(stack_offset))),
-                        ])
-                    }
-                    Prim1::Length => {
-                        is.extend(
-                            [
-                                // untag array
-                                Instr::Sub(BinArgs::ToReg(Reg::Rax, Arg32::Unsigned(1))),
-                                // return length
-                                Instr::Mov(MovArgs::ToReg(
-                                    Reg::Rax,
-                                    Arg64::Mem(MemRef {
-                                        reg: Reg::Rax,
-                                        offset: Offset::Constant(0),
-                                    }),
-                                )),
-                                // tag length value
-                                Instr::Shl(BinArgs::ToReg(Reg::Rax, Arg32::Signed(1))),
-                            ]
-                            .to_vec(),
-                        )
-                    }
-                    Prim1::IsArray => is.extend(
-                        [
-                            Instr::Mov(MovArgs::ToReg(Reg::R10, Arg64::Unsigned(TAG_CHECKER))),
-                            Instr::And(BinArgs::ToReg(Reg::R10, Arg32::Reg(Reg::Rax))),
-                            Instr::Cmp(BinArgs::ToReg(Reg::R10, Arg32::Unsigned(ARRAY_TAG))),
-                            put_snakeval_in_rax(SNAKE_TRU),
-                            Instr::Je(JmpArg::Label(format!("isarray_true_{}", tag))),
-                            put_snakeval_in_rax(SNAKE_FLS),
-                            Instr::Label(format!("isarray_true_{}", tag)),
-                        ]
-                        .to_vec(),
-                    ),
-                    Prim1::IsFun => is.extend(
-                        [
-                            Instr::Mov(MovArgs::ToReg(Reg::R10, Arg64::Unsigned(TAG_CHECKER))),
-                            Instr::And(BinArgs::ToReg(Reg::R10, Arg32::Reg(Reg::Rax))),
-                            Instr::Cmp(BinArgs::ToReg(Reg::R10, Arg32::Unsigned(CLOSURE_TAG))),
-                            put_snakeval_in_rax(SNAKE_TRU),
-                            Instr::Je(JmpArg::Label(format!("isfun_true_{}", tag))),
-                            put_snakeval_in_rax(SNAKE_FLS),
-                            Instr::Label(format!("isfun_true_{}", tag)),
-                        ]
-                        .to_vec(),
-                    ),
-                };
-                match op {
-                    // check for overflow
-                    Prim1::Add1 | Prim1::Sub1 => {
-                        is.extend(generate_overflow_check(*tag));
-                    }
-                    _ => (),
+                        Instr::Label(format!("isfun_true_{}", tag)),
+                    ]
+                    .to_vec(),
+                ),
+                // like a non-tail call, so that the continuation is the
+                // stack from the return address up
+                Prim1::CallCC => {
+                    let stack_offset = stack_align(sf_size, CallingConvention::Snake);
+                    is.extend([
+                        Instr::Sub(BinArgs::ToReg(Reg::Rsp, Arg32::Unsigned(stack_offset))),
+                        Instr::Call(JmpArg::Label(CALLCC.to_string())),
+                        Instr::Add(BinArgs::ToReg(Reg::Rsp, Arg32::Unsigned(stack_offset))),
+                    ])
+                }
+                // like a non-tail call too, so that resuming returns
+                // from it
+                Prim1::Yield => {
+                    let stack_offset = stack_align(sf_size, CallingConvention::Snake);
+                    is.extend([
+                        Instr::Sub(BinArgs::ToReg(Reg::Rsp, Arg32::Unsigned(stack_offset))),
+                        Instr::Call(JmpArg::Label(YIELD.to_string())),
+                        Instr::Add(BinArgs::ToReg(Reg::Rsp, Arg32::Unsigned(stack_offset))),
+                    ])
+                }
+                Prim1::Generator => {
+                    let stack_offset = stack_align(sf_size, CallingConvention::SystemV);
+                    let heap = |offset: i32| MemRef {
+                        reg: Reg::Rbp,
+                        offset: Offset::Constant(offset),
+                    };
+                    is.extend([
+                        Instr::Mov(MovArgs::ToMem(heap(48), Reg32::Reg(Reg::Rax))),
+                        Instr::Sub(BinArgs::ToReg(Reg::Rsp, Arg32::Unsigned(stack_offset))),
+                        Instr::Call(JmpArg::Label("snake_new_stack".to_string())),
+                        Instr::Add(BinArgs::ToReg(Reg::Rsp, Arg32::Unsigned(stack_offset))),
+                        Instr::Mov(MovArgs::ToMem(heap(40), Reg32::Reg(Reg::Rax))),
+                        // resuming it the first time returns to the start
+                        Instr::Mov(MovArgs::ToReg(
+                            Reg::R10,
+                            Arg64::Label(GENERATOR_START.to_string()),
+                        )),
+                        Instr::Sub(BinArgs::ToReg(Reg::Rax, Arg32::Unsigned(8))),
+                        Instr::Mov(MovArgs::ToMem(
+                            MemRef {
+                                reg: Reg::Rax,
+                                offset: Offset::Constant(0),
+                            },
+                            Reg32::Reg(Reg::R10),
+                        )),
+                        Instr::Mov(MovArgs::ToMem(heap(8), Reg32::Reg(Reg::Rax))),
+                        Instr::Mov(MovArgs::ToMem(heap(0), Reg32::Unsigned(0))),
+                        Instr::Mov(MovArgs::ToReg(Reg::R10, Arg64::Reg(Reg::Rbp))),
+                        Instr::Add(BinArgs::ToReg(Reg::R10, Arg32::Unsigned(72))),
+                        Instr::Mov(MovArgs::ToMem(heap(56), Reg32::Reg(Reg::R10))),
+                        Instr::Mov(MovArgs::ToReg(
+                            Reg::R10,
+                            Arg64::Label(GENERATOR_RAISE.to_string()),
+                        )),
+                        Instr::Mov(MovArgs::ToMem(heap(72), Reg32::Reg(Reg::R10))),
+                        Instr::Mov(MovArgs::ToReg(Reg::R10, Arg64::Mem(heap(40)))),
+                        Instr::Mov(MovArgs::ToMem(heap(80), Reg32::Reg(Reg::R10))),
+                        Instr::Mov(MovArgs::ToMem(heap(88), Reg32::Unsigned(0))),
+                        Instr::Mov(MovArgs::ToMem(heap(96), Reg32::Unsigned(0))),
+                        Instr::Mov(MovArgs::ToReg(Reg::R10, Arg64::Label(RESUME.to_string()))),
+                        Instr::Mov(MovArgs::ToMem(heap(104), Reg32::Reg(Reg::R10))),
+                        Instr::Mov(MovArgs::ToMem(heap(112), Reg32::Reg(Reg::Rbp))),
+                        Instr::Mov(MovArgs::ToMem(heap(120), Reg32::Unsigned(0))),
+                        Instr::Mov(MovArgs::ToReg(Reg::Rax, Arg64::Reg(Reg::Rbp))),
+                        Instr::Add(BinArgs::ToReg(Reg::Rax, Arg32::Unsigned(96 + CLOSURE_TAG))),
+                        Instr::Add(BinArgs::ToReg(Reg::Rbp, Arg32::Unsigned(128))),
+                    ])
                 }
+                Prim1::Spawn => {
+                    let stack_offset = stack_align(sf_size, CallingConvention::SystemV);
+                    is.extend([
+                        Instr::Mov(MovArgs::ToReg(Reg::Rdi, Arg64::Reg(Reg::Rax))),
+                        Instr::Mov(MovArgs::ToReg(
+                            Reg::Rsi,
+                            Arg64::Label(THREAD_START.to_string()),
+                        )),
+                        Instr::Sub(BinArgs::ToReg(Reg::Rsp, Arg32::Unsigned(stack_offset))),
+                        Instr::Call(JmpArg::Label("snake_spawn".to_string())),
+                        Instr::Add(BinArgs::ToReg(Reg::Rsp, Arg32::Unsigned(stack_offset))),
+                    ])
+                }
+                // like a non-tail call, so that the thread continues by
+                // returning from it
+                Prim1::Recv => {
+                    let stack_offset = stack_align(sf_size, CallingConvention::Snake);
+                    is.extend([
+                        Instr::Sub(BinArgs::ToReg(Reg::Rsp, Arg32::Unsigned(stack_offset))),
+                        Instr::Call(JmpArg::Label(RECV.to_string())),
+                        Instr::Add(BinArgs::ToReg(Reg::Rsp, Arg32::Unsigned(stack_offset))),
+                    ])
+                }
+                Prim1::NoField => is.extend([
+                    Instr::Mov(MovArgs::ToReg(Reg::Rdi, Arg64::Unsigned(FIELD_ERROR))),
+                    Instr::Mov(MovArgs::ToReg(Reg::Rsi, Arg64::Reg(Reg::Rax))),
+                    Instr::Jmp(JmpArg::Label(RAISE_ERROR.to_string())),
+                ]),
+            };
+            match op {
+                // check for overflow
+                Prim1::Add1 | Prim1::Sub1 => {
+                    is.extend(generate_overflow_check(*tag));
+                }
+                _ => (),
             }
-            SeqExp::Prim2(op, e1, e2, tag) => {
-                is.push(compile_immediate_help(e1, &stack_lt, Reg::Rax));
-                is.push(compile_immediate_help(e2, &stack_lt, Reg::R11));
-                match op {
-                    // first do type checking
-                    Prim2::Add | Prim2::Sub | Prim2::Mul => {
-                        is.extend(generate_type_check(
-                            Reg::Rax,
-                            SnakeType::Number,
-                            ARITH_ERROR,
-                            &format!("1_{}", *tag),
-                        ));
-                        is.extend(generate_type_check(
-                            Reg::R11,
-                            SnakeType::Number,
-                            ARITH_ERROR,
-                            &format!("2_{}", *tag),
-                        ));
-                    }
-                    Prim2::Lt | Prim2::Gt | Prim2::Le | Prim2::Ge => {
-                        is.extend(generate_type_check(
-                            Reg::Rax,
-                            SnakeType::Number,
-                            COMPARISON_ERROR,
-                            &format!("1_{}", *tag),
-                        ));
-                        is.extend(generate_type_check(
-                            Reg::R11,
-                            SnakeType::Number,
-                            COMPARISON_ERROR,
-                            &format!("2_{}", *tag),
-                        ));
-                    }
-                    Prim2::And | Prim2::Or => {
-                        is.extend(generate_type_check(
-                            Reg::Rax,
-                            SnakeType::Boolean,
-                            LOGIC_ERROR,
-                            &format!("1_{}", *tag),
-                        ));
-                        is.extend(generate_type_check(
-                            Reg::R11,
-                            SnakeType::Boolean,
-                            LOGIC_ERROR,
-                            &format!("2_{}", *tag),
-                        ));
-                    }
-                    Prim2::Eq | Prim2::Neq => (),
-                    Prim2::ArrayGet => {
-                        is.extend(generate_type_check(
-                            Reg::Rax,
-                            SnakeType::Array,
-                            NOT_ARRAY,
-                            &format!("1_{}", *tag),
-                        ));
-                        is.extend(generate_type_check(
-                            Reg::R11,
-                            SnakeType::Number,
-                            INDEX_NOT_NUMBER,
-                            &format!("2_{}", *tag),
-                        ));
-                    }
+            is
+        }
+
+        // The code of a primitive of two arguments, which are in rax and r11
+        fn compile_prim2(op: &Prim2, tag: &u32, sf_size: u32) -> Vec<Instr> {
+            let mut is = Vec::new();
+            match op {
+                // first do type checking
+                Prim2::Add | Prim2::Sub | Prim2::Mul => {
+                    is.extend(generate_type_check(
+                        Reg::Rax,
+                        SnakeType::Number,
+                        ARITH_ERROR,
+                        &format!("1_{}", *tag),
+                    ));
+                    is.extend(generate_type_check(
+                        Reg::R11,
+                        SnakeType::Number,
+                        ARITH_ERROR,
+                        &format!("2_{}", *tag),
+                    ));
                 }
-                match op {
-                    // then generate the instructions
-                    Prim2::Add => {
-                        is.push(Instr::Add(BinArgs::ToReg(Reg::Rax, Arg32::Reg(Reg::R11))));
-                    }
-                    Prim2::Sub => {
-                        is.push(Instr::Sub(BinArgs::ToReg(Reg::Rax, Arg32::Reg(Reg::R11))));
-                    }
-                    Prim2::Mul => {
-                        is.push(Instr::Sar(BinArgs::ToReg(Reg::Rax, Arg32::Signed(1))));
-                        is.push(Instr::IMul(BinArgs::ToReg(Reg::Rax, Arg32::Reg(Reg::R11))));
-                    }
-                    Prim2::And => is.extend([
-                        Instr::Mov(MovArgs::ToReg(Reg::R10, Arg64::Unsigned(SNAKE_TRU.0))),
-                        Instr::Cmp(BinArgs::ToReg(Reg::Rax, Arg32::Reg(Reg::R10))),
-                        Instr::Je(JmpArg::Label(format!("and_operand1_true_{}", tag))),
-                        put_snakeval_in_rax(SNAKE_FLS),
-                        Instr::Jmp(JmpArg::Label(format!("and_end_{}", tag))),
-                        Instr::Label(format!("and_operand1_true_{}", tag)),
-                        Instr::Cmp(BinArgs::ToReg(Reg::R11, Arg32::Reg(Reg::R10))),
-                        Instr::Je(JmpArg::Label(format!("and_operand2_true_{}", tag))),
-                        put_snakeval_in_rax(SNAKE_FLS),
-                        Instr::Jmp(JmpArg::Label(format!("and_end_{}", tag))),
-                        Instr::Label(format!("and_operand2_true_{}", tag)),
-                        put_snakeval_in_rax(SNAKE_TRU),
-                        Instr::Label(format!("and_end_{}", tag)),
-                    ]),
-                    Prim2::Or => is.extend([
-                        Instr::Mov(MovArgs::ToReg(Reg::R10, Arg64::Unsigned(SNAKE_TRU.0))),
-                        Instr::Cmp(BinArgs::ToReg(Reg::Rax, Arg32::Reg(Reg::R10))),
-                        Instr::Je(JmpArg::Label(format!("or_true_{}", tag))),
-                        Instr::Cmp(BinArgs::ToReg(Reg::R11, Arg32::Reg(Reg::R10))),
-                        Instr::Je(JmpArg::Label(format!("or_true_{}", tag))),
+                Prim2::Lt | Prim2::Gt | Prim2::Le | Prim2::Ge => {
+                    is.extend(generate_type_check(
+                        Reg::Rax,
+                        SnakeType::Number,
+                        COMPARISON_ERROR,
+                        &format!("1_{}", *tag),
+                    ));
+                    is.extend(generate_type_check(
+                        Reg::R11,
+                        SnakeType::Number,
+                        COMPARISON_ERROR,
+                        &format!("2_{}", *tag),
+                    ));
+                }
+                Prim2::And | Prim2::Or => {
+                    is.extend(generate_type_check(
+                        Reg::Rax,
+                        SnakeType::Boolean,
+                        LOGIC_ERROR,
+                        &format!("1_{}", *tag),
+                    ));
+                    is.extend(generate_type_check(
+                        Reg::R11,
+                        SnakeType::Boolean,
+                        LOGIC_ERROR,
+                        &format!("2_{}", *tag),
+                    ));
+                }
+                // the runtime checks the channel of a send
+                Prim2::Eq | Prim2::Neq | Prim2::Send => (),
+                Prim2::ArrayGet => {
+                    is.extend(generate_type_check(
+                        Reg::Rax,
+                        SnakeType::Array,
+                        NOT_ARRAY,
+                        &format!("1_{}", *tag),
+                    ));
+                    is.extend(generate_type_check(
+                        Reg::R11,
+                        SnakeType::Number,
+                        INDEX_NOT_NUMBER,
+                        &format!("2_{}", *tag),
+                    ));
+                }
+            }
+            match op {
+                // then generate the instructions
+                Prim2::Add => {
+                    is.push(Instr::Add(BinArgs::ToReg(Reg::Rax, Arg32::Reg(Reg::R11))));
+                }
+                Prim2::Sub => {
+                    is.push(Instr::Sub(BinArgs::ToReg(Reg::Rax, Arg32::Reg(Reg::R11))));
+                }
+                Prim2::Mul => {
+                    is.push(Instr::Sar(BinArgs::ToReg(Reg::Rax, Arg32::Signed(1))));
+                    is.push(Instr::IMul(BinArgs::ToReg(Reg::Rax, Arg32::Reg(Reg::R11))));
+                }
+                Prim2::And => is.extend([
+                    Instr::Mov(MovArgs::ToReg(Reg::R10, Arg64::Unsigned(SNAKE_TRU.0))),
+                    Instr::Cmp(BinArgs::ToReg(Reg::Rax, Arg32::Reg(Reg::R10))),
+                    Instr::Je(JmpArg::Label(format!("and_operand1_true_{}", tag))),
+                    put_snakeval_in_rax(SNAKE_FLS),
+                    Instr::Jmp(JmpArg::Label(format!("and_end_{}", tag))),
+                    Instr::Label(format!("and_operand1_true_{}", tag)),
+                    Instr::Cmp(BinArgs::ToReg(Reg::R11, Arg32::Reg(Reg::R10))),
+                    Instr::Je(JmpArg::Label(format!("and_operand2_true_{}", tag))),
+                    put_snakeval_in_rax(SNAKE_FLS),
+                    Instr::Jmp(JmpArg::Label(format!("and_end_{}", tag))),
+                    Instr::Label(format!("and_operand2_true_{}", tag)),
+                    put_snakeval_in_rax(SNAKE_TRU),
+                    Instr::Label(format!("and_end_{}", tag)),
+                ]),
+                Prim2::Or => is.extend([
+                    Instr::Mov(MovArgs::ToReg(Reg::R10, Arg64::Unsigned(SNAKE_TRU.0))),
+                    Instr::Cmp(BinArgs::ToReg(Reg::Rax, Arg32::Reg(Reg::R10))),
+                    Instr::Je(JmpArg::Label(format!("or_true_{}", tag))),
+                    Instr::Cmp(BinArgs::ToReg(Reg::R11, Arg32::Reg(Reg::R10))),
+                    Instr::Je(JmpArg::Label(format!("or_true_{}", tag))),
+                    put_snakeval_in_rax(SNAKE_FLS),
+                    Instr::Jmp(JmpArg::Label(format!("or_end_{}", tag))),
+                    Instr::Label(format!("or_true_{}", tag)),
+                    put_snakeval_in_rax(SNAKE_TRU),
+                    Instr::Label(format!("or_end_{}", tag)),
+                ]),
+                Prim2::Lt | Prim2::Gt | Prim2::Le | Prim2::Ge | Prim2::Eq | Prim2::Neq => {
+                    // shift right both operands while preserving signs
+                    is.extend([
+                        Instr::Sar(BinArgs::ToReg(Reg::Rax, Arg32::Signed(1))),
+                        Instr::Sar(BinArgs::ToReg(Reg::R11, Arg32::Signed(1))),
+                        Instr::Cmp(BinArgs::ToReg(Reg::Rax, Arg32::Reg(Reg::R11))),
+                        match op {
+                            Prim2::Lt => Instr::Jl(JmpArg::Label(format!("cmp_true_{}", tag))),
+                            Prim2::Gt => Instr::Jg(JmpArg::Label(format!("cmp_true_{}", tag))),
+                            Prim2::Le => Instr::Jle(JmpArg::Label(format!("cmp_true_{}", tag))),
+                            Prim2::Ge => Instr::Jge(JmpArg::Label(format!("cmp_true_{}", tag))),
+                            Prim2::Eq => Instr::Je(JmpArg::Label(format!("cmp_true_{}", tag))),
+                            Prim2::Neq => {
+                                Instr::Jne(JmpArg::Label(format!("cmp_true_{}", tag)))
+                            }
+                            _ => unreachable!(),
+                        },
                         put_snakeval_in_rax(SNAKE_FLS),
-                        Instr::Jmp(JmpArg::Label(format!("or_end_{}", tag))),
-                        Instr::Label(format!("or_true_{}", tag)),
+                        Instr::Jmp(JmpArg::Label(format!("cmp_end_{}", tag))),
+                        Instr::Label(format!("cmp_true_{}", tag)),
                         put_snakeval_in_rax(SNAKE_TRU),
-                        Instr::Label(format!("or_end_{}", tag)),
-                    ]),
-                    Prim2::Lt | Prim2::Gt | Prim2::Le | Prim2::Ge | Prim2::Eq | Prim2::Neq => {
-                        // shift right both operands while preserving signs
-                        is.extend([
-                            Instr::Sar(BinArgs::ToReg(Reg::Rax, Arg32::Signed(1))),
-                            Instr::Sar(BinArgs::ToReg(Reg::R11, Arg32::Signed(1))),
-                            Instr::Cmp(BinArgs::ToReg(Reg::Rax, Arg32::Reg(Reg::R11))),
-                            match op {
-                                Prim2::Lt => Instr::Jl(JmpArg::Label(format!("cmp_true_{}", tag))),
-                                Prim2::Gt => Instr::Jg(JmpArg::Label(format!("cmp_true_{}", tag))),
-                                Prim2::Le => Instr::Jle(JmpArg::Label(format!("cmp_true_{}", tag))),
-                                Prim2::Ge => Instr::Jge(JmpArg::Label(format!("cmp_true_{}", tag))),
-                                Prim2::Eq => Instr::Je(JmpArg::Label(format!("cmp_true_{}", tag))),
-                                Prim2::Neq => {
-                                    Instr::Jne(JmpArg::Label(format!("cmp_true_{}", tag)))
-                                }
-                                _ => unreachable!(),
+                        Instr::Label(format!("cmp_end_{}", tag)),
+                    ])
+                }
+                Prim2::ArrayGet => {
+                    // untag array
+                    is.push(Instr::Sub(BinArgs::ToReg(Reg::Rax, Arg32::Unsigned(1))));
+                    // untag index
+                    is.push(Instr::Sar(BinArgs::ToReg(Reg::R11, Arg32::Signed(1))));
+                    // confirm index is in bounds
+                    is.extend(generate_array_index_checks(Reg::R11, Reg::Rax, *tag));
+                    is.push(Instr::Mov(MovArgs::ToReg(
+                        Reg::Rax,
+                        Arg64::Mem(MemRef {
+                            reg: Reg::Rax,
+                            offset: Offset::Computed {
+                                reg: Reg::R11,
+                                factor: 8,
+                                constant: 8,
                             },
-                            put_snakeval_in_rax(SNAKE_FLS),
-                            Instr::Jmp(JmpArg::Label(format!("cmp_end_{}", tag))),
-                            Instr::Label(format!("cmp_true_{}", tag)),
-                            put_snakeval_in_rax(SNAKE_TRU),
-                            Instr::Label(format!("cmp_end_{}", tag)),
-                        ])
-                    }
-                    Prim2::ArrayGet => {
-                        // untag array
-                        is.push(Instr::Sub(BinArgs::ToReg(Reg::Rax, Arg32::Unsigned(1))));
-                        // untag index
-                        is.push(Instr::Sar(BinArgs::ToReg(Reg::R11, Arg32::Signed(1))));
-                        // confirm index is in bounds
-                        is.extend(generate_array_index_checks(Reg::R11, Reg::Rax, *tag));
-                        is.push(Instr::Mov(MovArgs::ToReg(
-                            Reg::Rax,
-                            Arg64::Mem(MemRef {
-                                reg: Reg::Rax,
-                                offset: Offset::Computed {
-                                    reg: Reg::R11,
-                                    factor: 8,
-                                    constant: 8,
-                                },
-                            }),
-                        )));
-                    }
+                        }),
+                    )));
                 }
-                match op {
-                    // check for overflow
-                    Prim2::Add | Prim2::Sub | Prim2::Mul => {
-                        is.extend(generate_overflow_check(*tag));
-                    }
-                    _ => (),
+                // like a non-tail call, so that the thread continues by
+                // returning from it
+                Prim2::Send => {
+                    let stack_offset = stack_align(sf_size, CallingConvention::Snake);
+                    is.extend([
+                        Instr::Sub(BinArgs::ToReg(Reg::Rsp, Arg32::Unsigned(stack_offset))),
+                        Instr::Call(JmpArg::Label(SEND.to_string())),
+                        Instr::Add(BinArgs::ToReg(Reg::Rsp, Arg32::Unsigned(stack_offset))),
+                    ])
+                }
+            }
+            match op {
+                // check for overflow
+                Prim2::Add | Prim2::Sub | Prim2::Mul => {
+                    is.extend(generate_overflow_check(*tag));
                 }
+                _ => (),
+            }
+            is
+        }
+
+        let mut is = Vec::new();
+
+        match expr {
+            SeqExp::Imm(imm, _) => {
+                is.push(compile_immediate_help(imm, &stack_lt, Reg::Rax));
+            }
+            SeqExp::Prim0(op, _) => is.extend(compile_prim0(op, sf_size)),
+            SeqExp::Prim1(op, e1, tag) => {
+                is.push(compile_immediate_help(e1, &stack_lt, Reg::Rax));
+                is.extend(compile_prim1(op, tag, sf_size));
+            }
+            SeqExp::Prim2(op, e1, e2, tag) => {
+                is.push(compile_immediate_help(e1, &stack_lt, Reg::Rax));
+                is.push(compile_immediate_help(e2, &stack_lt, Reg::R11));
+                is.extend(compile_prim2(op, tag, sf_size));
             }
             SeqExp::Let {
                 var,
@@ -546,6 +818,7 @@ pub fn compile_to_instrs(p: &SeqProg<u32>) -> Vec<Instr> {
                     stack_lt.clone(),
                     sf_size,
                     false,
+                    linkage,
                 ));
                 // create a new environment for the let block
                 let mut new_stack_lt = stack_lt.clone();
@@ -564,7 +837,25 @@ pub fn compile_to_instrs(p: &SeqProg<u32>) -> Vec<Instr> {
                 // register the new variable in the stack lookup table
                 new_stack_lt.insert(var.as_str(), mem_addr);
                 // normally compile the body with the new environment
-                is.extend(compile_to_instrs_help(body, new_stack_lt, sf_size, is_tail));
+                is.extend(compile_to_instrs_help(
+                    body,
+                    new_stack_lt,
+                    sf_size,
+                    is_tail,
+                    linkage,
+                ));
+            }
+            SeqExp::Assign {
+                var,
+                new_value,
+                ann: _,
+            } => {
+                // the new value is also the result
+                is.push(compile_immediate_help(new_value, &stack_lt, Reg::Rax));
+                is.push(Instr::Mov(MovArgs::ToMem(
+                    get_mem(stack_lt[var.as_str()]),
+                    Reg32::Reg(Reg::Rax),
+                )));
             }
             SeqExp::If {
                 cond,
@@ -590,12 +881,124 @@ pub fn compile_to_instrs(p: &SeqProg<u32>) -> Vec<Instr> {
                     stack_lt.clone(),
                     sf_size,
                     is_tail,
+                    linkage,
                 ));
                 is.push(Instr::Jmp(JmpArg::Label(format!("if_end_{}", tag))));
                 is.push(Instr::Label(format!("if_false_{}", tag)));
-                is.extend(compile_to_instrs_help(els, stack_lt, sf_size, is_tail));
+                is.extend(compile_to_instrs_help(
+                    els,
+                    stack_lt,
+                    sf_size,
+                    is_tail,
+                    linkage,
+                ));
                 is.push(Instr::Label(format!("if_end_{}", tag)));
             }
+            SeqExp::While {
+                cond,
+                body,
+                ann: tag,
+            } => {
+                is.push(Instr::Label(format!("while_start_{}", tag)));
+                is.extend(compile_to_instrs_help(
+                    cond,
+                    stack_lt.clone(),
+                    sf_size,
+                    false,
+                    linkage,
+                ));
+                is.extend(generate_type_check(
+                    Reg::Rax,
+                    SnakeType::Boolean,
+                    WHILE_ERROR,
+                    &format!("while_{}", tag),
+                ));
+                is.push(Instr::Mov(MovArgs::ToReg(
+                    Reg::R11,
+                    Arg64::Unsigned(SNAKE_FLS.0),
+                )));
+                is.push(Instr::Cmp(BinArgs::ToReg(Reg::Rax, Arg32::Reg(Reg::R11))));
+                // the loop evaluates to the false that ends it, which is in rax
+                is.push(Instr::Je(JmpArg::Label(format!("while_end_{}", tag))));
+                is.extend(compile_to_instrs_help(
+                    body,
+                    stack_lt,
+                    sf_size,
+                    false,
+                    linkage,
+                ));
+                is.push(Instr::Jmp(JmpArg::Label(format!("while_start_{}", tag))));
+                is.push(Instr::Label(format!("while_end_{}", tag)));
+            }
+            SeqExp::For {
+                var,
+                start,
+                end,
+                body,
+                ann: tag,
+            } => {
+                // both bounds must be numbers before the first iteration
+                is.push(compile_immediate_help(end, &stack_lt, Reg::R11));
+                is.extend(generate_type_check(
+                    Reg::R11,
+                    SnakeType::Number,
+                    FOR_ERROR,
+                    &format!("for_end_{}", tag),
+                ));
+                is.push(compile_immediate_help(start, &stack_lt, Reg::Rax));
+                is.extend(generate_type_check(
+                    Reg::Rax,
+                    SnakeType::Number,
+                    FOR_ERROR,
+                    &format!("for_start_{}", tag),
+                ));
+                // the loop variable lives on the stack like a `let`'s
+                let mut new_stack_lt = stack_lt.clone();
+                let mem_addr = alloc(&new_stack_lt);
+                is.push(Instr::Comment(format!(
+                    "storing {} into [rsp - {}]",
+                    var,
+                    mem_addr * 8
+                )));
+                is.push(Instr::Mov(MovArgs::ToMem(
+                    get_mem(mem_addr),
+                    Reg32::Reg(Reg::Rax),
+                )));
+                new_stack_lt.insert(var.as_str(), mem_addr);
+                is.push(Instr::Label(format!("for_start_{}", tag)));
+                is.push(Instr::Mov(MovArgs::ToReg(
+                    Reg::Rax,
+                    Arg64::Mem(get_mem(mem_addr)),
+                )));
+                is.push(compile_immediate_help(end, &stack_lt, Reg::R11));
+                is.push(Instr::Cmp(BinArgs::ToReg(Reg::Rax, Arg32::Reg(Reg::R11))));
+                is.push(Instr::Jg(JmpArg::Label(format!("for_done_{}", tag))));
+                is.extend(compile_to_instrs_help(
+                    body,
+                    new_stack_lt,
+                    sf_size,
+                    false,
+                    linkage,
+                ));
+                // step to the next number, stopping instead of wrapping around
+                // after the largest one
+                is.push(Instr::Mov(MovArgs::ToReg(
+                    Reg::Rax,
+                    Arg64::Mem(get_mem(mem_addr)),
+                )));
+                is.push(Instr::Add(BinArgs::ToReg(Reg::Rax, Arg32::Unsigned(2))));
+                is.push(Instr::Jo(JmpArg::Label(format!("for_done_{}", tag))));
+                is.push(Instr::Mov(MovArgs::ToMem(
+                    get_mem(mem_addr),
+                    Reg32::Reg(Reg::Rax),
+                )));
+                is.push(Instr::Jmp(JmpArg::Label(format!("for_start_{}", tag))));
+                is.push(Instr::Label(format!("for_done_{}", tag)));
+                is.push(Instr::Mov(MovArgs::ToReg(
+                    Reg::Rax,
+                    Arg64::Unsigned(SNAKE_FLS.0),
+                )));
+            }
             SeqExp::Array(array_values, _) => {
                 let array_size = array_values.len();
                 // Push the array size to the heap
@@ -811,12 +1214,13 @@ pub fn compile_to_instrs(p: &SeqProg<u32>) -> Vec<Instr> {
                 ann: _,
             } => is.extend([
                 // push the type tag onto the heap
+                Instr::Mov(MovArgs::ToReg(Reg::R11, type_tag(*typetag, linkage))),
                 Instr::Mov(MovArgs::ToMem(
                     MemRef {
                         reg: Reg::Rbp,
                         offset: Offset::Constant(0),
                     },
-                    Reg32::Unsigned(*typetag as u32),
+                    Reg32::Reg(Reg::R11),
                 )),
                 // push the fields array onto the heap
                 compile_immediate_help(fields, &stack_lt, Reg::Rax),
@@ -842,6 +1246,11 @@ pub fn compile_to_instrs(p: &SeqProg<u32>) -> Vec<Instr> {
                 is.extend([
                     // load snakeval in Rax
                     compile_immediate_help(expr, &stack_lt, Reg::Rax),
+                    // if the snakeval does not end in 0b101, return false
+                    Instr::Mov(MovArgs::ToReg(Reg::R10, Arg64::Unsigned(TAG_CHECKER))),
+                    Instr::And(BinArgs::ToReg(Reg::R10, Arg32::Reg(Reg::Rax))),
+                    Instr::Cmp(BinArgs::ToReg(Reg::R10, Arg32::Unsigned(0b101))),
+                    Instr::Jne(JmpArg::Label(format!("matchtype_false_{}", tag))),
                     // untag it
                     Instr::Sub(BinArgs::ToReg(Reg::Rax, Arg32::Unsigned(0b101))),
                     // it is now a pointer to the type tag, extract the tag to R10
@@ -853,12 +1262,13 @@ pub fn compile_to_instrs(p: &SeqProg<u32>) -> Vec<Instr> {
                         }),
                     )),
                     // load the expected type tag in R11
-                    Instr::Mov(MovArgs::ToReg(Reg::R11, Arg64::Unsigned(*typetag))),
+                    Instr::Mov(MovArgs::ToReg(Reg::R11, type_tag(*typetag, linkage))),
                     // compare R10 and R11
                     Instr::Cmp(BinArgs::ToReg(Reg::R10, Arg32::Reg(Reg::R11))),
                     // if equal, type matches, return true
                     Instr::Je(JmpArg::Label(format!("matchtype_true_{}", tag))),
                     // otherwise return false
+                    Instr::Label(format!("matchtype_false_{}", tag)),
                     put_snakeval_in_rax(SNAKE_FLS),
                     Instr::Jmp(JmpArg::Label(format!("matchtype_end_{}", tag))),
                     Instr::Label(format!("matchtype_true_{}", tag)),
@@ -892,7 +1302,471 @@ pub fn compile_to_instrs(p: &SeqProg<u32>) -> Vec<Instr> {
                     Instr::Label(format!("getfields_end_{}", tag)),
                 ])
             }
+            SeqExp::Raise(e, _) => {
+                is.push(compile_immediate_help(e, &stack_lt, Reg::Rax));
+                is.push(Instr::Jmp(JmpArg::Label(RAISE.to_string())));
+            }
+            SeqExp::Catch {
+                body,
+                var,
+                handler,
+                ann: tag,
+            } => {
+                // the handler record takes three slots, below the one of
+                // the enclosing record, and the raised value is in the next
+                let mem_addr = alloc(&stack_lt);
+                let record = 8 * (mem_addr + 2);
+                let mut new_stack_lt = stack_lt.clone();
+                new_stack_lt.insert(var.as_str(), mem_addr + 3);
+                let restore_handler = [
+                    Instr::Mov(MovArgs::ToReg(
+                        Reg::R11,
+                        Arg64::Label(HANDLER_SYMBOL.to_string()),
+                    )),
+                    Instr::Mov(MovArgs::ToReg(Reg::R10, Arg64::Mem(get_mem(mem_addr)))),
+                    Instr::Mov(MovArgs::ToMem(
+                        MemRef {
+                            reg: Reg::R11,
+                            offset: Offset::Constant(0),
+                        },
+                        Reg32::Reg(Reg::R10),
+                    )),
+                ];
+                is.extend([
+                    Instr::Comment(format!("handler record at [rsp - {}]", record)),
+                    Instr::Mov(MovArgs::ToReg(
+                        Reg::R11,
+                        Arg64::Label(HANDLER_SYMBOL.to_string()),
+                    )),
+                    Instr::Mov(MovArgs::ToReg(
+                        Reg::R10,
+                        Arg64::Mem(MemRef {
+                            reg: Reg::R11,
+                            offset: Offset::Constant(0),
+                        }),
+                    )),
+                    Instr::Mov(MovArgs::ToMem(get_mem(mem_addr), Reg32::Reg(Reg::R10))),
+                    Instr::Mov(MovArgs::ToMem(get_mem(mem_addr + 1), Reg32::Reg(Reg::Rsp))),
+                    Instr::Mov(MovArgs::ToReg(
+                        Reg::R10,
+                        Arg64::Label(format!("catch_{}", tag)),
+                    )),
+                    Instr::Mov(MovArgs::ToMem(get_mem(mem_addr + 2), Reg32::Reg(Reg::R10))),
+                    // make it the innermost handler
+                    Instr::Mov(MovArgs::ToReg(Reg::R10, Arg64::Reg(Reg::Rsp))),
+                    Instr::Sub(BinArgs::ToReg(Reg::R10, Arg32::Unsigned(record as u32))),
+                    Instr::Mov(MovArgs::ToMem(
+                        MemRef {
+                            reg: Reg::R11,
+                            offset: Offset::Constant(0),
+                        },
+                        Reg32::Reg(Reg::R10),
+                    )),
+                ]);
+                // the handler must be taken down before the body returns, so
+                // the body is never a tail call
+                is.extend(compile_to_instrs_help(
+                    body,
+                    new_stack_lt.clone(),
+                    sf_size,
+                    false,
+                    linkage,
+                ));
+                is.extend(restore_handler.clone());
+                is.push(Instr::Jmp(JmpArg::Label(format!("catch_end_{}", tag))));
+                // a raise continues here, with the stack pointer back as it
+                // was and the raised value in rax
+                is.push(Instr::Label(format!("catch_{}", tag)));
+                is.extend(restore_handler);
+                is.push(Instr::Mov(MovArgs::ToMem(
+                    get_mem(mem_addr + 3),
+                    Reg32::Reg(Reg::Rax),
+                )));
+                is.extend(compile_to_instrs_help(
+                    handler,
+                    new_stack_lt,
+                    sf_size,
+                    is_tail,
+                    linkage,
+                ));
+                is.push(Instr::Label(format!("catch_end_{}", tag)));
+            }
+        }
+        is
+    }
+    // The code every raise jumps to. A runtime error is made a value of the
+    // builtin error type whose tag is its error code, with the values it
+    // would print as fields.
+    fn raise_instrs() -> Vec<Instr> {
+        let caught_error = format!("{}_caught", RAISE_ERROR);
+        let not_index = format!("{}_not_index", RAISE_ERROR);
+        let not_arity = format!("{}_not_arity", RAISE_ERROR);
+        let fields = format!("{}_fields", RAISE_ERROR);
+        let no_fields = format!("{}_no_fields", RAISE_ERROR);
+        let caught = format!("{}_caught", RAISE);
+        let load_handler = [
+            Instr::Mov(MovArgs::ToReg(
+                Reg::R11,
+                Arg64::Label(HANDLER_SYMBOL.to_string()),
+            )),
+            Instr::Mov(MovArgs::ToReg(
+                Reg::R11,
+                Arg64::Mem(MemRef {
+                    reg: Reg::R11,
+                    offset: Offset::Constant(0),
+                }),
+            )),
+            Instr::Cmp(BinArgs::ToReg(Reg::R11, Arg32::Unsigned(0))),
+        ];
+        let heap = |offset: i32| MemRef {
+            reg: Reg::Rbp,
+            offset: Offset::Constant(offset),
+        };
+        let mut is = vec![Instr::Label(RAISE_ERROR.to_string())];
+        is.extend(load_handler.clone());
+        is.extend([
+            Instr::Jne(JmpArg::Label(caught_error.clone())),
+            Instr::Call(JmpArg::Label("snake_error".to_string())),
+            Instr::Label(caught_error),
+            // the number of fields in R10, and the fields that aren't snake
+            // values yet tagged as numbers
+            Instr::Mov(MovArgs::ToReg(Reg::R10, Arg64::Unsigned(1))),
+            Instr::Cmp(BinArgs::ToReg(Reg::Rdi, Arg32::Unsigned(INDEX_OUT_OF_BOUNDS as u32))),
+            Instr::Jne(JmpArg::Label(not_index.clone())),
+            Instr::Shl(BinArgs::ToReg(Reg::Rsi, Arg32::Signed(1))),
+            Instr::Label(not_index),
+            Instr::Cmp(BinArgs::ToReg(Reg::Rdi, Arg32::Unsigned(WRONG_ARITY as u32))),
+            Instr::Jne(JmpArg::Label(not_arity.clone())),
+            Instr::Shl(BinArgs::ToReg(Reg::Rsi, Arg32::Signed(1))),
+            Instr::Shl(BinArgs::ToReg(Reg::Rdx, Arg32::Signed(1))),
+            Instr::Mov(MovArgs::ToReg(Reg::R10, Arg64::Unsigned(2))),
+            Instr::Label(not_arity),
+            Instr::Cmp(BinArgs::ToReg(Reg::Rdi, Arg32::Unsigned(OVERFLOW_ERROR as u32))),
+            Instr::Je(JmpArg::Label(no_fields.clone())),
+            Instr::Cmp(BinArgs::ToReg(Reg::Rdi, Arg32::Unsigned(GENERATOR_DONE as u32))),
+            Instr::Jne(JmpArg::Label(fields.clone())),
+            Instr::Label(no_fields),
+            Instr::Mov(MovArgs::ToReg(Reg::R10, Arg64::Unsigned(0))),
+            Instr::Label(fields),
+            // the fields array always has room for two fields
+            Instr::Mov(MovArgs::ToMem(heap(0), Reg32::Reg(Reg::R10))),
+            Instr::Mov(MovArgs::ToMem(heap(8), Reg32::Reg(Reg::Rsi))),
+            Instr::Mov(MovArgs::ToMem(heap(16), Reg32::Reg(Reg::Rdx))),
+            // then the value, whose type tag is the error code
+            Instr::Mov(MovArgs::ToMem(heap(24), Reg32::Reg(Reg::Rdi))),
+            Instr::Mov(MovArgs::ToReg(Reg::Rax, Arg64::Reg(Reg::Rbp))),
+            Instr::Add(BinArgs::ToReg(Reg::Rax, Arg32::Unsigned(ARRAY_TAG))),
+            Instr::Mov(MovArgs::ToMem(heap(32), Reg32::Reg(Reg::Rax))),
+            Instr::Mov(MovArgs::ToReg(Reg::Rax, Arg64::Reg(Reg::Rbp))),
+            Instr::Add(BinArgs::ToReg(Reg::Rax, Arg32::Unsigned(24 + 0b101))),
+            Instr::Add(BinArgs::ToReg(Reg::Rbp, Arg32::Unsigned(40))),
+            Instr::Label(RAISE.to_string()),
+        ]);
+        is.extend(load_handler);
+        is.extend([
+            Instr::Jne(JmpArg::Label(caught.clone())),
+            Instr::Mov(MovArgs::ToReg(Reg::Rdi, Arg64::Unsigned(UNCAUGHT))),
+            Instr::Mov(MovArgs::ToReg(Reg::Rsi, Arg64::Reg(Reg::Rax))),
+            Instr::Call(JmpArg::Label("snake_error".to_string())),
+            // continue with the handler, on the stack of its function
+            Instr::Label(caught),
+            Instr::Mov(MovArgs::ToReg(
+                Reg::Rsp,
+                Arg64::Mem(MemRef {
+                    reg: Reg::R11,
+                    offset: Offset::Constant(8),
+                }),
+            )),
+            Instr::Mov(MovArgs::ToReg(
+                Reg::R11,
+                Arg64::Mem(MemRef {
+                    reg: Reg::R11,
+                    offset: Offset::Constant(0),
+                }),
+            )),
+            Instr::Jmp(JmpArg::Reg(Reg::R11)),
+        ]);
+        is
+    }
+    // The code of `callcc` and of the continuations it makes. A continuation
+    // is a closure whose environment is a copy of the stack from the return
+    // address of the `callcc` up to the stack base: the number of words
+    // copied, the stack pointer they were copied from, the handler record
+    // then, and the words themselves. Calling it copies them back, and
+    // returns from the `callcc` with its argument.
+    fn callcc_instrs() -> Vec<Instr> {
+        let mem = |reg: Reg, offset: i32| MemRef {
+            reg,
+            offset: Offset::Constant(offset),
+        };
+        let copy_loop = |label: String| {
+            [
+                Instr::Label(label.clone()),
+                Instr::Mov(MovArgs::ToReg(Reg::R10, Arg64::Mem(mem(Reg::Rsi, 0)))),
+                Instr::Mov(MovArgs::ToMem(mem(Reg::Rdi, 0), Reg32::Reg(Reg::R10))),
+                Instr::Add(BinArgs::ToReg(Reg::Rsi, Arg32::Unsigned(8))),
+                Instr::Add(BinArgs::ToReg(Reg::Rdi, Arg32::Unsigned(8))),
+                Instr::Sub(BinArgs::ToReg(Reg::Rcx, Arg32::Unsigned(1))),
+                Instr::Jnz(JmpArg::Label(label)),
+            ]
+        };
+        let pin_thread = format!("{}_pin_thread", CALLCC);
+        let pinned = format!("{}_pinned", CALLCC);
+        let mut is = vec![
+            Instr::Label(CALLCC.to_string()),
+            // the stack of the generator running, or else of the thread
+            // running, has to stay where it is
+            Instr::Mov(MovArgs::ToReg(
+                Reg::R10,
+                Arg64::Label(GENERATOR_SYMBOL.to_string()),
+            )),
+            Instr::Mov(MovArgs::ToReg(Reg::R10, Arg64::Mem(mem(Reg::R10, 0)))),
+            Instr::Cmp(BinArgs::ToReg(Reg::R10, Arg32::Unsigned(0))),
+            Instr::Je(JmpArg::Label(pin_thread.clone())),
+            Instr::Mov(MovArgs::ToMem(mem(Reg::R10, 120), Reg32::Unsigned(1))),
+            Instr::Jmp(JmpArg::Label(pinned.clone())),
+            Instr::Label(pin_thread),
+            Instr::Mov(MovArgs::ToReg(Reg::R10, Arg64::Label(THREAD_SYMBOL.to_string()))),
+            Instr::Mov(MovArgs::ToReg(Reg::R10, Arg64::Mem(mem(Reg::R10, 0)))),
+            Instr::Mov(MovArgs::ToMem(mem(Reg::R10, 48), Reg32::Unsigned(1))),
+            Instr::Label(pinned),
+            Instr::Mov(MovArgs::ToReg(
+                Reg::Rcx,
+                Arg64::Label(STACK_BASE_SYMBOL.to_string()),
+            )),
+            Instr::Mov(MovArgs::ToReg(Reg::Rcx, Arg64::Mem(mem(Reg::Rcx, 0)))),
+            Instr::Sub(BinArgs::ToReg(Reg::Rcx, Arg32::Reg(Reg::Rsp))),
+            Instr::Shr(BinArgs::ToReg(Reg::Rcx, Arg32::Unsigned(3))),
+            Instr::Mov(MovArgs::ToMem(mem(Reg::Rbp, 0), Reg32::Reg(Reg::Rcx))),
+            Instr::Mov(MovArgs::ToMem(mem(Reg::Rbp, 8), Reg32::Reg(Reg::Rsp))),
+            Instr::Mov(MovArgs::ToReg(
+                Reg::R10,
+                Arg64::Label(HANDLER_SYMBOL.to_string()),
+            )),
+            Instr::Mov(MovArgs::ToReg(Reg::R10, Arg64::Mem(mem(Reg::R10, 0)))),
+            Instr::Mov(MovArgs::ToMem(mem(Reg::Rbp, 16), Reg32::Reg(Reg::R10))),
+            Instr::Mov(MovArgs::ToReg(Reg::Rsi, Arg64::Reg(Reg::Rsp))),
+            Instr::Mov(MovArgs::ToReg(Reg::Rdi, Arg64::Reg(Reg::Rbp))),
+            Instr::Add(BinArgs::ToReg(Reg::Rdi, Arg32::Unsigned(24))),
+        ];
+        is.extend(copy_loop(format!("{}_copy", CALLCC)));
+        is.extend([
+            // then the closure, right after the copy
+            Instr::Mov(MovArgs::ToMem(mem(Reg::Rdi, 0), Reg32::Unsigned(1))),
+            Instr::Mov(MovArgs::ToReg(Reg::R10, Arg64::Label(CONTINUE.to_string()))),
+            Instr::Mov(MovArgs::ToMem(mem(Reg::Rdi, 8), Reg32::Reg(Reg::R10))),
+            Instr::Mov(MovArgs::ToMem(mem(Reg::Rdi, 16), Reg32::Reg(Reg::Rbp))),
+            Instr::Mov(MovArgs::ToReg(Reg::R11, Arg64::Reg(Reg::Rdi))),
+            Instr::Add(BinArgs::ToReg(Reg::R11, Arg32::Unsigned(CLOSURE_TAG))),
+            Instr::Mov(MovArgs::ToReg(Reg::Rbp, Arg64::Reg(Reg::Rdi))),
+            Instr::Add(BinArgs::ToReg(Reg::Rbp, Arg32::Unsigned(24))),
+            // tail call the closure in rax with it
+            Instr::Mov(MovArgs::ToReg(Reg::R10, Arg64::Mem(mem(Reg::Rax, 16)))),
+            Instr::Mov(MovArgs::ToMem(mem(Reg::Rsp, -8), Reg32::Reg(Reg::R10))),
+            Instr::Mov(MovArgs::ToMem(mem(Reg::Rsp, -16), Reg32::Reg(Reg::R11))),
+            Instr::Mov(MovArgs::ToReg(Reg::R10, Arg64::Mem(mem(Reg::Rax, 8)))),
+            Instr::Jmp(JmpArg::Reg(Reg::R10)),
+            // called with the copy as its environment and the value to
+            // continue with as its argument
+            Instr::Label(CONTINUE.to_string()),
+            Instr::Mov(MovArgs::ToReg(Reg::Rax, Arg64::Mem(mem(Reg::Rsp, -16)))),
+            Instr::Mov(MovArgs::ToReg(Reg::Rsi, Arg64::Mem(mem(Reg::Rsp, -8)))),
+            Instr::Mov(MovArgs::ToReg(Reg::Rcx, Arg64::Mem(mem(Reg::Rsi, 0)))),
+            Instr::Mov(MovArgs::ToReg(Reg::Rdi, Arg64::Mem(mem(Reg::Rsi, 8)))),
+            Instr::Mov(MovArgs::ToReg(Reg::R10, Arg64::Mem(mem(Reg::Rsi, 16)))),
+            Instr::Mov(MovArgs::ToReg(
+                Reg::R11,
+                Arg64::Label(HANDLER_SYMBOL.to_string()),
+            )),
+            Instr::Mov(MovArgs::ToMem(mem(Reg::R11, 0), Reg32::Reg(Reg::R10))),
+            Instr::Add(BinArgs::ToReg(Reg::Rsi, Arg32::Unsigned(24))),
+            // nothing is pushed while the stack is copied back, so the
+            // stack pointer can be moved first
+            Instr::Mov(MovArgs::ToReg(Reg::Rsp, Arg64::Reg(Reg::Rdi))),
+        ]);
+        is.extend(copy_loop(format!("{}_copy", CONTINUE)));
+        is.push(Instr::Ret);
+        is
+    }
+    // The code that switches between the stack of a generator and the stack
+    // of the code that resumes it, which saves and restores the globals
+    // along with the stack pointer
+    fn generator_instrs() -> Vec<Instr> {
+        let mem = |reg: Reg, offset: i32| MemRef {
+            reg,
+            offset: Offset::Constant(offset),
+        };
+        let global = |symbol: &str| Instr::Mov(MovArgs::ToReg(Reg::R10, Arg64::Label(symbol.to_string())));
+        // the generator running, in rsi
+        let load_generator = [
+            global(GENERATOR_SYMBOL),
+            Instr::Mov(MovArgs::ToReg(Reg::Rsi, Arg64::Mem(mem(Reg::R10, 0)))),
+        ];
+        // back to the code that resumed the generator in rsi
+        let mut restore_caller = vec![Instr::Mov(MovArgs::ToReg(Reg::Rsp, Arg64::Mem(mem(Reg::Rsi, 16))))];
+        for (symbol, offset) in [(HANDLER_SYMBOL, 24), (STACK_BASE_SYMBOL, 32), (GENERATOR_SYMBOL, 64)] {
+            restore_caller.extend([
+                global(symbol),
+                Instr::Mov(MovArgs::ToReg(Reg::R11, Arg64::Mem(mem(Reg::Rsi, offset)))),
+                Instr::Mov(MovArgs::ToMem(mem(Reg::R10, 0), Reg32::Reg(Reg::R11))),
+            ]);
+        }
+        // gives the stack of the generator in rsi, which finished, to the
+        // runtime to reuse, keeping rax. This runs on that stack, from its
+        // top, which is aligned.
+        let free_stack = [
+            Instr::Mov(MovArgs::ToMem(mem(Reg::Rsi, 0), Reg32::Unsigned(2))),
+            Instr::Mov(MovArgs::ToReg(Reg::Rdi, Arg64::Mem(mem(Reg::Rsi, 40)))),
+            Instr::Mov(MovArgs::ToReg(Reg::Rsp, Arg64::Reg(Reg::Rdi))),
+            Instr::Sub(BinArgs::ToReg(Reg::Rsp, Arg32::Unsigned(16))),
+            Instr::Mov(MovArgs::ToMem(mem(Reg::Rsp, 0), Reg32::Reg(Reg::Rax))),
+            Instr::Mov(MovArgs::ToReg(Reg::Rsi, Arg64::Mem(mem(Reg::Rsi, 120)))),
+            Instr::Call(JmpArg::Label("snake_free_stack".to_string())),
+            Instr::Mov(MovArgs::ToReg(Reg::Rax, Arg64::Mem(mem(Reg::Rsp, 0)))),
+        ];
+        let done = format!("{}_done", RESUME);
+        // called with the generator as its environment
+        let mut is = vec![
+            Instr::Label(RESUME.to_string()),
+            Instr::Mov(MovArgs::ToReg(Reg::Rsi, Arg64::Mem(mem(Reg::Rsp, -8)))),
+            Instr::Mov(MovArgs::ToReg(Reg::R10, Arg64::Mem(mem(Reg::Rsi, 0)))),
+            Instr::Cmp(BinArgs::ToReg(Reg::R10, Arg32::Unsigned(0))),
+            Instr::Jne(JmpArg::Label(done.clone())),
+            Instr::Mov(MovArgs::ToMem(mem(Reg::Rsi, 0), Reg32::Unsigned(1))),
+            Instr::Mov(MovArgs::ToMem(mem(Reg::Rsi, 16), Reg32::Reg(Reg::Rsp))),
+        ];
+        for (symbol, saved, resumed) in [(HANDLER_SYMBOL, 24, 56), (STACK_BASE_SYMBOL, 32, 40)] {
+            is.extend([
+                global(symbol),
+                Instr::Mov(MovArgs::ToReg(Reg::R11, Arg64::Mem(mem(Reg::R10, 0)))),
+                Instr::Mov(MovArgs::ToMem(mem(Reg::Rsi, saved), Reg32::Reg(Reg::R11))),
+                Instr::Mov(MovArgs::ToReg(Reg::R11, Arg64::Mem(mem(Reg::Rsi, resumed)))),
+                Instr::Mov(MovArgs::ToMem(mem(Reg::R10, 0), Reg32::Reg(Reg::R11))),
+            ]);
+        }
+        is.extend([
+            global(GENERATOR_SYMBOL),
+            Instr::Mov(MovArgs::ToReg(Reg::R11, Arg64::Mem(mem(Reg::R10, 0)))),
+            Instr::Mov(MovArgs::ToMem(mem(Reg::Rsi, 64), Reg32::Reg(Reg::R11))),
+            Instr::Mov(MovArgs::ToMem(mem(Reg::R10, 0), Reg32::Reg(Reg::Rsi))),
+            // the yield it is suspended at evaluates to false
+            Instr::Mov(MovArgs::ToReg(Reg::Rsp, Arg64::Mem(mem(Reg::Rsi, 8)))),
+            Instr::Mov(MovArgs::ToReg(Reg::Rax, Arg64::Unsigned(SNAKE_FLS.0))),
+            Instr::Ret,
+            Instr::Label(done),
+            Instr::Mov(MovArgs::ToReg(Reg::Rdi, Arg64::Unsigned(GENERATOR_DONE))),
+            Instr::Jmp(JmpArg::Label(RAISE_ERROR.to_string())),
+            Instr::Label(YIELD.to_string()),
+        ]);
+        is.extend(load_generator.clone());
+        is.extend([
+            Instr::Mov(MovArgs::ToMem(mem(Reg::Rsi, 8), Reg32::Reg(Reg::Rsp))),
+            global(HANDLER_SYMBOL),
+            Instr::Mov(MovArgs::ToReg(Reg::R11, Arg64::Mem(mem(Reg::R10, 0)))),
+            Instr::Mov(MovArgs::ToMem(mem(Reg::Rsi, 56), Reg32::Reg(Reg::R11))),
+            Instr::Mov(MovArgs::ToMem(mem(Reg::Rsi, 0), Reg32::Unsigned(0))),
+        ]);
+        is.extend(restore_caller.clone());
+        is.extend([
+            Instr::Ret,
+            // with the stack pointer at the top of the stack of the generator
+            Instr::Label(GENERATOR_START.to_string()),
+        ]);
+        is.extend(load_generator.clone());
+        is.extend([
+            Instr::Mov(MovArgs::ToReg(Reg::Rax, Arg64::Mem(mem(Reg::Rsi, 48)))),
+            Instr::Sub(BinArgs::ToReg(Reg::Rax, Arg32::Unsigned(CLOSURE_TAG))),
+            Instr::Sub(BinArgs::ToReg(Reg::Rsp, Arg32::Unsigned(8))),
+            Instr::Mov(MovArgs::ToReg(Reg::R10, Arg64::Mem(mem(Reg::Rax, 16)))),
+            Instr::Mov(MovArgs::ToMem(mem(Reg::Rsp, -16), Reg32::Reg(Reg::R10))),
+            Instr::Mov(MovArgs::ToReg(Reg::R10, Arg64::Mem(mem(Reg::Rax, 8)))),
+            Instr::Call(JmpArg::Reg(Reg::R10)),
+        ]);
+        // the body finished, so resuming it had nothing to return
+        is.extend(load_generator.clone());
+        is.extend(free_stack.clone());
+        is.extend(load_generator.clone());
+        is.extend(restore_caller.clone());
+        is.extend([
+            Instr::Mov(MovArgs::ToReg(Reg::Rdi, Arg64::Unsigned(GENERATOR_DONE))),
+            Instr::Jmp(JmpArg::Label(RAISE_ERROR.to_string())),
+            Instr::Label(GENERATOR_RAISE.to_string()),
+        ]);
+        is.extend(load_generator.clone());
+        is.extend(free_stack);
+        is.extend(load_generator);
+        is.extend(restore_caller);
+        is.push(Instr::Jmp(JmpArg::Label(RAISE.to_string())));
+        is
+    }
+    // The code that switches between the stacks of threads. The runtime
+    // keeps the contexts and picks the thread to continue with, which
+    // continues by returning from where it stopped.
+    fn threads_instrs() -> Vec<Instr> {
+        let mem = |reg: Reg, offset: i32| MemRef {
+            reg,
+            offset: Offset::Constant(offset),
+        };
+        let global = |symbol: &str| Instr::Mov(MovArgs::ToReg(Reg::R10, Arg64::Label(symbol.to_string())));
+        let globals = [(HANDLER_SYMBOL, 8), (STACK_BASE_SYMBOL, 16), (GENERATOR_SYMBOL, 24)];
+        let not_channel = format!("{}_not_channel", SWITCH);
+        let mut is = vec![
+            Instr::Label(SEND.to_string()),
+            Instr::Mov(MovArgs::ToReg(Reg::Rdi, Arg64::Unsigned(SWITCH_SEND))),
+            Instr::Jmp(JmpArg::Label(SWITCH.to_string())),
+            Instr::Label(RECV.to_string()),
+            Instr::Mov(MovArgs::ToReg(Reg::Rdi, Arg64::Unsigned(SWITCH_RECV))),
+            // the context of the thread running, in rsi
+            Instr::Label(SWITCH.to_string()),
+            global(THREAD_SYMBOL),
+            Instr::Mov(MovArgs::ToReg(Reg::Rsi, Arg64::Mem(mem(Reg::R10, 0)))),
+            Instr::Mov(MovArgs::ToMem(mem(Reg::Rsi, 32), Reg32::Reg(Reg::Rax))),
+            Instr::Mov(MovArgs::ToMem(mem(Reg::Rsi, 40), Reg32::Reg(Reg::R11))),
+            Instr::Mov(MovArgs::ToMem(mem(Reg::Rsi, 0), Reg32::Reg(Reg::Rsp))),
+        ];
+        for (symbol, offset) in globals {
+            is.extend([
+                global(symbol),
+                Instr::Mov(MovArgs::ToReg(Reg::R11, Arg64::Mem(mem(Reg::R10, 0)))),
+                Instr::Mov(MovArgs::ToMem(mem(Reg::Rsi, offset), Reg32::Reg(Reg::R11))),
+            ]);
+        }
+        // the return address keeps the stack aligned for the runtime
+        is.extend([
+            Instr::Call(JmpArg::Label("snake_switch".to_string())),
+            Instr::Cmp(BinArgs::ToReg(Reg::Rax, Arg32::Unsigned(0))),
+            Instr::Je(JmpArg::Label(not_channel.clone())),
+            Instr::Mov(MovArgs::ToReg(Reg::Rsi, Arg64::Reg(Reg::Rax))),
+            Instr::Mov(MovArgs::ToReg(Reg::Rsp, Arg64::Mem(mem(Reg::Rsi, 0)))),
+        ]);
+        for (symbol, offset) in globals {
+            is.extend([
+                global(symbol),
+                Instr::Mov(MovArgs::ToReg(Reg::R11, Arg64::Mem(mem(Reg::Rsi, offset)))),
+                Instr::Mov(MovArgs::ToMem(mem(Reg::R10, 0), Reg32::Reg(Reg::R11))),
+            ]);
         }
+        is.extend([
+            Instr::Mov(MovArgs::ToReg(Reg::Rax, Arg64::Mem(mem(Reg::Rsi, 32)))),
+            Instr::Ret,
+            Instr::Label(not_channel),
+            global(THREAD_SYMBOL),
+            Instr::Mov(MovArgs::ToReg(Reg::Rsi, Arg64::Mem(mem(Reg::R10, 0)))),
+            Instr::Mov(MovArgs::ToReg(Reg::Rsi, Arg64::Mem(mem(Reg::Rsi, 32)))),
+            Instr::Mov(MovArgs::ToReg(Reg::Rdi, Arg64::Unsigned(NOT_CHANNEL))),
+            Instr::Jmp(JmpArg::Label(RAISE_ERROR.to_string())),
+            // with the stack pointer at the top of the stack of the thread
+            Instr::Label(THREAD_START.to_string()),
+            Instr::Sub(BinArgs::ToReg(Reg::Rsp, Arg32::Unsigned(8))),
+            Instr::Mov(MovArgs::ToReg(Reg::R10, Arg64::Mem(mem(Reg::Rax, 16)))),
+            Instr::Mov(MovArgs::ToMem(mem(Reg::Rsp, -16), Reg32::Reg(Reg::R10))),
+            Instr::Mov(MovArgs::ToReg(Reg::R10, Arg64::Mem(mem(Reg::Rax, 8)))),
+            Instr::Call(JmpArg::Reg(Reg::R10)),
+            Instr::Add(BinArgs::ToReg(Reg::Rsp, Arg32::Unsigned(8))),
+            // the thread finished, so it never continues
+            Instr::Mov(MovArgs::ToReg(Reg::Rdi, Arg64::Unsigned(SWITCH_EXIT))),
+            Instr::Jmp(JmpArg::Label(SWITCH.to_string())),
+        ]);
         is
     }
     let mut is: Vec<Instr> = Vec::new();
@@ -901,7 +1775,9 @@ pub fn compile_to_instrs(p: &SeqProg<u32>) -> Vec<Instr> {
     // but that does not hold because Rust just called us
     // we solve the discrepancy by actually making it a function
     // this call re-aligns the stack since it pushes a value onto the stack
-    is.push(Instr::Label(String::from("__snake__main")));
+    is.push(Instr::Label(String::from(
+        linkage.map_or("__snake__main", |linkage| linkage.entry),
+    )));
 
     is.extend(compile_to_instrs_help(
         &p.main,
@@ -909,6 +1785,7 @@ pub fn compile_to_instrs(p: &SeqProg<u32>) -> Vec<Instr> {
         // main does not have parameters
         space_needed(&p.main, 0),
         true,
+        linkage,
     ));
 
     is.push(Instr::Ret);
@@ -927,8 +1804,13 @@ pub fn compile_to_instrs(p: &SeqProg<u32>) -> Vec<Instr> {
             // we need to take into consideration the function parameters that also take stack space
             space_needed(&funcdecl.body, funcdecl.parameters.len() as u32 + 1),
             true,
+            linkage,
         ));
         is.push(Instr::Ret);
     }
+    is.extend(raise_instrs());
+    is.extend(callcc_instrs());
+    is.extend(generator_instrs());
+    is.extend(threads_instrs());
     is
 }
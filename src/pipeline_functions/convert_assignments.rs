@@ -0,0 +1,409 @@
+use crate::syntax::{Exp, FunDecl, Prim1, Prim2};
+use std::collections::{HashMap, HashSet};
+
+// The variables bound by `var` that a function or lambda inside their scope
+// refers to, the names that are assigned to anywhere, and whether there is a
+// `callcc`. `depth` is the number of functions `e` is in, and `var_depths`
+// the number each variable bound by `var` is declared in.
+fn find_captured_and_assigned<Ann>(
+    e: &Exp<Ann>,
+    depth: usize,
+    var_depths: &mut HashMap<String, usize>,
+    captured: &mut HashSet<String>,
+    assigned: &mut HashSet<String>,
+    callcc: &mut bool,
+) {
+    if let Exp::Prim1(Prim1::CallCC, ..) = e {
+        *callcc = true;
+    }
+    // uniquify made the names of the variables unique
+    let in_nested_function = |name: &String, var_depths: &HashMap<String, usize>| {
+        var_depths.get(name).is_some_and(|d| *d < depth)
+    };
+    let mut help = |e: &Exp<Ann>| {
+        find_captured_and_assigned(e, depth, var_depths, captured, assigned, callcc)
+    };
+    match e {
+        Exp::Var(name, _) => {
+            if in_nested_function(name, var_depths) {
+                captured.insert(name.clone());
+            }
+        }
+        Exp::Num(..) | Exp::Bool(..) | Exp::Prim0(..) => (),
+        Exp::Prim1(_, operand, _) => help(operand),
+        Exp::Prim2(_, operand1, operand2, _) => {
+            help(operand1);
+            help(operand2);
+        }
+        Exp::Let { bindings, body, .. } => {
+            for (_, e) in bindings {
+                help(e);
+            }
+            help(body);
+        }
+        Exp::LetVar { bindings, body, .. } => {
+            for (x, _) in bindings {
+                var_depths.insert(x.clone(), depth);
+            }
+            let mut help = |e: &Exp<Ann>| {
+                find_captured_and_assigned(e, depth, var_depths, captured, assigned, callcc)
+            };
+            for (_, e) in bindings {
+                help(e);
+            }
+            help(body);
+        }
+        Exp::Assign { var, new_value, .. } => {
+            help(new_value);
+            if in_nested_function(var, var_depths) {
+                captured.insert(var.clone());
+            }
+            assigned.insert(var.clone());
+        }
+        Exp::If { cond, thn, els, .. } => {
+            help(cond);
+            help(thn);
+            help(els);
+        }
+        Exp::Array(es, _) => es.iter().for_each(help),
+        Exp::ArraySet {
+            array,
+            index,
+            new_value,
+            ..
+        } => {
+            help(array);
+            help(index);
+            help(new_value);
+        }
+        Exp::Semicolon { e1, e2, .. } => {
+            help(e1);
+            help(e2);
+        }
+        Exp::While { cond, body, .. } => {
+            help(cond);
+            help(body);
+        }
+        Exp::For {
+            start, end, body, ..
+        } => {
+            help(start);
+            help(end);
+            help(body);
+        }
+        Exp::FunDefs { decls, body, .. } => {
+            help(body);
+            for decl in decls {
+                find_captured_and_assigned(
+                    &decl.body,
+                    depth + 1,
+                    var_depths,
+                    captured,
+                    assigned,
+                    callcc,
+                );
+            }
+        }
+        Exp::Call(fun, args, _) => {
+            help(fun);
+            args.iter().for_each(help);
+        }
+        Exp::Lambda { body, .. } => {
+            find_captured_and_assigned(body, depth + 1, var_depths, captured, assigned, callcc)
+        }
+        Exp::Match {
+            expr,
+            default,
+            arms,
+            ..
+        } => {
+            help(expr);
+            help(default);
+            for (_, _, e) in arms {
+                help(e);
+            }
+        }
+        Exp::TypeDefs { body, .. } => help(body),
+        Exp::MakeTypeInstance { fields, .. } => help(fields),
+        Exp::MatchType { expr, .. } | Exp::GetTypeFields(expr, _) | Exp::Raise(expr, _) => {
+            help(expr)
+        }
+        Exp::Catch { body, handler, .. } => {
+            help(body);
+            help(handler);
+        }
+        // only `calls_callcc` sees these, in a program that is just loaded
+        Exp::Try { body, arms, .. } => {
+            help(body);
+            for (_, _, e) in arms {
+                help(e);
+            }
+        }
+        Exp::GetField { exp, .. } => help(exp),
+        Exp::UpdateField { exp, new_value, .. } => {
+            help(exp);
+            help(new_value);
+        }
+        Exp::Import { body, .. } => help(body),
+        Exp::Modules { modules, body, .. } => {
+            for module in modules {
+                help(&module.prog);
+            }
+            help(body);
+        }
+        // lambda_lift makes the closures
+        Exp::MakeClosure { .. } => unreachable!(),
+    }
+}
+
+// Replaces the variables bound by `var` with `let` bindings. The ones that
+// are both captured by a function and assigned to are put in an array of one
+// element, so that the function and the code around it share the variable,
+// and the rest stay in the stack slots of their `let`s. A continuation copies
+// the stack, so with `callcc` every variable assigned to is put in an array,
+// for calling a continuation not to undo assignments.
+fn convert_assignments_help<Ann>(e: &Exp<Ann>, boxed: &HashSet<String>) -> Exp<Ann>
+where
+    Ann: Clone,
+{
+    let help = |e: &Exp<Ann>| Box::new(convert_assignments_help(e, boxed));
+    match e {
+        // x[0]
+        Exp::Var(name, ann) if boxed.contains(name) => Exp::Prim2(
+            Prim2::ArrayGet,
+            Box::new(e.clone()),
+            Box::new(Exp::Num(0, ann.clone())),
+            ann.clone(),
+        ),
+        Exp::Var(..) | Exp::Num(..) | Exp::Bool(..) | Exp::Prim0(..) => e.clone(),
+        Exp::Prim1(op, operand, ann) => Exp::Prim1(*op, help(operand), ann.clone()),
+        Exp::Prim2(op, operand1, operand2, ann) => {
+            Exp::Prim2(*op, help(operand1), help(operand2), ann.clone())
+        }
+        Exp::Let { bindings, body, ann } => Exp::Let {
+            bindings: bindings
+                .iter()
+                .map(|(name, e)| (name.clone(), *help(e)))
+                .collect(),
+            body: help(body),
+            ann: ann.clone(),
+        },
+        // let x = [e]
+        Exp::LetVar { bindings, body, ann } => Exp::Let {
+            bindings: bindings
+                .iter()
+                .map(|(name, e)| {
+                    if boxed.contains(name) {
+                        (name.clone(), Exp::Array(vec![*help(e)], ann.clone()))
+                    } else {
+                        (name.clone(), *help(e))
+                    }
+                })
+                .collect(),
+            body: help(body),
+            ann: ann.clone(),
+        },
+        // (x[0] := e)[0], since setting an element evaluates to the array
+        Exp::Assign {
+            var,
+            new_value,
+            ann,
+        } if boxed.contains(var) => Exp::Prim2(
+            Prim2::ArrayGet,
+            Box::new(Exp::ArraySet {
+                array: Box::new(Exp::Var(var.clone(), ann.clone())),
+                index: Box::new(Exp::Num(0, ann.clone())),
+                new_value: help(new_value),
+                ann: ann.clone(),
+            }),
+            Box::new(Exp::Num(0, ann.clone())),
+            ann.clone(),
+        ),
+        Exp::Assign {
+            var,
+            new_value,
+            ann,
+        } => Exp::Assign {
+            var: var.clone(),
+            new_value: help(new_value),
+            ann: ann.clone(),
+        },
+        Exp::If {
+            cond,
+            thn,
+            els,
+            ann,
+        } => Exp::If {
+            cond: help(cond),
+            thn: help(thn),
+            els: help(els),
+            ann: ann.clone(),
+        },
+        Exp::Array(es, ann) => Exp::Array(es.iter().map(|e| *help(e)).collect(), ann.clone()),
+        Exp::ArraySet {
+            array,
+            index,
+            new_value,
+            ann,
+        } => Exp::ArraySet {
+            array: help(array),
+            index: help(index),
+            new_value: help(new_value),
+            ann: ann.clone(),
+        },
+        Exp::Semicolon { e1, e2, ann } => Exp::Semicolon {
+            e1: help(e1),
+            e2: help(e2),
+            ann: ann.clone(),
+        },
+        Exp::While { cond, body, ann } => Exp::While {
+            cond: help(cond),
+            body: help(body),
+            ann: ann.clone(),
+        },
+        Exp::For {
+            var,
+            start,
+            end,
+            body,
+            ann,
+        } => Exp::For {
+            var: var.clone(),
+            start: help(start),
+            end: help(end),
+            body: help(body),
+            ann: ann.clone(),
+        },
+        Exp::FunDefs { decls, body, ann } => Exp::FunDefs {
+            decls: decls
+                .iter()
+                .map(|decl| FunDecl {
+                    name: decl.name.clone(),
+                    parameters: decl.parameters.clone(),
+                    body: *help(&decl.body),
+                    doc: decl.doc.clone(),
+                    ann: decl.ann.clone(),
+                })
+                .collect(),
+            body: help(body),
+            ann: ann.clone(),
+        },
+        Exp::Call(fun, args, ann) => Exp::Call(
+            help(fun),
+            args.iter().map(|arg| *help(arg)).collect(),
+            ann.clone(),
+        ),
+        Exp::Lambda {
+            parameters,
+            body,
+            ann,
+        } => Exp::Lambda {
+            parameters: parameters.clone(),
+            body: help(body),
+            ann: ann.clone(),
+        },
+        Exp::TypeDefs {
+            decls,
+            doc,
+            body,
+            ann,
+        } => Exp::TypeDefs {
+            decls: decls.clone(),
+            doc: doc.clone(),
+            body: help(body),
+            ann: ann.clone(),
+        },
+        Exp::Match {
+            expr,
+            default,
+            arms,
+            ann,
+        } => Exp::Match {
+            expr: help(expr),
+            default: help(default),
+            arms: arms
+                .iter()
+                .map(|(t, fields, e)| (t.clone(), fields.clone(), *help(e)))
+                .collect(),
+            ann: ann.clone(),
+        },
+        Exp::MakeTypeInstance {
+            typetag,
+            fields,
+            ann,
+        } => Exp::MakeTypeInstance {
+            typetag: *typetag,
+            fields: help(fields),
+            ann: ann.clone(),
+        },
+        Exp::MatchType { expr, typetag, ann } => Exp::MatchType {
+            expr: help(expr),
+            typetag: *typetag,
+            ann: ann.clone(),
+        },
+        Exp::GetTypeFields(expr, ann) => Exp::GetTypeFields(help(expr), ann.clone()),
+        Exp::Raise(expr, ann) => Exp::Raise(help(expr), ann.clone()),
+        Exp::Catch {
+            body,
+            var,
+            handler,
+            ann,
+        } => Exp::Catch {
+            body: help(body),
+            var: var.clone(),
+            handler: help(handler),
+            ann: ann.clone(),
+        },
+        Exp::MakeClosure { .. } | Exp::Try { .. } | Exp::GetField { .. } | Exp::UpdateField { .. } => {
+            unreachable!()
+        }
+        Exp::Import { .. } | Exp::Modules { .. } => unreachable!(),
+    }
+}
+
+// Whether any file of a loaded program calls `callcc`. A unit of the
+// program compiled on its own has to box its variables as if it did, since
+// continuations can capture its frames from the code of another file.
+pub fn calls_callcc<Ann>(p: &Exp<Ann>) -> bool {
+    let mut callcc = false;
+    find_captured_and_assigned(
+        p,
+        0,
+        &mut HashMap::new(),
+        &mut HashSet::new(),
+        &mut HashSet::new(),
+        &mut callcc,
+    );
+    callcc
+}
+
+// Precondition: all names are uniquified
+pub fn convert_assignments<Ann>(p: &Exp<Ann>) -> Exp<Ann>
+where
+    Ann: Clone,
+{
+    convert_assignments_with(p, false)
+}
+
+// Like `convert_assignments`, treating `p` as calling `callcc` if `callcc`
+pub fn convert_assignments_with<Ann>(p: &Exp<Ann>, mut callcc: bool) -> Exp<Ann>
+where
+    Ann: Clone,
+{
+    let mut captured = HashSet::new();
+    let mut assigned = HashSet::new();
+    find_captured_and_assigned(
+        p,
+        0,
+        &mut HashMap::new(),
+        &mut captured,
+        &mut assigned,
+        &mut callcc,
+    );
+    let boxed = if callcc {
+        assigned
+    } else {
+        captured.intersection(&assigned).cloned().collect()
+    };
+    convert_assignments_help(p, &boxed)
+}
@@ -0,0 +1,633 @@
+use crate::syntax::{Exp, FunDecl, Module, Prim2};
+use std::collections::HashSet;
+
+// Drops the functions that the rest of the program never refers to, like
+// the ones of the prelude that it doesn't use, and likewise the values of
+// the constructors without fields, which `resolve_types` binds with a `let`.
+//
+// `used` collects the names the result refers to. In a uniquified program,
+// names used anywhere else are never the ones a `def` binds, and in any
+// other program the names are only more than what's used.
+fn drop_unused_help<Ann>(e: &Exp<Ann>, used: &mut HashSet<String>) -> Exp<Ann>
+where
+    Ann: Clone,
+{
+    let mut help = |e: &Exp<Ann>| Box::new(drop_unused_help(e, used));
+    match e {
+        Exp::FunDefs { decls, body, ann } => {
+            let body = help(body);
+            // a function is used if the body or a used function refers to it
+            let mut kept: Vec<Option<FunDecl<Exp<Ann>, Ann>>> = vec![None; decls.len()];
+            let mut changed = true;
+            while changed {
+                changed = false;
+                for (decl, kept) in decls.iter().zip(kept.iter_mut()) {
+                    if kept.is_none() && used.contains(&decl.name) {
+                        *kept = Some(FunDecl {
+                            name: decl.name.clone(),
+                            parameters: decl.parameters.clone(),
+                            body: drop_unused_help(&decl.body, used),
+                            doc: decl.doc.clone(),
+                            ann: decl.ann.clone(),
+                        });
+                        changed = true;
+                    }
+                }
+            }
+            let decls: Vec<_> = kept.into_iter().flatten().collect();
+            if decls.is_empty() {
+                *body
+            } else {
+                Exp::FunDefs {
+                    decls,
+                    body,
+                    ann: ann.clone(),
+                }
+            }
+        }
+        Exp::Var(name, ann) => {
+            used.insert(name.clone());
+            Exp::Var(name.clone(), ann.clone())
+        }
+        Exp::Num(..) | Exp::Bool(..) | Exp::Prim0(..) => e.clone(),
+        Exp::Prim1(op, operand, ann) => Exp::Prim1(*op, help(operand), ann.clone()),
+        Exp::Prim2(op, operand1, operand2, ann) => {
+            Exp::Prim2(*op, help(operand1), help(operand2), ann.clone())
+        }
+        // a binding is used if the body or a later binding refers to it
+        Exp::Let { bindings, body, ann } => {
+            let body = help(body);
+            let mut kept = vec![];
+            for (name, e) in bindings.iter().rev() {
+                let nullary = matches!(e, Exp::MakeTypeInstance { fields, .. }
+                    if matches!(**fields, Exp::Array(ref es, _) if es.is_empty()));
+                if !nullary || used.contains(name) {
+                    kept.push((name.clone(), drop_unused_help(e, used)));
+                }
+            }
+            if kept.is_empty() {
+                *body
+            } else {
+                kept.reverse();
+                Exp::Let {
+                    bindings: kept,
+                    body,
+                    ann: ann.clone(),
+                }
+            }
+        }
+        Exp::LetVar { bindings, body, ann } => Exp::LetVar {
+            bindings: bindings
+                .iter()
+                .map(|(name, e)| (name.clone(), *help(e)))
+                .collect(),
+            body: help(body),
+            ann: ann.clone(),
+        },
+        Exp::Assign {
+            var,
+            new_value,
+            ann,
+        } => Exp::Assign {
+            var: var.clone(),
+            new_value: help(new_value),
+            ann: ann.clone(),
+        },
+        Exp::If {
+            cond,
+            thn,
+            els,
+            ann,
+        } => Exp::If {
+            cond: help(cond),
+            thn: help(thn),
+            els: help(els),
+            ann: ann.clone(),
+        },
+        Exp::Array(es, ann) => Exp::Array(es.iter().map(|e| *help(e)).collect(), ann.clone()),
+        Exp::ArraySet {
+            array,
+            index,
+            new_value,
+            ann,
+        } => Exp::ArraySet {
+            array: help(array),
+            index: help(index),
+            new_value: help(new_value),
+            ann: ann.clone(),
+        },
+        Exp::Semicolon { e1, e2, ann } => Exp::Semicolon {
+            e1: help(e1),
+            e2: help(e2),
+            ann: ann.clone(),
+        },
+        Exp::While { cond, body, ann } => Exp::While {
+            cond: help(cond),
+            body: help(body),
+            ann: ann.clone(),
+        },
+        Exp::For {
+            var,
+            start,
+            end,
+            body,
+            ann,
+        } => Exp::For {
+            var: var.clone(),
+            start: help(start),
+            end: help(end),
+            body: help(body),
+            ann: ann.clone(),
+        },
+        Exp::Call(fun, args, ann) => Exp::Call(
+            help(fun),
+            args.iter().map(|arg| *help(arg)).collect(),
+            ann.clone(),
+        ),
+        Exp::Lambda {
+            parameters,
+            body,
+            ann,
+        } => Exp::Lambda {
+            parameters: parameters.clone(),
+            body: help(body),
+            ann: ann.clone(),
+        },
+        Exp::MakeClosure {
+            arity,
+            label,
+            env,
+            ann,
+        } => Exp::MakeClosure {
+            arity: *arity,
+            label: label.clone(),
+            env: help(env),
+            ann: ann.clone(),
+        },
+        Exp::TypeDefs {
+            decls,
+            doc,
+            body,
+            ann,
+        } => Exp::TypeDefs {
+            decls: decls.clone(),
+            doc: doc.clone(),
+            body: help(body),
+            ann: ann.clone(),
+        },
+        Exp::Match {
+            expr,
+            default,
+            arms,
+            ann,
+        } => Exp::Match {
+            expr: help(expr),
+            default: help(default),
+            arms: arms
+                .iter()
+                .map(|(t, fields, e)| (t.clone(), fields.clone(), *help(e)))
+                .collect(),
+            ann: ann.clone(),
+        },
+        Exp::MakeTypeInstance {
+            typetag,
+            fields,
+            ann,
+        } => Exp::MakeTypeInstance {
+            typetag: *typetag,
+            fields: help(fields),
+            ann: ann.clone(),
+        },
+        Exp::MatchType { expr, typetag, ann } => Exp::MatchType {
+            expr: help(expr),
+            typetag: *typetag,
+            ann: ann.clone(),
+        },
+        Exp::GetTypeFields(expr, ann) => Exp::GetTypeFields(help(expr), ann.clone()),
+        Exp::Raise(expr, ann) => Exp::Raise(help(expr), ann.clone()),
+        Exp::Catch {
+            body,
+            var,
+            handler,
+            ann,
+        } => Exp::Catch {
+            body: help(body),
+            var: var.clone(),
+            handler: help(handler),
+            ann: ann.clone(),
+        },
+        // only `used_names` sees a `try` or a field, in the code of a unit
+        // compiled on its own, before `resolve_types` resolves them
+        Exp::Try { body, arms, ann } => Exp::Try {
+            body: help(body),
+            arms: arms
+                .iter()
+                .map(|(t, vars, e)| (t.clone(), vars.clone(), *help(e)))
+                .collect(),
+            ann: ann.clone(),
+        },
+        Exp::GetField { exp, field, ann } => Exp::GetField {
+            exp: help(exp),
+            field: field.clone(),
+            ann: ann.clone(),
+        },
+        Exp::UpdateField {
+            exp,
+            field,
+            new_value,
+            ann,
+        } => Exp::UpdateField {
+            exp: help(exp),
+            field: field.clone(),
+            new_value: help(new_value),
+            ann: ann.clone(),
+        },
+        Exp::Import {
+            path,
+            module,
+            body,
+            ann,
+        } => Exp::Import {
+            path: path.clone(),
+            module: *module,
+            body: help(body),
+            ann: ann.clone(),
+        },
+        // the program goes first, and then each file before the files it
+        // imports, so the functions they use are known by then
+        Exp::Modules { modules, body, ann } => {
+            let body = help(body);
+            let mut modules: Vec<_> = modules
+                .iter()
+                .rev()
+                .map(|module| Module {
+                    name: module.name.clone(),
+                    prog: drop_unused_help(&module.prog, used),
+                })
+                .collect();
+            modules.reverse();
+            Exp::Modules {
+                modules,
+                body,
+                ann: ann.clone(),
+            }
+        }
+    }
+}
+
+// The names `p` refers to once its unused functions are dropped
+pub fn used_names<Ann>(p: &Exp<Ann>) -> HashSet<String>
+where
+    Ann: Clone,
+{
+    let mut used = HashSet::new();
+    drop_unused_help(p, &mut used);
+    used
+}
+
+// The array of the variables captured by the closures of a lambda or of a
+// group of functions, which `lambda_lift` names `#env_` and their tag
+fn is_env(name: &str) -> bool {
+    name.starts_with("#env_")
+}
+
+// Whether a binding can be dropped when nothing refers to its name: a
+// closure, a captured variable read from its array, the array itself, or
+// the value of a constructor without fields
+fn droppable<Ann>(name: &str, e: &Exp<Ann>) -> bool {
+    match e {
+        Exp::MakeClosure { .. } => true,
+        Exp::Prim2(Prim2::ArrayGet, array, index, _) => {
+            matches!((&**array, &**index), (Exp::Var(array, _), Exp::Num(..)) if is_env(array))
+        }
+        Exp::MakeTypeInstance { fields, .. } => {
+            matches!(**fields, Exp::Array(ref es, _) if es.is_empty())
+        }
+        _ => is_env(name),
+    }
+}
+
+// `env[i] := f`, with which `lambda_lift` ties the knot of a group of
+// functions, and `f`
+fn knot<Ann>(e: &Exp<Ann>) -> Option<&str> {
+    match e {
+        Exp::ArraySet {
+            array, new_value, ..
+        } => match (&**array, &**new_value) {
+            (Exp::Var(array, _), Exp::Var(f, _)) if is_env(array) => Some(f),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+// Collects the names `e` refers to in `used` and the labels of the closures
+// it makes in `live`. Neither the variables an array of captured variables
+// holds nor the functions the knot puts in it count: only the names read
+// back out of it do, which are the names of what was captured.
+fn mark<Ann>(e: &Exp<Ann>, used: &mut HashSet<String>, live: &mut HashSet<String>) {
+    match e {
+        Exp::Var(name, _) => {
+            used.insert(name.clone());
+        }
+        Exp::Let { bindings, body, .. } => {
+            mark(body, used, live);
+            for (name, e) in bindings.iter().rev() {
+                if !droppable(name, e) || used.contains(name) {
+                    match e {
+                        Exp::Array(..) if is_env(name) => {}
+                        _ => mark(e, used, live),
+                    }
+                }
+            }
+        }
+        Exp::MakeClosure { label, env, .. } => {
+            live.insert(label.clone());
+            mark(env, used, live);
+        }
+        Exp::ArraySet { .. } if knot(e).is_some() => {}
+        Exp::Num(..) | Exp::Bool(..) | Exp::Prim0(..) => {}
+        Exp::Prim1(_, e, _)
+        | Exp::Assign { new_value: e, .. }
+        | Exp::MakeTypeInstance { fields: e, .. }
+        | Exp::MatchType { expr: e, .. }
+        | Exp::GetTypeFields(e, _)
+        | Exp::Raise(e, _) => mark(e, used, live),
+        Exp::Prim2(_, e1, e2, _)
+        | Exp::Semicolon { e1, e2, .. }
+        | Exp::While {
+            cond: e1, body: e2, ..
+        }
+        | Exp::Catch {
+            body: e1,
+            handler: e2,
+            ..
+        } => {
+            mark(e1, used, live);
+            mark(e2, used, live);
+        }
+        Exp::If { cond, thn, els, .. } => {
+            mark(cond, used, live);
+            mark(thn, used, live);
+            mark(els, used, live);
+        }
+        Exp::ArraySet {
+            array,
+            index,
+            new_value,
+            ..
+        } => {
+            mark(array, used, live);
+            mark(index, used, live);
+            mark(new_value, used, live);
+        }
+        Exp::For {
+            start, end, body, ..
+        } => {
+            mark(start, used, live);
+            mark(end, used, live);
+            mark(body, used, live);
+        }
+        Exp::Array(es, _) => es.iter().for_each(|e| mark(e, used, live)),
+        Exp::Call(fun, args, _) => {
+            mark(fun, used, live);
+            args.iter().for_each(|arg| mark(arg, used, live));
+        }
+        Exp::Match {
+            expr,
+            default,
+            arms,
+            ..
+        } => {
+            mark(expr, used, live);
+            mark(default, used, live);
+            arms.iter().for_each(|(_, _, e)| mark(e, used, live));
+        }
+        // lambda_lift leaves none of these
+        Exp::FunDefs { .. }
+        | Exp::Lambda { .. }
+        | Exp::LetVar { .. }
+        | Exp::TypeDefs { .. }
+        | Exp::Try { .. }
+        | Exp::GetField { .. }
+        | Exp::UpdateField { .. }
+        | Exp::Import { .. }
+        | Exp::Modules { .. } => unreachable!(),
+    }
+}
+
+// `e` without the bindings nothing uses and the knots that tie them, and
+// with 0 in place of the captured variables nothing reads back
+fn sweep<Ann>(e: &Exp<Ann>, used: &HashSet<String>) -> Exp<Ann>
+where
+    Ann: Clone,
+{
+    let help = |e: &Exp<Ann>| Box::new(sweep(e, used));
+    match e {
+        Exp::Let { bindings, body, ann } => {
+            let bindings: Vec<_> = bindings
+                .iter()
+                .filter(|(name, e)| !droppable(name, e) || used.contains(name))
+                .map(|(name, e)| match e {
+                    Exp::Array(es, ann) if is_env(name) => (
+                        name.clone(),
+                        Exp::Array(
+                            es.iter()
+                                .map(|e| match e {
+                                    Exp::Var(x, ann) if !used.contains(x) => {
+                                        Exp::Num(0, ann.clone())
+                                    }
+                                    _ => e.clone(),
+                                })
+                                .collect(),
+                            ann.clone(),
+                        ),
+                    ),
+                    _ => (name.clone(), sweep(e, used)),
+                })
+                .collect();
+            if bindings.is_empty() {
+                *help(body)
+            } else {
+                Exp::Let {
+                    bindings,
+                    body: help(body),
+                    ann: ann.clone(),
+                }
+            }
+        }
+        Exp::Semicolon { e1, e2, ann } => match knot(e1) {
+            Some(f) if !used.contains(f) => *help(e2),
+            _ => Exp::Semicolon {
+                e1: help(e1),
+                e2: help(e2),
+                ann: ann.clone(),
+            },
+        },
+        Exp::Num(..) | Exp::Bool(..) | Exp::Prim0(..) | Exp::Var(..) => e.clone(),
+        Exp::Prim1(op, e, ann) => Exp::Prim1(*op, help(e), ann.clone()),
+        Exp::Prim2(op, e1, e2, ann) => Exp::Prim2(*op, help(e1), help(e2), ann.clone()),
+        Exp::Assign {
+            var,
+            new_value,
+            ann,
+        } => Exp::Assign {
+            var: var.clone(),
+            new_value: help(new_value),
+            ann: ann.clone(),
+        },
+        Exp::If {
+            cond,
+            thn,
+            els,
+            ann,
+        } => Exp::If {
+            cond: help(cond),
+            thn: help(thn),
+            els: help(els),
+            ann: ann.clone(),
+        },
+        Exp::Array(es, ann) => Exp::Array(es.iter().map(|e| *help(e)).collect(), ann.clone()),
+        Exp::ArraySet {
+            array,
+            index,
+            new_value,
+            ann,
+        } => Exp::ArraySet {
+            array: help(array),
+            index: help(index),
+            new_value: help(new_value),
+            ann: ann.clone(),
+        },
+        Exp::While { cond, body, ann } => Exp::While {
+            cond: help(cond),
+            body: help(body),
+            ann: ann.clone(),
+        },
+        Exp::For {
+            var,
+            start,
+            end,
+            body,
+            ann,
+        } => Exp::For {
+            var: var.clone(),
+            start: help(start),
+            end: help(end),
+            body: help(body),
+            ann: ann.clone(),
+        },
+        Exp::Call(fun, args, ann) => Exp::Call(
+            help(fun),
+            args.iter().map(|arg| *help(arg)).collect(),
+            ann.clone(),
+        ),
+        Exp::MakeClosure {
+            arity,
+            label,
+            env,
+            ann,
+        } => Exp::MakeClosure {
+            arity: *arity,
+            label: label.clone(),
+            env: help(env),
+            ann: ann.clone(),
+        },
+        Exp::Match {
+            expr,
+            default,
+            arms,
+            ann,
+        } => Exp::Match {
+            expr: help(expr),
+            default: help(default),
+            arms: arms
+                .iter()
+                .map(|(t, fields, e)| (t.clone(), fields.clone(), *help(e)))
+                .collect(),
+            ann: ann.clone(),
+        },
+        Exp::MakeTypeInstance {
+            typetag,
+            fields,
+            ann,
+        } => Exp::MakeTypeInstance {
+            typetag: *typetag,
+            fields: help(fields),
+            ann: ann.clone(),
+        },
+        Exp::MatchType { expr, typetag, ann } => Exp::MatchType {
+            expr: help(expr),
+            typetag: *typetag,
+            ann: ann.clone(),
+        },
+        Exp::GetTypeFields(e, ann) => Exp::GetTypeFields(help(e), ann.clone()),
+        Exp::Raise(e, ann) => Exp::Raise(help(e), ann.clone()),
+        Exp::Catch {
+            body,
+            var,
+            handler,
+            ann,
+        } => Exp::Catch {
+            body: help(body),
+            var: var.clone(),
+            handler: help(handler),
+            ann: ann.clone(),
+        },
+        // lambda_lift leaves none of these
+        Exp::FunDefs { .. }
+        | Exp::Lambda { .. }
+        | Exp::LetVar { .. }
+        | Exp::TypeDefs { .. }
+        | Exp::Try { .. }
+        | Exp::GetField { .. }
+        | Exp::UpdateField { .. }
+        | Exp::Import { .. }
+        | Exp::Modules { .. } => unreachable!(),
+    }
+}
+
+// Drops the lifted functions that no closure the program makes is of, like
+// the ones of the prelude that it doesn't use, along with the closures and
+// the values of constructors without fields that nothing refers to.
+// `lambda_lift` captures every function and variable in scope in each
+// closure, so the arrays of captured variables keep their layout, with 0
+// in place of what nothing reads back out of them.
+//
+// Precondition: the program is the result of `lambda_lift`
+pub fn drop_unused<Ann>(
+    defs: &[FunDecl<Exp<Ann>, Ann>],
+    main: &Exp<Ann>,
+) -> (Vec<FunDecl<Exp<Ann>, Ann>>, Exp<Ann>)
+where
+    Ann: Clone,
+{
+    let mut used = HashSet::new();
+    let mut live = HashSet::new();
+    // a function's captured variables are the names of the enclosing
+    // function's, so what each function uses can keep more of the others
+    loop {
+        let known = (used.len(), live.len());
+        mark(main, &mut used, &mut live);
+        for decl in defs {
+            if live.contains(&decl.name) {
+                mark(&decl.body, &mut used, &mut live);
+            }
+        }
+        if (used.len(), live.len()) == known {
+            break;
+        }
+    }
+    let defs = defs
+        .iter()
+        .filter(|decl| live.contains(&decl.name))
+        .map(|decl| FunDecl {
+            name: decl.name.clone(),
+            parameters: decl.parameters.clone(),
+            body: sweep(&decl.body, &used),
+            doc: decl.doc.clone(),
+            ann: decl.ann.clone(),
+        })
+        .collect();
+    (defs, sweep(main, &used))
+}
@@ -46,6 +46,7 @@ fn lift_function(
             .chain(params.clone().into_iter())
             .collect(),
         body,
+        doc: None,
         ann: (),
     });
 }
@@ -149,6 +150,7 @@ fn lambda_lift_help(
         }
         Exp::Num(val, _) => Exp::Num(*val, ()),
         Exp::Bool(val, _) => Exp::Bool(*val, ()),
+        Exp::Prim0(op, _) => Exp::Prim0(*op, ()),
         Exp::Var(name, _) => Exp::Var(name.clone(), ()),
         Exp::Prim1(op, operand, _) => {
             Exp::Prim1(*op, Box::new(lambda_lift_help(funcs, operand, env)), ())
@@ -183,6 +185,17 @@ fn lambda_lift_help(
                 ann: (),
             }
         }
+        Exp::Assign {
+            var,
+            new_value,
+            ann: _,
+        } => Exp::Assign {
+            var: var.clone(),
+            new_value: Box::new(lambda_lift_help(funcs, new_value, env)),
+            ann: (),
+        },
+        // convert_assignments turned these into `let`s
+        Exp::LetVar { .. } => unreachable!(),
         Exp::If {
             cond,
             thn,
@@ -226,6 +239,29 @@ fn lambda_lift_help(
             e2: Box::new(lambda_lift_help(funcs, e2, env)),
             ann: (),
         },
+        Exp::While { cond, body, ann: _ } => Exp::While {
+            cond: Box::new(lambda_lift_help(funcs, cond, env.clone())),
+            body: Box::new(lambda_lift_help(funcs, body, env)),
+            ann: (),
+        },
+        Exp::For {
+            var,
+            start,
+            end,
+            body,
+            ann: _,
+        } => {
+            // the loop variable is in scope in the body, like a `let`
+            let mut env_in_body = env.clone();
+            env_in_body.push(var.as_str());
+            Exp::For {
+                var: var.clone(),
+                start: Box::new(lambda_lift_help(funcs, start, env.clone())),
+                end: Box::new(lambda_lift_help(funcs, end, env)),
+                body: Box::new(lambda_lift_help(funcs, body, env_in_body)),
+                ann: (),
+            }
+        }
         Exp::Lambda {
             parameters,
             body,
@@ -280,10 +316,12 @@ fn lambda_lift_help(
         } => unreachable!(),
         Exp::TypeDefs {
             decls,
+            doc,
             body,
             ann: _,
         } => Exp::TypeDefs {
             decls: decls.clone(),
+            doc: doc.clone(),
             body: Box::new(lambda_lift_help(funcs, body, env)),
             ann: (),
         },
@@ -328,6 +366,26 @@ fn lambda_lift_help(
         Exp::GetTypeFields(expr, _) => {
             Exp::GetTypeFields(Box::new(lambda_lift_help(funcs, expr, env)), ())
         }
+        Exp::Raise(expr, _) => Exp::Raise(Box::new(lambda_lift_help(funcs, expr, env)), ()),
+        Exp::Catch {
+            body,
+            var,
+            handler,
+            ann: _,
+        } => {
+            // the raised value is in scope in the handler, like a `let`
+            let mut env_in_handler = env.clone();
+            env_in_handler.push(var.as_str());
+            Exp::Catch {
+                body: Box::new(lambda_lift_help(funcs, body, env)),
+                var: var.clone(),
+                handler: Box::new(lambda_lift_help(funcs, handler, env_in_handler)),
+                ann: (),
+            }
+        }
+        Exp::Try { .. } | Exp::GetField { .. } | Exp::UpdateField { .. } => unreachable!(),
+        // uniquify puts the declarations of imported files in the program
+        Exp::Import { .. } | Exp::Modules { .. } => unreachable!(),
     }
 }
 
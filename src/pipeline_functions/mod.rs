@@ -1,6 +1,9 @@
 pub mod check_prog;
 pub mod compile_to_instrs;
+pub mod convert_assignments;
+pub mod drop_unused;
 pub mod lambda_lift;
+pub mod peephole;
 pub mod print_prog;
 pub mod resolve_types;
 pub mod sequentialize;
@@ -0,0 +1,32 @@
+use crate::asm::{Arg64, Instr, JmpArg, MovArgs, Reg32};
+
+// Removes instructions that provably have no effect:
+//   mov [rsp - 8], rax
+//   mov rax, [rsp - 8]      <- the value is already in rax
+// and
+//   jmp label
+// label:                    <- falls through anyway
+pub fn peephole(instrs: Vec<Instr>) -> Vec<Instr> {
+  let mut out: Vec<Instr> = Vec::with_capacity(instrs.len());
+  let mut iter = instrs.into_iter().peekable();
+  while let Some(instr) = iter.next() {
+    match (&instr, iter.peek()) {
+      (Instr::Jmp(JmpArg::Label(target)), Some(Instr::Label(next))) if target == next => {
+        continue;
+      }
+      (
+        Instr::Mov(MovArgs::ToReg(reg, Arg64::Mem(src))),
+        _,
+      ) => {
+        if let Some(Instr::Mov(MovArgs::ToMem(dst, Reg32::Reg(stored)))) = out.last() {
+          if dst == src && stored == reg {
+            continue;
+          }
+        }
+      }
+      _ => (),
+    }
+    out.push(instr);
+  }
+  out
+}
@@ -1,16 +1,56 @@
-use crate::syntax::{Exp, ImmExp, Prim1, Prim2, SeqExp, SnakeType};
+use crate::lexer::Comment;
+use crate::span::Span1;
+use crate::syntax::{Exp, ImmExp, Prim0, Prim1, Prim2, SeqExp, SnakeType};
+use std::cmp::Reverse;
+use std::collections::HashMap;
 
 pub fn print_prog<Span>(expr: &Exp<Span>, indent: usize) -> String
+where
+    Span: Clone,
+{
+    print_exp(&|_| vec![], expr, indent)
+}
+
+// Like `print_prog`, but puts back the comments of the file `prog` was
+// parsed from. Each comment goes before the outermost expression that
+// starts first after it, or at the end if none does.
+pub fn print_prog_with_comments(prog: &Exp<Span1>, comments: &[Comment]) -> String {
+    let mut spans = vec![];
+    let tagged = prog.map_ann(&mut |span: &Span1| {
+        spans.push(*span);
+        spans.len() - 1
+    });
+    let mut leading: HashMap<usize, Vec<String>> = HashMap::new();
+    let mut trailing = String::new();
+    for comment in comments {
+        // an expression comes after the ones inside it
+        let next = spans
+            .iter()
+            .enumerate()
+            .filter(|(_, span)| span.file == comment.span.file && span.start_ix >= comment.span.end_ix)
+            .min_by_key(|(tag, span)| (span.start_ix, Reverse(span.end_ix), Reverse(*tag)));
+        match next {
+            Some((tag, _)) => leading.entry(tag).or_default().push(comment.text.clone()),
+            None => trailing += &format!("\n{}", comment.text),
+        }
+    }
+    print_exp(&|tag| leading.get(&tag).cloned().unwrap_or_default(), &tagged, 0) + &trailing
+}
+
+// `expr` printed with the `comments` before each subexpression
+fn print_exp<Span>(comments: &dyn Fn(Span) -> Vec<String>, expr: &Exp<Span>, indent: usize) -> String
 where
     Span: Clone,
 {
     format!(
-        "{}{}",
+        "{}{}{}",
         " ".repeat(indent),
+        print_comments(comments(expr.ann()), indent),
         match expr {
             Exp::Num(n, _) => format!("{}", n),
             Exp::Bool(b, _) => format!("{}", b),
             Exp::Var(v, _) => format!("{}", v),
+            Exp::Prim0(Prim0::Chan, _) => "chan()".to_string(),
             Exp::Prim1(op, a, _) => format!(
                 "{}({})",
                 match op {
@@ -23,15 +63,22 @@ where
                     Prim1::Length => "length",
                     Prim1::IsArray => "isarray",
                     Prim1::IsFun => "isfun",
+                    Prim1::CallCC => "callcc",
+                    Prim1::Yield => "yield",
+                    Prim1::Generator => "generator",
+                    Prim1::Spawn => "spawn",
+                    Prim1::Recv => "recv",
+                    Prim1::NoField => "nofield",
                 },
-                print_prog(a, 0)
+                print_exp(comments, a, 0)
             ),
             Exp::Prim2(op, a, b, _) => match op {
-                Prim2::ArrayGet => format!("{}[{}]", print_prog(a, 0), print_prog(b, 0)),
+                Prim2::ArrayGet => format!("{}[{}]", print_exp(comments, a, 0), print_exp(comments, b, 0)),
+                Prim2::Send => format!("send({}, {})", print_exp(comments, a, 0), print_exp(comments, b, 0)),
                 _ => {
                     format!(
                         "{} {} {}",
-                        print_prog(a, 0),
+                        print_exp(comments, a, 0),
                         match op {
                             Prim2::Add => "+",
                             Prim2::Sub => "-",
@@ -44,9 +91,9 @@ where
                             Prim2::Ge => ">=",
                             Prim2::Eq => "==",
                             Prim2::Neq => "!=",
-                            Prim2::ArrayGet => unreachable!(),
+                            Prim2::ArrayGet | Prim2::Send => unreachable!(),
                         },
-                        print_prog(b, 0)
+                        print_exp(comments, b, 0)
                     )
                 }
             },
@@ -59,12 +106,32 @@ where
                     "let {} in\n{}",
                     bindings
                         .iter()
-                        .map(|(name, expr)| format!("{} = {}", name, print_prog(expr, 0)))
+                        .map(|(name, expr)| format!("{} = {}", name, print_exp(comments, expr, 0)))
+                        .collect::<Vec<String>>()
+                        .join(", "),
+                    print_exp(comments, body, indent)
+                )
+            }
+            Exp::LetVar {
+                bindings,
+                body,
+                ann: _,
+            } => {
+                format!(
+                    "var {} in\n{}",
+                    bindings
+                        .iter()
+                        .map(|(name, expr)| format!("{} = {}", name, print_exp(comments, expr, 0)))
                         .collect::<Vec<String>>()
                         .join(", "),
-                    print_prog(body, indent)
+                    print_exp(comments, body, indent)
                 )
             }
+            Exp::Assign {
+                var,
+                new_value,
+                ann: _,
+            } => format!("{} := {}", var, print_exp(comments, new_value, 0)),
             Exp::If {
                 cond,
                 thn,
@@ -72,15 +139,15 @@ where
                 ann: _,
             } => format!(
                 "if {}:\n{}\n{}else:\n{}",
-                print_prog(cond, 0),
-                print_prog(thn, indent + 2),
+                print_exp(comments, cond, 0),
+                print_exp(comments, thn, indent + 2),
                 " ".repeat(indent),
-                print_prog(els, indent + 2)
+                print_exp(comments, els, indent + 2)
             ),
             Exp::Array(elts, _) => format!(
                 "[{}]",
                 elts.iter()
-                    .map(|elt| print_prog(elt, 0))
+                    .map(|elt| print_exp(comments, elt, 0))
                     .collect::<Vec<String>>()
                     .join(", ")
             ),
@@ -91,13 +158,33 @@ where
                 ann: _,
             } => format!(
                 "{}[{}] := {}",
-                print_prog(array, 0),
-                print_prog(index, 0),
-                print_prog(new_value, 0)
+                print_exp(comments, array, 0),
+                print_exp(comments, index, 0),
+                print_exp(comments, new_value, 0)
             ),
             Exp::Semicolon { e1, e2, ann: _ } => {
-                format!("{};\n{}", print_prog(e1, 0), print_prog(e2, indent))
+                format!("{};\n{}", print_exp(comments, e1, 0), print_exp(comments, e2, indent))
             }
+            Exp::While { cond, body, ann: _ } => format!(
+                "while {}:\n{}\n{}end",
+                print_exp(comments, cond, 0),
+                print_exp(comments, body, indent + 2),
+                " ".repeat(indent)
+            ),
+            Exp::For {
+                var,
+                start,
+                end,
+                body,
+                ann: _,
+            } => format!(
+                "for {} = {} to {}:\n{}\n{}end",
+                var,
+                print_exp(comments, start, 0),
+                print_exp(comments, end, 0),
+                print_exp(comments, body, indent + 2),
+                " ".repeat(indent)
+            ),
             Exp::FunDefs {
                 decls,
                 body,
@@ -107,21 +194,22 @@ where
                 decls
                     .iter()
                     .map(|decl| format!(
-                        "def {}({}):\n{}",
+                        "{}def {}({}):\n{}",
+                        print_doc(&decl.doc, indent),
                         decl.name,
                         decl.parameters.join(", "),
-                        print_prog(&decl.body, indent + 2)
+                        print_exp(comments, &decl.body, indent + 2)
                     ))
                     .collect::<Vec<String>>()
                     .join("\nand\n"),
-                print_prog(body, indent)
+                print_exp(comments, body, indent)
             ),
             Exp::Call(callee, params, _) => format!(
                 "{}({})",
-                print_prog(callee, 0),
+                print_exp(comments, callee, 0),
                 params
                     .iter()
-                    .map(|param| print_prog(param, 0))
+                    .map(|param| print_exp(comments, param, 0))
                     .collect::<Vec<String>>()
                     .join(", ")
             ),
@@ -132,42 +220,98 @@ where
             } => format!(
                 "lambda {}: {} end",
                 parameters.join(", "),
-                print_prog(body, 0)
+                print_exp(comments, body, 0)
             ),
             Exp::MakeClosure {
                 arity,
                 label,
                 env,
                 ann: _,
-            } => format!("make_closure({}, {}, {})", arity, label, print_prog(env, 0)),
+            } => format!("make_closure({}, {}, {})", arity, label, print_exp(comments, env, 0)),
             Exp::MakeTypeInstance {
                 typetag,
                 fields,
                 ann: _,
             } => {
-                format!("make_type_instance(type{}, {})", typetag, print_prog(fields, 0))
+                format!("make_type_instance(type{}, {})", typetag, print_exp(comments, fields, 0))
             }
             Exp::MatchType {
                 expr,
                 typetag,
                 ann: _,
             } => {
-                format!("{}.ofType({})", print_prog(expr, 0), typetag)
+                format!("{}.ofType({})", print_exp(comments, expr, 0), typetag)
             }
-            Exp::GetTypeFields(expr, _) => format!("{}.fields", print_prog(expr, 0)),
+            Exp::GetTypeFields(expr, _) => format!("{}.fields", print_exp(comments, expr, 0)),
+            Exp::GetField {
+                exp,
+                field,
+                ann: _,
+            } => format!("{}.{}", print_exp(comments, exp, 0), field),
+            Exp::UpdateField {
+                exp,
+                field,
+                new_value,
+                ann: _,
+            } => format!(
+                "({} with {} = {})",
+                print_exp(comments, exp, 0),
+                field,
+                print_exp(comments, new_value, 0)
+            ),
+            Exp::Raise(expr, _) => format!("raise {}", print_exp(comments, expr, 0)),
+            Exp::Try {
+                body,
+                arms,
+                ann: _,
+            } => format!(
+                "try\n{}\n{}with\n{}{}\n{}end",
+                print_exp(comments, body, indent + 2),
+                " ".repeat(indent),
+                " ".repeat(indent + 2),
+                arms.iter()
+                    .map(|(ctor, fields, expr)| format!(
+                        "case {} =>\n{},",
+                        print_pattern(ctor, fields),
+                        print_exp(comments, expr, indent + 4)
+                    ))
+                    .collect::<Vec<String>>()
+                    .join((" ".repeat(indent + 2) + "\n").as_str()),
+                " ".repeat(indent)
+            ),
+            Exp::Catch {
+                body,
+                var,
+                handler,
+                ann: _,
+            } => format!(
+                "try\n{}\n{}catch {}:\n{}\n{}end",
+                print_exp(comments, body, indent + 2),
+                " ".repeat(indent),
+                var,
+                print_exp(comments, handler, indent + 2),
+                " ".repeat(indent)
+            ),
+            Exp::Import { path, body, .. } => {
+                format!("import \"{}\"\n{}", path, print_exp(comments, body, indent))
+            }
+            // the imported files are printed on their own
+            Exp::Modules { body, .. } => print_exp(comments, body, 0),
             Exp::TypeDefs {
                 decls,
+                doc,
                 body,
                 ann: _,
             } => {
                 format!(
-                    "type {} in\n{}",
+                    "{}type {} in\n{}",
+                    print_doc(doc, indent),
                     decls
                         .iter()
                         .map(|(name, fields)| format!("{}({})", name, fields.join(", ")))
                         .collect::<Vec<String>>()
                         .join(", "),
-                    print_prog(body, indent)
+                    print_exp(comments, body, indent)
                 )
             }
             Exp::Match {
@@ -177,15 +321,14 @@ where
                 ann: _,
             } => format!(
                 "match {} default {}:\n{}{}",
-                print_prog(expr, 0),
-                print_prog(default, 0),
+                print_exp(comments, expr, 0),
+                print_exp(comments, default, 0),
                 " ".repeat(indent + 2),
                 arms.iter()
                     .map(|(ctor, fields, expr)| format!(
-                        "case {}({}) =>\n{},",
-                        print_ctor(ctor),
-                        fields.join(", "),
-                        print_prog(expr, indent + 4)
+                        "case {} =>\n{},",
+                        print_pattern(ctor, fields),
+                        print_exp(comments, expr, indent + 4)
                     ))
                     .collect::<Vec<String>>()
                     .join((" ".repeat(indent + 2) + "\n").as_str())
@@ -194,10 +337,47 @@ where
     )
 }
 
-fn print_ctor(ctor: &SnakeType) -> String {
+// A doc comment as `##` lines, each followed by the indentation of the
+// declaration it documents
+fn print_doc(doc: &Option<String>, indent: usize) -> String {
+    match doc {
+        None => String::new(),
+        Some(doc) => doc
+            .lines()
+            .map(|line| match line {
+                "" => format!("##\n{}", " ".repeat(indent)),
+                _ => format!("## {}\n{}", line, " ".repeat(indent)),
+            })
+            .collect(),
+    }
+}
+
+// The comments before an expression, each on a line of its own unless it's
+// a one-line block comment
+fn print_comments(comments: Vec<String>, indent: usize) -> String {
+    comments
+        .iter()
+        .map(|comment| match comment.starts_with("#|") && !comment.contains('\n') {
+            true => format!("{} ", comment),
+            false => format!("{}\n{}", comment, " ".repeat(indent)),
+        })
+        .collect()
+}
+
+fn print_pattern(ctor: &SnakeType, fields: &[String]) -> String {
     match ctor {
-        SnakeType::Custom(name) => name.clone(),
-        _ => format!("{:?}", ctor),
+        SnakeType::Custom(name) => format!("{}({})", name, fields.join(", ")),
+        SnakeType::CustomFields(name, names) => format!(
+            "{}{{{}}}",
+            name,
+            names
+                .iter()
+                .zip(fields)
+                .map(|(name, field)| format!("{} = {}", name, field))
+                .collect::<Vec<String>>()
+                .join(", ")
+        ),
+        _ => format!("{:?}({})", ctor, fields.join(", ")),
     }
 }
 
@@ -218,6 +398,7 @@ where
         " ".repeat(indent),
         match expr {
             SeqExp::Imm(imm, _) => print_imm(imm),
+            SeqExp::Prim0(Prim0::Chan, _) => "chan()".to_string(),
             SeqExp::Prim1(op, a, _) => format!(
                 "{}({})",
                 match op {
@@ -230,11 +411,18 @@ where
                     Prim1::Length => "length",
                     Prim1::IsArray => "isarray",
                     Prim1::IsFun => "isfun",
+                    Prim1::CallCC => "callcc",
+                    Prim1::Yield => "yield",
+                    Prim1::Generator => "generator",
+                    Prim1::Spawn => "spawn",
+                    Prim1::Recv => "recv",
+                    Prim1::NoField => "nofield",
                 },
                 print_imm(a)
             ),
             SeqExp::Prim2(op, a, b, _) => match op {
                 Prim2::ArrayGet => format!("{}[{}]", print_imm(a), print_imm(b)),
+                Prim2::Send => format!("send({}, {})", print_imm(a), print_imm(b)),
                 _ => {
                     format!(
                         "{} {} {}",
@@ -251,7 +439,7 @@ where
                             Prim2::Ge => ">=",
                             Prim2::Eq => "==",
                             Prim2::Neq => "!=",
-                            Prim2::ArrayGet => unreachable!(),
+                            Prim2::ArrayGet | Prim2::Send => unreachable!(),
                         },
                         print_imm(b)
                     )
@@ -311,6 +499,11 @@ where
                     print_sprog(body, indent)
                 ),
             },
+            SeqExp::Assign {
+                var,
+                new_value,
+                ann: _,
+            } => format!("{} := {}", var, print_imm(new_value)),
             SeqExp::If {
                 cond,
                 thn,
@@ -323,13 +516,52 @@ where
                 " ".repeat(indent),
                 print_sprog(els, indent + 2)
             ),
+            SeqExp::While { cond, body, ann: _ } => format!(
+                "while\n{}\n{}do:\n{}\n{}end",
+                print_sprog(cond, indent + 2),
+                " ".repeat(indent),
+                print_sprog(body, indent + 2),
+                " ".repeat(indent)
+            ),
+            SeqExp::For {
+                var,
+                start,
+                end,
+                body,
+                ann: _,
+            } => format!(
+                "for {} = {} to {}:\n{}\n{}end",
+                var,
+                print_imm(start),
+                print_imm(end),
+                print_sprog(body, indent + 2),
+                " ".repeat(indent)
+            ),
             SeqExp::MakeTypeInstance {
-                typetag: _,
-                fields: _,
+                typetag,
+                fields,
                 ann: _,
-            } => todo!(),
-            SeqExp::MatchType { expr: _, typetag: _, ann: _ } => todo!(),
-            SeqExp::GetTypeFields(_, _) => todo!(),
+            } => format!("make_type_instance(type{}, {})", typetag, print_imm(fields)),
+            SeqExp::MatchType {
+                expr,
+                typetag,
+                ann: _,
+            } => format!("{}.ofType({})", print_imm(expr), typetag),
+            SeqExp::GetTypeFields(expr, _) => format!("{}.fields", print_imm(expr)),
+            SeqExp::Raise(expr, _) => format!("raise {}", print_imm(expr)),
+            SeqExp::Catch {
+                body,
+                var,
+                handler,
+                ann: _,
+            } => format!(
+                "try\n{}\n{}catch {}:\n{}\n{}end",
+                print_sprog(body, indent + 2),
+                " ".repeat(indent),
+                var,
+                print_sprog(handler, indent + 2),
+                " ".repeat(indent)
+            ),
         }
     )
 }
@@ -1,12 +1,45 @@
-use crate::syntax::{Exp, FunDecl, Prim1, Prim2, SnakeType};
+use crate::syntax::{error_tag, Exp, FunDecl, Prim1, Prim2, SnakeType, ERROR_TYPES};
 use std::collections::HashMap;
 
+// The custom types declared so far, by their unique names
+struct Types {
+    tags: HashMap<String, u64>,
+    // the names of the fields of each declared type, which the builtin
+    // error types don't have
+    fields: HashMap<String, Vec<String>>,
+}
+
+impl Types {
+    fn tag(&self, name: &str) -> u64 {
+        *self.tags.get(name).unwrap()
+    }
+
+    // The index of the field `field` of the type `name`
+    fn field_index(&self, name: &str, field: &str) -> usize {
+        self.fields[name].iter().position(|f| f == field).unwrap()
+    }
+
+    // The tags of the types with a field named `field`, with their fields,
+    // in the order they were declared in
+    fn with_field(&self, field: &str) -> Vec<(u64, &[String])> {
+        let mut types: Vec<(u64, &[String])> = self
+            .fields
+            .iter()
+            .filter(|(_, fields)| fields.iter().any(|f| f == field))
+            .map(|(name, fields)| (self.tag(name), fields.as_slice()))
+            .collect();
+        types.sort_by_key(|(tag, _)| *tag);
+        types
+    }
+}
+
 // return the original expression except with every instance of
 // var_to_replace replaced with new_var
 fn replace_var_name(expr: &Exp<()>, var_to_replace: String, new_var: String) -> Exp<()> {
     match expr {
         Exp::Num(num, _) => Exp::Num(*num, ()),
         Exp::Bool(boolean, _) => Exp::Bool(*boolean, ()),
+        Exp::Prim0(op, _) => Exp::Prim0(*op, ()),
         Exp::Var(var, _) => {
             if *var == var_to_replace {
                 Exp::Var(new_var.clone(), ())
@@ -78,6 +111,36 @@ fn replace_var_name(expr: &Exp<()>, var_to_replace: String, new_var: String) ->
             )),
             ann: (),
         },
+        Exp::While { cond, body, ann: _ } => Exp::While {
+            cond: Box::new(replace_var_name(
+                cond,
+                var_to_replace.clone(),
+                new_var.clone(),
+            )),
+            body: Box::new(replace_var_name(body, var_to_replace, new_var)),
+            ann: (),
+        },
+        Exp::For {
+            var,
+            start,
+            end,
+            body,
+            ann: _,
+        } => Exp::For {
+            var: var.clone(),
+            start: Box::new(replace_var_name(
+                start,
+                var_to_replace.clone(),
+                new_var.clone(),
+            )),
+            end: Box::new(replace_var_name(
+                end,
+                var_to_replace.clone(),
+                new_var.clone(),
+            )),
+            body: Box::new(replace_var_name(body, var_to_replace, new_var)),
+            ann: (),
+        },
         Exp::Let {
             bindings,
             body,
@@ -99,6 +162,40 @@ fn replace_var_name(expr: &Exp<()>, var_to_replace: String, new_var: String) ->
             )),
             ann: (),
         },
+        Exp::LetVar {
+            bindings,
+            body,
+            ann: _,
+        } => Exp::LetVar {
+            bindings: bindings
+                .iter()
+                .map(|(var_name, exp)| {
+                    (
+                        var_name.clone(),
+                        replace_var_name(exp, var_to_replace.clone(), new_var.clone()),
+                    )
+                })
+                .collect(),
+            body: Box::new(replace_var_name(
+                body,
+                var_to_replace.clone(),
+                new_var.clone(),
+            )),
+            ann: (),
+        },
+        Exp::Assign {
+            var,
+            new_value,
+            ann: _,
+        } => Exp::Assign {
+            var: if *var == var_to_replace {
+                new_var.clone()
+            } else {
+                var.clone()
+            },
+            new_value: Box::new(replace_var_name(new_value, var_to_replace, new_var)),
+            ann: (),
+        },
         Exp::If {
             cond,
             thn,
@@ -133,6 +230,7 @@ fn replace_var_name(expr: &Exp<()>, var_to_replace: String, new_var: String) ->
                     name: decl.name.clone(),
                     parameters: decl.parameters.clone(),
                     body: replace_var_name(&decl.body, var_to_replace.clone(), new_var.clone()),
+                    doc: decl.doc.clone(),
                     ann: (),
                 })
                 .collect(),
@@ -174,10 +272,12 @@ fn replace_var_name(expr: &Exp<()>, var_to_replace: String, new_var: String) ->
         } => unreachable!(),
         Exp::TypeDefs {
             decls,
+            doc,
             body,
             ann: _,
         } => Exp::TypeDefs {
             decls: decls.clone(),
+            doc: doc.clone(),
             body: Box::new(replace_var_name(
                 body,
                 var_to_replace.clone(),
@@ -235,34 +335,237 @@ fn replace_var_name(expr: &Exp<()>, var_to_replace: String, new_var: String) ->
             typetag: *typetag,
             ann: (),
         },
-        Exp::GetTypeFields(exp, _) => replace_var_name(exp, var_to_replace, new_var),
+        Exp::GetTypeFields(exp, _) => Exp::GetTypeFields(
+            Box::new(replace_var_name(exp, var_to_replace, new_var)),
+            (),
+        ),
+        Exp::Raise(e, _) => Exp::Raise(Box::new(replace_var_name(e, var_to_replace, new_var)), ()),
+        Exp::Try { .. } | Exp::GetField { .. } | Exp::UpdateField { .. } => unreachable!(),
+        Exp::Catch {
+            body,
+            var,
+            handler,
+            ann: _,
+        } => Exp::Catch {
+            body: Box::new(replace_var_name(
+                body,
+                var_to_replace.clone(),
+                new_var.clone(),
+            )),
+            var: var.clone(),
+            handler: Box::new(replace_var_name(handler, var_to_replace, new_var)),
+            ann: (),
+        },
+        // uniquify puts the declarations of imported files in the program
+        Exp::Import { .. } | Exp::Modules { .. } => unreachable!(),
+    }
+}
+
+// The arms of a `match` or `try` as `if`s that test the value of
+// `matchee_var`, whose fields are in `fields_var`, in turn, ending with
+// `default`
+fn resolve_arms(
+    matchee_var: String,
+    fields_var: String,
+    default: Exp<()>,
+    arms: &[(SnakeType, Vec<String>, Exp<u32>)],
+    types: &mut Types,
+) -> Exp<()> {
+    let mut ret_exp = default;
+    for (snake_type, args, arm_exp) in arms.iter().rev() {
+        match snake_type {
+            SnakeType::Custom(type_name) | SnakeType::CustomFields(type_name, _) => {
+                // the fields of an arm binding them by name aren't in order
+                let indices: Vec<usize> = match snake_type {
+                    SnakeType::CustomFields(_, fields) => fields
+                        .iter()
+                        .map(|field| types.field_index(type_name, field))
+                        .collect(),
+                    _ => (0..args.len()).collect(),
+                };
+                ret_exp = Exp::If {
+                    cond: Box::new(Exp::MatchType {
+                        expr: Box::new(Exp::Var(matchee_var.clone(), ())),
+                        typetag: types.tag(type_name),
+                        ann: (),
+                    }),
+                    thn: Box::new(Exp::Let {
+                        bindings: args
+                            .into_iter()
+                            .zip(indices)
+                            .map(|(param, idx)| {
+                                (
+                                    param.clone(),
+                                    Exp::Prim2(
+                                        Prim2::ArrayGet,
+                                        Box::new(Exp::Var(fields_var.clone(), ())),
+                                        Box::new(Exp::Num(idx as i64, ())),
+                                        (),
+                                    ),
+                                )
+                            })
+                            .collect(),
+                        body: Box::new(resolve_types_helper(arm_exp, types)),
+                        ann: (),
+                    }),
+                    els: Box::new(ret_exp),
+                    ann: (),
+                };
+            }
+            SnakeType::Array => {
+                ret_exp = Exp::If {
+                    cond: Box::new(Exp::Prim1(
+                        Prim1::IsArray,
+                        Box::new(Exp::Var(matchee_var.clone(), ())),
+                        (),
+                    )),
+                    // args will always only have one argument in a non custom snake type
+                    thn: Box::new(replace_var_name(
+                        &resolve_types_helper(arm_exp, types),
+                        args[0].clone(),
+                        matchee_var.clone(),
+                    )),
+                    els: Box::new(ret_exp),
+                    ann: (),
+                };
+            }
+            SnakeType::Func => {
+                ret_exp = Exp::If {
+                    cond: Box::new(Exp::Prim1(
+                        Prim1::IsFun,
+                        Box::new(Exp::Var(matchee_var.clone(), ())),
+                        (),
+                    )),
+                    // args will always only have one argument in a non custom snake type
+                    thn: Box::new(replace_var_name(
+                        &resolve_types_helper(arm_exp, types),
+                        args[0].clone(),
+                        matchee_var.clone(),
+                    )),
+                    els: Box::new(ret_exp),
+                    ann: (),
+                };
+            }
+            SnakeType::Bool => {
+                ret_exp = Exp::If {
+                    cond: Box::new(Exp::Prim1(
+                        Prim1::IsBool,
+                        Box::new(Exp::Var(matchee_var.clone(), ())),
+                        (),
+                    )),
+                    // args will always only have one argument in a non custom snake type
+                    thn: Box::new(replace_var_name(
+                        &resolve_types_helper(arm_exp, types),
+                        args[0].clone(),
+                        matchee_var.clone(),
+                    )),
+                    els: Box::new(ret_exp),
+                    ann: (),
+                };
+            }
+            SnakeType::Num => {
+                ret_exp = Exp::If {
+                    cond: Box::new(Exp::Prim1(
+                        Prim1::IsNum,
+                        Box::new(Exp::Var(matchee_var.clone(), ())),
+                        (),
+                    )),
+                    // args will always only have one argument in a non custom snake type
+                    thn: Box::new(replace_var_name(
+                        &resolve_types_helper(arm_exp, types),
+                        args[0].clone(),
+                        matchee_var.clone(),
+                    )),
+                    els: Box::new(ret_exp),
+                    ann: (),
+                };
+            }
+        }
+    }
+    ret_exp
+}
+
+// An `if` for each type with a field named `field`, which tests whether
+// the value of `var` is of that type and evaluates to `found` of the type's
+// tag, the fields of `var` in `fields_var` and the field's index. Values of
+// none of them raise FieldError.
+fn resolve_field(
+    var: String,
+    fields_var: String,
+    field: &str,
+    types: &Types,
+    found: impl Fn(u64, &Exp<()>, usize, &[String]) -> Exp<()>,
+) -> Exp<()> {
+    let mut ret_exp = Exp::Prim1(Prim1::NoField, Box::new(Exp::Var(var.clone(), ())), ());
+    for (tag, fields) in types.with_field(field).into_iter().rev() {
+        let idx = fields.iter().position(|f| f == field).unwrap();
+        ret_exp = Exp::If {
+            cond: Box::new(Exp::MatchType {
+                expr: Box::new(Exp::Var(var.clone(), ())),
+                typetag: tag,
+                ann: (),
+            }),
+            thn: Box::new(found(tag, &Exp::Var(fields_var.clone(), ()), idx, fields)),
+            els: Box::new(ret_exp),
+            ann: (),
+        };
+    }
+    ret_exp
+}
+
+// The element `idx` of the array `array`
+fn array_get(array: &Exp<()>, idx: usize) -> Exp<()> {
+    Exp::Prim2(
+        Prim2::ArrayGet,
+        Box::new(array.clone()),
+        Box::new(Exp::Num(idx as i64, ())),
+        (),
+    )
+}
+
+// The body of a function. Calling a generator function makes a generator
+// that runs its body, as a function of no arguments.
+fn resolve_fun_body(body: &Exp<u32>, types: &mut Types) -> Exp<()> {
+    let resolved = resolve_types_helper(body, types);
+    match body.first_yield() {
+        Some(_) => Exp::Prim1(
+            Prim1::Generator,
+            Box::new(Exp::Lambda {
+                parameters: vec![],
+                body: Box::new(resolved),
+                ann: (),
+            }),
+            (),
+        ),
+        None => resolved,
     }
 }
 
 fn resolve_types_helper<'exp>(
     expr: &'exp Exp<u32>,
-    type_tag_translation: &'exp mut HashMap<String, u64>,
+    types: &'exp mut Types,
 ) -> Exp<()> {
     match expr {
         Exp::Num(num, _) => Exp::Num(*num, ()),
         Exp::Bool(boolean, _) => Exp::Bool(*boolean, ()),
+        Exp::Prim0(op, _) => Exp::Prim0(*op, ()),
         Exp::Var(var, _) => Exp::Var(var.clone(), ()),
         Exp::Prim1(op, operand, _) => Exp::Prim1(
             *op,
-            Box::new(resolve_types_helper(operand, type_tag_translation)),
+            Box::new(resolve_types_helper(operand, types)),
             (),
         ),
         Exp::Prim2(op, operand1, operand2, _) => Exp::Prim2(
             *op,
-            Box::new(resolve_types_helper(operand1, type_tag_translation)),
-            Box::new(resolve_types_helper(operand2, type_tag_translation)),
+            Box::new(resolve_types_helper(operand1, types)),
+            Box::new(resolve_types_helper(operand2, types)),
             (),
         ),
         Exp::Array(array_values, _) => Exp::Array(
             array_values
                 .iter()
                 .map(|array_val| -> Exp<()> {
-                    resolve_types_helper(&array_val, type_tag_translation)
+                    resolve_types_helper(&array_val, types)
                 })
                 .collect(),
             (),
@@ -273,14 +576,32 @@ fn resolve_types_helper<'exp>(
             new_value,
             ann: _,
         } => Exp::ArraySet {
-            array: Box::new(resolve_types_helper(array, type_tag_translation)),
-            index: Box::new(resolve_types_helper(index, type_tag_translation)),
-            new_value: Box::new(resolve_types_helper(new_value, type_tag_translation)),
+            array: Box::new(resolve_types_helper(array, types)),
+            index: Box::new(resolve_types_helper(index, types)),
+            new_value: Box::new(resolve_types_helper(new_value, types)),
             ann: (),
         },
         Exp::Semicolon { e1, e2, ann: _ } => Exp::Semicolon {
-            e1: Box::new(resolve_types_helper(e1, type_tag_translation)),
-            e2: Box::new(resolve_types_helper(e2, type_tag_translation)),
+            e1: Box::new(resolve_types_helper(e1, types)),
+            e2: Box::new(resolve_types_helper(e2, types)),
+            ann: (),
+        },
+        Exp::While { cond, body, ann: _ } => Exp::While {
+            cond: Box::new(resolve_types_helper(cond, types)),
+            body: Box::new(resolve_types_helper(body, types)),
+            ann: (),
+        },
+        Exp::For {
+            var,
+            start,
+            end,
+            body,
+            ann: _,
+        } => Exp::For {
+            var: var.clone(),
+            start: Box::new(resolve_types_helper(start, types)),
+            end: Box::new(resolve_types_helper(end, types)),
+            body: Box::new(resolve_types_helper(body, types)),
             ann: (),
         },
         Exp::Let {
@@ -293,11 +614,37 @@ fn resolve_types_helper<'exp>(
                 .map(|(var_name, exp)| {
                     (
                         var_name.clone(),
-                        resolve_types_helper(exp, type_tag_translation),
+                        resolve_types_helper(exp, types),
+                    )
+                })
+                .collect(),
+            body: Box::new(resolve_types_helper(body, types)),
+            ann: (),
+        },
+        Exp::LetVar {
+            bindings,
+            body,
+            ann: _,
+        } => Exp::LetVar {
+            bindings: bindings
+                .iter()
+                .map(|(var_name, exp)| {
+                    (
+                        var_name.clone(),
+                        resolve_types_helper(exp, types),
                     )
                 })
                 .collect(),
-            body: Box::new(resolve_types_helper(body, type_tag_translation)),
+            body: Box::new(resolve_types_helper(body, types)),
+            ann: (),
+        },
+        Exp::Assign {
+            var,
+            new_value,
+            ann: _,
+        } => Exp::Assign {
+            var: var.clone(),
+            new_value: Box::new(resolve_types_helper(new_value, types)),
             ann: (),
         },
         Exp::If {
@@ -306,9 +653,9 @@ fn resolve_types_helper<'exp>(
             els,
             ann: _,
         } => Exp::If {
-            cond: Box::new(resolve_types_helper(cond, type_tag_translation)),
-            thn: Box::new(resolve_types_helper(thn, type_tag_translation)),
-            els: Box::new(resolve_types_helper(els, type_tag_translation)),
+            cond: Box::new(resolve_types_helper(cond, types)),
+            thn: Box::new(resolve_types_helper(thn, types)),
+            els: Box::new(resolve_types_helper(els, types)),
             ann: (),
         },
         Exp::FunDefs {
@@ -321,18 +668,19 @@ fn resolve_types_helper<'exp>(
                 .map(|decl| FunDecl {
                     name: decl.name.clone(),
                     parameters: decl.parameters.clone(),
-                    body: resolve_types_helper(&decl.body, type_tag_translation),
+                    body: resolve_fun_body(&decl.body, types),
+                    doc: decl.doc.clone(),
                     ann: (),
                 })
                 .collect(),
-            body: Box::new(resolve_types_helper(body, type_tag_translation)),
+            body: Box::new(resolve_types_helper(body, types)),
             ann: (),
         },
         Exp::Call(callee, params, _) => Exp::Call(
-            Box::new(resolve_types_helper(callee, type_tag_translation)),
+            Box::new(resolve_types_helper(callee, types)),
             params
                 .into_iter()
-                .map(|param| -> Exp<()> { resolve_types_helper(param, type_tag_translation) })
+                .map(|param| -> Exp<()> { resolve_types_helper(param, types) })
                 .collect(),
             (),
         ),
@@ -342,7 +690,7 @@ fn resolve_types_helper<'exp>(
             ann: _,
         } => Exp::Lambda {
             parameters: parameters.clone(),
-            body: Box::new(resolve_types_helper(body, type_tag_translation)),
+            body: Box::new(resolve_fun_body(body, types)),
             ann: (),
         },
         Exp::MakeClosure {
@@ -353,20 +701,22 @@ fn resolve_types_helper<'exp>(
         } => unreachable!(),
         Exp::TypeDefs {
             decls,
+            doc: _,
             body,
             ann: _,
         } => {
-            for (name, _) in decls {
-                type_tag_translation.insert(name.clone(), type_tag_translation.len() as u64);
+            for (name, fields) in decls {
+                types.tags.insert(name.clone(), types.tags.len() as u64);
+                types.fields.insert(name.clone(), fields.clone());
             }
-            let mut ret_exp = resolve_types_helper(body, type_tag_translation);
+            let mut ret_exp = resolve_types_helper(body, types);
             for (name, args) in decls.iter().rev() {
                 if args.len() == 0 {
                     ret_exp = Exp::Let {
                         bindings: vec![(
                             name.clone(),
                             Exp::MakeTypeInstance {
-                                typetag: *type_tag_translation.get(name).unwrap(),
+                                typetag: types.tag(name),
                                 fields: Box::new(Exp::Array(vec![], ())),
                                 ann: (),
                             },
@@ -380,7 +730,7 @@ fn resolve_types_helper<'exp>(
                             name: name.clone(),
                             parameters: args.clone(),
                             body: Exp::MakeTypeInstance {
-                                typetag: *type_tag_translation.get(name).unwrap(),
+                                typetag: types.tag(name),
                                 fields: Box::new(Exp::Array(
                                     args.into_iter()
                                         .map(|type_arg| Exp::Var(type_arg.clone(), ()))
@@ -389,6 +739,7 @@ fn resolve_types_helper<'exp>(
                                 )),
                                 ann: (),
                             },
+                            doc: None,
                             ann: (),
                         }],
                         body: Box::new(ret_exp),
@@ -406,114 +757,18 @@ fn resolve_types_helper<'exp>(
         } => {
             let matchee_var = format!("__matchee_{}", expr.ann());
             let fields_var = format!("__fields_{}", expr.ann());
-            let mut ret_exp = resolve_types_helper(default, type_tag_translation);
-            for (snake_type, args, arm_exp) in arms.into_iter().rev() {
-                match snake_type {
-                    SnakeType::Custom(type_name) => {
-                        ret_exp = Exp::If {
-                            cond: Box::new(Exp::MatchType {
-                                expr: Box::new(Exp::Var(matchee_var.clone(), ())),
-                                typetag: type_tag_translation.get(type_name).unwrap().clone(),
-                                ann: (),
-                            }),
-                            thn: Box::new(Exp::Let {
-                                bindings: args
-                                    .into_iter()
-                                    .enumerate()
-                                    .map(|(idx, param)| {
-                                        (
-                                            param.clone(),
-                                            Exp::Prim2(
-                                                Prim2::ArrayGet,
-                                                Box::new(Exp::Var(fields_var.clone(), ())),
-                                                Box::new(Exp::Num(idx as i64, ())),
-                                                (),
-                                            ),
-                                        )
-                                    })
-                                    .collect(),
-                                body: Box::new(resolve_types_helper(arm_exp, type_tag_translation)),
-                                ann: (),
-                            }),
-                            els: Box::new(ret_exp),
-                            ann: (),
-                        };
-                    }
-                    SnakeType::Array => {
-                        ret_exp = Exp::If {
-                            cond: Box::new(Exp::Prim1(
-                                Prim1::IsArray,
-                                Box::new(Exp::Var(matchee_var.clone(), ())),
-                                (),
-                            )),
-                            // args will always only have one argument in a non custom snake type
-                            thn: Box::new(replace_var_name(
-                                &resolve_types_helper(arm_exp, type_tag_translation),
-                                args[0].clone(),
-                                matchee_var.clone(),
-                            )),
-                            els: Box::new(ret_exp),
-                            ann: (),
-                        };
-                    }
-                    SnakeType::Func => {
-                        ret_exp = Exp::If {
-                            cond: Box::new(Exp::Prim1(
-                                Prim1::IsFun,
-                                Box::new(Exp::Var(matchee_var.clone(), ())),
-                                (),
-                            )),
-                            // args will always only have one argument in a non custom snake type
-                            thn: Box::new(replace_var_name(
-                                &resolve_types_helper(arm_exp, type_tag_translation),
-                                args[0].clone(),
-                                matchee_var.clone(),
-                            )),
-                            els: Box::new(ret_exp),
-                            ann: (),
-                        };
-                    }
-                    SnakeType::Bool => {
-                        ret_exp = Exp::If {
-                            cond: Box::new(Exp::Prim1(
-                                Prim1::IsBool,
-                                Box::new(Exp::Var(matchee_var.clone(), ())),
-                                (),
-                            )),
-                            // args will always only have one argument in a non custom snake type
-                            thn: Box::new(replace_var_name(
-                                &resolve_types_helper(arm_exp, type_tag_translation),
-                                args[0].clone(),
-                                matchee_var.clone(),
-                            )),
-                            els: Box::new(ret_exp),
-                            ann: (),
-                        };
-                    }
-                    SnakeType::Num => {
-                        ret_exp = Exp::If {
-                            cond: Box::new(Exp::Prim1(
-                                Prim1::IsNum,
-                                Box::new(Exp::Var(matchee_var.clone(), ())),
-                                (),
-                            )),
-                            // args will always only have one argument in a non custom snake type
-                            thn: Box::new(replace_var_name(
-                                &resolve_types_helper(arm_exp, type_tag_translation),
-                                args[0].clone(),
-                                matchee_var.clone(),
-                            )),
-                            els: Box::new(ret_exp),
-                            ann: (),
-                        };
-                    }
-                }
-            }
+            let mut ret_exp = resolve_arms(
+                matchee_var.clone(),
+                fields_var.clone(),
+                resolve_types_helper(default, types),
+                arms,
+                types,
+            );
             ret_exp = Exp::Let {
                 bindings: vec![
                     (
                         matchee_var.clone(),
-                        resolve_types_helper(expr, type_tag_translation),
+                        resolve_types_helper(expr, types),
                     ),
                     (
                         fields_var,
@@ -535,12 +790,113 @@ fn resolve_types_helper<'exp>(
             typetag: _,
             ann: _,
         } => unreachable!(),
-        Exp::GetTypeFields(_, _) => unreachable!(),
+        Exp::GetTypeFields(_, _) | Exp::Catch { .. } => unreachable!(),
+        Exp::GetField {
+            exp,
+            field,
+            ann: tag,
+        } => {
+            let var = format!("__field_of_{}", tag);
+            let fields_var = format!("__field_of_fields_{}", tag);
+            Exp::Let {
+                bindings: vec![
+                    (var.clone(), resolve_types_helper(exp, types)),
+                    (
+                        fields_var.clone(),
+                        Exp::GetTypeFields(Box::new(Exp::Var(var.clone(), ())), ()),
+                    ),
+                ],
+                body: Box::new(resolve_field(var, fields_var, field, types, |_, fields, idx, _| {
+                    array_get(fields, idx)
+                })),
+                ann: (),
+            }
+        }
+        // a new instance of the same type, with the new value in place of
+        // the field
+        Exp::UpdateField {
+            exp,
+            field,
+            new_value,
+            ann: tag,
+        } => {
+            let var = format!("__update_{}", tag);
+            let fields_var = format!("__update_fields_{}", tag);
+            let value_var = format!("__update_value_{}", tag);
+            let value = Exp::Var(value_var.clone(), ());
+            Exp::Let {
+                bindings: vec![
+                    (var.clone(), resolve_types_helper(exp, types)),
+                    (
+                        fields_var.clone(),
+                        Exp::GetTypeFields(Box::new(Exp::Var(var.clone(), ())), ()),
+                    ),
+                    (value_var, resolve_types_helper(new_value, types)),
+                ],
+                body: Box::new(resolve_field(
+                    var,
+                    fields_var,
+                    field,
+                    types,
+                    |typetag, fields, idx, names| Exp::MakeTypeInstance {
+                        typetag,
+                        fields: Box::new(Exp::Array(
+                            (0..names.len())
+                                .map(|i| if i == idx { value.clone() } else { array_get(fields, i) })
+                                .collect(),
+                            (),
+                        )),
+                        ann: (),
+                    },
+                )),
+                ann: (),
+            }
+        }
+        Exp::Raise(e, _) => Exp::Raise(Box::new(resolve_types_helper(e, types)), ()),
+        // the raised value is matched like the value of a `match` whose
+        // default raises it again
+        Exp::Try {
+            body,
+            arms,
+            ann: tag,
+        } => {
+            let raised_var = format!("__raised_{}", tag);
+            let fields_var = format!("__raised_fields_{}", tag);
+            let handler = resolve_arms(
+                raised_var.clone(),
+                fields_var.clone(),
+                Exp::Raise(Box::new(Exp::Var(raised_var.clone(), ())), ()),
+                arms,
+                types,
+            );
+            Exp::Catch {
+                body: Box::new(resolve_types_helper(body, types)),
+                var: raised_var.clone(),
+                handler: Box::new(Exp::Let {
+                    bindings: vec![(
+                        fields_var,
+                        Exp::GetTypeFields(Box::new(Exp::Var(raised_var, ())), ()),
+                    )],
+                    body: Box::new(handler),
+                    ann: (),
+                }),
+                ann: (),
+            }
+        }
+        Exp::Import { .. } | Exp::Modules { .. } => unreachable!(),
     }
 }
 
 pub fn resolve_types(expr: &Exp<u32>) -> (Exp<()>, HashMap<String, u64>) {
-    let mut type_tag_translation: HashMap<String, u64> = HashMap::new();
-    let ret_exp = resolve_types_helper(expr, &mut type_tag_translation);
-    (ret_exp, type_tag_translation)
+    // the builtin error types come first, so that their tags are the same
+    // in every program
+    let mut types = Types {
+        tags: ERROR_TYPES
+            .iter()
+            .map(|(name, _)| (name.to_string(), error_tag(name).unwrap()))
+            .collect(),
+        fields: HashMap::new(),
+    };
+    let ret_exp = resolve_types_helper(expr, &mut types);
+    (ret_exp, types.tags)
 }
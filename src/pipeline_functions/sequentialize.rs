@@ -7,6 +7,7 @@ pub fn sequentialize(decls: &[FunDecl<Exp<u32>, u32>], p: &Exp<u32>) -> SeqProg<
       Exp::Num(val, _) => SeqExp::Imm(ImmExp::Num(val.clone()), ()),
       Exp::Bool(val, _) => SeqExp::Imm(ImmExp::Bool(val.clone()), ()),
       Exp::Var(name, _) => SeqExp::Imm(ImmExp::Var(name.clone()), ()),
+      Exp::Prim0(op, _) => SeqExp::Prim0(*op, ()),
       Exp::Prim1(op, e1, tag) => {
         let s_e1 = seq_help(&*e1);
         let name = format!("#prim1_{}", tag);
@@ -76,6 +77,24 @@ pub fn sequentialize(decls: &[FunDecl<Exp<u32>, u32>], p: &Exp<u32>) -> SeqProg<
         }
         ret
       }
+      Exp::Assign {
+        var,
+        new_value,
+        ann,
+      } => {
+        let name = format!("#assign_{}", ann);
+        SeqExp::Let {
+          var: name.clone(),
+          bound_exp: Box::new(seq_help(new_value)),
+          body: Box::new(SeqExp::Assign {
+            var: var.clone(),
+            new_value: ImmExp::Var(name),
+            ann: (),
+          }),
+          ann: (),
+        }
+      }
+      Exp::LetVar { .. } => unreachable!(), // should have been converted to a let
       Exp::FunDefs {
         decls: _,
         body: _,
@@ -131,6 +150,39 @@ pub fn sequentialize(decls: &[FunDecl<Exp<u32>, u32>], p: &Exp<u32>) -> SeqProg<
         body: Box::new(seq_help(e2)),
         ann: (),
       },
+      Exp::While { cond, body, ann: _ } => SeqExp::While {
+        cond: Box::new(seq_help(cond)),
+        body: Box::new(seq_help(body)),
+        ann: (),
+      },
+      Exp::For {
+        var,
+        start,
+        end,
+        body,
+        ann,
+      } => {
+        // the bounds are only evaluated once, before the loop
+        let start_name = format!("#for_start_{}", ann);
+        let end_name = format!("#for_end_{}", ann);
+        SeqExp::Let {
+          var: start_name.clone(),
+          bound_exp: Box::new(seq_help(start)),
+          body: Box::new(SeqExp::Let {
+            var: end_name.clone(),
+            bound_exp: Box::new(seq_help(end)),
+            body: Box::new(SeqExp::For {
+              var: var.clone(),
+              start: ImmExp::Var(start_name),
+              end: ImmExp::Var(end_name),
+              body: Box::new(seq_help(body)),
+              ann: (),
+            }),
+            ann: (),
+          }),
+          ann: (),
+        }
+      }
       Exp::Lambda {
         parameters: _,
         body: _,
@@ -262,6 +314,27 @@ pub fn sequentialize(decls: &[FunDecl<Exp<u32>, u32>], p: &Exp<u32>) -> SeqProg<
         )),
         ann: (),
       },
+      Exp::Raise(expr, tag) => SeqExp::Let {
+        var: format!("#raise_{}", tag),
+        bound_exp: Box::new(seq_help(expr)),
+        body: Box::new(SeqExp::Raise(ImmExp::Var(format!("#raise_{}", tag)), ())),
+        ann: (),
+      },
+      Exp::Catch {
+        body,
+        var,
+        handler,
+        ann: _,
+      } => SeqExp::Catch {
+        body: Box::new(seq_help(body)),
+        var: var.clone(),
+        handler: Box::new(seq_help(handler)),
+        ann: (),
+      },
+      // Try was resolved in a prior pass
+      Exp::Try { .. } | Exp::GetField { .. } | Exp::UpdateField { .. } => unreachable!(),
+      // uniquify puts the declarations of imported files in the program
+      Exp::Import { .. } | Exp::Modules { .. } => unreachable!(),
     }
   }
   let main = seq_help(p);
@@ -271,6 +344,7 @@ pub fn sequentialize(decls: &[FunDecl<Exp<u32>, u32>], p: &Exp<u32>) -> SeqProg<
       name: decl.name.clone(),
       parameters: decl.parameters.clone(),
       body: seq_help(&decl.body),
+      doc: decl.doc.clone(),
       ann: (),
     })
     .collect();
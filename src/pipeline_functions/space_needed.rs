@@ -4,18 +4,39 @@ pub fn space_needed<Ann>(_e: &SeqExp<Ann>, paramc: u32) -> u32 {
   use std::cmp;
   fn space_needed_help<Ann>(_e: &SeqExp<Ann>) -> u32 {
     match _e {
+      // the bound expression's variables are dead once the variable is bound
       SeqExp::Let {
         var: _,
-        bound_exp: _,
+        bound_exp,
         body,
         ann: _,
-      } => 8 + space_needed_help(body),
+      } => cmp::max(space_needed_help(bound_exp), 8 + space_needed_help(body)),
       SeqExp::If {
         cond: _,
         thn,
         els,
         ann: _,
       } => cmp::max(space_needed_help(thn), space_needed_help(els)),
+      SeqExp::While {
+        cond,
+        body,
+        ann: _,
+      } => cmp::max(space_needed_help(cond), space_needed_help(body)),
+      // the loop variable is on the stack like a `let`'s
+      SeqExp::For {
+        var: _,
+        start: _,
+        end: _,
+        body,
+        ann: _,
+      } => 8 + space_needed_help(body),
+      // the handler record and the raised value
+      SeqExp::Catch {
+        body,
+        var: _,
+        handler,
+        ann: _,
+      } => 32 + cmp::max(space_needed_help(body), space_needed_help(handler)),
       _ => 0,
     }
   }
@@ -1,30 +1,42 @@
-use crate::syntax::{Exp, FunDecl, SnakeType};
+use crate::syntax::{Exp, FunDecl, SnakeType, ERROR_TYPES};
 use std::collections::HashMap;
 
+// Gives every name in the program a unique one. The functions and types of
+// an imported file are also namespaced with the file's name, e.g.
+// `__snake_function_12_lists.map`, and the declarations of every imported
+// file are put before the main program.
 pub fn uniquify(e: &Exp<u32>) -> Exp<()> {
-  fn uniquify_help(e: &Exp<u32>, translation_table: HashMap<&str, String>) -> Exp<()> {
+  uniquify_with(e, &[])
+}
+
+// Like `uniquify`, with `globals` bound outside of the program, which keep
+// their names
+pub fn uniquify_with(e: &Exp<u32>, globals: &[String]) -> Exp<()> {
+  // `ns` is the namespace of the file `e` is in, empty in the main file
+  fn uniquify_help(e: &Exp<u32>, translation_table: HashMap<&str, String>, ns: &str) -> Exp<()> {
     match e {
       Exp::Num(val, _) => Exp::Num(*val, ()),
       Exp::Bool(val, _) => Exp::Bool(*val, ()),
+      Exp::Prim0(op, _) => Exp::Prim0(*op, ()),
       Exp::Var(name, _) => Exp::Var(translation_table.get(name.as_str()).unwrap().clone(), ()),
       Exp::Prim1(op, operand, _) => {
         // just uniquify the operand
         Exp::Prim1(
           *op,
-          Box::new(uniquify_help(operand, translation_table.clone())),
+          Box::new(uniquify_help(operand, translation_table.clone(), ns)),
           (),
         )
       }
       Exp::Prim2(op, operand1, operand2, _) => Exp::Prim2(
         *op,
-        Box::new(uniquify_help(operand1, translation_table.clone())),
-        Box::new(uniquify_help(operand2, translation_table.clone())),
+        Box::new(uniquify_help(operand1, translation_table.clone(), ns)),
+        Box::new(uniquify_help(operand2, translation_table.clone(), ns)),
         (),
       ),
       Exp::Array(array_values, _) => Exp::Array(
         array_values
           .into_iter()
-          .map(|array_val| -> Exp<()> { uniquify_help(array_val, translation_table.clone()) })
+          .map(|array_val| -> Exp<()> { uniquify_help(array_val, translation_table.clone(), ns) })
           .collect(),
         (),
       ),
@@ -34,16 +46,39 @@ pub fn uniquify(e: &Exp<u32>) -> Exp<()> {
         new_value,
         ann: _,
       } => Exp::ArraySet {
-        array: Box::new(uniquify_help(array, translation_table.clone())),
-        index: Box::new(uniquify_help(index, translation_table.clone())),
-        new_value: Box::new(uniquify_help(new_value, translation_table.clone())),
+        array: Box::new(uniquify_help(array, translation_table.clone(), ns)),
+        index: Box::new(uniquify_help(index, translation_table.clone(), ns)),
+        new_value: Box::new(uniquify_help(new_value, translation_table.clone(), ns)),
         ann: (),
       },
       Exp::Semicolon { e1, e2, ann: _ } => Exp::Semicolon {
-        e1: Box::new(uniquify_help(e1, translation_table.clone())),
-        e2: Box::new(uniquify_help(e2, translation_table.clone())),
+        e1: Box::new(uniquify_help(e1, translation_table.clone(), ns)),
+        e2: Box::new(uniquify_help(e2, translation_table.clone(), ns)),
+        ann: (),
+      },
+      Exp::While { cond, body, ann: _ } => Exp::While {
+        cond: Box::new(uniquify_help(cond, translation_table.clone(), ns)),
+        body: Box::new(uniquify_help(body, translation_table, ns)),
         ann: (),
       },
+      Exp::For {
+        var,
+        start,
+        end,
+        body,
+        ann: tag,
+      } => {
+        let new_var = format!("__snake_var_{}_{}", tag, var);
+        let mut new_tt = translation_table.clone();
+        new_tt.insert(var, new_var.clone());
+        Exp::For {
+          var: new_var,
+          start: Box::new(uniquify_help(start, translation_table.clone(), ns)),
+          end: Box::new(uniquify_help(end, translation_table, ns)),
+          body: Box::new(uniquify_help(body, new_tt, ns)),
+          ann: (),
+        }
+      }
       Exp::Let {
         bindings,
         body,
@@ -61,26 +96,56 @@ pub fn uniquify(e: &Exp<u32>) -> Exp<()> {
             // we provide to the recursive call to prevent breaking such cases:
             // let a = 2 in let b = 1, a = a + b in ...
             // b is already recorded in `new_tt` but the new a is not yet
-            uniquify_help(&definition, new_tt.clone()),
+            uniquify_help(&definition, new_tt.clone(), ns),
           ));
           new_tt.insert(&name, format!("__snake_var_{}_{}", tag, name));
         }
         // uniquify the let body, using the new tag lookup table
         Exp::Let {
           bindings: new_bindings,
-          body: Box::new(uniquify_help(body, new_tt)),
+          body: Box::new(uniquify_help(body, new_tt, ns)),
           ann: (),
         }
       }
+      Exp::LetVar {
+        bindings,
+        body,
+        ann: tag,
+      } => {
+        // renamed like the bindings of a `let`
+        let mut new_bindings: Vec<(String, Exp<()>)> = Vec::new();
+        let mut new_tt = translation_table.clone();
+        for (name, definition) in bindings {
+          new_bindings.push((
+            format!("__snake_var_{}_{}", tag, name),
+            uniquify_help(definition, new_tt.clone(), ns),
+          ));
+          new_tt.insert(name, format!("__snake_var_{}_{}", tag, name));
+        }
+        Exp::LetVar {
+          bindings: new_bindings,
+          body: Box::new(uniquify_help(body, new_tt, ns)),
+          ann: (),
+        }
+      }
+      Exp::Assign {
+        var,
+        new_value,
+        ann: _,
+      } => Exp::Assign {
+        var: translation_table.get(var.as_str()).unwrap().clone(),
+        new_value: Box::new(uniquify_help(new_value, translation_table, ns)),
+        ann: (),
+      },
       Exp::If {
         cond,
         thn,
         els,
         ann: _,
       } => Exp::If {
-        cond: Box::new(uniquify_help(cond, translation_table.clone())),
-        thn: Box::new(uniquify_help(thn, translation_table.clone())),
-        els: Box::new(uniquify_help(els, translation_table)),
+        cond: Box::new(uniquify_help(cond, translation_table.clone(), ns)),
+        thn: Box::new(uniquify_help(thn, translation_table.clone(), ns)),
+        els: Box::new(uniquify_help(els, translation_table, ns)),
         ann: (),
       },
       Exp::FunDefs {
@@ -96,16 +161,17 @@ pub fn uniquify(e: &Exp<u32>) -> Exp<()> {
         tt_with_all_new_funcs.extend(decls.iter().map(|decl| {
           (
             decl.name.as_str(),
-            format!("__snake_function_{}_{}", decl.ann, decl.name),
+            format!("__snake_function_{}_{}{}", decl.ann, ns, decl.name),
           )
         }));
         for decl in decls.iter() {
           // this translation table will contain all parameters as well
           let mut tt_for_funcbody = tt_with_all_new_funcs.clone();
           let mut new_decl: FunDecl<Exp<()>, ()> = FunDecl {
-            name: format!("__snake_function_{}_{}", decl.ann, decl.name),
+            name: format!("__snake_function_{}_{}{}", decl.ann, ns, decl.name),
             parameters: Vec::new(),
             body: Exp::Num(483, ()), // placeholder
+            doc: decl.doc.clone(),
             ann: (),
           };
           // uniquify the parameters and update the tag lookup table for funcbody
@@ -116,23 +182,23 @@ pub fn uniquify(e: &Exp<u32>) -> Exp<()> {
             tt_for_funcbody.insert(&param, format!("__snake_param_{}_{}", decl.ann, param));
           }
           // process the funcbody and add the updated decl to the new decl list
-          new_decl.body = uniquify_help(&decl.body, tt_for_funcbody);
+          new_decl.body = uniquify_help(&decl.body, tt_for_funcbody, ns);
           new_decls.push(new_decl);
         }
         Exp::FunDefs {
           decls: new_decls,
           // uniquify the program block, using the new tag lookup table
-          body: Box::new(uniquify_help(body, tt_with_all_new_funcs)),
+          body: Box::new(uniquify_help(body, tt_with_all_new_funcs, ns)),
           ann: (),
         }
       }
       Exp::Call(callee, params, _) => Exp::Call(
         // uniquify the callee expression
-        Box::new(uniquify_help(callee, translation_table.clone())),
+        Box::new(uniquify_help(callee, translation_table.clone(), ns)),
         params
           .into_iter()
           // uniquify each parameter expression
-          .map(|param| -> Exp<()> { uniquify_help(param, translation_table.clone()) })
+          .map(|param| -> Exp<()> { uniquify_help(param, translation_table.clone(), ns) })
           .collect(),
         (),
       ),
@@ -154,7 +220,7 @@ pub fn uniquify(e: &Exp<u32>) -> Exp<()> {
             .into_iter()
             .map(|param| format!("__snake_param_{}_{}", *tag, param))
             .collect(),
-          body: Box::new(uniquify_help(body, tt_inside_lambda)),
+          body: Box::new(uniquify_help(body, tt_inside_lambda, ns)),
           ann: (),
         }
       }
@@ -168,6 +234,7 @@ pub fn uniquify(e: &Exp<u32>) -> Exp<()> {
       } => unreachable!(),
       Exp::TypeDefs {
         decls,
+        doc,
         body,
         ann: tag,
       } => {
@@ -175,15 +242,16 @@ pub fn uniquify(e: &Exp<u32>) -> Exp<()> {
         let mut new_tt = translation_table.clone();
         for (name, args) in decls {
           new_decls.push((
-            format!("__custom_type_{}_{}", tag, name),
+            format!("__custom_type_{}_{}{}", tag, ns, name),
             // arguments are just placeholders, they don't need to be uniquified
             args.clone(),
           ));
-          new_tt.insert(name, format!("__custom_type_{}_{}", tag, name));
+          new_tt.insert(name, format!("__custom_type_{}_{}{}", tag, ns, name));
         }
         Exp::TypeDefs {
           decls: new_decls,
-          body: Box::new(uniquify_help(body, new_tt)),
+          doc: doc.clone(),
+          body: Box::new(uniquify_help(body, new_tt, ns)),
           ann: (),
         }
       }
@@ -192,36 +260,39 @@ pub fn uniquify(e: &Exp<u32>) -> Exp<()> {
         default,
         arms,
         ann: tag,
-      } => {
-        let mut new_arms: Vec<(SnakeType, Vec<String>, Exp<()>)> = Vec::new();
-        let mut new_tt = translation_table.clone();
-        for (type_used, args, exp) in arms {
-          let new_snake_type: SnakeType;
-          match type_used {
-            SnakeType::Custom(name) => {
-              new_snake_type =
-                SnakeType::Custom(translation_table.get(name.as_str()).unwrap().clone())
-            }
-            SnakeType::Array => new_snake_type = SnakeType::Array,
-            SnakeType::Func => new_snake_type = SnakeType::Func,
-            SnakeType::Num => new_snake_type = SnakeType::Num,
-            SnakeType::Bool => new_snake_type = SnakeType::Bool,
-          };
-          let mut new_args: Vec<String> = Vec::new();
-          for param in args {
-            new_args.push(format!("snake_type_param_{}_{}", tag, param));
-            new_tt.insert(&param, format!("snake_type_param_{}_{}", tag, param));
-          }
-          new_arms.push((new_snake_type, new_args, uniquify_help(exp, new_tt.clone())));
-        }
-
-        Exp::Match {
-          expr: Box::new(uniquify_help(expr, translation_table.clone())),
-          default: Box::new(uniquify_help(default, translation_table.clone())),
-          arms: new_arms,
-          ann: (),
-        }
-      }
+      } => Exp::Match {
+        expr: Box::new(uniquify_help(expr, translation_table.clone(), ns)),
+        default: Box::new(uniquify_help(default, translation_table.clone(), ns)),
+        arms: uniquify_arms(arms, &translation_table, *tag, ns),
+        ann: (),
+      },
+      // fields keep their names, like the fields of type declarations
+      Exp::GetField { exp, field, ann: _ } => Exp::GetField {
+        exp: Box::new(uniquify_help(exp, translation_table, ns)),
+        field: field.clone(),
+        ann: (),
+      },
+      Exp::UpdateField {
+        exp,
+        field,
+        new_value,
+        ann: _,
+      } => Exp::UpdateField {
+        exp: Box::new(uniquify_help(exp, translation_table.clone(), ns)),
+        field: field.clone(),
+        new_value: Box::new(uniquify_help(new_value, translation_table, ns)),
+        ann: (),
+      },
+      Exp::Raise(e, _) => Exp::Raise(Box::new(uniquify_help(e, translation_table, ns)), ()),
+      Exp::Try {
+        body,
+        arms,
+        ann: tag,
+      } => Exp::Try {
+        body: Box::new(uniquify_help(body, translation_table.clone(), ns)),
+        arms: uniquify_arms(arms, &translation_table, *tag, ns),
+        ann: (),
+      },
       Exp::MakeTypeInstance {
         typetag: _,
         fields: _,
@@ -232,15 +303,161 @@ pub fn uniquify(e: &Exp<u32>) -> Exp<()> {
         typetag: _,
         ann: _,
       } => unreachable!(),
-      Exp::GetTypeFields(_, _) => unreachable!(),
+      Exp::GetTypeFields(_, _) | Exp::Catch { .. } => unreachable!(),
+      // imports only come at the start of a file, and the modules around
+      // the whole program
+      Exp::Import { .. } | Exp::Modules { .. } => unreachable!(),
+    }
+  }
+
+  // The arms of a `match` or `try`, with their fields renamed
+  fn uniquify_arms(
+    arms: &[(SnakeType, Vec<String>, Exp<u32>)],
+    translation_table: &HashMap<&str, String>,
+    tag: u32,
+    ns: &str,
+  ) -> Vec<(SnakeType, Vec<String>, Exp<()>)> {
+    let mut new_arms: Vec<(SnakeType, Vec<String>, Exp<()>)> = Vec::new();
+    let mut new_tt = translation_table.clone();
+    for (type_used, args, exp) in arms {
+      let new_snake_type: SnakeType;
+      match type_used {
+        SnakeType::Custom(name) => {
+          new_snake_type =
+            SnakeType::Custom(translation_table.get(name.as_str()).unwrap().clone())
+        }
+        SnakeType::CustomFields(name, fields) => {
+          new_snake_type = SnakeType::CustomFields(
+            translation_table.get(name.as_str()).unwrap().clone(),
+            fields.clone(),
+          )
+        }
+        SnakeType::Array => new_snake_type = SnakeType::Array,
+        SnakeType::Func => new_snake_type = SnakeType::Func,
+        SnakeType::Num => new_snake_type = SnakeType::Num,
+        SnakeType::Bool => new_snake_type = SnakeType::Bool,
+      };
+      let mut new_args: Vec<String> = Vec::new();
+      for param in args {
+        new_args.push(format!("snake_type_param_{}_{}", tag, param));
+        new_tt.insert(&param, format!("snake_type_param_{}_{}", tag, param));
+      }
+      new_arms.push((new_snake_type, new_args, uniquify_help(exp, new_tt.clone(), ns)));
+    }
+    new_arms
+  }
+
+  // Adds the names exported by the files `e` imports to the translation
+  // table, returning what comes after the imports
+  fn imports<'e>(
+    mut e: &'e Exp<u32>,
+    translation_table: &mut HashMap<&'e str, String>,
+    exports: &[HashMap<&'e str, String>],
+  ) -> &'e Exp<u32> {
+    while let Exp::Import { module, body, .. } = e {
+      if let Some(module) = module {
+        translation_table.extend(exports[*module].clone());
+      }
+      e = body;
+    }
+    e
+  }
+
+  // The new names of the declarations at the start of a file, by their old
+  // names
+  fn exported_names<'e>(mut e: &'e Exp<u32>, mut uniq: &Exp<()>) -> HashMap<&'e str, String> {
+    let mut exports = HashMap::new();
+    loop {
+      match (e, uniq) {
+        (
+          Exp::FunDefs { decls, body, .. },
+          Exp::FunDefs {
+            decls: new_decls,
+            body: new_body,
+            ..
+          },
+        ) => {
+          for (decl, new_decl) in decls.iter().zip(new_decls) {
+            exports.insert(decl.name.as_str(), new_decl.name.clone());
+          }
+          e = body;
+          uniq = new_body;
+        }
+        (
+          Exp::TypeDefs { decls, body, .. },
+          Exp::TypeDefs {
+            decls: new_decls,
+            body: new_body,
+            ..
+          },
+        ) => {
+          for ((name, _), (new_name, _)) in decls.iter().zip(new_decls) {
+            exports.insert(name.as_str(), new_name.clone());
+          }
+          e = body;
+          uniq = new_body;
+        }
+        _ => return exports,
+      }
     }
   }
 
-  let translation_table: HashMap<&str, String> = HashMap::from([
+  // Replaces what comes after the declarations at the start of `decls`
+  // with `rest`
+  fn replace_body(decls: Exp<()>, rest: Exp<()>) -> Exp<()> {
+    match decls {
+      Exp::FunDefs { decls, body, ann } => Exp::FunDefs {
+        decls,
+        body: Box::new(replace_body(*body, rest)),
+        ann,
+      },
+      Exp::TypeDefs {
+        decls,
+        doc,
+        body,
+        ann,
+      } => Exp::TypeDefs {
+        decls,
+        doc,
+        body: Box::new(replace_body(*body, rest)),
+        ann,
+      },
+      _ => rest,
+    }
+  }
+
+  let mut translation_table: HashMap<&str, String> = HashMap::from([
     ("Func", String::from("Func")),
     ("Num", String::from("Num")),
     ("Bool", String::from("Bool")),
     ("Array", String::from("Array")),
   ]);
-  uniquify_help(e, translation_table)
+  // the builtin error types keep their names too
+  translation_table.extend(ERROR_TYPES.iter().map(|(name, _)| (*name, name.to_string())));
+  translation_table.extend(globals.iter().map(|g| (g.as_str(), g.clone())));
+  match e {
+    Exp::Modules { modules, body, .. } => {
+      let mut exports = Vec::new();
+      let mut uniq_modules = Vec::new();
+      for module in modules {
+        let mut tt = translation_table.clone();
+        let prog = imports(&module.prog, &mut tt, &exports);
+        let uniq = uniquify_help(prog, tt, &format!("{}.", module.name));
+        exports.push(exported_names(prog, &uniq));
+        uniq_modules.push(uniq);
+      }
+      let mut tt = translation_table;
+      let prog = imports(body, &mut tt, &exports);
+      // only the main file's body is run
+      uniq_modules
+        .into_iter()
+        .rev()
+        .fold(uniquify_help(prog, tt, ""), |rest, uniq| replace_body(uniq, rest))
+    }
+    _ => {
+      let mut tt = translation_table;
+      let prog = imports(e, &mut tt, &[]);
+      uniquify_help(prog, tt, "")
+    }
+  }
 }
@@ -0,0 +1,293 @@
+use crate::interp::{Config, Event, InterpErr, Machine, Session};
+use crate::span::{file_info, span1_to_span2, Span1};
+use crate::syntax::{Exp, SurfProg};
+
+use std::collections::HashMap;
+
+// What the profiler measured for one def or lambda
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Row {
+  pub name: String,
+  pub calls: u64,
+  // steps taken while the function was anywhere on the call stack
+  pub inclusive_steps: u64,
+  // steps taken while the function was the innermost call
+  pub exclusive_steps: u64,
+  // arrays allocated while the function was the innermost call
+  pub allocations: u64,
+}
+
+#[derive(Clone, Debug)]
+pub struct Profile {
+  // sorted by inclusive steps, most expensive first
+  pub rows: Vec<Row>,
+  // exclusive steps for each distinct call stack, outermost function first
+  pub stacks: Vec<(Vec<String>, u64)>,
+}
+
+// The name of the top-level program in profiles
+pub static MAIN: &str = "<main>";
+
+impl Profile {
+  pub fn table(&self) -> String {
+    let width = self
+      .rows
+      .iter()
+      .map(|r| r.name.len())
+      .max()
+      .unwrap_or(0)
+      .max("function".len());
+    let mut buf = format!(
+      "{:<width$}  {:>10}  {:>12}  {:>12}  {:>10}\n",
+      "function",
+      "calls",
+      "inclusive",
+      "exclusive",
+      "allocs",
+      width = width
+    );
+    for r in &self.rows {
+      buf.push_str(&format!(
+        "{:<width$}  {:>10}  {:>12}  {:>12}  {:>10}\n",
+        r.name,
+        r.calls,
+        r.inclusive_steps,
+        r.exclusive_steps,
+        r.allocations,
+        width = width
+      ));
+    }
+    buf
+  }
+
+  // The folded stack format read by flamegraph tools: one line per call
+  // stack, with the frames separated by semicolons, then the step count
+  pub fn folded(&self) -> String {
+    let mut buf = String::new();
+    for (stack, steps) in &self.stacks {
+      buf.push_str(&format!("{} {}\n", stack.join(";"), steps));
+    }
+    buf
+  }
+}
+
+struct FunStats {
+  name: String,
+  // where the body starts
+  line: usize,
+  col: usize,
+  calls: u64,
+  inclusive_steps: u64,
+  exclusive_steps: u64,
+  allocations: u64,
+  // how many times the function is on the call stack, and the step at
+  // which it got there
+  active: usize,
+  entered_at: u64,
+}
+
+// A call that hasn't returned yet
+struct Frame {
+  fun: usize,
+  // the depth of the continuation the call returns to
+  depth: usize,
+  // the node of the call tree for the stack up to this call
+  node: usize,
+}
+
+// Call stacks are nodes in a tree, so that counting a step against the
+// current stack doesn't need to copy it
+struct CallTree {
+  // (parent, function) for each node; the root is the program itself
+  nodes: Vec<(usize, usize)>,
+  children: HashMap<(usize, usize), usize>,
+  steps: Vec<u64>,
+}
+
+impl CallTree {
+  fn child(&mut self, parent: usize, fun: usize) -> usize {
+    let nodes = &mut self.nodes;
+    let steps = &mut self.steps;
+    *self.children.entry((parent, fun)).or_insert_with(|| {
+      nodes.push((parent, fun));
+      steps.push(0);
+      nodes.len() - 1
+    })
+  }
+
+  fn path(&self, mut node: usize) -> Vec<usize> {
+    let mut path = vec![];
+    while node != 0 {
+      let (parent, fun) = self.nodes[node];
+      path.push(fun);
+      node = parent;
+    }
+    path.push(0);
+    path.reverse();
+    path
+  }
+}
+
+// Runs `prog` in the interpreter and measures where it spends its steps.
+//
+// A tail call replaces its caller on the call stack, so the caller stops
+// accumulating inclusive steps once it makes a tail call.
+pub fn profile<W>(
+  prog: &SurfProg<Span1>,
+  source: &str,
+  config: &Config,
+  out: &mut W,
+) -> Result<Profile, InterpErr>
+where
+  W: std::io::Write,
+{
+  let info = file_info(source);
+  let mut session = Session::new(prog, config);
+  let mut funs = vec![FunStats {
+    name: MAIN.to_string(),
+    line: 1,
+    col: 0,
+    calls: 1,
+    inclusive_steps: 0,
+    exclusive_steps: 0,
+    allocations: 0,
+    active: 1,
+    entered_at: 0,
+  }];
+  // function bodies are unique to each def or lambda
+  let mut fun_ids: HashMap<*const Exp<Span1>, usize> = HashMap::new();
+  let mut tree = CallTree {
+    nodes: vec![(0, 0)],
+    children: HashMap::new(),
+    steps: vec![0],
+  };
+  let mut frames: Vec<Frame> = Vec::new();
+
+  fn leave(fun: &mut FunStats, now: u64) {
+    fun.active -= 1;
+    if fun.active == 0 {
+      fun.inclusive_steps += now - fun.entered_at;
+    }
+  }
+
+  while !session.is_done() {
+    let (current, node) = frames.last().map_or((0, 0), |f| (f.fun, f.node));
+    let allocated = session.store.heap().len();
+    let event = session.step(out)?;
+    let now = session.steps;
+    funs[current].exclusive_steps += 1;
+    funs[current].allocations += (session.store.heap().len() - allocated) as u64;
+    tree.steps[node] += 1;
+
+    if let Event::Call { fun, .. } = event {
+      let body = session.store.fun_body(fun).expect("only functions are called");
+      let id = *fun_ids.entry(body as *const _).or_insert_with(|| {
+        let start = span1_to_span2(&info, body.ann());
+        funs.push(FunStats {
+          name: session.store.fun_name(fun).to_string(),
+          line: start.start_line,
+          col: start.start_col,
+          calls: 0,
+          inclusive_steps: 0,
+          exclusive_steps: 0,
+          allocations: 0,
+          active: 0,
+          entered_at: 0,
+        });
+        funs.len() - 1
+      });
+      let depth = match &session.machine {
+        Some(Machine::Descending { stk, .. }) => stk.depth(),
+        _ => unreachable!("calls always descend into the function body"),
+      };
+      // a tail call returns to the same continuation as its caller
+      let mut parent = node;
+      while let Some(frame) = frames.last() {
+        if frame.depth != depth {
+          break;
+        }
+        let frame = frames.pop().unwrap();
+        leave(&mut funs[frame.fun], now);
+        parent = frames.last().map_or(0, |f| f.node);
+      }
+      let stats = &mut funs[id];
+      stats.calls += 1;
+      if stats.active == 0 {
+        stats.entered_at = now;
+      }
+      stats.active += 1;
+      frames.push(Frame {
+        fun: id,
+        depth,
+        node: tree.child(parent, id),
+      });
+    }
+
+    // a call returns once its value reaches the continuation it was called with
+    if let (Some(Machine::Returning { stk, .. }), Some(_)) = (&session.machine, frames.last()) {
+      let depth = stk.depth();
+      while let Some(frame) = frames.last() {
+        if frame.depth != depth {
+          break;
+        }
+        let frame = frames.pop().unwrap();
+        leave(&mut funs[frame.fun], now);
+      }
+    }
+  }
+  let now = session.steps;
+  for frame in frames.drain(..).rev() {
+    leave(&mut funs[frame.fun], now);
+  }
+  leave(&mut funs[0], now);
+
+  // tell apart different functions with the same name, like lambdas, by
+  // where they are
+  let mut name_counts: HashMap<&str, usize> = HashMap::new();
+  for f in &funs {
+    *name_counts.entry(&f.name).or_insert(0) += 1;
+  }
+  let labels: Vec<String> = funs
+    .iter()
+    .map(|f| {
+      if name_counts[f.name.as_str()] > 1 {
+        format!("{}:{}:{}", f.name, f.line, f.col)
+      } else {
+        f.name.clone()
+      }
+    })
+    .collect();
+
+  let mut rows: Vec<Row> = funs
+    .iter()
+    .zip(labels.iter())
+    .map(|(f, label)| Row {
+      name: label.clone(),
+      calls: f.calls,
+      inclusive_steps: f.inclusive_steps,
+      exclusive_steps: f.exclusive_steps,
+      allocations: f.allocations,
+    })
+    .collect();
+  rows.sort_by(|r1, r2| {
+    r2.inclusive_steps
+      .cmp(&r1.inclusive_steps)
+      .then_with(|| r1.name.cmp(&r2.name))
+  });
+
+  let mut stacks: Vec<(Vec<String>, u64)> = (0..tree.nodes.len())
+    .filter(|node| tree.steps[*node] > 0)
+    .map(|node| {
+      (
+        tree
+          .path(node)
+          .into_iter()
+          .map(|fun| labels[fun].clone())
+          .collect(),
+        tree.steps[node],
+      )
+    })
+    .collect();
+  stacks.sort();
+  Ok(Profile { rows, stacks })
+}
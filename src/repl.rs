@@ -0,0 +1,368 @@
+use crate::compile::{parse_prog, resolve_prog_types, Compiler, Stage};
+use crate::errors::RunnerErr;
+use crate::interp::{Config, Machine, Session, SnakeVal, Stack};
+use crate::modules::{prelude, PRELUDE_FILE, PRELUDE_MODULE};
+use crate::pipeline_functions::check_prog::check_prog;
+use crate::span::{file_info, span1_to_span2, Span1, Span2, MAIN_FILE};
+use crate::syntax::{Exp, SurfFunDecl, SurfProg};
+
+use std::io::{BufRead, Write};
+use std::path::Path;
+
+static HELP_MSG: &str = "\
+Type an expression to evaluate it, or a def or type declaration without the
+final `in` to make it visible to everything typed after it. Input continues
+onto the next line while it is incomplete, and a blank line ends it.
+
+Commands:
+
+    :type EXPR            print the type of the value of EXPR
+    :load FILE            add the declarations at the start of FILE and of
+                          the files it imports, then evaluate the rest of it
+    :dump STAGE [EXPR]    print EXPR after STAGE of the compiler, where STAGE
+                          is one of parsed, uniquified, resolved, lifted,
+                          sequentialized (default EXPR: the last one evaluated)
+    :asm [EXPR]           print the assembly code the compiler generates for
+                          EXPR (default: the last expression evaluated)
+    :help, :h             print this message
+    :quit, :q             leave the REPL
+";
+
+// Declarations that stay visible for the rest of the session
+#[derive(Clone)]
+enum Decl {
+  Funs(Vec<SurfFunDecl<Span1>>),
+  Types(Vec<(String, Vec<String>)>, Option<String>),
+}
+
+// What a line of input, together with the lines before it, amounts to
+enum Input {
+  Exp(SurfProg<Span1>),
+  Decls(Vec<Decl>),
+  Incomplete,
+}
+
+struct Repl {
+  config: Config,
+  decls: Vec<Decl>,
+  // the last expression evaluated, with its source
+  last: Option<(String, SurfProg<Span1>)>,
+}
+
+// Runs the REPL, reading input from `input` and writing the results, errors
+// and prompts to `out`
+pub fn repl<R, W>(config: &Config, input: R, out: &mut W) -> std::io::Result<()>
+where
+  R: BufRead,
+  W: Write,
+{
+  let mut repl = Repl {
+    config: *config,
+    // the prelude is declared before anything typed
+    decls: split_decls(prelude(PRELUDE_FILE)).0,
+    last: None,
+  };
+  let mut lines = input.lines();
+  let mut buf = String::new();
+  loop {
+    write!(out, "{}", if buf.is_empty() { "snake> " } else { "  ...> " })?;
+    out.flush()?;
+    let line = match lines.next() {
+      None => return Ok(()),
+      Some(line) => line?,
+    };
+    if buf.is_empty() {
+      if line.trim().is_empty() {
+        continue;
+      }
+      if let Some(command) = line.trim().strip_prefix(':') {
+        if !repl.command(command, out)? {
+          return Ok(());
+        }
+        continue;
+      }
+    }
+    let blank = line.trim().is_empty();
+    buf.push_str(&line);
+    buf.push('\n');
+    let source = std::mem::take(&mut buf);
+    match classify(&source) {
+      Ok(Input::Incomplete) if !blank => buf = source,
+      // a blank line ends the input, even if it's incomplete
+      Ok(Input::Incomplete) => report(parse(&source).map(|_| ()), out)?,
+      Ok(Input::Exp(e)) => match repl.eval(&e, &source, out) {
+        Ok(()) => repl.last = Some((source, e)),
+        Err(e) => writeln!(out, "{}", e)?,
+      },
+      Ok(Input::Decls(decls)) => {
+        let result = repl.declare(decls, &source);
+        report(result, out)?;
+      }
+      Err(e) => writeln!(out, "{}", e)?,
+    }
+  }
+}
+
+fn report<W>(result: Result<(), RunnerErr<Span2>>, out: &mut W) -> std::io::Result<()>
+where
+  W: Write,
+{
+  match result {
+    Ok(()) => Ok(()),
+    Err(e) => writeln!(out, "{}", e),
+  }
+}
+
+fn parse(source: &str) -> Result<SurfProg<Span1>, RunnerErr<Span2>> {
+  Compiler::new().parse(source)
+}
+
+fn check(prog: &SurfProg<Span1>, source: &str) -> Result<(), RunnerErr<Span2>> {
+  let info = file_info(source);
+  check_prog(prog).map_err(|e| RunnerErr::CodeGen(e.map_span(|s| span1_to_span2(&info, *s))))
+}
+
+// Decides whether `source` is an expression, declarations missing their
+// `in`, or the start of something longer
+fn classify(source: &str) -> Result<Input, RunnerErr<Span2>> {
+  let err = match parse_prog(source) {
+    Ok(e) => return Ok(Input::Exp(e)),
+    Err(err) => err,
+  };
+  let with_body = format!("{}in 0", source);
+  if let Ok(mut e) = parse_prog(&with_body) {
+    let mut decls = vec![];
+    loop {
+      match e {
+        Exp::FunDefs { decls: funs, body, .. } => {
+          decls.push(Decl::Funs(funs));
+          e = *body;
+        }
+        Exp::TypeDefs {
+          decls: types, doc, body, ..
+        } => {
+          decls.push(Decl::Types(types, doc));
+          e = *body;
+        }
+        // the `in` must be the one added here, or this is e.g. a let
+        Exp::Num(0, ann) if ann.start_ix >= source.len() => return Ok(Input::Decls(decls)),
+        _ => break,
+      }
+    }
+  }
+  match err {
+    lalrpop_util::ParseError::UnrecognizedEOF { .. } => Ok(Input::Incomplete),
+    _ => parse(source).map(Input::Exp),
+  }
+}
+
+// Splits the declarations off the start of a program, whose imports have
+// already been loaded
+fn split_decls(mut prog: SurfProg<Span1>) -> (Vec<Decl>, SurfProg<Span1>) {
+  let mut decls = vec![];
+  loop {
+    match prog {
+      Exp::Import { body, .. } => prog = *body,
+      Exp::FunDefs { decls: funs, body, .. } => {
+        decls.push(Decl::Funs(funs));
+        prog = *body;
+      }
+      Exp::TypeDefs {
+        decls: types, doc, body, ..
+      } => {
+        decls.push(Decl::Types(types, doc));
+        prog = *body;
+      }
+      _ => return (decls, prog),
+    }
+  }
+}
+
+impl Repl {
+  // Runs a command, returning whether to keep going
+  fn command<W>(&mut self, command: &str, out: &mut W) -> std::io::Result<bool>
+  where
+    W: Write,
+  {
+    let (name, arg) = match command.split_once(char::is_whitespace) {
+      Some((name, arg)) => (name, arg.trim()),
+      None => (command, ""),
+    };
+    let result = match name {
+      "type" | "t" if !arg.is_empty() => self.type_of(arg, out).map(Some),
+      "load" | "l" if !arg.is_empty() => self.load(arg, out).map(|_| None),
+      "dump" | "d" if !arg.is_empty() => {
+        let (stage, exp) = arg.split_once(char::is_whitespace).unwrap_or((arg, ""));
+        match stage.parse::<Stage>() {
+          Ok(stage) => self.compile(Some(stage), exp.trim()).map(Some),
+          Err(e) => {
+            writeln!(out, "{}", e)?;
+            return Ok(true);
+          }
+        }
+      }
+      "asm" | "a" => self.compile(None, arg).map(Some),
+      "help" | "h" => {
+        write!(out, "{}", HELP_MSG)?;
+        return Ok(true);
+      }
+      "quit" | "q" => return Ok(false),
+      "type" | "t" | "load" | "l" | "dump" | "d" => {
+        writeln!(out, "The :{} command needs an argument. Type :help for details", name)?;
+        return Ok(true);
+      }
+      _ => {
+        writeln!(out, "Unknown command :{}. Type :help for a list of commands", name)?;
+        return Ok(true);
+      }
+    };
+    match result {
+      Ok(Some(text)) => writeln!(out, "{}", text)?,
+      Ok(None) => {}
+      Err(e) => writeln!(out, "{}", e)?,
+    }
+    Ok(true)
+  }
+
+  // The program that evaluates `body` with all the declarations so far
+  fn program(&self, decls: &[Decl], body: SurfProg<Span1>) -> SurfProg<Span1> {
+    self
+      .decls
+      .iter()
+      .chain(decls.iter())
+      .rev()
+      .fold(body, |body, decl| {
+        let ann = body.ann();
+        match decl {
+          Decl::Funs(funs) => Exp::FunDefs {
+            decls: funs.clone(),
+            body: Box::new(body),
+            ann,
+          },
+          Decl::Types(types, doc) => Exp::TypeDefs {
+            decls: types.clone(),
+            doc: doc.clone(),
+            body: Box::new(body),
+            ann,
+          },
+        }
+      })
+  }
+
+  fn declare(&mut self, decls: Vec<Decl>, source: &str) -> Result<(), RunnerErr<Span2>> {
+    let end = source.len();
+    let prog = self.program(&decls, Exp::Num(
+        0,
+        Span1 {
+          file: MAIN_FILE,
+          start_ix: end,
+          end_ix: end,
+        },
+      ),);
+    check(&prog, source)?;
+    self.decls.extend(decls);
+    Ok(())
+  }
+
+  fn eval<W>(&self, e: &SurfProg<Span1>, source: &str, out: &mut W) -> Result<(), RunnerErr<Span2>>
+  where
+    W: Write,
+  {
+    self.run(e, source, true, out).map(|_| ())
+  }
+
+  // Runs `e` with the declarations so far. The program prints its own value
+  // if `print` is set, and otherwise the value's type is returned instead.
+  fn run<W>(
+    &self,
+    e: &SurfProg<Span1>,
+    source: &str,
+    print: bool,
+    out: &mut W,
+  ) -> Result<Option<String>, RunnerErr<Span2>>
+  where
+    W: Write,
+  {
+    let prog = self.program(&[], e.clone());
+    check(&prog, source)?;
+    let (resolved, type_names) = resolve_prog_types(&prog);
+    let mut session = Session::new(&resolved, &self.config);
+    session.store.set_type_names(type_names);
+    while !session.is_done() {
+      if let Some(Machine::Returning { v, stk: Stack::Done }) = &session.machine {
+        if !print {
+          let heap = session.store.heap();
+          return Ok(Some(match v {
+            SnakeVal::Num(_) => "Num".to_string(),
+            SnakeVal::Bool(_) => "Bool".to_string(),
+            SnakeVal::Array(_) => "Array".to_string(),
+            SnakeVal::Closure(_) => "Func".to_string(),
+            SnakeVal::Custom { tag, .. } => heap.type_name(*tag).to_string(),
+          }));
+        }
+      }
+      session.step(out).map_err(RunnerErr::Interp)?;
+    }
+    Ok(None)
+  }
+
+  fn type_of<W>(&self, source: &str, out: &mut W) -> Result<String, RunnerErr<Span2>>
+  where
+    W: Write,
+  {
+    let e = parse(source)?;
+    let t = self.run(&e, source, false, out)?;
+    Ok(t.expect("a program always returns a value before finishing"))
+  }
+
+  fn load<W>(&mut self, path: &str, out: &mut W) -> Result<(), RunnerErr<Span2>>
+  where
+    W: Write,
+  {
+    let source = std::fs::read_to_string(path).map_err(|e| RunnerErr::FileOpen(format!("{}: {}", path, e)))?;
+    let dir = Path::new(path).parent().unwrap_or(Path::new(""));
+    let (prog, files) = Compiler::new().load(&source, dir)?;
+    // the declarations of the imported files come first, as if they had
+    // been loaded before
+    let mut decls = vec![];
+    let prog = match prog {
+      Exp::Modules { modules, body, .. } => {
+        // the prelude has been declared already
+        for module in modules.into_iter().skip(PRELUDE_MODULE + 1) {
+          decls.extend(split_decls(module.prog).0);
+        }
+        *body
+      }
+      prog => prog,
+    };
+    let (main_decls, body) = split_decls(prog);
+    decls.extend(main_decls);
+    check_prog(&self.program(&decls, body.clone()))
+      .map_err(|e| RunnerErr::CodeGen(e.map_span(|s| files.span2(*s))))?;
+    self.decls.extend(decls);
+    self.eval(&body, &source, out)
+  }
+
+  // Compiles the program for `source`, or for the last expression evaluated,
+  // and shows it after `stage`, or as assembly code if there's no stage
+  fn compile(&self, stage: Option<Stage>, source: &str) -> Result<String, RunnerErr<Span2>> {
+    let (source, e) = match (source, &self.last) {
+      ("", Some((source, e))) => (source.as_str(), e.clone()),
+      ("", None) => ("0", parse("0")?),
+      (source, _) => (source, parse(source)?),
+    };
+    let prog = self.program(&[], e);
+    let compiler = match stage {
+      Some(stage) => Compiler::new().dump(stage),
+      None => Compiler::new(),
+    };
+    let info = file_info(source);
+    let compiled = compiler
+      .compile_prog(&prog)
+      .map_err(|e| RunnerErr::CodeGen(e.map_span(|s| span1_to_span2(&info, *s))))?;
+    Ok(match stage {
+      Some(stage) => compiled.dump(stage).unwrap_or_default().to_string(),
+      None => compiled.assembly.trim().to_string(),
+    })
+  }
+}
@@ -0,0 +1,119 @@
+use crate::interp::{Config, Event, InterpErr, SnakeVal, Session};
+use crate::syntax::Exp;
+
+// How many steps apart the snapshots of the session are. Going back in time
+// restores the latest snapshot before the target and replays from there, so
+// this bounds the number of steps replayed.
+const SNAPSHOT_INTERVAL: u64 = 1000;
+
+// An element of an array being overwritten
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct Mutation {
+  // the step that made the change
+  pub(crate) step: u64,
+  pub(crate) array: usize,
+  pub(crate) index: usize,
+  pub(crate) old: SnakeVal,
+  pub(crate) new: SnakeVal,
+}
+
+// A session that, when recording, remembers enough of its history to go
+// back to any step it has already taken.
+//
+// Steps up to the furthest one taken so far have already been recorded, and
+// running them again doesn't repeat the program's output.
+pub(crate) struct Recorder<'exp, Ann> {
+  session: Session<'exp, Ann>,
+  recording: bool,
+  // snapshots[i] is the session after i * SNAPSHOT_INTERVAL steps, which
+  // shares with the later ones the parts of the heap and stack neither changed
+  snapshots: Vec<Session<'exp, Ann>>,
+  mutations: Vec<Mutation>,
+  // the furthest step taken so far
+  recorded: u64,
+}
+
+impl<'exp, Ann> Recorder<'exp, Ann>
+where
+  Ann: Clone,
+{
+  pub(crate) fn new(e: &'exp Exp<Ann>, config: &Config, recording: bool) -> Self {
+    let session = Session::new(e, config);
+    Recorder {
+      snapshots: if recording { vec![session.clone()] } else { vec![] },
+      session,
+      recording,
+      mutations: vec![],
+      recorded: 0,
+    }
+  }
+
+  pub(crate) fn session(&self) -> &Session<'exp, Ann> {
+    &self.session
+  }
+
+  pub(crate) fn is_recording(&self) -> bool {
+    self.recording
+  }
+
+  // The changes to element `index` of the array at `array`, oldest first
+  pub(crate) fn mutations_of(&self, array: usize, index: usize) -> impl Iterator<Item = &Mutation> {
+    self
+      .mutations
+      .iter()
+      .filter(move |m| m.array == array && m.index == index)
+  }
+
+  // Whether the next step has already been taken before
+  pub(crate) fn is_replaying(&self) -> bool {
+    self.session.steps < self.recorded
+  }
+
+  pub(crate) fn step<W>(&mut self, buf: &mut W) -> Result<Event, InterpErr>
+  where
+    W: std::io::Write,
+  {
+    if self.is_replaying() {
+      return self.session.step(&mut std::io::sink());
+    }
+    let event = self.session.step(buf)?;
+    let step = self.session.steps;
+    self.recorded = step;
+    if !self.recording {
+      return Ok(event);
+    }
+    if let Event::ArraySet {
+      array,
+      index,
+      old,
+      new,
+    } = event
+    {
+      self.mutations.push(Mutation {
+        step,
+        array,
+        index,
+        old,
+        new,
+      });
+    }
+    if step.is_multiple_of(SNAPSHOT_INTERVAL) {
+      self.snapshots.push(self.session.clone());
+    }
+    Ok(event)
+  }
+
+  // Goes back to just after `step` steps, which must already be recorded
+  pub(crate) fn rewind(&mut self, step: u64) -> Result<(), InterpErr> {
+    assert!(
+      self.recording && step <= self.recorded,
+      "can only rewind to recorded steps"
+    );
+    let snapshot = &self.snapshots[(step / SNAPSHOT_INTERVAL) as usize];
+    self.session = snapshot.clone();
+    while self.session.steps < step {
+      self.session.step(&mut std::io::sink())?;
+    }
+    Ok(())
+  }
+}
@@ -1,368 +1,273 @@
 use std::fs::File;
-use std::io::prelude::*;
-use std::io::{BufReader, Read, Write};
-use std::path::Path;
-use std::process::{Command, Stdio};
-
-use std::fmt::{Display, Formatter};
-
-use crate::compile::compile_to_string;
-use crate::errors::CompileErr;
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use std::process::{Child, Command, Stdio};
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::compile::{Compiled, Compiler, Target};
+use crate::debugger;
+pub use crate::errors::RunnerErr;
+use crate::fmt;
 use crate::interp;
-use crate::interp::InterpErr;
-use crate::parser::ProgParser;
+use crate::link;
+use crate::lsp;
 use crate::pipeline_functions::check_prog::check_prog;
+use crate::profiler::{self, Profile};
+use crate::repl;
+use crate::span::{Span1, Span2};
 use crate::syntax::SurfProg;
+use crate::tracer;
 
-mod span {
-  use crate::span::{Span1, Span2};
-  use std::fmt::Display;
-
-  #[derive(Clone, Debug)]
-  pub struct FileInfo {
-    newlines: Vec<usize>,
-    len: usize,
-  }
-
-  pub fn file_info(s: &str) -> FileInfo {
-    FileInfo {
-      newlines: s
-        .char_indices()
-        .filter(|(_i, c)| *c == '\n')
-        .map(|(i, _c)| i)
-        .collect(),
-      len: s.len(),
-    }
-  }
-
-  pub fn span1_to_span2(info: &FileInfo, offsets: Span1) -> Span2 {
-    let mut v = vec![0];
-    v.extend(info.newlines.iter().map(|ix| ix + 1));
-    v.push(info.len);
-
-    let (start_line, start_col) = offset_to_line_col(&v, offsets.start_ix);
-    let (end_line, end_col) = offset_to_line_col(&v, offsets.end_ix - 1);
-    Span2 {
-      start_line,
-      start_col,
-      end_line,
-      end_col: end_col + 1,
-    }
-  }
+fn fail<Span>(e: RunnerErr<Span>)
+where
+  Span: Display,
+{
+  eprintln!("{}", e);
+  std::process::exit(1);
+}
 
-  fn offset_to_line_col(newlines: &[usize], offset: usize) -> (usize, usize) {
-    let mut win = newlines.windows(2).enumerate();
-    while let Some((line, &[start, end])) = win.next() {
-      if start <= offset && offset < end {
-        return (line + 1, offset - start);
+pub fn emit_assembly(p: &Path, compiler: &Compiler) {
+  match compile_file(p, compiler) {
+    Ok(compiled) => {
+      for (stage, dump) in compiled.dumps.iter() {
+        eprintln!(";;; ---------- {} ----------\n{}\n", stage, dump);
       }
+      println!("{}", compiled.assembly)
     }
-    panic!("internal error: offset_to_line_col. Send this to the professor");
+    Err(e) => fail(e),
   }
+}
 
-  use crate::errors::CompileErr;
-  impl<Span> CompileErr<Span> {
-    pub fn map_span<F, SpanPrime>(self, f: F) -> CompileErr<SpanPrime>
-    where
-      F: FnOnce(&Span) -> SpanPrime,
-    {
-      match self {
-        CompileErr::UnboundVariable { unbound, location } => CompileErr::UnboundVariable {
-          unbound,
-          location: f(&location),
-        },
-        CompileErr::DuplicateBinding {
-          duplicated_name,
-          location,
-        } => CompileErr::DuplicateBinding {
-          duplicated_name,
-          location: f(&location),
-        },
-        CompileErr::Overflow { num, location } => CompileErr::Overflow {
-          num,
-          location: f(&location),
-        },
-        CompileErr::DuplicateArgName {
-          duplicated_name,
-          location,
-        } => CompileErr::DuplicateArgName {
-          location: f(&location),
-          duplicated_name,
-        },
-
-        CompileErr::DuplicateFunName {
-          duplicated_name,
-          location,
-        } => CompileErr::DuplicateFunName {
-          duplicated_name,
-          location: f(&location),
-        },
-
-        CompileErr::UndefinedType {
-          undefined_type,
-          location,
-        } => CompileErr::UndefinedType {
-          undefined_type,
-          location: f(&location),
-        },
-        CompileErr::WrongTypeArity {
-          type_used,
-          expected_arity,
-          given_arity,
-          location,
-        } => CompileErr::WrongTypeArity {
-          type_used,
-          expected_arity,
-          given_arity,
-          location: f(&location),
-        },
-        CompileErr::DuplicateTypeDefs {
-          duplicate_type,
-          location,
-        } => CompileErr::DuplicateTypeDefs {
-          duplicate_type,
-          location: f(&location),
-        },
-        CompileErr::DuplicateMatchArms {
-          type_used,
-          location,
-        } => CompileErr::DuplicateMatchArms {
-          type_used,
-          location: f(&location),
-        },
-        CompileErr::DuplicateMatchArmArguments {
-          type_used,
-          location,
-        } => CompileErr::DuplicateMatchArmArguments {
-          type_used,
-          location: f(&location),
-        },
-        CompileErr::WrongTypeCall {
-          type_used,
-          location,
-        } => CompileErr::WrongTypeCall {
-          type_used,
-          location: f(&location),
-        },
-        CompileErr::ShadowPrimType {
-          primitive_type,
-          location,
-        } => CompileErr::ShadowPrimType {
-          primitive_type,
-          location: f(&location),
-        },
-      }
-    }
+pub fn run(p: &Path, compiler: &Compiler, limits: &Limits) {
+  let tmp_dir = match tempfile::TempDir::new() {
+    Ok(dir) => dir,
+    Err(e) => return fail(RunnerErr::<Span2>::Link(e.to_string())),
+  };
+  if let Err(e) =
+    compile_and_run_file_with(compiler, limits, p, tmp_dir.path(), &mut std::io::stdout())
+  {
+    fail(e)
   }
+}
 
-  impl Display for Span2 {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-      write!(
-        f,
-        "line {}, column {} to line {}, column {}",
-        self.start_line, self.start_col, self.end_line, self.end_col
-      )
-    }
+pub fn build(p: &Path, compiler: &Compiler, artifact: Artifact, output: &Path, save_temps: bool) {
+  if let Err(e) = build_file(p, compiler, artifact, output, save_temps) {
+    fail(e)
   }
 }
-use crate::span::{Span1, Span2};
-use span::{file_info, span1_to_span2, FileInfo};
 
-pub enum RunnerErr<Span> {
-  FileOpen(String),
-  Lex(String),
-  Parse(String),
-  CodeGen(CompileErr<Span>),
-  Link(String),
-  Interp(InterpErr),
-  Run(String),
+pub fn build_separately(p: &Path, compiler: &Compiler, output: &Path, obj_dir: &Path) {
+  if let Err(e) = build_file_separately(p, compiler, output, obj_dir) {
+    fail(e)
+  }
 }
 
-impl<Span> Display for CompileErr<Span>
+pub fn interp<W>(p: &Path, compiler: &Compiler, config: &interp::Config, w: &mut W)
 where
-  Span: Display,
+  W: std::io::Write,
 {
-  fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-    match self {
-      CompileErr::UnboundVariable { unbound, location } => {
-        write!(f, "Unbound variable {} at {}", unbound, location)
-      }
-      CompileErr::DuplicateBinding {
-        duplicated_name,
-        location,
-      } => write!(
-        f,
-        "Variable {} defined twice in let-expression at {}",
-        duplicated_name, location
-      ),
-
-      CompileErr::Overflow { num, location } => write!(
-        f,
-        "Number literal {} doesn't fit into 63-bit integer at {}",
-        num, location
-      ),
-
-      CompileErr::DuplicateArgName {
-        duplicated_name,
-        location,
-      } => write!(
-        f,
-        "Multiple arguments named \"{}\" at {}",
-        duplicated_name, location,
-      ),
-
-      CompileErr::DuplicateFunName {
-        duplicated_name,
-        location,
-      } => write!(
-        f,
-        "Multiple defined functions named \"{}\" at {}",
-        duplicated_name, location
-      ),
-
-      CompileErr::UndefinedType {
-        undefined_type,
-        location,
-      } => write!(
-        f,
-        "Use of undefined type {} at {}",
-        undefined_type, location
-      ),
-      CompileErr::WrongTypeArity {
-        type_used,
-        expected_arity,
-        given_arity,
-        location,
-      } => write!(
-        f,
-        "Type {} expected arguments of size {} but received {} at {}",
-        type_used, expected_arity, given_arity, location
-      ),
-      CompileErr::DuplicateTypeDefs {
-        duplicate_type,
-        location,
-      } => write!(
-        f,
-        "Custom type {} defined repeatedly at {}",
-        duplicate_type, location
-      ),
-      CompileErr::DuplicateMatchArms {
-        type_used,
-        location,
-      } => write!(
-        f,
-        "Type {} used repeatedly in one match expression at {}",
-        type_used, location
-      ),
-      CompileErr::DuplicateMatchArmArguments {
-        type_used,
-        location,
-      } => write!(
-        f,
-        "Duplicate arguments in match arm {} at {}",
-        type_used, location
-      ),
-      CompileErr::WrongTypeCall {
-        type_used,
-        location,
-      } => write!(
-        f,
-        "Type constructor for type {} was incorrectly called at {}",
-        type_used, location
-      ),
-      CompileErr::ShadowPrimType {
-        primitive_type,
-        location,
-      } => write!(
-        f,
-        "Attempting to shadow primitive type {} at {}",
-        primitive_type, location
-      ),
-    }
+  if let Err(e) = interpret_file(p, compiler, config, w) {
+    fail(e)
   }
 }
 
-impl<Span> Display for RunnerErr<Span>
+pub fn interpret_file<W>(
+  p: &Path,
+  compiler: &Compiler,
+  config: &interp::Config,
+  w: &mut W,
+) -> Result<(), RunnerErr<Span2>>
 where
-  Span: Display,
+  W: std::io::Write,
 {
-  fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
-    match self {
-      RunnerErr::FileOpen(s) => write!(f, "Error reading file: {}", s),
-      RunnerErr::Lex(s) => write!(f, "Error lexing input: {}", s),
-      RunnerErr::Parse(s) => write!(f, "Error parsing input: {}", s),
-      RunnerErr::CodeGen(ce) => write!(f, "Error generating assembly: {}", ce),
-      RunnerErr::Link(s) => write!(f, "Error linking generated assembly with runtime: {}", s),
-      RunnerErr::Interp(s) => write!(f, "Error in interpreter: {}", s),
-      RunnerErr::Run(s) => write!(f, "Error running your compiled output: {}", s),
-    }
+  let (_, prog) = read_checked_prog(p, compiler)?;
+  interp::prog(&prog, config, w).map_err(RunnerErr::Interp)?;
+  Ok(())
+}
+
+pub fn trace<W>(p: &Path, compiler: &Compiler, config: &interp::Config, only: &[String], w: &mut W)
+where
+  W: std::io::Write,
+{
+  if let Err(e) = trace_file(p, compiler, config, only, w, &mut std::io::stderr()) {
+    fail(e)
   }
 }
 
-fn fail<Span>(e: RunnerErr<Span>)
+pub fn trace_file<W, L>(
+  p: &Path,
+  compiler: &Compiler,
+  config: &interp::Config,
+  only: &[String],
+  w: &mut W,
+  log: &mut L,
+) -> Result<(), RunnerErr<Span2>>
 where
-  Span: Display,
+  W: std::io::Write,
+  L: std::io::Write,
 {
-  eprintln!("{}", e);
-  std::process::exit(1);
+  let (_, prog) = read_checked_prog(p, compiler)?;
+  tracer::trace(&prog, config, only, w, log).map_err(RunnerErr::Interp)
 }
 
-fn handle_errs<Span>(r: Result<String, RunnerErr<Span>>)
+// Prints the profile to stderr once the program finishes, and writes the
+// folded call stacks to `folded` if given
+pub fn profile<W>(
+  p: &Path,
+  compiler: &Compiler,
+  config: &interp::Config,
+  folded: Option<&Path>,
+  w: &mut W,
+)
 where
-  Span: Display,
+  W: std::io::Write,
 {
-  match r {
-    Ok(s) => println!("{}", s),
-    Err(e) => {
-      eprintln!("{}", e);
-      std::process::exit(1);
+  match profile_file(p, compiler, config, w) {
+    Ok(profile) => {
+      eprint!("{}", profile.table());
+      if let Some(folded) = folded {
+        if let Err(e) = std::fs::write(folded, profile.folded()) {
+          eprintln!("Error writing {}: {}", folded.display(), e);
+          std::process::exit(1);
+        }
+      }
     }
+    Err(e) => fail(e),
   }
 }
 
-pub fn emit_assembly(p: &Path) {
-  handle_errs(compile_file(p))
+pub fn profile_file<W>(
+  p: &Path,
+  compiler: &Compiler,
+  config: &interp::Config,
+  w: &mut W,
+) -> Result<Profile, RunnerErr<Span2>>
+where
+  W: std::io::Write,
+{
+  let (source, prog) = read_checked_prog(p, compiler)?;
+  profiler::profile(&prog, &source, config, w).map_err(RunnerErr::Interp)
 }
 
-pub fn run(p: &Path) {
-  if let Err(e) = compile_and_run_file(p, Path::new("runtime"), &mut std::io::stdout()) {
+pub fn debug(p: &Path, compiler: &Compiler, config: &interp::Config, record: bool) {
+  let stdin = std::io::stdin();
+  if let Err(e) = debug_file(p, compiler, config, record, stdin.lock(), &mut std::io::stdout()) {
     fail(e)
   }
 }
 
-pub fn interp<W>(p: &Path, w: &mut W)
+pub fn debug_file<R, W>(
+  p: &Path,
+  compiler: &Compiler,
+  config: &interp::Config,
+  record: bool,
+  input: R,
+  out: &mut W,
+) -> Result<(), RunnerErr<Span2>>
 where
+  R: std::io::BufRead,
   W: std::io::Write,
 {
-  if let Err(e) = interpret_file(p, w) {
-    fail(e)
+  let (source, prog) = read_checked_prog(p, compiler)?;
+  debugger::debug(&prog, &source, config, record, input, out).map_err(RunnerErr::Interp)
+}
+
+pub fn repl(config: &interp::Config) {
+  let stdin = std::io::stdin();
+  if let Err(e) = repl::repl(config, stdin.lock(), &mut std::io::stdout()) {
+    fail(RunnerErr::<Span2>::Interp(interp::InterpErr::Write { msg: e.to_string() }))
   }
 }
 
-pub fn interpret_file<W>(p: &Path, w: &mut W) -> Result<(), RunnerErr<Span2>>
+// Rewrites each file in the canonical layout, or with `check`, lists the
+// files that aren't in it. Fails if any file can't be formatted, or is
+// listed.
+pub fn fmt(paths: &[&Path], check: bool) {
+  let mut ok = true;
+  for p in paths {
+    let result = read_file(p).and_then(|source| Ok((format_source(&source)?, source)));
+    match result {
+      Ok((formatted, source)) if formatted == source => {}
+      Ok(_) if check => {
+        println!("{} isn't formatted", p.display());
+        ok = false;
+      }
+      Ok((formatted, _)) => {
+        if let Err(e) = std::fs::write(p, formatted) {
+          eprintln!("Error writing {}: {}", p.display(), e);
+          ok = false;
+        }
+      }
+      Err(e) => {
+        eprintln!("{}: {}", p.display(), e);
+        ok = false;
+      }
+    }
+  }
+  if !ok {
+    std::process::exit(1);
+  }
+}
+
+pub fn format_source(source: &str) -> Result<String, RunnerErr<Span2>> {
+  let prog = Compiler::new().parse(source)?;
+  Ok(fmt::format_source(&prog, source, fmt::DEFAULT_WIDTH))
+}
+
+pub fn lsp() {
+  let stdin = std::io::stdin();
+  if let Err(e) = lsp::serve(stdin.lock(), &mut std::io::stdout()) {
+    eprintln!("Error in language server: {}", e);
+    std::process::exit(1);
+  }
+}
+
+// The source of the file at `p` and its checked program with the files it
+// imports, which are looked for with the search directories of `compiler`,
+// for the tools built on the interpreter
+fn read_checked_prog(
+  p: &Path,
+  compiler: &Compiler,
+) -> Result<(String, SurfProg<Span1>), RunnerErr<Span2>> {
+  let source = read_file(p)?;
+  let (prog, files) = compiler.load(&source, dir_of(p))?;
+  check_prog(&prog).map_err(|e| RunnerErr::CodeGen(e.map_span(|s| files.span2(*s))))?;
+  Ok((source, prog))
+}
+
+// The directory the files imported by the file at `p` are relative to
+fn dir_of(p: &Path) -> &Path {
+  p.parent().unwrap_or(Path::new(""))
+}
+
+pub fn compile_and_run_file<W>(p: &Path, dir: &Path, out: &mut W) -> Result<(), RunnerErr<Span2>>
 where
   W: std::io::Write,
 {
-  let (info, prog) = parse_file(p)?;
-  let () =
-    check_prog(&prog).map_err(|e| RunnerErr::CodeGen(e.map_span(|s| span1_to_span2(&info, *s))))?;
-
-  interp::prog(&prog, w).map_err(|e| RunnerErr::Interp(e))?;
-  Ok(())
+  compile_and_run_file_with(&Compiler::new(), &Limits::new(), p, dir, out)
 }
 
-pub fn compile_and_run_file<W>(p: &Path, dir: &Path, out: &mut W) -> Result<(), RunnerErr<Span2>>
+pub fn compile_and_run_file_with<W>(
+  compiler: &Compiler,
+  limits: &Limits,
+  p: &Path,
+  dir: &Path,
+  out: &mut W,
+) -> Result<(), RunnerErr<Span2>>
 where
   W: std::io::Write,
 {
-  let asm = compile_file(p)?;
-  link_and_run(&asm, dir, out)
+  let compiled = compile_file(p, compiler)?;
+  link_and_run(&compiled.assembly, compiler.get_target(), limits, dir, out)
 }
 
-fn compile_file(p: &Path) -> Result<String, RunnerErr<Span2>> {
-  let (info, prog) = parse_file(p)?;
-  compile_to_string(&prog)
-    .map_err(|e| RunnerErr::CodeGen(e.map_span(|s| span1_to_span2(&info, *s))))
+pub fn compile_file(p: &Path, compiler: &Compiler) -> Result<Compiled, RunnerErr<Span2>> {
+  compiler.compile_in(&read_file(p)?, dir_of(p))
 }
 
 fn read_file<Span>(p: &Path) -> Result<String, RunnerErr<Span>> {
@@ -373,49 +278,170 @@ fn read_file<Span>(p: &Path) -> Result<String, RunnerErr<Span>> {
   Ok(buf)
 }
 
-fn parse_file(p: &Path) -> Result<(FileInfo, SurfProg<Span1>), RunnerErr<Span2>> {
-  let s = read_file(p)?;
-  let e = ProgParser::new()
-    .parse(&s)
-    .map_err(|e| RunnerErr::Parse(e.to_string()))?;
-  Ok((file_info(&s), e))
+// The runtime, compiled from runtime/stub.rs by build.rs
+static RUNTIME_LIB: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/libsnake_runtime.a"));
+// System libraries the runtime depends on
+const RUNTIME_NATIVE_LIBS: &str = env!("SNAKE_RUNTIME_NATIVE_LIBS");
+
+// What `snake build` leaves at its output path
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Artifact {
+  Executable,
+  Object,
+  Assembly,
 }
 
-fn link_and_run<W>(assembly: &str, dir: &Path, out: &mut W) -> Result<(), RunnerErr<Span2>>
-where
-  W: std::io::Write,
-{
-  let (nasm_format, lib_name) = if cfg!(target_os = "linux") {
-    ("elf64", "libcompiled_code.a")
-  } else if cfg!(target_os = "macos") {
-    ("macho64", "libcompiled_code.a")
-  } else if cfg!(target_os = "windows") {
-    ("win64", "compiled_code.lib")
+impl Artifact {
+  pub fn extension(&self) -> Option<&'static str> {
+    match self {
+      Artifact::Executable => None,
+      Artifact::Object => Some("o"),
+      Artifact::Assembly => Some("s"),
+    }
+  }
+}
+
+impl FromStr for Artifact {
+  type Err = String;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    match s {
+      "exe" => Ok(Artifact::Executable),
+      "obj" => Ok(Artifact::Object),
+      "asm" => Ok(Artifact::Assembly),
+      _ => Err(format!(
+        "unknown artifact {}, expected one of exe, obj, asm",
+        s
+      )),
+    }
+  }
+}
+
+// Compile the file at `p` and write `artifact` to `output`. With
+// `save_temps` the assembly and object files are kept beside `output`.
+pub fn build_file(
+  p: &Path,
+  compiler: &Compiler,
+  artifact: Artifact,
+  output: &Path,
+  save_temps: bool,
+) -> Result<(), RunnerErr<Span2>> {
+  let compiled = compile_file(p, compiler)?;
+  let target = compiler.get_target();
+  if artifact == Artifact::Assembly {
+    return write_file(output, compiled.assembly.as_bytes());
+  }
+
+  let tmp_dir = tempfile::TempDir::new().map_err(|e| RunnerErr::Link(e.to_string()))?;
+  let (asm_fname, obj_fname) = if save_temps {
+    (output.with_extension("s"), output.with_extension("o"))
   } else {
-    panic!("Runner script only works on linux, macos and windows")
+    (
+      tmp_dir.path().join("compiled_code.s"),
+      tmp_dir.path().join("compiled_code.o"),
+    )
+  };
+  let obj_fname = match artifact {
+    Artifact::Object => output.to_path_buf(),
+    _ => obj_fname,
   };
 
+  write_file(&asm_fname, compiled.assembly.as_bytes())?;
+  assemble(&asm_fname, &obj_fname, target)?;
+  if artifact == Artifact::Executable {
+    link_executable(&[obj_fname], output, target, tmp_dir.path())?;
+  }
+  Ok(())
+}
+
+// Compile each file of the program at `p` to its own object file in
+// `obj_dir` and link them into an executable at `output`. Files whose
+// assembly code in `obj_dir` has the fingerprint they have now aren't
+// compiled again. Returns the names of the units that were compiled.
+pub fn build_file_separately(
+  p: &Path,
+  compiler: &Compiler,
+  output: &Path,
+  obj_dir: &Path,
+) -> Result<Vec<String>, RunnerErr<Span2>> {
+  let source = read_file(p)?;
+  let (prog, files) = compiler.load(&source, dir_of(p))?;
+  check_prog(&prog).map_err(|e| RunnerErr::CodeGen(e.map_span(|s| files.span2(*s))))?;
+  std::fs::create_dir_all(obj_dir)
+    .map_err(|e| RunnerErr::Link(format!("{}: {}", obj_dir.display(), e)))?;
+  let target = compiler.get_target();
+
+  let units = compiler.units(&prog);
+  let mut compiled = Vec::new();
+  let mut objects = Vec::new();
+  let mut types = Vec::new();
+  for (i, unit) in units.iter().enumerate() {
+    let asm_fname = obj_dir.join(format!("{}.s", unit.name));
+    let obj_fname = obj_dir.join(format!("{}.o", unit.name));
+    let up_to_date = std::fs::read_to_string(&asm_fname)
+      .ok()
+      .filter(|assembly| {
+        link::header_fingerprint(assembly) == Some(unit.fingerprint) && obj_fname.exists()
+      });
+    let assembly = match up_to_date {
+      Some(assembly) => assembly,
+      None => {
+        let assembly = compiler.compile_unit(&prog, &units, i);
+        write_file(&asm_fname, assembly.as_bytes())?;
+        assemble(&asm_fname, &obj_fname, target)?;
+        compiled.push(unit.name.clone());
+        assembly
+      }
+    };
+    types.extend(link::header_types(&assembly));
+    objects.push(obj_fname);
+  }
+
+  // the start of the program is linked again every time, since it gives
+  // the types of all the units their tags
+  let asm_fname = obj_dir.join("snake-start.s");
+  let obj_fname = obj_dir.join("snake-start.o");
+  write_file(&asm_fname, compiler.link_units(&units, &types).as_bytes())?;
+  assemble(&asm_fname, &obj_fname, target)?;
+  objects.push(obj_fname);
+  link_executable(&objects, output, target, obj_dir)?;
+  Ok(compiled)
+}
+
+// Assemble the code in `dir` and link it with the runtime into an executable
+pub fn link(assembly: &str, target: Target, dir: &Path) -> Result<PathBuf, RunnerErr<Span2>> {
   let asm_fname = dir.join("compiled_code.s");
   let obj_fname = dir.join("compiled_code.o");
-  let lib_fname = dir.join(lib_name);
   let exe_fname = dir.join("stub.exe");
 
   // first put the assembly in a new file compiled_code.s
-  let mut asm_file = File::create(&asm_fname).map_err(|e| RunnerErr::Link(e.to_string()))?;
-  asm_file
-    .write(assembly.as_bytes())
-    .map_err(|e| RunnerErr::Link(e.to_string()))?;
-  asm_file
-    .flush()
+  write_file(&asm_fname, assembly.as_bytes())?;
+  assemble(&asm_fname, &obj_fname, target)?;
+  link_executable(&[obj_fname], &exe_fname, target, dir)?;
+  Ok(exe_fname)
+}
+
+fn write_file(p: &Path, contents: &[u8]) -> Result<(), RunnerErr<Span2>> {
+  let mut file = File::create(p).map_err(|e| RunnerErr::Link(format!("{}: {}", p.display(), e)))?;
+  file
+    .write_all(contents)
     .map_err(|e| RunnerErr::Link(e.to_string()))?;
+  file.flush().map_err(|e| RunnerErr::Link(e.to_string()))
+}
 
-  // nasm -fFORMAT -o compiled_code.o compiled_code.s
-  let nasm_out = Command::new("nasm")
+// nasm -fFORMAT -o compiled_code.o compiled_code.s
+fn assemble(asm_fname: &Path, obj_fname: &Path, target: Target) -> Result<(), RunnerErr<Span2>> {
+  let mut nasm = Command::new("nasm");
+  if target == Target::MacOs {
+    // C symbols on macOS start with an underscore
+    nasm.arg("--gprefix").arg("_");
+  }
+  let nasm_out = nasm
     .arg("-f")
-    .arg(nasm_format)
+    .arg(target.nasm_format())
     .arg("-o")
-    .arg(&obj_fname)
-    .arg(&asm_fname)
+    .arg(obj_fname)
+    .arg(asm_fname)
     .output()
     .map_err(|e| RunnerErr::Link(format!("nasm err: {}", e)))?;
   if !nasm_out.status.success() {
@@ -425,86 +451,216 @@ where
       std::str::from_utf8(&nasm_out.stderr).expect("nasm produced invalid UTF-8")
     )));
   }
+  Ok(())
+}
 
-  // ar r libcompiled_code.a compiled_code.o
-  let ar_out = Command::new("ar")
-    .arg("rus")
-    .arg(lib_fname)
-    .arg(&obj_fname)
-    .output()
-    .map_err(|e| RunnerErr::Link(format!("ar err: {}", e)))?;
-  if !ar_out.status.success() {
+// cc -no-pie -o stub.exe compiled_code.o ... libsnake_runtime.a
+// `lib_dir` is where the embedded runtime library is written for cc to read
+fn link_executable(
+  obj_fnames: &[PathBuf],
+  exe_fname: &Path,
+  target: Target,
+  lib_dir: &Path,
+) -> Result<(), RunnerErr<Span2>> {
+  if target != Target::host() {
     return Err(RunnerErr::Link(format!(
-      "Failure in ar call:\n{}\n{}",
-      ar_out.status,
-      std::str::from_utf8(&ar_out.stderr).expect("ar produced invalid UTF-8")
+      "cannot link code for {} on this host",
+      target
     )));
   }
-
-  // rustc stub.rs -L tmp
-  let rustc_out = if cfg!(target_os = "macos") {
-    Command::new("rustc")
-      .arg("runtime/stub.rs")
-      .arg("--target")
-      .arg("x86_64-apple-darwin")
-      .arg("-L")
-      .arg(dir)
-      .arg("-o")
-      .arg(&exe_fname)
-      .output()
-      .map_err(|e| RunnerErr::Link(format!("rustc err: {}", e)))?
-  } else {
-    Command::new("rustc")
-      .arg("runtime/stub.rs")
-      .arg("-L")
-      .arg(dir)
-      .arg("-o")
-      .arg(&exe_fname)
-      .output()
-      .map_err(|e| RunnerErr::Link(format!("rustc err: {}", e)))?
+  let lib_fname = lib_dir.join("libsnake_runtime.a");
+  write_file(&lib_fname, RUNTIME_LIB)?;
+
+  // The compiled code uses absolute addresses, so it can't be position independent
+  let mut cc = Command::new("cc");
+  match target {
+    Target::MacOs => cc.args(["-arch", "x86_64", "-Wl,-no_pie"]),
+    Target::Linux | Target::Windows => cc.arg("-no-pie"),
   };
-  if !rustc_out.status.success() {
+  let cc_out = cc
+    .arg("-o")
+    .arg(exe_fname)
+    .args(obj_fnames)
+    .arg(&lib_fname)
+    .args(RUNTIME_NATIVE_LIBS.split_whitespace())
+    .output()
+    .map_err(|e| RunnerErr::Link(format!("cc err: {}", e)))?;
+  if !cc_out.status.success() {
     return Err(RunnerErr::Link(format!(
-      "Failure in rustc call: {}\n{}",
-      rustc_out.status,
-      std::str::from_utf8(&rustc_out.stderr).expect("rustc produced invalid UTF-8")
+      "Failure in cc call: {}\n{}",
+      cc_out.status,
+      std::str::from_utf8(&cc_out.stderr).expect("cc produced invalid UTF-8")
     )));
   }
+  Ok(())
+}
+
+// Resource limits for running compiled programs
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Limits {
+  timeout: Option<Duration>,
+  memory: Option<u64>,
+  cpu_time: Option<u64>,
+}
+
+impl Limits {
+  pub fn new() -> Self {
+    Limits::default()
+  }
+
+  // kill the program once it has run for `timeout` of wall-clock time
+  pub fn timeout(mut self, timeout: Duration) -> Self {
+    self.timeout = Some(timeout);
+    self
+  }
+
+  // cap the address space of the program at `bytes`
+  pub fn memory(mut self, bytes: u64) -> Self {
+    self.memory = Some(bytes);
+    self
+  }
+
+  // cap the CPU time of the program at `seconds`
+  pub fn cpu_time(mut self, seconds: u64) -> Self {
+    self.cpu_time = Some(seconds);
+    self
+  }
+
+  #[cfg(unix)]
+  fn apply(&self, cmd: &mut Command) {
+    use std::os::unix::process::CommandExt;
+    let Limits {
+      memory, cpu_time, ..
+    } = *self;
+    if memory.is_none() && cpu_time.is_none() {
+      return;
+    }
+    let set = |resource, value: u64| {
+      let limit = libc::rlimit {
+        rlim_cur: value as libc::rlim_t,
+        rlim_max: value as libc::rlim_t,
+      };
+      if unsafe { libc::setrlimit(resource, &limit) } == 0 {
+        Ok(())
+      } else {
+        Err(std::io::Error::last_os_error())
+      }
+    };
+    // runs in the forked child just before exec
+    unsafe {
+      cmd.pre_exec(move || {
+        if let Some(bytes) = memory {
+          set(libc::RLIMIT_AS, bytes)?;
+        }
+        if let Some(seconds) = cpu_time {
+          set(libc::RLIMIT_CPU, seconds)?;
+        }
+        Ok(())
+      });
+    }
+  }
+
+  #[cfg(not(unix))]
+  fn apply(&self, _cmd: &mut Command) {}
+}
+
+fn link_and_run<W>(
+  assembly: &str,
+  target: Target,
+  limits: &Limits,
+  dir: &Path,
+  out: &mut W,
+) -> Result<(), RunnerErr<Span2>>
+where
+  W: std::io::Write,
+{
+  let exe_fname = link(assembly, target, dir)?;
 
-  let mut child = Command::new(&exe_fname)
-    .stdout(Stdio::piped())
-    .stderr(Stdio::piped())
+  let mut cmd = Command::new(&exe_fname);
+  cmd.stdout(Stdio::piped()).stderr(Stdio::piped());
+  limits.apply(&mut cmd);
+  let mut child = cmd
     .spawn()
     .map_err(|e| RunnerErr::Run(format!("{}", e)))?;
-  let compiled_out = BufReader::new(
-    child
-      .stdout
-      .take()
-      .expect("Failed to capture compiled code's stdout"),
-  );
-  let compiled_err = BufReader::new(
-    child
-      .stderr
-      .take()
-      .expect("Failed to capture compiled code's stderr"),
-  );
-
-  for line in compiled_out.lines() {
-    let line = line.map_err(|e| RunnerErr::Run(format!("{}", e)))?;
-    writeln!(out, "{}", line).map_err(|e| RunnerErr::Run(format!("I/O error: {}", e)))?;
+  let mut compiled_out = child
+    .stdout
+    .take()
+    .expect("Failed to capture compiled code's stdout");
+  let mut compiled_err = child
+    .stderr
+    .take()
+    .expect("Failed to capture compiled code's stderr");
+
+  // Drain both pipes at once so that the program never blocks writing to a
+  // full pipe. Stdout is forwarded to `out` as it arrives.
+  let (out_tx, out_rx) = mpsc::channel();
+  let out_thread = thread::spawn(move || {
+    let mut buf = [0; 4096];
+    loop {
+      match compiled_out.read(&mut buf) {
+        Ok(0) | Err(_) => break,
+        Ok(n) => {
+          if out_tx.send(buf[..n].to_vec()).is_err() {
+            break;
+          }
+        }
+      }
+    }
+  });
+  let err_thread = thread::spawn(move || {
+    let mut stderr = Vec::new();
+    let _ = compiled_err.read_to_end(&mut stderr);
+    stderr
+  });
+
+  let deadline = limits.timeout.map(|t| Instant::now() + t);
+  // stops the program before giving up on it with `err`
+  let stop = |child: &mut Child, err: RunnerErr<_>| {
+    let _ = child.kill();
+    let _ = child.wait();
+    err
+  };
+  let timed_out = |child: &mut Child| stop(child, RunnerErr::Timeout(limits.timeout.unwrap()));
+  loop {
+    let wait = match deadline {
+      Some(deadline) => deadline.saturating_duration_since(Instant::now()),
+      None => Duration::from_secs(3600),
+    };
+    match out_rx.recv_timeout(wait) {
+      Ok(chunk) => {
+        if let Err(e) = out.write_all(&chunk) {
+          return Err(stop(&mut child, RunnerErr::Run(format!("I/O error: {}", e))));
+        }
+      }
+      Err(mpsc::RecvTimeoutError::Timeout) => {
+        if deadline.is_some() {
+          return Err(timed_out(&mut child));
+        }
+      }
+      Err(mpsc::RecvTimeoutError::Disconnected) => break,
+    }
   }
 
-  let status = child
-    .wait()
-    .map_err(|e| RunnerErr::Run(format!("Error waiting for child process {}", e)))?;
-  if !status.success() {
-    let mut stderr = String::new();
-    for line in compiled_err.lines() {
-      stderr.push_str(&format!("{}\n", line.unwrap()));
+  // stdout is closed, but the program may still be running
+  let status = loop {
+    match child
+      .try_wait()
+      .map_err(|e| RunnerErr::Run(format!("Error waiting for child process {}", e)))?
+    {
+      Some(status) => break status,
+      None if deadline.is_some_and(|d| Instant::now() >= d) => {
+        return Err(timed_out(&mut child))
+      }
+      None => thread::sleep(Duration::from_millis(5)),
     }
+  };
+  let _ = out_thread.join();
+  let stderr = err_thread.join().unwrap_or_default();
+  if !status.success() {
     return Err(RunnerErr::Run(format!(
       "Error code {} when running compiled code Stderr:\n{}",
-      status, stderr
+      status,
+      String::from_utf8_lossy(&stderr)
     )));
   }
   Ok(())
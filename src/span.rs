@@ -1,20 +1,136 @@
 /* A Span is a region of source code.
  */
 
+// Which of a program's source files a span is in, as an index into its
+// `Files`
+pub type FileId = usize;
+
+// The file a program starts from, which imports the others
+pub const MAIN_FILE: FileId = 0;
+
 // 1-dimensional span of source locations
 // This is what the parser outputs.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct Span1 {
+  pub file: FileId,
   pub start_ix: usize,
   pub end_ix: usize, // exclusive
 }
 
 // 2-dimensional span of source locations
 // This is what we use in error messages.
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Span2 {
+  // the name of the file, unless it's the main one
+  pub file: Option<String>,
   pub start_line: usize,
   pub start_col: usize,
   pub end_line: usize, // inclusive
   pub end_col: usize,  // exclusive
 }
+
+#[derive(Clone, Debug)]
+pub struct FileInfo {
+  newlines: Vec<usize>,
+  len: usize,
+}
+
+pub fn file_info(s: &str) -> FileInfo {
+  FileInfo {
+    newlines: s
+      .char_indices()
+      .filter(|(_i, c)| *c == '\n')
+      .map(|(i, _c)| i)
+      .collect(),
+    len: s.len(),
+  }
+}
+
+impl FileInfo {
+  // the 1-based line containing `offset`
+  pub fn line_of(&self, offset: usize) -> usize {
+    self.newlines.partition_point(|&nl| nl < offset) + 1
+  }
+}
+
+pub fn span1_to_span2(info: &FileInfo, offsets: Span1) -> Span2 {
+  let mut v = vec![0];
+  v.extend(info.newlines.iter().map(|ix| ix + 1));
+  v.push(info.len);
+
+  let (start_line, start_col) = offset_to_line_col(&v, offsets.start_ix);
+  let (end_line, end_col) = offset_to_line_col(&v, offsets.end_ix.max(offsets.start_ix + 1) - 1);
+  Span2 {
+    file: None,
+    start_line,
+    start_col,
+    end_line,
+    end_col: end_col + 1,
+  }
+}
+
+fn offset_to_line_col(newlines: &[usize], offset: usize) -> (usize, usize) {
+  let mut win = newlines.windows(2).enumerate();
+  while let Some((line, &[start, end])) = win.next() {
+    if start <= offset && offset < end {
+      return (line + 1, offset - start);
+    }
+  }
+  // offsets at (or past) the end of the file, e.g. an unexpected EOF,
+  // are reported at the end of the last line
+  let last_line = newlines.len() - 1;
+  let last_start = newlines[last_line - 1];
+  (last_line, offset.saturating_sub(last_start))
+}
+
+impl std::fmt::Display for Span2 {
+  fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    write!(
+      f,
+      "line {}, column {} to line {}, column {}",
+      self.start_line, self.start_col, self.end_line, self.end_col
+    )?;
+    match &self.file {
+      Some(file) => write!(f, " of {}", file),
+      None => Ok(()),
+    }
+  }
+}
+
+// The source files of a program, the main one first
+#[derive(Clone, Debug, Default)]
+pub struct Files {
+  // the name of each file, as it was imported, with its source
+  files: Vec<(String, String, FileInfo)>,
+}
+
+impl Files {
+  pub fn new() -> Self {
+    Files::default()
+  }
+
+  pub fn add(&mut self, name: &str, source: &str) -> FileId {
+    self.files.push((name.to_string(), source.to_string(), file_info(source)));
+    self.files.len() - 1
+  }
+
+  pub fn name(&self, file: FileId) -> &str {
+    &self.files[file].0
+  }
+
+  pub fn source(&self, file: FileId) -> &str {
+    &self.files[file].1
+  }
+
+  pub fn info(&self, file: FileId) -> &FileInfo {
+    &self.files[file].2
+  }
+
+  pub fn span2(&self, span: Span1) -> Span2 {
+    let mut span2 = span1_to_span2(self.info(span.file), span);
+    if span.file != MAIN_FILE {
+      span2.file = Some(self.name(span.file).to_string());
+    }
+    span2
+  }
+}
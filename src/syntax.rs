@@ -6,6 +6,8 @@ pub struct FunDecl<E, Ann> {
   pub name: String,
   pub parameters: Vec<String>,
   pub body: E,
+  // the `##` comment before the def
+  pub doc: Option<String>,
   pub ann: Ann,
 }
 
@@ -15,6 +17,7 @@ pub enum Exp<Ann> {
   Num(i64, Ann),
   Bool(bool, Ann),
   Var(String, Ann),
+  Prim0(Prim0, Ann),
   Prim1(Prim1, Box<Exp<Ann>>, Ann),
   Prim2(Prim2, Box<Exp<Ann>>, Box<Exp<Ann>>, Ann),
   Let {
@@ -22,6 +25,18 @@ pub enum Exp<Ann> {
     body: Box<Exp<Ann>>,               // the expression in which the new variables are bound
     ann: Ann,
   },
+  // `var` bindings, which `Assign` can change in `body`
+  LetVar {
+    bindings: Vec<(String, Exp<Ann>)>,
+    body: Box<Exp<Ann>>,
+    ann: Ann,
+  },
+  // `var := new_value` evaluates to `new_value`
+  Assign {
+    var: String,
+    new_value: Box<Exp<Ann>>,
+    ann: Ann,
+  },
   If {
     cond: Box<Exp<Ann>>,
     thn: Box<Exp<Ann>>,
@@ -43,6 +58,22 @@ pub enum Exp<Ann> {
     ann: Ann,
   },
 
+  // Loops evaluate to `false`
+  While {
+    cond: Box<Exp<Ann>>,
+    body: Box<Exp<Ann>>,
+    ann: Ann,
+  },
+  // `for var = start to end: body end` runs `body` with `var` bound to each
+  // number from `start` up to and including `end`, which are evaluated once
+  For {
+    var: String,
+    start: Box<Exp<Ann>>,
+    end: Box<Exp<Ann>>,
+    body: Box<Exp<Ann>>,
+    ann: Ann,
+  },
+
   FunDefs {
     decls: Vec<FunDecl<Exp<Ann>, Ann>>,
     body: Box<Exp<Ann>>,
@@ -64,6 +95,8 @@ pub enum Exp<Ann> {
 
   TypeDefs {
     decls: Vec<(String, Vec<String>)>,
+    // the `##` comment before the type declaration
+    doc: Option<String>,
     body: Box<Exp<Ann>>,
     ann: Ann,
   },
@@ -73,6 +106,30 @@ pub enum Exp<Ann> {
     arms: Vec<(SnakeType, Vec<String>, Exp<Ann>)>,
     ann: Ann,
   },
+  // `exp.field`, the field named `field` of a value of a custom type
+  GetField {
+    exp: Box<Exp<Ann>>,
+    field: String,
+    ann: Ann,
+  },
+  // `exp with field = new_value`, a new value of the same custom type as
+  // `exp` with the same fields, except for `field`
+  UpdateField {
+    exp: Box<Exp<Ann>>,
+    field: String,
+    new_value: Box<Exp<Ann>>,
+    ann: Ann,
+  },
+  // `raise e` stops evaluating and continues with the nearest enclosing `try`
+  // that has an arm for the value of `e`
+  Raise(Box<Exp<Ann>>, Ann),
+  // `try body with arms end` is `body`, or the first of `arms` that matches
+  // what `body` raised. Values no arm matches are raised again.
+  Try {
+    body: Box<Exp<Ann>>,
+    arms: Vec<(SnakeType, Vec<String>, Exp<Ann>)>,
+    ann: Ann,
+  },
   // intermediate forms
   MakeTypeInstance {
     typetag: u64,
@@ -85,8 +142,79 @@ pub enum Exp<Ann> {
     ann: Ann,
   },
   GetTypeFields(Box<Exp<Ann>>, Ann),
+  // what `Try` is resolved to: `handler` runs with `var` bound to the raised
+  // value
+  Catch {
+    body: Box<Exp<Ann>>,
+    var: String,
+    handler: Box<Exp<Ann>>,
+    ann: Ann,
+  },
+
+  // `import "path"` at the start of a file, before the rest of it, with the
+  // span of just the import. Loading the program sets `module` to where the
+  // imported file is in the enclosing `Modules`.
+  Import {
+    path: String,
+    module: Option<usize>,
+    body: Box<Exp<Ann>>,
+    ann: Ann,
+  },
+  // A program together with the files it imports, each after the files it
+  // imports in turn. Only `uniquify` and the interpreter see these, since
+  // `uniquify` puts the modules' declarations into one program.
+  Modules {
+    modules: Vec<Module<Ann>>,
+    body: Box<Exp<Ann>>,
+    ann: Ann,
+  },
 }
 
+// A file imported by a program. The files that import it see the `def`s
+// and `type`s at its start, and the rest of it only runs when it's the
+// main file.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Module<Ann> {
+  // the name its declarations are namespaced with, from its file name
+  pub name: String,
+  pub prog: Exp<Ann>,
+}
+
+// The custom types of the errors the language raises itself, which `try`
+// can catch, and their number of fields. They're in the order of the error
+// codes of the runtime, so an error's code is also its type tag, and the
+// types a program declares are tagged after them.
+pub const ERROR_TYPES: [(&str, usize); 16] = [
+  ("ArithError", 1),
+  ("ComparisonError", 1),
+  ("IfError", 1),
+  ("LogicError", 1),
+  ("Overflow", 0),
+  ("NotArray", 1),
+  ("IndexOutOfBounds", 1),
+  ("IndexNotNumber", 1),
+  ("CalledNonFunction", 1),
+  ("WrongArity", 2),
+  ("LengthNonArray", 1),
+  ("WhileError", 1),
+  ("ForError", 1),
+  ("GeneratorDone", 0),
+  ("NotChannel", 1),
+  ("FieldError", 1),
+];
+
+// The type tag of the builtin error type `name`
+pub fn error_tag(name: &str) -> Option<u64> {
+  ERROR_TYPES
+    .iter()
+    .position(|(error, _)| *error == name)
+    .map(|tag| tag as u64)
+}
+
+// The type tag of channels, a custom type no program can name, so nothing
+// else is a channel. Its one field is the number of the channel.
+pub const CHANNEL_TAG: u64 = u64::MAX;
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum SnakeType {
   Num,
@@ -94,6 +222,15 @@ pub enum SnakeType {
   Array,
   Func,
   Custom(String),
+  // `Name{field, field = x}` in an arm, a custom type whose fields with
+  // these names are bound to the arm's variables, in the same order
+  CustomFields(String, Vec<String>),
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Prim0 {
+  // `chan()`, a new channel
+  Chan,
 }
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
@@ -107,6 +244,22 @@ pub enum Prim1 {
   Length,
   IsArray,
   IsFun,
+  // calls its argument with the continuation of the call
+  CallCC,
+  // `yield(e)` in a generator function stops its body, until the generator
+  // is called again, and returns `e` from the call that ran it
+  Yield,
+  // what the body of a generator function is resolved to: a generator that
+  // runs the function of no arguments it's given
+  Generator,
+  // runs a function of no arguments in a new thread, evaluating to the
+  // number of the thread
+  Spawn,
+  // the next value sent to a channel, waiting for one if there is none
+  Recv,
+  // what a field access is resolved to for a value of none of the types
+  // with the field, which raises FieldError with the value
+  NoField,
 }
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
@@ -126,6 +279,9 @@ pub enum Prim2 {
   Eq,
   Neq,
   ArrayGet, // first arg is array, second is index
+  // `send(c, v)` sends `v` to the channel `c`, evaluating to `v`, and lets
+  // another thread run
+  Send,
 }
 
 /* Sequential Expressions */
@@ -148,6 +304,7 @@ pub enum ImmExp {
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum SeqExp<Ann> {
   Imm(ImmExp, Ann),
+  Prim0(Prim0, Ann),
   Prim1(Prim1, ImmExp, Ann),
   Prim2(Prim2, ImmExp, ImmExp, Ann),
   ArraySet {
@@ -174,12 +331,31 @@ pub enum SeqExp<Ann> {
     body: Box<SeqExp<Ann>>,
     ann: Ann,
   },
+  // overwrites the variable of an enclosing `Let`
+  Assign {
+    var: String,
+    new_value: ImmExp,
+    ann: Ann,
+  },
   If {
     cond: ImmExp,
     thn: Box<SeqExp<Ann>>,
     els: Box<SeqExp<Ann>>,
     ann: Ann,
   },
+  // `cond` is evaluated again before each iteration
+  While {
+    cond: Box<SeqExp<Ann>>,
+    body: Box<SeqExp<Ann>>,
+    ann: Ann,
+  },
+  For {
+    var: String,
+    start: ImmExp,
+    end: ImmExp,
+    body: Box<SeqExp<Ann>>,
+    ann: Ann,
+  },
   MakeTypeInstance {
     typetag: u64,
     fields: ImmExp,
@@ -191,10 +367,81 @@ pub enum SeqExp<Ann> {
     ann: Ann,
   },
   GetTypeFields(ImmExp, Ann),
+  Raise(ImmExp, Ann),
+  // runs `handler` with `var` bound to what `body` raised
+  Catch {
+    body: Box<SeqExp<Ann>>,
+    var: String,
+    handler: Box<SeqExp<Ann>>,
+    ann: Ann,
+  },
 }
 
 /* Useful functions for Exps, BindExps, SeqExps */
 impl<Ann> Exp<Ann> {
+  // The first `yield` in this expression that isn't in a function it
+  // declares. A function whose body yields is a generator function.
+  pub fn first_yield(&self) -> Option<&Ann> {
+    fn first<'e, Ann: 'e>(es: impl IntoIterator<Item = &'e Exp<Ann>>) -> Option<&'e Ann> {
+      es.into_iter().find_map(Exp::first_yield)
+    }
+    match self {
+      Exp::Prim1(Prim1::Yield, _, ann) => Some(ann),
+      Exp::Num(..) | Exp::Bool(..) | Exp::Var(..) | Exp::Prim0(..) | Exp::Lambda { .. } => None,
+      Exp::Prim1(_, e, _)
+      | Exp::Assign { new_value: e, .. }
+      | Exp::FunDefs { body: e, .. }
+      | Exp::MakeClosure { env: e, .. }
+      | Exp::TypeDefs { body: e, .. }
+      | Exp::Raise(e, _)
+      | Exp::MakeTypeInstance { fields: e, .. }
+      | Exp::MatchType { expr: e, .. }
+      | Exp::GetTypeFields(e, _)
+      | Exp::GetField { exp: e, .. }
+      | Exp::Import { body: e, .. }
+      | Exp::Modules { body: e, .. } => e.first_yield(),
+      Exp::Prim2(_, e1, e2, _)
+      | Exp::Semicolon { e1, e2, .. }
+      | Exp::UpdateField {
+        exp: e1,
+        new_value: e2,
+        ..
+      }
+      | Exp::While {
+        cond: e1, body: e2, ..
+      }
+      | Exp::Catch {
+        body: e1,
+        handler: e2,
+        ..
+      } => first([&**e1, e2]),
+      Exp::Let { bindings, body, .. } | Exp::LetVar { bindings, body, .. } => {
+        first(bindings.iter().map(|(_, e)| e).chain([&**body]))
+      }
+      Exp::If { cond, thn, els, .. } => first([&**cond, thn, els]),
+      Exp::Array(es, _) => first(es),
+      Exp::ArraySet {
+        array,
+        index,
+        new_value,
+        ..
+      } => first([&**array, index, new_value]),
+      Exp::For {
+        start, end, body, ..
+      } => first([&**start, end, body]),
+      Exp::Call(fun, args, _) => first(std::iter::once(&**fun).chain(args)),
+      Exp::Match {
+        expr,
+        default,
+        arms,
+        ..
+      } => first([&**expr, default].iter().copied().chain(arms.iter().map(|(_, _, e)| e))),
+      Exp::Try { body, arms, .. } => {
+        first(std::iter::once(&**body).chain(arms.iter().map(|(_, _, e)| e)))
+      }
+    }
+  }
+
   pub fn ann(&self) -> Ann
   where
     Ann: Clone,
@@ -203,22 +450,34 @@ impl<Ann> Exp<Ann> {
       Exp::Num(_, a)
       | Exp::Bool(_, a)
       | Exp::Var(_, a)
+      | Exp::Prim0(_, a)
       | Exp::Prim1(_, _, a)
       | Exp::Prim2(_, _, _, a)
       | Exp::Let { ann: a, .. }
+      | Exp::LetVar { ann: a, .. }
+      | Exp::Assign { ann: a, .. }
       | Exp::If { ann: a, .. }
       | Exp::Array(_, a)
       | Exp::ArraySet { ann: a, .. }
       | Exp::Semicolon { ann: a, .. }
+      | Exp::While { ann: a, .. }
+      | Exp::For { ann: a, .. }
       | Exp::Call(_, _, a)
       | Exp::FunDefs { ann: a, .. }
       | Exp::Lambda { ann: a, .. }
       | Exp::MakeClosure { ann: a, .. }
       | Exp::TypeDefs { ann: a, .. }
       | Exp::Match { ann: a, .. }
+      | Exp::GetField { ann: a, .. }
+      | Exp::UpdateField { ann: a, .. }
       | Exp::MakeTypeInstance { ann: a, .. }
       | Exp::MatchType { ann: a, .. }
-      | Exp::GetTypeFields(_, a) => a.clone(),
+      | Exp::GetTypeFields(_, a)
+      | Exp::Raise(_, a)
+      | Exp::Try { ann: a, .. }
+      | Exp::Catch { ann: a, .. }
+      | Exp::Import { ann: a, .. }
+      | Exp::Modules { ann: a, .. } => a.clone(),
     }
   }
 
@@ -227,22 +486,34 @@ impl<Ann> Exp<Ann> {
       Exp::Num(_, a)
       | Exp::Bool(_, a)
       | Exp::Var(_, a)
+      | Exp::Prim0(_, a)
       | Exp::Prim1(_, _, a)
       | Exp::Prim2(_, _, _, a)
       | Exp::Let { ann: a, .. }
+      | Exp::LetVar { ann: a, .. }
+      | Exp::Assign { ann: a, .. }
       | Exp::If { ann: a, .. }
       | Exp::Array(_, a)
       | Exp::ArraySet { ann: a, .. }
       | Exp::Semicolon { ann: a, .. }
+      | Exp::While { ann: a, .. }
+      | Exp::For { ann: a, .. }
       | Exp::Call(_, _, a)
       | Exp::FunDefs { ann: a, .. }
       | Exp::Lambda { ann: a, .. }
       | Exp::MakeClosure { ann: a, .. }
       | Exp::TypeDefs { ann: a, .. }
       | Exp::Match { ann: a, .. }
+      | Exp::GetField { ann: a, .. }
+      | Exp::UpdateField { ann: a, .. }
       | Exp::MakeTypeInstance { ann: a, .. }
       | Exp::MatchType { ann: a, .. }
-      | Exp::GetTypeFields(_, a) => a,
+      | Exp::GetTypeFields(_, a)
+      | Exp::Raise(_, a)
+      | Exp::Try { ann: a, .. }
+      | Exp::Catch { ann: a, .. }
+      | Exp::Import { ann: a, .. }
+      | Exp::Modules { ann: a, .. } => a,
     }
   }
 
@@ -254,6 +525,7 @@ impl<Ann> Exp<Ann> {
       Exp::Num(n, a) => Exp::Num(*n, f(a)),
       Exp::Bool(b, a) => Exp::Bool(*b, f(a)),
       Exp::Var(s, a) => Exp::Var(s.clone(), f(a)),
+      Exp::Prim0(op, a) => Exp::Prim0(*op, f(a)),
       Exp::Prim1(op, e, a) => Exp::Prim1(*op, Box::new(e.map_ann(f)), f(a)),
       Exp::Prim2(op, e1, e2, a) => {
         Exp::Prim2(*op, Box::new(e1.map_ann(f)), Box::new(e2.map_ann(f)), f(a))
@@ -270,6 +542,27 @@ impl<Ann> Exp<Ann> {
         body: Box::new(body.map_ann(f)),
         ann: f(ann),
       },
+      Exp::LetVar {
+        bindings,
+        body,
+        ann,
+      } => Exp::LetVar {
+        bindings: bindings
+          .iter()
+          .map(|(x, e)| (x.clone(), e.map_ann(f)))
+          .collect(),
+        body: Box::new(body.map_ann(f)),
+        ann: f(ann),
+      },
+      Exp::Assign {
+        var,
+        new_value,
+        ann,
+      } => Exp::Assign {
+        var: var.clone(),
+        new_value: Box::new(new_value.map_ann(f)),
+        ann: f(ann),
+      },
       Exp::If {
         cond,
         thn,
@@ -298,6 +591,24 @@ impl<Ann> Exp<Ann> {
         e2: Box::new(e2.map_ann(f)),
         ann: f(ann),
       },
+      Exp::While { cond, body, ann } => Exp::While {
+        cond: Box::new(cond.map_ann(f)),
+        body: Box::new(body.map_ann(f)),
+        ann: f(ann),
+      },
+      Exp::For {
+        var,
+        start,
+        end,
+        body,
+        ann,
+      } => Exp::For {
+        var: var.clone(),
+        start: Box::new(start.map_ann(f)),
+        end: Box::new(end.map_ann(f)),
+        body: Box::new(body.map_ann(f)),
+        ann: f(ann),
+      },
       Exp::Call(fun, args, ann) => Exp::Call(
         Box::new(fun.map_ann(f)),
         args.iter().map(|e| e.map_ann(f)).collect(),
@@ -328,8 +639,14 @@ impl<Ann> Exp<Ann> {
         env: Box::new(env.map_ann(f)),
         ann: f(ann),
       },
-      Exp::TypeDefs { decls, body, ann } => Exp::TypeDefs {
+      Exp::TypeDefs {
+        decls,
+        doc,
+        body,
+        ann,
+      } => Exp::TypeDefs {
         decls: decls.clone(),
+        doc: doc.clone(),
         body: Box::new(body.map_ann(f)),
         ann: f(ann),
       },
@@ -347,6 +664,22 @@ impl<Ann> Exp<Ann> {
           .collect(),
         ann: f(ann),
       },
+      Exp::GetField { exp, field, ann } => Exp::GetField {
+        exp: Box::new(exp.map_ann(f)),
+        field: field.clone(),
+        ann: f(ann),
+      },
+      Exp::UpdateField {
+        exp,
+        field,
+        new_value,
+        ann,
+      } => Exp::UpdateField {
+        exp: Box::new(exp.map_ann(f)),
+        field: field.clone(),
+        new_value: Box::new(new_value.map_ann(f)),
+        ann: f(ann),
+      },
       Exp::MakeTypeInstance {
         typetag,
         fields,
@@ -364,6 +697,48 @@ impl<Ann> Exp<Ann> {
       Exp::GetTypeFields(custom_type, ann) => {
         Exp::GetTypeFields(Box::new(custom_type.map_ann(f)), f(ann))
       }
+      Exp::Raise(e, ann) => Exp::Raise(Box::new(e.map_ann(f)), f(ann)),
+      Exp::Try { body, arms, ann } => Exp::Try {
+        body: Box::new(body.map_ann(f)),
+        arms: arms
+          .iter()
+          .map(|(match_case, params, case)| (match_case.clone(), params.clone(), case.map_ann(f)))
+          .collect(),
+        ann: f(ann),
+      },
+      Exp::Catch {
+        body,
+        var,
+        handler,
+        ann,
+      } => Exp::Catch {
+        body: Box::new(body.map_ann(f)),
+        var: var.clone(),
+        handler: Box::new(handler.map_ann(f)),
+        ann: f(ann),
+      },
+      Exp::Import {
+        path,
+        module,
+        body,
+        ann,
+      } => Exp::Import {
+        path: path.clone(),
+        module: *module,
+        body: Box::new(body.map_ann(f)),
+        ann: f(ann),
+      },
+      Exp::Modules { modules, body, ann } => Exp::Modules {
+        modules: modules
+          .iter()
+          .map(|module| Module {
+            name: module.name.clone(),
+            prog: module.prog.map_ann(f),
+          })
+          .collect(),
+        body: Box::new(body.map_ann(f)),
+        ann: f(ann),
+      },
     }
   }
 }
@@ -375,6 +750,7 @@ impl<Ann> SeqExp<Ann> {
   {
     match self {
       SeqExp::Imm(_, a)
+      | SeqExp::Prim0(_, a)
       | SeqExp::Prim1(_, _, a)
       | SeqExp::Prim2(_, _, _, a)
       | SeqExp::ArraySet { ann: a, .. }
@@ -382,10 +758,15 @@ impl<Ann> SeqExp<Ann> {
       | SeqExp::MakeClosure { ann: a, .. }
       | SeqExp::CallClosure { ann: a, .. }
       | SeqExp::Let { ann: a, .. }
+      | SeqExp::Assign { ann: a, .. }
       | SeqExp::If { ann: a, .. }
+      | SeqExp::While { ann: a, .. }
+      | SeqExp::For { ann: a, .. }
       | SeqExp::MakeTypeInstance { ann: a, .. }
       | SeqExp::MatchType { ann: a, .. }
-      | SeqExp::GetTypeFields(_, a) => a.clone(),
+      | SeqExp::GetTypeFields(_, a)
+      | SeqExp::Raise(_, a)
+      | SeqExp::Catch { ann: a, .. } => a.clone(),
     }
   }
 
@@ -395,6 +776,7 @@ impl<Ann> SeqExp<Ann> {
   {
     match self {
       SeqExp::Imm(imm, a) => SeqExp::Imm(imm.clone(), f(a)),
+      SeqExp::Prim0(op, a) => SeqExp::Prim0(*op, f(a)),
       SeqExp::Prim1(op, imm, a) => SeqExp::Prim1(*op, imm.clone(), f(a)),
       SeqExp::Prim2(op, imm1, imm2, a) => SeqExp::Prim2(*op, imm1.clone(), imm2.clone(), f(a)),
       SeqExp::ArraySet {
@@ -436,6 +818,15 @@ impl<Ann> SeqExp<Ann> {
         body: Box::new(body.map_ann(f)),
         ann: f(ann),
       },
+      SeqExp::Assign {
+        var,
+        new_value,
+        ann,
+      } => SeqExp::Assign {
+        var: var.clone(),
+        new_value: new_value.clone(),
+        ann: f(ann),
+      },
       SeqExp::If {
         cond,
         thn,
@@ -447,6 +838,24 @@ impl<Ann> SeqExp<Ann> {
         els: Box::new(els.map_ann(f)),
         ann: f(ann),
       },
+      SeqExp::While { cond, body, ann } => SeqExp::While {
+        cond: Box::new(cond.map_ann(f)),
+        body: Box::new(body.map_ann(f)),
+        ann: f(ann),
+      },
+      SeqExp::For {
+        var,
+        start,
+        end,
+        body,
+        ann,
+      } => SeqExp::For {
+        var: var.clone(),
+        start: start.clone(),
+        end: end.clone(),
+        body: Box::new(body.map_ann(f)),
+        ann: f(ann),
+      },
       SeqExp::MakeTypeInstance {
         typetag,
         fields,
@@ -462,6 +871,18 @@ impl<Ann> SeqExp<Ann> {
         ann: f(ann),
       },
       SeqExp::GetTypeFields(type_tag, ann) => SeqExp::GetTypeFields(type_tag.clone(), f(ann)),
+      SeqExp::Raise(imm, ann) => SeqExp::Raise(imm.clone(), f(ann)),
+      SeqExp::Catch {
+        body,
+        var,
+        handler,
+        ann,
+      } => SeqExp::Catch {
+        body: Box::new(body.map_ann(f)),
+        var: var.clone(),
+        handler: Box::new(handler.map_ann(f)),
+        ann: f(ann),
+      },
     }
   }
 }
@@ -475,6 +896,7 @@ impl<Ann> FunDecl<Exp<Ann>, Ann> {
       name: self.name.clone(),
       parameters: self.parameters.clone(),
       body: self.body.map_ann(f),
+      doc: self.doc.clone(),
       ann: f(&self.ann),
     }
   }
@@ -501,6 +923,7 @@ impl<Ann> FunDecl<SeqExp<Ann>, Ann> {
       name: self.name.clone(),
       parameters: self.parameters.clone(),
       body: self.body.map_ann(f),
+      doc: self.doc.clone(),
       ann: f(&self.ann),
     }
   }
@@ -0,0 +1,88 @@
+use crate::interp::{sprint_snake_val, Config, Event, InterpErr, Machine, Session};
+use crate::syntax::SurfProg;
+
+use std::io::Write;
+
+// A call that hasn't returned yet
+struct Frame {
+  call: String,
+  // the depth of the continuation the call returns to
+  depth: usize,
+  shown: bool,
+}
+
+fn write_err(e: std::io::Error) -> InterpErr {
+  InterpErr::Write { msg: e.to_string() }
+}
+
+// Runs `prog` in the interpreter, logging every function call and return
+// to `log`, indented by call depth. If `only` is non-empty, just the calls
+// to functions with those names are logged.
+//
+// Because of tail calls, a function that tail calls another returns at the
+// same time as its callee, and both returns are logged.
+pub fn trace<Ann, W, L>(
+  prog: &SurfProg<Ann>,
+  config: &Config,
+  only: &[String],
+  out: &mut W,
+  log: &mut L,
+) -> Result<(), InterpErr>
+where
+  Ann: Clone,
+  W: Write,
+  L: Write,
+{
+  let mut session = Session::new(prog, config);
+  let mut frames: Vec<Frame> = Vec::new();
+  let indent = |frames: &[Frame]| "  ".repeat(frames.iter().filter(|f| f.shown).count());
+  while !session.is_done() {
+    match session.step(out)? {
+      Event::Call { fun, args } => {
+        let name = session.store.fun_name(fun);
+        let heap = session.store.heap();
+        let call = format!(
+          "{}({})",
+          name,
+          args
+            .iter()
+            .map(|v| sprint_snake_val(*v, heap))
+            .collect::<Vec<String>>()
+            .join(", ")
+        );
+        let shown = only.is_empty() || only.iter().any(|x| x == name);
+        if shown {
+          writeln!(log, "{}-> {}", indent(&frames), call).map_err(write_err)?;
+        }
+        let depth = match &session.machine {
+          Some(Machine::Descending { stk, .. }) => stk.depth(),
+          _ => unreachable!("calls always descend into the function body"),
+        };
+        frames.push(Frame { call, depth, shown });
+      }
+      Event::Step | Event::ArraySet { .. } | Event::Done => {}
+    }
+
+    // a call returns once its value reaches the continuation it was called with
+    if let (Some(Machine::Returning { v, stk }), Some(_)) = (&session.machine, frames.last()) {
+      let depth = stk.depth();
+      while let Some(frame) = frames.last() {
+        if frame.depth != depth {
+          break;
+        }
+        let frame = frames.pop().unwrap();
+        if frame.shown {
+          writeln!(
+            log,
+            "{}<- {} = {}",
+            indent(&frames),
+            frame.call,
+            sprint_snake_val(*v, session.store.heap())
+          )
+          .map_err(write_err)?;
+        }
+      }
+    }
+  }
+  Ok(())
+}
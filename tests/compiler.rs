@@ -0,0 +1,559 @@
+use snake::compile::{OptLevel, Stage};
+use snake::errors::{CompileErr, RunnerErr};
+use snake::span::Span2;
+use snake::syntax::{Exp, Prim0};
+use snake::Compiler;
+use std::path::Path;
+
+#[test]
+fn compile_from_source_text() {
+  let compiled = Compiler::new().compile("def f(x): x + 1 in f(1)").unwrap();
+  assert!(compiled.assembly.contains("start_here:"));
+  assert!(compiled.dumps.is_empty());
+}
+
+#[test]
+fn compile_with_heap_size() {
+  let compiled = Compiler::new().heap_size(1024).compile("[1, 2, 3]").unwrap();
+  assert!(compiled.assembly.contains("times 1024 dq 0"));
+}
+
+#[test]
+fn compile_dumps_requested_stages() {
+  let compiled = Compiler::new()
+    .dump(Stage::Parsed)
+    .dump(Stage::Lifted)
+    .compile("let y = 2 in lambda x: x + y end")
+    .unwrap();
+  assert_eq!(compiled.dumps.len(), 2);
+  assert!(compiled.dump(Stage::Parsed).unwrap().contains("lambda x"));
+  assert!(compiled
+    .dump(Stage::Lifted)
+    .unwrap()
+    .contains("make_closure(1, __snake_lambda_"));
+  assert!(compiled.dump(Stage::Sequentialized).is_none());
+}
+
+#[test]
+fn compile_with_optimizations_is_shorter() {
+  let src = "let a = 1, b = a + 1 in if b == 2: a else: b";
+  let o0 = Compiler::new().compile(src).unwrap().assembly;
+  let o1 = Compiler::new()
+    .opt_level(OptLevel::O1)
+    .compile(src)
+    .unwrap()
+    .assembly;
+  assert!(o1.lines().count() < o0.lines().count());
+}
+
+#[test]
+fn parse_error_has_location() {
+  match Compiler::new().compile("let x = 1 in\nlet y = in y") {
+    Err(RunnerErr::Parse { location, .. }) => assert_eq!(
+      location,
+      Some(Span2 {
+        file: None,
+        start_line: 2,
+        start_col: 8,
+        end_line: 2,
+        end_col: 10
+      })
+    ),
+    other => panic!("expected a parse error, got {:?}", other.map(|c| c.assembly)),
+  }
+}
+
+#[test]
+fn unexpected_eof_has_location() {
+  match Compiler::new().compile("let x = 1 in") {
+    Err(RunnerErr::Parse { location, .. }) => assert!(location.is_some()),
+    other => panic!("expected a parse error, got {:?}", other.map(|c| c.assembly)),
+  }
+}
+
+#[test]
+fn unterminated_comment_has_location() {
+  match Compiler::new().compile("1 + #| one #| two |#\n2") {
+    Err(RunnerErr::Parse { msg, location }) => {
+      assert!(msg.contains("Unterminated block comment"), "{}", msg);
+      assert_eq!(
+        location,
+        Some(Span2 {
+          file: None,
+          start_line: 1,
+          start_col: 4,
+          end_line: 1,
+          end_col: 6
+        })
+      );
+    }
+    other => panic!("expected a parse error, got {:?}", other.map(|c| c.assembly)),
+  }
+}
+
+#[test]
+fn lex_errors_print_line_and_column() {
+  match Compiler::new().compile("1 +\n 0b102") {
+    Err(e @ RunnerErr::Parse { .. }) => assert_eq!(
+      e.to_string(),
+      "Error parsing input: Invalid number literal 0b102 at line 2, column 1 to line 2, column 6"
+    ),
+    other => panic!("expected a parse error, got {:?}", other.map(|c| c.assembly)),
+  }
+}
+
+#[test]
+fn number_literal_errors_cover_the_literal() {
+  let cases = [
+    ("1 + 9_223_372_036_854_775_808", "out of the range", 4, 29),
+    ("-0x8000_0000_0000_0001", "out of the range", 0, 22),
+    (
+      "0x4000_0000_0000_0000",
+      "Number literal 0x4000_0000_0000_0000 is out of the range -4611686018427387904 to 4611686018427387903",
+      0,
+      21,
+    ),
+    ("1 + 0b1012", "Invalid number literal 0b1012", 4, 10),
+    ("[1_, 2]", "Invalid number literal 1_", 1, 3),
+    ("3rd", "Invalid number literal 3rd", 0, 3),
+  ];
+  for (source, expected, start_col, end_col) in cases {
+    match Compiler::new().compile(source) {
+      Err(RunnerErr::Parse { msg, location }) => {
+        assert!(msg.contains(expected), "{}: {}", source, msg);
+        assert_eq!(
+          location,
+          Some(Span2 {
+            file: None,
+            start_line: 1,
+            start_col,
+            end_line: 1,
+            end_col
+          }),
+          "{}",
+          source
+        );
+      }
+      other => panic!("expected a parse error, got {:?}", other.map(|c| c.assembly)),
+    }
+  }
+  // the most negative number only fits with its sign
+  assert!(Compiler::new().parse("-0x4000_0000_0000_0000").is_ok());
+}
+
+#[test]
+fn doc_comments_are_kept_in_the_syntax_tree() {
+  let compiled = Compiler::new()
+    .dump(Stage::Parsed)
+    .compile("# plain\n## Kept\n##\n## twice.\ndef f(x): x in f(1)")
+    .unwrap();
+  let parsed = compiled.dump(Stage::Parsed).unwrap();
+  assert!(parsed.contains("## Kept\n##\n## twice.\ndef f(x)"), "{}", parsed);
+  assert!(parsed.starts_with("# plain\n"), "{}", parsed);
+}
+
+#[test]
+fn parsed_program_keeps_comments() {
+  let source = "# top\nlet x = 1 in # x\n#| block |# x + [2, #| two\nlines |# 3][0]\n# last";
+  let compiled = Compiler::new().dump(Stage::Parsed).compile(source).unwrap();
+  assert_eq!(
+    compiled.dump(Stage::Parsed).unwrap(),
+    "# top\nimport \"<prelude>\"\nlet x = 1 in\n# x\n#| block |# x + [2, #| two\nlines |#\n3][0]\n# last"
+  );
+}
+
+#[test]
+fn chan_has_no_operand() {
+  let prog = Compiler::new().parse("chan()").unwrap();
+  assert!(matches!(prog, Exp::Prim0(Prim0::Chan, _)), "{:?}", prog);
+  let compiled = Compiler::new().dump(Stage::Sequentialized).compile("chan()").unwrap();
+  assert!(compiled.dump(Stage::Sequentialized).unwrap().contains("chan()"));
+}
+
+#[test]
+fn compile_error_is_structured() {
+  match Compiler::new().compile("let a = 1 in\nb") {
+    Err(RunnerErr::CodeGen(CompileErr::UnboundVariable { unbound, location })) => {
+      assert_eq!(unbound, "b");
+      assert_eq!(location.start_line, 2);
+    }
+    other => panic!("expected an unbound variable, got {:?}", other.map(|c| c.assembly)),
+  }
+}
+
+#[test]
+fn type_names_are_emitted_into_assembly() {
+  let compiled = Compiler::new()
+    .compile("type Ginger(size), Fish in print(Ginger(2)); Fish")
+    .unwrap();
+  assert!(compiled.assembly.contains("snake_type_names:"));
+  assert!(compiled.assembly.contains("db \"Ginger\", 0"));
+  assert!(compiled.assembly.contains("db \"Fish\", 0"));
+  assert!(!std::path::Path::new("runtime/custom_types.txt").exists());
+}
+
+#[test]
+fn print_writes_its_argument_and_returns_it() {
+  use snake::runner::{compile_and_run_file_with, Limits};
+  let tmp_dir = tempfile::TempDir::new().unwrap();
+  let src = tmp_dir.path().join("print.garter");
+  std::fs::write(&src, "let x = print([1, true]) in x[0] + 1").unwrap();
+  let mut out = Vec::new();
+  compile_and_run_file_with(
+    &Compiler::new(),
+    &Limits::new(),
+    &src,
+    tmp_dir.path(),
+    &mut out,
+  )
+  .unwrap();
+  assert_eq!(String::from_utf8(out).unwrap(), "[1, true]\n2\n");
+}
+
+#[test]
+fn build_standalone_executable() {
+  use snake::runner::{build_file, Artifact};
+  let tmp_dir = tempfile::TempDir::new().unwrap();
+  let exe = tmp_dir.path().join("print_type");
+  build_file(
+    std::path::Path::new("examples/garter/trivial_tests/print_type.garter"),
+    &Compiler::new(),
+    Artifact::Executable,
+    &exe,
+    true,
+  )
+  .unwrap();
+  assert!(exe.with_extension("s").exists());
+  assert!(exe.with_extension("o").exists());
+  let out = std::process::Command::new(&exe).output().unwrap();
+  assert!(out.status.success());
+  assert_eq!(String::from_utf8(out.stdout).unwrap(), "Some(2)\n0\n");
+}
+
+#[test]
+fn build_object_file_only() {
+  use snake::runner::{build_file, Artifact};
+  let tmp_dir = tempfile::TempDir::new().unwrap();
+  let obj = tmp_dir.path().join("print_type.o");
+  build_file(
+    std::path::Path::new("examples/garter/trivial_tests/print_type.garter"),
+    &Compiler::new(),
+    Artifact::Object,
+    &obj,
+    false,
+  )
+  .unwrap();
+  assert!(obj.exists());
+  assert!(!obj.with_extension("s").exists());
+  assert_eq!(std::fs::read_dir(tmp_dir.path()).unwrap().count(), 1);
+}
+
+#[test]
+fn run_times_out() {
+  use snake::runner::{compile_and_run_file_with, Limits};
+  use std::time::{Duration, Instant};
+  let tmp_dir = tempfile::TempDir::new().unwrap();
+  let start = Instant::now();
+  let result = compile_and_run_file_with(
+    &Compiler::new(),
+    &Limits::new().timeout(Duration::from_millis(500)),
+    std::path::Path::new("examples/egg/infinite_loop.egg"),
+    tmp_dir.path(),
+    &mut Vec::new(),
+  );
+  match result {
+    Err(RunnerErr::Timeout(t)) => assert_eq!(t, Duration::from_millis(500)),
+    other => panic!("expected a timeout, got {:?}", other.map_err(|e| e.to_string())),
+  }
+  assert!(start.elapsed() < Duration::from_secs(5));
+}
+
+#[test]
+fn run_stops_when_output_fails() {
+  use snake::runner::{compile_and_run_file_with, Limits};
+  use std::time::{Duration, Instant};
+
+  struct Closed;
+  impl std::io::Write for Closed {
+    fn write(&mut self, _: &[u8]) -> std::io::Result<usize> {
+      Err(std::io::ErrorKind::BrokenPipe.into())
+    }
+    fn flush(&mut self) -> std::io::Result<()> {
+      Ok(())
+    }
+  }
+
+  let tmp_dir = tempfile::TempDir::new().unwrap();
+  let src = tmp_dir.path().join("forever.garter");
+  std::fs::write(&src, "while true: print(1) end").unwrap();
+  let start = Instant::now();
+  let result = compile_and_run_file_with(
+    &Compiler::new(),
+    &Limits::new(),
+    &src,
+    tmp_dir.path(),
+    &mut Closed,
+  );
+  match result {
+    Err(RunnerErr::Run(msg)) => assert!(msg.starts_with("I/O error"), "{}", msg),
+    other => panic!("expected an I/O error, got {:?}", other.map_err(|e| e.to_string())),
+  }
+  assert!(start.elapsed() < Duration::from_secs(5));
+}
+
+#[cfg(unix)]
+#[test]
+fn run_exceeds_cpu_limit() {
+  use snake::runner::{compile_and_run_file_with, Limits};
+  let tmp_dir = tempfile::TempDir::new().unwrap();
+  let result = compile_and_run_file_with(
+    &Compiler::new(),
+    &Limits::new()
+      .cpu_time(1)
+      .timeout(std::time::Duration::from_secs(30)),
+    std::path::Path::new("examples/egg/infinite_loop.egg"),
+    tmp_dir.path(),
+    &mut Vec::new(),
+  );
+  match result {
+    Err(RunnerErr::Run(msg)) => assert!(msg.contains("signal"), "{}", msg),
+    other => panic!("expected a crash, got {:?}", other.map_err(|e| e.to_string())),
+  }
+}
+
+#[test]
+fn imported_names_are_namespaced() {
+  let compiled = Compiler::new()
+    .dump(Stage::Uniquified)
+    .compile("import \"examples/garter/modules/lists.garter\"\nrange(0, 3)")
+    .unwrap();
+  let uniquified = compiled.dump(Stage::Uniquified).unwrap();
+  assert!(uniquified.contains("lists.range"));
+  assert!(uniquified.contains("lists.Cons"));
+  // the imported file's own body isn't run
+  assert!(!uniquified.contains("range(0, 5)"));
+  // but its types are printed without the namespace
+  assert!(compiled.assembly.contains("db \"Cons\", 0"));
+}
+
+#[test]
+fn imports_are_found_in_search_dirs() {
+  let source = "import \"option.garter\"\nhead(Some(1))";
+  match Compiler::new().compile(source) {
+    Err(RunnerErr::CodeGen(CompileErr::UnknownModule { path, location })) => {
+      assert_eq!(path, "option.garter");
+      assert_eq!(location.start_line, 1);
+      assert_eq!(location.file, None);
+    }
+    other => panic!("expected an unknown module, got {:?}", other.map(|c| c.assembly)),
+  }
+  assert!(Compiler::new()
+    .search_dir(Path::new("examples/garter/modules"))
+    .compile(source)
+    .is_ok());
+}
+
+#[test]
+fn errors_in_imported_files_name_the_file() {
+  match Compiler::new().compile("import \"examples/garter/modules/bad_lib.garter\"\nf(1)") {
+    Err(RunnerErr::CodeGen(CompileErr::UnboundVariable { unbound, location })) => {
+      assert_eq!(unbound, "y");
+      assert_eq!(location.start_line, 2);
+      assert_eq!(location.file.as_deref(), Some("./examples/garter/modules/bad_lib.garter"));
+    }
+    other => panic!("expected an unbound variable, got {:?}", other.map(|c| c.assembly)),
+  }
+}
+
+#[test]
+fn prelude_is_in_scope() {
+  let compiled = Compiler::new()
+    .dump(Stage::Lifted)
+    .compile("foldl(lambda acc, x: acc + x end, 0, range(0, 4))")
+    .unwrap();
+  let lifted = compiled.dump(Stage::Lifted).unwrap();
+  assert!(lifted.contains("prelude.foldl"));
+  assert!(lifted.contains("prelude.range"));
+  assert!(lifted.contains("prelude.cons"));
+}
+
+#[test]
+fn unused_prelude_functions_are_dropped() {
+  let compiled = Compiler::new()
+    .dump(Stage::ResolvedTypes)
+    .dump(Stage::Lifted)
+    .compile("min(1, 2)")
+    .unwrap();
+  assert!(compiled.dump(Stage::ResolvedTypes).unwrap().contains("prelude.map"));
+  let lifted = compiled.dump(Stage::Lifted).unwrap();
+  assert!(lifted.contains("prelude.min"));
+  assert!(!lifted.contains("prelude.map"));
+  assert!(!lifted.contains("prelude.max"));
+}
+
+#[test]
+fn only_vars_captured_by_a_closure_are_boxed() {
+  let compiled = Compiler::new()
+    .dump(Stage::Lifted)
+    .compile("def f(n): var x = 0, y = 0 in let g = lambda: y := y + n end in x := x + n; g(); x + y in f(1)")
+    .unwrap();
+  let lifted = compiled.dump(Stage::Lifted).unwrap();
+  assert!(lifted.contains("_x = 0"), "{}", lifted);
+  assert!(!lifted.contains("_x[0]"), "{}", lifted);
+  assert!(lifted.contains("_y = [0]"), "{}", lifted);
+}
+
+#[test]
+fn unused_prelude_values_are_dropped() {
+  let compiled = Compiler::new()
+    .dump(Stage::Lifted)
+    .compile("def f(x): x + 1 in f(3)")
+    .unwrap();
+  let lifted = compiled.dump(Stage::Lifted).unwrap();
+  assert!(lifted.contains("_f(3)"), "{}", lifted);
+  assert!(!lifted.contains("prelude.None"), "{}", lifted);
+  assert!(!lifted.contains("prelude."), "{}", lifted);
+}
+
+#[test]
+fn build_modules_separately() {
+  use snake::runner::build_file_separately;
+  let tmp_dir = tempfile::TempDir::new().unwrap();
+  let obj_dir = tmp_dir.path().join("obj");
+  let exe = tmp_dir.path().join("main");
+  let compiled = build_file_separately(
+    std::path::Path::new("examples/garter/modules/main.garter"),
+    &Compiler::new(),
+    &exe,
+    &obj_dir,
+  )
+  .unwrap();
+  assert_eq!(compiled, vec!["prelude", "lists", "option", "main"]);
+  assert!(obj_dir.join("lists.o").exists());
+  let out = std::process::Command::new(&exe).output().unwrap();
+  assert!(out.status.success());
+  assert_eq!(String::from_utf8(out.stdout).unwrap(), "Some(10)\n10\n");
+}
+
+#[test]
+fn separate_builds_only_compile_changed_files() {
+  use snake::runner::build_file_separately;
+  let tmp_dir = tempfile::TempDir::new().unwrap();
+  let obj_dir = tmp_dir.path().join("obj");
+  let exe = tmp_dir.path().join("main");
+  let main = tmp_dir.path().join("main.garter");
+  std::fs::write(
+    tmp_dir.path().join("shapes.garter"),
+    "type Square(side) in\ndef area(s):\n  match s default 0:\n    case Square(side) => side * side\n  end\nin\n0",
+  )
+  .unwrap();
+  let build = |source: &str| {
+    std::fs::write(&main, source).unwrap();
+    let compiled = build_file_separately(&main, &Compiler::new(), &exe, &obj_dir).unwrap();
+    let out = std::process::Command::new(&exe).output().unwrap();
+    (compiled, String::from_utf8(out.stdout).unwrap())
+  };
+  let (compiled, out) = build("import \"shapes.garter\"\nprint(Square(3));\narea(Square(3))");
+  assert_eq!(compiled, vec!["prelude", "shapes", "main"]);
+  assert_eq!(out, "Square(3)\n9\n");
+  let (compiled, out) = build("import \"shapes.garter\"\narea(Square(4))");
+  assert_eq!(compiled, vec!["main"]);
+  assert_eq!(out, "16\n");
+  let (compiled, _) = build("import \"shapes.garter\"\n# unchanged\narea(Square(4))");
+  assert!(compiled.is_empty());
+}
+
+// Builds `source` as the main file in `dir`, compiling each file on its own
+// into `dir/obj`, and returns the files compiled and what the program printed
+fn build_separately_in(dir: &Path, source: &str) -> (Vec<String>, String) {
+  use snake::runner::build_file_separately;
+  let main = dir.join("main.garter");
+  let exe = dir.join("main");
+  std::fs::write(&main, source).unwrap();
+  let compiled = build_file_separately(&main, &Compiler::new(), &exe, &dir.join("obj")).unwrap();
+  let out = std::process::Command::new(&exe).output().unwrap();
+  let printed = String::from_utf8(out.stdout).unwrap() + &String::from_utf8(out.stderr).unwrap();
+  (compiled, printed)
+}
+
+#[test]
+fn separate_builds_keep_importers_of_changed_bodies() {
+  let tmp_dir = tempfile::TempDir::new().unwrap();
+  let lib = tmp_dir.path().join("lib.garter");
+  std::fs::write(&lib, "def next(n): n + 1 in 0").unwrap();
+  let (compiled, out) = build_separately_in(tmp_dir.path(), "import \"lib.garter\"\nnext(1)");
+  assert_eq!(compiled, vec!["prelude", "lib", "main"]);
+  assert_eq!(out, "2\n");
+  std::fs::write(&lib, "def next(n): n + 2 in 0").unwrap();
+  let (compiled, out) = build_separately_in(tmp_dir.path(), "import \"lib.garter\"\nnext(1)");
+  assert_eq!(compiled, vec!["lib"]);
+  assert_eq!(out, "3\n");
+}
+
+#[test]
+fn separate_builds_compile_reparenthesized_code() {
+  let tmp_dir = tempfile::TempDir::new().unwrap();
+  let (_, out) = build_separately_in(tmp_dir.path(), "let x = [0] in (x[0] := 5) + 1; x");
+  assert!(out.contains("arithmetic expected a number"), "{}", out);
+  let (compiled, out) = build_separately_in(tmp_dir.path(), "let x = [0] in x[0] := (5 + 1); x");
+  assert_eq!(compiled, vec!["main"]);
+  assert_eq!(out, "[6]\n");
+}
+
+#[test]
+fn separate_builds_box_vars_for_callcc_in_imports() {
+  let tmp_dir = tempfile::TempDir::new().unwrap();
+  std::fs::write(tmp_dir.path().join("lib.garter"), "def capture(f): callcc(f) in 0").unwrap();
+  let (_, out) = build_separately_in(
+    tmp_dir.path(),
+    "import \"lib.garter\"
+    var again = false, count = 0 in
+    let x = capture(lambda k: again := k; 0 end) in
+    count := count + 1; if x < 3: again(x + 1) else: count",
+  );
+  assert_eq!(out, "4\n");
+}
+
+#[test]
+fn separate_builds_compile_try() {
+  let tmp_dir = tempfile::TempDir::new().unwrap();
+  let (_, out) = build_separately_in(
+    tmp_dir.path(),
+    "try raise 4 with case Num(n) => n + 1 end",
+  );
+  assert_eq!(out, "5\n");
+}
+
+#[test]
+fn separate_builds_compile_fields() {
+  let tmp_dir = tempfile::TempDir::new().unwrap();
+  let (_, out) = build_separately_in(
+    tmp_dir.path(),
+    "type P(x, y) in let p = P(1, 2) with y = 5 in [p.x, p.y]",
+  );
+  assert_eq!(out, "[1, 5]\n");
+}
+
+#[test]
+fn compiled_threads_follow_the_seed_like_the_interpreter() {
+  use snake::interp::{self, Config};
+  use snake::runner::{build_file, Artifact};
+  let tmp_dir = tempfile::TempDir::new().unwrap();
+  let src = "let c = chan() in
+    spawn(lambda: send(c, 1) end); spawn(lambda: send(c, 2) end); spawn(lambda: send(c, 3) end);
+    let a = recv(c), b = recv(c) in [a, b, recv(c)]";
+  let source = tmp_dir.path().join("race.garter");
+  std::fs::write(&source, src).unwrap();
+  let exe = tmp_dir.path().join("race");
+  build_file(&source, &Compiler::new(), Artifact::Executable, &exe, false).unwrap();
+  let prog = Compiler::new().parse(src).unwrap();
+  for seed in 0..8 {
+    let out = std::process::Command::new(&exe)
+      .env("SNAKE_SEED", seed.to_string())
+      .output()
+      .unwrap();
+    let mut interpreted = Vec::new();
+    interp::prog(&prog, &Config::new().seed(seed), &mut interpreted).unwrap();
+    assert_eq!(out.stdout, interpreted, "with seed {}", seed);
+  }
+}
@@ -0,0 +1,108 @@
+use snake::interp::Config;
+use snake::runner::debug_file;
+use snake::Compiler;
+use std::path::Path;
+
+fn debug_session(f: &str, commands: &str) -> String {
+  debug_session_with(f, false, commands)
+}
+
+fn debug_session_with(f: &str, record: bool, commands: &str) -> String {
+  let mut out = Vec::new();
+  debug_file(
+    Path::new(&format!("examples/{}", f)),
+    &Compiler::new(),
+    &Config::new(),
+    record,
+    commands.as_bytes(),
+    &mut out,
+  )
+  .unwrap();
+  String::from_utf8(out).unwrap()
+}
+
+#[test]
+fn debug_breakpoint_and_env() {
+  let out = debug_session("diamond/mutual_recursion.diamond", "b 11\nc\ne\n");
+  assert!(out.contains("Breakpoint at line 11"));
+  assert!(out.contains("*  11 | isEven(a - 1)"));
+  assert!(out.contains("a = 4\nisOdd = <closure>\nisEven = <closure>\n"));
+}
+
+#[test]
+fn debug_runs_to_completion() {
+  let out = debug_session("diamond/mutual_recursion.diamond", "c\n");
+  assert!(out.ends_with("false\nProgram finished\n"));
+}
+
+#[test]
+fn debug_shows_arrays_like_print() {
+  let out = debug_session("egg/arr_mutate.egg", "s\ne\nk\n");
+  assert!(out.contains("   2 | arr[0] := 9"));
+  assert!(out.contains("arr = [1, 2, 3]"));
+  assert!(out.contains("The continuation is empty"));
+}
+
+#[test]
+fn debug_step_into_and_over() {
+  let out = debug_session("diamond/fib.diamond", "b 5\nc\nc\nk\n");
+  assert!(out.contains("#0   print(_)\n#1   _ + print(fib(i - 2))\n"));
+
+  // fib(10) is a tail call, so stepping over it still enters fib, but
+  // stepping over the recursive calls on line 5 runs them to completion
+  let out = debug_session("diamond/fib.diamond", "s\nn\nn\nn\n");
+  assert!(out.contains("   7 | fib(10)\n(sdb)     2 | if i == 0 || i == 1:\n"));
+  assert!(out.contains("(sdb)     5 | print(fib(i - 1)) + print(fib(i - 2))\n(sdb) 1\n"));
+  assert!(out.ends_with("89\nProgram finished\n"));
+}
+
+#[test]
+fn debug_reverse_needs_recording() {
+  let out = debug_session("egg/arr_mutate.egg", "s\nrs\n");
+  assert!(out.ends_with("Start the debugger with --record to go back in time\n(sdb) "));
+}
+
+#[test]
+fn debug_reverse_step_and_continue() {
+  let out = debug_session_with("diamond/fib.diamond", true, "b 7\ns\ns\nrs\nrs\nrc\n");
+  assert!(out.contains("(sdb)     2 | if i == 0 || i == 1:\n(sdb) *   7 | fib(10)\n"));
+  assert!(out.contains("(sdb)     1 | def fib(i):\n(sdb) Already at the start of the program\n"));
+  // the output isn't repeated when running recorded steps again
+  let out = debug_session_with("diamond/fib.diamond", true, "b 7\nc\nc\nrc\nc\n");
+  assert!(out.ends_with("89\nProgram finished\n(sdb) *   7 | fib(10)\n(sdb) Program finished\n(sdb) "));
+
+  // going back from the end replays from the latest snapshot
+  let out = debug_session_with("diamond/fib.diamond", true, "c\nrs\ne\nk\n");
+  assert!(out.contains("Program finished\n(sdb)     3 | 1\n(sdb) i = 0\n"));
+  assert!(out.ends_with("#9   55 + _\n(sdb) "));
+}
+
+#[test]
+fn debug_reverse_continue_to_start_of_long_run() {
+  // the snapshots share the array with the session that goes on changing it
+  let out = debug_session_with("egg/arr_count_up.egg", true, "c\nrc\nb 5\nc\ne\n");
+  assert!(out.contains("[499]\nProgram finished\n"));
+  assert!(out.ends_with("n = 0\ncounts = [0]\ncount_up = <closure>\n(sdb) "));
+}
+
+#[test]
+fn debug_finds_last_change_to_array() {
+  let out = debug_session_with(
+    "egg/arr_accumulate.egg",
+    true,
+    "b 3\nc\nlc counts 0\ne\nlc counts 0\nlc counts 0\nlc counts 0\nlc counts 2\n",
+  );
+  assert!(out.contains("(sdb) counts[0] changes from 5 to 6\n    6 | counts[0] := total;\n(sdb) "));
+  assert!(out.contains("total = 6\nn = 1\ncounts = [5, 2]\n"));
+  assert!(out.contains("(sdb) counts[0] changes from 3 to 5\n"));
+  assert!(out.contains("(sdb) counts[0] changes from 0 to 3\n"));
+  assert!(out.contains("(sdb) counts[0] hasn't changed\n"));
+  assert!(out.ends_with("(sdb) counts[2] hasn't changed\n(sdb) "));
+}
+
+#[test]
+fn debug_last_change_to_cyclic_array() {
+  let out = debug_session_with("egg/recursive_array.egg", true, "c\nrs\nlc arr 1\ne\n");
+  assert!(out.contains("arr[1] changes from true to [0, <loop>]\n    2 | arr[1] := arr;\n"));
+  assert!(out.ends_with("arr = [0, true]\n(sdb) "));
+}
@@ -19,6 +19,12 @@ macro_rules! mk_fail_test {
 }
 
 // IMPLEMENTATION
+
+// no example should take anywhere near this long
+fn test_limits() -> runner::Limits {
+  runner::Limits::new().timeout(std::time::Duration::from_secs(10))
+}
+
 fn test_example_file(f: &str, expected_str: &str) -> std::io::Result<()> {
   use std::path::Path;
   let p_name = format!("examples/{}", f);
@@ -26,7 +32,13 @@ fn test_example_file(f: &str, expected_str: &str) -> std::io::Result<()> {
 
   let tmp_dir = tempfile::TempDir::new()?;
   let mut w = Vec::new();
-  match runner::compile_and_run_file(&path, tmp_dir.path(), &mut w) {
+  match runner::compile_and_run_file_with(
+    &snake::Compiler::new(),
+    &test_limits(),
+    &path,
+    tmp_dir.path(),
+    &mut w,
+  ) {
     Ok(()) => {
       let stdout = std::str::from_utf8(&w).unwrap();
       assert_eq!(stdout.trim(), expected_str)
@@ -45,7 +57,9 @@ fn test_example_fail(f: &str, includes: &str) -> std::io::Result<()> {
 
   let tmp_dir = tempfile::TempDir::new()?;
   let mut w_run = Vec::new();
-  match runner::compile_and_run_file(
+  match runner::compile_and_run_file_with(
+    &snake::Compiler::new(),
+    &test_limits(),
     &Path::new(&format!("examples/{}", f)),
     tmp_dir.path(),
     &mut w_run,
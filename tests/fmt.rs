@@ -0,0 +1,251 @@
+use snake::fmt::{format_prog, DEFAULT_WIDTH};
+use snake::compile::parse_prog;
+use snake::lexer::lex;
+use snake::runner::format_source;
+use snake::syntax::SurfProg;
+
+fn parse(source: &str) -> SurfProg<()> {
+  match parse_prog(source) {
+    Ok(prog) => prog.map_ann(&mut |_| ()),
+    Err(e) => panic!("formatted program doesn't parse: {}\n{}", e, source),
+  }
+}
+
+fn fmt(source: &str, width: usize) -> String {
+  format_prog(&parse(source), width)
+}
+
+fn example_files(dir: &std::path::Path, files: &mut Vec<std::path::PathBuf>) {
+  for entry in std::fs::read_dir(dir).unwrap() {
+    let path = entry.unwrap().path();
+    if path.is_dir() {
+      example_files(&path, files);
+    } else {
+      files.push(path);
+    }
+  }
+}
+
+#[test]
+fn fmt_round_trips_examples() {
+  let mut files = vec![];
+  example_files(std::path::Path::new("examples"), &mut files);
+  let mut checked = 0;
+  for path in files {
+    let source = std::fs::read_to_string(&path).unwrap();
+    let prog = match parse_prog(&source) {
+      Ok(prog) => prog.map_ann(&mut |_| ()),
+      Err(_) => continue,
+    };
+    for width in [DEFAULT_WIDTH, 30, 1] {
+      let formatted = format_prog(&prog, width);
+      assert_eq!(parse(&formatted), prog, "{} at width {}", path.display(), width);
+      assert_eq!(fmt(&formatted, width), formatted, "{} at width {}", path.display(), width);
+    }
+    let formatted = format_source(&source).unwrap();
+    assert_eq!(parse(&formatted), prog, "{}", path.display());
+    let comments = |source: &str| {
+      let (_, comments) = lex(source).unwrap();
+      comments.into_iter().map(|c| c.text).collect::<Vec<String>>()
+    };
+    assert_eq!(comments(&formatted), comments(&source), "{}", path.display());
+    checked += 1;
+  }
+  assert!(checked > 100);
+}
+
+#[test]
+fn fmt_parenthesizes_by_precedence() {
+  let cases = [
+    ("(1 + 2) * 3", "(1 + 2) * 3\n"),
+    ("1 + (2 * 3)", "1 + 2 * 3\n"),
+    ("1 - (2 - 3)", "1 - (2 - 3)\n"),
+    ("(1 - 2) - 3", "1 - 2 - 3\n"),
+    ("(true && false) || true", "(true && false) || true\n"),
+    ("true && (false || true)", "true && false || true\n"),
+    ("!(!true)", "!(!true)\n"),
+    ("!(1 < 2)", "!(1 < 2)\n"),
+    ("(1 < 2) == true", "1 < 2 == true\n"),
+    ("a[0] := (b[1])", "a[0] := (b[1])\n"),
+    ("a[0] := (1 + 2)", "a[0] := (1 + 2)\n"),
+    ("(a[0])(1)", "(a[0])(1)\n"),
+    ("(if true: 1 else: 2) + 1", "(if true: 1 else: 2) + 1\n"),
+    ("(print(1); 2) * 3", "(print(1); 2) * 3\n"),
+    ("(print(1); 2); 3", "(print(1); 2); 3\n"),
+    ("x - -5", "x - -5\n"),
+  ];
+  for (source, expected) in cases {
+    assert_eq!(fmt(source, DEFAULT_WIDTH), expected, "{}", source);
+  }
+}
+
+#[test]
+fn fmt_fits_the_width() {
+  let source = "def f(x, y): let a = x + y, b = x * y in if a < b: [a, b] else: [b, a] in f(1, 2)";
+  assert_eq!(fmt(source, 100), format!("{}\n", source));
+  assert_eq!(
+    fmt(source, 40),
+    "\
+def f(x, y):
+  let a = x + y, b = x * y in
+  if a < b: [a, b] else: [b, a]
+in
+f(1, 2)
+"
+  );
+  assert_eq!(
+    fmt(source, 20),
+    "\
+def f(x, y):
+  let a = x + y,
+      b = x * y in
+  if a < b:
+    [a, b]
+  else:
+    [b, a]
+in
+f(1, 2)
+"
+  );
+}
+
+#[test]
+fn fmt_lays_out_types_and_matches() {
+  let source = "type Some(v), None in def g(o): match o default 0: case Some(v) => v + 1, case None => 0, case Num(n) => n end in g(Some(lambda x, y: x end))";
+  assert_eq!(
+    fmt(source, 40),
+    "\
+type Some(v), None in
+def g(o):
+  match o default 0:
+    case Some(v) => v + 1,
+    case None => 0,
+    case Num(n) => n
+  end
+in
+g(Some(lambda x, y: x end))
+"
+  );
+}
+
+#[test]
+fn fmt_lays_out_fields() {
+  let source = "type P(x, y) in match (P(1, 2) with x = 3).x default 0: case P{y, x = a} => (a with y = 1).y end";
+  assert_eq!(
+    fmt(source, 40),
+    "\
+type P(x, y) in
+match (P(1, 2) with x = 3).x default 0:
+  case P{y, x = a} => (a with y = 1).y
+end
+"
+  );
+}
+
+#[test]
+fn fmt_lays_out_loops() {
+  let source = "let a = [0] in while a[0] < 3: a[0] := (a[0] + 1) end; for i = 1 to a[0]: print(i * 100) end";
+  assert_eq!(
+    fmt(source, 30),
+    "\
+let a = [0] in
+while a[0] < 3:
+  a[0] := (a[0] + 1)
+end;
+for i = 1 to a[0]:
+  print(i * 100)
+end
+"
+  );
+}
+
+#[test]
+fn fmt_lays_out_exceptions() {
+  let source = "try f(raise (1 + 2)) with case Num(n) => n * 100, case IndexOutOfBounds(i) => raise (!true) end";
+  assert_eq!(
+    fmt(source, 30),
+    "\
+try
+  f(raise (1 + 2))
+with
+  case Num(n) => n * 100,
+  case IndexOutOfBounds(i) =>
+    raise (!true)
+end
+"
+  );
+}
+
+#[test]
+fn fmt_lays_out_variables() {
+  assert_eq!(
+    format_source("var x = 1,y = 2 in x := x + y; print((x := 3) * 2); (x := 1)").unwrap(),
+    "var x = 1, y = 2 in x := x + y; print((x := 3) * 2); x := 1\n"
+  );
+}
+
+#[test]
+fn fmt_keeps_number_literals_as_written() {
+  assert_eq!(
+    format_source("[0xFF,0b1010,  1_000, -0x10,+5]").unwrap(),
+    "[0xFF, 0b1010, 1_000, -0x10, +5]\n"
+  );
+}
+
+#[test]
+fn fmt_puts_imports_on_their_own_lines() {
+  assert_eq!(
+    format_source("import \"lists.garter\"   import \"option.garter\" head(Empty)").unwrap(),
+    "import \"lists.garter\"\nimport \"option.garter\"\nhead(Empty)\n"
+  );
+}
+
+#[test]
+fn fmt_reports_parse_errors() {
+  assert!(format_source("let x = in 1").is_err());
+  assert_eq!(format_source("let   x=1 in\n\n  x").unwrap(), "let x = 1 in x\n");
+}
+
+#[test]
+fn fmt_keeps_comments() {
+  let source = "\
+# leading
+## Doubles `x`.
+##
+## Really.
+def f(x): #| inline |# x * 2 # trailing
+in
+let a = 1,
+  # about b
+  b = 2 in
+f(a + b)
+#| the
+   end |#
+";
+  let expected = "\
+# leading
+## Doubles `x`.
+##
+## Really.
+def f(x): #| inline |# x * 2
+# trailing
+in
+let a = 1,
+    # about b
+    b = 2 in
+f(a + b)
+#| the
+   end |#
+";
+  assert_eq!(format_source(source).unwrap(), expected);
+  assert_eq!(format_source(expected).unwrap(), expected);
+}
+
+#[test]
+fn fmt_keeps_doc_comments_apart_from_comments() {
+  // the `##` comment is before a parenthesis, so it doesn't document `f`
+  let source = "## not a doc\n(def f(x): x in f(1))";
+  let formatted = format_source(source).unwrap();
+  assert_eq!(formatted, "## not a doc\n#\ndef f(x): x in f(1)\n");
+  assert_eq!(parse(&formatted), parse(source));
+}
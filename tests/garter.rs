@@ -24,7 +24,7 @@ mk_fail_test!(
 mk_fail_test!(
   garter_err_undefined_type,
   "garter/compile_errs/err_undefined_type.garter",
-  "Use of undefined type None"
+  "Use of undefined type Nothing"
 );
 mk_fail_test!(
   garter_err_wrong_type_arity,
@@ -161,3 +161,322 @@ mk_test!(
   "garter/non-trivial_tests/equality_single_var_assign.garter",
   "false"
 );
+
+// Imports
+mk_test!(
+  garter_modules_main,
+  "garter/modules/main.garter",
+  "Some(10)
+10"
+);
+
+mk_test!(garter_modules_lists, "garter/modules/lists.garter", "10");
+
+mk_test!(garter_modules_option, "garter/modules/option.garter", "Some(3)");
+
+mk_test!(garter_modules_squares, "garter/modules/squares.garter", "101");
+
+mk_fail_test!(
+  garter_modules_err_unknown_import,
+  "garter/modules/err_unknown_import.garter",
+  "Cannot find the imported file \"missing.garter\" at line 1"
+);
+
+mk_fail_test!(
+  garter_modules_err_import_cycle,
+  "garter/modules/err_import_cycle.garter",
+  "leads back to the importing file"
+);
+
+mk_fail_test!(
+  garter_modules_err_in_import,
+  "garter/modules/err_in_import.garter",
+  "Unbound variable y at line 2, column 6 to line 2, column 7 of examples/garter/modules/bad_lib.garter"
+);
+
+// Prelude
+mk_test!(
+  garter_prelude_lists,
+  "garter/prelude/lists.garter",
+  "[1, [4, [9, [16, [25, []]]]]]
+[9, [16, [25, []]]]
+[3, [2, [1, []]]]
+[1, [2, [3, []]]]
+5
+79"
+);
+
+mk_test!(
+  garter_prelude_option,
+  "garter/prelude/option.garter",
+  "Some(13)
+None
+Some(16)
+-1
+5"
+);
+
+mk_test!(
+  garter_prelude_sieve,
+  "garter/prelude/sieve.garter",
+  "[2, [3, [5, [7, [11, [13, [17, [19, [23, [29, []]]]]]]]]]]"
+);
+
+mk_test!(garter_prelude_shadowed, "garter/prelude/shadowed.garter", "42\nNone");
+
+// Loops
+mk_test!(garter_loops_while_sum, "garter/loops/while_sum.garter", "10");
+
+mk_test!(garter_loops_for_print, "garter/loops/for_print.garter", "1\n2\n3\nfalse");
+
+mk_test!(garter_loops_for_closures, "garter/loops/for_closures.garter", "[0, 20]");
+
+mk_test!(garter_loops_for_nested, "garter/loops/for_nested.garter", "20");
+
+mk_fail_test!(
+  garter_loops_err_while_cond,
+  "garter/loops/err_while_cond.garter",
+  "while expected a boolean, but got 1"
+);
+
+mk_fail_test!(
+  garter_loops_err_for_bound,
+  "garter/loops/err_for_bound.garter",
+  "for expected a number, but got true"
+);
+
+// Variables
+mk_test!(garter_vars_counter, "garter/vars/counter.garter", "15");
+
+mk_test!(garter_vars_captured, "garter/vars/captured.garter", "12");
+
+mk_test!(garter_vars_shared, "garter/vars/shared.garter", "[2, 2, 3, 3]");
+
+mk_test!(garter_vars_assign_value, "garter/vars/assign_value.garter", "6\n10\n10");
+
+mk_fail_test!(
+  garter_vars_err_assign_let,
+  "garter/vars/err_assign_let.garter",
+  "Cannot assign to x, which isn't declared with var"
+);
+
+mk_fail_test!(
+  garter_vars_err_assign_unbound,
+  "garter/vars/err_assign_unbound.garter",
+  "Unbound variable y"
+);
+
+// Exceptions
+mk_test!(garter_exceptions_raise_custom, "garter/exceptions/raise_custom.garter", "[2, 99]");
+
+mk_test!(
+  garter_exceptions_index_out_of_bounds,
+  "garter/exceptions/index_out_of_bounds.garter",
+  "[2, 0, 42]"
+);
+
+mk_test!(garter_exceptions_wrong_arity, "garter/exceptions/wrong_arity.garter", "[2, 1]");
+
+mk_test!(garter_exceptions_reraise, "garter/exceptions/reraise.garter", "300");
+
+mk_test!(garter_exceptions_nested, "garter/exceptions/nested.garter", "1\ntrue\n5");
+
+mk_test!(
+  garter_exceptions_nested_in_arm,
+  "garter/exceptions/nested_in_arm.garter",
+  "[7, 5]"
+);
+
+mk_fail_test!(
+  garter_exceptions_err_uncaught,
+  "garter/exceptions/err_uncaught.garter",
+  "uncaught exception 7"
+);
+
+mk_fail_test!(
+  garter_exceptions_err_uncaught_error,
+  "garter/exceptions/err_uncaught_error.garter",
+  "uncaught exception IfError(1)"
+);
+
+mk_fail_test!(
+  garter_exceptions_err_undefined_type,
+  "garter/exceptions/err_undefined_type.garter",
+  "Use of undefined type Oops"
+);
+
+// Continuations
+mk_test!(garter_callcc_early_exit, "garter/callcc/early_exit.garter", "[2, -1]");
+
+mk_test!(garter_callcc_reenter, "garter/callcc/reenter.garter", "0\n1\n2\n3\n4");
+
+mk_test!(garter_callcc_escape_try, "garter/callcc/escape_try.garter", "10");
+
+mk_fail_test!(
+  garter_callcc_err_not_function,
+  "garter/callcc/err_not_function.garter",
+  "called a non-function 5"
+);
+
+mk_fail_test!(
+  garter_callcc_err_arity,
+  "garter/callcc/err_arity.garter",
+  "wrong number of arguments: 0 expected, 1 given"
+);
+
+// Generators
+mk_test!(garter_generators_range, "garter/generators/range.garter", "5000050000");
+
+mk_test!(
+  garter_generators_interleave,
+  "garter/generators/interleave.garter",
+  "[0, 1, 2, 3, 4, 5]"
+);
+
+mk_test!(
+  garter_generators_raise_through,
+  "garter/generators/raise_through.garter",
+  "[1, 20, 3]"
+);
+
+mk_fail_test!(
+  garter_generators_err_finished,
+  "garter/generators/err_finished.garter",
+  "called a generator that is finished or running"
+);
+
+mk_fail_test!(
+  garter_generators_err_yield_outside,
+  "garter/generators/err_yield_outside.garter",
+  "yield outside of a function"
+);
+
+mk_test!(
+  garter_generators_deep_recursion,
+  "garter/generators/deep_recursion.garter",
+  "7000"
+);
+
+mk_fail_test!(
+  garter_generators_err_overflow,
+  "garter/generators/err_overflow.garter",
+  "stack overflow"
+);
+
+mk_test!(
+  garter_generators_many_finished,
+  "garter/generators/many_finished.garter",
+  "612517500"
+);
+
+// Threads
+mk_test!(
+  garter_threads_ping_pong,
+  "garter/threads/ping_pong.garter",
+  "[1, 10, 2, 20, 3, 30]"
+);
+
+mk_test!(garter_threads_workers, "garter/threads/workers.garter", "30");
+
+mk_test!(garter_threads_handoff, "garter/threads/handoff.garter", "[3, 3]");
+
+mk_test!(
+  garter_threads_catch_in_thread,
+  "garter/threads/catch_in_thread.garter",
+  "8"
+);
+
+mk_fail_test!(
+  garter_threads_err_not_channel,
+  "garter/threads/err_not_channel.garter",
+  "expected a channel, but got true"
+);
+
+mk_fail_test!(
+  garter_threads_err_number_not_channel,
+  "garter/threads/err_number_not_channel.garter",
+  "expected a channel, but got 0"
+);
+
+mk_test!(
+  garter_threads_channel_value,
+  "garter/threads/channel_value.garter",
+  "[false, false, false, true]"
+);
+
+mk_fail_test!(
+  garter_threads_err_deadlock,
+  "garter/threads/err_deadlock.garter",
+  "deadlock: every thread is waiting to receive from a channel"
+);
+
+mk_fail_test!(
+  garter_threads_err_spawn_arity,
+  "garter/threads/err_spawn_arity.garter",
+  "wrong number of arguments: 1 expected, 0 given"
+);
+
+mk_fail_test!(
+  garter_threads_raise_in_thread,
+  "garter/threads/raise_in_thread.garter",
+  "uncaught exception 5"
+);
+
+mk_test!(
+  garter_threads_deep_recursion,
+  "garter/threads/deep_recursion.garter",
+  "7000"
+);
+
+mk_fail_test!(
+  garter_threads_err_overflow,
+  "garter/threads/err_overflow.garter",
+  "stack overflow"
+);
+
+mk_test!(
+  garter_threads_many_finished,
+  "garter/threads/many_finished.garter",
+  "612517500"
+);
+
+// Fields
+mk_test!(garter_fields_access, "garter/fields/access.garter", "[3, 4, 10]");
+
+mk_test!(garter_fields_update, "garter/fields/update.garter", "[1, 2, 3, 20]");
+
+mk_test!(
+  garter_fields_match_by_name,
+  "garter/fields/match_by_name.garter",
+  "[12, 20, 0]"
+);
+
+mk_test!(
+  garter_fields_catch_field_error,
+  "garter/fields/catch_field_error.garter",
+  "[1, 0, 5]"
+);
+
+mk_fail_test!(
+  garter_fields_err_no_field,
+  "garter/fields/err_no_field.garter",
+  "field access expected a value of a type with the field, but got Size(1, 2)"
+);
+
+mk_fail_test!(
+  garter_fields_err_undefined_field,
+  "garter/fields/err_undefined_field.garter",
+  "No type in scope has a field named z"
+);
+
+mk_fail_test!(
+  garter_fields_err_field_not_in_type,
+  "garter/fields/err_field_not_in_type.garter",
+  "Match arm binds field z that its type doesn't have"
+);
+
+mk_fail_test!(
+  garter_fields_err_dup_field,
+  "garter/fields/err_dup_field.garter",
+  "Field x declared repeatedly in one type"
+);
@@ -56,6 +56,14 @@ mk_fail_test!(
 /* Error produced by interpreter:
 Error in interpreter: arithmetic expected a number, but got true in +
 */
+mk_fail_test!(
+  cobra_err_literal_out_of_range,
+  "cobra/err_literal_out_of_range.cobra",
+  "Number literal 0x1_0000_0000_0000_0000 is out of the range"
+);
+/* Error produced by interpreter:
+Error parsing input: Number literal 0x1_0000_0000_0000_0000 is out of the range -4611686018427387904 to 4611686018427387903 at line 1, column 10 to line 1, column 33
+*/
 mk_fail_test!(
   cobra_err_logic_type,
   "cobra/err_logic_type.cobra",
@@ -75,10 +83,10 @@ Error in interpreter: Operation -2305843009213693952 * 3 = -6917529027641081856
 mk_fail_test!(
   cobra_err_overflow,
   "cobra/err_overflow.cobra",
-  "Number literal 4611686018427387904 doesn't fit into 63-bit integer at"
+  "Number literal 4611686018427387904 is out of the range -4611686018427387904 to 4611686018427387903 at line 1, column 0 to line 1, column 19"
 );
 /* Error produced by interpreter:
-Error generating assembly: Number literal 4611686018427387904 doesn't fit into 63-bit integer at line 1, column 0 to line 1, column 19
+Error parsing input: Number literal 4611686018427387904 is out of the range -4611686018427387904 to 4611686018427387903 at line 1, column 0 to line 1, column 19
 */
 mk_fail_test!(
   cobra_logic_error_short_circuit,
@@ -263,6 +271,15 @@ mk_test!(
   "cobra/no_overflow.cobra",
   "4611686018427387903"
 );
+mk_test!(
+  cobra_number_literals,
+  "cobra/number_literals.cobra",
+  "42
+170
+1000000
+-16
+4611686018427387903"
+);
 mk_test!(
   cobra_print_bool,
   "cobra/print_bool.cobra",
@@ -702,6 +719,8 @@ mk_test!(
 2
 4"
 );
+mk_test!(egg_arr_accumulate, "egg/arr_accumulate.egg", "[6, 1]");
+mk_test!(egg_arr_count_up, "egg/arr_count_up.egg", "[499]");
 mk_test!(egg_arr_mutate, "egg/arr_mutate.egg", "[9, 2, 3]");
 mk_test!(
   egg_arr_semantics,
@@ -710,6 +729,7 @@ mk_test!(
 [1, 4]"
 );
 mk_test!(egg_capturing_lambda, "egg/capturing_lambda.egg", "3");
+mk_test!(egg_comments, "egg/comments.egg", "6");
 mk_test!(
   egg_interesting,
   "egg/interesting.egg",
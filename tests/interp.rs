@@ -0,0 +1,358 @@
+use snake::interp::{self, Config, InterpErr};
+use snake::profiler::{self, Profile, Row, MAIN};
+use snake::runner::profile_file;
+use snake::Compiler;
+use std::path::Path;
+
+fn interp_with(src: &str, config: &Config) -> Result<String, InterpErr> {
+  let prog = Compiler::new().parse(src).unwrap();
+  let mut w = Vec::new();
+  interp::prog(&prog, config, &mut w)?;
+  Ok(String::from_utf8(w).unwrap())
+}
+
+#[test]
+fn interp_without_limits() {
+  assert_eq!(
+    interp_with("def f(x): x + 1 in f(41)", &Config::new()).unwrap(),
+    "42\n"
+  );
+}
+
+#[test]
+fn interp_runs_out_of_fuel() {
+  let src = "def spin(n): spin(n + 1) in spin(0)";
+  match interp_with(src, &Config::new().max_steps(1000)) {
+    Err(InterpErr::OutOfFuel { steps }) => assert_eq!(steps, 1000),
+    other => panic!("expected to run out of fuel, got {:?}", other),
+  }
+}
+
+#[test]
+fn interp_runs_out_of_memory() {
+  let src = "def grow(n): let a = [n, n, n] in grow(n + 1) in grow(0)";
+  match interp_with(src, &Config::new().max_heap_cells(100)) {
+    Err(InterpErr::OutOfMemory { max_cells }) => assert_eq!(max_cells, 100),
+    other => panic!("expected to run out of memory, got {:?}", other),
+  }
+}
+
+#[test]
+fn interp_runs_out_of_memory_for_vars() {
+  let src = "var s = 0 in while true: var x = 1 in s := x end";
+  match interp_with(src, &Config::new().max_heap_cells(1000)) {
+    Err(InterpErr::OutOfMemory { max_cells }) => assert_eq!(max_cells, 1000),
+    other => panic!("expected to run out of memory, got {:?}", other),
+  }
+}
+
+#[test]
+fn interp_runs_out_of_stack() {
+  let src = "def f(n): [n, f(n + 1)] in f(0)";
+  match interp_with(src, &Config::new().max_stack_depth(1000)) {
+    Err(InterpErr::OutOfStack { max_depth }) => assert_eq!(max_depth, 1000),
+    other => panic!("expected to run out of stack, got {:?}", other),
+  }
+}
+
+// Stopping drops the whole continuation, which must not overflow the native
+// stack however deep the program recursed
+#[test]
+fn interp_stops_deep_recursion_at_limits() {
+  let src = "def f(n): if n == 0: 0 else: 1 + f(n - 1) in f(1000000)";
+  match interp_with(src, &Config::new().max_stack_depth(200000)) {
+    Err(InterpErr::OutOfStack { max_depth }) => assert_eq!(max_depth, 200000),
+    other => panic!("expected to run out of stack, got {:?}", other),
+  }
+  match interp_with(src, &Config::new().max_steps(2000000)) {
+    Err(InterpErr::OutOfFuel { steps }) => assert_eq!(steps, 2000000),
+    other => panic!("expected to run out of fuel, got {:?}", other),
+  }
+}
+
+#[test]
+fn interp_within_limits() {
+  let src = "let a = [1, 2, 3] in a[1]";
+  let config = Config::new().max_steps(1000).max_heap_cells(4);
+  assert_eq!(interp_with(src, &config).unwrap(), "2\n");
+}
+
+#[test]
+fn interp_shares_variables_with_closures() {
+  let src = "var n = 0 in let incr = lambda: n := n + 1 end in incr(); incr(); n";
+  assert_eq!(interp_with(src, &Config::new()).unwrap(), "2\n");
+}
+
+#[test]
+fn interp_catches_raised_values_and_errors() {
+  let src = "def f(n): if n > 2: raise n else: [0][n] in
+    [try f(5) with case Num(n) => n end, try f(1) with case IndexOutOfBounds(i) => i * 10 end]";
+  assert_eq!(interp_with(src, &Config::new()).unwrap(), "[5, 10]\n");
+  match interp_with("try raise [1] with case Num(n) => n end", &Config::new()) {
+    Err(e @ InterpErr::Uncaught { .. }) => assert_eq!(e.to_string(), "Uncaught exception [1]"),
+    other => panic!("expected an uncaught exception, got {:?}", other),
+  }
+}
+
+#[test]
+fn interp_calls_continuations() {
+  let src = "let k = callcc(lambda k: k end) in if isfun(k): k(5) else: k * 2";
+  assert_eq!(interp_with(src, &Config::new()).unwrap(), "10\n");
+  match interp_with("callcc(lambda k: k(1, 2) end)", &Config::new()) {
+    Err(InterpErr::ArityErr {
+      expected_arity,
+      num_provided,
+    }) => assert_eq!((expected_arity, num_provided), (1, 2)),
+    other => panic!("expected an arity error, got {:?}", other),
+  }
+}
+
+#[test]
+fn interp_resumes_generators() {
+  let src = "def gen(n): try yield(n); raise n with case Num(m) => yield(m + 1) end in
+    let g = gen(1) in [g(), g(), try g() with case GeneratorDone => 0 end]";
+  assert_eq!(interp_with(src, &Config::new()).unwrap(), "[1, 2, 0]\n");
+  match interp_with("let g = (lambda: yield(1) end)() in g(); g()", &Config::new()) {
+    Err(InterpErr::GeneratorDone) => (),
+    other => panic!("expected a finished generator, got {:?}", other),
+  }
+}
+
+#[test]
+fn interp_schedules_threads_by_seed() {
+  let src = "let c = chan() in
+    spawn(lambda: send(c, 1) end); spawn(lambda: send(c, 2) end); spawn(lambda: send(c, 3) end);
+    let a = recv(c), b = recv(c) in [a, b, recv(c)]";
+  assert_eq!(interp_with(src, &Config::new()).unwrap(), "[2, 1, 3]\n");
+  assert_eq!(interp_with(src, &Config::new().seed(1)).unwrap(), "[3, 2, 1]\n");
+  assert_eq!(interp_with(src, &Config::new().seed(3)).unwrap(), "[1, 3, 2]\n");
+  match interp_with("let c = chan() in spawn(lambda: recv(c) end); recv(c)", &Config::new()) {
+    Err(InterpErr::Deadlock) => (),
+    other => panic!("expected a deadlock, got {:?}", other),
+  }
+}
+
+#[test]
+fn interp_keeps_channels_apart_from_numbers() {
+  let src = "let c = chan() in [isnum(c), print(c) == c]";
+  assert_eq!(interp_with(src, &Config::new()).unwrap(), "<channel>\n[false, true]\n");
+  match interp_with("let c = chan() in send(0, 1)", &Config::new()) {
+    Err(InterpErr::NotChannel {
+      got: interp::SnakeVal::Num(0),
+    }) => (),
+    other => panic!("expected a number that isn't a channel, got {:?}", other),
+  }
+}
+
+#[test]
+fn interp_reads_and_updates_fields_by_name() {
+  let src = "type P(x, y) in let p = P(1, 2) with y = 5 in
+    [p.x, match p default 0: case P{y, x = a} => y * 10 + a end, try true.x with case FieldError(v) => v end]";
+  assert_eq!(interp_with(src, &Config::new()).unwrap(), "[1, 51, true]\n");
+  match interp_with("type P(x), Q(y) in Q(1).x", &Config::new()) {
+    Err(InterpErr::NoField { printed, .. }) => assert_eq!(printed, "Q(1)"),
+    other => panic!("expected a missing field, got {:?}", other),
+  }
+}
+
+#[test]
+fn interp_runs_imported_functions() {
+  let mut out = Vec::new();
+  snake::runner::interpret_file(
+    Path::new("examples/garter/modules/squares.garter"),
+    &Compiler::new(),
+    &Config::new(),
+    &mut out,
+  )
+  .unwrap();
+  assert_eq!(String::from_utf8(out).unwrap(), "101\n");
+}
+
+#[test]
+fn interp_finds_imports_in_search_dirs() {
+  let tmp_dir = tempfile::TempDir::new().unwrap();
+  let main = tmp_dir.path().join("main.garter");
+  std::fs::write(&main, "import \"numbers.garter\"\ntwice(square, 3)").unwrap();
+  let mut out = Vec::new();
+  snake::runner::interpret_file(
+    &main,
+    &Compiler::new().search_dir(Path::new("examples/garter/modules")),
+    &Config::new(),
+    &mut out,
+  )
+  .unwrap();
+  assert_eq!(String::from_utf8(out).unwrap(), "81\n");
+}
+
+#[test]
+fn interp_runs_prelude_functions() {
+  let mut out = Vec::new();
+  snake::runner::interpret_file(
+    Path::new("examples/garter/prelude/sieve.garter"),
+    &Compiler::new(),
+    &Config::new(),
+    &mut out,
+  )
+  .unwrap();
+  assert_eq!(
+    String::from_utf8(out).unwrap(),
+    "[2, [3, [5, [7, [11, [13, [17, [19, [23, [29, []]]]]]]]]]]\n"
+  );
+}
+
+fn trace_example(f: &str, only: &[&str]) -> (String, String) {
+  let only: Vec<String> = only.iter().map(|s| s.to_string()).collect();
+  let mut out = Vec::new();
+  let mut log = Vec::new();
+  snake::runner::trace_file(
+    std::path::Path::new(&format!("examples/{}", f)),
+    &Compiler::new(),
+    &Config::new(),
+    &only,
+    &mut out,
+    &mut log,
+  )
+  .unwrap();
+  (
+    String::from_utf8(out).unwrap(),
+    String::from_utf8(log).unwrap(),
+  )
+}
+
+#[test]
+fn trace_calls_and_returns() {
+  let (out, log) = trace_example("diamond/mutual_recursion.diamond", &[]);
+  assert_eq!(out, "false\n");
+  assert_eq!(
+    log,
+    "\
+-> isEven(5)
+  -> isOdd(4)
+    -> isEven(3)
+      -> isOdd(2)
+        -> isEven(1)
+        <- isEven(1) = false
+      <- isOdd(2) = false
+    <- isEven(3) = false
+  <- isOdd(4) = false
+<- isEven(5) = false
+"
+  );
+}
+
+#[test]
+fn trace_only_named_functions() {
+  let (_, log) = trace_example("diamond/mutual_recursion.diamond", &["isOdd"]);
+  assert_eq!(
+    log,
+    "\
+-> isOdd(4)
+  -> isOdd(2)
+  <- isOdd(2) = false
+<- isOdd(4) = false
+"
+  );
+}
+
+#[test]
+fn trace_non_tail_calls() {
+  let (_, log) = trace_example("diamond/fib.diamond", &[]);
+  assert!(log.starts_with("-> fib(10)\n  -> fib(9)\n"));
+  assert!(log.contains("                  -> fib(1)\n                  <- fib(1) = 1\n"));
+  assert!(log.ends_with("  <- fib(8) = 34\n<- fib(10) = 89\n"));
+}
+
+fn profile(src: &str) -> Profile {
+  let prog = Compiler::new().parse(src).unwrap();
+  profiler::profile(&prog, src, &Config::new(), &mut Vec::new()).unwrap()
+}
+
+fn row<'p>(profile: &'p Profile, name: &str) -> &'p Row {
+  profile
+    .rows
+    .iter()
+    .find(|r| r.name == name)
+    .unwrap_or_else(|| panic!("no row for {} in\n{}", name, profile.table()))
+}
+
+#[test]
+fn profile_counts_calls_and_steps() {
+  let profile = profile_file(
+    Path::new("examples/diamond/fib.diamond"),
+    &Compiler::new(),
+    &Config::new(),
+    &mut Vec::new(),
+  )
+  .unwrap();
+  let main = row(&profile, MAIN);
+  let fib = row(&profile, "fib");
+  assert_eq!(profile.rows[0], *main);
+  assert_eq!(main.calls, 1);
+  assert_eq!(fib.calls, 177);
+  // fib runs everything but the top-level call
+  assert_eq!(fib.inclusive_steps, fib.exclusive_steps);
+  assert_eq!(main.inclusive_steps, main.exclusive_steps + fib.exclusive_steps);
+}
+
+#[test]
+fn profile_counts_allocations() {
+  let profile = profile(
+    "\
+def pair(x): [x, x] in
+def pairs(n): if n == 0: [] else: [pair(n), pairs(n - 1)] in
+pairs(3)",
+  );
+  assert_eq!(row(&profile, "pair").allocations, 3);
+  assert_eq!(row(&profile, "pairs").allocations, 4);
+  assert_eq!(row(&profile, MAIN).allocations, 0);
+}
+
+#[test]
+fn profile_leaves_out_unused_prelude_values() {
+  let profile = profile_file(
+    Path::new("examples/diamond/fib.diamond"),
+    &Compiler::new(),
+    &Config::new(),
+    &mut Vec::new(),
+  )
+  .unwrap();
+  assert_eq!(row(&profile, MAIN).allocations, 0);
+}
+
+#[test]
+fn profile_tells_apart_functions_by_position() {
+  let two_lines = profile(
+    "\
+let f = lambda x: x + 1 end in
+let g = lambda x: f(x) * 2 end in
+g(1)",
+  );
+  assert_eq!(row(&two_lines, "lambda:1:18").calls, 1);
+  assert_eq!(row(&two_lines, "lambda:2:18").calls, 1);
+
+  // two lambdas on one line
+  let one_line = profile("let f = lambda x: x + 1 end, g = lambda x: f(x) * 2 end in g(1) + g(2)");
+  assert_eq!(row(&one_line, "lambda:1:18").calls, 2);
+  assert_eq!(row(&one_line, "lambda:1:43").calls, 2);
+}
+
+#[test]
+fn profile_folded_stacks() {
+  let profile = profile(
+    "\
+def sq(x): x * x in
+def sum_sq(a, b): sq(a) + sq(b) in
+sum_sq(1, 2)",
+  );
+  let folded = profile.folded();
+  let stacks: Vec<&str> = folded
+    .lines()
+    .map(|l| l.rsplit_once(' ').unwrap().0)
+    .collect();
+  assert_eq!(stacks, vec![MAIN, "<main>;sum_sq", "<main>;sum_sq;sq"]);
+  let total: u64 = folded
+    .lines()
+    .map(|l| l.rsplit_once(' ').unwrap().1.parse::<u64>().unwrap())
+    .sum();
+  assert_eq!(total, row(&profile, MAIN).inclusive_steps);
+}
@@ -0,0 +1,301 @@
+use serde_json::{json, Value};
+use snake::lsp::serve;
+
+static URI: &str = "file:///test.garter";
+
+// Runs the server on `messages`, returning everything it sends back
+fn lsp_session(messages: &[Value]) -> Vec<Value> {
+  let mut input = String::new();
+  for message in messages {
+    let content = message.to_string();
+    input.push_str(&format!("Content-Length: {}\r\n\r\n{}", content.len(), content));
+  }
+  let mut out = Vec::new();
+  serve(input.as_bytes(), &mut out).unwrap();
+  let mut out = String::from_utf8(out).unwrap();
+  let mut replies = vec![];
+  while !out.is_empty() {
+    let (header, rest) = out.split_once("\r\n\r\n").unwrap();
+    let length: usize = header.strip_prefix("Content-Length: ").unwrap().parse().unwrap();
+    replies.push(serde_json::from_str(&rest[..length]).unwrap());
+    out = rest[length..].to_string();
+  }
+  replies
+}
+
+fn open(text: &str) -> Value {
+  json!({
+    "jsonrpc": "2.0",
+    "method": "textDocument/didOpen",
+    "params": { "textDocument": { "uri": URI, "languageId": "snake", "version": 1, "text": text } },
+  })
+}
+
+fn request(id: u64, method: &str, line: u64, character: u64) -> Value {
+  json!({
+    "jsonrpc": "2.0",
+    "id": id,
+    "method": method,
+    "params": {
+      "textDocument": { "uri": URI },
+      "position": { "line": line, "character": character },
+      "context": { "includeDeclaration": true },
+    },
+  })
+}
+
+fn range(start: (u64, u64), end: (u64, u64)) -> Value {
+  json!({
+    "start": { "line": start.0, "character": start.1 },
+    "end": { "line": end.0, "character": end.1 },
+  })
+}
+
+// Runs a request about the document `text`, returning its result
+fn query(text: &str, method: &str, line: u64, character: u64) -> Value {
+  let replies = lsp_session(&[open(text), request(1, method, line, character)]);
+  assert_eq!(replies.len(), 2);
+  assert_eq!(replies[1]["id"], 1);
+  replies[1]["result"].clone()
+}
+
+static PROGRAM: &str = "\
+def add(x, y):
+  let sum = x + y in
+  sum
+in
+type Some(val), None in
+let f = lambda n: add(n, 1) end in
+match Some(f(1)) default 0:
+  case Some(v) => v,
+  case None => 0
+end
+";
+
+#[test]
+fn lsp_initialize_and_exit() {
+  let replies = lsp_session(&[
+    json!({ "jsonrpc": "2.0", "id": 1, "method": "initialize", "params": { "capabilities": {} } }),
+    json!({ "jsonrpc": "2.0", "method": "initialized", "params": {} }),
+    json!({ "jsonrpc": "2.0", "id": 2, "method": "shutdown" }),
+    json!({ "jsonrpc": "2.0", "method": "exit" }),
+    json!({ "jsonrpc": "2.0", "id": 3, "method": "shutdown" }),
+  ]);
+  assert_eq!(replies.len(), 2);
+  let capabilities = &replies[0]["result"]["capabilities"];
+  assert_eq!(capabilities["textDocumentSync"], 1);
+  assert_eq!(capabilities["definitionProvider"], true);
+  assert_eq!(capabilities["referencesProvider"], true);
+  assert_eq!(capabilities["hoverProvider"], true);
+  assert_eq!(capabilities["documentSymbolProvider"], true);
+  assert_eq!(replies[1], json!({ "jsonrpc": "2.0", "id": 2, "result": null }));
+}
+
+#[test]
+fn lsp_unknown_method() {
+  let replies = lsp_session(&[json!({ "jsonrpc": "2.0", "id": 7, "method": "workspace/symbol", "params": {} })]);
+  assert_eq!(replies[0]["id"], 7);
+  assert_eq!(replies[0]["error"]["code"], -32601);
+}
+
+#[test]
+fn lsp_diagnostics() {
+  let change = |text: &str| {
+    json!({
+      "jsonrpc": "2.0",
+      "method": "textDocument/didChange",
+      "params": { "textDocument": { "uri": URI, "version": 2 }, "contentChanges": [{ "text": text }] },
+    })
+  };
+  let replies = lsp_session(&[
+    open("let x = 1 in\nx +"),
+    change("let x = 1 in\ny + x"),
+    change(PROGRAM),
+    json!({ "jsonrpc": "2.0", "method": "textDocument/didClose", "params": { "textDocument": { "uri": URI } } }),
+  ]);
+  assert_eq!(replies.len(), 4);
+  for reply in &replies {
+    assert_eq!(reply["method"], "textDocument/publishDiagnostics");
+    assert_eq!(reply["params"]["uri"], URI);
+  }
+  let parse_error = &replies[0]["params"]["diagnostics"][0];
+  assert_eq!(parse_error["range"]["start"], json!({ "line": 1, "character": 3 }));
+  assert!(parse_error["message"].as_str().unwrap().starts_with("Unrecognized EOF"));
+  let unbound = &replies[1]["params"]["diagnostics"];
+  assert_eq!(unbound.as_array().unwrap().len(), 1);
+  assert_eq!(unbound[0]["range"], range((1, 0), (1, 1)));
+  assert_eq!(unbound[0]["severity"], 1);
+  assert!(unbound[0]["message"].as_str().unwrap().starts_with("Unbound variable y"));
+  assert_eq!(replies[2]["params"]["diagnostics"], json!([]));
+  assert_eq!(replies[3]["params"]["diagnostics"], json!([]));
+}
+
+#[test]
+fn lsp_reports_errors_in_imported_files_at_the_import() {
+  let uri = format!("file://{}/examples/garter/modules/doc.garter", env!("CARGO_MANIFEST_DIR"));
+  let open = |text: &str| {
+    json!({
+      "jsonrpc": "2.0",
+      "method": "textDocument/didOpen",
+      "params": { "textDocument": { "uri": uri, "languageId": "snake", "version": 1, "text": text } },
+    })
+  };
+  let replies = lsp_session(&[
+    open("import \"bad_lib.garter\"\nf(1)"),
+    open("import \"numbers.garter\"\nlet n = 3 in\nsquare(n)"),
+  ]);
+  let diagnostics = &replies[0]["params"]["diagnostics"];
+  assert_eq!(diagnostics[0]["range"], range((0, 0), (0, 23)));
+  let message = diagnostics[0]["message"].as_str().unwrap();
+  assert!(message.starts_with("Unbound variable y"));
+  assert!(message.ends_with("/examples/garter/modules/bad_lib.garter"));
+  // imported names are bound, and local ones still resolved
+  assert_eq!(replies[1]["params"]["diagnostics"], json!([]));
+}
+
+#[test]
+fn lsp_definition() {
+  let location = |line, character| query(PROGRAM, "textDocument/definition", line, character);
+  // x in x + y
+  assert_eq!(location(1, 12), json!({ "uri": URI, "range": range((0, 8), (0, 9)) }));
+  // sum in the let body
+  assert_eq!(location(2, 2)["range"], range((1, 6), (1, 9)));
+  // add in the lambda body
+  assert_eq!(location(5, 19)["range"], range((0, 4), (0, 7)));
+  // n as an argument
+  assert_eq!(location(5, 23)["range"], range((5, 15), (5, 16)));
+  // the type in a match arm, and the field it binds
+  assert_eq!(location(7, 7)["range"], range((4, 5), (4, 9)));
+  assert_eq!(location(7, 18)["range"], range((7, 12), (7, 13)));
+  // from the end of a name
+  assert_eq!(location(6, 12)["range"], range((5, 4), (5, 5)));
+  // not on a name
+  assert_eq!(location(6, 0), Value::Null);
+}
+
+#[test]
+fn lsp_references() {
+  let references = query(PROGRAM, "textDocument/references", 4, 6);
+  let ranges: Vec<&Value> = references.as_array().unwrap().iter().map(|r| &r["range"]).collect();
+  assert_eq!(
+    ranges,
+    vec![
+      &range((4, 5), (4, 9)),
+      &range((6, 6), (6, 10)),
+      &range((7, 7), (7, 11)),
+    ]
+  );
+
+  let replies = lsp_session(&[
+    open("let x = 1 in\nlet y = x in\nx + y"),
+    json!({
+      "jsonrpc": "2.0",
+      "id": 1,
+      "method": "textDocument/references",
+      "params": {
+        "textDocument": { "uri": URI },
+        "position": { "line": 2, "character": 0 },
+        "context": { "includeDeclaration": false },
+      },
+    }),
+  ]);
+  assert_eq!(
+    replies[1]["result"],
+    json!([
+      { "uri": URI, "range": range((1, 8), (1, 9)) },
+      { "uri": URI, "range": range((2, 0), (2, 1)) },
+    ])
+  );
+}
+
+#[test]
+fn lsp_hover() {
+  let hover = query(PROGRAM, "textDocument/hover", 1, 16);
+  assert_eq!(
+    hover["contents"]["value"],
+    "parameter `y` of `add`, bound on line 1:\n\n```snake\ndef add(x, y):\n```"
+  );
+  assert_eq!(hover["range"], range((1, 16), (1, 17)));
+  let hover = query(PROGRAM, "textDocument/hover", 7, 18);
+  assert_eq!(
+    hover["contents"]["value"],
+    "field `v` of `Some` in a match arm, bound on line 8:\n\n```snake\ncase Some(v) => v,\n```"
+  );
+  assert_eq!(query(PROGRAM, "textDocument/hover", 3, 0), Value::Null);
+}
+
+#[test]
+fn lsp_definition_in_try_arm() {
+  let text = "try raise 1 with\n  case Num(n) => n + 1,\n  case ArithError(v) => v\nend";
+  let location = |line, character| query(text, "textDocument/definition", line, character);
+  assert_eq!(location(1, 17)["range"], range((1, 11), (1, 12)));
+  assert_eq!(location(2, 24)["range"], range((2, 18), (2, 19)));
+}
+
+#[test]
+fn lsp_hover_on_assigned_variable() {
+  let text = "var n = 0 in\nn := n + 1;\nn";
+  let hover = query(text, "textDocument/hover", 1, 0);
+  assert_eq!(
+    hover["contents"]["value"],
+    "mutable variable `n`, bound on line 1:\n\n```snake\nvar n = 0 in\n```"
+  );
+  assert_eq!(hover["range"], range((1, 0), (1, 1)));
+  let references = query(text, "textDocument/references", 0, 4);
+  assert_eq!(references.as_array().unwrap().len(), 4);
+}
+
+#[test]
+fn lsp_hover_shows_doc_comments() {
+  let text = "## Adds one\n## to `x`.\ndef inc(x): # x is a number\n  x + 1\nin\ninc(2)";
+  let hover = query(text, "textDocument/hover", 5, 0);
+  assert_eq!(
+    hover["contents"]["value"],
+    "function `inc`, bound on line 3:\n\n```snake\ndef inc(x): # x is a number\n```\n\nAdds one\nto `x`."
+  );
+  // the x in the comment isn't the parameter
+  let location = query(text, "textDocument/definition", 3, 2);
+  assert_eq!(location["range"], range((2, 8), (2, 9)));
+}
+
+#[test]
+fn lsp_document_symbols() {
+  let text = "def f(x):\n  def g(): x in g()\nin\ntype Pair(a, b), Empty in\nf(1)";
+  let symbols = query(text, "textDocument/documentSymbol", 0, 0);
+  assert_eq!(
+    symbols,
+    json!([
+      {
+        "name": "f",
+        "detail": "(x)",
+        "kind": 12,
+        "range": range((0, 0), (1, 19)),
+        "selectionRange": range((0, 4), (0, 5)),
+        "children": [{
+          "name": "g",
+          "detail": "()",
+          "kind": 12,
+          "range": range((1, 2), (1, 12)),
+          "selectionRange": range((1, 6), (1, 7)),
+          "children": [],
+        }],
+      },
+      {
+        "name": "Pair",
+        "detail": "(a, b)",
+        "kind": 23,
+        "range": range((3, 5), (3, 15)),
+        "selectionRange": range((3, 5), (3, 9)),
+        "children": [],
+      },
+      {
+        "name": "Empty",
+        "detail": "",
+        "kind": 23,
+        "range": range((3, 17), (3, 22)),
+        "selectionRange": range((3, 17), (3, 22)),
+        "children": [],
+      },
+    ])
+  );
+}
@@ -0,0 +1,106 @@
+use snake::interp::Config;
+use snake::repl::repl;
+
+fn repl_session(input: &str) -> String {
+  repl_session_with(&Config::new(), input)
+}
+
+fn repl_session_with(config: &Config, input: &str) -> String {
+  let mut out = Vec::new();
+  repl(config, input.as_bytes(), &mut out).unwrap();
+  String::from_utf8(out).unwrap()
+}
+
+#[test]
+fn repl_evaluates_expressions() {
+  let out = repl_session("1 + 2\nprint(true); [1, 2]\n");
+  assert_eq!(out, "snake> 3\nsnake> true\n[1, 2]\nsnake> ");
+}
+
+#[test]
+fn repl_has_the_prelude() {
+  let out = repl_session("map(lambda x: x + 1 end, range(0, 2))\ngetor(nth(range(5, 9), 2), 0)\n");
+  assert_eq!(out, "snake> [1, [2, []]]\nsnake> 7\nsnake> ");
+}
+
+#[test]
+fn repl_keeps_declarations() {
+  let out = repl_session(
+    "\
+def double(x): x * 2
+type Point(x, y), Origin
+Point(double(2), Origin)
+:type Point(1, 2)
+:type double
+match Point(1, 2) default 0: case Point(a, b) => a + b end
+",
+  );
+  assert_eq!(
+    out,
+    "snake> snake> snake> Point(4, Origin)\nsnake> Point\nsnake> Func\nsnake> 3\nsnake> "
+  );
+}
+
+#[test]
+fn repl_reads_multiple_lines() {
+  let out = repl_session(
+    "\
+def fact(n):
+  if n == 0: 1
+  else: n * fact(n - 1)
+fact(5)
+let x = 1
+in x + 1
+",
+  );
+  assert_eq!(
+    out,
+    "snake>   ...>   ...> snake> 120\nsnake>   ...> 2\nsnake> "
+  );
+}
+
+#[test]
+fn repl_reports_errors_and_continues() {
+  let out = repl_session("1 +\n\nundefined(1)\ndef f(x): y\n1 + true\nf(1)\n");
+  assert!(out.starts_with("snake>   ...> Error parsing input: "));
+  assert!(out.contains("snake> Error generating assembly: Unbound variable undefined"));
+  assert!(out.contains("snake> Error generating assembly: Unbound variable y"));
+  assert!(out.contains("snake> Error in interpreter: arithmetic expected a number"));
+  // f was never defined
+  assert!(out.ends_with("Unbound variable f at line 1, column 0 to line 1, column 1\nsnake> "));
+}
+
+#[test]
+fn repl_stops_runaway_expressions() {
+  let out = repl_session_with(
+    &Config::new().max_steps(1000),
+    "def spin(n): spin(n + 1)\nspin(0)\n1\n",
+  );
+  assert!(out.contains("Ran out of fuel after 1000 steps\nsnake> 1\n"));
+}
+
+#[test]
+fn repl_loads_files() {
+  let out = repl_session(":load examples/garter/non-trivial_tests/types_funcs.garter\nfoo(Some(1) == None)\n");
+  assert!(out.starts_with("snake> 1\nsnake> "));
+  assert!(out.contains("arithmetic expected a number, but got false in +"));
+}
+
+#[test]
+fn repl_compiles_input() {
+  let out = repl_session("def f(x): x\nf(1)\n:dump lifted\n:asm 7\n");
+  assert!(out.contains("snake> def "));
+  assert!(out.contains("\nin\n"));
+  assert!(out.contains("start_here:"));
+  assert!(out.contains("mov rax, 14"));
+}
+
+#[test]
+fn repl_commands() {
+  let out = repl_session(":help\n:dump nowhere\n:type\n:what\n:quit\n1\n");
+  assert!(out.contains(":load FILE"));
+  assert!(out.contains("unknown stage nowhere"));
+  assert!(out.contains("The :type command needs an argument"));
+  assert!(out.contains("Unknown command :what"));
+  assert!(out.ends_with("snake> "));
+}